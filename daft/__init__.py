@@ -87,6 +87,7 @@ from daft.convert import (
     from_arrow,
     from_dask_dataframe,
     from_pandas,
+    from_polars,
     from_pydict,
     from_pylist,
     from_ray_dataset,
@@ -99,11 +100,13 @@ from daft.expressions import Expression, col, list_, lit, interval, struct, coal
 from daft.io import (
     DataCatalogTable,
     DataCatalogType,
+    analyze_parquet,
     from_glob_path,
     read_csv,
     read_deltalake,
     read_hudi,
     read_iceberg,
+    read_ipc,
     read_json,
     read_parquet,
     read_sql,
@@ -114,8 +117,10 @@ from daft.series import Series
 from daft.session import (
     Session,
     attach_catalog,
+    attach_function,
     attach_table,
     detach_catalog,
+    detach_function,
     detach_table,
     create_temp_table,
     current_catalog,
@@ -135,6 +140,7 @@ from daft.session import (
 from daft.sql import sql, sql_expr
 from daft.udf import udf
 from daft.viz import register_viz_hook
+from daft.window import Window
 
 to_struct = Expression.to_struct
 
@@ -154,7 +160,10 @@ __all__ = [
     "Session",
     "Table",
     "TimeUnit",
+    "Window",
+    "analyze_parquet",
     "attach_catalog",
+    "attach_function",
     "attach_table",
     "coalesce",
     "col",
@@ -163,12 +172,14 @@ __all__ = [
     "current_namespace",
     "current_session",
     "detach_catalog",
+    "detach_function",
     "detach_table",
     "execution_config_ctx",
     "from_arrow",
     "from_dask_dataframe",
     "from_glob_path",
     "from_pandas",
+    "from_polars",
     "from_pydict",
     "from_pylist",
     "from_ray_dataset",
@@ -186,6 +197,7 @@ __all__ = [
     "read_deltalake",
     "read_hudi",
     "read_iceberg",
+    "read_ipc",
     "read_json",
     "read_lance",
     "read_parquet",
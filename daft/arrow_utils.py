@@ -26,6 +26,23 @@ def ensure_table(tbl: pa.Table) -> pa.Table:
     return tbl
 
 
+def to_large_offsets_type(arrow_type: pa.DataType) -> pa.DataType:
+    """Promotes strings, binaries and lists with 32-bit offsets to their 64-bit offset variants, including in lists.
+
+    Daft stores these types with 64-bit offsets, so chunks are promoted before they are combined into a single array,
+    which would otherwise overflow its 32-bit offsets if the chunks hold more than 2GB of data.
+    """
+    if pa.types.is_string(arrow_type):
+        return pa.large_string()
+    elif pa.types.is_binary(arrow_type):
+        return pa.large_binary()
+    elif pa.types.is_list(arrow_type) or pa.types.is_large_list(arrow_type):
+        value_field = arrow_type.value_field
+        return pa.large_list(value_field.with_type(to_large_offsets_type(value_field.type)))
+    else:
+        return arrow_type
+
+
 class _FixEmptyStructArrays:
     """Converts StructArrays that are empty (have no fields) to StructArrays with a single field named "" and with a NullType.
 
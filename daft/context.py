@@ -7,12 +7,16 @@ from typing import TYPE_CHECKING, ClassVar
 
 from daft.daft import IOConfig, PyDaftContext, PyDaftExecutionConfig, PyDaftPlanningConfig
 from daft.daft import get_context as _get_context
+from daft.daft import invalidate_parquet_metadata_cache as _invalidate_parquet_metadata_cache
 from daft.daft import set_runner_native as _set_runner_native
 from daft.daft import set_runner_py as _set_runner_py
+from daft.daft import set_parquet_metadata_cache_capacity as _set_parquet_metadata_cache_capacity
 from daft.daft import set_runner_ray as _set_runner_ray
 
 if TYPE_CHECKING:
+    from daft.dataframe import DataFrame
     from daft.runners.runner import Runner
+    from daft.session import Session
 
 logger = logging.getLogger(__name__)
 
@@ -56,6 +60,17 @@ class DaftContext:
     def daft_planning_config(self) -> PyDaftPlanningConfig:
         return self._ctx._daft_planning_config
 
+    @property
+    def session(self) -> Session:
+        """The current session, which holds the attached catalogs, tables, and temporary functions."""
+        from daft.session import Session
+
+        return Session._from_pysession(self._ctx._session)
+
+    @session.setter
+    def session(self, session: Session):
+        self._ctx._session = session._session
+
 
 def get_context() -> DaftContext:
     return DaftContext(_get_context())
@@ -145,6 +160,7 @@ def planning_config_ctx(**kwargs):
 def set_planning_config(
     config: PyDaftPlanningConfig | None = None,
     default_io_config: IOConfig | None = None,
+    disabled_optimizer_rules: list[str] | None = None,
 ) -> DaftContext:
     """Globally sets various configuration parameters which control Daft plan construction behavior.
 
@@ -155,6 +171,9 @@ def set_planning_config(
             that the old (current) config should be used.
         default_io_config: A default IOConfig to use in the absence of one being explicitly passed into any Expression (e.g. `.url.download()`)
             or Dataframe operation (e.g. `daft.read_parquet()`).
+        disabled_optimizer_rules: Names of logical plan optimizer rules to skip when optimizing Dataframes built
+            afterwards, e.g. `["PushDownFilter"]`, which is useful to debug plans. Defaults to None, which keeps the
+            current rules.
     """
     # Replace values in the DaftPlanningConfig with user-specified overrides
     ctx = get_context()
@@ -162,6 +181,7 @@ def set_planning_config(
         old_daft_planning_config = ctx._ctx._daft_planning_config if config is None else config
         new_daft_planning_config = old_daft_planning_config.with_config_values(
             default_io_config=default_io_config,
+            disabled_optimizer_rules=disabled_optimizer_rules,
         )
 
         ctx._ctx._daft_planning_config = new_daft_planning_config
@@ -189,6 +209,7 @@ def set_execution_config(
     sort_merge_join_sort_with_aligned_boundaries: bool | None = None,
     hash_join_partition_size_leniency: float | None = None,
     sample_size_for_sort: int | None = None,
+    sort_boundary_oversampling_factor: int | None = None,
     num_preview_rows: int | None = None,
     parquet_target_filesize: int | None = None,
     parquet_target_row_group_size: int | None = None,
@@ -206,6 +227,13 @@ def set_execution_config(
     pre_shuffle_merge_threshold: int | None = None,
     enable_ray_tracing: bool | None = None,
     scantask_splitting_level: int | None = None,
+    memory_limit_bytes: int | None = None,
+    enable_speculative_execution: bool | None = None,
+    speculative_execution_multiplier: float | None = None,
+    scan_task_retry_backoff_ms: int | None = None,
+    enable_result_cache: bool | None = None,
+    target_partition_size_bytes: int | None = None,
+    default_morsel_size_bytes: int | None = None,
 ) -> DaftContext:
     """Globally sets various configuration parameters which control various aspects of Daft execution.
 
@@ -233,6 +261,9 @@ def set_execution_config(
             number of partitions as the left. Defaults to 0.5.
         sample_size_for_sort: number of elements to sample from each partition when running sort,
             Default is 20.
+        sort_boundary_oversampling_factor: number of sort keys to sample for each range of sorted rows when choosing
+            the boundaries of the ranges, which are then sorted independently. Higher values balance the ranges better
+            at the cost of sampling more keys. Defaults to 32.
         num_preview_rows: number of rows to when showing a dataframe preview,
             Default is 8.
         parquet_target_filesize: Target File Size when writing out Parquet Files. Defaults to 512MB
@@ -251,6 +282,36 @@ def set_execution_config(
         pre_shuffle_merge_threshold: Memory threshold in bytes for pre-shuffle merge. Defaults to 1GB
         enable_ray_tracing: Enable tracing for Ray. Accessible in `/tmp/ray/session_latest/logs/daft` after the run completes. Defaults to False.
         scantask_splitting_level: How aggressively to split scan tasks. Setting this to `2` will use a more aggressive ScanTask splitting algorithm which might be more expensive to run but results in more even splits of partitions. Defaults to 1.
+        memory_limit_bytes: Maximum number of bytes a single query may reserve at once on the Native Runner. Tasks
+            wait for the query's other reservations to be released when theirs don't fit, and a single reservation
+            that's larger than this fails with a "memory limit exceeded" error instead of the process being killed by
+            the OS. The peak bytes reserved and the peak bytes of data held by the query's operators are written to
+            explain analyze output.
+            Sorts that buffer more than their share of it spill sorted runs to disk and merge them. Defaults to None,
+            which uses the total memory available to the process.
+        enable_speculative_execution: Re-launch tasks that take much longer than the other tasks of a query on the Python
+            Runner, and use the results of whichever copy finishes first. The other copy stops before its next
+            instruction. Tasks that write data are never re-launched. Other runners ignore this, with a warning.
+            Defaults to False.
+        speculative_execution_multiplier: How many times slower than the median completed task a task must be before it
+            is re-launched when speculative execution is enabled. Must be greater than 1.0. Defaults to 1.5.
+        scan_task_retry_backoff_ms: Time to wait before the first retry of a failed scan task, for reads that retry
+            scan tasks with `scan_task_max_attempts`. The wait doubles with every further attempt. Defaults to 1000.
+        enable_result_cache: Reuse the results of a previously executed query when the same query is executed again on
+            the Native or Python Runner. Queries are identified by their optimized plan, and results are only reused
+            if the files they read have the same ETags, or modification times for local files, as when they were
+            computed. Queries that write data, call nondeterministic functions or read files without versions are
+            never cached. Use :func:`invalidate_result_cache` to drop results that are stale. Defaults to False.
+        target_partition_size_bytes: Target size of partitions after shuffles on the Ray Runner. When set, the number
+            of partitions of aggregations, joins and sorts is chosen from the estimated size of the data they shuffle,
+            such as the sizes of the files read and the rows left after filters and limits, instead of the number of
+            input partitions, which it never exceeds. Partitions that filters are estimated to shrink well below this
+            size are also coalesced. Defaults to None, which keeps the number of input partitions.
+        default_morsel_size_bytes: Maximum size in bytes of the morsels that the Native Runner passes to operators,
+            including scanned data and the batches passed to Python UDFs without a ``batch_size``, on top of
+            ``default_morsel_size`` rows. The number of rows per morsel is chosen from the average size of the rows.
+            Lower this to reduce the memory used for wide rows, such as images, at the cost of more per-morsel
+            overhead. Defaults to None, which only limits the number of rows.
     """
     # Replace values in the DaftExecutionConfig with user-specified overrides
     ctx = get_context()
@@ -266,6 +327,7 @@ def set_execution_config(
             sort_merge_join_sort_with_aligned_boundaries=sort_merge_join_sort_with_aligned_boundaries,
             hash_join_partition_size_leniency=hash_join_partition_size_leniency,
             sample_size_for_sort=sample_size_for_sort,
+            sort_boundary_oversampling_factor=sort_boundary_oversampling_factor,
             num_preview_rows=num_preview_rows,
             parquet_target_filesize=parquet_target_filesize,
             parquet_target_row_group_size=parquet_target_row_group_size,
@@ -283,7 +345,53 @@ def set_execution_config(
             pre_shuffle_merge_threshold=pre_shuffle_merge_threshold,
             enable_ray_tracing=enable_ray_tracing,
             scantask_splitting_level=scantask_splitting_level,
+            memory_limit_bytes=memory_limit_bytes,
+            enable_speculative_execution=enable_speculative_execution,
+            speculative_execution_multiplier=speculative_execution_multiplier,
+            scan_task_retry_backoff_ms=scan_task_retry_backoff_ms,
+            enable_result_cache=enable_result_cache,
+            target_partition_size_bytes=target_partition_size_bytes,
+            default_morsel_size_bytes=default_morsel_size_bytes,
         )
 
         ctx._ctx._daft_execution_config = new_daft_execution_config
         return ctx
+
+
+def invalidate_result_cache(df: DataFrame | None = None) -> None:
+    """Drops query results cached when `enable_result_cache` is set in the execution config.
+
+    Args:
+        df: If provided, only the results of this DataFrame's query are dropped. Its plan is optimized to identify the
+            query, which lists the files it reads again. Defaults to None, which drops all cached results.
+    """
+    cache = get_context().get_or_create_runner()._query_result_cache
+    if df is None:
+        cache.clear()
+    else:
+        cache.invalidate(df._builder.optimize())
+
+
+def set_parquet_metadata_cache_capacity(capacity_bytes: int) -> None:
+    """Sets how much Parquet metadata is kept in memory to be reused by later queries that read the same files.
+
+    Parsed footers are cached by file path and ETag, so files that are overwritten are read again. Files on local disk
+    use their modification time instead of an ETag, and files on sources without ETags are never cached. Least recently
+    used footers are evicted once the estimated memory taken up by their parsed metadata exceeds the capacity.
+
+    Args:
+        capacity_bytes: Bound on the estimated size of the cached metadata. 0 disables the cache. Defaults to 256 MiB.
+    """
+    if capacity_bytes < 0:
+        raise ValueError(f"capacity_bytes must be non-negative, got {capacity_bytes}")
+    _set_parquet_metadata_cache_capacity(capacity_bytes)
+
+
+def invalidate_parquet_metadata_cache(path: str | None = None) -> None:
+    """Drops cached Parquet metadata, see :func:`set_parquet_metadata_cache_capacity`.
+
+    Args:
+        path: If provided, only the metadata of the file at this path is dropped. Defaults to None, which drops all
+            cached metadata.
+    """
+    _invalidate_parquet_metadata_cache(path)
@@ -8,6 +8,7 @@ if TYPE_CHECKING:
     import dask
     import numpy as np
     import pandas as pd
+    import polars as pl
     import pyarrow as pa
     from ray.data.dataset import Dataset as RayDataset
 
@@ -150,6 +151,48 @@ def from_pandas(data: Union["pd.DataFrame", List["pd.DataFrame"]]) -> "DataFrame
     return DataFrame._from_pandas(data)
 
 
+@PublicAPI
+def from_polars(data: Union["pl.DataFrame", List["pl.DataFrame"]]) -> "DataFrame":
+    """Creates a Daft DataFrame from a polars DataFrame.
+
+    The data is imported in Rust through the Arrow PyCapsule Interface (``__arrow_c_stream__``), so buffers are shared
+    with polars rather than copied.
+
+    Example:
+        >>> import polars as pl
+        >>> import daft
+        >>> pl_df = pl.DataFrame({"a": [1, 2, 3], "b": ["foo", "bar", "baz"]})
+        >>> df = daft.from_polars(pl_df)
+        >>> df.show()
+        ╭───────┬──────╮
+        │ a     ┆ b    │
+        │ ---   ┆ ---  │
+        │ Int64 ┆ Utf8 │
+        ╞═══════╪══════╡
+        │ 1     ┆ foo  │
+        ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌┤
+        │ 2     ┆ bar  │
+        ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌┤
+        │ 3     ┆ baz  │
+        ╰───────┴──────╯
+        <BLANKLINE>
+        (Showing first 3 of 3 rows)
+
+    Args:
+        data: polars DataFrame(s) that we wish to convert into a Daft DataFrame.
+
+    Returns:
+        DataFrame: Daft DataFrame created from the provided polars DataFrame.
+    """
+    from daft import DataFrame
+
+    from daft.recordbatch import MicroPartition
+
+    if not isinstance(data, list):
+        data = [data]
+    return DataFrame._from_tables(*[MicroPartition.from_arrow_c_stream(df) for df in data])
+
+
 @PublicAPI
 def from_ray_dataset(ds: "RayDataset") -> "DataFrame":
     """Creates a DataFrame from a Ray Dataset.
@@ -6,6 +6,8 @@ from typing import TYPE_CHECKING, Any, Callable, Iterator, Literal
 from daft.catalog import Catalog, Table
 from daft.dataframe.display import MermaidOptions
 from daft.execution import physical_plan
+from daft.expressions import Expression
+from daft.io.parquet_encryption import ParquetEncryptionConfig
 from daft.io.scan import ScanOperator
 from daft.plan_scheduler.physical_plan_scheduler import PartitionT
 from daft.runners.partitioning import PartitionCacheEntry
@@ -180,6 +182,7 @@ class FileFormat(Enum):
     Parquet: int
     Csv: int
     Json: int
+    ArrowIpc: int
 
     def ext(self): ...
 
@@ -190,6 +193,7 @@ class ParquetSourceConfig:
     field_id_mapping: dict[int, PyField] | None
     row_groups: list[list[int]] | None
     chunk_size: int | None
+    row_index_column: str | None
 
     def __init__(
         self,
@@ -197,6 +201,7 @@ class ParquetSourceConfig:
         field_id_mapping: dict[int, PyField] | None = None,
         row_groups: list[list[int]] | None = None,
         chunk_size: int | None = None,
+        row_index_column: str | None = None,
     ): ...
 
 class CsvSourceConfig:
@@ -241,6 +246,10 @@ class WarcSourceConfig:
     """Configuration of a Warc data source."""
     def __init__(self): ...
 
+class ArrowIpcSourceConfig:
+    """Configuration of an Arrow IPC (Feather) data source."""
+    def __init__(self): ...
+
 class DatabaseSourceConfig:
     """Configuration of a database data source."""
 
@@ -252,7 +261,14 @@ class DatabaseSourceConfig:
 class FileFormatConfig:
     """Configuration for parsing a particular file format (Parquet, CSV, JSON)."""
 
-    config: ParquetSourceConfig | CsvSourceConfig | JsonSourceConfig | DatabaseSourceConfig | WarcSourceConfig
+    config: (
+        ParquetSourceConfig
+        | CsvSourceConfig
+        | JsonSourceConfig
+        | DatabaseSourceConfig
+        | WarcSourceConfig
+        | ArrowIpcSourceConfig
+    )
 
     @staticmethod
     def from_parquet_config(config: ParquetSourceConfig) -> FileFormatConfig:
@@ -274,6 +290,11 @@ class FileFormatConfig:
         """Create a WARC file format config."""
         ...
 
+    @staticmethod
+    def from_arrow_ipc_config(config: ArrowIpcSourceConfig) -> FileFormatConfig:
+        """Create an Arrow IPC file format config."""
+        ...
+
     @staticmethod
     def from_database_config(config: DatabaseSourceConfig) -> FileFormatConfig:
         """Create a database file format config."""
@@ -609,7 +630,12 @@ class StorageConfig:
     multithreaded_io: bool
     io_config: IOConfig
 
-    def __init__(self, multithreaded_io: bool, io_config: IOConfig | None): ...
+    # Number of times the Native Runner attempts to read each scan task that fails with a transient IO error
+    scan_task_max_attempts: int
+
+    def __init__(
+        self, multithreaded_io: bool, io_config: IOConfig | None = None, scan_task_max_attempts: int = 1
+    ) -> None: ...
 
 class ScanTask:
     """A batch of scan tasks for reading data from an external source."""
@@ -634,6 +660,7 @@ class ScanTask:
         pushdowns: Pushdowns | None,
         partition_values: PyRecordBatch | None,
         stats: PyRecordBatch | None,
+        deletion_vector: dict[str, Any] | None = None,
     ) -> ScanTask | None:
         """Create a Catalog Scan Task."""
         ...
@@ -728,10 +755,14 @@ class Pushdowns:
     filters: PyExpr | None
     partition_filters: PyExpr | None
     limit: int | None
+    aggregations: list[PyExpr] | None
 
     def filter_required_column_names(self) -> list[str]:
         """List of field names that are required by the filter predicate."""
         ...
+    def with_filters(self, filters: PyExpr | None) -> Pushdowns:
+        """These pushdowns, with the given filter predicate instead of their own."""
+        ...
 
 def read_parquet(
     uri: str,
@@ -761,6 +792,8 @@ def read_parquet_statistics(
     io_config: IOConfig | None = None,
     multithreaded_io: bool | None = None,
 ): ...
+def set_parquet_metadata_cache_capacity(capacity_bytes: int) -> None: ...
+def invalidate_parquet_metadata_cache(uri: str | None = None) -> None: ...
 def read_parquet_into_pyarrow(
     uri: str,
     columns: list[str] | None = None,
@@ -819,6 +852,7 @@ def read_json_schema(
     io_config: IOConfig | None = None,
     multithreaded_io: bool | None = None,
 ): ...
+def write_json_lines(table: PyRecordBatch) -> bytes: ...
 
 class PyTimeUnit:
     @staticmethod
@@ -921,8 +955,9 @@ class PyDataType:
 class PyField:
     def name(self) -> str: ...
     @staticmethod
-    def create(name: str, datatype: PyDataType) -> PyField: ...
+    def create(name: str, datatype: PyDataType, metadata: dict[str, str] | None = None) -> PyField: ...
     def dtype(self) -> PyDataType: ...
+    def metadata(self) -> dict[str, str]: ...
     def eq(self, other: PyField) -> bool: ...
     def __reduce__(self) -> tuple: ...
 
@@ -931,6 +966,7 @@ class PySchema:
     def names(self) -> list[str]: ...
     def union(self, other: PySchema) -> PySchema: ...
     def eq(self, other: PySchema) -> bool: ...
+    def validate_matches(self, expected: PySchema) -> None: ...
     def estimate_row_size_bytes(self) -> float: ...
     @staticmethod
     def from_field_name_and_types(names_and_types: list[tuple[str, PyDataType]]) -> PySchema: ...
@@ -952,6 +988,8 @@ class PyExpr:
     def count_distinct(self) -> PyExpr: ...
     def sum(self) -> PyExpr: ...
     def approx_count_distinct(self) -> PyExpr: ...
+    def hll_sketch(self) -> PyExpr: ...
+    def hll_merge(self) -> PyExpr: ...
     def approx_percentiles(self, percentiles: float | list[float]) -> PyExpr: ...
     def mean(self) -> PyExpr: ...
     def stddev(self) -> PyExpr: ...
@@ -987,9 +1025,28 @@ class PyExpr:
     def eq_null_safe(self, other: PyExpr) -> PyExpr: ...
     def is_in(self, other: list[PyExpr]) -> PyExpr: ...
     def between(self, lower: PyExpr, upper: PyExpr) -> PyExpr: ...
+    def lag(self, offset: int, default: PyExpr | None = None) -> PyExpr: ...
+    def lead(self, offset: int, default: PyExpr | None = None) -> PyExpr: ...
+    def over(
+        self,
+        partition_by: list[PyExpr],
+        order_by: list[PyExpr],
+        descending: list[builtins.bool],
+        null_strategy: Literal["smallest", "largest", "skip"] = "largest",
+        frame: tuple[int | None, int | None] | None = None,
+    ) -> PyExpr: ...
     def name(self) -> str: ...
+    def _merge_partial_aggregation(self) -> PyExpr: ...
     def to_field(self, schema: PySchema) -> PyField: ...
-    def to_sql(self) -> str: ...
+    def to_sql(self) -> str | None: ...
+    def split_sql_pushdown(
+        self,
+        arithmetic: bool = True,
+        is_in: bool = True,
+        between: bool = True,
+        like: bool = True,
+        null_safe_equals: bool = True,
+    ) -> tuple[str | None, PyExpr | None]: ...
     def __repr__(self) -> str: ...
     def __hash__(self) -> int: ...
     def __reduce__(self) -> tuple: ...
@@ -1044,7 +1101,18 @@ def initialize_udfs(expression: PyExpr) -> PyExpr: ...
 def get_udf_names(expression: PyExpr) -> list[str]: ...
 def resolve_expr(expr: PyExpr, schema: PySchema) -> tuple[PyExpr, PyField]: ...
 def hash(expr: PyExpr, seed: Any | None = None) -> PyExpr: ...
+def hmac_sha256(expr: PyExpr, key: bytes) -> PyExpr: ...
+def one_hot(expr: PyExpr, categories: PyExpr) -> PyExpr: ...
+def label_encode(expr: PyExpr, categories: PyExpr, labels: PyExpr | None = None) -> PyExpr: ...
+def recorded_violations(violations_id: str) -> list[list[PySeries]]: ...
 def cosine_distance(expr: PyExpr, other: PyExpr) -> PyExpr: ...
+def point(lat: PyExpr, lon: PyExpr) -> PyExpr: ...
+def haversine_distance(lat1: PyExpr, lon1: PyExpr, lat2: PyExpr, lon2: PyExpr) -> PyExpr: ...
+def geohash_encode(lat: PyExpr, lon: PyExpr, precision: int) -> PyExpr: ...
+def geohash_decode(expr: PyExpr) -> PyExpr: ...
+def within_bbox(
+    lat: PyExpr, lon: PyExpr, min_lat: PyExpr, min_lon: PyExpr, max_lat: PyExpr, max_lon: PyExpr
+) -> PyExpr: ...
 def url_download(
     expr: PyExpr,
     max_connections: int,
@@ -1083,7 +1151,20 @@ def minhash(
     seed: int = 1,
     hash_function: Literal["murmurhash3", "xxhash", "sha1"] = "murmurhash3",
 ) -> PyExpr: ...
+def minhash_similarity(left: PyExpr, right: PyExpr) -> PyExpr: ...
+def simhash(
+    expr: PyExpr,
+    ngram_size: int,
+    seed: int = 1,
+    hash_function: Literal["murmurhash3", "xxhash", "sha1"] = "murmurhash3",
+) -> PyExpr: ...
+def simhash_distance(left: PyExpr, right: PyExpr) -> PyExpr: ...
+def rand(seed: int | None = None, row_seed: PyExpr | None = None) -> PyExpr: ...
+def randn(seed: int | None = None, row_seed: PyExpr | None = None) -> PyExpr: ...
+def randint(low: int, high: int, seed: int | None = None, row_seed: PyExpr | None = None) -> PyExpr: ...
 def coalesce(exprs: list[PyExpr]) -> PyExpr: ...
+def row_number() -> PyExpr: ...
+def rank() -> PyExpr: ...
 
 # -----
 # SQL functions
@@ -1111,8 +1192,18 @@ class ConnectionHandle:
 # expr numeric ops
 def abs(expr: PyExpr) -> PyExpr: ...
 def cbrt(expr: PyExpr) -> PyExpr: ...
+def int_to_ip(expr: PyExpr) -> PyExpr: ...
+def format_decimal(
+    expr: PyExpr,
+    decimal_separator: str = ".",
+    thousands_separator: str | None = ",",
+    currency_symbol: str | None = None,
+    accounting: bool = False,
+) -> PyExpr: ...
 def ceil(expr: PyExpr) -> PyExpr: ...
 def clip(expr: PyExpr, min: PyExpr, max: PyExpr) -> PyExpr: ...
+def width_bucket(expr: PyExpr, low: PyExpr, high: PyExpr, num_buckets: int) -> PyExpr: ...
+def quantile_bucket(expr: PyExpr, boundaries: list[float]) -> PyExpr: ...
 def exp(expr: PyExpr) -> PyExpr: ...
 def expm1(expr: PyExpr) -> PyExpr: ...
 def floor(expr: PyExpr) -> PyExpr: ...
@@ -1186,6 +1277,8 @@ def explode(expr: PyExpr) -> PyExpr: ...
 def list_sort(expr: PyExpr, desc: PyExpr, nulls_first: PyExpr) -> PyExpr: ...
 def list_distinct(expr: PyExpr) -> PyExpr: ...
 def list_value_counts(expr: PyExpr) -> PyExpr: ...
+def list_rle_encode(expr: PyExpr) -> PyExpr: ...
+def list_delta_encode(expr: PyExpr) -> PyExpr: ...
 def list_join(expr: PyExpr, delimiter: PyExpr) -> PyExpr: ...
 def list_count(expr: PyExpr, mode: CountMode) -> PyExpr: ...
 def list_get(expr: PyExpr, idx: PyExpr, default: PyExpr) -> PyExpr: ...
@@ -1229,7 +1322,18 @@ def utf8_substr(expr: PyExpr, start: PyExpr, length: PyExpr) -> PyExpr: ...
 def utf8_to_date(expr: PyExpr, format: str) -> PyExpr: ...
 def utf8_to_datetime(expr: PyExpr, format: str, timezone: str | None = None) -> PyExpr: ...
 def utf8_normalize(
-    expr: PyExpr, remove_punct: bool, lowercase: bool, nfd_unicode: bool, white_space: bool
+    expr: PyExpr, remove_punct: bool, lowercase: bool, nfd_unicode: bool, white_space: bool, form: str | None = None
+) -> PyExpr: ...
+def utf8_remove_accents(expr: PyExpr) -> PyExpr: ...
+def utf8_ascii_fold(expr: PyExpr) -> PyExpr: ...
+def utf8_is_email(expr: PyExpr) -> PyExpr: ...
+def utf8_is_ipv4(expr: PyExpr) -> PyExpr: ...
+def utf8_is_ipv6(expr: PyExpr) -> PyExpr: ...
+def utf8_is_phone_number(expr: PyExpr) -> PyExpr: ...
+def utf8_ip_to_int(expr: PyExpr) -> PyExpr: ...
+def utf8_extract_domain(expr: PyExpr) -> PyExpr: ...
+def utf8_parse_decimal(
+    expr: PyExpr, precision: int, scale: int, decimal_separator: str = ".", thousands_separator: str | None = ","
 ) -> PyExpr: ...
 
 # ---
@@ -1303,6 +1407,14 @@ class PySeries:
     def clip(self, min: PySeries, max: PySeries) -> PySeries: ...
     def sqrt(self) -> PySeries: ...
     def cbrt(self) -> PySeries: ...
+    def int_to_ip(self) -> PySeries: ...
+    def format_decimal(
+        self,
+        decimal_separator: str = ".",
+        thousands_separator: str | None = ",",
+        currency_symbol: str | None = None,
+        accounting: bool = False,
+    ) -> PySeries: ...
     def sin(self) -> PySeries: ...
     def cos(self) -> PySeries: ...
     def tan(self) -> PySeries: ...
@@ -1362,7 +1474,20 @@ class PySeries:
     def utf8_substr(self, start: PySeries, length: PySeries | None = None) -> PySeries: ...
     def utf8_to_date(self, format: str) -> PySeries: ...
     def utf8_to_datetime(self, format: str, timezone: str | None = None) -> PySeries: ...
-    def utf8_normalize(self, remove_punct: bool, lowercase: bool, nfd_unicode: bool, white_space: bool) -> PySeries: ...
+    def utf8_normalize(
+        self, remove_punct: bool, lowercase: bool, nfd_unicode: bool, white_space: bool, form: str | None = None
+    ) -> PySeries: ...
+    def utf8_remove_accents(self) -> PySeries: ...
+    def utf8_ascii_fold(self) -> PySeries: ...
+    def utf8_is_email(self) -> PySeries: ...
+    def utf8_is_ipv4(self) -> PySeries: ...
+    def utf8_is_ipv6(self) -> PySeries: ...
+    def utf8_is_phone_number(self) -> PySeries: ...
+    def utf8_ip_to_int(self) -> PySeries: ...
+    def utf8_extract_domain(self) -> PySeries: ...
+    def utf8_parse_decimal(
+        self, precision: int, scale: int, decimal_separator: str = ".", thousands_separator: str | None = ","
+    ) -> PySeries: ...
     def utf8_count_matches(self, patterns: PySeries, whole_word: bool, case_sensitive: bool) -> PySeries: ...
     def is_nan(self) -> PySeries: ...
     def is_inf(self) -> PySeries: ...
@@ -1454,6 +1579,9 @@ class PyRecordBatch:
     @staticmethod
     def from_arrow_record_batches(record_batches: list[pa.RecordBatch], schema: PySchema) -> PyRecordBatch: ...
     @staticmethod
+    def from_arrow_c_stream(obj: Any) -> PyRecordBatch: ...
+    def __arrow_c_stream__(self, requested_schema: object | None = None) -> object: ...
+    @staticmethod
     def from_pylist_series(dict: dict[str, PySeries]) -> PyRecordBatch: ...
     def to_arrow_record_batch(self) -> pa.RecordBatch: ...
     @staticmethod
@@ -1485,6 +1613,13 @@ class PyMicroPartition:
     def take(self, idx: PySeries) -> PyMicroPartition: ...
     def filter(self, exprs: list[PyExpr]) -> PyMicroPartition: ...
     def sort(self, sort_keys: list[PyExpr], descending: list[bool], nulls_first: list[bool]) -> PyMicroPartition: ...
+    def window(
+        self,
+        window_functions: list[PyExpr],
+        partition_by: list[PyExpr],
+        order_by: list[PyExpr],
+        null_strategy: Literal["smallest", "largest", "skip"],
+    ) -> PyMicroPartition: ...
     def argsort(self, sort_keys: list[PyExpr], descending: list[bool], nulls_first: list[bool]) -> PySeries: ...
     def agg(self, to_agg: list[PyExpr], group_by: list[PyExpr]) -> PyMicroPartition: ...
     def hash_join(
@@ -1594,6 +1729,7 @@ class PhysicalPlanScheduler:
     def num_partitions(self) -> int: ...
     def repr_ascii(self, simple: bool) -> str: ...
     def repr_mermaid(self, options: MermaidOptions) -> str: ...
+    def repr_json(self, simple: bool) -> str: ...
     def to_json_string(self) -> str: ...
     def to_partition_tasks(
         self, psets: dict[str, list[PartitionT]], actor_pool_manager: Any
@@ -1643,12 +1779,24 @@ class LogicalPlanBuilder:
         size_bytes: int,
         num_rows: int,
     ) -> LogicalPlanBuilder: ...
+    @staticmethod
+    def iteration_scan(partition_key: str, schema: PySchema) -> LogicalPlanBuilder: ...
     def with_planning_config(self, daft_planning_config: PyDaftPlanningConfig) -> LogicalPlanBuilder: ...
     def select(self, to_select: list[PyExpr]) -> LogicalPlanBuilder: ...
     def with_columns(self, columns: list[PyExpr]) -> LogicalPlanBuilder: ...
     def with_columns_renamed(self, cols_map: dict[str, str]) -> LogicalPlanBuilder: ...
     def exclude(self, to_exclude: list[str]) -> LogicalPlanBuilder: ...
     def filter(self, predicate: PyExpr) -> LogicalPlanBuilder: ...
+    def in_subquery(self, expr: PyExpr) -> PyExpr: ...
+    def assert_that(self, predicate: PyExpr, message: str, violations_id: str | None = None) -> LogicalPlanBuilder: ...
+    def iterate(
+        self,
+        body: LogicalPlanBuilder,
+        convergence: LogicalPlanBuilder,
+        previous_key: str,
+        result_key: str,
+        max_iters: int,
+    ) -> LogicalPlanBuilder: ...
     def limit(self, limit: int, eager: bool) -> LogicalPlanBuilder: ...
     def explode(self, to_explode: list[PyExpr]) -> LogicalPlanBuilder: ...
     def unpivot(
@@ -1658,12 +1806,31 @@ class LogicalPlanBuilder:
         variable_name: str,
         value_name: str,
     ) -> LogicalPlanBuilder: ...
-    def sort(self, sort_by: list[PyExpr], descending: list[bool], nulls_first: list[bool]) -> LogicalPlanBuilder: ...
+    def sort(
+        self,
+        sort_by: list[PyExpr],
+        descending: list[bool],
+        nulls_first: list[bool],
+        collations: list[str] | None = None,
+    ) -> LogicalPlanBuilder: ...
+    def sort_within_partitions(
+        self,
+        sort_by: list[PyExpr],
+        descending: list[bool],
+        nulls_first: list[bool],
+        collations: list[str] | None = None,
+    ) -> LogicalPlanBuilder: ...
     def hash_repartition(
         self,
         partition_by: list[PyExpr],
         num_partitions: int | None,
     ) -> LogicalPlanBuilder: ...
+    def range_repartition(
+        self,
+        partition_by: list[PyExpr],
+        descending: list[bool],
+        num_partitions: int | None,
+    ) -> LogicalPlanBuilder: ...
     def random_shuffle(self, num_partitions: int | None) -> LogicalPlanBuilder: ...
     def into_partitions(self, num_partitions: int) -> LogicalPlanBuilder: ...
     def coalesce(self, num_partitions: int) -> LogicalPlanBuilder: ...
@@ -1700,6 +1867,10 @@ class LogicalPlanBuilder:
         partition_cols: list[PyExpr] | None = None,
         compression: str | None = None,
         io_config: IOConfig | None = None,
+        job_id: str | None = None,
+        bloom_filter_columns: list[str] | None = None,
+        row_group_size_bytes: int | None = None,
+        encryption: ParquetEncryptionConfig | None = None,
     ) -> LogicalPlanBuilder: ...
     def iceberg_write(
         self,
@@ -1734,10 +1905,16 @@ class LogicalPlanBuilder:
     def describe(self) -> LogicalPlanBuilder: ...
     def summarize(self) -> LogicalPlanBuilder: ...
     def optimize(self) -> LogicalPlanBuilder: ...
+    def fingerprint(self) -> int: ...
+    def plan_eq(self, other: LogicalPlanBuilder) -> bool: ...
+    def is_cacheable(self) -> bool: ...
+    def source_file_versions(self) -> list[tuple[str, str | None]]: ...
+    def estimated_size_bytes(self) -> int: ...
     def to_physical_plan_scheduler(self, cfg: PyDaftExecutionConfig) -> PhysicalPlanScheduler: ...
     def to_adaptive_physical_plan_scheduler(self, cfg: PyDaftExecutionConfig) -> AdaptivePhysicalPlanScheduler: ...
-    def repr_ascii(self, simple: bool) -> str: ...
+    def repr_ascii(self, simple: bool, verbose: bool = False) -> str: ...
     def repr_mermaid(self, options: MermaidOptions) -> str: ...
+    def repr_json(self, simple: bool) -> str: ...
 
 class NativeExecutor:
     def __init__(self) -> None: ...
@@ -1754,6 +1931,9 @@ class NativeExecutor:
     def repr_mermaid(
         self, builder: LogicalPlanBuilder, daft_execution_config: PyDaftExecutionConfig, options: MermaidOptions
     ) -> str: ...
+    def repr_json(
+        self, builder: LogicalPlanBuilder, daft_execution_config: PyDaftExecutionConfig, simple: bool
+    ) -> str: ...
 
 class PyDaftExecutionConfig:
     @staticmethod
@@ -1768,6 +1948,7 @@ class PyDaftExecutionConfig:
         sort_merge_join_sort_with_aligned_boundaries: bool | None = None,
         hash_join_partition_size_leniency: float | None = None,
         sample_size_for_sort: int | None = None,
+        sort_boundary_oversampling_factor: int | None = None,
         num_preview_rows: int | None = None,
         parquet_target_filesize: int | None = None,
         parquet_target_row_group_size: int | None = None,
@@ -1785,6 +1966,13 @@ class PyDaftExecutionConfig:
         shuffle_algorithm: str | None = None,
         pre_shuffle_merge_threshold: int | None = None,
         scantask_splitting_level: int | None = None,
+        memory_limit_bytes: int | None = None,
+        enable_speculative_execution: bool | None = None,
+        speculative_execution_multiplier: float | None = None,
+        scan_task_retry_backoff_ms: int | None = None,
+        enable_result_cache: bool | None = None,
+        target_partition_size_bytes: int | None = None,
+        default_morsel_size_bytes: int | None = None,
     ) -> PyDaftExecutionConfig: ...
     @property
     def scan_tasks_min_size_bytes(self) -> int: ...
@@ -1801,6 +1989,8 @@ class PyDaftExecutionConfig:
     @property
     def sample_size_for_sort(self) -> int: ...
     @property
+    def sort_boundary_oversampling_factor(self) -> int: ...
+    @property
     def num_preview_rows(self) -> int: ...
     @property
     def parquet_target_filesize(self) -> int: ...
@@ -1832,6 +2022,20 @@ class PyDaftExecutionConfig:
     def pre_shuffle_merge_threshold(self) -> int: ...
     @property
     def enable_ray_tracing(self) -> bool: ...
+    @property
+    def memory_limit_bytes(self) -> int | None: ...
+    @property
+    def enable_speculative_execution(self) -> bool: ...
+    @property
+    def speculative_execution_multiplier(self) -> float: ...
+    @property
+    def scan_task_retry_backoff_ms(self) -> int: ...
+    @property
+    def enable_result_cache(self) -> bool: ...
+    @property
+    def target_partition_size_bytes(self) -> int | None: ...
+    @property
+    def default_morsel_size_bytes(self) -> int | None: ...
 
 class PyDaftPlanningConfig:
     @staticmethod
@@ -1839,9 +2043,12 @@ class PyDaftPlanningConfig:
     def with_config_values(
         self,
         default_io_config: IOConfig | None = None,
+        disabled_optimizer_rules: list[str] | None = None,
     ) -> PyDaftPlanningConfig: ...
     @property
     def default_io_config(self) -> IOConfig: ...
+    @property
+    def disabled_optimizer_rules(self) -> list[str]: ...
 
 class PyDaftContext:
     def __init__(self) -> None: ...
@@ -1849,6 +2056,7 @@ class PyDaftContext:
     def get_or_create_runner(self) -> Runner: ...
     _daft_execution_config: PyDaftExecutionConfig
     _daft_planning_config: PyDaftPlanningConfig
+    _session: PySession
     @property
     def daft_execution_config(self) -> PyDaftExecutionConfig: ...
     @property
@@ -1915,17 +2123,22 @@ class PySession:
     @staticmethod
     def empty() -> PySession: ...
     def attach_catalog(self, catalog: Catalog, alias: str): ...
+    def attach_function(self, function: Callable[..., Expression], alias: str): ...
     def attach_table(self, table: Table, alias: str): ...
     def detach_catalog(self, alias: str): ...
+    def detach_function(self, alias: str): ...
     def detach_table(self, alias: str): ...
     def create_temp_table(self, ident: str, source: PyTableSource, replace: bool): ...
     def current_catalog(self) -> Catalog | None: ...
     def current_namespace(self) -> PyIdentifier | None: ...
     def get_catalog(self, ident: str) -> Catalog: ...
+    def get_function(self, name: str) -> Callable[..., Expression]: ...
     def get_table(self, ident: PyIdentifier) -> Table: ...
     def has_catalog(self, ident: str) -> bool: ...
+    def has_function(self, name: str) -> bool: ...
     def has_table(self, ident: PyIdentifier) -> bool: ...
     def list_catalogs(self, pattern: str | None = None) -> list[str]: ...
+    def list_functions(self, pattern: str | None = None) -> list[str]: ...
     def list_tables(self, pattern: str | None = None) -> list[PyIdentifier]: ...
     def set_catalog(self, ident: str | None): ...
     def set_namespace(self, ident: PyIdentifier | None): ...
@@ -9,6 +9,7 @@ import multiprocessing
 import os
 import pathlib
 import typing
+import uuid
 import warnings
 from dataclasses import dataclass
 from datetime import datetime, timezone
@@ -33,15 +34,22 @@ from typing import (
 from daft.api_annotations import DataframePublicAPI
 from daft.context import get_context
 from daft.convert import InputListType
-from daft.daft import FileFormat, IOConfig, JoinStrategy, JoinType
+from daft.daft import FileFormat, IOConfig, JoinStrategy, JoinType, hmac_sha256
 from daft.dataframe.preview import DataFramePreview
 from daft.datatype import DataType
 from daft.errors import ExpressionTypeError
 from daft.execution.native_executor import NativeExecutor
 from daft.expressions import Expression, ExpressionsProjection, col, lit
-from daft.filesystem import overwrite_files
+from daft.filesystem import (
+    commit_write_job,
+    is_stream_path,
+    overwrite_files,
+    read_parquet_summary,
+    start_write_job,
+    write_parquet_summary,
+)
 from daft.logical.builder import LogicalPlanBuilder
-from daft.recordbatch import MicroPartition
+from daft.recordbatch import MicroPartition, RecordBatch
 from daft.runners.partitioning import LocalPartitionSet, PartitionCacheEntry, PartitionSet
 from daft.viz import DataFrameDisplay
 
@@ -49,12 +57,13 @@ if TYPE_CHECKING:
     import dask
     import deltalake
     import pandas
+    import polars
     import pyarrow
     import pyiceberg
     import ray
     import torch
 
-    from daft.io import DataCatalogTable
+    from daft.io import DataCatalogTable, ParquetEncryptionConfig
     from daft.unity_catalog import UnityCatalogTable
 
 from daft.logical.schema import Schema
@@ -98,6 +107,13 @@ def _utc_now() -> datetime:
     return datetime.now(timezone.utc)
 
 
+def _recorded_violations(violations_id: str) -> Iterator[RecordBatch]:
+    from daft.daft import PyRecordBatch, recorded_violations
+
+    for columns in recorded_violations(violations_id):
+        yield RecordBatch._from_pytable(PyRecordBatch.from_pylist_series({c.name(): c for c in columns}))
+
+
 class DataFrame:
     """A Daft DataFrame is a table of data.
 
@@ -186,7 +202,12 @@ class DataFrame:
 
     @DataframePublicAPI
     def explain(
-        self, show_all: bool = False, format: str = "ascii", simple: bool = False, file: Optional[io.IOBase] = None
+        self,
+        show_all: bool = False,
+        format: str = "ascii",
+        simple: bool = False,
+        file: Optional[io.IOBase] = None,
+        verbose: bool = False,
     ) -> Any:
         """Prints the (logical and physical) plans that will be executed to produce this DataFrame.
 
@@ -196,12 +217,15 @@ class DataFrame:
         Args:
             show_all (bool): Whether to show the optimized logical plan and the physical plan in addition to the
                 unoptimized logical plan.
-            format (str): The format to print the plan in. one of 'ascii' or 'mermaid'
+            format (str): The format to print the plan in. one of 'ascii', 'mermaid' or 'json'. The 'json' format
+                prints each plan as a tree of objects with the `name`, `details` and `children` of every node.
             simple (bool): Whether to only show the type of op for each node in the plan, rather than showing details
                 of how each op is configured.
 
             file (Optional[io.IOBase]): Location to print the output to, or defaults to None which defaults to the default location for
                 print (in Python, that should be sys.stdout)
+            verbose (bool): Whether to also show the output schema, with the resolved type of every column, of each node
+                in the logical plans. Only applies to the 'ascii' format.
         """
         is_cached = self._result_cache is not None
         if format == "mermaid":
@@ -224,17 +248,17 @@ class DataFrame:
 
         if self._result_cache is not None:
             print_to_file("Result is cached and will skip computation\n")
-            print_to_file(self._builder.pretty_print(simple, format=format))
+            print_to_file(self._builder.pretty_print(simple, format=format, verbose=verbose))
 
             print_to_file("However here is the logical plan used to produce this result:\n", file=file)
 
         builder = self.__builder
         print_to_file("== Unoptimized Logical Plan ==\n")
-        print_to_file(builder.pretty_print(simple, format=format))
+        print_to_file(builder.pretty_print(simple, format=format, verbose=verbose))
         if show_all:
             print_to_file("\n== Optimized Logical Plan ==\n")
             builder = builder.optimize()
-            print_to_file(builder.pretty_print(simple))
+            print_to_file(builder.pretty_print(simple, format=format, verbose=verbose))
             print_to_file("\n== Physical Plan ==\n")
             if get_context().get_or_create_runner().name != "native":
                 physical_plan_scheduler = builder.to_physical_plan_scheduler(get_context().daft_execution_config)
@@ -618,6 +642,44 @@ class DataFrame:
     # Write methods
     ###
 
+    def __validate_write_schema(
+        self,
+        root_dir: Union[str, pathlib.Path],
+        file_format: FileFormat,
+        partition_cols: Optional[List[Expression]],
+        expected_schema: Union[Schema, Literal["existing"]],
+        io_config: IOConfig,
+    ) -> None:
+        schema = self.schema()
+        if isinstance(expected_schema, str):
+            if expected_schema != "existing":
+                raise ValueError(f"`expected_schema` must be a Schema or 'existing', but got: {expected_schema}")
+
+            from daft.io import read_csv, read_ipc, read_json, read_parquet
+
+            if file_format == FileFormat.Parquet:
+                read_fn = read_parquet
+            elif file_format == FileFormat.ArrowIpc:
+                read_fn = read_ipc
+            elif file_format == FileFormat.Json:
+                read_fn = read_json
+            else:
+                read_fn = read_csv
+            glob = f"*.{file_format.ext()}" if partition_cols is None else f"**/*.{file_format.ext()}"
+            path = f"{str(root_dir).rstrip('/')}/{glob}"
+            try:
+                expected_schema = read_fn(path, io_config=io_config).schema()
+            except FileNotFoundError:
+                # Nothing has been written yet, so there is nothing to drift from.
+                return
+
+            # Partition columns are encoded in the directory names rather than in the files themselves.
+            if partition_cols is not None:
+                partition_names = {expr.name() for expr in partition_cols}
+                schema = Schema._from_fields([field for field in schema if field.name not in partition_names])
+
+        schema.validate_matches(expected_schema)
+
     @DataframePublicAPI
     def write_parquet(
         self,
@@ -626,10 +688,20 @@ class DataFrame:
         write_mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
         partition_cols: Optional[List[ColumnInputType]] = None,
         io_config: Optional[IOConfig] = None,
+        expected_schema: Optional[Union[Schema, Literal["existing"]]] = None,
+        encryption: Optional["ParquetEncryptionConfig"] = None,
+        bloom_filter_columns: Optional[List[str]] = None,
+        row_group_size_bytes: Optional[int] = None,
+        write_metadata: bool = False,
     ) -> "DataFrame":
         """Writes the DataFrame as parquet files, returning a new DataFrame with paths to the files that were written.
 
-        Files will be written to ``<root_dir>/*`` with randomly generated UUIDs as the file names.
+        Files will be written to ``<root_dir>/*`` with randomly generated UUIDs as the file names, prefixed with the ID of
+        the write job. While the job runs, ``<root_dir>/_started_<job_id>`` marks it as in progress; on success this is replaced
+        by a ``<root_dir>/_committed_<job_id>`` manifest of the written files. The output of an interrupted job can be removed with
+        :func:`daft.filesystem.cleanup_uncommitted_writes` before retrying. The approximate number of distinct values of each
+        column is recorded in the footer of every file, so that queries reading the files back can better estimate the size
+        of group-bys and joins.
 
         .. NOTE::
             This call is **blocking** and will execute the DataFrame when called
@@ -640,6 +712,24 @@ class DataFrame:
             write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace the contents of the root directory with new data. `overwrite-partitions` will replace only the contents in the partitions that are being written to. Defaults to "append".
             partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
             io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+            expected_schema (Optional[Union[Schema, str]], optional): If provided, the schema of the DataFrame is checked
+                against it before anything is written, and a precise diff is raised on any mismatch. Pass `"existing"` to
+                check against the files already in ``root_dir`` (excluding partition columns), if there are any. Defaults to None.
+            encryption (Optional[ParquetEncryptionConfig], optional): If provided, files are written with Parquet modular
+                encryption, using the footer and column keys it specifies. Read them back by passing the same config to
+                :func:`daft.read_parquet`. Defaults to None.
+            bloom_filter_columns (Optional[List[str]], optional): Columns to write a bloom filter for in every row group.
+                Reads with an equality or ``is_in`` filter on these columns skip the row groups whose bloom filters rule
+                out the filtered values, which speeds up point lookups on high-cardinality columns. Requires
+                pyarrow>=22.0.0. Defaults to None.
+            row_group_size_bytes (Optional[int], optional): Target in-memory size in bytes of every row group of the
+                written files. Smaller row groups let filtered reads skip more data, at the cost of larger footers.
+                Defaults to None, which uses the ``parquet_target_row_group_size`` of the execution config.
+            write_metadata (bool, optional): Whether to also write a ``<root_dir>/_metadata`` file that summarizes
+                the schemas and row group statistics of all the Parquet files in ``root_dir``. Reads of ``root_dir``
+                then use it instead of listing the directory and fetching the footer of every file. Later writes into
+                ``root_dir`` delete the summary unless they also set this. Can't be used with ``encryption``.
+                Defaults to False.
 
         Returns:
             DataFrame: The filenames that were written out as strings.
@@ -653,6 +743,8 @@ class DataFrame:
             )
         if write_mode == "overwrite-partitions" and partition_cols is None:
             raise ValueError("Partition columns must be specified to use `overwrite-partitions` mode.")
+        if write_metadata and encryption is not None:
+            raise ValueError("A `_metadata` summary can't be written for encrypted files.")
 
         io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
 
@@ -660,12 +752,25 @@ class DataFrame:
         if partition_cols is not None:
             cols = self.__column_input_to_expression(tuple(partition_cols))
 
+        if expected_schema is not None:
+            self.__validate_write_schema(root_dir, FileFormat.Parquet, cols, expected_schema, io_config)
+
+        # Appended files can be added to the existing summary, whereas other writes replace files that it summarizes.
+        summary_to_extend = None
+        if write_metadata and write_mode == "append":
+            summary_to_extend = read_parquet_summary(root_dir, io_config)
+
+        job_id = start_write_job(root_dir, io_config)
         builder = self._builder.write_tabular(
             root_dir=root_dir,
             partition_cols=cols,
             file_format=FileFormat.Parquet,
             compression=compression,
             io_config=io_config,
+            job_id=job_id,
+            bloom_filter_columns=bloom_filter_columns,
+            row_group_size_bytes=row_group_size_bytes,
+            encryption=encryption,
         )
         # Block and write, then retrieve data
         write_df = DataFrame(builder)
@@ -682,21 +787,98 @@ class DataFrame:
             result_df = DataFrame(write_df._builder)
             result_df._result_cache = write_df._result_cache
             result_df._preview = write_df._preview
-            return result_df
         else:
             from daft import from_pydict
             from daft.recordbatch.recordbatch_io import write_empty_tabular
 
             file_path = write_empty_tabular(
-                root_dir, FileFormat.Parquet, self.schema(), compression=compression, io_config=io_config
+                root_dir,
+                FileFormat.Parquet,
+                self.schema(),
+                compression=compression,
+                io_config=io_config,
+                job_id=job_id,
+                bloom_filter_columns=bloom_filter_columns,
+                encryption=encryption,
             )
 
-            return from_pydict(
+            result_df = from_pydict(
                 {
                     "path": [file_path],
                 }
             )
 
+        written_file_paths = result_df.to_pydict()["path"]
+        commit_write_job(root_dir, job_id, written_file_paths, io_config)
+        if write_metadata:
+            write_parquet_summary(root_dir, written_file_paths, io_config, summary_to_extend)
+        return result_df
+
+    @DataframePublicAPI
+    def write_parquet_and_read(
+        self,
+        root_dir: Union[str, pathlib.Path],
+        compression: str = "snappy",
+        write_mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
+        partition_cols: Optional[List[ColumnInputType]] = None,
+        io_config: Optional[IOConfig] = None,
+        expected_schema: Optional[Union[Schema, Literal["existing"]]] = None,
+    ) -> "DataFrame":
+        """Writes the DataFrame as parquet files, returning a new lazy DataFrame that reads back the files that were written.
+
+        This is useful for checkpointing in write-then-continue pipelines: the returned DataFrame scans exactly the
+        written files and reuses this DataFrame's schema (and partition columns, read back from the hive-style paths),
+        so no directory listing or schema inference is needed to continue from the written data.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"a": [1, 2, 3], "b": ["x", "y", "x"]})
+            >>> df = df.write_parquet_and_read("/tmp/checkpoint", partition_cols=["b"])  # doctest: +SKIP
+            >>> df.where(daft.col("a") > 1).collect()  # doctest: +SKIP
+
+        .. NOTE::
+            This call is **blocking** and will execute the DataFrame when called, but the returned DataFrame is lazy.
+
+        Args:
+            root_dir (str): root file path to write parquet files to.
+            compression (str, optional): compression algorithm. Defaults to "snappy".
+            write_mode (str, optional): Operation mode of the write. See :meth:`DataFrame.write_parquet <daft.DataFrame.write_parquet>`. Defaults to "append".
+            partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
+            io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+            expected_schema (Optional[Union[Schema, str]], optional): Schema to validate against before writing. See :meth:`DataFrame.write_parquet <daft.DataFrame.write_parquet>`. Defaults to None.
+
+        Returns:
+            DataFrame: A DataFrame backed by the written parquet files, with the same schema as this DataFrame.
+        """
+        from daft.io import read_parquet
+
+        io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+        written = self.write_parquet(
+            root_dir,
+            compression=compression,
+            write_mode=write_mode,
+            partition_cols=partition_cols,
+            io_config=io_config,
+            expected_schema=expected_schema,
+        )
+        paths = written.to_pydict()["path"]
+        if not paths:
+            # Nothing was written, e.g. a partitioned write of an empty DataFrame, so there are no files to read.
+            return DataFrame._from_tables(MicroPartition.empty(self.schema()))
+
+        # The written files have exactly this DataFrame's schema, so there is nothing to infer. For
+        # partitioned writes, also read the hive-style partition values so that filters on the
+        # partition columns can prune files.
+        df = read_parquet(
+            paths,
+            infer_schema=False,
+            schema={field.name: field.dtype for field in self.schema()},
+            hive_partitioning=partition_cols is not None,
+            io_config=io_config,
+        )
+        # Drop any generated partition columns (e.g. from partition transforms) that aren't in this DataFrame.
+        return df.select(*self.column_names) if partition_cols is not None else df
+
     @DataframePublicAPI
     def write_csv(
         self,
@@ -704,19 +886,29 @@ class DataFrame:
         write_mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
         partition_cols: Optional[List[ColumnInputType]] = None,
         io_config: Optional[IOConfig] = None,
+        expected_schema: Optional[Union[Schema, Literal["existing"]]] = None,
     ) -> "DataFrame":
         """Writes the DataFrame as CSV files, returning a new DataFrame with paths to the files that were written.
 
-        Files will be written to ``<root_dir>/*`` with randomly generated UUIDs as the file names.
+        Files will be written to ``<root_dir>/*`` with randomly generated UUIDs as the file names, prefixed with the ID of
+        the write job. While the job runs, ``<root_dir>/_started_<job_id>`` marks it as in progress; on success this is replaced
+        by a ``<root_dir>/_committed_<job_id>`` manifest of the written files. The output of an interrupted job can be removed with
+        :func:`daft.filesystem.cleanup_uncommitted_writes` before retrying.
+
+        If ``root_dir`` is ``"-"`` or a named pipe, the DataFrame is instead written as a single CSV to standard output
+        or the pipe, streaming out each batch of rows as soon as it's computed.
 
         .. NOTE::
             This call is **blocking** and will execute the DataFrame when called
 
         Args:
-            root_dir (str): root file path to write parquet files to.
+            root_dir (str): root file path to write CSV files to, or ``"-"`` or a named pipe to stream to.
             write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace the contents of the root directory with new data. `overwrite-partitions` will replace only the contents in the partitions that are being written to. Defaults to "append".
             partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
             io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+            expected_schema (Optional[Union[Schema, str]], optional): If provided, the schema of the DataFrame is checked
+                against it before anything is written, and a precise diff is raised on any mismatch. Pass `"existing"` to
+                check against the files already in ``root_dir`` (excluding partition columns), if there are any. Defaults to None.
 
         Returns:
             DataFrame: The filenames that were written out as strings.
@@ -733,11 +925,21 @@ class DataFrame:
         cols: Optional[List[Expression]] = None
         if partition_cols is not None:
             cols = self.__column_input_to_expression(tuple(partition_cols))
+        if expected_schema is not None:
+            self.__validate_write_schema(root_dir, FileFormat.Csv, cols, expected_schema, io_config)
+
+        if is_stream_path(root_dir):
+            if partition_cols is not None:
+                raise ValueError("Partition columns can't be specified when writing to a stream.")
+            return self.__write_stream(str(root_dir), FileFormat.Csv)
+
+        job_id = start_write_job(root_dir, io_config)
         builder = self._builder.write_tabular(
             root_dir=root_dir,
             partition_cols=cols,
             file_format=FileFormat.Csv,
             io_config=io_config,
+            job_id=job_id,
         )
 
         # Block and write, then retrieve data
@@ -755,19 +957,227 @@ class DataFrame:
             result_df = DataFrame(write_df._builder)
             result_df._result_cache = write_df._result_cache
             result_df._preview = write_df._preview
-            return result_df
         else:
             from daft import from_pydict
             from daft.recordbatch.recordbatch_io import write_empty_tabular
 
-            file_path = write_empty_tabular(root_dir, FileFormat.Csv, self.schema(), io_config=io_config)
+            file_path = write_empty_tabular(root_dir, FileFormat.Csv, self.schema(), io_config=io_config, job_id=job_id)
 
-            return from_pydict(
+            result_df = from_pydict(
                 {
                     "path": [file_path],
                 }
             )
 
+        commit_write_job(root_dir, job_id, result_df.to_pydict()["path"], io_config)
+        return result_df
+
+    @DataframePublicAPI
+    def write_json(
+        self,
+        root_dir: Union[str, pathlib.Path],
+        write_mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
+        partition_cols: Optional[List[ColumnInputType]] = None,
+        io_config: Optional[IOConfig] = None,
+        expected_schema: Optional[Union[Schema, Literal["existing"]]] = None,
+    ) -> "DataFrame":
+        """Writes the DataFrame as JSON Lines files, returning a new DataFrame with paths to the files that were written.
+
+        Files will be written to ``<root_dir>/*`` with randomly generated UUIDs as the file names, prefixed with the ID of
+        the write job. While the job runs, ``<root_dir>/_started_<job_id>`` marks it as in progress; on success this is replaced
+        by a ``<root_dir>/_committed_<job_id>`` manifest of the written files. The output of an interrupted job can be removed with
+        :func:`daft.filesystem.cleanup_uncommitted_writes` before retrying.
+
+        If ``root_dir`` is ``"-"`` or a named pipe, the DataFrame is instead written as JSON Lines to standard output
+        or the pipe, streaming out each batch of rows as soon as it's computed.
+
+        Values that JSON can't represent directly, such as decimals and binary data, are written as strings.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"a": [1, 2]})
+            >>> df.write_json("-")  # doctest: +SKIP
+            {"a":1}
+            {"a":2}
+
+        .. NOTE::
+            This call is **blocking** and will execute the DataFrame when called
+
+        Args:
+            root_dir (str): root file path to write JSON Lines files to, or ``"-"`` or a named pipe to stream to.
+            write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace the contents of the root directory with new data. `overwrite-partitions` will replace only the contents in the partitions that are being written to. Defaults to "append".
+            partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
+            io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+            expected_schema (Optional[Union[Schema, str]], optional): If provided, the schema of the DataFrame is checked
+                against it before anything is written, and a precise diff is raised on any mismatch. Pass `"existing"` to
+                check against the files already in ``root_dir`` (excluding partition columns), if there are any. Defaults to None.
+
+        Returns:
+            DataFrame: The filenames that were written out as strings.
+        """
+        if write_mode not in ["append", "overwrite", "overwrite-partitions"]:
+            raise ValueError(
+                f"Only support `append`, `overwrite`, or `overwrite-partitions` mode. {write_mode} is unsupported"
+            )
+        if write_mode == "overwrite-partitions" and partition_cols is None:
+            raise ValueError("Partition columns must be specified to use `overwrite-partitions` mode.")
+
+        io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+        cols: Optional[List[Expression]] = None
+        if partition_cols is not None:
+            cols = self.__column_input_to_expression(tuple(partition_cols))
+        if expected_schema is not None:
+            self.__validate_write_schema(root_dir, FileFormat.Json, cols, expected_schema, io_config)
+
+        if is_stream_path(root_dir):
+            if partition_cols is not None:
+                raise ValueError("Partition columns can't be specified when writing to a stream.")
+            return self.__write_stream(str(root_dir), FileFormat.Json)
+
+        job_id = start_write_job(root_dir, io_config)
+        builder = self._builder.write_tabular(
+            root_dir=root_dir,
+            partition_cols=cols,
+            file_format=FileFormat.Json,
+            io_config=io_config,
+            job_id=job_id,
+        )
+
+        # Block and write, then retrieve data
+        write_df = DataFrame(builder)
+        write_df.collect()
+        assert write_df._result is not None
+
+        if write_mode == "overwrite":
+            overwrite_files(write_df, root_dir, io_config, False)
+        elif write_mode == "overwrite-partitions":
+            overwrite_files(write_df, root_dir, io_config, True)
+
+        if len(write_df) > 0:
+            # Populate and return a new disconnected DataFrame
+            result_df = DataFrame(write_df._builder)
+            result_df._result_cache = write_df._result_cache
+            result_df._preview = write_df._preview
+        else:
+            from daft import from_pydict
+            from daft.recordbatch.recordbatch_io import write_empty_tabular
+
+            file_path = write_empty_tabular(
+                root_dir, FileFormat.Json, self.schema(), io_config=io_config, job_id=job_id
+            )
+
+            result_df = from_pydict(
+                {
+                    "path": [file_path],
+                }
+            )
+
+        commit_write_job(root_dir, job_id, result_df.to_pydict()["path"], io_config)
+        return result_df
+
+    def __write_stream(self, path: str, file_format: FileFormat) -> "DataFrame":
+        from daft import from_pydict
+        from daft.io.writer import write_stream
+
+        write_stream(self.to_arrow_iter(), self.schema().to_pyarrow_schema(), path, file_format)
+        return from_pydict({"path": [path]})
+
+    @DataframePublicAPI
+    def write_ipc(
+        self,
+        root_dir: Union[str, pathlib.Path],
+        write_mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
+        partition_cols: Optional[List[ColumnInputType]] = None,
+        compression: Optional[str] = None,
+        io_config: Optional[IOConfig] = None,
+        expected_schema: Optional[Union[Schema, Literal["existing"]]] = None,
+    ) -> "DataFrame":
+        """Writes the DataFrame as Arrow IPC files, which are also known as Feather (version 2) files, returning a new DataFrame with paths to the files that were written.
+
+        Files will be written to ``<root_dir>/*`` with randomly generated UUIDs as the file names, prefixed with the ID of
+        the write job. While the job runs, ``<root_dir>/_started_<job_id>`` marks it as in progress; on success this is replaced
+        by a ``<root_dir>/_committed_<job_id>`` manifest of the written files. The output of an interrupted job can be removed with
+        :func:`daft.filesystem.cleanup_uncommitted_writes` before retrying.
+
+        .. NOTE::
+            This call is **blocking** and will execute the DataFrame when called
+
+        Args:
+            root_dir (str): root file path to write Arrow IPC files to.
+            write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace the contents of the root directory with new data. `overwrite-partitions` will replace only the contents in the partitions that are being written to. Defaults to "append".
+            partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
+            compression (str, optional): compression codec of the record batches, either "lz4" or "zstd". Defaults to None, which leaves them uncompressed.
+            io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+            expected_schema (Optional[Union[Schema, str]], optional): If provided, the schema of the DataFrame is checked
+                against it before anything is written, and a precise diff is raised on any mismatch. Pass `"existing"` to
+                check against the files already in ``root_dir`` (excluding partition columns), if there are any. Defaults to None.
+
+        Returns:
+            DataFrame: The filenames that were written out as strings.
+        """
+        if write_mode not in ["append", "overwrite", "overwrite-partitions"]:
+            raise ValueError(
+                f"Only support `append`, `overwrite`, or `overwrite-partitions` mode. {write_mode} is unsupported"
+            )
+        if write_mode == "overwrite-partitions" and partition_cols is None:
+            raise ValueError("Partition columns must be specified to use `overwrite-partitions` mode.")
+
+        io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+        cols: Optional[List[Expression]] = None
+        if partition_cols is not None:
+            cols = self.__column_input_to_expression(tuple(partition_cols))
+        if expected_schema is not None:
+            self.__validate_write_schema(root_dir, FileFormat.ArrowIpc, cols, expected_schema, io_config)
+
+        job_id = start_write_job(root_dir, io_config)
+        builder = self._builder.write_tabular(
+            root_dir=root_dir,
+            partition_cols=cols,
+            file_format=FileFormat.ArrowIpc,
+            compression=compression,
+            io_config=io_config,
+            job_id=job_id,
+        )
+
+        # Block and write, then retrieve data
+        write_df = DataFrame(builder)
+        write_df.collect()
+        assert write_df._result is not None
+
+        if write_mode == "overwrite":
+            overwrite_files(write_df, root_dir, io_config, False)
+        elif write_mode == "overwrite-partitions":
+            overwrite_files(write_df, root_dir, io_config, True)
+
+        if len(write_df) > 0:
+            # Populate and return a new disconnected DataFrame
+            result_df = DataFrame(write_df._builder)
+            result_df._result_cache = write_df._result_cache
+            result_df._preview = write_df._preview
+        else:
+            from daft import from_pydict
+            from daft.recordbatch.recordbatch_io import write_empty_tabular
+
+            file_path = write_empty_tabular(
+                root_dir,
+                FileFormat.ArrowIpc,
+                self.schema(),
+                compression=compression,
+                io_config=io_config,
+                job_id=job_id,
+            )
+
+            result_df = from_pydict(
+                {
+                    "path": [file_path],
+                }
+            )
+
+        commit_write_job(root_dir, job_id, result_df.to_pydict()["path"], io_config)
+        return result_df
+
     @DataframePublicAPI
     def write_iceberg(
         self, table: "pyiceberg.table.Table", mode: str = "append", io_config: Optional[IOConfig] = None
@@ -1603,6 +2013,59 @@ class DataFrame:
         builder = self._builder.filter(predicate)
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def assert_that(
+        self, predicate: Union[Expression, str], message: str, violations_table: Optional[str] = None
+    ) -> "DataFrame":
+        """Checks a data quality constraint on every row as the DataFrame is executed.
+
+        By default, execution fails with ``message`` if the predicate is false or null for any row. If
+        ``violations_table`` is provided, nothing fails: the returned DataFrame only keeps the rows that satisfy the
+        predicate, and the violating rows are recorded as it's executed, in a temporary table of that name in the
+        current session. Violations are recorded in the process that executes the query, so they can only be read back
+        on the Native and Python runners, and every execution of the returned DataFrame adds its violations to the
+        table.
+
+        The check is always evaluated on exactly the rows of this DataFrame: the optimizer doesn't push it into scans,
+        and filters applied to the returned DataFrame aren't pushed below it.
+
+        Example:
+            >>> import daft
+            >>> from daft import col
+            >>> df = daft.from_pydict({"x": [1, 2, None]})
+            >>> df.assert_that(col("x") > 0, "x must be positive").collect()  # doctest: +SKIP
+            ValueError: Assertion failed for 1 of 3 rows: x must be positive
+            >>> valid = df.assert_that(col("x") > 0, "x must be positive", violations_table="bad_x")
+            >>> daft.read_table("bad_x").to_pydict()  # doctest: +SKIP
+            {'x': [None]}
+
+        Args:
+            predicate (Expression): boolean expression that every row must satisfy, e.g. ``col("x").not_null()``.
+            message (str): message to fail with when the predicate does not hold.
+            violations_table (Optional[str], optional): name of a temporary table to record violating rows into instead of failing. Defaults to None.
+
+        Returns:
+            DataFrame: This DataFrame with the constraint applied.
+        """
+        if isinstance(predicate, str):
+            from daft.sql.sql import sql_expr
+
+            predicate = sql_expr(predicate)
+
+        if violations_table is None:
+            return DataFrame(self._builder.assert_that(predicate, message))
+
+        from daft.io._generator import read_generator
+        from daft.session import create_temp_table
+
+        # The violating rows are recorded under an id of this check, so that other checks recording into a table of
+        # the same name don't mix with them.
+        violations_id = f"{violations_table}-{uuid.uuid4()}"
+        checked = DataFrame(self._builder.assert_that(predicate, message, violations_id))
+        violations = read_generator([partial(_recorded_violations, violations_id)], self.schema())
+        create_temp_table(violations_table, violations)
+        return checked
+
     @DataframePublicAPI
     def with_column(
         self,
@@ -1676,6 +2139,132 @@ class DataFrame:
         builder = self._builder.with_columns(new_columns)
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def anonymize(
+        self,
+        columns: List[str],
+        method: Literal["hash", "mask"] = "hash",
+        key: Optional[Union[str, bytes]] = None,
+    ) -> "DataFrame":
+        r"""Replaces the values of PII columns in a single projection, keeping all other columns as they are.
+
+        ``"hash"`` replaces each value with its HMAC-SHA256 keyed by ``key``, as a hex string, so values can still be
+        joined and grouped on. ``"mask"`` keeps the shape of each value, replacing uppercase letters with ``X``,
+        lowercase letters with ``x`` and digits with ``9``. Nulls are left as nulls.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"name": ["Alice", "Bob"], "phone": ["555-0100", "555-0199"]})
+            >>> df.anonymize(["name", "phone"], method="mask").show()
+            ╭───────┬──────────╮
+            │ name  ┆ phone    │
+            │ ---   ┆ ---      │
+            │ Utf8  ┆ Utf8     │
+            ╞═══════╪══════════╡
+            │ Xxxxx ┆ 999-9999 │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌┤
+            │ Xxx   ┆ 999-9999 │
+            ╰───────┴──────────╯
+            <BLANKLINE>
+            (Showing first 2 of 2 rows)
+
+        Args:
+            columns (List[str]): Names of the columns to anonymize.
+            method (str, optional): Either ``"hash"`` or ``"mask"``. Defaults to ``"hash"``.
+            key (Optional[Union[str, bytes]], optional): Secret key of the HMAC, required for ``"hash"``. Without it,
+                hashes can't be reversed by hashing guessed values. The key is redacted when the plan is displayed.
+
+        Returns:
+            DataFrame: DataFrame with the given columns anonymized.
+        """
+        if method == "hash":
+            if not key:
+                raise ValueError("A key is required to anonymize columns by hashing")
+            key_bytes = key.encode("utf-8") if isinstance(key, str) else bytes(key)
+
+            def anonymize_column(c: Expression) -> Expression:
+                return Expression._from_pyexpr(hmac_sha256(c.cast(DataType.string())._expr, key_bytes))
+        elif method == "mask":
+
+            def anonymize_column(c: Expression) -> Expression:
+                return (
+                    c.cast(DataType.string())
+                    .str.replace(r"\p{Lu}", "X", regex=True)
+                    .str.replace(r"\p{Ll}", "x", regex=True)
+                    .str.replace(r"\d", "9", regex=True)
+                )
+        else:
+            raise ValueError(f"Unsupported anonymization method: {method}, expected one of 'hash' or 'mask'")
+
+        missing = [name for name in columns if name not in self.column_names]
+        if missing:
+            raise ValueError(f"Cannot anonymize columns that don't exist: {missing}")
+
+        return self.with_columns(
+            {name: col(name).is_null().if_else(lit(None), anonymize_column(col(name))) for name in columns}
+        )
+
+    @DataframePublicAPI
+    def with_quantile_bucket(self, column_name: str, expr: Expression, num_buckets: int) -> "DataFrame":
+        """Adds a column with the bucket of each value of ``expr`` among ``num_buckets`` buckets of roughly equal size.
+
+        This eagerly runs a pass over the DataFrame to compute approximate quantiles of ``expr`` with a sketch, like
+        :meth:`Expression.approx_percentiles`, and then buckets the values between them with
+        :meth:`Expression.quantile_bucket`. Buckets are numbered from 1, and nulls are left as nulls.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": list(range(100))})
+            >>> buckets = df.with_quantile_bucket("bucket", df["x"], 4).to_pydict()["bucket"]
+            >>> sorted(set(buckets))
+            [1, 2, 3, 4]
+            >>> buckets == sorted(buckets)
+            True
+
+        Args:
+            column_name (str): name of new column
+            expr (Expression): numeric expression to bucket.
+            num_buckets (int): number of buckets, which must be positive.
+
+        Returns:
+            DataFrame: DataFrame with the new column of buckets.
+        """
+        if num_buckets <= 0:
+            raise ValueError(f"Expected num_buckets to be positive, got {num_buckets}")
+        expr = Expression._to_expression(expr)
+        percentiles = [i / num_buckets for i in range(1, num_buckets)]
+        quantiles = []
+        if percentiles:
+            stats = self.agg(expr.approx_percentiles(percentiles).alias("quantiles")).to_pydict()
+            # All of the values are null if there are no quantiles.
+            quantiles = stats["quantiles"][0] or []
+        return self.with_column(column_name, expr.quantile_bucket(quantiles))
+
+    @DataframePublicAPI
+    def with_label_encoding(self, column_name: str, expr: Expression) -> "DataFrame":
+        """Adds a column that labels each distinct value of ``expr`` with its index among the sorted distinct values.
+
+        This eagerly runs a pass over the DataFrame to find the distinct values of ``expr``, and then labels them with
+        :meth:`Expression.label_encode`. Nulls are left as nulls.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"city": ["Paris", "Berlin", "Paris", None, "Austin"]})
+            >>> df.with_label_encoding("label", df["city"]).to_pydict()
+            {'city': ['Paris', 'Berlin', 'Paris', None, 'Austin'], 'label': [2, 1, 2, None, 0]}
+
+        Args:
+            column_name (str): name of new column
+            expr (Expression): expression to label encode.
+
+        Returns:
+            DataFrame: DataFrame with the new column of labels.
+        """
+        expr = Expression._to_expression(expr)
+        distinct = self.select(expr.alias("value")).distinct().where(col("value").not_null()).sort("value")
+        categories = distinct.to_pydict()["value"]
+        return self.with_column(column_name, expr.label_encode(categories))
+
     @DataframePublicAPI
     def with_column_renamed(self, existing: str, new: str) -> "DataFrame":
         """Renames a column in the current DataFrame.
@@ -1749,6 +2338,7 @@ class DataFrame:
         by: Union[ColumnInputType, List[ColumnInputType]],
         desc: Union[bool, List[bool]] = False,
         nulls_first: Optional[Union[bool, List[bool]]] = None,
+        collation: Optional[Union[str, List[str]]] = None,
     ) -> "DataFrame":
         """Sorts DataFrame globally.
 
@@ -1799,6 +2389,9 @@ class DataFrame:
         Args:
             column (Union[ColumnInputType, List[ColumnInputType]]): column to sort by. Can be `str` or expression as well as a list of either.
             desc (Union[bool, List[bool]), optional): Sort by descending order. Defaults to False.
+            collation (Union[str, List[str]], optional): How to order string columns, either for all of them or for each
+                of them: "binary" orders strings by their bytes, "case_insensitive" ignores case, and "natural" orders
+                runs of digits by their numeric values, so that "file2" comes before "file10". Defaults to "binary".
 
         Returns:
             DataFrame: Sorted DataFrame.
@@ -1813,7 +2406,64 @@ class DataFrame:
 
         sort_by = self.__column_input_to_expression(by)
 
-        builder = self._builder.sort(sort_by=sort_by, descending=desc, nulls_first=nulls_first)
+        builder = self._builder.sort(sort_by=sort_by, descending=desc, nulls_first=nulls_first, collation=collation)
+        return DataFrame(builder)
+
+    @DataframePublicAPI
+    def sort_within_partitions(
+        self,
+        by: Union[ColumnInputType, List[ColumnInputType]],
+        desc: Union[bool, List[bool]] = False,
+        nulls_first: Optional[Union[bool, List[bool]]] = None,
+        collation: Optional[Union[str, List[str]]] = None,
+    ) -> "DataFrame":
+        """Sorts each partition of the DataFrame on its own, without ordering the partitions.
+
+        Note:
+            This is much cheaper than a global `.sort()`, because rows aren't moved between partitions. It's useful
+            when only the rows within each partition need to be ordered, e.g. before writing each partition to a file.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": [3, 2, 1], "y": [6, 4, 5]})
+            >>> sorted_df = df.sort_within_partitions("x")
+            >>> sorted_df.show()
+            ╭───────┬───────╮
+            │ x     ┆ y     │
+            │ ---   ┆ ---   │
+            │ Int64 ┆ Int64 │
+            ╞═══════╪═══════╡
+            │ 1     ┆ 5     │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 2     ┆ 4     │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 3     ┆ 6     │
+            ╰───────┴───────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Args:
+            by (Union[ColumnInputType, List[ColumnInputType]]): column to sort by. Can be `str` or expression as well as a list of either.
+            desc (Union[bool, List[bool]), optional): Sort by descending order. Defaults to False.
+            nulls_first (Union[bool, List[bool]), optional): Whether to place nulls first. Defaults to `desc`.
+            collation (Union[str, List[str]], optional): How to order string columns, either for all of them or for each
+                of them: "binary" orders strings by their bytes, "case_insensitive" ignores case, and "natural" orders
+                runs of digits by their numeric values, so that "file2" comes before "file10". Defaults to "binary".
+
+        Returns:
+            DataFrame: DataFrame whose partitions are each sorted.
+        """
+        if not isinstance(by, list):
+            by = [by]
+
+        if nulls_first is None:
+            nulls_first = desc
+
+        sort_by = self.__column_input_to_expression(by)
+
+        builder = self._builder.sort_within_partitions(
+            sort_by=sort_by, descending=desc, nulls_first=nulls_first, collation=collation
+        )
         return DataFrame(builder)
 
     @DataframePublicAPI
@@ -1907,6 +2557,44 @@ class DataFrame:
             builder = self._builder.hash_repartition(num, self.__column_input_to_expression(partition_by))
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def repartition_by_range(
+        self,
+        num: Optional[int],
+        by: Union[ColumnInputType, List[ColumnInputType]],
+        desc: Union[bool, List[bool]] = False,
+    ) -> "DataFrame":
+        """Repartitions DataFrame to ``num`` partitions of contiguous ranges of the values of ``by``.
+
+        The boundaries of the ranges are chosen from a sample of the data so that the partitions are about equally
+        large. Rows with equal values of ``by`` end up in the same partition, and the partitions are ordered by the
+        values of ``by``, but rows aren't sorted within each partition.
+
+        .. NOTE::
+
+            This function will globally shuffle your data, which is potentially a very expensive operation.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": [3, 1, 2], "y": [4, 5, 6]})
+            >>> repartitioned_df = df.repartition_by_range(3, "x")
+            >>> repartitioned_df.num_partitions()
+            3
+
+        Args:
+            num (Optional[int]): Number of target partitions; if None, the number of partitions will not be changed.
+            by (Union[ColumnInputType, List[ColumnInputType]]): Columns to partition by, either a single one or a list.
+            desc (Union[bool, List[bool]], optional): Whether the ranges are in descending order, either for all
+                columns or for each of them. Defaults to False.
+
+        Returns:
+            DataFrame: Repartitioned DataFrame.
+        """
+        if not isinstance(by, list):
+            by = [by]
+        builder = self._builder.range_repartition(num, self.__column_input_to_expression(by), descending=desc)
+        return DataFrame(builder)
+
     @DataframePublicAPI
     def into_partitions(self, num: int) -> "DataFrame":
         """Splits or coalesces DataFrame to ``num`` partitions. Order is preserved.
@@ -2061,23 +2749,67 @@ class DataFrame:
         return DataFrame(builder)
 
     @DataframePublicAPI
-    def concat(self, other: "DataFrame") -> "DataFrame":
+    def concat(
+        self,
+        other: "DataFrame",
+        strategy: Literal["vertical", "by_name"] = "vertical",
+        upcast: bool = True,
+    ) -> "DataFrame":
         """Concatenates two DataFrames together in a "vertical" concatenation.
 
         The resulting DataFrame
         has number of rows equal to the sum of the number of rows of the input DataFrames.
 
         .. NOTE::
-            DataFrames being concatenated **must have exactly the same schema**. You may wish to use the
-            :meth:`df.select() <daft.DataFrame.select>` and :meth:`expr.cast() <daft.Expression.cast>` methods
-            to ensure schema compatibility before concatenation.
+            With the default ``"vertical"`` strategy, DataFrames being concatenated **must have exactly the same
+            schema**. You may wish to use the :meth:`df.select() <daft.DataFrame.select>` and
+            :meth:`expr.cast() <daft.Expression.cast>` methods to ensure schema compatibility before concatenation,
+            or concatenate with the ``"by_name"`` strategy instead.
+
+        Example:
+            >>> import daft
+            >>> df1 = daft.from_pydict({"x": [1, 2], "y": ["a", "b"]})
+            >>> df2 = daft.from_pydict({"y": ["c"], "z": [True]})
+            >>> df1.concat(df2, strategy="by_name").sort("y").show()
+            ╭───────┬──────┬─────────╮
+            │ x     ┆ y    ┆ z       │
+            │ ---   ┆ ---  ┆ ---     │
+            │ Int64 ┆ Utf8 ┆ Boolean │
+            ╞═══════╪══════╪═════════╡
+            │ 1     ┆ a    ┆ None    │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+            │ 2     ┆ b    ┆ None    │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+            │ None  ┆ c    ┆ true    │
+            ╰───────┴──────┴─────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
 
         Args:
             other (DataFrame): other DataFrame to concatenate
+            strategy (str): how to align the columns of the DataFrames. ``"vertical"`` requires them to have the same
+                schema, while ``"by_name"`` matches columns by name, in the order they first appear in either
+                DataFrame, and fills the columns that a DataFrame doesn't have with nulls. Defaults to ``"vertical"``.
+            upcast (bool): whether columns of the same name with different types are cast to a common supertype when
+                concatenating by name, rather than raising an error. Defaults to True.
 
         Returns:
             DataFrame: DataFrame with rows from `self` on top and rows from `other` at the bottom.
         """
+        if strategy == "by_name":
+            if not upcast:
+                other_schema = other.schema()
+                for field in self.schema():
+                    if field.name in other_schema.column_names() and other_schema[field.name].dtype != field.dtype:
+                        raise ValueError(
+                            f"Column {field.name} has different types to concatenate by name without upcasting: "
+                            f"{field.dtype} and {other_schema[field.name].dtype}"
+                        )
+            builder = self._builder.union(other._builder, is_all=True, is_by_name=True)
+            return DataFrame(builder)
+        elif strategy != "vertical":
+            raise ValueError(f"Expected concat strategy to be 'vertical' or 'by_name', got {strategy!r}")
+
         if self.schema() != other.schema():
             raise ValueError(
                 f"DataFrames must have exactly the same schema for concatenation!\nExpected:\n{self.schema()}\n\nReceived:\n{other.schema()}"
@@ -2356,6 +3088,89 @@ class DataFrame:
         ), f"Func returned an instance of type [{type(result)}], should have been DataFrame."
         return result
 
+    @DataframePublicAPI
+    def iterate(
+        self,
+        plan_fn: Callable[["DataFrame"], "DataFrame"],
+        max_iters: int,
+        convergence_expr: Optional[Expression] = None,
+    ) -> "DataFrame":
+        """Repeatedly applies a function to the DataFrame, feeding each materialized result back in, until convergence.
+
+        This allows iterative algorithms such as transitive closures and label propagation to be expressed as a
+        function for a single step. The function is called once to build the plan of an iteration, which is added to
+        the query plan along with the convergence check. When the query is executed, the plan of an iteration is run
+        on the result of the previous one until it converges, materializing each result before the next iteration so
+        that the work doesn't grow with the number of iterations.
+
+        Note:
+            Iterations are only supported on the native runner.
+
+        Example:
+            >>> import daft
+            >>> from daft import col
+            >>> edges = daft.from_pydict({"src": [1, 2, 3], "dst": [2, 3, 4]})
+            >>> def extend_paths(paths):
+            ...     next_hops = edges.select(col("src").alias("dst"), col("dst").alias("next"))
+            ...     longer = paths.join(next_hops, on="dst").select("src", col("next").alias("dst"))
+            ...     return paths.concat(longer).distinct()
+            >>> edges.iterate(extend_paths, max_iters=10).sort(["src", "dst"]).show()
+            ╭───────┬───────╮
+            │ src   ┆ dst   │
+            │ ---   ┆ ---   │
+            │ Int64 ┆ Int64 │
+            ╞═══════╪═══════╡
+            │ 1     ┆ 2     │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 1     ┆ 3     │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 1     ┆ 4     │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 2     ┆ 3     │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 2     ┆ 4     │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 3     ┆ 4     │
+            ╰───────┴───────╯
+            <BLANKLINE>
+            (Showing first 6 of 6 rows)
+
+        Args:
+            plan_fn: A function that takes the result of the previous iteration, or this DataFrame for the first one,
+                and returns the result of the next iteration, with the same schema. It should only build a DataFrame
+                from its argument, not execute it.
+            max_iters (int): The maximum number of iterations, after which the latest result is returned even if it
+                hasn't converged.
+            convergence_expr (Optional[Expression]): A boolean aggregation expression, such as
+                ``col("changed").sum() == 0``, that's evaluated over the result of each iteration and is true once
+                it has converged. If not given, the iterations converge once a result has the same rows as the
+                previous one.
+
+        Returns:
+            DataFrame: The result of the last iteration.
+        """
+        if max_iters < 1:
+            raise ValueError(f"Expected max_iters to be at least 1, got {max_iters}")
+
+        # The executor puts the results of the iterations under these keys before running the plans that read them.
+        iteration_id = uuid.uuid4()
+        previous_key = f"iterate-{iteration_id}-previous"
+        result_key = f"iterate-{iteration_id}-result"
+        previous = DataFrame(LogicalPlanBuilder.from_iteration_scan(previous_key, self.schema()))
+        body = plan_fn(previous)
+        if not isinstance(body, DataFrame):
+            raise TypeError(f"Expected plan_fn to return a DataFrame, got {type(body)}")
+
+        result = DataFrame(LogicalPlanBuilder.from_iteration_scan(result_key, body.schema()))
+        if convergence_expr is not None:
+            convergence = result.agg(convergence_expr.alias("converged"))
+        else:
+            difference = previous.except_all(result).concat(result.except_all(previous))
+            convergence = difference.count().select((col("count") == 0).alias("converged"))
+
+        builder = self._builder.iterate(body._builder, convergence._builder, previous_key, result_key, max_iters)
+        return DataFrame(builder)
+
     def _agg(
         self,
         to_agg: Iterable[Expression],
@@ -3083,6 +3898,41 @@ class DataFrame:
             print(dataframe_display)
         return None
 
+    @DataframePublicAPI
+    def preview(self, n: int = 8) -> "DataFrameDisplay":
+        """Executes enough of the DataFrame to preview its first ``n`` rows, and returns them for display.
+
+        Only enough of the DataFrame is executed to produce ``n`` rows: the limit is pushed down into the scans, and
+        partitions stop being materialized as soon as ``n`` rows have been produced. Unlike :meth:`show`, the preview
+        is returned rather than displayed, and renders as a table with its ``repr`` or as HTML in a notebook.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": [1, 2, 3]})
+            >>> df.preview(2)
+            ╭───────╮
+            │ x     │
+            │ ---   │
+            │ Int64 │
+            ╞═══════╡
+            │ 1     │
+            ├╌╌╌╌╌╌╌┤
+            │ 2     │
+            ╰───────╯
+            <BLANKLINE>
+            (Showing first 2 of 3 rows)
+
+        .. NOTE::
+            This call is **blocking** and will execute the DataFrame when called
+
+        Args:
+            n: number of rows to preview. Defaults to 8.
+
+        Returns:
+            DataFrameDisplay: the first ``n`` rows of the DataFrame, which is displayed as a table.
+        """
+        return self._construct_show_display(n)
+
     def __len__(self):
         """Returns the count of rows when dataframe is materialized.
 
@@ -3120,13 +3970,21 @@ class DataFrame:
         return col_name in self.column_names
 
     @DataframePublicAPI
-    def to_pandas(self, coerce_temporal_nanoseconds: bool = False) -> "pandas.DataFrame":
+    def to_pandas(
+        self,
+        coerce_temporal_nanoseconds: bool = False,
+        date_as_object: bool = True,
+        use_nullable_dtypes: bool = False,
+    ) -> "pandas.DataFrame":
         """Converts the current DataFrame to a `pandas DataFrame <https://pandas.pydata.org/docs/reference/api/pandas.DataFrame.html>`__.
 
-        If results have not computed yet, collect will be called.
+        If results have not computed yet, collect will be called. Arrow-native columns are converted column-wise through pyarrow,
+        which avoids copies where the pandas and Arrow memory layouts agree.
 
         Args:
             coerce_temporal_nanoseconds (bool): Whether to coerce temporal columns to nanoseconds. Only applicable to pandas version >= 2.0 and pyarrow version >= 13.0.0. Defaults to False. See `pyarrow.Table.to_pandas <https://arrow.apache.org/docs/python/generated/pyarrow.Table.html#pyarrow.Table.to_pandas>`__ for more information.
+            date_as_object (bool): Whether to convert date columns to Python ``datetime.date`` objects rather than ``datetime64`` values. Defaults to True.
+            use_nullable_dtypes (bool): Whether to use pandas' nullable extension dtypes (e.g. ``Int64``, ``boolean``, ``string``) for integer, float, boolean and string columns, so that nulls are preserved without casting to float or object. Defaults to False.
 
         Returns:
             pandas.DataFrame: `pandas DataFrame <https://pandas.pydata.org/docs/reference/api/pandas.DataFrame.html>`__ converted from a Daft DataFrame
@@ -3141,9 +3999,32 @@ class DataFrame:
         pd_df = result.to_pandas(
             schema=self._builder.schema(),
             coerce_temporal_nanoseconds=coerce_temporal_nanoseconds,
+            date_as_object=date_as_object,
+            use_nullable_dtypes=use_nullable_dtypes,
         )
         return pd_df
 
+    @DataframePublicAPI
+    def to_polars(self) -> "polars.DataFrame":
+        """Converts the current DataFrame to a `polars DataFrame <https://docs.pola.rs/api/python/stable/reference/dataframe/index.html>`__.
+
+        If results have not computed yet, collect will be called. The data is exported from Rust to polars
+        through the Arrow PyCapsule Interface (``__arrow_c_stream__``), so buffers are shared rather than
+        copied wherever polars supports the Arrow layout.
+
+        Returns:
+            polars.DataFrame: `polars DataFrame <https://docs.pola.rs/api/python/stable/reference/dataframe/index.html>`__ converted from a Daft DataFrame
+
+            .. NOTE::
+                This call is **blocking** and will execute the DataFrame when called
+        """
+        import polars as pl
+
+        self.collect()
+        result = self._result
+        assert result is not None
+        return pl.DataFrame(result._get_merged_micropartition().to_table())
+
     @DataframePublicAPI
     def to_arrow(self) -> "pyarrow.Table":
         """Converts the current DataFrame to a `pyarrow Table <https://arrow.apache.org/docs/python/generated/pyarrow.Table.html>`__.
@@ -3631,3 +4512,92 @@ class GroupedDataFrame:
             DataFrame: DataFrame with grouped aggregations
         """
         return self.df._map_groups(udf, group_by=self.group_by)
+
+    def apply(
+        self,
+        fn: Callable[["pyarrow.Table"], Union["pyarrow.Table", "pandas.DataFrame", Dict[str, list]]],
+        return_dtypes: Dict[str, DataType],
+    ) -> "DataFrame":
+        """Apply a Python function to the rows of each group, returning any number of rows and columns per group.
+
+        ``fn`` receives the non-key columns of each group as a PyArrow Table, and returns a PyArrow Table, pandas
+        DataFrame or dict of lists with the columns in ``return_dtypes``. The group keys are repeated for every row
+        that ``fn`` returns. Unlike :meth:`map_groups`, which returns a single column, this is the equivalent of
+        pandas' ``groupby(...).apply(...)``.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"group": ["a", "a", "b"], "x": [1, 2, 3], "y": [4, 5, 6]})
+            >>>
+            >>> def top_row(table):
+            ...     top = table.sort_by([("x", "descending")]).slice(0, 1)
+            ...     return {"x": top["x"].to_pylist(), "total": [sum(table["y"].to_pylist())]}
+            >>>
+            >>> df = df.groupby("group").apply(top_row, {"x": daft.DataType.int64(), "total": daft.DataType.int64()})
+            >>> df.sort("group").show()
+            ╭───────┬───────┬───────╮
+            │ group ┆ x     ┆ total │
+            │ ---   ┆ ---   ┆ ---   │
+            │ Utf8  ┆ Int64 ┆ Int64 │
+            ╞═══════╪═══════╪═══════╡
+            │ a     ┆ 2     ┆ 9     │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ b     ┆ 3     ┆ 6     │
+            ╰───────┴───────┴───────╯
+            <BLANKLINE>
+            (Showing first 2 of 2 rows)
+
+        Args:
+            fn (Callable): Function to apply to the PyArrow Table of each group's non-key columns.
+            return_dtypes (Dict[str, DataType]): Names and types of the columns that ``fn`` returns.
+
+        Returns:
+            DataFrame: DataFrame with the group keys and the columns returned by ``fn``.
+        """
+        from daft.dependencies import pa, pd
+        from daft.expressions import struct
+        from daft.recordbatch import RecordBatch
+        from daft.udf import udf
+
+        if not return_dtypes:
+            raise ValueError("GroupedDataFrame.apply() requires at least one column in return_dtypes")
+        key_names = [e.name() for e in self.group_by]
+        overlapping = [name for name in return_dtypes if name in key_names]
+        if overlapping:
+            raise ValueError(f"GroupedDataFrame.apply() cannot return columns named after group keys: {overlapping}")
+        input_names = [name for name in self.df.column_names if name not in key_names]
+        if not input_names:
+            raise ValueError("GroupedDataFrame.apply() requires at least one column that isn't a group key")
+
+        return_schema = Schema._from_field_name_and_types(list(return_dtypes.items()))
+        result_name = input_names[0]
+
+        @udf(return_dtype=DataType.struct(return_dtypes))
+        def apply_to_group(*columns):
+            group = RecordBatch.from_pydict({name: column for name, column in zip(input_names, columns)})
+            # Partitions without any rows are still mapped, but don't have a group to call `fn` on.
+            if len(group) == 0:
+                result = RecordBatch.empty(return_schema)
+            else:
+                result = fn(group.to_arrow())
+                if isinstance(result, RecordBatch):
+                    pass
+                elif isinstance(result, dict):
+                    result = RecordBatch.from_pydict(result)
+                elif pd.module_available() and isinstance(result, pd.DataFrame):
+                    result = RecordBatch.from_pandas(result)
+                elif isinstance(result, pa.Table):
+                    result = RecordBatch.from_arrow(result)
+                else:
+                    raise TypeError(
+                        f"Expected GroupedDataFrame.apply() function to return a PyArrow Table, pandas DataFrame or "
+                        f"dict, got {type(result)}"
+                    )
+                result = result.cast_to_schema(return_schema)
+            projection = ExpressionsProjection([struct(*return_dtypes.keys()).alias(result_name)])
+            return result.eval_expression_list(projection).get_column(result_name)
+
+        grouped = self.df._map_groups(apply_to_group(*[col(name) for name in input_names]), group_by=self.group_by)
+        return grouped.select(
+            *key_names, *[col(result_name).struct.get(name).alias(name) for name in return_dtypes.keys()]
+        )
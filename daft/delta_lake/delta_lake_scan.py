@@ -25,6 +25,9 @@ from daft.logical.schema import Schema
 if TYPE_CHECKING:
     from collections.abc import Iterator
     from datetime import datetime
+    from typing import Any
+
+    import pyarrow as pa
 
 logger = logging.getLogger(__name__)
 
@@ -143,14 +146,6 @@ class DeltaLakeScanOperator(ScanOperator):
                 self.partitioning_keys(),
             )
 
-        # TODO(Clark): Add support for deletion vectors.
-        # Issue: https://github.com/Eventual-Inc/Daft/issues/1954
-        if "deletionVector" in add_actions.schema.names:
-            raise NotImplementedError(
-                "Delta Lake deletion vectors are not yet supported; please let the Daft team know if you'd like to see this feature!\n"
-                "Deletion records can be dropped from this table to allow it to be read with Daft: https://docs.delta.io/latest/delta-drop-feature.html"
-            )
-
         # TODO(Clark): Add support for column mappings.
         # Issue: https://github.com/Eventual-Inc/Daft/issues/1955
 
@@ -177,6 +172,11 @@ class DeltaLakeScanOperator(ScanOperator):
                 size_bytes = add_actions["size_bytes"][task_idx].as_py()
             except KeyError:
                 size_bytes = None
+
+            # Deletion vectors are read and applied to the rows of the file when it is read.
+            deletion_vector = self._deletion_vector(add_actions, task_idx)
+            if deletion_vector is not None and record_count is not None:
+                record_count -= deletion_vector["cardinality"]
             file_format_config = FileFormatConfig.from_parquet_config(ParquetSourceConfig())
 
             if is_partitioned:
@@ -231,6 +231,7 @@ class DeltaLakeScanOperator(ScanOperator):
                 pushdowns=pushdowns,
                 partition_values=partition_values,
                 stats=stats._table if stats is not None else None,
+                deletion_vector=deletion_vector,
             )
             if st is None:
                 continue
@@ -239,6 +240,22 @@ class DeltaLakeScanOperator(ScanOperator):
             scan_tasks.append(st)
         return iter(scan_tasks)
 
+    def _deletion_vector(self, add_actions: pa.RecordBatch, task_idx: int) -> dict[str, Any] | None:
+        """The descriptor of the deletion vector of the file of an add action, if it has one."""
+        if "deletionVector" not in add_actions.schema.names:
+            return None
+        deletion_vector = add_actions["deletionVector"][task_idx].as_py()
+        if deletion_vector is None or deletion_vector.get("storageType") is None:
+            return None
+        return {
+            "table_uri": self._table.table_uri,
+            "storage_type": deletion_vector["storageType"],
+            "path_or_inline_dv": deletion_vector["pathOrInlineDv"],
+            "offset": deletion_vector.get("offset"),
+            "size_in_bytes": deletion_vector["sizeInBytes"],
+            "cardinality": deletion_vector["cardinality"],
+        }
+
     def can_absorb_filter(self) -> bool:
         return False
 
@@ -13,6 +13,7 @@ if TYPE_CHECKING:
     import pyarrow.fs as pafs
     import pyarrow.json as pajson
     import pyarrow.parquet as pq
+    import pyarrow.parquet.encryption as pqe
 else:
     fsspec = LazyImport("fsspec")
     np = LazyImport("numpy")
@@ -24,5 +25,6 @@ else:
     pafs = LazyImport("pyarrow.fs")
     pajson = LazyImport("pyarrow.json")
     pq = LazyImport("pyarrow.parquet")
+    pqe = LazyImport("pyarrow.parquet.encryption")
 
 unity_catalog = LazyImport("daft.unity_catalog")
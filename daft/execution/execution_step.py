@@ -2,7 +2,7 @@ from __future__ import annotations
 
 import itertools
 from dataclasses import dataclass, field
-from typing import TYPE_CHECKING, Generic, Protocol
+from typing import TYPE_CHECKING, Generic, Literal, Protocol
 
 from daft.context import get_context
 from daft.daft import JoinSide, ResourceRequest
@@ -23,6 +23,7 @@ if TYPE_CHECKING:
     from pyiceberg.table import TableProperties as IcebergTableProperties
 
     from daft.daft import FileFormat, IOConfig, JoinType, ScanTask
+    from daft.io.parquet_encryption import ParquetEncryptionConfig
     from daft.logical.map_partition_ops import MapPartitionOp
     from daft.logical.schema import Schema
 
@@ -402,6 +403,10 @@ class WriteFile(SingleOutputInstruction):
     compression: str | None
     partition_cols: ExpressionsProjection | None
     io_config: IOConfig | None
+    job_id: str | None = None
+    bloom_filter_columns: list[str] | None = None
+    row_group_size_bytes: int | None = None
+    encryption: ParquetEncryptionConfig | None = None
 
     def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
         return self._write_file(inputs)
@@ -431,6 +436,10 @@ class WriteFile(SingleOutputInstruction):
             compression=self.compression,
             partition_cols=self.partition_cols,
             io_config=self.io_config,
+            job_id=self.job_id,
+            bloom_filter_columns=self.bloom_filter_columns,
+            row_group_size_bytes=self.row_group_size_bytes,
+            encryption=self.encryption,
         )
 
 
@@ -678,6 +687,57 @@ class GlobalLimit(LocalLimit):
     pass
 
 
+@dataclass(frozen=True)
+class LocalSort(SingleOutputInstruction):
+    sort_by: ExpressionsProjection
+    descending: list[bool]
+    nulls_first: list[bool]
+
+    def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        return self._sort(inputs)
+
+    def _sort(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        [input] = inputs
+        return [input.sort(self.sort_by, descending=self.descending, nulls_first=self.nulls_first)]
+
+    def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
+        [input_meta] = input_metadatas
+        # Sorting doesn't move rows between partitions.
+        return [
+            PartialPartitionMetadata(
+                num_rows=input_meta.num_rows,
+                size_bytes=input_meta.size_bytes,
+                boundaries=input_meta.boundaries,
+            )
+        ]
+
+
+@dataclass(frozen=True)
+class Window(SingleOutputInstruction):
+    window_functions: ExpressionsProjection
+    partition_by: ExpressionsProjection
+    order_by: ExpressionsProjection
+    null_strategy: Literal["smallest", "largest", "skip"]
+
+    def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        return self._window(inputs)
+
+    def _window(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        [input] = inputs
+        return [input.window(self.window_functions, self.partition_by, self.order_by, self.null_strategy)]
+
+    def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
+        [input_meta] = input_metadatas
+        # Window functions add columns without changing the rows, which stay in the same order.
+        return [
+            PartialPartitionMetadata(
+                num_rows=input_meta.num_rows,
+                size_bytes=None,
+                boundaries=input_meta.boundaries,
+            )
+        ]
+
+
 @dataclass(frozen=True)
 class MapPartition(SingleOutputInstruction):
     map_op: MapPartitionOp
@@ -51,5 +51,7 @@ class NativeExecutor:
             return self._executor.repr_ascii(builder._builder, daft_execution_config, simple)
         elif format == "mermaid":
             return self._executor.repr_mermaid(builder._builder, daft_execution_config, MermaidOptions(simple))
+        elif format == "json":
+            return self._executor.repr_json(builder._builder, daft_execution_config, simple)
         else:
             raise ValueError(f"Unknown format: {format}")
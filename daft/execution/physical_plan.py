@@ -60,6 +60,7 @@ if TYPE_CHECKING:
     from pyiceberg.table import TableProperties as IcebergTableProperties
 
     from daft.daft import FileFormat, IOConfig, JoinType
+    from daft.io.parquet_encryption import ParquetEncryptionConfig
     from daft.logical.schema import Schema
 
 
@@ -98,6 +99,10 @@ def file_write(
     compression: str | None,
     partition_cols: ExpressionsProjection | None,
     io_config: IOConfig | None,
+    job_id: str | None = None,
+    bloom_filter_columns: list[str] | None = None,
+    row_group_size_bytes: int | None = None,
+    encryption: ParquetEncryptionConfig | None = None,
 ) -> InProgressPhysicalPlan[PartitionT]:
     """Write the results of `child_plan` into files described by `write_info`."""
     yield from (
@@ -109,6 +114,10 @@ def file_write(
                 compression=compression,
                 partition_cols=partition_cols,
                 io_config=io_config,
+                job_id=job_id,
+                bloom_filter_columns=bloom_filter_columns,
+                row_group_size_bytes=row_group_size_bytes,
+                encryption=encryption,
             ),
         )
         if isinstance(step, PartitionTaskBuilder)
@@ -1586,14 +1595,21 @@ def reduce(
         ).add_instruction(reduce_instructions_.popleft())
 
 
-def sort(
+def _sample_range_boundaries(
     child_plan: InProgressPhysicalPlan[PartitionT],
     sort_by: ExpressionsProjection,
     descending: list[bool],
     nulls_first: list[bool],
     num_partitions: int,
-) -> InProgressPhysicalPlan[PartitionT]:
-    """Sort the result of `child_plan` according to `sort_info`."""
+) -> Generator[
+    Union[None, PartitionTask[PartitionT], PartitionTaskBuilder[PartitionT]],
+    None,
+    tuple[deque[SingleOutputPartitionTask[PartitionT]], SingleOutputPartitionTask[PartitionT]],
+]:
+    """Materializes `child_plan` and samples the boundaries of `num_partitions` ranges of `sort_by` from it.
+
+    Returns the materializations of `child_plan` and the materialized boundaries.
+    """
     # First, materialize the child plan.
     source_materializations: deque[SingleOutputPartitionTask[PartitionT]] = deque()
     stage_id_children = next(stage_id_counter)
@@ -1607,7 +1623,14 @@ def sort(
     sample_materializations: deque[SingleOutputPartitionTask[PartitionT]] = deque()
     stage_id_sampling = next(stage_id_counter)
 
-    sample_size = get_context().daft_execution_config.sample_size_for_sort
+    # Sample enough keys in total for the boundaries of every range to be chosen from several of them.
+    execution_config = get_context().daft_execution_config
+    sample_size = max(
+        execution_config.sample_size_for_sort,
+        math.ceil(
+            execution_config.sort_boundary_oversampling_factor * num_partitions / max(len(source_materializations), 1)
+        ),
+    )
     for source in source_materializations:
         while not source.done():
             logger.debug("sort blocked on completion of source: %s", source)
@@ -1657,8 +1680,18 @@ def sort(
         logger.debug("sort blocked on completion of boundary partition: %s", boundaries)
         yield None
 
-    # Create a range fanout plan.
-    range_fanout_plan = (
+    return source_materializations, boundaries
+
+
+def _fanout_by_range(
+    source_materializations: deque[SingleOutputPartitionTask[PartitionT]],
+    boundaries: SingleOutputPartitionTask[PartitionT],
+    sort_by: ExpressionsProjection,
+    descending: list[bool],
+    num_partitions: int,
+) -> InProgressPhysicalPlan[PartitionT]:
+    """Splits each of the materialized sources into the `num_partitions` ranges between `boundaries`."""
+    return (
         PartitionTaskBuilder[PartitionT](
             inputs=[boundaries.partition(), source.partition()],
             partial_metadatas=[boundaries.partition_metadata(), source.partition_metadata()],
@@ -1674,6 +1707,22 @@ def sort(
         )
         for source in consume_deque(source_materializations)
     )
+
+
+def sort(
+    child_plan: InProgressPhysicalPlan[PartitionT],
+    sort_by: ExpressionsProjection,
+    descending: list[bool],
+    nulls_first: list[bool],
+    num_partitions: int,
+) -> InProgressPhysicalPlan[PartitionT]:
+    """Sort the result of `child_plan` according to `sort_info`."""
+    source_materializations, boundaries = yield from _sample_range_boundaries(
+        child_plan, sort_by, descending, nulls_first, num_partitions
+    )
+
+    # Create a range fanout plan.
+    range_fanout_plan = _fanout_by_range(source_materializations, boundaries, sort_by, descending, num_partitions)
     per_partition_bounds = _to_per_partition_bounds(boundaries.micropartition(), num_partitions)
 
     # Execute a sorting reduce on it.
@@ -1691,6 +1740,26 @@ def sort(
     )
 
 
+def range_repartition(
+    child_plan: InProgressPhysicalPlan[PartitionT],
+    partition_by: ExpressionsProjection,
+    descending: list[bool],
+    num_partitions: int,
+) -> InProgressPhysicalPlan[PartitionT]:
+    """Repartition the result of `child_plan` into `num_partitions` ranges of `partition_by`, without sorting them."""
+    # Nulls go where a sort with the default null ordering would put them.
+    nulls_first = descending
+    source_materializations, boundaries = yield from _sample_range_boundaries(
+        child_plan, partition_by, descending, nulls_first, num_partitions
+    )
+
+    range_fanout_plan = _fanout_by_range(source_materializations, boundaries, partition_by, descending, num_partitions)
+    yield from reduce(
+        fanout_plan=range_fanout_plan,
+        reduce_instructions=execution_step.ReduceMerge(),
+    )
+
+
 def fanout_random(child_plan: InProgressPhysicalPlan[PartitionT], num_partitions: int):
     """Splits the results of `child_plan` randomly into a list of `node.num_partitions()` number of partitions."""
     seed = 0
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Literal
 
 from daft.context import get_context
 from daft.daft import (
@@ -22,6 +22,7 @@ if TYPE_CHECKING:
     from pyiceberg.schema import Schema as IcebergSchema
     from pyiceberg.table import TableProperties as IcebergTableProperties
 
+    from daft.io.parquet_encryption import ParquetEncryptionConfig
     from daft.recordbatch import MicroPartition
 
 
@@ -215,6 +216,41 @@ def sort(
     )
 
 
+def sort_within_partitions(
+    input: physical_plan.InProgressPhysicalPlan[PartitionT],
+    sort_by: list[PyExpr],
+    descending: list[bool],
+    nulls_first: list[bool],
+) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
+    expr_projection = ExpressionsProjection([Expression._from_pyexpr(expr) for expr in sort_by])
+    return physical_plan.pipeline_instruction(
+        child_plan=input,
+        pipeable_instruction=execution_step.LocalSort(
+            sort_by=expr_projection, descending=descending, nulls_first=nulls_first
+        ),
+        resource_request=ResourceRequest(),
+    )
+
+
+def window(
+    input: physical_plan.InProgressPhysicalPlan[PartitionT],
+    window_functions: list[PyExpr],
+    partition_by: list[PyExpr],
+    order_by: list[PyExpr],
+    null_strategy: Literal["smallest", "largest", "skip"],
+) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
+    return physical_plan.pipeline_instruction(
+        child_plan=input,
+        pipeable_instruction=execution_step.Window(
+            window_functions=ExpressionsProjection([Expression._from_pyexpr(expr) for expr in window_functions]),
+            partition_by=ExpressionsProjection([Expression._from_pyexpr(expr) for expr in partition_by]),
+            order_by=ExpressionsProjection([Expression._from_pyexpr(expr) for expr in order_by]),
+            null_strategy=null_strategy,
+        ),
+        resource_request=ResourceRequest(),
+    )
+
+
 def fanout_by_hash(
     input: physical_plan.InProgressPhysicalPlan[PartitionT],
     num_partitions: int,
@@ -232,6 +268,21 @@ def fanout_by_hash(
     )
 
 
+def range_repartition(
+    input: physical_plan.InProgressPhysicalPlan[PartitionT],
+    num_partitions: int,
+    partition_by: list[PyExpr],
+    descending: list[bool],
+) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
+    expr_projection = ExpressionsProjection([Expression._from_pyexpr(expr) for expr in partition_by])
+    return physical_plan.range_repartition(
+        child_plan=input,
+        partition_by=expr_projection,
+        descending=descending,
+        num_partitions=num_partitions,
+    )
+
+
 def reduce_merge(
     input: physical_plan.InProgressPhysicalPlan[PartitionT],
 ) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
@@ -331,6 +382,10 @@ def write_file(
     compression: str | None,
     partition_cols: list[PyExpr] | None,
     io_config: IOConfig | None,
+    job_id: str | None = None,
+    bloom_filter_columns: list[str] | None = None,
+    row_group_size_bytes: int | None = None,
+    encryption: ParquetEncryptionConfig | None = None,
 ) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
     if partition_cols is not None:
         expr_projection = ExpressionsProjection([Expression._from_pyexpr(expr) for expr in partition_cols])
@@ -344,6 +399,10 @@ def write_file(
         compression,
         expr_projection,
         io_config,
+        job_id,
+        bloom_filter_columns,
+        row_group_size_bytes,
+        encryption,
     )
 
 
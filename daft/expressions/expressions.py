@@ -54,6 +54,7 @@ from daft.series import Series, item_to_series
 if TYPE_CHECKING:
     from daft.io import IOConfig
     from daft.udf import BoundUDFArgs, InitArgsType, UninitializedUdf
+    from daft.window import Window
 # This allows Sphinx to correctly work against our "namespaced" accessor functions by overriding @property to
 # return a class instance of the namespace instead of a property object.
 elif os.getenv("DAFT_SPHINX_BUILD") == "1":
@@ -734,6 +735,52 @@ class Expression:
         max_expr = Expression._to_expression(max)
         return Expression._from_pyexpr(native.clip(self._expr, min_expr._expr, max_expr._expr))
 
+    def width_bucket(self, low: Expression | float, high: Expression | float, num_buckets: int) -> Expression:
+        """Assigns each value to one of ``num_buckets`` equal-width buckets between ``low`` and ``high``.
+
+        Buckets are numbered from 1. Values below ``low`` are put in bucket 0 and values at or above ``high`` in bucket
+        ``num_buckets + 1``, like SQL's ``width_bucket``. If ``low`` is greater than ``high``, the buckets count down
+        from ``low`` instead.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": [-1.0, 0.0, 4.9, 5.0, 9.99, 10.0, None]})
+            >>> df.select(df["x"].width_bucket(0, 10, 2)).to_pydict()
+            {'x': [0, 1, 1, 2, 2, 3, None]}
+
+        Args:
+            low: The lower bound of the first bucket.
+            high: The upper bound of the last bucket.
+            num_buckets: The number of buckets between the bounds, which must be positive.
+
+        Returns:
+            Expression: an Int64 expression with the bucket of each value
+        """
+        low_expr = Expression._to_expression(low)
+        high_expr = Expression._to_expression(high)
+        return Expression._from_pyexpr(native.width_bucket(self._expr, low_expr._expr, high_expr._expr, num_buckets))
+
+    def quantile_bucket(self, quantiles: builtins.list[builtins.float]) -> Expression:
+        """Assigns each value to the bucket between consecutive ``quantiles``, numbered from 1.
+
+        With the ``n - 1`` quantiles that split the values into ``n`` parts, this puts them in ``n`` buckets of roughly
+        equal size. Values equal to a quantile go in the bucket above it. See :meth:`DataFrame.with_quantile_bucket` to
+        compute the quantiles of a column and bucket it in one step.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": [1, 5, 10, 20, None]})
+            >>> df.select(df["x"].quantile_bucket([5.0, 15.0])).to_pydict()
+            {'x': [1, 2, 2, 3, None]}
+
+        Args:
+            quantiles: The boundaries between the buckets.
+
+        Returns:
+            Expression: an Int64 expression with the bucket of each value
+        """
+        return Expression._from_pyexpr(native.quantile_bucket(self._expr, [float(q) for q in quantiles]))
+
     def sign(self) -> Expression:
         """The sign of a numeric expression."""
         expr = native.sign(self._expr)
@@ -758,6 +805,81 @@ class Expression:
         """The cube root of a numeric expression."""
         return Expression._from_pyexpr(native.cbrt(self._expr))
 
+    def int_to_ip(self) -> Expression:
+        """Formats each integer as an IPv4 address, such as "192.168.0.1" for 3232235521.
+
+        Integers that are negative or greater than 2^32 - 1 are converted to null. This is the inverse of
+        :meth:`Expression.str.ip_to_int`.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": [3232235521, 167772161, -1]})
+            >>> df = df.select(df["x"].int_to_ip())
+            >>> df.show()
+            ╭─────────────╮
+            │ x           │
+            │ ---         │
+            │ Utf8        │
+            ╞═════════════╡
+            │ 192.168.0.1 │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 10.0.0.1    │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ None        │
+            ╰─────────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Returns:
+            Expression: a String expression with the IPv4 address of each integer
+        """
+        return Expression._from_pyexpr(native.int_to_ip(self._expr))
+
+    def format_decimal(
+        self,
+        decimal_separator: str = ".",
+        thousands_separator: str | None = ",",
+        currency_symbol: str | None = None,
+        accounting: bool = False,
+    ) -> Expression:
+        """Formats each decimal or integer as a string with grouped digits, such as "$1,234.50" for 1234.50.
+
+        Decimals are written with all of the digits of their scale. This is the inverse of
+        :meth:`Expression.str.parse_decimal`.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": ["1234.5", "-99.99", "1000000"]})
+            >>> df = df.select(df["x"].str.parse_decimal(12, 2).format_decimal(currency_symbol="$", accounting=True))
+            >>> df.show()
+            ╭───────────────╮
+            │ x             │
+            │ ---           │
+            │ Utf8          │
+            ╞═══════════════╡
+            │ $1,234.50     │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ ($99.99)      │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ $1,000,000.00 │
+            ╰───────────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Args:
+            decimal_separator: The character between the integer and fractional parts of the numbers.
+            thousands_separator: The character between groups of 3 digits of the integer part, or None to not group
+                digits.
+            currency_symbol: A symbol to write before each number, such as "$".
+            accounting: Whether to wrap negative numbers in parentheses rather than prefix them with a minus sign.
+
+        Returns:
+            Expression: a String expression with each formatted number
+        """
+        return Expression._from_pyexpr(
+            native.format_decimal(self._expr, decimal_separator, thousands_separator, currency_symbol, accounting)
+        )
+
     def sin(self) -> Expression:
         """The elementwise sine of a numeric expression."""
         expr = native.sin(self._expr)
@@ -1363,6 +1485,11 @@ class Expression:
     def is_in(self, other: Any) -> Expression:
         """Checks if values in the Expression are in the provided list.
 
+        ``other`` can also be a DataFrame with a single column, in which case filtering on the result is planned as a
+        semi join with it, or an anti join when the result is negated, without collecting the DataFrame. Such
+        expressions can only be used in filters. As with a list, the result is null for null values, so their rows are
+        filtered out whether or not the result is negated.
+
         Example:
             >>> import daft
             >>> df = daft.from_pydict({"data": [1, 2, 3]})
@@ -1381,11 +1508,33 @@ class Expression:
             ╰─────────╯
             <BLANKLINE>
             (Showing first 3 of 3 rows)
+            >>>
+            >>> df = daft.from_pydict({"id": [1, 2, 3, 4]})
+            >>> other = daft.from_pydict({"other_id": [2, 4, 6]})
+            >>> df.where(df["id"].is_in(other)).sort("id").show()
+            ╭───────╮
+            │ id    │
+            │ ---   │
+            │ Int64 │
+            ╞═══════╡
+            │ 2     │
+            ├╌╌╌╌╌╌╌┤
+            │ 4     │
+            ╰───────╯
+            <BLANKLINE>
+            (Showing first 2 of 2 rows)
+
+        Args:
+            other (Any): A list of values, a Series, or a DataFrame with a single column
 
         Returns:
             Expression: Boolean Expression indicating whether values are in the provided list
         """
-        if isinstance(other, Collection):
+        from daft.dataframe import DataFrame
+
+        if isinstance(other, DataFrame):
+            return other._builder.in_subquery(self)
+        elif isinstance(other, Collection):
             other = [Expression._to_expression(item) for item in other]
         elif not isinstance(other, Expression):
             series = item_to_series("items", other)
@@ -1396,6 +1545,60 @@ class Expression:
         expr = self._expr.is_in([item._expr for item in other])
         return Expression._from_pyexpr(expr)
 
+    def one_hot(self, categories: builtins.list[Any]) -> Expression:
+        """One-hot encodes each value as a list with a 1 at the index of its category and 0s elsewhere.
+
+        Values that aren't one of the categories are encoded as all 0s, and nulls are left as nulls.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"color": ["red", "blue", "green", None]})
+            >>> df.select(df["color"].one_hot(["red", "blue"])).to_pydict()
+            {'color': [[1, 0], [0, 1], [0, 0], None]}
+
+        Args:
+            categories: The values to encode, in the order of the list elements.
+
+        Returns:
+            Expression: a FixedSizeList[UInt8] expression with one element per category
+        """
+        if len(categories) == 0:
+            raise ValueError("Expected at least one category to one-hot encode")
+        categories_expr = Expression._to_expression(item_to_series("categories", list(categories)))
+        return Expression._from_pyexpr(native.one_hot(self._expr, categories_expr._expr))
+
+    def label_encode(self, mapping: builtins.list[Any] | builtins.dict[Any, Any]) -> Expression:
+        """Label encodes each value as a number for its category, or null if it isn't one of the categories.
+
+        With a list of categories, each value is labeled with the index of its category. With a dict, each value is
+        labeled with the value its category maps to. See :meth:`DataFrame.with_label_encoding` to label all of the
+        distinct values of a column.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"size": ["small", "large", "medium", "huge"]})
+            >>> df.select(df["size"].label_encode(["small", "medium", "large"])).to_pydict()
+            {'size': [0, 2, 1, None]}
+            >>> df.select(df["size"].label_encode({"small": 1, "medium": 2, "large": 3})).to_pydict()
+            {'size': [1, 3, 2, None]}
+
+        Args:
+            mapping: The list of categories, or a dict from each category to its label.
+
+        Returns:
+            Expression: a UInt64 expression with the index of each category for a list, or an expression with the type
+                of the labels for a dict
+        """
+        if isinstance(mapping, builtins.dict):
+            categories = item_to_series("categories", list(mapping.keys()))
+            labels = item_to_series("labels", list(mapping.values()))
+            labels_expr = Expression._to_expression(labels)._expr
+        else:
+            categories = item_to_series("categories", list(mapping))
+            labels_expr = None
+        categories_expr = Expression._to_expression(categories)
+        return Expression._from_pyexpr(native.label_encode(self._expr, categories_expr._expr, labels_expr))
+
     def between(self, lower: Any, upper: Any) -> Expression:
         """Checks if values in the Expression are between lower and upper, inclusive.
 
@@ -1429,6 +1632,102 @@ class Expression:
         expr = self._expr.between(lower._expr, upper._expr)
         return Expression._from_pyexpr(expr)
 
+    def lag(self, offset: int = 1, default: Any | None = None) -> Expression:
+        """The value of the Expression `offset` rows before the current row in its window partition.
+
+        This is a window function, so it has to be evaluated over a window with :meth:`over`.
+
+        Example:
+            >>> import daft
+            >>> from daft import Window
+            >>> df = daft.from_pydict({"day": [1, 2, 3], "price": [10, 12, 11]})
+            >>> df = df.with_column("prev_price", df["price"].lag(1).over(Window().order_by("day")))
+            >>> df.sort("day").show()
+            ╭───────┬───────┬────────────╮
+            │ day   ┆ price ┆ prev_price │
+            │ ---   ┆ ---   ┆ ---        │
+            │ Int64 ┆ Int64 ┆ Int64      │
+            ╞═══════╪═══════╪════════════╡
+            │ 1     ┆ 10    ┆ None       │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 2     ┆ 12    ┆ 10         │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 3     ┆ 11    ┆ 12         │
+            ╰───────┴───────┴────────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Args:
+            offset: The number of rows before the current row, defaults to 1.
+            default: The value for rows without a row `offset` rows before them in their partition, null if not given.
+
+        Returns:
+            Expression: A window function of the values of the Expression at the previous rows
+        """
+        if offset < 0:
+            raise ValueError(f"Expected a non-negative offset for lag, but received {offset}")
+        default_expr = Expression._to_expression(default)._expr if default is not None else None
+        return Expression._from_pyexpr(self._expr.lag(offset, default_expr))
+
+    def lead(self, offset: int = 1, default: Any | None = None) -> Expression:
+        """The value of the Expression `offset` rows after the current row in its window partition.
+
+        This is a window function, so it has to be evaluated over a window with :meth:`over`.
+
+        Example:
+            >>> import daft
+            >>> from daft import Window
+            >>> df = daft.from_pydict({"day": [1, 2, 3], "price": [10, 12, 11]})
+            >>> df = df.with_column("next_price", df["price"].lead(1, default=0).over(Window().order_by("day")))
+            >>> df.sort("day").show()
+            ╭───────┬───────┬────────────╮
+            │ day   ┆ price ┆ next_price │
+            │ ---   ┆ ---   ┆ ---        │
+            │ Int64 ┆ Int64 ┆ Int64      │
+            ╞═══════╪═══════╪════════════╡
+            │ 1     ┆ 10    ┆ 12         │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 2     ┆ 12    ┆ 11         │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 3     ┆ 11    ┆ 0          │
+            ╰───────┴───────┴────────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Args:
+            offset: The number of rows after the current row, defaults to 1.
+            default: The value for rows without a row `offset` rows after them in their partition, null if not given.
+
+        Returns:
+            Expression: A window function of the values of the Expression at the following rows
+        """
+        if offset < 0:
+            raise ValueError(f"Expected a non-negative offset for lead, but received {offset}")
+        default_expr = Expression._to_expression(default)._expr if default is not None else None
+        return Expression._from_pyexpr(self._expr.lead(offset, default_expr))
+
+    def over(self, window: Window) -> Expression:
+        """Evaluates a window function, such as :func:`~daft.functions.row_number` or :meth:`lag`, over `window`.
+
+        Window functions can only be used in projections, such as :meth:`DataFrame.select` and
+        :meth:`DataFrame.with_column`.
+
+        Args:
+            window: How the rows are partitioned and ordered for the window function.
+
+        Returns:
+            Expression: The window function evaluated over `window`
+        """
+        return Expression._from_pyexpr(
+            self._expr.over(
+                [e._expr for e in window._partition_by],
+                [e._expr for e in window._order_by],
+                window._descending,
+                window._null_strategy,
+                window._frame,
+            )
+        )
+
     def hash(self, seed: Any | None = None) -> Expression:
         """Hashes the values in the Expression.
 
@@ -1460,10 +1759,13 @@ class Expression:
         For a string, calculates the minimum hash over all its ngrams,
         repeating with `num_hashes` permutations. Returns as a list of 32-bit unsigned integers.
 
-        Tokens for the ngrams are delimited by spaces.
+        Tokens for the ngrams are delimited by spaces. The input may also be a list of strings that have
+        already been tokenized, in which case each string is a token and null tokens are skipped.
         The strings are not normalized or pre-processed, so it is recommended
         to normalize the strings yourself.
 
+        Use :meth:`minhash_similarity` to compare the resulting signatures.
+
         Args:
             num_hashes: The number of hash permutations to compute.
             ngram_size: The number of tokens in each shingle/ngram.
@@ -1479,6 +1781,63 @@ class Expression:
 
         return Expression._from_pyexpr(native.minhash(self._expr, num_hashes, ngram_size, seed, hash_function))
 
+    def minhash_similarity(self, other: Expression) -> Expression:
+        """Estimates the Jaccard similarity of the inputs two MinHash signatures were computed from.
+
+        The estimate is the fraction of positions at which the signatures are equal, so both must be computed
+        with the same ``num_hashes``, ``seed`` and ``hash_function``.
+
+        Args:
+            other: The signatures to compare against.
+
+        Returns:
+            Expression: A Float64 expression between 0 and 1, or null if either signature is null.
+        """
+        other = Expression._to_expression(other)
+        return Expression._from_pyexpr(native.minhash_similarity(self._expr, other._expr))
+
+    def simhash(
+        self,
+        ngram_size: int,
+        seed: int = 1,
+        hash_function: Literal["murmurhash3", "xxhash", "sha1"] = "murmurhash3",
+    ) -> Expression:
+        """Runs the SimHash algorithm on the series.
+
+        For a string, combines the hashes of all its ngrams into a single 64-bit fingerprint, in which each bit is
+        set if most of the ngram hashes have it set. Similar strings have fingerprints that differ in few bits,
+        which can be counted with :meth:`simhash_distance`.
+
+        Tokens for the ngrams are delimited by spaces.
+
+        Args:
+            ngram_size: The number of tokens in each shingle/ngram.
+            seed (optional): Seed used for the ngram hashes. Defaults to 1.
+            hash_function (optional): Hash function to use for the ngram hashes.
+                One of "murmurhash3", "xxhash", or "sha1". Defaults to "murmurhash3".
+
+        Returns:
+            Expression: A UInt64 expression.
+        """
+        assert isinstance(ngram_size, int)
+        assert isinstance(seed, int)
+        assert isinstance(hash_function, str)
+        assert hash_function in ["murmurhash3", "xxhash", "sha1"], f"Hash function {hash_function} not found"
+
+        return Expression._from_pyexpr(native.simhash(self._expr, ngram_size, seed, hash_function))
+
+    def simhash_distance(self, other: Expression) -> Expression:
+        """Counts the bits that differ between two SimHash fingerprints.
+
+        Args:
+            other: The fingerprints to compare against.
+
+        Returns:
+            Expression: A UInt32 expression between 0 and 64, or null if either fingerprint is null.
+        """
+        other = Expression._to_expression(other)
+        return Expression._from_pyexpr(native.simhash_distance(self._expr, other._expr))
+
     def encode(self, codec: Literal["deflate", "gzip", "zlib"]) -> Expression:
         r"""Encodes the expression (binary strings) using the specified codec.
 
@@ -3160,6 +3519,7 @@ class ExpressionStringNamespace(ExpressionNamespace):
         lowercase: bool = False,
         nfd_unicode: bool = False,
         white_space: bool = False,
+        form: Literal["NFC", "NFD", "NFKC", "NFKD"] | None = None,
     ):
         r"""Normalizes a string for more useful deduplication.
 
@@ -3193,12 +3553,316 @@ class ExpressionStringNamespace(ExpressionNamespace):
             lowercase: Whether to convert the string to lowercase.
             nfd_unicode: Whether to normalize and decompose Unicode characters according to NFD.
             white_space: Whether to normalize whitespace, replacing newlines etc with spaces and removing double spaces.
+            form: The Unicode normalization form to convert the string to after the other options, one of "NFC", "NFD",
+                "NFKC" or "NFKD". The compatibility forms also replace characters such as ligatures and full-width
+                letters with their plain equivalents.
 
         Returns:
             Expression: a String expression which is normalized.
         """
         return Expression._from_pyexpr(
-            native.utf8_normalize(self._expr, remove_punct, lowercase, nfd_unicode, white_space)
+            native.utf8_normalize(self._expr, remove_punct, lowercase, nfd_unicode, white_space, form)
+        )
+
+    def remove_accents(self) -> Expression:
+        """Removes the accents and other combining marks of the characters of a string.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": ["café", "naïve", "Ångström"]})
+            >>> df = df.select(df["x"].str.remove_accents())
+            >>> df.show()
+            ╭──────────╮
+            │ x        │
+            │ ---      │
+            │ Utf8     │
+            ╞══════════╡
+            │ cafe     │
+            ├╌╌╌╌╌╌╌╌╌╌┤
+            │ naive    │
+            ├╌╌╌╌╌╌╌╌╌╌┤
+            │ Angstrom │
+            ╰──────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Returns:
+            Expression: a String expression which is `self` without combining marks
+        """
+        return Expression._from_pyexpr(native.utf8_remove_accents(self._expr))
+
+    def ascii_fold(self) -> Expression:
+        """Replaces the characters of a string with their closest ASCII equivalents, for matching multilingual text.
+
+        Accents are removed, compatibility characters such as ligatures are decomposed, and common letters and
+        punctuation without decompositions are transliterated, such as "ß" to "ss". Characters without an ASCII
+        equivalent are kept as they are.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": ["Straße", "Œuvre", "ﬁancé"]})
+            >>> df = df.select(df["x"].str.ascii_fold())
+            >>> df.show()
+            ╭─────────╮
+            │ x       │
+            │ ---     │
+            │ Utf8    │
+            ╞═════════╡
+            │ Strasse │
+            ├╌╌╌╌╌╌╌╌╌┤
+            │ OEuvre  │
+            ├╌╌╌╌╌╌╌╌╌┤
+            │ fiance  │
+            ╰─────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Returns:
+            Expression: a String expression which is `self` folded to ASCII
+        """
+        return Expression._from_pyexpr(native.utf8_ascii_fold(self._expr))
+
+    def is_email(self) -> Expression:
+        """Checks whether each string is an email address, such as "user@example.com".
+
+        This checks the common form of email addresses rather than every form that RFC 5322 allows: the local part is
+        made of letters, digits, dots and a few symbols such as "+" and "_", and the domain has to be a domain name with
+        a top-level domain, so addresses such as "user@localhost" are rejected.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": ["user@example.com", "user@localhost", None]})
+            >>> df = df.select(df["x"].str.is_email())
+            >>> df.show()
+            ╭─────────╮
+            │ x       │
+            │ ---     │
+            │ Boolean │
+            ╞═════════╡
+            │ true    │
+            ├╌╌╌╌╌╌╌╌╌┤
+            │ false   │
+            ├╌╌╌╌╌╌╌╌╌┤
+            │ None    │
+            ╰─────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Returns:
+            Expression: a Boolean expression indicating whether each string is an email address
+        """
+        return Expression._from_pyexpr(native.utf8_is_email(self._expr))
+
+    def is_ipv4(self) -> Expression:
+        """Checks whether each string is an IPv4 address in dotted decimal notation, such as "192.168.0.1".
+
+        Octets with leading zeros, such as in "192.168.00.1", are rejected since they are ambiguous.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": ["192.168.0.1", "256.0.0.1", "::1"]})
+            >>> df = df.select(df["x"].str.is_ipv4())
+            >>> df.show()
+            ╭─────────╮
+            │ x       │
+            │ ---     │
+            │ Boolean │
+            ╞═════════╡
+            │ true    │
+            ├╌╌╌╌╌╌╌╌╌┤
+            │ false   │
+            ├╌╌╌╌╌╌╌╌╌┤
+            │ false   │
+            ╰─────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Returns:
+            Expression: a Boolean expression indicating whether each string is an IPv4 address
+        """
+        return Expression._from_pyexpr(native.utf8_is_ipv4(self._expr))
+
+    def is_ipv6(self) -> Expression:
+        """Checks whether each string is an IPv6 address, such as "2001:db8::1".
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": ["2001:db8::1", "::ffff:192.168.0.1", "192.168.0.1"]})
+            >>> df = df.select(df["x"].str.is_ipv6())
+            >>> df.show()
+            ╭─────────╮
+            │ x       │
+            │ ---     │
+            │ Boolean │
+            ╞═════════╡
+            │ true    │
+            ├╌╌╌╌╌╌╌╌╌┤
+            │ true    │
+            ├╌╌╌╌╌╌╌╌╌┤
+            │ false   │
+            ╰─────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Returns:
+            Expression: a Boolean expression indicating whether each string is an IPv6 address
+        """
+        return Expression._from_pyexpr(native.utf8_is_ipv6(self._expr))
+
+    def is_phone_number(self) -> Expression:
+        """Checks whether each string is a phone number, such as "+1 (555) 010-0199".
+
+        Phone numbers have 7 to 15 digits and an optional leading "+" as in E.164, and their digits may be separated by
+        spaces, hyphens, dots and parentheses. Whether the number exists in its country's numbering plan isn't checked.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": ["+1 (555) 010-0199", "555.010.0199", "12345"]})
+            >>> df = df.select(df["x"].str.is_phone_number())
+            >>> df.show()
+            ╭─────────╮
+            │ x       │
+            │ ---     │
+            │ Boolean │
+            ╞═════════╡
+            │ true    │
+            ├╌╌╌╌╌╌╌╌╌┤
+            │ true    │
+            ├╌╌╌╌╌╌╌╌╌┤
+            │ false   │
+            ╰─────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Returns:
+            Expression: a Boolean expression indicating whether each string is a phone number
+        """
+        return Expression._from_pyexpr(native.utf8_is_phone_number(self._expr))
+
+    def ip_to_int(self) -> Expression:
+        """Converts each IPv4 address to its integer value, such as 3232235521 for "192.168.0.1".
+
+        Strings that aren't IPv4 addresses are converted to null. :meth:`Expression.int_to_ip` converts integers back to
+        addresses.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": ["192.168.0.1", "10.0.0.1", "not an ip"]})
+            >>> df = df.select(df["x"].str.ip_to_int())
+            >>> df.show()
+            ╭────────────╮
+            │ x          │
+            │ ---        │
+            │ UInt32     │
+            ╞════════════╡
+            │ 3232235521 │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 167772161  │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ None       │
+            ╰────────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Returns:
+            Expression: a UInt32 expression with the value of each IPv4 address
+        """
+        return Expression._from_pyexpr(native.utf8_ip_to_int(self._expr))
+
+    def extract_domain(self) -> Expression:
+        """Extracts the host of each URL or the domain of each email address.
+
+        Domains are lowercased, and ports, user info and trailing dots are removed. Strings without a valid host are
+        converted to null, as are strings without a scheme that aren't domain names or email addresses, such as
+        "localhost".
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": ["https://www.Example.com/path", "user@mail.example.org", "not a domain"]})
+            >>> df = df.select(df["x"].str.extract_domain())
+            >>> df.show()
+            ╭──────────────────╮
+            │ x                │
+            │ ---              │
+            │ Utf8             │
+            ╞══════════════════╡
+            │ www.example.com  │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ mail.example.org │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ None             │
+            ╰──────────────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Returns:
+            Expression: a String expression with the domain of each string
+        """
+        return Expression._from_pyexpr(native.utf8_extract_domain(self._expr))
+
+    def parse_decimal(
+        self,
+        precision: int,
+        scale: int,
+        decimal_separator: str = ".",
+        thousands_separator: str | None = ",",
+    ) -> Expression:
+        """Parses formatted numbers, such as currency amounts, into decimals with the given precision and scale.
+
+        Numbers may have thousands separators, a currency symbol or code such as "$" or "EUR", and a leading or trailing
+        sign. Numbers in parentheses, as is common in accounting, are negative. Digits beyond the scale are rounded half
+        away from zero, and strings that aren't numbers or don't fit in the precision are converted to null.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": ["$1,234.50", "(99.99)", "-€7", "n/a"]})
+            >>> df = df.select(df["x"].str.parse_decimal(10, 2))
+            >>> df.show()
+            ╭────────────────────────────────╮
+            │ x                              │
+            │ ---                            │
+            │ Decimal(precision=10, scale=2) │
+            ╞════════════════════════════════╡
+            │ 1234.50                        │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ -99.99                         │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ -7.00                          │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ None                           │
+            ╰────────────────────────────────╯
+            <BLANKLINE>
+            (Showing first 4 of 4 rows)
+
+            European formats can be parsed by swapping the separators.
+
+            >>> df = daft.from_pydict({"x": ["1.234,50 €", "-1.234,5"]})
+            >>> df = df.select(df["x"].str.parse_decimal(10, 2, decimal_separator=",", thousands_separator="."))
+            >>> df.show()
+            ╭────────────────────────────────╮
+            │ x                              │
+            │ ---                            │
+            │ Decimal(precision=10, scale=2) │
+            ╞════════════════════════════════╡
+            │ 1234.50                        │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ -1234.50                       │
+            ╰────────────────────────────────╯
+            <BLANKLINE>
+            (Showing first 2 of 2 rows)
+
+        Args:
+            precision: The total number of digits of the decimals, at most 38.
+            scale: The number of digits after the decimal point, at most the precision.
+            decimal_separator: The character between the integer and fractional parts of the numbers.
+            thousands_separator: The character between groups of digits of the integer part, or None if digits aren't
+                grouped. A space also matches non-breaking spaces.
+
+        Returns:
+            Expression: a Decimal expression with the value of each string
+        """
+        return Expression._from_pyexpr(
+            native.utf8_parse_decimal(self._expr, precision, scale, decimal_separator, thousands_separator)
         )
 
     def tokenize_encode(
@@ -3603,6 +4267,40 @@ class ExpressionListNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(_list_distinct(self._expr))
 
+    def rle_encode(self) -> Expression:
+        """Run-length encodes each list into structs of the value of each run of equal elements and its length.
+
+        Consecutive nulls form a run with a null value. Use ``.agg_list()`` first to encode a whole column.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"a": [[1, 1, 2, 2, 2, 1], [None, None, 3], []]})
+            >>> df = df.select(df["a"].list.rle_encode())
+            >>> df.to_pydict()["a"][0]
+            [{'value': 1, 'run_length': 2}, {'value': 2, 'run_length': 3}, {'value': 1, 'run_length': 1}]
+
+        Returns:
+            Expression: A List[Struct[value, run_length]] expression, where the run lengths are UInt64.
+        """
+        return Expression._from_pyexpr(native.list_rle_encode(self._expr))
+
+    def delta_encode(self) -> Expression:
+        """Delta encodes each list of numbers as its first element followed by the differences between elements.
+
+        Integers are widened to Int64, so decreasing unsigned values have negative deltas.
+        Differences involving a null element are null.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"a": [[10, 12, 11, 11], [5], []]})
+            >>> df.select(df["a"].list.delta_encode()).to_pydict()
+            {'a': [[10, 2, -1, 0], [5], []]}
+
+        Returns:
+            Expression: A List[Int64] expression for lists of integers, or a list of the same floats otherwise.
+        """
+        return Expression._from_pyexpr(native.list_delta_encode(self._expr))
+
 
 class ExpressionStructNamespace(ExpressionNamespace):
     def get(self, name: str) -> Expression:
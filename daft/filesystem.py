@@ -1,17 +1,20 @@
 from __future__ import annotations
 
 import dataclasses
+import json
 import logging
 import os
 import pathlib
+import stat
 import sys
 import urllib.parse
+import uuid
 from datetime import datetime, timezone
 from typing import TYPE_CHECKING, Any
 
 from daft.convert import from_pydict
 from daft.daft import FileFormat, FileInfos, IOConfig, io_glob
-from daft.dependencies import fsspec, pafs
+from daft.dependencies import fsspec, pafs, pq
 from daft.expressions.expressions import col, lit
 from daft.recordbatch import MicroPartition
 
@@ -356,6 +359,24 @@ def glob_path_with_stats(
 ###
 
 
+# As is customary for Unix tools, this path stands for standard input when reading and standard output when writing.
+STDIO_PATH = "-"
+
+
+def is_stream_path(path: str | pathlib.Path) -> bool:
+    """Whether `path` is standard input or output, or a local named pipe or other stream rather than a regular file."""
+    path = str(path)
+    if path == STDIO_PATH:
+        return True
+    if "://" in path and not path.startswith("file://"):
+        return False
+    try:
+        mode = os.stat(path.removeprefix("file://")).st_mode
+    except OSError:
+        return False
+    return stat.S_ISFIFO(mode) or stat.S_ISCHR(mode)
+
+
 def join_path(fs: pafs.FileSystem, base_path: str, *sub_paths: str) -> str:
     """Join a base path with sub-paths using the appropriate path separator for the given filesystem."""
     if isinstance(fs, pafs.LocalFileSystem):
@@ -408,3 +429,152 @@ def overwrite_files(
     # TODO: Look into parallelizing this
     for entry in to_delete:
         fs.delete_file(entry["path"])
+
+
+# Write jobs leave a `_started_<job_id>` marker in the root directory while they run, and replace it
+# with a `_committed_<job_id>` manifest of the files they added once they succeed. Every file written
+# by a job is prefixed with its job ID, so the output of an interrupted job can be found and removed.
+# Both marker names are skipped when globbing, like the equivalent Spark/Databricks markers.
+_STARTED_MARKER_PREFIX = "_started_"
+_COMMITTED_MARKER_PREFIX = "_committed_"
+
+
+def start_write_job(root_dir: str | pathlib.Path, io_config: IOConfig | None) -> str:
+    """Writes the `_started_<job_id>` marker for a new write job into `root_dir` and returns the job ID."""
+    job_id = uuid.uuid4().hex
+    [resolved_path], fs = _resolve_paths_and_filesystem(root_dir, io_config=io_config)
+    if canonicalize_protocol(get_protocol_from_path(str(root_dir))) == "file":
+        fs.create_dir(resolved_path, recursive=True)
+    with fs.open_output_stream(join_path(fs, resolved_path, f"{_STARTED_MARKER_PREFIX}{job_id}")):
+        pass
+    return job_id
+
+
+def commit_write_job(
+    root_dir: str | pathlib.Path, job_id: str, written_file_paths: list[str], io_config: IOConfig | None
+) -> None:
+    """Records a successful write job by replacing its `_started_<job_id>` marker with a `_committed_<job_id>` manifest."""
+    [resolved_path], fs = _resolve_paths_and_filesystem(root_dir, io_config=io_config)
+    manifest = json.dumps({"job_id": job_id, "added": written_file_paths})
+    with fs.open_output_stream(join_path(fs, resolved_path, f"{_COMMITTED_MARKER_PREFIX}{job_id}")) as f:
+        f.write(manifest.encode("utf-8"))
+    try:
+        fs.delete_file(join_path(fs, resolved_path, f"{_STARTED_MARKER_PREFIX}{job_id}"))
+    except FileNotFoundError:
+        # An `overwrite` write already removed the marker along with the previous contents of the directory.
+        pass
+    # The statistics persisted by `daft.analyze_parquet` and the summary of the Parquet footers no longer describe the
+    # dataset.
+    for file_name in (_STATISTICS_FILE_NAME, _PARQUET_SUMMARY_FILE_NAME):
+        try:
+            fs.delete_file(join_path(fs, resolved_path, file_name))
+        except FileNotFoundError:
+            pass
+
+
+# `daft.analyze_parquet` persists the statistics of a dataset to this file in its root directory, which
+# the planner reads when scanning the directory. Committing a write job into the directory deletes it.
+_STATISTICS_FILE_NAME = "_daft_statistics.json"
+
+
+def write_dataset_statistics(root_dir: str | pathlib.Path, statistics: dict, io_config: IOConfig | None) -> None:
+    """Writes the statistics of the dataset in `root_dir` to its `_daft_statistics.json` file."""
+    [resolved_path], fs = _resolve_paths_and_filesystem(root_dir, io_config=io_config)
+    with fs.open_output_stream(join_path(fs, resolved_path, _STATISTICS_FILE_NAME)) as f:
+        f.write(json.dumps(statistics).encode("utf-8"))
+
+
+# `DataFrame.write_parquet(..., write_metadata=True)` summarizes the footers of all the Parquet files of a dataset in
+# this file in its root directory, so that scans of the directory needn't list the files or fetch their footers.
+# Committing a write job into the directory deletes it, unless the job writes a new one.
+_PARQUET_SUMMARY_FILE_NAME = "_metadata"
+
+
+def read_parquet_summary(root_dir: str | pathlib.Path, io_config: IOConfig | None) -> pq.FileMetaData | None:
+    """Reads the `_metadata` summary of the Parquet files of the dataset in `root_dir`, if there is one."""
+    [resolved_path], fs = _resolve_paths_and_filesystem(root_dir, io_config=io_config)
+    try:
+        with fs.open_input_file(join_path(fs, resolved_path, _PARQUET_SUMMARY_FILE_NAME)) as f:
+            return pq.read_metadata(f)
+    except FileNotFoundError:
+        return None
+
+
+def write_parquet_summary(
+    root_dir: str | pathlib.Path,
+    added_file_paths: list[str],
+    io_config: IOConfig | None,
+    summary_to_extend: pq.FileMetaData | None = None,
+) -> None:
+    """Writes the `_metadata` file that summarizes the footers of all the Parquet files of the dataset in `root_dir`.
+
+    The footers of `added_file_paths` are appended to `summary_to_extend` if it's given, which must summarize the rest
+    of the files of the dataset. Otherwise, the footers of all the files in `root_dir` are read.
+    """
+    [resolved_path], fs = _resolve_paths_and_filesystem(root_dir, io_config=io_config)
+    if summary_to_extend is None:
+        file_paths = [
+            info.path
+            for info in fs.get_file_info(pafs.FileSelector(resolved_path, recursive=True))
+            if info.type == pafs.FileType.File and not info.base_name.startswith(("_", "."))
+        ]
+    else:
+        file_paths, _ = _resolve_paths_and_filesystem(added_file_paths, io_config=io_config)
+
+    summary = summary_to_extend
+    for file_path in sorted(file_paths):
+        with fs.open_input_file(file_path) as f:
+            metadata = pq.read_metadata(f)
+        # Row groups of a summary name the file that they're in relative to the root directory.
+        metadata.set_file_path(file_path[len(resolved_path) :].lstrip("/"))
+        if summary is None:
+            summary = metadata
+        else:
+            summary.append_row_groups(metadata)
+    if summary is None:
+        return
+    with fs.open_output_stream(join_path(fs, resolved_path, _PARQUET_SUMMARY_FILE_NAME)) as f:
+        summary.write_metadata_file(f)
+
+
+def cleanup_uncommitted_writes(root_dir: str | pathlib.Path, io_config: IOConfig | None = None) -> list[str]:
+    """Deletes the files left behind by write jobs into `root_dir` that started but never committed.
+
+    This makes an interrupted write safe to simply run again, without duplicating the output of the
+    failed attempt. Only jobs that are no longer running should be cleaned up.
+
+    Args:
+        root_dir: The root directory that was written to.
+        io_config: Configurations to use when interacting with remote storage.
+
+    Returns:
+        The paths of the deleted files.
+    """
+    [resolved_path], fs = _resolve_paths_and_filesystem(root_dir, io_config=io_config)
+    try:
+        file_infos = [
+            info
+            for info in fs.get_file_info(pafs.FileSelector(resolved_path, recursive=True))
+            if info.type == pafs.FileType.File
+        ]
+    except FileNotFoundError:
+        return []
+
+    uncommitted_job_ids = {
+        info.base_name[len(_STARTED_MARKER_PREFIX) :]
+        for info in file_infos
+        if info.base_name.startswith(_STARTED_MARKER_PREFIX)
+    }
+    if not uncommitted_job_ids:
+        return []
+
+    deleted = []
+    for info in file_infos:
+        if info.base_name.startswith(_STARTED_MARKER_PREFIX):
+            job_id = info.base_name[len(_STARTED_MARKER_PREFIX) :]
+        else:
+            job_id = info.base_name.split("-", 1)[0]
+        if job_id in uncommitted_job_ids:
+            fs.delete_file(info.path)
+            deleted.append(info.path)
+    return deleted
@@ -1,5 +1,29 @@
 from __future__ import annotations
 
-from .functions import monotonically_increasing_id
+from .functions import (
+    geohash_decode,
+    geohash_encode,
+    haversine_distance,
+    monotonically_increasing_id,
+    point,
+    rand,
+    randint,
+    randn,
+    rank,
+    row_number,
+    within_bbox,
+)
 
-__all__ = ["monotonically_increasing_id"]
+__all__ = [
+    "geohash_decode",
+    "geohash_encode",
+    "haversine_distance",
+    "monotonically_increasing_id",
+    "point",
+    "rand",
+    "randint",
+    "randn",
+    "rank",
+    "row_number",
+    "within_bbox",
+]
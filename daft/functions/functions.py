@@ -34,3 +34,382 @@ def monotonically_increasing_id() -> Expression:
         Expression: An expression that generates monotonically increasing IDs
     """
     return Expression._from_pyexpr(native.monotonically_increasing_id())
+
+
+def rand(seed: int | None = None, row_seed: Expression | None = None) -> Expression:
+    """Generates a column of random floats between 0 (inclusive) and 1 (exclusive).
+
+    By default, values are different on every run. With a ``seed``, they're reproducible as long as the data is split
+    into the same partitions and batches. With a ``row_seed``, such as a key column, each row's value only depends on
+    its seed and the ``seed``, so it's reproducible no matter how the data is split, and rows with the same seed have
+    the same value. Rows with a null ``row_seed`` get null values.
+
+    Random values are nondeterministic, so the optimizer doesn't push filters past them or merge calls that look alike.
+
+    Example:
+        >>> import daft
+        >>> from daft.functions import rand
+        >>> df = daft.from_pydict({"a": [1, 2, 3]})
+        >>> df = df.with_column("r", rand())
+        >>> df.select((df["r"] >= 0) & (df["r"] < 1)).to_pydict()
+        {'r': [True, True, True]}
+
+        Values with a per-row seed are the same across runs.
+
+        >>> df = daft.from_pydict({"key": ["a", "b", "a"]})
+        >>> df = df.with_column("r", rand(seed=42, row_seed=df["key"]))
+        >>> r = df.to_pydict()["r"]
+        >>> r[0] == r[2]
+        True
+
+    Args:
+        seed: A non-negative integer seed for the random values.
+        row_seed: An expression whose value seeds the random value of each row.
+
+    Returns:
+        Expression: A Float64 expression with a random value for each row
+    """
+    row_seed = row_seed._expr if row_seed is not None else None
+    return Expression._from_pyexpr(native.rand(seed, row_seed))
+
+
+def randn(seed: int | None = None, row_seed: Expression | None = None) -> Expression:
+    """Generates a column of random floats from the standard normal distribution, with a mean of 0 and a variance of 1.
+
+    Values are seeded the same way as :func:`rand`.
+
+    Example:
+        >>> import daft
+        >>> from daft.functions import randn
+        >>> df = daft.from_pydict({"a": list(range(1000))})
+        >>> df = df.with_column("r", randn(seed=0))
+        >>> mean = df.agg(df["r"].mean()).to_pydict()["r"][0]
+        >>> abs(mean) < 0.2
+        True
+
+    Args:
+        seed: A non-negative integer seed for the random values.
+        row_seed: An expression whose value seeds the random value of each row.
+
+    Returns:
+        Expression: A Float64 expression with a random value for each row
+    """
+    row_seed = row_seed._expr if row_seed is not None else None
+    return Expression._from_pyexpr(native.randn(seed, row_seed))
+
+
+def randint(low: int, high: int, seed: int | None = None, row_seed: Expression | None = None) -> Expression:
+    """Generates a column of random integers between ``low`` (inclusive) and ``high`` (exclusive).
+
+    Values are seeded the same way as :func:`rand`.
+
+    Example:
+        >>> import daft
+        >>> from daft.functions import randint
+        >>> df = daft.from_pydict({"a": [1, 2, 3]})
+        >>> df = df.with_column("die", randint(1, 7))
+        >>> df.select(df["die"].between(1, 6)).to_pydict()
+        {'die': [True, True, True]}
+
+    Args:
+        low: The smallest possible value.
+        high: One more than the largest possible value, which must be greater than ``low``.
+        seed: A non-negative integer seed for the random values.
+        row_seed: An expression whose value seeds the random value of each row.
+
+    Returns:
+        Expression: An Int64 expression with a random value for each row
+    """
+    row_seed = row_seed._expr if row_seed is not None else None
+    return Expression._from_pyexpr(native.randint(low, high, seed, row_seed))
+
+
+def row_number() -> Expression:
+    """The position of each row in its window partition, starting at 1.
+
+    This is a window function, so it has to be evaluated over a window with :meth:`Expression.over`.
+
+    Example:
+        >>> import daft
+        >>> from daft import Window
+        >>> from daft.functions import row_number
+        >>> df = daft.from_pydict({"group": ["a", "a", "b"], "value": [3, 1, 2]})
+        >>> df = df.with_column("row", row_number().over(Window().partition_by("group").order_by("value")))
+        >>> df.sort(["group", "value"]).show()
+        ╭───────┬───────┬────────╮
+        │ group ┆ value ┆ row    │
+        │ ---   ┆ ---   ┆ ---    │
+        │ Utf8  ┆ Int64 ┆ UInt64 │
+        ╞═══════╪═══════╪════════╡
+        │ a     ┆ 1     ┆ 1      │
+        ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+        │ a     ┆ 3     ┆ 2      │
+        ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+        │ b     ┆ 2     ┆ 1      │
+        ╰───────┴───────┴────────╯
+        <BLANKLINE>
+        (Showing first 3 of 3 rows)
+
+    Returns:
+        Expression: A window function of the row numbers
+    """
+    return Expression._from_pyexpr(native.row_number())
+
+
+def rank() -> Expression:
+    """The rank of each row in its window partition, starting at 1.
+
+    Rows that tie in the order of the window get the same rank, which leaves a gap after them. This is a window
+    function, so it has to be evaluated over a window with :meth:`Expression.over`.
+
+    Example:
+        >>> import daft
+        >>> from daft import Window
+        >>> from daft.functions import rank
+        >>> df = daft.from_pydict({"score": [10, 20, 20, 30]})
+        >>> df = df.with_column("rank", rank().over(Window().order_by("score", desc=True)))
+        >>> df.sort("score", desc=True).show()
+        ╭───────┬────────╮
+        │ score ┆ rank   │
+        │ ---   ┆ ---    │
+        │ Int64 ┆ UInt64 │
+        ╞═══════╪════════╡
+        │ 30    ┆ 1      │
+        ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+        │ 20    ┆ 2      │
+        ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+        │ 20    ┆ 2      │
+        ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+        │ 10    ┆ 4      │
+        ╰───────┴────────╯
+        <BLANKLINE>
+        (Showing first 4 of 4 rows)
+
+    Returns:
+        Expression: A window function of the ranks
+    """
+    return Expression._from_pyexpr(native.rank())
+
+
+def point(lat: Expression | float, lon: Expression | float) -> Expression:
+    """Builds points out of latitudes and longitudes in degrees.
+
+    Points are structs with a ``lat`` and a ``lon`` field of type Float64, which is also what :func:`geohash_decode`
+    returns.
+
+    Example:
+        >>> import daft
+        >>> from daft import col
+        >>> from daft.functions import point
+        >>> df = daft.from_pydict({"lat": [51.5, 48.9], "lon": [-0.1, 2.4]})
+        >>> df.select(point(col("lat"), col("lon")).alias("point")).show()
+        ╭────────────────────────────────────╮
+        │ point                              │
+        │ ---                                │
+        │ Struct[lat: Float64, lon: Float64] │
+        ╞════════════════════════════════════╡
+        │ {lat: 51.5,                        │
+        │ lon: -0.1,                         │
+        │ }                                  │
+        ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+        │ {lat: 48.9,                        │
+        │ lon: 2.4,                          │
+        │ }                                  │
+        ╰────────────────────────────────────╯
+        <BLANKLINE>
+        (Showing first 2 of 2 rows)
+
+    Args:
+        lat: The latitudes of the points.
+        lon: The longitudes of the points.
+
+    Returns:
+        Expression: A struct expression of the points
+    """
+    lat, lon = Expression._to_expression(lat), Expression._to_expression(lon)
+    return Expression._from_pyexpr(native.point(lat._expr, lon._expr))
+
+
+def haversine_distance(
+    lat1: Expression | float, lon1: Expression | float, lat2: Expression | float, lon2: Expression | float
+) -> Expression:
+    """The great-circle distance in kilometers between two points, given as latitudes and longitudes in degrees.
+
+    The haversine formula treats the Earth as a sphere with its mean radius, so distances can be off by up to 0.5%.
+
+    Example:
+        >>> import daft
+        >>> from daft import col
+        >>> from daft.functions import haversine_distance
+        >>> df = daft.from_pydict(
+        ...     {
+        ...         "city": ["London", "Paris", "Tokyo"],
+        ...         "lat": [51.5074, 48.8566, 35.6762],
+        ...         "lon": [-0.1278, 2.3522, 139.6503],
+        ...     }
+        ... )
+        >>> distance = haversine_distance(col("lat"), col("lon"), 40.7128, -74.0060)
+        >>> df.select("city", distance.round(1).alias("km_to_nyc")).show()
+        ╭────────┬───────────╮
+        │ city   ┆ km_to_nyc │
+        │ ---    ┆ ---       │
+        │ Utf8   ┆ Float64   │
+        ╞════════╪═══════════╡
+        │ London ┆ 5570.2    │
+        ├╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌┤
+        │ Paris  ┆ 5837.2    │
+        ├╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌┤
+        │ Tokyo  ┆ 10851.7   │
+        ╰────────┴───────────╯
+        <BLANKLINE>
+        (Showing first 3 of 3 rows)
+
+    Args:
+        lat1: The latitudes of the first points.
+        lon1: The longitudes of the first points.
+        lat2: The latitudes of the second points.
+        lon2: The longitudes of the second points.
+
+    Returns:
+        Expression: A Float64 expression of the distances in kilometers
+    """
+    lat1, lon1, lat2, lon2 = (Expression._to_expression(c) for c in (lat1, lon1, lat2, lon2))
+    return Expression._from_pyexpr(native.haversine_distance(lat1._expr, lon1._expr, lat2._expr, lon2._expr))
+
+
+def geohash_encode(lat: Expression | float, lon: Expression | float, precision: int = 12) -> Expression:
+    """Encodes points, given as latitudes and longitudes in degrees, as geohashes.
+
+    Geohashes are base-32 strings of cells that nest within the cells of their prefixes, so points that share a long
+    prefix are close together. Points that are out of range encode to null.
+
+    Example:
+        >>> import daft
+        >>> from daft import col
+        >>> from daft.functions import geohash_encode
+        >>> df = daft.from_pydict(
+        ...     {
+        ...         "city": ["London", "Paris", "Tokyo"],
+        ...         "lat": [51.5074, 48.8566, 35.6762],
+        ...         "lon": [-0.1278, 2.3522, 139.6503],
+        ...     }
+        ... )
+        >>> df.select("city", geohash_encode(col("lat"), col("lon"), precision=6).alias("geohash")).show()
+        ╭────────┬─────────╮
+        │ city   ┆ geohash │
+        │ ---    ┆ ---     │
+        │ Utf8   ┆ Utf8    │
+        ╞════════╪═════════╡
+        │ London ┆ gcpvj0  │
+        ├╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+        │ Paris  ┆ u09tvw  │
+        ├╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+        │ Tokyo  ┆ xn76cy  │
+        ╰────────┴─────────╯
+        <BLANKLINE>
+        (Showing first 3 of 3 rows)
+
+    Args:
+        lat: The latitudes of the points.
+        lon: The longitudes of the points.
+        precision: The number of characters of the geohashes, between 1 and 12. Defaults to 12.
+
+    Returns:
+        Expression: A Utf8 expression of the geohashes
+    """
+    lat, lon = Expression._to_expression(lat), Expression._to_expression(lon)
+    return Expression._from_pyexpr(native.geohash_encode(lat._expr, lon._expr, precision))
+
+
+def geohash_decode(geohash: Expression) -> Expression:
+    """Decodes geohashes into the points at the centers of their cells, as returned by :func:`point`.
+
+    Geohashes are case-insensitive, and strings that aren't geohashes decode to null.
+
+    Example:
+        >>> import daft
+        >>> from daft import col
+        >>> from daft.functions import geohash_decode
+        >>> df = daft.from_pydict({"geohash": ["gcpvj0", "u09tvw", "xn76cy"]})
+        >>> df = df.with_column("point", geohash_decode(col("geohash")))
+        >>> df.select(
+        ...     "geohash", col("point").struct.get("lat").round(3), col("point").struct.get("lon").round(3)
+        ... ).show()
+        ╭─────────┬─────────┬─────────╮
+        │ geohash ┆ lat     ┆ lon     │
+        │ ---     ┆ ---     ┆ ---     │
+        │ Utf8    ┆ Float64 ┆ Float64 │
+        ╞═════════╪═════════╪═════════╡
+        │ gcpvj0  ┆ 51.507  ┆ -0.126  │
+        ├╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+        │ u09tvw  ┆ 48.859  ┆ 2.357   │
+        ├╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+        │ xn76cy  ┆ 35.675  ┆ 139.653 │
+        ╰─────────┴─────────┴─────────╯
+        <BLANKLINE>
+        (Showing first 3 of 3 rows)
+
+    Args:
+        geohash: The geohashes to decode.
+
+    Returns:
+        Expression: A struct expression of the points
+    """
+    return Expression._from_pyexpr(native.geohash_decode(geohash._expr))
+
+
+def within_bbox(
+    lat: Expression | float,
+    lon: Expression | float,
+    min_lat: Expression | float,
+    min_lon: Expression | float,
+    max_lat: Expression | float,
+    max_lon: Expression | float,
+) -> Expression:
+    """Whether points, given as latitudes and longitudes in degrees, are within a bounding box.
+
+    The box spans the corners (``min_lat``, ``min_lon``) and (``max_lat``, ``max_lon``), inclusive, and crosses the
+    antimeridian when ``min_lon`` is greater than ``max_lon``. The predicate is made of comparisons of the coordinates,
+    so filters with it can skip files and row groups by their statistics.
+
+    Example:
+        >>> import daft
+        >>> from daft import col
+        >>> from daft.functions import within_bbox
+        >>> df = daft.from_pydict(
+        ...     {
+        ...         "city": ["London", "Paris", "Tokyo"],
+        ...         "lat": [51.5074, 48.8566, 35.6762],
+        ...         "lon": [-0.1278, 2.3522, 139.6503],
+        ...     }
+        ... )
+        >>> df.where(within_bbox(col("lat"), col("lon"), 35.0, -10.0, 60.0, 30.0)).select("city").show()
+        ╭────────╮
+        │ city   │
+        │ ---    │
+        │ Utf8   │
+        ╞════════╡
+        │ London │
+        ├╌╌╌╌╌╌╌╌┤
+        │ Paris  │
+        ╰────────╯
+        <BLANKLINE>
+        (Showing first 2 of 2 rows)
+
+    Args:
+        lat: The latitudes of the points.
+        lon: The longitudes of the points.
+        min_lat: The southern bound of the box.
+        min_lon: The western bound of the box.
+        max_lat: The northern bound of the box.
+        max_lon: The eastern bound of the box.
+
+    Returns:
+        Expression: A Boolean expression of whether the points are in the box
+    """
+    lat, lon, min_lat, min_lon, max_lat, max_lon = (
+        Expression._to_expression(c) for c in (lat, lon, min_lat, min_lon, max_lat, max_lon)
+    )
+    return Expression._from_pyexpr(
+        native.within_bbox(lat._expr, lon._expr, min_lat._expr, min_lon._expr, max_lat._expr, max_lon._expr)
+    )
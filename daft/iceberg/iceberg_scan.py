@@ -14,6 +14,8 @@ from daft.daft import (
     ParquetSourceConfig,
     PartitionTransform,
     Pushdowns,
+    PyExpr,
+    PyRecordBatch,
     ScanTask,
     StorageConfig,
 )
@@ -26,9 +28,10 @@ from daft.logical.schema import Field, Schema
 if TYPE_CHECKING:
     from collections.abc import Iterator
 
+    from pyiceberg.manifest import DataFile
     from pyiceberg.partitioning import PartitionField as IcebergPartitionField
     from pyiceberg.partitioning import PartitionSpec as IcebergPartitionSpec
-    from pyiceberg.table import Table
+    from pyiceberg.table import Snapshot, Table
     from pyiceberg.typedef import Record
 
 logger = logging.getLogger(__name__)
@@ -90,6 +93,12 @@ def _iceberg_partition_field_to_daft_partition_field(
     return make_partition_field(result_field, daft_field, transform=tfm)
 
 
+def _iceberg_record_counts(names: list[str], record_counts: list[int]) -> Iterator[PyRecordBatch]:
+    """Yields the record counts of data files as the partial results of counts of all rows, one row per file."""
+    counts = daft.Series.from_pylist(record_counts).cast(DataType.uint64())
+    yield daft.recordbatch.RecordBatch.from_pydict({name: counts.rename(name) for name in names})._table
+
+
 def iceberg_partition_spec_to_fields(iceberg_schema: IcebergSchema, spec: IcebergPartitionSpec) -> list[PartitionField]:
     return [_iceberg_partition_field_to_daft_partition_field(iceberg_schema, field) for field in spec.fields]
 
@@ -106,6 +115,7 @@ class IcebergScanOperator(ScanOperator):
             if self._snapshot_id is None
             else self._table.scan(snapshot_id=self._snapshot_id).projection()
         )
+        self._iceberg_schema = iceberg_schema
         arrow_schema = schema_to_pyarrow(iceberg_schema)
         self._field_id_mapping = visit(iceberg_schema, SchemaFieldIdMappingVisitor())
         self._schema = Schema.from_pyarrow_schema(arrow_schema)
@@ -143,6 +153,38 @@ class IcebergScanOperator(ScanOperator):
         else:
             return None
 
+    def _iceberg_file_stats(self, file: DataFile) -> daft.recordbatch.RecordBatch | None:
+        """Builds a stats table of the lower and upper bounds of the top-level primitive columns of a data file.
+
+        Columns without both bounds, such as columns that only have nulls in the file, are left out.
+        """
+        from pyiceberg.conversions import from_bytes
+
+        lower_bounds = file.lower_bounds or {}
+        upper_bounds = file.upper_bounds or {}
+        arrays = {}
+        for field in self._iceberg_schema.fields:
+            if (
+                not field.field_type.is_primitive
+                or field.field_id not in lower_bounds
+                or field.field_id not in upper_bounds
+            ):
+                continue
+            try:
+                bounds = [
+                    from_bytes(field.field_type, lower_bounds[field.field_id]),
+                    from_bytes(field.field_type, upper_bounds[field.field_id]),
+                ]
+                arrow_arr = pa.array(bounds, type=schema_to_pyarrow(field.field_type))
+            except (pa.ArrowException, TypeError, ValueError):
+                # Bounds of types that pyarrow can't build from their Python values, such as UUIDs, can't be used.
+                continue
+            arrays[field.name] = daft.Series.from_arrow(arrow_arr, field.name)
+        if len(arrays) > 0:
+            return daft.recordbatch.RecordBatch.from_pydict(arrays)
+        else:
+            return None
+
     def multiline_display(self) -> list[str]:
         return [
             self.display_name(),
@@ -152,6 +194,11 @@ class IcebergScanOperator(ScanOperator):
             f"Storage config = {self._storage_config}",
         ]
 
+    def _snapshot(self) -> Snapshot | None:
+        if self._snapshot_id is None:
+            return self._table.current_snapshot()
+        return self._table.snapshot_by_id(self._snapshot_id)
+
     def to_scan_tasks(self, pushdowns: Pushdowns) -> Iterator[ScanTask]:
         limit = pushdowns.limit
         iceberg_tasks = self._table.scan(limit=limit, snapshot_id=self._snapshot_id).plan_files()
@@ -165,6 +212,7 @@ class IcebergScanOperator(ScanOperator):
                 self.partitioning_keys(),
             )
         scan_tasks = []
+        record_counts = []
 
         if limit is not None:
             rows_left = limit
@@ -187,8 +235,8 @@ class IcebergScanOperator(ScanOperator):
 
             iceberg_delete_files = [f.file_path for f in task.delete_files]
 
-            # TODO: Thread in Statistics to each ScanTask: P2
             pspec = self._iceberg_record_to_partition_spec(self._table.specs()[file.spec_id], file.partition)
+            stats = self._iceberg_file_stats(file)
             st = ScanTask.catalog_scan_task(
                 file=path,
                 file_format=file_format_config,
@@ -199,12 +247,30 @@ class IcebergScanOperator(ScanOperator):
                 iceberg_delete_files=iceberg_delete_files,
                 pushdowns=pushdowns,
                 partition_values=pspec._table if pspec is not None else None,
-                stats=None,
+                stats=stats._table if stats is not None else None,
             )
             if st is None:
                 continue
             rows_left -= record_count
             scan_tasks.append(st)
+            record_counts.append(record_count)
+
+        if pushdowns.aggregations is not None:
+            # The pushed down counts are answered from the record counts of the files that aren't pruned.
+            schema = Schema._from_fields(
+                [Field._from_pyfield(agg.to_field(self._schema._schema)) for agg in pushdowns.aggregations]
+            )
+            return iter(
+                [
+                    ScanTask.python_factory_func_scan_task(
+                        module=_iceberg_record_counts.__module__,
+                        func_name=_iceberg_record_counts.__name__,
+                        func_args=([agg.name() for agg in pushdowns.aggregations], record_counts),
+                        schema=schema._schema,
+                        num_rows=len(record_counts),
+                    )
+                ]
+            )
         return iter(scan_tasks)
 
     def can_absorb_filter(self) -> bool:
@@ -215,3 +281,11 @@ class IcebergScanOperator(ScanOperator):
 
     def can_absorb_select(self) -> bool:
         return True
+
+    def can_absorb_aggregation(self, aggregation: PyExpr, pushdowns: Pushdowns) -> bool:
+        # Counts of all rows are answered from the record counts of data files in the manifests, which don't account
+        # for deleted rows or rows that filters remove.
+        if pushdowns.filters is not None or aggregation.to_sql() != "COUNT(*)":
+            return False
+        snapshot = self._snapshot()
+        return snapshot is not None and snapshot.summary.get("total-delete-files") == "0"
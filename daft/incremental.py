@@ -0,0 +1,243 @@
+# isort: dont-add-import: from __future__ import annotations
+
+import json
+import logging
+import os
+import time
+from dataclasses import dataclass
+from datetime import datetime, timedelta
+from typing import Any, Callable, FrozenSet, List, Optional, Set
+
+from daft.api_annotations import PublicAPI
+from daft.daft import IOConfig
+from daft.dataframe import DataFrame
+from daft.expressions import Expression, col, lit
+from daft.io.file_path import from_glob_path
+
+logger = logging.getLogger(__name__)
+
+
+def _list_files(path: str, io_config: Optional[IOConfig]) -> List[str]:
+    files = from_glob_path(path, io_config=io_config)
+    return files.where(files["type"] == "file").select("path").to_pydict()["path"]
+
+
+@dataclass(frozen=True)
+class IncrementalResult:
+    """A materialized aggregation, along with the manifest of the files it was computed over.
+
+    Both can be persisted, e.g. with :meth:`DataFrame.write_parquet` and as a list of paths, and passed back to
+    :func:`aggregate_incrementally` as the previous result once more files have been added.
+    """
+
+    result: DataFrame
+    manifest: FrozenSet[str]
+
+
+@PublicAPI
+def aggregate_incrementally(
+    path: str,
+    read: Callable[[List[str]], DataFrame],
+    aggs: List[Expression],
+    group_by: Optional[List[str]] = None,
+    previous: Optional[IncrementalResult] = None,
+    io_config: Optional[IOConfig] = None,
+) -> IncrementalResult:
+    """Aggregates the files in a growing directory, only reading the files that were added since the previous result.
+
+    The aggregation of the new files is merged with the previous result, so only aggregations whose results can be
+    combined are supported: count, sum, min, max, bool_and, bool_or, any_value, agg_list and agg_concat. Files must only
+    ever be added; if a file of the previous manifest no longer exists, a ValueError is raised.
+
+    Example:
+        >>> from daft.incremental import aggregate_incrementally
+        >>> aggs = [daft.col("amount").sum(), daft.col("amount").count().alias("num_orders")]
+        >>> state = aggregate_incrementally("s3://bucket/orders/*.parquet", daft.read_parquet, aggs, group_by=["user"])
+        >>> # ... once more files have been written to s3://bucket/orders/
+        >>> state = aggregate_incrementally(
+        ...     "s3://bucket/orders/*.parquet", daft.read_parquet, aggs, group_by=["user"], previous=state
+        ... )
+
+    Args:
+        path: Glob path of the files to aggregate.
+        read: Reads a list of file paths into a DataFrame, e.g. ``daft.read_parquet``.
+        aggs: Aggregation expressions, as passed to :meth:`DataFrame.agg`.
+        group_by: Columns to group by. Defaults to None, which aggregates all rows.
+        previous: The result of a previous call with the same `path`, `read`, `aggs` and `group_by`.
+        io_config: Configuration to use when listing files.
+
+    Returns:
+        IncrementalResult: The materialized aggregation over all the files at `path`, and their manifest.
+    """
+    merge_aggs = [Expression._from_pyexpr(agg._expr._merge_partial_aggregation()) for agg in aggs]
+
+    files = _list_files(path, io_config)
+    manifest = frozenset(files)
+
+    if previous is None:
+        new_files = files
+    else:
+        removed = previous.manifest - manifest
+        if removed:
+            raise ValueError(
+                f"Files were removed since the previous result was computed, e.g. {next(iter(removed))}. "
+                "Only appended files can be aggregated incrementally."
+            )
+        new_files = [f for f in files if f not in previous.manifest]
+        if not new_files:
+            return previous
+
+    def aggregate(df: DataFrame, aggs: List[Expression]) -> DataFrame:
+        return df.groupby(*group_by).agg(*aggs) if group_by else df.agg(*aggs)
+
+    result = aggregate(read(new_files), aggs)
+    if previous is not None:
+        result = aggregate(previous.result.concat(result), merge_aggs)
+    return IncrementalResult(result.collect(), manifest)
+
+
+class MicroBatchStream:
+    """Processes the files of a growing directory in micro-batches, appending the results to a sink.
+
+    Every batch lists the files at `path`, reads the ones that haven't been processed yet, applies `transform` and hands
+    the result to `sink`. The processed files are recorded in a checkpoint file once the sink returns, so a stream that
+    is restarted with the same checkpoint picks up where it left off. A batch that fails before it is checkpointed is
+    processed again, so sinks should tolerate seeing the same rows twice.
+
+    If an `event_time_column` is given, the stream tracks a watermark: the latest event time seen so far minus the
+    `allowed_lateness`. Rows that arrive with an event time older than the watermark are dropped.
+
+    Example:
+        >>> from daft.incremental import MicroBatchStream
+        >>> stream = MicroBatchStream(
+        ...     "s3://bucket/events/*.parquet",
+        ...     read=daft.read_parquet,
+        ...     transform=lambda df: df.where(daft.col("status") == "error"),
+        ...     sink=lambda df: df.write_parquet("s3://bucket/errors/"),
+        ...     checkpoint_path="/tmp/errors-checkpoint.json",
+        ... )
+        >>> stream.run(poll_interval_seconds=60)
+
+    Args:
+        path: Glob path of the input files.
+        read: Reads a list of file paths into a DataFrame, e.g. ``daft.read_parquet``.
+        sink: Called with the transformed DataFrame of every batch, e.g. to append it to a table.
+        transform: Applied to every batch before it is passed to the sink. Defaults to None, which passes batches
+            through unchanged.
+        checkpoint_path: Local path of a JSON file in which processed files and the watermark are recorded.
+            Defaults to None, which keeps them in memory only.
+        max_files_per_batch: Maximum number of new files to read in one batch. Defaults to None, which reads all new
+            files.
+        event_time_column: Timestamp column that the watermark is computed from. Defaults to None, which disables
+            the watermark.
+        allowed_lateness: How far behind the latest event time a row may be before it is dropped.
+        io_config: Configuration to use when listing files.
+    """
+
+    def __init__(
+        self,
+        path: str,
+        read: Callable[[List[str]], DataFrame],
+        sink: Callable[[DataFrame], Any],
+        transform: Optional[Callable[[DataFrame], DataFrame]] = None,
+        checkpoint_path: Optional[str] = None,
+        max_files_per_batch: Optional[int] = None,
+        event_time_column: Optional[str] = None,
+        allowed_lateness: timedelta = timedelta(0),
+        io_config: Optional[IOConfig] = None,
+    ) -> None:
+        if max_files_per_batch is not None and max_files_per_batch < 1:
+            raise ValueError(f"max_files_per_batch must be at least 1, got {max_files_per_batch}")
+        self._path = path
+        self._read = read
+        self._sink = sink
+        self._transform = transform
+        self._checkpoint_path = checkpoint_path
+        self._max_files_per_batch = max_files_per_batch
+        self._event_time_column = event_time_column
+        self._allowed_lateness = allowed_lateness
+        self._io_config = io_config
+
+        self._processed_files: Set[str] = set()
+        self._max_event_time: Optional[datetime] = None
+        if checkpoint_path is not None and os.path.exists(checkpoint_path):
+            with open(checkpoint_path) as f:
+                checkpoint = json.load(f)
+            self._processed_files = set(checkpoint["processed_files"])
+            if checkpoint.get("max_event_time") is not None:
+                self._max_event_time = datetime.fromisoformat(checkpoint["max_event_time"])
+
+    @property
+    def processed_files(self) -> FrozenSet[str]:
+        return frozenset(self._processed_files)
+
+    @property
+    def watermark(self) -> Optional[datetime]:
+        """Rows with an event time before this are dropped, or None if there is no watermark yet."""
+        if self._max_event_time is None:
+            return None
+        return self._max_event_time - self._allowed_lateness
+
+    def run_once(self) -> int:
+        """Processes one batch of new files, returning the number of files in it."""
+        new_files = [f for f in _list_files(self._path, self._io_config) if f not in self._processed_files]
+        if self._max_files_per_batch is not None:
+            new_files = new_files[: self._max_files_per_batch]
+        if not new_files:
+            return 0
+
+        batch = self._read(new_files)
+        max_event_time = self._max_event_time
+        if self._event_time_column is not None:
+            # Materialize the batch so that it is only read once to both advance the watermark and process it.
+            batch = batch.collect()
+            watermark = self.watermark
+            if watermark is not None:
+                batch = batch.where(col(self._event_time_column) >= lit(watermark))
+            batch_max = batch.agg(col(self._event_time_column).max()).to_pydict()[self._event_time_column][0]
+            if batch_max is not None and (max_event_time is None or batch_max > max_event_time):
+                max_event_time = batch_max
+
+        if self._transform is not None:
+            batch = self._transform(batch)
+        self._sink(batch)
+
+        self._processed_files.update(new_files)
+        self._max_event_time = max_event_time
+        self._write_checkpoint()
+        logger.info("Processed a batch of %d files from %s", len(new_files), self._path)
+        return len(new_files)
+
+    def run(
+        self,
+        poll_interval_seconds: float = 10.0,
+        max_batches: Optional[int] = None,
+        stop_when_idle: bool = False,
+    ) -> int:
+        """Processes batches of new files until `max_batches` have run or, if `stop_when_idle`, no new files are found.
+
+        Returns:
+            int: The number of batches that were processed.
+        """
+        num_batches = 0
+        while max_batches is None or num_batches < max_batches:
+            if self.run_once() > 0:
+                num_batches += 1
+            elif stop_when_idle:
+                break
+            else:
+                time.sleep(poll_interval_seconds)
+        return num_batches
+
+    def _write_checkpoint(self) -> None:
+        if self._checkpoint_path is None:
+            return
+        checkpoint = {
+            "processed_files": sorted(self._processed_files),
+            "max_event_time": self._max_event_time.isoformat() if self._max_event_time is not None else None,
+        }
+        # Write to a temporary file first so that a crash never leaves a partially written checkpoint behind.
+        tmp_path = f"{self._checkpoint_path}.tmp"
+        with open(tmp_path, "w") as f:
+            json.dump(checkpoint, f)
+        os.replace(tmp_path, self._checkpoint_path)
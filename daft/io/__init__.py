@@ -8,10 +8,12 @@ from daft.daft import (
     S3Config,
     S3Credentials,
 )
+from daft.io._analyze import analyze_parquet
 from daft.io._csv import read_csv
 from daft.io._deltalake import read_deltalake
 from daft.io._hudi import read_hudi
 from daft.io._iceberg import read_iceberg
+from daft.io._ipc import read_ipc
 from daft.io._json import read_json
 from daft.io._lance import read_lance
 from daft.io._parquet import read_parquet
@@ -19,6 +21,7 @@ from daft.io._sql import read_sql
 from daft.io._warc import read_warc
 from daft.io.catalog import DataCatalogTable, DataCatalogType
 from daft.io.file_path import from_glob_path
+from daft.io.parquet_encryption import ParquetEncryptionConfig
 
 __all__ = [
     "AzureConfig",
@@ -27,13 +30,16 @@ __all__ = [
     "GCSConfig",
     "HTTPConfig",
     "IOConfig",
+    "ParquetEncryptionConfig",
     "S3Config",
     "S3Credentials",
+    "analyze_parquet",
     "from_glob_path",
     "read_csv",
     "read_deltalake",
     "read_hudi",
     "read_iceberg",
+    "read_ipc",
     "read_json",
     "read_lance",
     "read_parquet",
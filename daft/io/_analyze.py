@@ -0,0 +1,108 @@
+# isort: dont-add-import: from __future__ import annotations
+
+import math
+from typing import List, Optional
+
+from daft import context
+from daft.api_annotations import PublicAPI
+from daft.convert import from_pydict
+from daft.daft import IOConfig
+from daft.dataframe import DataFrame
+from daft.datatype import DataType
+from daft.expressions import Expression, col
+from daft.filesystem import write_dataset_statistics
+from daft.io._parquet import read_parquet
+from daft.io.writer import _can_count_distinct
+
+
+# The number of buckets of the equi-depth histograms of numeric and temporal columns.
+_HISTOGRAM_BUCKETS = 32
+
+
+def _finite_or_none(value: Optional[float]) -> Optional[float]:
+    # NaN and infinite bounds can't be stored as JSON, and don't help to estimate selectivities anyway.
+    return value if value is not None and math.isfinite(value) else None
+
+
+def _physical_values(column: Expression, dtype: DataType) -> Optional[Expression]:
+    """The values of a numeric or temporal column as floats, e.g. days since the epoch for dates."""
+    if dtype._is_numeric_type():
+        return column.cast(DataType.float64())
+    elif dtype._is_temporal_type():
+        return column.cast(DataType.int64()).cast(DataType.float64())
+    return None
+
+
+def _histogram(
+    boundaries: Optional[List[float]], lower: Optional[float], upper: Optional[float]
+) -> Optional[List[float]]:
+    if boundaries is None or lower is None or upper is None or not all(math.isfinite(b) for b in boundaries):
+        return None
+    # The extreme percentiles are approximate, while the bounds are exact.
+    return sorted([lower, *boundaries[1:-1], upper])
+
+
+@PublicAPI
+def analyze_parquet(root_dir: str, io_config: Optional[IOConfig] = None) -> DataFrame:
+    """Computes statistics of every column of the Parquet dataset in `root_dir` and persists them for the planner.
+
+    The statistics are the number of nulls of every column, the approximate number of distinct values of columns with
+    primitive types, and the minimum, maximum and an equi-depth histogram of numeric and temporal columns. For temporal
+    columns, these are in terms of their physical values, e.g. days since the epoch for dates. The statistics are written
+    to a `_daft_statistics.json` file in `root_dir`, which `daft.read_parquet(root_dir)` picks up to estimate the
+    cardinality of filters, group-bys, sorts and joins more accurately. Writing to `root_dir` with Daft deletes the file,
+    so the dataset has to be analyzed again afterwards.
+
+    Example:
+        >>> stats = daft.analyze_parquet("/path/to/directory")
+        >>> df = daft.read_parquet("/path/to/directory")
+
+    Args:
+        root_dir (str): The root directory of the dataset, as passed to `daft.read_parquet`.
+        io_config (IOConfig): Configurations to use when interacting with remote storage.
+
+    Returns:
+        DataFrame: The statistics, with a row per column of the dataset and the columns `column`, `min`, `max`,
+            `null_count` and `num_distinct`.
+    """
+    io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+    df = read_parquet(root_dir, io_config=io_config)
+    fields = list(df.schema())
+
+    aggs = [col(fields[0].name).count("all").alias("num_rows")]
+    for i, field in enumerate(fields):
+        column = col(field.name)
+        aggs.append(column.count("null").alias(f"null_count_{i}"))
+        if _can_count_distinct(field.dtype):
+            aggs.append(column.approx_count_distinct().alias(f"num_distinct_{i}"))
+        values = _physical_values(column, field.dtype)
+        if values is not None:
+            aggs.append(values.min().alias(f"min_{i}"))
+            aggs.append(values.max().alias(f"max_{i}"))
+            percentiles = [b / _HISTOGRAM_BUCKETS for b in range(_HISTOGRAM_BUCKETS + 1)]
+            aggs.append(values.approx_percentiles(percentiles).alias(f"histogram_{i}"))
+    [row] = df.agg(*aggs).to_pylist()
+
+    columns = {}
+    for i, field in enumerate(fields):
+        lower = _finite_or_none(row.get(f"min_{i}"))
+        upper = _finite_or_none(row.get(f"max_{i}"))
+        columns[field.name] = {
+            "min": lower,
+            "max": upper,
+            "null_count": row[f"null_count_{i}"],
+            "num_distinct": row.get(f"num_distinct_{i}"),
+            "histogram": _histogram(row.get(f"histogram_{i}"), lower, upper),
+        }
+    write_dataset_statistics(root_dir, {"num_rows": row["num_rows"], "columns": columns}, io_config)
+
+    return from_pydict(
+        {
+            "column": list(columns.keys()),
+            "min": [stats["min"] for stats in columns.values()],
+            "max": [stats["max"] for stats in columns.values()],
+            "null_count": [stats["null_count"] for stats in columns.values()],
+            "num_distinct": [stats["num_distinct"] for stats in columns.values()],
+        }
+    )
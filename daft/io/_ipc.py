@@ -0,0 +1,68 @@
+# isort: dont-add-import: from __future__ import annotations
+
+from typing import Dict, List, Optional, Union
+
+from daft import context
+from daft.api_annotations import PublicAPI
+from daft.daft import (
+    ArrowIpcSourceConfig,
+    FileFormatConfig,
+    IOConfig,
+    StorageConfig,
+)
+from daft.dataframe import DataFrame
+from daft.datatype import DataType
+from daft.io.common import get_tabular_files_scan
+
+
+@PublicAPI
+def read_ipc(
+    path: Union[str, List[str]],
+    infer_schema: bool = True,
+    schema: Optional[Dict[str, DataType]] = None,
+    io_config: Optional["IOConfig"] = None,
+    file_path_column: Optional[str] = None,
+    hive_partitioning: bool = False,
+) -> DataFrame:
+    """Creates a DataFrame from Arrow IPC file(s), which are also known as Feather (version 2) files.
+
+    Example:
+        >>> df = daft.read_ipc("/path/to/file.arrow")
+        >>> df = daft.read_ipc("/path/to/directory")
+        >>> df = daft.read_ipc("/path/to/files-*.feather")
+        >>> df = daft.read_ipc("s3://path/to/files-*.arrow")
+
+    Args:
+        path (str): Path to Arrow IPC files (allows for wildcards)
+        infer_schema (bool): Whether to read the schema from the footer of the first file, defaults to True.
+        schema (dict[str, DataType]): A schema that is used as the definitive schema for the files if infer_schema is False, otherwise it is used as a schema hint that is applied after the schema is inferred.
+        io_config (IOConfig): Config to be used with the native downloader
+        file_path_column: Include the source path(s) as a column with this name. Defaults to None.
+        hive_partitioning: Whether to infer hive_style partitions from file paths and include them as columns in the Dataframe. Defaults to False.
+
+    returns:
+        DataFrame: parsed DataFrame
+    """
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError("Cannot read DataFrame from from empty list of Arrow IPC filepaths")
+
+    if not infer_schema and schema is None:
+        raise ValueError(
+            "Cannot read DataFrame with infer_schema=False and schema=None, please provide a schema or set infer_schema=True"
+        )
+
+    io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+    file_format_config = FileFormatConfig.from_arrow_ipc_config(ArrowIpcSourceConfig())
+    storage_config = StorageConfig(True, io_config)
+
+    builder = get_tabular_files_scan(
+        path=path,
+        infer_schema=infer_schema,
+        schema=schema,
+        file_format_config=file_format_config,
+        storage_config=storage_config,
+        file_path_column=file_path_column,
+        hive_partitioning=hive_partitioning,
+    )
+    return DataFrame(builder)
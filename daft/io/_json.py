@@ -23,6 +23,7 @@ def read_json(
     io_config: Optional["IOConfig"] = None,
     file_path_column: Optional[str] = None,
     hive_partitioning: bool = False,
+    scan_task_max_attempts: int = 1,
     schema_hints: Optional[Dict[str, DataType]] = None,
     _buffer_size: Optional[int] = None,
     _chunk_size: Optional[int] = None,
@@ -34,14 +35,18 @@ def read_json(
         >>> df = daft.read_json("/path/to/directory")
         >>> df = daft.read_json("/path/to/files-*.json")
         >>> df = daft.read_json("s3://path/to/files-*.json")
+        >>> df = daft.read_json("-")  # standard input
 
     Args:
-        path (str): Path to JSON files (allows for wildcards)
+        path (str): Path to JSON files (allows for wildcards), or ``"-"`` or a named pipe to stream JSON Lines from
         infer_schema (bool): Whether to infer the schema of the JSON, defaults to True.
         schema (dict[str, DataType]): A schema that is used as the definitive schema for the JSON if infer_schema is False, otherwise it is used as a schema hint that is applied after the schema is inferred.
         io_config (IOConfig): Config to be used with the native downloader
         file_path_column: Include the source path(s) as a column with this name. Defaults to None.
         hive_partitioning: Whether to infer hive_style partitions from file paths and include them as columns in the Dataframe. Defaults to False.
+        scan_task_max_attempts: Number of times to attempt reading each scan task on the Native Runner, when reading it
+            fails with a transient IO error, such as a timeout or a dropped connection, after the IO layer's own
+            retries. Rows that an attempt already produced aren't produced again. Defaults to 1, which doesn't retry.
 
     returns:
         DataFrame: parsed DataFrame
@@ -63,7 +68,7 @@ def read_json(
 
     json_config = JsonSourceConfig(_buffer_size, _chunk_size)
     file_format_config = FileFormatConfig.from_json_config(json_config)
-    storage_config = StorageConfig(True, io_config)
+    storage_config = StorageConfig(True, io_config, scan_task_max_attempts)
 
     builder = get_tabular_files_scan(
         path=path,
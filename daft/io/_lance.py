@@ -17,14 +17,23 @@ if TYPE_CHECKING:
 
 
 def _lancedb_table_factory_function(
-    fragment: "lance.LanceFragment", required_columns: Optional[List[str]]
+    fragment: "lance.LanceFragment", required_columns: Optional[List[str]], limit: Optional[int] = None
 ) -> Iterator["PyRecordBatch"]:
     return (
         RecordBatch.from_arrow_record_batches([rb], rb.schema)._table
-        for rb in fragment.to_batches(columns=required_columns)
+        for rb in fragment.to_batches(columns=required_columns, limit=limit)
     )
 
 
+def _lancedb_fragment_num_rows(fragment: "lance.LanceFragment") -> Optional[int]:
+    """The number of rows in a fragment, read from the dataset manifest rather than the fragment's files."""
+    metadata = fragment.metadata
+    if metadata.deletion_file is None:
+        return metadata.physical_rows
+    num_deleted_rows = metadata.deletion_file.num_deleted_rows
+    return None if num_deleted_rows is None else metadata.physical_rows - num_deleted_rows
+
+
 @PublicAPI
 def read_lance(url: str, io_config: Optional["IOConfig"] = None) -> DataFrame:
     """Create a DataFrame from a LanceDB table.
@@ -109,24 +118,28 @@ class LanceDBScanOperator(ScanOperator):
 
         # TODO: figure out how to translate Pushdowns into LanceDB filters
         filters = None
+        # A limit can only be applied to each fragment if there are no filters to apply after reading it.
+        limit = pushdowns.limit if pushdowns.filters is None else None
+        rows_left = limit
         fragments = self._ds.get_fragments(filter=filters)
-        for i, fragment in enumerate(fragments):
+        for fragment in fragments:
+            if rows_left is not None and rows_left <= 0:
+                break
+
             # TODO: figure out how if we can get this metadata from LanceDB fragments cheaply
             size_bytes = None
             stats = None
 
-            # NOTE: `fragment.count_rows()` should result in 1 IO call for the data file
-            # (1 fragment = 1 data file) and 1 more IO call for the deletion file (if present).
-            # This could potentially be expensive to perform serially if there are thousands of files.
-            # Given that num_rows isn't leveraged for much at the moment, and without statistics
-            # we will probably end up materializing the data anyways for any operations, we leave this
-            # as None.
-            num_rows = None
+            # NOTE: Row counts come from the manifest, which has already been read, rather than
+            # `fragment.count_rows()`, which makes an IO call for the data file and another for the deletion file.
+            num_rows = _lancedb_fragment_num_rows(fragment)
+            if rows_left is not None and num_rows is not None:
+                rows_left -= num_rows
 
             yield ScanTask.python_factory_func_scan_task(
                 module=_lancedb_table_factory_function.__module__,
                 func_name=_lancedb_table_factory_function.__name__,
-                func_args=(fragment, required_columns),
+                func_args=(fragment, required_columns, limit),
                 schema=self.schema()._schema,
                 num_rows=num_rows,
                 size_bytes=size_bytes,
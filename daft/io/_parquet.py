@@ -1,6 +1,6 @@
 # isort: dont-add-import: from __future__ import annotations
 
-from typing import Dict, List, Optional, Union
+from typing import Dict, Iterator, List, Optional, Union
 
 from daft import context
 from daft.api_annotations import PublicAPI
@@ -8,11 +8,23 @@ from daft.daft import (
     FileFormatConfig,
     IOConfig,
     ParquetSourceConfig,
+    Pushdowns,
+    PyRecordBatch,
+    ScanOperatorHandle,
+    ScanTask,
     StorageConfig,
+    io_glob,
 )
 from daft.dataframe import DataFrame
 from daft.datatype import DataType, TimeUnit
+from daft.dependencies import pq
+from daft.filesystem import _resolve_paths_and_filesystem
 from daft.io.common import get_tabular_files_scan
+from daft.io.parquet_encryption import ParquetEncryptionConfig
+from daft.io.scan import PartitionField, ScanOperator
+from daft.logical.builder import LogicalPlanBuilder
+from daft.logical.schema import Schema
+from daft.recordbatch import RecordBatch
 
 
 @PublicAPI
@@ -25,6 +37,9 @@ def read_parquet(
     file_path_column: Optional[str] = None,
     hive_partitioning: bool = False,
     coerce_int96_timestamp_unit: Optional[Union[str, TimeUnit]] = None,
+    row_index_column: Optional[str] = None,
+    encryption: Optional[ParquetEncryptionConfig] = None,
+    scan_task_max_attempts: int = 1,
     schema_hints: Optional[Dict[str, DataType]] = None,
     _multithreaded_io: Optional[bool] = None,
     _chunk_size: Optional[int] = None,  # A hidden parameter for testing purposes.
@@ -47,6 +62,13 @@ def read_parquet(
         file_path_column: Include the source path(s) as a column with this name. Defaults to None.
         hive_partitioning: Whether to infer hive_style partitions from file paths and include them as columns in the Dataframe. Defaults to False.
         coerce_int96_timestamp_unit: TimeUnit to coerce Int96 TimeStamps to. e.g.: [ns, us, ms], Defaults to None.
+        row_index_column: Include the index of each row within its source file as a column with this name. Together with
+            `file_path_column`, this lets rows be traced back to where they were read from. Defaults to None.
+        encryption: Settings to decrypt files written with Parquet modular encryption. Encrypted files are decoded by
+            PyArrow rather than Daft's native reader. Defaults to None.
+        scan_task_max_attempts: Number of times to attempt reading each scan task on the Native Runner, when reading it
+            fails with a transient IO error, such as a timeout or a dropped connection, after the IO layer's own
+            retries. Rows that an attempt already produced aren't produced again. Defaults to 1, which doesn't retry.
         _multithreaded_io: Whether to use multithreading for IO threads. Setting this to False can be helpful in reducing
             the amount of system resources (number of connections and thread contention) when running in the Ray runner.
             Defaults to None, which will let Daft decide based on the runner it is currently using.
@@ -79,11 +101,34 @@ def read_parquet(
         raise ValueError("row_groups must be the same length as the list of paths provided.")
     if isinstance(row_groups, list) and not isinstance(path, list):
         raise ValueError("row_groups are only supported when reading multiple non-globbed/wildcarded files")
+    if row_index_column is not None and row_groups is not None:
+        raise ValueError("row_index_column cannot be used when reading specific row_groups")
+
+    if encryption is not None:
+        unsupported = {
+            "row_groups": row_groups,
+            "schema": schema,
+            "file_path_column": file_path_column,
+            "hive_partitioning": hive_partitioning or None,
+            "coerce_int96_timestamp_unit": coerce_int96_timestamp_unit,
+            "row_index_column": row_index_column,
+        }
+        for name, value in unsupported.items():
+            if value is not None:
+                raise ValueError(f"{name} is not supported when reading encrypted Parquet files")
+        scan_op = EncryptedParquetScanOperator(path if isinstance(path, list) else [path], encryption, io_config)
+        handle = ScanOperatorHandle.from_python_scan_operator(scan_op)
+        return DataFrame(LogicalPlanBuilder.from_tabular_scan(scan_operator=handle))
 
     file_format_config = FileFormatConfig.from_parquet_config(
-        ParquetSourceConfig(coerce_int96_timestamp_unit=pytimeunit, row_groups=row_groups, chunk_size=_chunk_size)
+        ParquetSourceConfig(
+            coerce_int96_timestamp_unit=pytimeunit,
+            row_groups=row_groups,
+            chunk_size=_chunk_size,
+            row_index_column=row_index_column,
+        )
     )
-    storage_config = StorageConfig(multithreaded_io, io_config)
+    storage_config = StorageConfig(multithreaded_io, io_config, scan_task_max_attempts)
 
     builder = get_tabular_files_scan(
         path=path,
@@ -95,3 +140,85 @@ def read_parquet(
         hive_partitioning=hive_partitioning,
     )
     return DataFrame(builder)
+
+
+def _encrypted_parquet_factory_function(
+    path: str,
+    encryption: ParquetEncryptionConfig,
+    io_config: Optional[IOConfig],
+    required_columns: Optional[List[str]],
+) -> Iterator[PyRecordBatch]:
+    [resolved_path], fs = _resolve_paths_and_filesystem(path, io_config=io_config)
+    with fs.open_input_file(resolved_path) as f:
+        file = pq.ParquetFile(f, decryption_properties=encryption.file_decryption_properties())
+        for rb in file.iter_batches(columns=required_columns):
+            yield RecordBatch.from_arrow_record_batches([rb], rb.schema)._table
+
+
+class EncryptedParquetScanOperator(ScanOperator):
+    """Reads Parquet files written with modular encryption, one scan task per file."""
+
+    def __init__(self, paths: List[str], encryption: ParquetEncryptionConfig, io_config: Optional[IOConfig]):
+        self._encryption = encryption
+        self._io_config = io_config
+        self._files = [f for path in paths for f in io_glob(path, io_config=io_config) if f["type"] == "File"]
+        if not self._files:
+            raise FileNotFoundError(f"No files found at {paths}")
+
+        first_path = self._files[0]["path"]
+        [resolved_path], fs = _resolve_paths_and_filesystem(first_path, io_config=io_config)
+        with fs.open_input_file(resolved_path) as f:
+            file = pq.ParquetFile(f, decryption_properties=encryption.file_decryption_properties())
+            self._schema = Schema.from_pyarrow_schema(file.schema_arrow)
+
+    def name(self) -> str:
+        return "EncryptedParquetScanOperator"
+
+    def display_name(self) -> str:
+        return f"EncryptedParquetScanOperator({self._files[0]['path']})"
+
+    def schema(self) -> Schema:
+        return self._schema
+
+    def partitioning_keys(self) -> List[PartitionField]:
+        return []
+
+    def can_absorb_filter(self) -> bool:
+        return False
+
+    def can_absorb_limit(self) -> bool:
+        return False
+
+    def can_absorb_select(self) -> bool:
+        return False
+
+    def multiline_display(self) -> List[str]:
+        return [
+            self.display_name(),
+            f"Schema = {self.schema()}",
+            f"Number of files = {len(self._files)}",
+        ]
+
+    def to_scan_tasks(self, pushdowns: Pushdowns) -> Iterator[ScanTask]:
+        required_columns: Optional[List[str]]
+        if pushdowns.columns is None:
+            required_columns = None
+        else:
+            filter_required_column_names = pushdowns.filter_required_column_names()
+            required_columns = (
+                pushdowns.columns
+                if filter_required_column_names is None
+                else list(dict.fromkeys(pushdowns.columns + filter_required_column_names))
+            )
+
+        for file in self._files:
+            yield ScanTask.python_factory_func_scan_task(
+                module=_encrypted_parquet_factory_function.__module__,
+                func_name=_encrypted_parquet_factory_function.__name__,
+                func_args=(file["path"], self._encryption, self._io_config, required_columns),
+                schema=self.schema()._schema,
+                num_rows=None,
+                size_bytes=file["size"],
+                pushdowns=pushdowns,
+                stats=None,
+            )
@@ -0,0 +1,101 @@
+from __future__ import annotations
+
+import dataclasses
+from typing import TYPE_CHECKING, Callable
+
+from daft.dependencies import pads, pqe
+
+if TYPE_CHECKING:
+    import pyarrow.parquet.encryption
+
+
+@dataclasses.dataclass(frozen=True)
+class ParquetEncryptionConfig:
+    """Settings for reading and writing Parquet files with Parquet modular encryption.
+
+    Data keys are generated per file and wrapped with master keys held in a Key Management Service (KMS). Access to the
+    KMS is provided by ``kms_client_factory``, which is called with a ``pyarrow.parquet.encryption.KmsConnectionConfig``
+    and returns a ``pyarrow.parquet.encryption.KmsClient`` implementing ``wrap_key`` and ``unwrap_key``.
+
+    The config is sent to every worker that reads or writes the files, so ``kms_client_factory`` must be picklable,
+    e.g. a class or a module-level function.
+
+    Example:
+        >>> import pyarrow.parquet.encryption as pqe
+        >>> class MyKmsClient(pqe.KmsClient):
+        ...     def __init__(self, kms_connection_config):
+        ...         super().__init__()
+        ...
+        ...     def wrap_key(self, key_bytes, master_key_identifier): ...
+        ...
+        ...     def unwrap_key(self, wrapped_key, master_key_identifier): ...
+        >>> encryption = daft.io.ParquetEncryptionConfig(
+        ...     kms_client_factory=MyKmsClient,
+        ...     footer_key="footer_key",
+        ...     column_keys={"pii_key": ["name", "email"]},
+        ... )
+        >>> df.write_parquet("/path/to/dir", encryption=encryption)
+        >>> df = daft.read_parquet("/path/to/dir", encryption=encryption)
+
+    Args:
+        kms_client_factory: Creates the KMS client used to wrap and unwrap data keys.
+        footer_key: ID of the master key used to encrypt the file footer. Required for writing.
+        column_keys: Mapping of master key IDs to the names of the columns encrypted with that key. Columns that aren't
+            listed are encrypted with the footer key.
+        plaintext_footer: Whether to leave the footer unencrypted, so that readers without access to the keys can still
+            read the schema and any unencrypted columns. Defaults to False.
+        kms_instance_id: ID of the KMS instance, passed to ``kms_client_factory``.
+        kms_instance_url: URL of the KMS instance, passed to ``kms_client_factory``.
+        key_access_token: Token used to authenticate with the KMS, passed to ``kms_client_factory``.
+        custom_kms_conf: Any additional KMS settings, passed to ``kms_client_factory``.
+    """
+
+    kms_client_factory: Callable[[pyarrow.parquet.encryption.KmsConnectionConfig], pyarrow.parquet.encryption.KmsClient]
+    footer_key: str | None = None
+    column_keys: dict[str, list[str]] | None = None
+    plaintext_footer: bool = False
+    kms_instance_id: str | None = None
+    kms_instance_url: str | None = None
+    key_access_token: str | None = None
+    custom_kms_conf: dict[str, str] | None = None
+
+    def _crypto_factory(self) -> pyarrow.parquet.encryption.CryptoFactory:
+        return pqe.CryptoFactory(self.kms_client_factory)
+
+    def _kms_connection_config(self) -> pyarrow.parquet.encryption.KmsConnectionConfig:
+        kwargs = {
+            "kms_instance_id": self.kms_instance_id,
+            "kms_instance_url": self.kms_instance_url,
+            "key_access_token": self.key_access_token,
+            "custom_kms_conf": self.custom_kms_conf,
+        }
+        return pqe.KmsConnectionConfig(**{k: v for k, v in kwargs.items() if v is not None})
+
+    def _encryption_configuration(self) -> pyarrow.parquet.encryption.EncryptionConfiguration:
+        if self.footer_key is None:
+            raise ValueError("A footer_key is required to write encrypted Parquet files")
+        return pqe.EncryptionConfiguration(
+            footer_key=self.footer_key,
+            column_keys=self.column_keys or {},
+            plaintext_footer=self.plaintext_footer,
+        )
+
+    def file_encryption_properties(self) -> pyarrow.parquet.FileEncryptionProperties:
+        """Returns the properties to pass to a ``pyarrow.parquet.ParquetWriter`` to encrypt a file."""
+        return self._crypto_factory().file_encryption_properties(
+            self._kms_connection_config(), self._encryption_configuration()
+        )
+
+    def file_decryption_properties(self) -> pyarrow.parquet.FileDecryptionProperties:
+        """Returns the properties to pass to a ``pyarrow.parquet.ParquetFile`` to decrypt a file."""
+        return self._crypto_factory().file_decryption_properties(
+            self._kms_connection_config(), pqe.DecryptionConfiguration()
+        )
+
+    def dataset_write_options(self) -> pyarrow.dataset.ParquetEncryptionConfig:
+        """Returns the ``encryption_config`` to pass to ``pyarrow.dataset.ParquetFileFormat.make_write_options``."""
+        if not hasattr(pads, "ParquetEncryptionConfig"):
+            raise ValueError("Writing encrypted Parquet files with this runner requires pyarrow >= 15.0.0")
+        return pads.ParquetEncryptionConfig(
+            self._crypto_factory(), self._kms_connection_config(), self._encryption_configuration()
+        )
@@ -8,6 +8,7 @@ from daft.daft import PartitionField, PartitionTransform, Pushdowns, ScanTask
 if TYPE_CHECKING:
     from collections.abc import Iterator
 
+    from daft.daft import PyExpr
     from daft.logical.schema import Field, Schema
 
 
@@ -46,6 +47,14 @@ class ScanOperator(abc.ABC):
     def can_absorb_select(self) -> bool:
         raise NotImplementedError()
 
+    def can_absorb_aggregation(self, aggregation: PyExpr, pushdowns: Pushdowns) -> bool:
+        """Whether the scan tasks can compute a count, minimum or maximum of a column over the rows that they read.
+
+        If so, the aggregation is pushed down into ``pushdowns.aggregations``, and each scan task yields rows of
+        partial results, with a column named like each aggregation, which are then merged.
+        """
+        return False
+
     @abc.abstractmethod
     def multiline_display(self) -> list[str]:
         raise NotImplementedError()
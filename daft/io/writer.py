@@ -1,11 +1,16 @@
+import json
+import sys
 import uuid
 from abc import ABC, abstractmethod
-from typing import TYPE_CHECKING, List, Optional
+from typing import TYPE_CHECKING, Dict, Iterator, List, Optional
 
-from daft.daft import IOConfig
+from daft.daft import FileFormat, IOConfig, write_json_lines
+from daft.datatype import DataType
 from daft.delta_lake.delta_lake_write import make_deltalake_add_action, make_deltalake_fs, sanitize_table_for_deltalake
 from daft.dependencies import pa, pacsv, pq
+from daft.expressions import Expression, col
 from daft.filesystem import (
+    STDIO_PATH,
     _resolve_paths_and_filesystem,
     canonicalize_protocol,
     get_protocol_from_path,
@@ -22,11 +27,14 @@ from daft.recordbatch.partitioning import (
 )
 from daft.recordbatch.recordbatch import RecordBatch
 from daft.series import Series
+from daft.utils import PARQUET_NUM_DISTINCT_KEY, parquet_bloom_filter_options
 
 if TYPE_CHECKING:
     from pyiceberg.schema import Schema as IcebergSchema
     from pyiceberg.table import TableProperties as IcebergTableProperties
 
+    from daft.io.parquet_encryption import ParquetEncryptionConfig
+
 
 class FileWriterBase(ABC):
     def __init__(
@@ -39,17 +47,18 @@ class FileWriterBase(ABC):
         io_config: Optional[IOConfig] = None,
         version: Optional[int] = None,
         default_partition_fallback: Optional[str] = None,
+        job_id: Optional[str] = None,
     ):
         resolved_path, self.fs = self.resolve_path_and_fs(root_dir, io_config=io_config)
         self.protocol = get_protocol_from_path(root_dir)
         canonicalized_protocol = canonicalize_protocol(self.protocol)
         is_local_fs = canonicalized_protocol == "file"
 
-        self.file_name = (
-            f"{uuid.uuid4()}-{file_idx}.{file_format}"
-            if version is None
-            else f"{version}-{uuid.uuid4()}-{file_idx}.{file_format}"
-        )
+        self.file_name = f"{uuid.uuid4()}-{file_idx}.{file_format}"
+        if job_id is not None:
+            self.file_name = f"{job_id}-{self.file_name}"
+        if version is not None:
+            self.file_name = f"{version}-{self.file_name}"
         self.partition_values = partition_values
         if self.partition_values is not None:
             self.partition_strings = {
@@ -102,6 +111,52 @@ class FileWriterBase(ABC):
         pass
 
 
+def write_stream(
+    batches: Iterator[pa.RecordBatch], schema: pa.Schema, path: str, file_format: FileFormat
+) -> None:
+    """Writes `batches` as CSV or JSON Lines to standard output, if `path` is `-`, or to the pipe at `path`.
+
+    Each batch is flushed as soon as it's written, so that readers of the stream can process it while later batches
+    are still being computed. Batches are encoded the same way as by the file writers, i.e. CSV by PyArrow and JSON
+    Lines natively.
+    """
+    if file_format not in (FileFormat.Csv, FileFormat.Json):
+        raise ValueError(f"Only CSV and JSON Lines can be written to a stream, got {file_format}")
+    if path == STDIO_PATH:
+        sys.stdout.flush()
+        _write_batches(batches, schema, sys.stdout.buffer, file_format)
+    else:
+        with open(path.removeprefix("file://"), "wb") as f:
+            _write_batches(batches, schema, f, file_format)
+
+
+def _write_batches(batches: Iterator[pa.RecordBatch], schema: pa.Schema, sink, file_format: FileFormat) -> None:
+    if file_format == FileFormat.Csv:
+        # The header is written on its own, so that it's written even if there are no rows.
+        sink.write(_encode_csv(schema.empty_table(), include_header=True))
+        sink.flush()
+    for batch in batches:
+        if file_format == FileFormat.Csv:
+            sink.write(_encode_csv(batch, include_header=False))
+        else:
+            sink.write(_encode_json_lines(RecordBatch.from_arrow_record_batches([batch], schema)))
+        sink.flush()
+
+
+def _encode_csv(data, include_header: bool) -> bytes:
+    buffer = pa.BufferOutputStream()
+    pacsv.write_csv(data, buffer, write_options=pacsv.WriteOptions(include_header=include_header))
+    return buffer.getvalue().to_pybytes()
+
+
+def _encode_json_lines(table: RecordBatch) -> bytes:
+    return write_json_lines(table._table)
+
+
+def _can_count_distinct(dtype: DataType) -> bool:
+    return dtype._is_numeric_type() or dtype._is_string() or dtype._is_boolean() or dtype._is_temporal_type()
+
+
 class ParquetFileWriter(FileWriterBase):
     def __init__(
         self,
@@ -113,6 +168,9 @@ class ParquetFileWriter(FileWriterBase):
         version: Optional[int] = None,
         default_partition_fallback: Optional[str] = None,
         metadata_collector: Optional[List[pq.FileMetaData]] = None,
+        job_id: Optional[str] = None,
+        bloom_filter_columns: Optional[List[str]] = None,
+        encryption: Optional["ParquetEncryptionConfig"] = None,
     ):
         super().__init__(
             root_dir=root_dir,
@@ -123,15 +181,23 @@ class ParquetFileWriter(FileWriterBase):
             io_config=io_config,
             version=version,
             default_partition_fallback=default_partition_fallback,
+            job_id=job_id,
         )
         self.is_closed = False
         self.current_writer: Optional[pq.ParquetWriter] = None
         self.metadata_collector: Optional[List[pq.FileMetaData]] = metadata_collector
+        self.bloom_filter_columns = bloom_filter_columns
+        self.encryption = encryption
+        # HyperLogLog sketches of the columns of every table written, merged into the approximate number of distinct
+        # values of each column that is recorded in the footer of the file.
+        self.num_distinct_sketches: List[MicroPartition] = []
 
     def _create_writer(self, schema: pa.Schema) -> pq.ParquetWriter:
-        opts = {}
+        opts = parquet_bloom_filter_options(self.bloom_filter_columns)
         if self.metadata_collector is not None:
             opts["metadata_collector"] = self.metadata_collector
+        if self.encryption is not None:
+            opts["encryption_properties"] = self.encryption.file_encryption_properties()
         return pq.ParquetWriter(
             self.full_path,
             schema,
@@ -146,14 +212,31 @@ class ParquetFileWriter(FileWriterBase):
         if self.current_writer is None:
             self.current_writer = self._create_writer(table.schema().to_pyarrow_schema())
         self.current_writer.write_table(table.to_arrow(), row_group_size=len(table))
+        sketches = [
+            Expression._from_pyexpr(col(field.name)._expr.hll_sketch())
+            for field in table.schema()
+            if _can_count_distinct(field.dtype)
+        ]
+        if sketches:
+            self.num_distinct_sketches.append(table.agg(sketches))
 
         current_position = self.current_writer.file_handle.tell()
         bytes_written = current_position - self.position
         self.position = current_position
         return bytes_written
 
+    def _num_distinct(self) -> Dict[str, int]:
+        sketches = MicroPartition.concat(self.num_distinct_sketches)
+        merged = sketches.agg(
+            [Expression._from_pyexpr(col(name)._expr.hll_merge()) for name in sketches.column_names()]
+        )
+        return {name: values[0] for name, values in merged.to_pydict().items()}
+
     def close(self) -> RecordBatch:
         if self.current_writer is not None:
+            # Older versions of pyarrow can't add to the footer metadata once the schema has been written.
+            if self.num_distinct_sketches and hasattr(self.current_writer, "add_key_value_metadata"):
+                self.current_writer.add_key_value_metadata({PARQUET_NUM_DISTINCT_KEY: json.dumps(self._num_distinct())})
             self.current_writer.close()
 
         self.is_closed = True
@@ -171,6 +254,7 @@ class CSVFileWriter(FileWriterBase):
         file_idx: int,
         partition_values: Optional[RecordBatch] = None,
         io_config: Optional[IOConfig] = None,
+        job_id: Optional[str] = None,
     ):
         super().__init__(
             root_dir=root_dir,
@@ -178,6 +262,7 @@ class CSVFileWriter(FileWriterBase):
             file_format="csv",
             partition_values=partition_values,
             io_config=io_config,
+            job_id=job_id,
         )
         self.file_handle = None
         self.current_writer: Optional[pacsv.CSVWriter] = None
@@ -214,6 +299,106 @@ class CSVFileWriter(FileWriterBase):
         return RecordBatch.from_pydict(metadata)
 
 
+class JSONFileWriter(FileWriterBase):
+    """Writes JSON Lines files, with one JSON object per row."""
+
+    def __init__(
+        self,
+        root_dir: str,
+        file_idx: int,
+        partition_values: Optional[RecordBatch] = None,
+        io_config: Optional[IOConfig] = None,
+        job_id: Optional[str] = None,
+    ):
+        super().__init__(
+            root_dir=root_dir,
+            file_idx=file_idx,
+            file_format="json",
+            partition_values=partition_values,
+            io_config=io_config,
+            job_id=job_id,
+        )
+        self.file_handle: Optional[pa.NativeFile] = None
+        self.is_closed = False
+
+    def write(self, table: MicroPartition) -> int:
+        assert not self.is_closed, "Cannot write to a closed JSONFileWriter"
+        if self.file_handle is None:
+            self.file_handle = self.fs.open_output_stream(self.full_path)
+        self.file_handle.write(_encode_json_lines(table.to_table()))
+
+        current_position = self.file_handle.tell()
+        bytes_written = current_position - self.position
+        self.position = current_position
+        return bytes_written
+
+    def close(self) -> RecordBatch:
+        if self.file_handle is not None:
+            self.file_handle.close()
+
+        self.is_closed = True
+        metadata = {"path": Series.from_pylist([self.full_path])}
+        if self.partition_values is not None:
+            for col_name in self.partition_values.column_names():
+                metadata[col_name] = self.partition_values.get_column(col_name)
+        return RecordBatch.from_pydict(metadata)
+
+
+class IpcFileWriter(FileWriterBase):
+    def __init__(
+        self,
+        root_dir: str,
+        file_idx: int,
+        partition_values: Optional[RecordBatch] = None,
+        compression: Optional[str] = None,
+        io_config: Optional[IOConfig] = None,
+        job_id: Optional[str] = None,
+    ):
+        super().__init__(
+            root_dir=root_dir,
+            file_idx=file_idx,
+            file_format="arrow",
+            partition_values=partition_values,
+            compression=compression,
+            io_config=io_config,
+            job_id=job_id,
+        )
+        self.file_handle = None
+        self.current_writer: Optional[pa.ipc.RecordBatchFileWriter] = None
+        self.is_closed = False
+
+    def _create_writer(self, schema: pa.Schema) -> pa.ipc.RecordBatchFileWriter:
+        self.file_handle = self.fs.open_output_stream(self.full_path)
+        compression = None if self.compression == "none" else self.compression
+        return pa.ipc.new_file(self.file_handle, schema, options=pa.ipc.IpcWriteOptions(compression=compression))
+
+    def write(self, table: MicroPartition) -> int:
+        assert not self.is_closed, "Cannot write to a closed IpcFileWriter"
+        if self.current_writer is None:
+            self.current_writer = self._create_writer(table.schema().to_pyarrow_schema())
+        self.current_writer.write_table(table.to_arrow())
+
+        assert self.file_handle is not None  # We should have created the file handle in _create_writer
+        current_position = self.file_handle.tell()
+        bytes_written = current_position - self.position
+        self.position = current_position
+        return bytes_written
+
+    def close(self) -> RecordBatch:
+        if self.current_writer is not None:
+            # Closing the writer writes the footer, but leaves the file open.
+            self.current_writer.close()
+            assert self.file_handle is not None
+            self.file_handle.close()
+
+        self.is_closed = True
+        metadata = {"path": Series.from_pylist([self.full_path])}
+        if self.partition_values is not None:
+            for col_name in self.partition_values.column_names():
+                metadata[col_name] = self.partition_values.get_column(col_name)
+        return RecordBatch.from_pydict(metadata)
+
+
 class IcebergWriter(ParquetFileWriter):
     def __init__(
         self,
@@ -23,6 +23,7 @@ if TYPE_CHECKING:
 
     from pyiceberg.table import Table as IcebergTable
 
+    from daft.io.parquet_encryption import ParquetEncryptionConfig
     from daft.plan_scheduler.physical_plan_scheduler import (
         AdaptivePhysicalPlanScheduler,
         PhysicalPlanScheduler,
@@ -97,14 +98,16 @@ class LogicalPlanBuilder:
         builder = self._builder.summarize()
         return LogicalPlanBuilder(builder)
 
-    def pretty_print(self, simple: bool = False, format: str = "ascii") -> str:
-        """Pretty prints the current underlying logical plan."""
+    def pretty_print(self, simple: bool = False, format: str = "ascii", verbose: bool = False) -> str:
+        """Pretty prints the current underlying logical plan, with the output schema of every node if `verbose`."""
         from daft.dataframe.display import MermaidOptions
 
         if format == "ascii":
-            return self._builder.repr_ascii(simple)
+            return self._builder.repr_ascii(simple, verbose)
         elif format == "mermaid":
             return self._builder.repr_mermaid(MermaidOptions(simple))
+        elif format == "json":
+            return self._builder.repr_json(simple)
         else:
             raise ValueError(f"Unknown format: {format}")
 
@@ -116,6 +119,26 @@ class LogicalPlanBuilder:
         builder = self._builder.optimize()
         return LogicalPlanBuilder(builder)
 
+    def fingerprint(self) -> int:
+        """Hash of the underlying logical plan within this process, which is equal for equal plans."""
+        return self._builder.fingerprint()
+
+    def plan_eq(self, other: LogicalPlanBuilder) -> bool:
+        """Whether the underlying logical plans are equal."""
+        return self._builder.plan_eq(other._builder)
+
+    def is_cacheable(self) -> bool:
+        """Whether the results of the plan can be reused, i.e. it doesn't write data and is deterministic."""
+        return self._builder.is_cacheable()
+
+    def source_file_versions(self) -> list[tuple[str, str | None]]:
+        """Paths and current versions, e.g. ETags, of the files read by the plan's materialized scans."""
+        return self._builder.source_file_versions()
+
+    def estimated_size_bytes(self) -> int:
+        """Approximate in-memory size of the output of the underlying logical plan, in bytes."""
+        return self._builder.estimated_size_bytes()
+
     @classmethod
     @_apply_daft_planning_config_to_initializer
     def from_in_memory_scan(
@@ -136,6 +159,12 @@ class LogicalPlanBuilder:
         )
         return cls(builder)
 
+    @classmethod
+    @_apply_daft_planning_config_to_initializer
+    def from_iteration_scan(cls, partition_key: str, schema: Schema) -> LogicalPlanBuilder:
+        builder = _LogicalPlanBuilder.iteration_scan(partition_key, schema._schema)
+        return cls(builder)
+
     @classmethod
     @_apply_daft_planning_config_to_initializer
     def from_tabular_scan(
@@ -176,6 +205,24 @@ class LogicalPlanBuilder:
         builder = self._builder.filter(predicate._expr)
         return LogicalPlanBuilder(builder)
 
+    def in_subquery(self, expr: Expression) -> Expression:
+        return Expression._from_pyexpr(self._builder.in_subquery(expr._expr))
+
+    def assert_that(self, predicate: Expression, message: str, violations_id: str | None = None) -> LogicalPlanBuilder:
+        builder = self._builder.assert_that(predicate._expr, message, violations_id)
+        return LogicalPlanBuilder(builder)
+
+    def iterate(
+        self,
+        body: LogicalPlanBuilder,
+        convergence: LogicalPlanBuilder,
+        previous_key: str,
+        result_key: str,
+        max_iters: int,
+    ) -> LogicalPlanBuilder:
+        builder = self._builder.iterate(body._builder, convergence._builder, previous_key, result_key, max_iters)
+        return LogicalPlanBuilder(builder)
+
     def limit(self, num_rows: int, eager: bool) -> LogicalPlanBuilder:
         builder = self._builder.limit(num_rows, eager)
         return LogicalPlanBuilder(builder)
@@ -198,7 +245,7 @@ class LogicalPlanBuilder:
         return LogicalPlanBuilder(builder)
 
     def count(self) -> LogicalPlanBuilder:
-        # TODO(Clark): Add dedicated logical/physical ops when introducing metadata-based count optimizations.
+        # The optimizer answers this from the metadata of the scan tasks when they know their numbers of rows.
         first_col = col(self.schema().column_names()[0])
         builder = self._builder.aggregate([first_col.count(CountMode.All)._expr], [])
         builder = builder.select([first_col.alias("count")._expr])
@@ -217,6 +264,7 @@ class LogicalPlanBuilder:
         sort_by: list[Expression],
         descending: list[bool] | bool = False,
         nulls_first: list[bool] | bool | None = None,
+        collation: list[str] | str | None = None,
     ) -> LogicalPlanBuilder:
         sort_by_pyexprs = [expr._expr for expr in sort_by]
         if not isinstance(descending, list):
@@ -225,7 +273,28 @@ class LogicalPlanBuilder:
             nulls_first = descending
         elif isinstance(nulls_first, bool):
             nulls_first = [nulls_first] * len(sort_by_pyexprs)
-        builder = self._builder.sort(sort_by_pyexprs, descending, nulls_first)
+        if isinstance(collation, str):
+            collation = [collation] * len(sort_by_pyexprs)
+        builder = self._builder.sort(sort_by_pyexprs, descending, nulls_first, collation)
+        return LogicalPlanBuilder(builder)
+
+    def sort_within_partitions(
+        self,
+        sort_by: list[Expression],
+        descending: list[bool] | bool = False,
+        nulls_first: list[bool] | bool | None = None,
+        collation: list[str] | str | None = None,
+    ) -> LogicalPlanBuilder:
+        sort_by_pyexprs = [expr._expr for expr in sort_by]
+        if not isinstance(descending, list):
+            descending = [descending] * len(sort_by_pyexprs)
+        if nulls_first is None:
+            nulls_first = descending
+        elif isinstance(nulls_first, bool):
+            nulls_first = [nulls_first] * len(sort_by_pyexprs)
+        if isinstance(collation, str):
+            collation = [collation] * len(sort_by_pyexprs)
+        builder = self._builder.sort_within_partitions(sort_by_pyexprs, descending, nulls_first, collation)
         return LogicalPlanBuilder(builder)
 
     def hash_repartition(self, num_partitions: int | None, partition_by: list[Expression]) -> LogicalPlanBuilder:
@@ -233,6 +302,18 @@ class LogicalPlanBuilder:
         builder = self._builder.hash_repartition(partition_by_pyexprs, num_partitions=num_partitions)
         return LogicalPlanBuilder(builder)
 
+    def range_repartition(
+        self,
+        num_partitions: int | None,
+        partition_by: list[Expression],
+        descending: list[bool] | bool = False,
+    ) -> LogicalPlanBuilder:
+        partition_by_pyexprs = [expr._expr for expr in partition_by]
+        if not isinstance(descending, list):
+            descending = [descending] * len(partition_by_pyexprs)
+        builder = self._builder.range_repartition(partition_by_pyexprs, descending, num_partitions=num_partitions)
+        return LogicalPlanBuilder(builder)
+
     def random_shuffle(self, num_partitions: int | None) -> LogicalPlanBuilder:
         builder = self._builder.random_shuffle(num_partitions)
         return LogicalPlanBuilder(builder)
@@ -323,11 +404,29 @@ class LogicalPlanBuilder:
         io_config: IOConfig,
         partition_cols: list[Expression] | None = None,
         compression: str | None = None,
+        job_id: str | None = None,
+        bloom_filter_columns: list[str] | None = None,
+        row_group_size_bytes: int | None = None,
+        encryption: ParquetEncryptionConfig | None = None,
     ) -> LogicalPlanBuilder:
-        if file_format != FileFormat.Csv and file_format != FileFormat.Parquet:
-            raise ValueError(f"Writing is only supported for Parquet and CSV file formats, but got: {file_format}")
+        if file_format not in (FileFormat.Csv, FileFormat.Parquet, FileFormat.ArrowIpc):
+            raise ValueError(
+                f"Writing is only supported for Parquet, CSV and Arrow IPC file formats, but got: {file_format}"
+            )
+        if encryption is not None and file_format != FileFormat.Parquet:
+            raise ValueError(f"Encryption is only supported for Parquet writes, but got: {file_format}")
         part_cols_pyexprs = [expr._expr for expr in partition_cols] if partition_cols is not None else None
-        builder = self._builder.table_write(str(root_dir), file_format, part_cols_pyexprs, compression, io_config)
+        builder = self._builder.table_write(
+            str(root_dir),
+            file_format,
+            part_cols_pyexprs,
+            compression,
+            io_config,
+            job_id,
+            bloom_filter_columns,
+            row_group_size_bytes,
+            encryption,
+        )
         return LogicalPlanBuilder(builder)
 
     def write_iceberg(self, table: IcebergTable, io_config: IOConfig) -> LogicalPlanBuilder:
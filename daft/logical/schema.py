@@ -16,6 +16,12 @@ if TYPE_CHECKING:
     from daft.io import IOConfig
 
 
+def _decode_arrow_metadata(metadata: dict[bytes, bytes] | None) -> dict[str, str] | None:
+    if not metadata:
+        return None
+    return {k.decode("utf-8", errors="replace"): v.decode("utf-8", errors="replace") for k, v in metadata.items()}
+
+
 class Field:
     _field: _PyField
 
@@ -29,8 +35,8 @@ class Field:
         return f
 
     @staticmethod
-    def create(name: str, dtype: DataType) -> Field:
-        pyfield = _PyField.create(name, dtype._dtype)
+    def create(name: str, dtype: DataType, metadata: dict[str, str] | None = None) -> Field:
+        pyfield = _PyField.create(name, dtype._dtype, metadata)
         return Field._from_pyfield(pyfield)
 
     @property
@@ -41,6 +47,11 @@ class Field:
     def dtype(self) -> DataType:
         return DataType._from_pydatatype(self._field.dtype())
 
+    @property
+    def metadata(self) -> dict[str, str]:
+        """The Arrow field metadata of this field, such as units or semantic tags, which Daft carries along."""
+        return self._field.metadata()
+
     def __eq__(self, other: object) -> bool:
         if not isinstance(other, Field):
             return False
@@ -72,8 +83,15 @@ class Schema:
         Returns:
             Schema: Converted Daft schema
         """
-        return cls._from_field_name_and_types(
-            [(pa_field.name, DataType.from_arrow_type(pa_field.type)) for pa_field in pa_schema]
+        return cls._from_fields(
+            [
+                Field.create(
+                    pa_field.name,
+                    DataType.from_arrow_type(pa_field.type),
+                    _decode_arrow_metadata(pa_field.metadata),
+                )
+                for pa_field in pa_schema
+            ]
         )
 
     def to_pyarrow_schema(self) -> pa.Schema:
@@ -157,6 +175,16 @@ class Schema:
 
         return Schema._from_pyschema(self._schema.union(other._schema))
 
+    def validate_matches(self, expected: Schema) -> None:
+        """Raises an error describing every difference between this schema and `expected`.
+
+        Columns must match by name, type and order.
+        """
+        if not isinstance(expected, Schema):
+            raise ValueError(f"Expected Schema, got expected: {type(expected)}")
+
+        self._schema.validate_matches(expected._schema)
+
     def __reduce__(self) -> tuple:
         return Schema._from_pyschema, (self._schema,)
 
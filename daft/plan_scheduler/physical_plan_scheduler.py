@@ -43,6 +43,8 @@ class PhysicalPlanScheduler:
             return self._scheduler.repr_ascii(simple)
         elif format == "mermaid":
             return self._scheduler.repr_mermaid(MermaidOptions(simple))
+        elif format == "json":
+            return self._scheduler.repr_json(simple)
         else:
             raise ValueError(f"Unknown format: {format}")
 
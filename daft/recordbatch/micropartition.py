@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 import logging
-from typing import TYPE_CHECKING, Any
+from typing import TYPE_CHECKING, Any, Literal
 
 from daft.daft import (
     CsvConvertOptions,
@@ -17,7 +17,7 @@ from daft.daft import (
 from daft.daft import PyMicroPartition as _PyMicroPartition
 from daft.daft import PyRecordBatch as _PyRecordBatch
 from daft.daft import ScanTask as _ScanTask
-from daft.datatype import DataType, TimeUnit
+from daft.datatype import TimeUnit
 from daft.expressions import Expression, ExpressionsProjection
 from daft.logical.schema import Schema
 from daft.recordbatch.recordbatch import RecordBatch
@@ -94,10 +94,15 @@ class MicroPartition:
 
     @staticmethod
     def from_arrow_record_batches(rbs: list[pa.RecordBatch], arrow_schema: pa.Schema) -> MicroPartition:
-        schema = Schema._from_field_name_and_types([(f.name, DataType.from_arrow_type(f.type)) for f in arrow_schema])
+        schema = Schema.from_pyarrow_schema(arrow_schema)
         pyt = _PyMicroPartition.from_arrow_record_batches(rbs, schema._schema)
         return MicroPartition._from_pymicropartition(pyt)
 
+    @staticmethod
+    def from_arrow_c_stream(obj: Any) -> MicroPartition:
+        table = RecordBatch.from_arrow_c_stream(obj)
+        return MicroPartition._from_tables([table])
+
     @staticmethod
     def from_pandas(pd_df: pd.DataFrame) -> MicroPartition:
         table = RecordBatch.from_pandas(pd_df)
@@ -144,10 +149,14 @@ class MicroPartition:
         self,
         schema: Schema | None = None,
         coerce_temporal_nanoseconds: bool = False,
+        date_as_object: bool = True,
+        use_nullable_dtypes: bool = False,
     ) -> pd.DataFrame:
         return self.to_table().to_pandas(
             schema=schema,
             coerce_temporal_nanoseconds=coerce_temporal_nanoseconds,
+            date_as_object=date_as_object,
+            use_nullable_dtypes=use_nullable_dtypes,
         )
 
     ###
@@ -374,6 +383,23 @@ class MicroPartition:
             self._micropartition.add_monotonically_increasing_id(partition_num, column_name)
         )
 
+    def window(
+        self,
+        window_functions: ExpressionsProjection,
+        partition_by: ExpressionsProjection,
+        order_by: ExpressionsProjection,
+        null_strategy: Literal["smallest", "largest", "skip"] = "largest",
+    ) -> MicroPartition:
+        """Appends the results of window functions, which must be sorted by `partition_by` and then `order_by`."""
+        return MicroPartition._from_pymicropartition(
+            self._micropartition.window(
+                [e._expr for e in window_functions],
+                [e._expr for e in partition_by],
+                [e._expr for e in order_by],
+                null_strategy,
+            )
+        )
+
     ###
     # Compute methods (MicroPartition -> Series)
     ###
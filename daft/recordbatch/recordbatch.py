@@ -84,9 +84,7 @@ class RecordBatch:
     @staticmethod
     def from_arrow(arrow_table: pa.Table) -> RecordBatch:
         assert isinstance(arrow_table, pa.Table)
-        schema = Schema._from_field_name_and_types(
-            [(f.name, DataType.from_arrow_type(f.type)) for f in arrow_table.schema]
-        )
+        schema = Schema.from_pyarrow_schema(arrow_table.schema)
         non_native_fields = [
             field.name
             for field in schema
@@ -109,10 +107,15 @@ class RecordBatch:
 
     @staticmethod
     def from_arrow_record_batches(rbs: list[pa.RecordBatch], arrow_schema: pa.Schema) -> RecordBatch:
-        schema = Schema._from_field_name_and_types([(f.name, DataType.from_arrow_type(f.type)) for f in arrow_schema])
+        schema = Schema.from_pyarrow_schema(arrow_schema)
         pyt = _PyRecordBatch.from_arrow_record_batches(rbs, schema._schema)
         return RecordBatch._from_pytable(pyt)
 
+    @staticmethod
+    def from_arrow_c_stream(obj: Any) -> RecordBatch:
+        """Creates a RecordBatch from an object implementing the Arrow PyCapsule Interface, e.g. a polars DataFrame."""
+        return RecordBatch._from_pytable(_PyRecordBatch.from_arrow_c_stream(obj))
+
     @staticmethod
     def from_pandas(pd_df: pd.DataFrame) -> RecordBatch:
         if not pd.module_available():
@@ -162,7 +165,16 @@ class RecordBatch:
 
     def to_arrow(self) -> pa.Table:
         tab = pa.Table.from_pydict({colname: self.get_column(colname).to_arrow() for colname in self.column_names()})
-        return tab
+        # Attach the metadata that the columns carried through Daft, such as units or extension names.
+        fields = [
+            pa_field.with_metadata(field.metadata) if field.metadata else pa_field
+            for pa_field, field in zip(tab.schema, self.schema())
+        ]
+        return pa.Table.from_arrays(tab.columns, schema=pa.schema(fields))
+
+    def __arrow_c_stream__(self, requested_schema: object | None = None) -> object:
+        """Exports the RecordBatch through the Arrow PyCapsule Interface, e.g. for ``polars.DataFrame(...)``."""
+        return self._table.__arrow_c_stream__(requested_schema)
 
     def to_pydict(self) -> dict[str, list]:
         return {colname: self.get_column(colname).to_pylist() for colname in self.column_names()}
@@ -178,12 +190,21 @@ class RecordBatch:
         self,
         schema: Schema | None = None,
         coerce_temporal_nanoseconds: bool = False,
+        date_as_object: bool = True,
+        use_nullable_dtypes: bool = False,
     ) -> pd.DataFrame:
         from packaging.version import parse
 
         if not pd.module_available():
             raise ImportError("Unable to import Pandas - please ensure that it is installed.")
 
+        # Arrow-native columns are handed to pyarrow's (zero-copy where possible) conversion.
+        to_pandas_kwargs: dict[str, Any] = {"date_as_object": date_as_object}
+        if parse(pa.__version__) >= parse("13.0.0"):
+            to_pandas_kwargs["coerce_temporal_nanoseconds"] = coerce_temporal_nanoseconds
+        if use_nullable_dtypes:
+            to_pandas_kwargs["types_mapper"] = _pandas_nullable_dtype_mapping().get
+
         python_fields = set()
         tensor_fields = set()
         for field in self.schema():
@@ -201,20 +222,12 @@ class RecordBatch:
                     column = column_series.to_pylist()
                 else:
                     # Arrow-native field, so provide column as Arrow array.
-                    column_arrow = column_series.to_arrow()
-                    if parse(pa.__version__) < parse("13.0.0"):
-                        column = column_arrow.to_pandas()
-                    else:
-                        column = column_arrow.to_pandas(coerce_temporal_nanoseconds=coerce_temporal_nanoseconds)
+                    column = column_series.to_arrow().to_pandas(**to_pandas_kwargs)
                 table[colname] = column
 
             return pd.DataFrame.from_dict(table)
         else:
-            arrow_table = self.to_arrow()
-            if parse(pa.__version__) < parse("13.0.0"):
-                return arrow_table.to_pandas()
-            else:
-                return arrow_table.to_pandas(coerce_temporal_nanoseconds=coerce_temporal_nanoseconds)
+            return self.to_arrow().to_pandas(**to_pandas_kwargs)
 
     ###
     # Compute methods (Table -> Table)
@@ -557,6 +570,25 @@ class RecordBatch:
         )
 
 
+def _pandas_nullable_dtype_mapping() -> dict[pa.DataType, pd.api.extensions.ExtensionDtype]:
+    """Maps Arrow types to pandas' nullable extension dtypes, so that nulls don't force a cast to float/object."""
+    return {
+        pa.int8(): pd.Int8Dtype(),
+        pa.int16(): pd.Int16Dtype(),
+        pa.int32(): pd.Int32Dtype(),
+        pa.int64(): pd.Int64Dtype(),
+        pa.uint8(): pd.UInt8Dtype(),
+        pa.uint16(): pd.UInt16Dtype(),
+        pa.uint32(): pd.UInt32Dtype(),
+        pa.uint64(): pd.UInt64Dtype(),
+        pa.float32(): pd.Float32Dtype(),
+        pa.float64(): pd.Float64Dtype(),
+        pa.bool_(): pd.BooleanDtype(),
+        pa.string(): pd.StringDtype(),
+        pa.large_string(): pd.StringDtype(),
+    }
+
+
 def read_parquet_into_pyarrow(
     path: str,
     columns: list[str] | None = None,
@@ -34,9 +34,11 @@ from daft.runners.partitioning import (
 )
 from daft.series import Series
 from daft.sql.sql_connection import SQLConnection
+from daft.utils import parquet_bloom_filter_options
 
 from .micropartition import MicroPartition
 from .partitioning import PartitionedTable, partition_strings_to_path
+from .recordbatch import RecordBatch
 
 FileInput = Union[pathlib.Path, str]
 
@@ -47,6 +49,7 @@ if TYPE_CHECKING:
     from pyiceberg.table import TableProperties as IcebergTableProperties
 
     from daft.expressions.expressions import Expression
+    from daft.io.parquet_encryption import ParquetEncryptionConfig
     from daft.sql.sql_connection import SQLConnection
 
 
@@ -286,6 +289,10 @@ def write_tabular(
     partition_cols: ExpressionsProjection | None = None,
     compression: str | None = None,
     io_config: IOConfig | None = None,
+    job_id: str | None = None,
+    bloom_filter_columns: list[str] | None = None,
+    row_group_size_bytes: int | None = None,
+    encryption: ParquetEncryptionConfig | None = None,
 ) -> MicroPartition:
     [resolved_path], fs = _resolve_paths_and_filesystem(path, io_config=io_config)
     if isinstance(path, pathlib.Path):
@@ -300,19 +307,40 @@ def write_tabular(
 
     execution_config = get_context().daft_execution_config
 
-    TARGET_ROW_GROUP_SIZE = execution_config.parquet_target_row_group_size
+    TARGET_ROW_GROUP_SIZE = (
+        execution_config.parquet_target_row_group_size if row_group_size_bytes is None else row_group_size_bytes
+    )
 
     if file_format == FileFormat.Parquet:
         format = pads.ParquetFileFormat()
         inflation_factor = execution_config.parquet_inflation_factor
         target_file_size = execution_config.parquet_target_filesize
-        opts = format.make_write_options(compression=compression, use_compliant_nested_type=False)
+        encryption_opts = {} if encryption is None else {"encryption_config": encryption.dataset_write_options()}
+        opts = format.make_write_options(
+            compression=compression,
+            use_compliant_nested_type=False,
+            **encryption_opts,
+            **parquet_bloom_filter_options(bloom_filter_columns),
+        )
     elif file_format == FileFormat.Csv:
         format = pads.CsvFileFormat()
         opts = None
         assert compression is None
         inflation_factor = execution_config.csv_inflation_factor
         target_file_size = execution_config.csv_target_filesize
+    elif file_format == FileFormat.Json:
+        # PyArrow datasets can't write JSON, so each partition is written as a single JSON Lines file instead.
+        format = None
+        opts = None
+        assert compression is None
+        inflation_factor = execution_config.csv_inflation_factor
+        target_file_size = execution_config.csv_target_filesize
+    elif file_format == FileFormat.ArrowIpc:
+        format = pads.IpcFileFormat()
+        opts = format.make_write_options(compression=compression)
+        # Arrow IPC files hold record batches as they are laid out in memory, so they aren't inflated when read.
+        inflation_factor = 1.0
+        target_file_size = execution_config.parquet_target_filesize
     else:
         raise ValueError(f"Unsupported file format {file_format}")
 
@@ -321,6 +349,11 @@ def write_tabular(
     visitors = TabularWriteVisitors(partitioned.partition_values(), schema)
 
     for i, (part_table, part_path) in enumerate(partitioned_table_to_hive_iter(partitioned, resolved_path)):
+        if format is None:
+            visitors.paths.append(_write_json_lines_arrow_table(part_table, part_path, fs, is_local_fs, job_id))
+            visitors.partition_indices.append(i)
+            continue
+
         size_bytes = part_table.nbytes
 
         target_num_files = max(math.ceil(size_bytes / target_file_size / inflation_factor), 1)
@@ -342,6 +375,7 @@ def write_tabular(
             rows_per_row_group=rows_per_row_group,
             create_dir=is_local_fs,
             file_visitor=visitors.visitor(i),
+            job_id=job_id,
         )
 
     return visitors.to_metadata()
@@ -532,11 +566,13 @@ def _retry_with_backoff(
                 raise
 
 
-def _generate_basename_template(ext: str, version: int | None = None) -> str:
+def _generate_basename_template(ext: str, version: int | None = None, job_id: str | None = None) -> str:
+    template = f"{uuid4()}-{{i}}.{ext}"
+    if job_id is not None:
+        template = f"{job_id}-{template}"
     if version is not None:
-        return f"{version}-{uuid4()}-{{i}}.{ext}"
-    else:
-        return f"{uuid4()}-{{i}}.{ext}"
+        template = f"{version}-{template}"
+    return template
 
 
 def _write_tabular_arrow_table(
@@ -551,6 +587,7 @@ def _write_tabular_arrow_table(
     create_dir: bool,
     file_visitor: Callable | None,
     version: int | None = None,
+    job_id: str | None = None,
 ):
     kwargs = dict()
 
@@ -561,7 +598,7 @@ def _write_tabular_arrow_table(
     if not create_dir:
         kwargs["create_dir"] = False
 
-    basename_template = _generate_basename_template(format.default_extname, version)
+    basename_template = _generate_basename_template(format.default_extname, version, job_id)
 
     def write_dataset():
         pads.write_dataset(
@@ -590,12 +627,40 @@ def _write_tabular_arrow_table(
     )
 
 
+def _write_json_lines_arrow_table(
+    arrow_table: pa.Table,
+    full_path: str,
+    fs: Any,
+    create_dir: bool,
+    job_id: str | None = None,
+) -> str:
+    from daft.io.writer import _encode_json_lines
+
+    if create_dir:
+        fs.create_dir(full_path, recursive=True)
+    file_path = f"{full_path}/{_generate_basename_template(FileFormat.Json.ext(), job_id=job_id).format(i=0)}"
+
+    def write_file():
+        with fs.open_output_stream(file_path) as output_file:
+            output_file.write(_encode_json_lines(RecordBatch.from_arrow(arrow_table)))
+
+    def retry_error(e: Exception) -> bool:
+        ERROR_MSGS = ("InvalidPart", "curlCode: 28, Timeout was reached")
+        return isinstance(e, OSError) and any(err_str in str(e) for err_str in ERROR_MSGS)
+
+    _retry_with_backoff(write_file, file_path, retry_error=retry_error)
+    return file_path
+
+
 def write_empty_tabular(
     path: str | pathlib.Path,
     file_format: FileFormat,
     schema: Schema,
     compression: str | None = None,
     io_config: IOConfig | None = None,
+    job_id: str | None = None,
+    bloom_filter_columns: list[str] | None = None,
+    encryption: ParquetEncryptionConfig | None = None,
 ) -> str:
     table = pa.Table.from_pylist([], schema=schema.to_pyarrow_schema())
 
@@ -604,7 +669,7 @@ def write_empty_tabular(
     if is_local_fs:
         fs.create_dir(resolved_path, recursive=True)
 
-    basename_template = _generate_basename_template(file_format.ext())
+    basename_template = _generate_basename_template(file_format.ext(), job_id=job_id)
     file_path = f"{resolved_path}/{basename_template.format(i=0)}"
 
     def write_table():
@@ -615,10 +680,20 @@ def write_empty_tabular(
                 compression=compression,
                 use_compliant_nested_type=False,
                 filesystem=fs,
+                encryption_properties=None if encryption is None else encryption.file_encryption_properties(),
+                **parquet_bloom_filter_options(bloom_filter_columns),
             )
         elif file_format == FileFormat.Csv:
             output_file = fs.open_output_stream(file_path)
             pacsv.write_csv(table, output_file)
+        elif file_format == FileFormat.Json:
+            # JSON Lines has no header, so a file with no rows is empty.
+            fs.open_output_stream(file_path).close()
+        elif file_format == FileFormat.ArrowIpc:
+            options = pa.ipc.IpcWriteOptions(compression=compression)
+            with fs.open_output_stream(file_path) as output_file:
+                with pa.ipc.new_file(output_file, table.schema, options=options) as writer:
+                    writer.write_table(table)
         else:
             raise ValueError(f"Unsupported file format {file_format}")
 
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import logging
+import warnings
 from typing import TYPE_CHECKING, Iterator
 
 from daft.context import get_context
@@ -19,6 +20,7 @@ from daft.runners.runner import LOCAL_PARTITION_SET_CACHE, Runner
 from daft.scarf_telemetry import scarf_telemetry
 
 if TYPE_CHECKING:
+    from daft.daft import PyDaftExecutionConfig
     from daft.logical.builder import LogicalPlanBuilder
 
 logger = logging.getLogger(__name__)
@@ -75,17 +77,32 @@ class NativeRunner(Runner[MicroPartition]):
 
         # NOTE: Freeze and use this same execution config for the entire execution
         daft_execution_config = get_context().daft_execution_config
+        if daft_execution_config.enable_speculative_execution:
+            warnings.warn(
+                "Speculative execution is only supported on the Python Runner, "
+                f"and is ignored by the {self.name} runner"
+            )
 
         # Optimize the logical plan.
         builder = builder.optimize()
+        results_gen = self._execute_plan(builder, daft_execution_config, results_buffer_size)
+        if daft_execution_config.enable_result_cache:
+            results_gen = self._query_result_cache.get_or_run(builder, results_gen)
+        yield from results_gen
+
+    def _execute_plan(
+        self,
+        builder: LogicalPlanBuilder,
+        daft_execution_config: PyDaftExecutionConfig,
+        results_buffer_size: int | None,
+    ) -> Iterator[LocalMaterializedResult]:
         executor = NativeExecutor()
-        results_gen = executor.run(
+        yield from executor.run(
             builder,
             {k: v.values() for k, v in self._part_set_cache.get_all_partition_sets().items()},
             daft_execution_config,
             results_buffer_size,
         )
-        yield from results_gen
 
     def run_iter_tables(
         self, builder: LogicalPlanBuilder, results_buffer_size: int | None = None
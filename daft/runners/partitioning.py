@@ -218,11 +218,15 @@ class PartitionSet(Generic[PartitionT]):
         self,
         schema: Schema | None = None,
         coerce_temporal_nanoseconds: bool = False,
+        date_as_object: bool = True,
+        use_nullable_dtypes: bool = False,
     ) -> pd.DataFrame:
         merged_partition = self._get_merged_micropartition()
         return merged_partition.to_pandas(
             schema=schema,
             coerce_temporal_nanoseconds=coerce_temporal_nanoseconds,
+            date_as_object=date_as_object,
+            use_nullable_dtypes=use_nullable_dtypes,
         )
 
     def to_arrow(self) -> pa.Table:
@@ -3,7 +3,9 @@ from __future__ import annotations
 import contextlib
 import logging
 import multiprocessing as mp
+import statistics
 import threading
+import time
 import uuid
 import warnings
 from concurrent import futures
@@ -11,7 +13,7 @@ from dataclasses import dataclass
 from typing import TYPE_CHECKING, Callable, Iterator
 
 from daft.context import get_context
-from daft.daft import FileFormatConfig, FileInfos, IOConfig, ResourceRequest, SystemInfo
+from daft.daft import FileFormatConfig, FileInfos, IOConfig, PyDaftExecutionConfig, ResourceRequest, SystemInfo
 from daft.execution.native_executor import NativeExecutor
 from daft.execution.physical_plan import ActorPoolManager
 from daft.expressions import ExpressionsProjection
@@ -165,6 +167,76 @@ class PyRunnerResources:
                     self.available_resources.gpus[gpu] += amount
 
 
+class SpeculativeExecutionTracker:
+    """Tracks the runtimes of tasks to find stragglers that are worth re-launching.
+
+    A task is a straggler once it has been running for longer than `multiplier` times the median runtime of the tasks
+    that have completed so far.
+    """
+
+    # Don't speculate until enough tasks have completed for their median runtime to be meaningful.
+    MIN_COMPLETED_TASKS = 3
+    # How often to check for stragglers while waiting on tasks.
+    CHECK_INTERVAL_SECONDS = 0.5
+
+    def __init__(self, multiplier: float):
+        self._multiplier = multiplier
+        self._start_times: dict[TaskID, float] = {}
+        self._durations: list[float] = []
+        self._speculated: set[TaskID] = set()
+        self._cancel_events: dict[TaskID, threading.Event] = {}
+
+    def task_started(self, task_id: TaskID) -> threading.Event:
+        """Starts timing a task, returning the event that its copies are cancelled with once one of them finishes."""
+        self._start_times[task_id] = time.monotonic()
+        return self._cancel_events.setdefault(task_id, threading.Event())
+
+    def task_finished(self, task_id: TaskID) -> None:
+        start_time = self._start_times.pop(task_id, None)
+        if start_time is not None:
+            self._durations.append(time.monotonic() - start_time)
+        # Stop the other copy of the task, if it was speculated.
+        cancel_event = self._cancel_events.pop(task_id, None)
+        if cancel_event is not None:
+            cancel_event.set()
+
+    def cancel_event(self, task_id: TaskID) -> threading.Event | None:
+        return self._cancel_events.get(task_id)
+
+    def cancel_all(self) -> None:
+        for cancel_event in self._cancel_events.values():
+            cancel_event.set()
+        self._cancel_events.clear()
+
+    def mark_speculated(self, task_id: TaskID) -> None:
+        self._speculated.add(task_id)
+
+    def stragglers(self) -> list[TaskID]:
+        if len(self._durations) < self.MIN_COMPLETED_TASKS:
+            return []
+        threshold = statistics.median(self._durations) * self._multiplier
+        now = time.monotonic()
+        return [
+            task_id
+            for task_id, start_time in self._start_times.items()
+            if task_id not in self._speculated and now - start_time > threshold
+        ]
+
+
+class TaskCancelledError(Exception):
+    """Raised in a copy of a task that was cancelled because another copy of it finished first."""
+
+
+def _can_speculate(task: PartitionTask) -> bool:
+    """Whether it's safe to run a second copy of the task, i.e. it has no side effects."""
+    from daft.execution.execution_step import WriteDeltaLake, WriteFile, WriteIceberg, WriteLance
+
+    return task.actor_pool_id is None and not any(
+        isinstance(instruction, (WriteFile, WriteIceberg, WriteDeltaLake, WriteLance))
+        for instruction in task.instructions
+    )
+
+
 class PyActorSingleton:
     """This class stores the singleton `initialized_projection` that is isolated to each Python process. It stores the projection with initialized actor pool UDF objects of a single actor.
 
@@ -354,7 +426,18 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
 
         # Optimize the logical plan.
         builder = builder.optimize()
+        results_gen = self._execute_plan(builder, daft_execution_config, execution_id, results_buffer_size)
+        if daft_execution_config.enable_result_cache:
+            results_gen = self._query_result_cache.get_or_run(builder, results_gen)
+        yield from results_gen
 
+    def _execute_plan(
+        self,
+        builder: LogicalPlanBuilder,
+        daft_execution_config: PyDaftExecutionConfig,
+        execution_id: str,
+        results_buffer_size: int | None,
+    ) -> Iterator[LocalMaterializedResult]:
         if daft_execution_config.enable_aqe:
             adaptive_planner = builder.to_adaptive_physical_plan_scheduler(daft_execution_config)
             while not adaptive_planner.is_done():
@@ -366,7 +449,7 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
                     results_buffer_size,
                 )
                 del plan_scheduler
-                results_gen = self._physical_plan_to_partitions(execution_id, tasks)
+                results_gen = self._physical_plan_to_partitions(execution_id, tasks, daft_execution_config)
                 # if source_id is none that means this is the final stage
                 if source_id is None:
                     yield from results_gen
@@ -401,7 +484,7 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
                 tasks = plan_scheduler.to_partition_tasks(psets, self, results_buffer_size)
                 del psets
                 with profiler("profile_PyRunner.run_{datetime.now().isoformat()}.json"):
-                    results_gen = self._physical_plan_to_partitions(execution_id, tasks)
+                    results_gen = self._physical_plan_to_partitions(execution_id, tasks, daft_execution_config)
                     yield from results_gen
 
     def run_iter_tables(
@@ -453,13 +536,66 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
         """This higher order function is used so that the `resources` released by the callback are from the ones stored in the variable at the creation of the callback instead of during its call."""
         return lambda _: self._resources.release(resources)
 
+    def _submit_task(
+        self, task: PartitionTask, cancel_event: threading.Event | None = None
+    ) -> futures.Future[list[MaterializedResult[MicroPartition]]]:
+        if task.actor_pool_id is None:
+            return self._thread_pool.submit(
+                self.build_partitions,
+                task.instructions,
+                task.inputs,
+                task.partial_metadatas,
+                cancel_event,
+            )
+        actor_pool = self._actor_pools.get(task.actor_pool_id)
+        assert (
+            actor_pool is not None
+        ), f"PyActorPool={task.actor_pool_id} must outlive the tasks that need to be run on it."
+        return actor_pool.submit(
+            task.instructions,
+            task.inputs,
+            task.partial_metadatas,
+        )
+
+    def _launch_speculative_tasks(
+        self,
+        execution_id: str,
+        tracker: SpeculativeExecutionTracker,
+        local_futures_to_task: dict[futures.Future, PartitionTask],
+    ) -> None:
+        inflight_tasks = {task.id(): task for task in local_futures_to_task.values()}
+        for task_id in tracker.stragglers():
+            task = inflight_tasks.get(task_id)
+            if task is None or not _can_speculate(task):
+                tracker.mark_speculated(task_id)
+                continue
+
+            resources = self._resources.try_acquire(task.resource_request)
+            if resources is None:
+                # Try again once some tasks have released their resources.
+                break
+
+            logger.debug("execution[%s] Launching speculative copy of straggler task: %s", execution_id, task_id)
+            future = self._submit_task(task, tracker.cancel_event(task_id))
+            future.add_done_callback(self._create_resource_release_callback(resources))
+            self._inflight_futures[(execution_id, f"{task_id}-speculative")] = future
+            local_futures_to_task[future] = task
+            tracker.mark_speculated(task_id)
+
     def _physical_plan_to_partitions(
         self,
         execution_id: str,
         plan: physical_plan.MaterializedPhysicalPlan[MicroPartition],
+        daft_execution_config: PyDaftExecutionConfig,
     ) -> Iterator[LocalMaterializedResult]:
         local_futures_to_task: dict[futures.Future, PartitionTask] = {}
         pbar = ProgressBar(use_ray_tqdm=False)
+        tracker = (
+            SpeculativeExecutionTracker(daft_execution_config.speculative_execution_multiplier)
+            if daft_execution_config.enable_speculative_execution
+            else None
+        )
+        wait_timeout = tracker.CHECK_INTERVAL_SECONDS if tracker is not None else None
 
         try:
             next_step = next(plan)
@@ -532,23 +668,8 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
                             # update progress bar
                             pbar.mark_task_start(next_step)
 
-                            if next_step.actor_pool_id is None:
-                                future = self._thread_pool.submit(
-                                    self.build_partitions,
-                                    next_step.instructions,
-                                    next_step.inputs,
-                                    next_step.partial_metadatas,
-                                )
-                            else:
-                                actor_pool = self._actor_pools.get(next_step.actor_pool_id)
-                                assert (
-                                    actor_pool is not None
-                                ), f"PyActorPool={next_step.actor_pool_id} must outlive the tasks that need to be run on it."
-                                future = actor_pool.submit(
-                                    next_step.instructions,
-                                    next_step.inputs,
-                                    next_step.partial_metadatas,
-                                )
+                            cancel_event = tracker.task_started(next_step.id()) if tracker is not None else None
+                            future = self._submit_task(next_step, cancel_event)
 
                             future.add_done_callback(self._create_resource_release_callback(resources))
 
@@ -567,16 +688,32 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
                     )
 
                 # Await at least one task in the global futures to finish before proceeding
-                _ = futures.wait(list(self._inflight_futures.values()), return_when=futures.FIRST_COMPLETED)
+                _ = futures.wait(
+                    list(self._inflight_futures.values()), timeout=wait_timeout, return_when=futures.FIRST_COMPLETED
+                )
 
                 # Now await at a task in the local futures to finish, so as to progress the local execution
-                done_set, _ = futures.wait(list(local_futures_to_task), return_when=futures.FIRST_COMPLETED)
+                done_set, _ = futures.wait(
+                    list(local_futures_to_task), timeout=wait_timeout, return_when=futures.FIRST_COMPLETED
+                )
                 for done_future in done_set:
-                    done_task = local_futures_to_task.pop(done_future)
+                    done_task = local_futures_to_task.pop(done_future, None)
+                    if done_task is None:
+                        # Another copy of this task already finished in this batch.
+                        continue
                     materialized_results = done_future.result()
 
                     pbar.mark_task_done(done_task)
-                    del self._inflight_futures[(execution_id, done_task.id())]
+                    self._inflight_futures.pop((execution_id, done_task.id()), None)
+                    if tracker is not None:
+                        tracker.task_finished(done_task.id())
+                        # Take whichever copy of a speculated task finished first and drop the other one. Finishing the
+                        # task sets its cancel event, so the other copy stops before its next instruction if it's
+                        # already running; its resources are released once it has stopped.
+                        self._inflight_futures.pop((execution_id, f"{done_task.id()}-speculative"), None)
+                        for other_future in [f for f, t in local_futures_to_task.items() if t is done_task]:
+                            other_future.cancel()
+                            del local_futures_to_task[other_future]
 
                     logger.debug(
                         "execution[%s] Task completed: %s -> <%s partitions>",
@@ -588,6 +725,9 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
                     done_task.set_result(materialized_results)
                     done_task.set_done()
 
+                if tracker is not None:
+                    self._launch_speculative_tasks(execution_id, tracker, local_futures_to_task)
+
                 if next_step is None:
                     next_step = next(plan)
 
@@ -600,6 +740,10 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
             # Close the progress bar
             pbar.close()
 
+            # Stop any tasks that are still running, e.g. because the results are no longer needed.
+            if tracker is not None:
+                tracker.cancel_all()
+
             # Cleanup any remaining inflight futures/results from this local execution
             for (exec_id, task_id), _ in list(self._inflight_futures.items()):
                 if exec_id == execution_id:
@@ -610,8 +754,11 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
         instruction_stack: list[Instruction],
         partitions: list[MicroPartition],
         final_metadata: list[PartialPartitionMetadata],
+        cancel_event: threading.Event | None = None,
     ) -> list[MaterializedResult[MicroPartition]]:
         for instruction in instruction_stack:
+            if cancel_event is not None and cancel_event.is_set():
+                raise TaskCancelledError("Another copy of the task finished first")
             partitions = instruction.run(partitions)
 
         results: list[MaterializedResult[MicroPartition]] = [
@@ -7,6 +7,7 @@ import os
 import threading
 import time
 import uuid
+import warnings
 from datetime import datetime
 from queue import Full, Queue
 from typing import TYPE_CHECKING, Any, Generator, Iterable, Iterator
@@ -1280,6 +1281,11 @@ class RayRunner(Runner[ray.ObjectRef]):
 
         # Grab and freeze the current DaftExecutionConfig
         daft_execution_config = get_context().daft_execution_config
+        if daft_execution_config.enable_speculative_execution:
+            warnings.warn(
+                "Speculative execution is only supported on the Python Runner, "
+                f"and is ignored by the {self.name} runner"
+            )
 
         # Optimize the logical plan.
         builder = builder.optimize()
@@ -0,0 +1,129 @@
+from __future__ import annotations
+
+import logging
+import threading
+from collections import OrderedDict
+from typing import TYPE_CHECKING, Generic, Iterator
+
+from daft.runners.partitioning import MaterializedResult, PartitionT
+
+if TYPE_CHECKING:
+    from daft.logical.builder import LogicalPlanBuilder
+
+logger = logging.getLogger(__name__)
+
+
+class _PlanKey:
+    """Identifies a query by its optimized logical plan, using the plan's fingerprint only to find candidates."""
+
+    __slots__ = ("_builder", "_fingerprint")
+
+    def __init__(self, builder: LogicalPlanBuilder) -> None:
+        self._builder = builder
+        self._fingerprint = builder.fingerprint()
+
+    def __hash__(self) -> int:
+        return self._fingerprint
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, _PlanKey):
+            return NotImplemented
+        return self._fingerprint == other._fingerprint and self._builder.plan_eq(other._builder)
+
+
+class QueryResultCache(Generic[PartitionT]):
+    """Results of previously executed queries, keyed by their optimized logical plan.
+
+    Results are stored with the versions of the files that the query read, such as their ETags or modification times,
+    and are only reused while the files have the same versions. Queries that write data, aren't deterministic or read
+    files whose versions can't be determined are never cached.
+    """
+
+    MAX_ENTRIES = 32
+
+    def __init__(self, max_entries: int = MAX_ENTRIES) -> None:
+        self._max_entries = max_entries
+        self._entries: OrderedDict[
+            _PlanKey, tuple[list[tuple[str, str | None]], list[MaterializedResult[PartitionT]]]
+        ] = OrderedDict()
+        self._lock = threading.Lock()
+
+    def __len__(self) -> int:
+        with self._lock:
+            return len(self._entries)
+
+    def get(
+        self, builder: LogicalPlanBuilder, file_versions: list[tuple[str, str | None]]
+    ) -> list[MaterializedResult[PartitionT]] | None:
+        """Returns the cached results of a plan if the files it reads still have the given versions."""
+        key = _PlanKey(builder)
+        with self._lock:
+            entry = self._entries.get(key)
+            if entry is None:
+                return None
+            cached_versions, results = entry
+            if cached_versions != file_versions:
+                del self._entries[key]
+                return None
+            self._entries.move_to_end(key)
+            return results
+
+    def put(
+        self,
+        builder: LogicalPlanBuilder,
+        file_versions: list[tuple[str, str | None]],
+        results: list[MaterializedResult[PartitionT]],
+    ) -> None:
+        key = _PlanKey(builder)
+        with self._lock:
+            self._entries[key] = (file_versions, results)
+            self._entries.move_to_end(key)
+            while len(self._entries) > self._max_entries:
+                self._entries.popitem(last=False)
+
+    def invalidate(self, builder: LogicalPlanBuilder) -> bool:
+        """Drops the results of a plan, returning whether there were any."""
+        with self._lock:
+            return self._entries.pop(_PlanKey(builder), None) is not None
+
+    def clear(self) -> None:
+        with self._lock:
+            self._entries.clear()
+
+    def get_or_run(
+        self, builder: LogicalPlanBuilder, results: Iterator[MaterializedResult[PartitionT]]
+    ) -> Iterator[MaterializedResult[PartitionT]]:
+        """Yields the cached results of an optimized plan, or runs it and caches its results if it's cacheable.
+
+        `results` should be lazy, e.g. a generator, so that the plan is only executed on a cache miss. Results are only
+        cached once all of them have been produced.
+        """
+        file_versions = self._file_versions(builder)
+        if file_versions is None:
+            yield from results
+            return
+
+        cached = self.get(builder, file_versions)
+        if cached is not None:
+            yield from cached
+            return
+
+        collected = []
+        for result in results:
+            collected.append(result)
+            yield result
+        self.put(builder, file_versions, collected)
+
+    @staticmethod
+    def _file_versions(builder: LogicalPlanBuilder) -> list[tuple[str, str | None]] | None:
+        """The versions of the files read by a plan, or None if its results can't be cached."""
+        if not builder.is_cacheable():
+            return None
+        try:
+            file_versions = builder.source_file_versions()
+        except Exception as e:
+            logger.debug("Not caching results of a query whose source files can't be looked up: %s", e)
+            return None
+        if any(version is None for _, version in file_versions):
+            return None
+        return file_versions
@@ -10,6 +10,7 @@ from daft.runners.partitioning import (
     PartitionSetCache,
     PartitionT,
 )
+from daft.runners.result_cache import QueryResultCache
 
 if TYPE_CHECKING:
     from daft.logical.builder import LogicalPlanBuilder
@@ -24,6 +25,7 @@ class Runner(Generic[PartitionT]):
 
     def __init__(self) -> None:
         self._part_set_cache = self.initialize_partition_set_cache()
+        self._query_result_cache: QueryResultCache[PartitionT] = QueryResultCache()
 
     def get_partition_set_from_cache(self, pset_id: str) -> PartitionCacheEntry:
         return self._part_set_cache.get_partition_set(pset_id=pset_id)
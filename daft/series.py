@@ -3,7 +3,7 @@ from __future__ import annotations
 import warnings
 from typing import Any, Literal, TypeVar
 
-from daft.arrow_utils import ensure_array, ensure_chunked_array
+from daft.arrow_utils import ensure_array, ensure_chunked_array, to_large_offsets_type
 from daft.daft import CountMode, ImageFormat, ImageMode, PySeries, image
 from daft.datatype import DataType, _ensure_registered_super_ext_type
 from daft.dependencies import np, pa, pd
@@ -51,6 +51,10 @@ class Series:
                 combined_storage_array = array.cast(arr_type.storage_type).combine_chunks()
                 combined_array = arr_type.wrap_array(combined_storage_array)
             else:
+                # Promote to 64-bit offsets before combining the chunks, so that their combined data can exceed 2GB.
+                large_offsets_type = to_large_offsets_type(arr_type)
+                if large_offsets_type != arr_type:
+                    array = array.cast(large_offsets_type)
                 combined_array = array.combine_chunks()
             return Series.from_arrow(combined_array)
         else:
@@ -322,6 +326,20 @@ class Series:
     def cbrt(self) -> Series:
         return Series._from_pyseries(self._series.cbrt())
 
+    def int_to_ip(self) -> Series:
+        return Series._from_pyseries(self._series.int_to_ip())
+
+    def format_decimal(
+        self,
+        decimal_separator: str = ".",
+        thousands_separator: str | None = ",",
+        currency_symbol: str | None = None,
+        accounting: bool = False,
+    ) -> Series:
+        return Series._from_pyseries(
+            self._series.format_decimal(decimal_separator, thousands_separator, currency_symbol, accounting)
+        )
+
     def sin(self) -> Series:
         """The elementwise sine of a numeric series."""
         return Series._from_pyseries(self._series.sin())
@@ -897,6 +915,7 @@ class SeriesStringNamespace(SeriesNamespace):
         lowercase: bool = False,
         nfd_unicode: bool = False,
         white_space: bool = False,
+        form: Literal["NFC", "NFD", "NFKC", "NFKD"] | None = None,
     ) -> Series:
         if not isinstance(remove_punct, bool):
             raise ValueError(f"expected bool for remove_punct but got {type(remove_punct)}")
@@ -907,7 +926,49 @@ class SeriesStringNamespace(SeriesNamespace):
         if not isinstance(white_space, bool):
             raise ValueError(f"expected bool for white_space but got {type(white_space)}")
         assert self._series is not None
-        return Series._from_pyseries(self._series.utf8_normalize(remove_punct, lowercase, nfd_unicode, white_space))
+        return Series._from_pyseries(
+            self._series.utf8_normalize(remove_punct, lowercase, nfd_unicode, white_space, form)
+        )
+
+    def remove_accents(self) -> Series:
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_remove_accents())
+
+    def ascii_fold(self) -> Series:
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_ascii_fold())
+
+    def is_email(self) -> Series:
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_is_email())
+
+    def is_ipv4(self) -> Series:
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_is_ipv4())
+
+    def is_ipv6(self) -> Series:
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_is_ipv6())
+
+    def is_phone_number(self) -> Series:
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_is_phone_number())
+
+    def ip_to_int(self) -> Series:
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_ip_to_int())
+
+    def extract_domain(self) -> Series:
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_extract_domain())
+
+    def parse_decimal(
+        self, precision: int, scale: int, decimal_separator: str = ".", thousands_separator: str | None = ","
+    ) -> Series:
+        assert self._series is not None
+        return Series._from_pyseries(
+            self._series.utf8_parse_decimal(precision, scale, decimal_separator, thousands_separator)
+        )
 
     def count_matches(self, patterns: Series, whole_words: bool = False, case_sensitive: bool = True) -> Series:
         if not isinstance(patterns, Series):
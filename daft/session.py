@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import Literal
+from typing import TYPE_CHECKING, Callable, Literal
 
 from daft.catalog import Catalog, Identifier, Table, TableSource
 from daft.context import get_context
@@ -8,16 +8,22 @@ from daft.daft import LogicalPlanBuilder as PyBuilder
 from daft.daft import PySession, sql_exec
 from daft.dataframe import DataFrame
 from daft.logical.builder import LogicalPlanBuilder
+from daft.udf import UDF
+
+if TYPE_CHECKING:
+    from daft.expressions import Expression
 
 __all__ = [
     "Session",
     "attach_catalog",
+    "attach_function",
     "attach_table",
     "create_temp_table",
     "current_catalog",
     "current_namespace",
     "current_session",
     "detach_catalog",
+    "detach_function",
     "detach_table",
     "get_catalog",
     "get_table",
@@ -114,6 +120,37 @@ class Session:
         a = alias if alias else c.name
         return self._session.attach_catalog(c, a)
 
+    def attach_function(self, function: Callable[..., Expression], alias: str | None = None) -> None:
+        """Attaches a temporary function to this session, so that it can be called by name in SQL.
+
+        Example:
+            >>> import daft
+            >>> @daft.udf(return_dtype=daft.DataType.int64())
+            ... def add_one(x):
+            ...     return [v + 1 for v in x.to_pylist()]
+            >>> sess = daft.Session()
+            >>> sess.attach_function(add_one)
+            >>> sess.create_temp_table("T", daft.from_pydict({"x": [1, 2, 3]}))
+            >>> sess.sql("SELECT add_one(x) AS y FROM T").to_pydict()
+            {'y': [2, 3, 4]}
+
+        Args:
+            function (Callable[..., Expression]): UDF or any function from argument expressions to an expression
+            alias (str | None): optional alias for name resolution, defaults to the function's name
+
+        Returns:
+            None
+        """
+        if alias:
+            a = alias
+        elif isinstance(function, UDF):
+            a = function.inner.__name__
+        elif hasattr(function, "__name__"):
+            a = function.__name__
+        else:
+            raise ValueError(f"Cannot attach function {function} without an alias")
+        return self._session.attach_function(function, a)
+
     def attach_table(self, table: Table | object, alias: str | None = None) -> Table:
         """Attaches an external table instance to this session.
 
@@ -139,6 +176,17 @@ class Session:
         """
         return self._session.detach_catalog(alias)
 
+    def detach_function(self, alias: str):
+        """Detaches the temporary function from this session or raises if the function does not exist.
+
+        Args:
+            alias (str): function alias to detach
+
+        Returns:
+            None
+        """
+        return self._session.detach_function(alias)
+
     def detach_table(self, alias: str):
         """Detaches the table from this session or raises if the table does not exist.
 
@@ -377,21 +425,9 @@ class Session:
 # global active session
 ###
 
-_SESSION: Session | None = None
-
 
 def _session() -> Session:
-    # Consider registering into the global context
-    # ```
-    # ctx = get_context()
-    # if not ctx._session
-    #     set_session(Session.from_env())
-    # return ctx._session
-    # ```
-    global _SESSION
-    if not _SESSION:
-        _SESSION = Session._from_env()
-    return _SESSION
+    return get_context().session
 
 
 ###
@@ -404,6 +440,11 @@ def attach_catalog(catalog: object | Catalog, alias: str | None = None) -> Catal
     return _session().attach_catalog(catalog, alias)
 
 
+def attach_function(function: Callable[..., Expression], alias: str | None = None) -> None:
+    """Attaches a temporary function to the current session."""
+    return _session().attach_function(function, alias)
+
+
 def attach_table(table: object | Table, alias: str | None = None) -> Table:
     """Attaches an external table to the current session."""
     return _session().attach_table(table, alias)
@@ -414,6 +455,11 @@ def detach_catalog(alias: str):
     return _session().detach_catalog(alias)
 
 
+def detach_function(alias: str):
+    """Detaches the temporary function from the current session."""
+    return _session().detach_function(alias)
+
+
 def detach_table(alias: str):
     """Detaches the table from the current session."""
     return _session().detach_table(alias)
@@ -548,11 +594,4 @@ def set_namespace(identifier: Identifier | str | None):
 
 def set_session(session: Session):
     """Sets the global context's current session."""
-    # Consider registering into the global context.
-    # ```
-    # ctx = get_context()
-    # with ctx._lock:
-    #     ctx._session = session
-    # ```
-    global _SESSION
-    _SESSION = session
+    get_context().session = session
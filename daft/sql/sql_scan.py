@@ -11,6 +11,7 @@ from daft.daft import (
     DatabaseSourceConfig,
     FileFormatConfig,
     Pushdowns,
+    PyExpr,
     PyRecordBatch,
     ScanTask,
     StorageConfig,
@@ -18,13 +19,13 @@ from daft.daft import (
 from daft.expressions.expressions import lit
 from daft.io.common import _get_schema_from_dict
 from daft.io.scan import PartitionField, ScanOperator
+from daft.logical.schema import Field, Schema
 from daft.recordbatch import RecordBatch
 
 if TYPE_CHECKING:
     from collections.abc import Iterator
 
     from daft.datatype import DataType
-    from daft.logical.schema import Schema
     from daft.sql.sql_connection import SQLConnection
 
 logger = logging.getLogger(__name__)
@@ -85,6 +86,10 @@ class SQLScanOperator(ScanOperator):
         ]
 
     def to_scan_tasks(self, pushdowns: Pushdowns) -> Iterator[ScanTask]:
+        if pushdowns.aggregations is not None:
+            # A single query computes the aggregations over all the rows.
+            return iter([self._construct_scan_task(pushdowns, num_rows=1)])
+
         total_rows, total_size, num_scan_tasks = self._get_size_estimates()
         if num_scan_tasks == 0:
             return iter(())
@@ -132,6 +137,12 @@ class SQLScanOperator(ScanOperator):
     def can_absorb_select(self) -> bool:
         return False
 
+    def can_absorb_aggregation(self, aggregation: PyExpr, pushdowns: Pushdowns) -> bool:
+        # The database computes the aggregation if it can also apply all of the filters.
+        if self._disable_pushdowns_to_sql or aggregation.to_sql() is None:
+            return False
+        return pushdowns.filters is None or pushdowns.filters.split_sql_pushdown()[1] is None
+
     def _attempt_schema_read(
         self,
         infer_schema: bool,
@@ -250,12 +261,30 @@ class SQLScanOperator(ScanOperator):
         partition_bounds: tuple[str, str] | None = None,
         stats: PyRecordBatch | None = None,
     ) -> ScanTask:
-        predicate_sql = pushdowns.filters.to_sql() if pushdowns.filters is not None else None
+        # Only the conjuncts of the filter that can be translated to SQL are pushed into the query, while the rest of
+        # the filter, along with the limit, are applied after reading the results of the query.
+        predicate_sql, remaining_filters = (
+            pushdowns.filters.split_sql_pushdown() if pushdowns.filters is not None else (None, None)
+        )
         apply_pushdowns_to_sql = not self._disable_pushdowns_to_sql and (
             pushdowns.filters is None or predicate_sql is not None
         )
+        schema = self._schema
 
-        if apply_pushdowns_to_sql:
+        if pushdowns.aggregations is not None:
+            # The query yields a row of the aggregations, which the optimizer only pushes down if the filters are
+            # fully applied in the query too.
+            sql = self.conn.construct_sql_query(
+                self.sql,
+                projection=[f'{agg.to_sql()} AS "{agg.name()}"' for agg in pushdowns.aggregations],
+                predicate=predicate_sql,
+                partition_bounds=partition_bounds,
+            )
+            schema = Schema._from_fields(
+                [Field._from_pyfield(agg.to_field(self._schema._schema)) for agg in pushdowns.aggregations]
+            )
+            pushdowns = None
+        elif apply_pushdowns_to_sql and remaining_filters is None:
             sql = self.conn.construct_sql_query(
                 self.sql,
                 projection=pushdowns.columns,
@@ -263,6 +292,20 @@ class SQLScanOperator(ScanOperator):
                 limit=pushdowns.limit,
                 partition_bounds=partition_bounds,
             )
+            pushdowns = None
+        elif apply_pushdowns_to_sql:
+            pushdowns = pushdowns.with_filters(remaining_filters)
+            projection = pushdowns.columns
+            if projection is not None:
+                # The query also selects the columns that the rest of the filter needs.
+                filter_columns = pushdowns.filter_required_column_names() or []
+                projection = projection + [c for c in filter_columns if c not in projection]
+            sql = self.conn.construct_sql_query(
+                self.sql,
+                projection=projection,
+                predicate=predicate_sql,
+                partition_bounds=partition_bounds,
+            )
         else:
             sql = self.conn.construct_sql_query(self.sql, partition_bounds=partition_bounds)
 
@@ -270,10 +313,10 @@ class SQLScanOperator(ScanOperator):
         return ScanTask.sql_scan_task(
             url=self.conn.url,
             file_format=file_format_config,
-            schema=self._schema._schema,
+            schema=schema._schema,
             storage_config=self.storage_config,
             num_rows=num_rows,
             size_bytes=size_bytes,
-            pushdowns=pushdowns if not apply_pushdowns_to_sql else None,
+            pushdowns=pushdowns,
             stats=stats,
         )
@@ -9,6 +9,25 @@ def get_arrow_version():
     return tuple(int(s) for s in pa.__version__.split(".") if s.isnumeric())
 
 
+# False positive probability of the bloom filters written to Parquet files.
+PARQUET_BLOOM_FILTER_FPP = 0.01
+
+# Key of the footer metadata of the Parquet files written by Daft that holds the approximate number of distinct
+# values of each column, which the planner uses to estimate the cardinality of group-bys and joins.
+PARQUET_NUM_DISTINCT_KEY = "daft.num_distinct"
+
+
+def parquet_bloom_filter_options(columns: list[str] | None) -> dict[str, Any]:
+    """Returns the PyArrow Parquet writer options that write a bloom filter for each of `columns`."""
+    if not columns:
+        return {}
+    if get_arrow_version() < (22, 0, 0):
+        raise ValueError(
+            f"Writing Parquet bloom filters is only supported on pyarrow>=22.0.0, found {pa.__version__}"
+        )
+    return {"bloom_filter_options": {column: {"fpp": PARQUET_BLOOM_FILTER_FPP} for column in columns}}
+
+
 def in_notebook():
     """Check if we are in a Jupyter notebook."""
     try:
@@ -0,0 +1,112 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Literal
+
+from daft.expressions import col
+
+if TYPE_CHECKING:
+    from daft.expressions import Expression
+
+
+class Window:
+    """Describes how the rows of the input of a window function are partitioned and ordered.
+
+    Windows are immutable, so every method returns a new window.
+
+    Example:
+        >>> import daft
+        >>> from daft import Window
+        >>> from daft.functions import row_number
+        >>> df = daft.from_pydict({"group": ["a", "a", "b"], "value": [3, 1, 2]})
+        >>> window = Window().partition_by("group").order_by("value")
+        >>> df = df.with_column("row", row_number().over(window))
+        >>> df.sort(["group", "value"]).show()
+        ╭───────┬───────┬────────╮
+        │ group ┆ value ┆ row    │
+        │ ---   ┆ ---   ┆ ---    │
+        │ Utf8  ┆ Int64 ┆ UInt64 │
+        ╞═══════╪═══════╪════════╡
+        │ a     ┆ 1     ┆ 1      │
+        ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+        │ a     ┆ 3     ┆ 2      │
+        ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+        │ b     ┆ 2     ┆ 1      │
+        ╰───────┴───────┴────────╯
+        <BLANKLINE>
+        (Showing first 3 of 3 rows)
+    """
+
+    def __init__(self) -> None:
+        self._partition_by: list[Expression] = []
+        self._order_by: list[Expression] = []
+        self._descending: list[bool] = []
+        self._null_strategy: Literal["smallest", "largest", "skip"] = "largest"
+        self._frame: tuple[int | None, int | None] | None = None
+
+    def _copy(self) -> Window:
+        window = Window()
+        window._partition_by = list(self._partition_by)
+        window._order_by = list(self._order_by)
+        window._descending = list(self._descending)
+        window._null_strategy = self._null_strategy
+        window._frame = self._frame
+        return window
+
+    def partition_by(self, *cols: Expression | str) -> Window:
+        """Partitions the rows by the values of `cols`, so that window functions are computed within each partition.
+
+        Args:
+            *cols: The columns or expressions to partition by.
+
+        Returns:
+            Window: A window partitioned by `cols`.
+        """
+        window = self._copy()
+        window._partition_by = [col(c) if isinstance(c, str) else c for c in cols]
+        return window
+
+    def order_by(
+        self,
+        *cols: Expression | str,
+        desc: bool | list[bool] = False,
+        nulls: Literal["smallest", "largest", "skip"] = "largest",
+    ) -> Window:
+        """Orders the rows of every partition by the values of `cols`.
+
+        Args:
+            *cols: The columns or expressions to order by.
+            desc: Whether to order by each of `cols` in descending order, either for all of them or for each of them.
+            nulls: How rows with null values of `cols` are ordered. With "smallest", nulls are ordered before other
+                values in ascending order, and with "largest", after them, like in sorts. With "skip", such rows are
+                left out of the window, so that window functions are null for them, and e.g. `lag` skips over them.
+
+        Returns:
+            Window: A window ordered by `cols`.
+        """
+        if isinstance(desc, bool):
+            desc = [desc] * len(cols)
+        elif len(desc) != len(cols):
+            raise ValueError(f"Expected a sort direction for each of the {len(cols)} columns, but received {len(desc)}")
+        window = self._copy()
+        window._order_by = [col(c) if isinstance(c, str) else c for c in cols]
+        window._descending = list(desc)
+        window._null_strategy = nulls
+        return window
+
+    def rows_between(self, start: int | None = None, end: int | None = None) -> Window:
+        """Restricts window functions to the rows between `start` and `end` rows from the current row, inclusive.
+
+        Negative offsets are rows before the current row and positive offsets are rows after it, while None is
+        unbounded. Frames are not supported by `row_number`, `rank`, `lag` and `lead`, which are computed over whole
+        partitions.
+
+        Args:
+            start: The offset of the first row of the frame, unbounded if None.
+            end: The offset of the last row of the frame, unbounded if None.
+
+        Returns:
+            Window: A window over the rows between `start` and `end`.
+        """
+        window = self._copy()
+        window._frame = (start, end)
+        return window
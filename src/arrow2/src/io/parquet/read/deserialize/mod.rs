@@ -15,7 +15,7 @@ use parquet2::read::get_page_iterator as _get_page_iterator;
 use parquet2::schema::types::PrimitiveType;
 
 use crate::{
-    array::{Array, DictionaryKey, FixedSizeListArray, ListArray, MapArray},
+    array::{growable::make_growable, Array, DictionaryKey, FixedSizeListArray, ListArray, MapArray},
     datatypes::{DataType, Field, IntervalUnit},
     offset::Offsets,
 };
@@ -77,11 +77,31 @@ pub fn create_list(
                 validity.and_then(|x| x.into()),
             ))
         }
-        DataType::FixedSizeList(_, _) => Box::new(FixedSizeListArray::new(
-            data_type,
-            values,
-            validity.and_then(|x| x.into()),
-        )),
+        DataType::FixedSizeList(_, size) => {
+            let size = *size;
+            let num_lists = offsets.len();
+            // Parquet has no fixed-size list type, so null lists are written without any
+            // values; pad those slots with nulls so that every list spans `size` values.
+            let values = if values.len() == num_lists * size {
+                values
+            } else {
+                offsets.push(values.len() as i64);
+                let mut growable = make_growable(&[values.as_ref()], true, num_lists * size);
+                for window in offsets.windows(2) {
+                    let (start, end) = (window[0] as usize, window[1] as usize);
+                    match end - start {
+                        0 => growable.extend_validity(size),
+                        len => growable.extend(0, start, len),
+                    }
+                }
+                growable.as_box()
+            };
+            Box::new(FixedSizeListArray::new(
+                data_type,
+                values,
+                validity.and_then(|x| x.into()),
+            ))
+        }
         _ => unreachable!(),
     }
 }
@@ -12,7 +12,10 @@ use parquet2::{
 };
 
 use crate::{
-    array::Array, chunk::Chunk, datatypes::Field, error::Result,
+    array::Array,
+    chunk::Chunk,
+    datatypes::{DataType, Field},
+    error::Result,
     io::parquet::read::column_iter_to_arrays,
 };
 
@@ -93,6 +96,37 @@ pub fn get_field_columns<'a>(
         .collect()
 }
 
+/// Returns whether the column at `path_in_schema` is part of `field`.
+/// Unlike [`get_field_columns`], this takes the struct types of `field` into account: they may only
+/// contain some of the fields of the parquet group they are read from, in which case the columns of
+/// the other fields are not part of `field`.
+pub fn is_field_column(path_in_schema: &[String], field: &Field) -> bool {
+    fn is_column_of_type(path: &[String], data_type: &DataType) -> bool {
+        match (data_type.to_logical_type(), path.split_first()) {
+            (DataType::Struct(fields), Some((name, rest))) => fields
+                .iter()
+                .any(|f| &f.name == name && is_column_of_type(rest, &f.data_type)),
+            _ => true,
+        }
+    }
+
+    match path_in_schema.split_first() {
+        Some((name, rest)) => name == &field.name && is_column_of_type(rest, &field.data_type),
+        None => false,
+    }
+}
+
+/// Returns all [`ColumnChunkMetaData`] needed to deserialize `field`, see [`is_field_column`].
+pub fn get_pruned_field_columns<'a>(
+    columns: &'a [ColumnChunkMetaData],
+    field: &Field,
+) -> Vec<&'a ColumnChunkMetaData> {
+    columns
+        .iter()
+        .filter(|x| is_field_column(&x.descriptor().path_in_schema, field))
+        .collect()
+}
+
 /// Returns all [`ColumnChunkMetaData`] associated to `field_name`.
 /// For non-nested parquet types, this returns a single column
 pub fn get_field_pages<'a, T>(
@@ -263,7 +297,8 @@ pub fn to_deserializer<'a>(
 }
 
 /// Returns a vector of iterators of [`Array`] ([`ArrayIter`]) corresponding to the top
-/// level parquet fields whose name matches `fields`'s names.
+/// level parquet fields whose name matches `fields`'s names. Struct types of `fields` may only
+/// contain some of the fields of the parquet group, in which case only those are read.
 ///
 /// # Implementation
 /// This operation is IO-bounded `O(C)` where C is the number of columns in the row group -
@@ -287,7 +322,12 @@ pub fn read_columns_many<'a, R: Read + Seek>(
     // This operation is IO-bounded `O(C)` where C is the number of columns in the row group
     let field_columns = fields
         .iter()
-        .map(|field| read_columns(reader, row_group.columns(), &field.name))
+        .map(|field| {
+            get_pruned_field_columns(row_group.columns(), field)
+                .into_iter()
+                .map(|meta| _read_single_column(reader, meta))
+                .collect::<Result<Vec<_>>>()
+        })
         .collect::<Result<Vec<_>>>()?;
 
     if let Some(pages) = pages {
@@ -332,9 +372,14 @@ pub async fn read_columns_many_async<
     let num_rows = row_group.num_rows();
     let num_rows = limit.map(|limit| limit.min(num_rows)).unwrap_or(num_rows);
 
-    let futures = fields
-        .iter()
-        .map(|field| read_columns_async(reader_factory.clone(), row_group.columns(), &field.name));
+    let futures = fields.iter().map(|field| {
+        let reader_factory = reader_factory.clone();
+        try_join_all(
+            get_pruned_field_columns(row_group.columns(), field)
+                .into_iter()
+                .map(move |meta| _read_single_column_async(reader_factory.clone(), meta)),
+        )
+    });
 
     let field_columns = try_join_all(futures).await?;
 
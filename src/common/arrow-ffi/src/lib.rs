@@ -1,6 +1,11 @@
+#[cfg(any(feature = "python", feature = "arrow-rs"))]
 use std::io::Cursor;
 
-use arrow2::{array::Array, datatypes::Field, ffi};
+use arrow2::array::Array;
+#[cfg(any(feature = "python", feature = "arrow-rs"))]
+use arrow2::{datatypes::Field, ffi};
+#[cfg(feature = "arrow-rs")]
+pub use arrow_array;
 #[cfg(feature = "python")]
 use pyo3::ffi::Py_uintptr_t;
 #[cfg(feature = "python")]
@@ -94,9 +99,132 @@ pub fn dtype_to_py<'py>(
     field.getattr(pyo3::intern!(py, "type"))
 }
 
+/// An Arrow C stream that can be moved into a PyCapsule.
+///
+/// The stream's private data is only accessed through its callbacks by whoever consumes it.
+#[cfg(feature = "python")]
+#[repr(transparent)]
+struct SendableArrowArrayStream(ffi::ArrowArrayStream);
+
+#[cfg(feature = "python")]
+unsafe impl Send for SendableArrowArrayStream {}
+
+/// Exports arrays with the type of `field` to Python as an `arrow_array_stream` PyCapsule, following the
+/// [Arrow PyCapsule Interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html).
+///
+/// This is what `__arrow_c_stream__` returns, which libraries such as polars and pyarrow consume without copying.
+#[cfg(feature = "python")]
+pub fn export_arrow_c_stream<'py>(
+    py: Python<'py>,
+    field: Field,
+    arrays: Vec<ArrayRef>,
+) -> PyResult<Bound<'py, pyo3::types::PyCapsule>> {
+    let arrays = arrays
+        .into_iter()
+        .map(|array| Ok(fix_child_array_slice_offsets(array)));
+    let stream = ffi::export_iterator(Box::new(arrays), field);
+    pyo3::types::PyCapsule::new(
+        py,
+        SendableArrowArrayStream(stream),
+        Some(c"arrow_array_stream".into()),
+    )
+}
+
+/// Imports the arrays of an object that implements `__arrow_c_stream__`, such as a polars or pyarrow table, along
+/// with the field that describes them.
+#[cfg(feature = "python")]
+pub fn import_arrow_c_stream(py: Python, obj: &Bound<PyAny>) -> PyResult<(Field, Vec<ArrayRef>)> {
+    use pyo3::{exceptions::PyValueError, types::PyCapsule};
+
+    let capsule = obj.call_method0(pyo3::intern!(py, "__arrow_c_stream__"))?;
+    let capsule = capsule.downcast::<PyCapsule>()?;
+    if capsule.name()? != Some(c"arrow_array_stream") {
+        return Err(PyValueError::new_err(
+            "Expected __arrow_c_stream__ to return an arrow_array_stream PyCapsule",
+        ));
+    }
+    // Move the stream out of the capsule, leaving a released stream behind for the capsule's destructor.
+    let stream = unsafe {
+        Box::new(std::ptr::replace(
+            capsule.pointer().cast::<ffi::ArrowArrayStream>(),
+            ffi::ArrowArrayStream::empty(),
+        ))
+    };
+    let mut reader = unsafe { ffi::ArrowArrayStreamReader::try_new(stream) }
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let mut arrays = vec![];
+    while let Some(array) = unsafe { reader.next() } {
+        arrays.push(array.map_err(|e| PyValueError::new_err(e.to_string()))?);
+    }
+    Ok((reader.field().clone(), arrays))
+}
+
+/// Converts an arrow2 array into an [arrow-rs](https://docs.rs/arrow-array) array.
+///
+/// The conversion goes through the Arrow C Data Interface, so buffers are shared rather than
+/// copied (except for sliced struct/fixed-size list arrays, see [`fix_child_array_slice_offsets`]).
+#[cfg(feature = "arrow-rs")]
+pub fn to_arrow_rs(array: ArrayRef) -> arrow2::error::Result<arrow_array::ArrayRef> {
+    use arrow_array::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+
+    let mut schema = Box::new(ffi::export_field_to_c(&Field::new(
+        "",
+        array.data_type().clone(),
+        true,
+    )));
+    let mut array = Box::new(ffi::export_array_to_c(fix_child_array_slice_offsets(array)));
+
+    // Like with pyarrow, the structs are handed over by pointer: arrow-rs moves them out and leaves
+    // released structs behind, so the release callbacks are only called by arrow-rs.
+    let data = unsafe {
+        let array = FFI_ArrowArray::from_raw((&raw mut *array).cast());
+        let schema = FFI_ArrowSchema::from_raw((&raw mut *schema).cast());
+        arrow_array::ffi::from_ffi(array, &schema)
+    }
+    .map_err(external_error)?;
+    Ok(arrow_array::make_array(data))
+}
+
+/// Converts an [arrow-rs](https://docs.rs/arrow-array) array into an arrow2 array.
+///
+/// This is the inverse of [`to_arrow_rs`] and is zero-copy.
+#[cfg(feature = "arrow-rs")]
+pub fn from_arrow_rs(array: &dyn arrow_array::Array) -> arrow2::error::Result<ArrayRef> {
+    use arrow_array::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+
+    let data = array.to_data();
+    let schema = FFI_ArrowSchema::try_from(data.data_type()).map_err(external_error)?;
+    let array = FFI_ArrowArray::new(&data);
+
+    // Like with pyarrow, arrow-rs exports into structs that arrow2 allocated and then owns.
+    let mut arrow2_array = Box::new(ffi::ArrowArray::empty());
+    let mut arrow2_schema = Box::new(ffi::ArrowSchema::empty());
+    unsafe {
+        std::ptr::write((&raw mut *arrow2_array).cast::<FFI_ArrowArray>(), array);
+        std::ptr::write((&raw mut *arrow2_schema).cast::<FFI_ArrowSchema>(), schema);
+        let field = ffi::import_field_from_c(&arrow2_schema)?;
+        ffi::import_array_from_c(*arrow2_array, field.data_type)
+    }
+}
+
+// Both libraries define these structs as `#[repr(C)]` mirrors of the C Data Interface.
+#[cfg(feature = "arrow-rs")]
+const _: () = {
+    use arrow_array::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+
+    assert!(std::mem::size_of::<ffi::ArrowArray>() == std::mem::size_of::<FFI_ArrowArray>());
+    assert!(std::mem::size_of::<ffi::ArrowSchema>() == std::mem::size_of::<FFI_ArrowSchema>());
+};
+
+#[cfg(feature = "arrow-rs")]
+fn external_error(err: impl std::error::Error + Send + Sync + 'static) -> arrow2::error::Error {
+    arrow2::error::Error::External(String::new(), Box::new(err))
+}
+
+#[cfg(any(feature = "python", feature = "arrow-rs"))]
 fn fix_child_array_slice_offsets(array: ArrayRef) -> ArrayRef {
     /* Zero-copy slices of arrow2 struct/fixed-size list arrays are currently not correctly
-    converted to pyarrow struct/fixed-size list arrays when going over the FFI boundary;
+    converted to pyarrow (or arrow-rs) struct/fixed-size list arrays when going over the FFI boundary;
     this helper function ensures that such arrays' slice representation is changed to work
     around this bug.
 
@@ -160,3 +288,40 @@ fn fix_child_array_slice_offsets(array: ArrayRef) -> ArrayRef {
         _ => panic!("shouldn't be reached"),
     }
 }
+
+#[cfg(all(test, feature = "arrow-rs"))]
+mod tests {
+    use arrow2::array::{Int64Array, StructArray, Utf8Array};
+
+    use super::*;
+
+    fn roundtrip(array: ArrayRef) {
+        let converted = to_arrow_rs(array.clone()).unwrap();
+        assert_eq!(converted.len(), array.len());
+        assert_eq!(converted.null_count(), array.null_count());
+        let back = from_arrow_rs(converted.as_ref()).unwrap();
+        assert_eq!(back, array);
+    }
+
+    #[test]
+    fn test_arrow_rs_roundtrip_primitive() {
+        roundtrip(Int64Array::from([Some(1), None, Some(3)]).boxed());
+    }
+
+    #[test]
+    fn test_arrow_rs_roundtrip_utf8() {
+        roundtrip(Utf8Array::<i64>::from([Some("a"), None, Some("ccc")]).boxed());
+    }
+
+    #[test]
+    fn test_arrow_rs_roundtrip_sliced_struct() {
+        let values = Int64Array::from_slice([1, 2, 3, 4]).boxed();
+        let dtype = arrow2::datatypes::DataType::Struct(vec![Field::new(
+            "a",
+            values.data_type().clone(),
+            true,
+        )]);
+        let array = StructArray::new(dtype, vec![values], None).sliced(1, 2);
+        roundtrip(array.boxed());
+    }
+}
@@ -11,6 +11,9 @@ use serde::{Deserialize, Serialize};
 pub struct DaftPlanningConfig {
     pub default_io_config: IOConfig,
     pub enable_join_reordering: bool,
+    /// Names of logical optimizer rules to skip, e.g. `PushDownFilter`, for debugging plans.
+    #[serde(default)]
+    pub disabled_optimizer_rules: Vec<String>,
 }
 
 impl DaftPlanningConfig {
@@ -23,6 +26,15 @@ impl DaftPlanningConfig {
         {
             cfg.enable_join_reordering = true;
         }
+        let disabled_optimizer_rules_var_name = "DAFT_DEV_DISABLED_OPTIMIZER_RULES";
+        if let Ok(val) = std::env::var(disabled_optimizer_rules_var_name) {
+            cfg.disabled_optimizer_rules = val
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(String::from)
+                .collect();
+        }
         cfg
     }
 }
@@ -45,6 +57,7 @@ pub struct DaftExecutionConfig {
     pub sort_merge_join_sort_with_aligned_boundaries: bool,
     pub hash_join_partition_size_leniency: f64,
     pub sample_size_for_sort: usize,
+    pub sort_boundary_oversampling_factor: usize,
     pub parquet_split_row_groups_max_files: usize,
     pub num_preview_rows: usize,
     pub parquet_target_filesize: usize,
@@ -63,6 +76,20 @@ pub struct DaftExecutionConfig {
     pub pre_shuffle_merge_threshold: usize,
     pub enable_ray_tracing: bool,
     pub scantask_splitting_level: i32,
+    pub memory_limit_bytes: Option<usize>,
+    pub enable_speculative_execution: bool,
+    pub speculative_execution_multiplier: f64,
+    pub scan_task_retry_backoff_ms: u64,
+    pub enable_result_cache: bool,
+    /// Target size of partitions after shuffles, used to choose the number of partitions from size estimates of the
+    /// data being shuffled. `None` keeps the number of input partitions.
+    #[serde(default)]
+    pub target_partition_size_bytes: Option<usize>,
+    /// Upper bound on the size of the morsels that the native executor passes to operators, including Python UDFs
+    /// without a batch size, on top of `default_morsel_size` rows. Smaller morsels use less memory for wide rows.
+    /// `None` only limits the number of rows.
+    #[serde(default)]
+    pub default_morsel_size_bytes: Option<usize>,
 }
 
 impl Default for DaftExecutionConfig {
@@ -75,6 +102,7 @@ impl Default for DaftExecutionConfig {
             sort_merge_join_sort_with_aligned_boundaries: false,
             hash_join_partition_size_leniency: 0.5,
             sample_size_for_sort: 20,
+            sort_boundary_oversampling_factor: 32,
             parquet_split_row_groups_max_files: 10,
             num_preview_rows: 8,
             parquet_target_filesize: 512 * 1024 * 1024, // 512MB
@@ -93,6 +121,13 @@ impl Default for DaftExecutionConfig {
             pre_shuffle_merge_threshold: 1024 * 1024 * 1024, // 1GB
             enable_ray_tracing: false,
             scantask_splitting_level: 1,
+            memory_limit_bytes: None,
+            enable_speculative_execution: false,
+            speculative_execution_multiplier: 1.5,
+            scan_task_retry_backoff_ms: 1000,
+            enable_result_cache: false,
+            target_partition_size_bytes: None,
+            default_morsel_size_bytes: None,
         }
     }
 }
@@ -134,6 +169,13 @@ impl DaftExecutionConfig {
         if let Ok(val) = std::env::var(enable_aggressive_scantask_splitting_env_var_name) {
             cfg.scantask_splitting_level = val.parse::<i32>().unwrap_or(0);
         }
+        let morsel_size_bytes_env_var_name = "DAFT_DEFAULT_MORSEL_SIZE_BYTES";
+        if let Ok(val) = std::env::var(morsel_size_bytes_env_var_name)
+            && let Ok(size) = val.trim().parse::<usize>()
+            && size > 0
+        {
+            cfg.default_morsel_size_bytes = Some(size);
+        }
         cfg
     }
 }
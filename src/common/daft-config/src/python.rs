@@ -29,14 +29,22 @@ impl PyDaftPlanningConfig {
         }
     }
 
-    #[pyo3(signature = (default_io_config=None))]
-    fn with_config_values(&mut self, default_io_config: Option<PyIOConfig>) -> PyResult<Self> {
+    #[pyo3(signature = (default_io_config=None, disabled_optimizer_rules=None))]
+    fn with_config_values(
+        &mut self,
+        default_io_config: Option<PyIOConfig>,
+        disabled_optimizer_rules: Option<Vec<String>>,
+    ) -> PyResult<Self> {
         let mut config = self.config.as_ref().clone();
 
         if let Some(default_io_config) = default_io_config {
             config.default_io_config = default_io_config.config;
         }
 
+        if let Some(disabled_optimizer_rules) = disabled_optimizer_rules {
+            config.disabled_optimizer_rules = disabled_optimizer_rules;
+        }
+
         Ok(Self {
             config: Arc::new(config),
         })
@@ -48,6 +56,11 @@ impl PyDaftPlanningConfig {
             config: self.config.default_io_config.clone(),
         })
     }
+
+    #[getter(disabled_optimizer_rules)]
+    fn disabled_optimizer_rules(&self) -> PyResult<Vec<String>> {
+        Ok(self.config.disabled_optimizer_rules.clone())
+    }
 }
 
 impl_bincode_py_state_serialization!(PyDaftPlanningConfig);
@@ -84,6 +97,7 @@ impl PyDaftExecutionConfig {
         sort_merge_join_sort_with_aligned_boundaries=None,
         hash_join_partition_size_leniency=None,
         sample_size_for_sort=None,
+        sort_boundary_oversampling_factor=None,
         num_preview_rows=None,
         parquet_target_filesize=None,
         parquet_target_row_group_size=None,
@@ -100,7 +114,14 @@ impl PyDaftExecutionConfig {
         shuffle_algorithm=None,
         pre_shuffle_merge_threshold=None,
         enable_ray_tracing=None,
-        scantask_splitting_level=None
+        scantask_splitting_level=None,
+        memory_limit_bytes=None,
+        enable_speculative_execution=None,
+        speculative_execution_multiplier=None,
+        scan_task_retry_backoff_ms=None,
+        enable_result_cache=None,
+        target_partition_size_bytes=None,
+        default_morsel_size_bytes=None
     ))]
     fn with_config_values(
         &self,
@@ -112,6 +133,7 @@ impl PyDaftExecutionConfig {
         sort_merge_join_sort_with_aligned_boundaries: Option<bool>,
         hash_join_partition_size_leniency: Option<f64>,
         sample_size_for_sort: Option<usize>,
+        sort_boundary_oversampling_factor: Option<usize>,
         num_preview_rows: Option<usize>,
         parquet_target_filesize: Option<usize>,
         parquet_target_row_group_size: Option<usize>,
@@ -129,6 +151,13 @@ impl PyDaftExecutionConfig {
         pre_shuffle_merge_threshold: Option<usize>,
         enable_ray_tracing: Option<bool>,
         scantask_splitting_level: Option<i32>,
+        memory_limit_bytes: Option<usize>,
+        enable_speculative_execution: Option<bool>,
+        speculative_execution_multiplier: Option<f64>,
+        scan_task_retry_backoff_ms: Option<u64>,
+        enable_result_cache: Option<bool>,
+        target_partition_size_bytes: Option<usize>,
+        default_morsel_size_bytes: Option<usize>,
     ) -> PyResult<Self> {
         let mut config = self.config.as_ref().clone();
 
@@ -159,6 +188,9 @@ impl PyDaftExecutionConfig {
         if let Some(sample_size_for_sort) = sample_size_for_sort {
             config.sample_size_for_sort = sample_size_for_sort;
         }
+        if let Some(sort_boundary_oversampling_factor) = sort_boundary_oversampling_factor {
+            config.sort_boundary_oversampling_factor = sort_boundary_oversampling_factor;
+        }
         if let Some(num_preview_rows) = num_preview_rows {
             config.num_preview_rows = num_preview_rows;
         }
@@ -229,6 +261,49 @@ impl PyDaftExecutionConfig {
             config.scantask_splitting_level = scantask_splitting_level;
         }
 
+        if let Some(memory_limit_bytes) = memory_limit_bytes {
+            config.memory_limit_bytes = Some(memory_limit_bytes);
+        }
+
+        if let Some(enable_speculative_execution) = enable_speculative_execution {
+            config.enable_speculative_execution = enable_speculative_execution;
+        }
+
+        if let Some(speculative_execution_multiplier) = speculative_execution_multiplier {
+            if speculative_execution_multiplier <= 1.0 {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "speculative_execution_multiplier must be greater than 1.0",
+                ));
+            }
+            config.speculative_execution_multiplier = speculative_execution_multiplier;
+        }
+
+        if let Some(scan_task_retry_backoff_ms) = scan_task_retry_backoff_ms {
+            config.scan_task_retry_backoff_ms = scan_task_retry_backoff_ms;
+        }
+
+        if let Some(enable_result_cache) = enable_result_cache {
+            config.enable_result_cache = enable_result_cache;
+        }
+
+        if let Some(target_partition_size_bytes) = target_partition_size_bytes {
+            if target_partition_size_bytes == 0 {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "target_partition_size_bytes must be positive",
+                ));
+            }
+            config.target_partition_size_bytes = Some(target_partition_size_bytes);
+        }
+
+        if let Some(default_morsel_size_bytes) = default_morsel_size_bytes {
+            if default_morsel_size_bytes == 0 {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "default_morsel_size_bytes must be positive",
+                ));
+            }
+            config.default_morsel_size_bytes = Some(default_morsel_size_bytes);
+        }
+
         Ok(Self {
             config: Arc::new(config),
         })
@@ -269,6 +344,11 @@ impl PyDaftExecutionConfig {
         Ok(self.config.sample_size_for_sort)
     }
 
+    #[getter]
+    fn get_sort_boundary_oversampling_factor(&self) -> PyResult<usize> {
+        Ok(self.config.sort_boundary_oversampling_factor)
+    }
+
     #[getter]
     fn get_num_preview_rows(&self) -> PyResult<usize> {
         Ok(self.config.num_preview_rows)
@@ -348,6 +428,41 @@ impl PyDaftExecutionConfig {
     fn scantask_splitting_level(&self) -> PyResult<i32> {
         Ok(self.config.scantask_splitting_level)
     }
+
+    #[getter]
+    fn memory_limit_bytes(&self) -> PyResult<Option<usize>> {
+        Ok(self.config.memory_limit_bytes)
+    }
+
+    #[getter]
+    fn enable_speculative_execution(&self) -> PyResult<bool> {
+        Ok(self.config.enable_speculative_execution)
+    }
+
+    #[getter]
+    fn speculative_execution_multiplier(&self) -> PyResult<f64> {
+        Ok(self.config.speculative_execution_multiplier)
+    }
+
+    #[getter]
+    fn scan_task_retry_backoff_ms(&self) -> PyResult<u64> {
+        Ok(self.config.scan_task_retry_backoff_ms)
+    }
+
+    #[getter]
+    fn enable_result_cache(&self) -> PyResult<bool> {
+        Ok(self.config.enable_result_cache)
+    }
+
+    #[getter]
+    fn target_partition_size_bytes(&self) -> PyResult<Option<usize>> {
+        Ok(self.config.target_partition_size_bytes)
+    }
+
+    #[getter]
+    fn default_morsel_size_bytes(&self) -> PyResult<Option<usize>> {
+        Ok(self.config.default_morsel_size_bytes)
+    }
 }
 
 impl_bincode_py_state_serialization!(PyDaftExecutionConfig);
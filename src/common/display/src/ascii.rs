@@ -140,6 +140,18 @@ pub trait AsciiTreeDisplay: TreeDisplay {
         fmt_tree_gitstyle(self, 0, w, level)
     }
 
+    // Print the whole tree represented by this node, with every node displayed at the given level.
+    fn fmt_tree_with_level<'a, W: fmt::Write + 'a>(
+        &self,
+        w: &'a mut W,
+        level: crate::DisplayLevel,
+    ) -> fmt::Result
+    where
+        Self: Sized,
+    {
+        fmt_tree_gitstyle(self, 0, w, level)
+    }
+
     fn fmt_tree_indent_style<'a, W: fmt::Write + 'a>(
         &self,
         indent: usize,
@@ -0,0 +1,96 @@
+use serde_json::{json, Value};
+
+use crate::{tree::TreeDisplay, DisplayLevel};
+
+pub trait JsonDisplay: TreeDisplay {
+    /// Displays the tree as a JSON object per node, with the `name` of the node, the lines of its `details` and its
+    /// `children`, so that it can be inspected by tools rather than people.
+    ///
+    /// In simple mode, the details of each node are just its name.
+    fn repr_json(&self, simple: bool) -> String;
+}
+
+impl<T: TreeDisplay> JsonDisplay for T {
+    fn repr_json(&self, simple: bool) -> String {
+        let level = if simple {
+            DisplayLevel::Compact
+        } else {
+            DisplayLevel::Default
+        };
+        fmt_tree_json(self, level)
+    }
+}
+
+/// Displays the tree rooted at `node` as JSON, see [`JsonDisplay::repr_json`].
+pub fn fmt_tree_json(node: &dyn TreeDisplay, level: DisplayLevel) -> String {
+    serde_json::to_string_pretty(&to_json_value(node, level))
+        .expect("Serializing JSON values can't fail")
+}
+
+fn to_json_value(node: &dyn TreeDisplay, level: DisplayLevel) -> Value {
+    json!({
+        "name": node.get_name(),
+        "details": node.display_as(level).lines().collect::<Vec<_>>(),
+        "children": node
+            .get_children()
+            .into_iter()
+            .map(|child| to_json_value(child, level))
+            .collect::<Vec<_>>(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Node {
+        name: &'static str,
+        children: Vec<Node>,
+    }
+
+    impl TreeDisplay for Node {
+        fn display_as(&self, level: DisplayLevel) -> String {
+            match level {
+                DisplayLevel::Compact => self.name.to_string(),
+                _ => format!("{}\nNum children = {}", self.name, self.children.len()),
+            }
+        }
+
+        fn get_name(&self) -> String {
+            self.name.to_string()
+        }
+
+        fn get_children(&self) -> Vec<&dyn TreeDisplay> {
+            self.children.iter().map(|child| child as _).collect()
+        }
+    }
+
+    #[test]
+    fn test_repr_json() {
+        let tree = Node {
+            name: "Join",
+            children: vec![
+                Node {
+                    name: "Scan",
+                    children: vec![],
+                },
+                Node {
+                    name: "Scan",
+                    children: vec![],
+                },
+            ],
+        };
+
+        let json: Value = serde_json::from_str(&tree.repr_json(false)).unwrap();
+        assert_eq!(json["name"], "Join");
+        assert_eq!(json["details"], json!(["Join", "Num children = 2"]));
+        assert_eq!(
+            json["children"][1]["details"],
+            json!(["Scan", "Num children = 0"])
+        );
+        assert_eq!(json["children"][1]["children"], json!([]));
+
+        let json: Value = serde_json::from_str(&tree.repr_json(true)).unwrap();
+        assert_eq!(json["details"], json!(["Join"]));
+    }
+}
@@ -1,5 +1,6 @@
 #![feature(let_chains)]
 pub mod ascii;
+pub mod json;
 pub mod mermaid;
 pub mod table_display;
 pub mod tree;
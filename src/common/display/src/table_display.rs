@@ -1,4 +1,5 @@
 pub use comfy_table;
+use textwrap::core::display_width;
 
 const BOLD_TABLE_HEADERS_IN_DISPLAY: &str = "DAFT_BOLD_TABLE_HEADERS";
 
@@ -51,13 +52,52 @@ pub fn make_schema_vertical_table(
     table
 }
 
+const DOTS: &str = "…";
+
+/// Display width of a character in a table cell.
+///
+/// Control characters such as newlines have no display width, but count as one column so that
+/// multi-line values are still truncated.
+fn cell_char_width(c: char) -> usize {
+    if c.is_control() {
+        1
+    } else {
+        let mut buf = [0; 4];
+        display_width(c.encode_utf8(&mut buf))
+    }
+}
+
+/// Truncates a cell value that is wider than `max_col_width`, ending it with an ellipsis.
+///
+/// Widths are display widths rather than bytes or characters, so wide (e.g. CJK) characters
+/// take two columns and multi-byte strings are never split in the middle of a character.
+fn truncate_cell(value: String, max_col_width: Option<usize>) -> String {
+    let Some(max_col_width) = max_col_width else {
+        return value;
+    };
+    if value.chars().map(cell_char_width).sum::<usize>() <= max_col_width {
+        return value;
+    }
+    let max_width = max_col_width.saturating_sub(display_width(DOTS));
+    let mut truncated = String::new();
+    let mut width = 0;
+    for c in value.chars() {
+        width += cell_char_width(c);
+        if width > max_width {
+            break;
+        }
+        truncated.push(c);
+    }
+    truncated.push_str(DOTS);
+    truncated
+}
+
 pub fn make_comfy_table<S: AsRef<str>>(
     fields: &[S],
     columns: Option<&[&dyn StrValue]>,
     num_rows: Option<usize>,
     max_col_width: Option<usize>,
 ) -> comfy_table::Table {
-    const DOTS: &str = "…";
     const TOTAL_ROWS: usize = 10;
 
     let mut table = comfy_table::Table::new();
@@ -121,22 +161,7 @@ pub fn make_comfy_table<S: AsRef<str>>(
         for i in 0..head_rows {
             let all_cols = columns
                 .iter()
-                .map(|s| {
-                    let mut str_val = s.str_value(i);
-                    if let Some(max_col_width) = max_col_width {
-                        if str_val.len() > max_col_width - DOTS.len() {
-                            str_val = format!(
-                                "{}{DOTS}",
-                                &str_val
-                                    .char_indices()
-                                    .take(max_col_width - DOTS.len())
-                                    .map(|(_, c)| c)
-                                    .collect::<String>()
-                            );
-                        }
-                    }
-                    str_val
-                })
+                .map(|s| truncate_cell(s.str_value(i), max_col_width))
                 .collect::<Vec<_>>();
 
             if tail_cols > 0 {
@@ -155,22 +180,7 @@ pub fn make_comfy_table<S: AsRef<str>>(
         for i in (len - tail_rows)..(len) {
             let all_cols = columns
                 .iter()
-                .map(|s| {
-                    let mut str_val = s.str_value(i);
-                    if let Some(max_col_width) = max_col_width {
-                        if str_val.len() > max_col_width - DOTS.len() {
-                            str_val = format!(
-                                "{}{DOTS}",
-                                &str_val
-                                    .char_indices()
-                                    .take(max_col_width - DOTS.len())
-                                    .map(|(_, c)| c)
-                                    .collect::<String>()
-                            );
-                        }
-                    }
-                    str_val
-                })
+                .map(|s| truncate_cell(s.str_value(i), max_col_width))
                 .collect::<Vec<_>>();
 
             if tail_cols > 0 {
@@ -187,3 +197,41 @@ pub fn make_comfy_table<S: AsRef<str>>(
     }
     table
 }
+
+#[cfg(test)]
+mod tests {
+    use super::truncate_cell;
+
+    #[test]
+    fn test_truncate_cell() {
+        assert_eq!(truncate_cell("abcdef".to_string(), None), "abcdef");
+        assert_eq!(truncate_cell("abcdef".to_string(), Some(9)), "abcdef");
+        assert_eq!(truncate_cell("abcdefg".to_string(), Some(7)), "abcdefg");
+        assert_eq!(truncate_cell("abcdefgh".to_string(), Some(7)), "abcdef…");
+        assert_eq!(truncate_cell("abc".to_string(), Some(1)), "…");
+    }
+
+    #[test]
+    fn test_truncate_cell_multibyte() {
+        // Seven characters, but 14 bytes: this fits and must not be truncated.
+        assert_eq!(truncate_cell("éèêëàâç".to_string(), Some(7)), "éèêëàâç");
+        assert_eq!(truncate_cell("éèêëàâçô".to_string(), Some(7)), "éèêëàâ…");
+    }
+
+    #[test]
+    fn test_truncate_cell_multiline() {
+        assert_eq!(truncate_cell("ab\ncd".to_string(), Some(5)), "ab\ncd");
+        assert_eq!(truncate_cell("ab\ncdef".to_string(), Some(5)), "ab\nc…");
+    }
+
+    #[test]
+    fn test_truncate_cell_wide_chars() {
+        // Each of these characters is two columns wide.
+        assert_eq!(truncate_cell("日本語".to_string(), Some(7)), "日本語");
+        assert_eq!(
+            truncate_cell("日本語の文字".to_string(), Some(7)),
+            "日本語…"
+        );
+        assert_eq!(truncate_cell("日本語の文字".to_string(), Some(6)), "日本…");
+    }
+}
@@ -18,6 +18,7 @@ pub enum FileFormat {
     Csv,
     Json,
     Warc,
+    ArrowIpc,
     Database,
     Python,
 }
@@ -31,6 +32,7 @@ impl FileFormat {
             Self::Csv => "csv",
             Self::Json => "json",
             Self::Warc => "warc",
+            Self::ArrowIpc => "arrow",
             Self::Database => "db",
             Self::Python => "py",
         }
@@ -41,7 +43,7 @@ impl FromStr for FileFormat {
     type Err = DaftError;
 
     fn from_str(file_format: &str) -> DaftResult<Self> {
-        use FileFormat::{Csv, Database, Json, Parquet, Warc};
+        use FileFormat::{ArrowIpc, Csv, Database, Json, Parquet, Warc};
 
         if file_format.trim().eq_ignore_ascii_case("parquet") {
             Ok(Parquet)
@@ -51,6 +53,11 @@ impl FromStr for FileFormat {
             Ok(Json)
         } else if file_format.trim().eq_ignore_ascii_case("warc") {
             Ok(Warc)
+        } else if ["arrow", "ipc", "feather"]
+            .iter()
+            .any(|name| file_format.trim().eq_ignore_ascii_case(name))
+        {
+            Ok(ArrowIpc)
         } else if file_format.trim().eq_ignore_ascii_case("database") {
             Ok(Database)
         } else {
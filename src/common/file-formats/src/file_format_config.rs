@@ -19,6 +19,7 @@ pub enum FileFormatConfig {
     Csv(CsvSourceConfig),
     Json(JsonSourceConfig),
     Warc(WarcSourceConfig),
+    ArrowIpc(ArrowIpcSourceConfig),
     #[cfg(feature = "python")]
     Database(DatabaseSourceConfig),
     #[cfg(feature = "python")]
@@ -38,6 +39,7 @@ impl FileFormatConfig {
             Self::Csv(_) => "Csv",
             Self::Json(_) => "Json",
             Self::Warc(_) => "Warc",
+            Self::ArrowIpc(_) => "ArrowIpc",
             #[cfg(feature = "python")]
             Self::Database(_) => "Database",
             #[cfg(feature = "python")]
@@ -52,6 +54,7 @@ impl FileFormatConfig {
             Self::Csv(source) => source.multiline_display(),
             Self::Json(source) => source.multiline_display(),
             Self::Warc(source) => source.multiline_display(),
+            Self::ArrowIpc(source) => source.multiline_display(),
             #[cfg(feature = "python")]
             Self::Database(source) => source.multiline_display(),
             #[cfg(feature = "python")]
@@ -77,6 +80,12 @@ pub struct ParquetSourceConfig {
     pub field_id_mapping: Option<Arc<BTreeMap<i32, Field>>>,
     pub row_groups: Option<Vec<Option<Vec<i64>>>>,
     pub chunk_size: Option<usize>,
+
+    /// Name of a column to add that holds the index of each row within its source file.
+    ///
+    /// Filter pushdowns are applied after this column is computed, so files are always numbered from
+    /// their first row and never split by row groups.
+    pub row_index_column: Option<String>,
 }
 
 impl ParquetSourceConfig {
@@ -116,6 +125,9 @@ impl ParquetSourceConfig {
                     .join(",")
             ));
         }
+        if let Some(row_index_column) = &self.row_index_column {
+            res.push(format!("Row index column = {row_index_column}"));
+        }
         res
     }
 }
@@ -127,6 +139,7 @@ impl Default for ParquetSourceConfig {
             field_id_mapping: None,
             row_groups: None,
             chunk_size: None,
+            row_index_column: None,
         }
     }
 }
@@ -136,12 +149,13 @@ impl Default for ParquetSourceConfig {
 impl ParquetSourceConfig {
     /// Create a config for a Parquet data source.
     #[new]
-    #[pyo3(signature = (coerce_int96_timestamp_unit=None, field_id_mapping=None, row_groups=None, chunk_size=None))]
+    #[pyo3(signature = (coerce_int96_timestamp_unit=None, field_id_mapping=None, row_groups=None, chunk_size=None, row_index_column=None))]
     fn new(
         coerce_int96_timestamp_unit: Option<PyTimeUnit>,
         field_id_mapping: Option<BTreeMap<i32, PyField>>,
         row_groups: Option<Vec<Option<Vec<i64>>>>,
         chunk_size: Option<usize>,
+        row_index_column: Option<String>,
     ) -> Self {
         Self {
             coerce_int96_timestamp_unit: coerce_int96_timestamp_unit
@@ -151,6 +165,7 @@ impl ParquetSourceConfig {
                 .map(|map| Arc::new(map.into_iter().map(|(k, v)| (k, v.field)).collect())),
             row_groups,
             chunk_size,
+            row_index_column,
         }
     }
 
@@ -402,3 +417,28 @@ impl WarcSourceConfig {
 }
 
 impl_bincode_py_state_serialization!(WarcSourceConfig);
+
+/// Configuration for an Arrow IPC data source, which also covers Feather (version 2) files.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct ArrowIpcSourceConfig {}
+
+impl ArrowIpcSourceConfig {
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl ArrowIpcSourceConfig {
+    /// Create a config for an Arrow IPC data source.
+    #[new]
+    #[pyo3(signature = ())]
+    fn new() -> PyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+impl_bincode_py_state_serialization!(ArrowIpcSourceConfig);
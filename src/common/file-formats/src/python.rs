@@ -5,8 +5,8 @@ use pyo3::{basic::CompareOp, prelude::*};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    file_format_config::DatabaseSourceConfig, CsvSourceConfig, FileFormat, FileFormatConfig,
-    JsonSourceConfig, ParquetSourceConfig, WarcSourceConfig,
+    file_format_config::DatabaseSourceConfig, ArrowIpcSourceConfig, CsvSourceConfig, FileFormat,
+    FileFormatConfig, JsonSourceConfig, ParquetSourceConfig, WarcSourceConfig,
 };
 
 /// Configuration for parsing a particular file format.
@@ -44,6 +44,12 @@ impl PyFileFormatConfig {
         Self(Arc::new(FileFormatConfig::Warc(config)))
     }
 
+    /// Create an Arrow IPC file format config.
+    #[staticmethod]
+    fn from_arrow_ipc_config(config: ArrowIpcSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::ArrowIpc(config)))
+    }
+
     /// Create a Database file format config.
     #[staticmethod]
     fn from_database_config(config: DatabaseSourceConfig) -> Self {
@@ -70,6 +76,10 @@ impl PyFileFormatConfig {
                 .clone()
                 .into_pyobject(py)
                 .map(|c| c.unbind().into_any()),
+            FileFormatConfig::ArrowIpc(config) => config
+                .clone()
+                .into_pyobject(py)
+                .map(|c| c.unbind().into_any()),
             FileFormatConfig::Database(config) => config
                 .clone()
                 .into_pyobject(py)
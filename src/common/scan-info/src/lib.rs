@@ -8,6 +8,7 @@ mod pushdowns;
 pub mod python;
 mod scan_operator;
 mod scan_task;
+mod statistics;
 pub mod test;
 
 use std::{fmt::Debug, hash::Hash, sync::Arc};
@@ -20,6 +21,7 @@ pub use pushdowns::Pushdowns;
 pub use python::register_modules;
 pub use scan_operator::{ScanOperator, ScanOperatorRef};
 pub use scan_task::{ScanTaskLike, ScanTaskLikeRef, SPLIT_AND_MERGE_PASS};
+pub use statistics::{DatasetColumnStatistics, DatasetStatistics, DATASET_STATISTICS_FILE_NAME};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ScanState {
@@ -51,6 +53,8 @@ pub struct PhysicalScanInfo {
     pub source_schema: SchemaRef,
     pub partitioning_keys: Vec<PartitionField>,
     pub pushdowns: Pushdowns,
+    /// Statistics of the whole dataset that is scanned, if it was analyzed.
+    pub statistics: Option<Arc<DatasetStatistics>>,
 }
 
 impl PhysicalScanInfo {
@@ -61,11 +65,13 @@ impl PhysicalScanInfo {
         partitioning_keys: Vec<PartitionField>,
         pushdowns: Pushdowns,
     ) -> Self {
+        let statistics = scan_op.0.statistics();
         Self {
             scan_state: ScanState::Operator(scan_op),
             source_schema,
             partitioning_keys,
             pushdowns,
+            statistics,
         }
     }
 
@@ -76,6 +82,7 @@ impl PhysicalScanInfo {
             source_schema: self.source_schema.clone(),
             partitioning_keys: self.partitioning_keys.clone(),
             pushdowns,
+            statistics: self.statistics.clone(),
         }
     }
 }
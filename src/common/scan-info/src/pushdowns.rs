@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use common_display::DisplayAs;
-use daft_dsl::{estimated_selectivity, ExprRef};
+use daft_dsl::{estimated_selectivity_with_stats, ExprRef, SelectivityStats};
 use daft_schema::schema::Schema;
 use serde::{Deserialize, Serialize};
 
@@ -15,6 +15,13 @@ pub struct Pushdowns {
     pub columns: Option<Arc<Vec<String>>>,
     /// Optional number of rows to read.
     pub limit: Option<usize>,
+    /// Optional paths of the fields to read from struct columns, each of which is a column name
+    /// followed by the names of nested struct fields. Struct columns without a path are read in full.
+    pub struct_fields: Option<Arc<Vec<Vec<String>>>>,
+    /// Optional global aggregations to compute over the source data, each a count, minimum or maximum of a column.
+    /// Each scan task then yields rows of partial results, with a column named like each aggregation, which are
+    /// merged afterwards.
+    pub aggregations: Option<Arc<Vec<ExprRef>>>,
 }
 
 impl Default for Pushdowns {
@@ -36,6 +43,8 @@ impl Pushdowns {
             partition_filters,
             columns,
             limit,
+            struct_fields: None,
+            aggregations: None,
         }
     }
 
@@ -45,6 +54,8 @@ impl Pushdowns {
             && self.partition_filters.is_none()
             && self.columns.is_none()
             && self.limit.is_none()
+            && self.struct_fields.is_none()
+            && self.aggregations.is_none()
     }
 
     #[must_use]
@@ -54,6 +65,8 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns: self.columns.clone(),
             limit,
+            struct_fields: self.struct_fields.clone(),
+            aggregations: self.aggregations.clone(),
         }
     }
 
@@ -64,6 +77,8 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns: self.columns.clone(),
             limit: self.limit,
+            struct_fields: self.struct_fields.clone(),
+            aggregations: self.aggregations.clone(),
         }
     }
 
@@ -74,6 +89,8 @@ impl Pushdowns {
             partition_filters,
             columns: self.columns.clone(),
             limit: self.limit,
+            struct_fields: self.struct_fields.clone(),
+            aggregations: self.aggregations.clone(),
         }
     }
 
@@ -84,6 +101,32 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns,
             limit: self.limit,
+            struct_fields: self.struct_fields.clone(),
+            aggregations: self.aggregations.clone(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_struct_fields(&self, struct_fields: Option<Arc<Vec<Vec<String>>>>) -> Self {
+        Self {
+            filters: self.filters.clone(),
+            partition_filters: self.partition_filters.clone(),
+            columns: self.columns.clone(),
+            limit: self.limit,
+            struct_fields,
+            aggregations: self.aggregations.clone(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_aggregations(&self, aggregations: Option<Arc<Vec<ExprRef>>>) -> Self {
+        Self {
+            filters: self.filters.clone(),
+            partition_filters: self.partition_filters.clone(),
+            columns: self.columns.clone(),
+            limit: self.limit,
+            struct_fields: self.struct_fields.clone(),
+            aggregations,
         }
     }
 
@@ -102,12 +145,41 @@ impl Pushdowns {
         if let Some(limit) = self.limit {
             res.push(format!("Limit pushdown = {limit}"));
         }
+        if let Some(struct_fields) = &self.struct_fields {
+            res.push(format!(
+                "Struct field pushdown = [{}]",
+                struct_fields
+                    .iter()
+                    .map(|path| path.join("."))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        if let Some(aggregations) = &self.aggregations {
+            res.push(format!(
+                "Aggregation pushdown = [{}]",
+                aggregations
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
         res
     }
 
     pub fn estimated_selectivity(&self, schema: &Schema) -> f64 {
+        self.estimated_selectivity_with_stats(schema, &SelectivityStats::new())
+    }
+
+    /// Like [`Pushdowns::estimated_selectivity`], refined by the statistics of the scanned columns.
+    pub fn estimated_selectivity_with_stats(
+        &self,
+        schema: &Schema,
+        stats: &SelectivityStats,
+    ) -> f64 {
         if let Some(filters) = &self.filters {
-            estimated_selectivity(filters, schema)
+            estimated_selectivity_with_stats(filters, schema, stats)
         } else {
             1.0
         }
@@ -133,6 +205,26 @@ impl DisplayAs for Pushdowns {
                 if let Some(limit) = self.limit {
                     sub_items.push(format!("limit: {limit}"));
                 }
+                if let Some(struct_fields) = &self.struct_fields {
+                    sub_items.push(format!(
+                        "struct_fields: [{}]",
+                        struct_fields
+                            .iter()
+                            .map(|path| path.join("."))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ));
+                }
+                if let Some(aggregations) = &self.aggregations {
+                    sub_items.push(format!(
+                        "aggregations: [{}]",
+                        aggregations
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ));
+                }
                 s.push_str(&sub_items.join(", "));
                 s.push('}');
                 s
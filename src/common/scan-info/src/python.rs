@@ -127,12 +127,30 @@ pub mod pylib {
             self.0.columns.as_deref().cloned()
         }
 
+        #[getter]
+        #[must_use]
+        pub fn aggregations(&self) -> Option<Vec<PyExpr>> {
+            self.0.aggregations.as_ref().map(|aggregations| {
+                aggregations
+                    .iter()
+                    .map(|e| PyExpr { expr: e.clone() })
+                    .collect()
+            })
+        }
+
         pub fn filter_required_column_names(&self) -> Option<Vec<String>> {
             self.0
                 .filters
                 .as_ref()
                 .map(daft_dsl::optimization::get_required_columns)
         }
+
+        /// Returns these pushdowns with `filters` instead of their filters, e.g. the part of them left for Daft to
+        /// apply after a source applied the rest.
+        #[must_use]
+        pub fn with_filters(&self, filters: Option<PyExpr>) -> Self {
+            Self(Arc::new(self.0.with_filters(filters.map(|f| f.expr))))
+        }
     }
 }
 
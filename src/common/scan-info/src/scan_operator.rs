@@ -5,9 +5,10 @@ use std::{
 };
 
 use common_error::DaftResult;
+use daft_dsl::ExprRef;
 use daft_schema::schema::SchemaRef;
 
-use crate::{PartitionField, Pushdowns, ScanTaskLikeRef};
+use crate::{DatasetStatistics, PartitionField, Pushdowns, ScanTaskLikeRef};
 
 pub trait ScanOperator: Send + Sync + Debug {
     fn name(&self) -> &str;
@@ -30,7 +31,16 @@ pub trait ScanOperator: Send + Sync + Debug {
     fn can_absorb_filter(&self) -> bool;
     fn can_absorb_select(&self) -> bool;
     fn can_absorb_limit(&self) -> bool;
+    /// Whether the scan tasks can compute `aggregation`, a count, minimum or maximum of a column, over the rows that
+    /// they read with `pushdowns`, e.g. from file metadata or with a database query, instead of reading the rows.
+    fn can_absorb_aggregation(
+        &self,
+        aggregation: &ExprRef,
+        pushdowns: &Pushdowns,
+    ) -> DaftResult<bool>;
     fn multiline_display(&self) -> Vec<String>;
+    /// Statistics of the whole dataset, if they were computed and persisted by analyzing it.
+    fn statistics(&self) -> Option<Arc<DatasetStatistics>>;
 
     /// If cfg provided, `to_scan_tasks` should apply the appropriate transformations
     /// (merging, splitting) to the outputted scan tasks
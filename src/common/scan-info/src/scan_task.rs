@@ -1,5 +1,6 @@
 use std::{
     any::Any,
+    collections::BTreeMap,
     fmt::Debug,
     hash::{Hash, Hasher},
     sync::{Arc, OnceLock},
@@ -9,6 +10,7 @@ use common_daft_config::DaftExecutionConfig;
 use common_display::DisplayAs;
 use common_error::DaftResult;
 use common_file_formats::FileFormatConfig;
+use daft_dsl::LiteralValue;
 use daft_schema::schema::SchemaRef;
 
 use crate::Pushdowns;
@@ -23,10 +25,25 @@ pub trait ScanTaskLike: Debug + DisplayAs + Send + Sync {
     fn materialized_schema(&self) -> SchemaRef;
     #[must_use]
     fn num_rows(&self) -> Option<usize>;
+    /// Whether some rows of the files that the task reads are deleted, e.g. by Iceberg delete files or Delta Lake
+    /// deletion vectors, so that the metadata of the files also describes rows that the task doesn't read.
+    #[must_use]
+    fn has_deleted_rows(&self) -> bool;
     #[must_use]
     fn approx_num_rows(&self, config: Option<&DaftExecutionConfig>) -> Option<f64>;
     #[must_use]
     fn upper_bound_rows(&self) -> Option<usize>;
+    /// The approximate number of distinct non-null values of the columns for which it is known, counted over all
+    /// [`ScanTaskLike::upper_bound_rows`] rows of the task before any filters.
+    #[must_use]
+    fn num_distinct(&self) -> Option<&BTreeMap<String, usize>>;
+    /// The exact minimum and maximum of the non-null values of a column over all the rows that the task reads, e.g.
+    /// because it's a partition column, or `None` if they aren't known exactly.
+    #[must_use]
+    fn column_bounds(&self, column: &str) -> Option<(LiteralValue, LiteralValue)>;
+    /// The value of a column in every row that the task reads, if it's a partition column.
+    #[must_use]
+    fn partition_value(&self, column: &str) -> Option<LiteralValue>;
     #[must_use]
     fn size_bytes_on_disk(&self) -> Option<usize>;
     #[must_use]
@@ -37,6 +54,12 @@ pub trait ScanTaskLike: Debug + DisplayAs + Send + Sync {
     fn pushdowns(&self) -> &Pushdowns;
     #[must_use]
     fn schema(&self) -> SchemaRef;
+    /// A copy of the task that reads its data with different pushdowns.
+    #[must_use]
+    fn with_pushdowns(&self, pushdowns: Pushdowns) -> ScanTaskLikeRef;
+    /// The path of every file that the task reads along with its current version, e.g. its ETag or modification
+    /// time, or `None` if the version can't be determined. This looks up every file, so it does IO.
+    fn file_versions(&self) -> DaftResult<Vec<(String, Option<String>)>>;
 }
 
 pub type ScanTaskLikeRef = Arc<dyn ScanTaskLike>;
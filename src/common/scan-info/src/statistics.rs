@@ -0,0 +1,125 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::{Hash, Hasher},
+};
+
+use daft_dsl::{ColumnSelectivityStats, Histogram, SelectivityStats};
+use serde::{Deserialize, Serialize};
+
+/// The name of the file in the root directory of a dataset that holds its [`DatasetStatistics`].
+pub const DATASET_STATISTICS_FILE_NAME: &str = "_daft_statistics.json";
+
+/// Statistics of a column over a whole dataset.
+///
+/// Bounds and histograms are only recorded for numeric and temporal columns, in terms of their physical values.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DatasetColumnStatistics {
+    #[serde(default)]
+    pub min: Option<f64>,
+    #[serde(default)]
+    pub max: Option<f64>,
+    pub null_count: usize,
+    /// The approximate number of distinct non-null values, if they could be counted for the type of the column.
+    #[serde(default)]
+    pub num_distinct: Option<usize>,
+    /// The boundaries of an equi-depth histogram of the non-null values, see [`Histogram`].
+    #[serde(default)]
+    pub histogram: Option<Vec<f64>>,
+}
+
+impl DatasetColumnStatistics {
+    fn histogram(&self) -> Option<Histogram> {
+        self.histogram.clone().and_then(Histogram::new)
+    }
+}
+
+/// Column statistics of a dataset, computed by scanning all of it with `daft.analyze_parquet` and persisted next to its
+/// files, so that the planner can use them for later scans of the dataset.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DatasetStatistics {
+    pub num_rows: usize,
+    pub columns: BTreeMap<String, DatasetColumnStatistics>,
+}
+
+impl DatasetStatistics {
+    /// The NDVs of the columns of the dataset.
+    #[must_use]
+    pub fn num_distinct(&self) -> HashMap<String, usize> {
+        self.columns
+            .iter()
+            .filter_map(|(column, stats)| Some((column.clone(), stats.num_distinct?)))
+            .collect()
+    }
+
+    /// The histograms of the columns of the dataset that have one.
+    #[must_use]
+    pub fn histograms(&self) -> HashMap<String, Histogram> {
+        self.columns
+            .iter()
+            .filter_map(|(column, stats)| Some((column.clone(), stats.histogram()?)))
+            .collect()
+    }
+
+    /// The column stats used to estimate the selectivity of predicates on the dataset.
+    #[must_use]
+    pub fn selectivity_stats(&self) -> SelectivityStats {
+        self.columns
+            .iter()
+            .map(|(column, stats)| {
+                let selectivity_stats = ColumnSelectivityStats {
+                    min: stats.min,
+                    max: stats.max,
+                    num_distinct: stats.num_distinct.map(|n| n as f64),
+                    null_fraction: (self.num_rows > 0)
+                        .then(|| stats.null_count as f64 / self.num_rows as f64),
+                    histogram: stats.histogram(),
+                };
+                (column.clone(), selectivity_stats)
+            })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![format!("Analyzed num rows = {}", self.num_rows)];
+        for (column, stats) in &self.columns {
+            let num_distinct = match stats.num_distinct {
+                Some(num_distinct) => format!(", approx num distinct = {num_distinct}"),
+                None => String::new(),
+            };
+            let bounds = match (stats.min, stats.max) {
+                (Some(min), Some(max)) => format!(", range = [{min}, {max}]"),
+                _ => String::new(),
+            };
+            let histogram = match stats.histogram() {
+                Some(histogram) => format!(", histogram buckets = {}", histogram.num_buckets()),
+                None => String::new(),
+            };
+            res.push(format!(
+                "Analyzed column {column}: nulls = {}{num_distinct}{bounds}{histogram}",
+                stats.null_count
+            ));
+        }
+        res
+    }
+}
+
+impl Eq for DatasetStatistics {}
+
+impl Hash for DatasetStatistics {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.num_rows.hash(state);
+        for (column, stats) in &self.columns {
+            column.hash(state);
+            stats.min.map(f64::to_bits).hash(state);
+            stats.max.map(f64::to_bits).hash(state);
+            stats.null_count.hash(state);
+            stats.num_distinct.hash(state);
+            stats
+                .histogram
+                .as_ref()
+                .map(|boundaries| boundaries.iter().map(|b| b.to_bits()).collect::<Vec<_>>())
+                .hash(state);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use std::{
     any::Any,
+    collections::BTreeMap,
     hash::{Hash, Hasher},
     sync::Arc,
 };
@@ -8,16 +9,22 @@ use common_daft_config::DaftExecutionConfig;
 use common_display::DisplayAs;
 use common_error::DaftResult;
 use common_file_formats::FileFormatConfig;
+use daft_dsl::{ExprRef, LiteralValue};
 use daft_schema::schema::SchemaRef;
 use serde::{Deserialize, Serialize};
 
-use crate::{PartitionField, Pushdowns, ScanOperator, ScanTaskLike, ScanTaskLikeRef};
+use crate::{
+    DatasetStatistics, PartitionField, Pushdowns, ScanOperator, ScanTaskLike, ScanTaskLikeRef,
+};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Hash)]
-struct DummyScanTask {
+pub struct DummyScanTask {
     pub schema: SchemaRef,
     pub pushdowns: Pushdowns,
     pub num_rows: Option<usize>,
+    pub size_bytes: Option<usize>,
+    pub partition_values: BTreeMap<String, LiteralValue>,
+    pub has_deleted_rows: bool,
 }
 
 #[derive(Debug)]
@@ -25,6 +32,8 @@ pub struct DummyScanOperator {
     pub schema: SchemaRef,
     pub num_scan_tasks: u32,
     pub num_rows_per_task: Option<usize>,
+    pub size_bytes_per_task: Option<usize>,
+    pub can_absorb_aggregations: bool,
 }
 
 #[typetag::serde]
@@ -53,6 +62,10 @@ impl ScanTaskLike for DummyScanTask {
         self.num_rows
     }
 
+    fn has_deleted_rows(&self) -> bool {
+        self.has_deleted_rows
+    }
+
     fn approx_num_rows(&self, _: Option<&DaftExecutionConfig>) -> Option<f64> {
         None
     }
@@ -61,12 +74,24 @@ impl ScanTaskLike for DummyScanTask {
         None
     }
 
+    fn num_distinct(&self) -> Option<&BTreeMap<String, usize>> {
+        None
+    }
+
+    fn column_bounds(&self, _: &str) -> Option<(LiteralValue, LiteralValue)> {
+        None
+    }
+
+    fn partition_value(&self, column: &str) -> Option<LiteralValue> {
+        self.partition_values.get(column).cloned()
+    }
+
     fn size_bytes_on_disk(&self) -> Option<usize> {
         None
     }
 
     fn estimate_in_memory_size_bytes(&self, _: Option<&DaftExecutionConfig>) -> Option<usize> {
-        None
+        self.size_bytes
     }
 
     fn file_format_config(&self) -> Arc<FileFormatConfig> {
@@ -80,6 +105,21 @@ impl ScanTaskLike for DummyScanTask {
     fn schema(&self) -> SchemaRef {
         self.schema.clone()
     }
+
+    fn with_pushdowns(&self, pushdowns: Pushdowns) -> ScanTaskLikeRef {
+        Arc::new(Self {
+            schema: self.schema.clone(),
+            pushdowns,
+            num_rows: self.num_rows,
+            size_bytes: self.size_bytes,
+            partition_values: self.partition_values.clone(),
+            has_deleted_rows: self.has_deleted_rows,
+        })
+    }
+
+    fn file_versions(&self) -> DaftResult<Vec<(String, Option<String>)>> {
+        Ok(vec![])
+    }
 }
 
 impl DisplayAs for DummyScanTask {
@@ -127,15 +167,30 @@ impl ScanOperator for DummyScanOperator {
         false
     }
 
+    fn can_absorb_aggregation(
+        &self,
+        _aggregation: &ExprRef,
+        _pushdowns: &Pushdowns,
+    ) -> DaftResult<bool> {
+        Ok(self.can_absorb_aggregations)
+    }
+
     fn multiline_display(&self) -> Vec<String> {
         vec!["DummyScanOperator".to_string()]
     }
 
+    fn statistics(&self) -> Option<Arc<DatasetStatistics>> {
+        None
+    }
+
     fn to_scan_tasks(&self, pushdowns: Pushdowns) -> DaftResult<Vec<ScanTaskLikeRef>> {
         let scan_task = Arc::new(DummyScanTask {
             schema: self.schema.clone(),
             pushdowns,
             num_rows: self.num_rows_per_task,
+            size_bytes: self.size_bytes_per_task,
+            partition_values: BTreeMap::new(),
+            has_deleted_rows: false,
         });
 
         Ok((0..self.num_scan_tasks)
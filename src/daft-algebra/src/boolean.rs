@@ -1,41 +1,37 @@
 use std::sync::Arc;
 
-use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
+use common_treenode::{Transformed, TreeNode};
 use daft_dsl::{Expr, ExprRef, Operator};
 
 pub fn split_conjunction(expr: &ExprRef) -> Vec<ExprRef> {
-    let mut splits = vec![];
-
-    expr.apply(|e| match e.as_ref() {
-        Expr::BinaryOp {
-            op: Operator::And, ..
-        }
-        | Expr::Alias(..) => Ok(TreeNodeRecursion::Continue),
-        _ => {
-            splits.push(e.clone());
-            Ok(TreeNodeRecursion::Jump)
-        }
-    })
-    .unwrap();
-
-    splits
+    split_chain(expr, Operator::And)
 }
 
 pub fn split_disjunction(expr: &ExprRef) -> Vec<ExprRef> {
-    let mut splits = vec![];
+    split_chain(expr, Operator::Or)
+}
 
-    expr.apply(|e| match e.as_ref() {
-        Expr::BinaryOp {
-            op: Operator::Or, ..
-        }
-        | Expr::Alias(..) => Ok(TreeNodeRecursion::Continue),
-        _ => {
-            splits.push(e.clone());
-            Ok(TreeNodeRecursion::Jump)
+/// The operands of a chain of `op`s, from left to right, looking through aliases.
+///
+/// The chain is walked iteratively, since generated predicates can be chains of thousands of operators.
+fn split_chain(expr: &ExprRef, op: Operator) -> Vec<ExprRef> {
+    let mut splits = vec![];
+    let mut stack = vec![expr.clone()];
+    while let Some(e) = stack.pop() {
+        match e.as_ref() {
+            Expr::BinaryOp {
+                op: chain_op,
+                left,
+                right,
+            } if *chain_op == op => {
+                // Pushed in reverse so that the operands stay in order.
+                stack.push(right.clone());
+                stack.push(left.clone());
+            }
+            Expr::Alias(inner, _) => stack.push(inner.clone()),
+            _ => splits.push(e),
         }
-    })
-    .unwrap();
-
+    }
     splits
 }
 
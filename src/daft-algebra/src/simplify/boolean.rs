@@ -1,6 +1,6 @@
 use common_error::DaftResult;
 use common_treenode::Transformed;
-use daft_dsl::{lit, Expr, ExprRef, LiteralValue, Operator};
+use daft_dsl::{is_deterministic, lit, Expr, ExprRef, LiteralValue, Operator};
 use daft_schema::{dtype::DataType, schema::SchemaRef};
 use indexmap::IndexSet;
 
@@ -79,6 +79,10 @@ pub(crate) fn simplify_boolean_expr(
 ///
 /// 4. Finally, combine all new and remaining expressions back into conjunction
 fn simplify_and(expr: ExprRef, left: &ExprRef, right: &ExprRef) -> Transformed<ExprRef> {
+    // Calls to nondeterministic functions, such as rand(), aren't common sub-expressions even if they look alike.
+    if !is_deterministic(&expr) {
+        return Transformed::no(expr);
+    }
     let left_and_exprs = split_conjunction(left);
     let right_and_exprs = split_conjunction(right);
 
@@ -173,6 +177,9 @@ fn simplify_and(expr: ExprRef, left: &ExprRef, right: &ExprRef) -> Transformed<E
 ///
 /// 4. Finally, combine all new and remaining expressions back into disjunction
 fn simplify_or(expr: ExprRef, left: &ExprRef, right: &ExprRef) -> Transformed<ExprRef> {
+    if !is_deterministic(&expr) {
+        return Transformed::no(expr);
+    }
     let left_or_exprs = split_disjunction(left);
     let right_or_exprs = split_disjunction(right);
 
@@ -1,19 +1,81 @@
 mod boolean;
 mod null;
 mod numeric;
+mod utf8;
 
 use boolean::{simplify_binary_compare, simplify_boolean_expr};
 use common_error::DaftResult;
 use common_treenode::{Transformed, TreeNode};
-use daft_dsl::{lit, Expr, ExprRef, LiteralValue};
-use daft_schema::schema::SchemaRef;
+use daft_dsl::{is_deterministic, lit, Expr, ExprRef, LiteralValue, Operator};
+use daft_schema::{dtype::DataType, schema::SchemaRef};
+use indexmap::{IndexMap, IndexSet};
 use null::simplify_expr_with_null;
 use numeric::simplify_numeric_expr;
+use utf8::simplify_like_expr;
 
-use crate::boolean::combine_disjunction;
+use crate::boolean::{combine_disjunction, split_disjunction};
 
 /// Recursively simplify expression.
 pub fn simplify_expr(expr: ExprRef, schema: &SchemaRef) -> DaftResult<Transformed<ExprRef>> {
+    // Collapse long chains of ORs first, top-down, so that the bottom-up pass doesn't have to go through them.
+    simplify_or_eq_chain(expr, schema)?.transform_data(|expr| {
+        if matches!(expr.as_ref(), Expr::BinaryOp { .. }) {
+            return simplify_binary_op_chain(expr, schema);
+        }
+        // Our simplify rules currently require bottom-up traversal to work
+        // If we introduce top-down rules in the future, please add a separate pass
+        // on the expression instead of changing this.
+        expr.map_children(|child| simplify_expr(child, schema))?
+            .transform_data(|node| simplify_node(node, schema))
+    })
+}
+
+/// Simplifies a chain of the same binary operator bottom-up, like [`simplify_expr`] does for other expressions.
+///
+/// The chain's left operands are walked iteratively rather than recursively, so that generated predicates with
+/// thousands of operators don't overflow the stack.
+fn simplify_binary_op_chain(expr: ExprRef, schema: &SchemaRef) -> DaftResult<Transformed<ExprRef>> {
+    let Expr::BinaryOp { op: chain_op, .. } = expr.as_ref() else {
+        return Ok(Transformed::no(expr));
+    };
+    let mut spine = vec![];
+    let mut leftmost = &expr;
+    while let Expr::BinaryOp { op, left, right } = leftmost.as_ref() {
+        if op != chain_op {
+            break;
+        }
+        spine.push((*op, right));
+        leftmost = left;
+    }
+
+    let Transformed {
+        data: mut simplified,
+        mut transformed,
+        ..
+    } = simplify_expr(leftmost.clone(), schema)?;
+    for (op, right) in spine.into_iter().rev() {
+        let right = simplify_expr(right.clone(), schema)?;
+        let node = simplify_node(
+            Expr::BinaryOp {
+                op,
+                left: simplified,
+                right: right.data,
+            }
+            .arced(),
+            schema,
+        )?;
+        transformed |= right.transformed || node.transformed;
+        simplified = node.data;
+    }
+    Ok(if transformed {
+        Transformed::yes(simplified)
+    } else {
+        Transformed::no(expr)
+    })
+}
+
+/// Applies the simplify rules to a node whose children have already been simplified.
+fn simplify_node(node: ExprRef, schema: &SchemaRef) -> DaftResult<Transformed<ExprRef>> {
     let simplify_fns = [
         simplify_boolean_expr,
         simplify_binary_compare,
@@ -21,27 +83,23 @@ pub fn simplify_expr(expr: ExprRef, schema: &SchemaRef) -> DaftResult<Transforme
         simplify_numeric_expr,
         simplify_misc_expr,
         simplify_is_in_expr,
+        simplify_like_expr,
     ];
 
-    // Our simplify rules currently require bottom-up traversal to work
-    // If we introduce top-down rules in the future, please add a separate pass
-    // on the expression instead of changing this.
-    expr.transform_up(|node| {
-        let dtype = node.to_field(schema)?.dtype;
-
-        let transformed = simplify_fns
-            .into_iter()
-            .try_fold(Transformed::no(node), |transformed, f| {
-                transformed.transform_data(|e| f(e, schema))
-            })?;
-
-        // cast back to original dtype if necessary
-        transformed.map_data(|new_node| {
-            Ok(if new_node.to_field(schema)?.dtype == dtype {
-                new_node
-            } else {
-                new_node.cast(&dtype)
-            })
+    let dtype = node.to_field(schema)?.dtype;
+
+    let transformed = simplify_fns
+        .into_iter()
+        .try_fold(Transformed::no(node), |transformed, f| {
+            transformed.transform_data(|e| f(e, schema))
+        })?;
+
+    // cast back to original dtype if necessary
+    transformed.map_data(|new_node| {
+        Ok(if new_node.to_field(schema)?.dtype == dtype {
+            new_node
+        } else {
+            new_node.cast(&dtype)
         })
     })
 }
@@ -80,12 +138,102 @@ fn simplify_is_in_expr(expr: ExprRef, _schema: &SchemaRef) -> DaftResult<Transfo
         _ => Transformed::no(expr),
     })
 }
+/// Whether `expr` is a literal that can be an item of an `IN` list that is equivalent to a chain of equalities.
+fn is_scalar_literal(expr: &ExprRef) -> bool {
+    matches!(expr.as_ref(), Expr::Literal(l) if !matches!(l, LiteralValue::Null | LiteralValue::Series(_)))
+}
+
+/// Rewrites the equalities of the same expression to literals in a chain of ORs into an `IN` if there are many of them,
+/// e.g. e = 1 OR e = 2 OR ... OR e = 100 -> e IN (1, 2, ..., 100)
+///
+/// Generated predicates can have thousands of these, which are much deeper and slower to evaluate than an `IN`. The chain
+/// is flattened iteratively, so that rewriting doesn't overflow the stack either. Equalities are only merged if their
+/// literals have the same type, since the items of an `IN` must have the same type. Groups of at most
+/// [`MAX_IS_IN_CHAIN_LENGTH`] equalities are left alone, since [`simplify_is_in_expr`] prefers them to an `IN`.
+fn simplify_or_eq_chain(expr: ExprRef, schema: &SchemaRef) -> DaftResult<Transformed<ExprRef>> {
+    if !matches!(
+        expr.as_ref(),
+        Expr::BinaryOp {
+            op: Operator::Or,
+            ..
+        }
+    ) {
+        return Ok(Transformed::no(expr));
+    }
+    let disjuncts = split_disjunction(&expr);
+    // Each comparison with a nondeterministic value, such as rand(), evaluates it separately.
+    if !disjuncts.iter().all(is_deterministic) {
+        return Ok(Transformed::no(expr));
+    }
+
+    // The items that each expression is compared to, by the expression and the type of the items.
+    let mut groups: IndexMap<(&ExprRef, DataType), Vec<ExprRef>> = IndexMap::new();
+    let mut disjunct_groups = Vec::with_capacity(disjuncts.len());
+    for disjunct in &disjuncts {
+        let (value, items) = match disjunct.as_ref() {
+            Expr::BinaryOp {
+                op: Operator::Eq,
+                left,
+                right,
+            } if is_scalar_literal(right) => (left, std::slice::from_ref(right)),
+            Expr::BinaryOp {
+                op: Operator::Eq,
+                left,
+                right,
+            } if is_scalar_literal(left) => (right, std::slice::from_ref(left)),
+            Expr::IsIn(value, list) if list.iter().all(is_scalar_literal) => {
+                (value, list.as_slice())
+            }
+            _ => {
+                disjunct_groups.push(None);
+                continue;
+            }
+        };
+        let mut dtypes = items
+            .iter()
+            .map(|item| item.get_type(schema))
+            .collect::<DaftResult<IndexSet<_>>>()?;
+        if dtypes.len() == 1 {
+            let entry = groups.entry((value, dtypes.pop().unwrap()));
+            disjunct_groups.push(Some(entry.index()));
+            entry.or_default().extend(items.iter().cloned());
+        } else {
+            disjunct_groups.push(None);
+        }
+    }
+    if groups
+        .values()
+        .all(|items| items.len() <= MAX_IS_IN_CHAIN_LENGTH)
+    {
+        return Ok(Transformed::no(expr));
+    }
+
+    // Each large group is replaced by an `IN` where its first equality was.
+    let mut rewritten = vec![false; groups.len()];
+    let simplified = disjuncts
+        .iter()
+        .zip(disjunct_groups)
+        .filter_map(|(disjunct, group)| match group {
+            Some(group) => {
+                let ((value, _), items) = groups.get_index(group).unwrap();
+                if items.len() <= MAX_IS_IN_CHAIN_LENGTH {
+                    Some(disjunct.clone())
+                } else if std::mem::replace(&mut rewritten[group], true) {
+                    None
+                } else {
+                    Some((*value).clone().is_in(items.clone()))
+                }
+            }
+            None => Some(disjunct.clone()),
+        });
+    Ok(Transformed::yes(combine_disjunction(simplified).unwrap()))
+}
+
 #[cfg(test)]
 mod test {
     use std::sync::Arc;
 
-    use common_error::DaftResult;
-    use daft_dsl::{lit, null_lit, resolved_col, ExprRef};
+    use daft_dsl::{binary_op, lit, null_lit, resolved_col, Expr, ExprRef, Operator};
     use daft_schema::{
         dtype::DataType,
         field::Field,
@@ -104,6 +252,7 @@ mod test {
                 Field::new("a", DataType::Boolean),
                 Field::new("b", DataType::Boolean),
                 Field::new("c", DataType::Boolean),
+                Field::new("str", DataType::Utf8),
             ])
             .unwrap(),
         )
@@ -218,4 +367,136 @@ mod test {
         assert_eq!(optimized.data, expected);
         Ok(())
     }
+
+    #[rstest]
+    fn test_or_eq_chain_to_is_in(schema: SchemaRef) -> DaftResult<()> {
+        let chain = |values: std::ops::Range<i32>| {
+            values
+                .map(|value| resolved_col("int").eq(lit(value)))
+                .reduce(|acc, eq| acc.or(eq))
+                .unwrap()
+        };
+
+        // e = 0 OR e = 1 OR ... OR e = 1999 --> e IN (0, 1, ..., 1999)
+        let optimized = simplify_expr(chain(0..2000), &schema)?;
+        assert!(optimized.transformed);
+        assert_eq!(
+            optimized.data,
+            resolved_col("int").is_in((0..2000).map(lit).collect())
+        );
+
+        // Equalities with the literal on the left and existing INs are merged into the IN.
+        let input = lit(0)
+            .eq(resolved_col("int"))
+            .or(resolved_col("int").is_in((1..8).map(lit).collect()))
+            .or(resolved_col("int").eq(lit(8)));
+        let optimized = simplify_expr(input, &schema)?;
+        assert_eq!(
+            optimized.data,
+            resolved_col("int").is_in((0..9).map(lit).collect())
+        );
+
+        // Short chains are left as ORs.
+        let optimized = simplify_expr(chain(0..3), &schema)?;
+        assert!(!optimized.transformed);
+
+        // Equalities are merged even if the chain has other disjuncts.
+        let input = chain(0..10).or(resolved_col("bool"));
+        let optimized = simplify_expr(input, &schema)?;
+        assert_eq!(
+            optimized.data,
+            resolved_col("int")
+                .is_in((0..10).map(lit).collect())
+                .or(resolved_col("bool"))
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_or_eq_chain_with_mixed_types_to_is_in(schema: SchemaRef) -> DaftResult<()> {
+        // The items of an IN must have the same type, so equalities to literals of different types aren't merged.
+        let input = (0..20)
+            .map(|value| {
+                resolved_col("int").eq(if value % 2 == 0 {
+                    lit(value)
+                } else {
+                    lit(i64::from(value))
+                })
+            })
+            .reduce(|acc, eq| acc.or(eq))
+            .unwrap();
+        let optimized = simplify_expr(input, &schema)?;
+        assert_eq!(
+            optimized.data,
+            resolved_col("int")
+                .is_in((0..20).step_by(2).map(lit).collect())
+                .or(resolved_col("int").is_in(
+                    (1..20)
+                        .step_by(2)
+                        .map(|value| lit(i64::from(value)))
+                        .collect()
+                ))
+        );
+
+        // Fewer equalities of each type than would be rewritten are left alone.
+        let input = resolved_col("int")
+            .eq(lit(1))
+            .or(resolved_col("int").eq(lit(2i64)))
+            .or(resolved_col("int").eq(lit(3)))
+            .or(resolved_col("int").eq(lit(4i64)))
+            .or(resolved_col("int").eq(lit(5)))
+            .or(resolved_col("int").eq(lit(6i64)));
+        let optimized = simplify_expr(input, &schema)?;
+        assert!(!optimized.transformed);
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_long_binary_op_chain(schema: SchemaRef) -> DaftResult<()> {
+        // Chains of thousands of operators are simplified without overflowing the stack.
+        let input = (1..2000)
+            .fold(resolved_col("int"), |acc, value| {
+                binary_op(Operator::Plus, acc, lit(value))
+            })
+            .gt(lit(0));
+        let optimized = simplify_expr(input.clone(), &schema)?;
+        assert_eq!(optimized.data, input);
+
+        let input = (1..2000)
+            .map(|value| {
+                resolved_col("int").eq(binary_op(Operator::Plus, resolved_col("int"), lit(value)))
+            })
+            .reduce(|acc, eq| acc.or(eq))
+            .unwrap();
+        let optimized = simplify_expr(input.clone(), &schema)?;
+        assert_eq!(optimized.data, input);
+        Ok(())
+    }
+
+    fn like(pattern: &str) -> ExprRef {
+        daft_functions::utf8::like(resolved_col("str"), lit(pattern))
+    }
+
+    #[rstest]
+    // e LIKE 'abc' --> e = 'abc'
+    #[case(like("abc"), Some(resolved_col("str").eq(lit("abc"))))]
+    // e LIKE 'abc%' --> e >= 'abc' AND e < 'abd'
+    #[case(like("abc%"), Some(resolved_col("str").gt_eq(lit("abc")).and(resolved_col("str").lt(lit("abd")))))]
+    // e LIKE 'ab\u{10FFFF}%' --> e >= 'ab\u{10FFFF}' AND e < 'ac'
+    #[case(like("ab\u{10FFFF}%"), Some(resolved_col("str").gt_eq(lit("ab\u{10FFFF}")).and(resolved_col("str").lt(lit("ac")))))]
+    // Patterns with other wildcards are left alone.
+    #[case(like("%abc"), None)]
+    #[case(like("a_c%"), None)]
+    #[case(like("%"), None)]
+    fn test_like_exprs(
+        #[case] input: ExprRef,
+        #[case] expected: Option<ExprRef>,
+        schema: SchemaRef,
+    ) -> DaftResult<()> {
+        let optimized = simplify_expr(input.clone(), &schema)?;
+
+        assert_eq!(optimized.transformed, expected.is_some());
+        assert_eq!(optimized.data, expected.unwrap_or(input));
+        Ok(())
+    }
 }
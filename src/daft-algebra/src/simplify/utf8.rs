@@ -0,0 +1,42 @@
+use common_error::DaftResult;
+use common_treenode::Transformed;
+use daft_core::array::ops::prefix_upper_bound;
+use daft_dsl::{functions::ScalarFunction, lit, Expr, ExprRef, LiteralValue};
+use daft_schema::schema::SchemaRef;
+
+/// Simplify LIKE expressions with literal patterns into comparisons, which can prune scans by their statistics.
+pub(crate) fn simplify_like_expr(
+    expr: ExprRef,
+    _schema: &SchemaRef,
+) -> DaftResult<Transformed<ExprRef>> {
+    let Expr::ScalarFunction(ScalarFunction { udf, inputs }) = expr.as_ref() else {
+        return Ok(Transformed::no(expr));
+    };
+    let [input, pattern] = inputs.as_slice() else {
+        return Ok(Transformed::no(expr));
+    };
+    let Some(LiteralValue::Utf8(pattern)) = pattern.as_literal() else {
+        return Ok(Transformed::no(expr));
+    };
+    if udf.name() != "like" {
+        return Ok(Transformed::no(expr));
+    }
+
+    let prefix = pattern.trim_end_matches('%');
+    if prefix.contains(['%', '_']) {
+        return Ok(Transformed::no(expr));
+    }
+    Ok(if prefix.len() == pattern.len() {
+        // e LIKE 'abc' -> e = 'abc'
+        Transformed::yes(input.clone().eq(lit(prefix)))
+    } else if prefix.is_empty() {
+        Transformed::no(expr)
+    } else {
+        // e LIKE 'abc%' -> e >= 'abc' AND e < 'abd'
+        let lower = input.clone().gt_eq(lit(prefix));
+        Transformed::yes(match prefix_upper_bound(prefix) {
+            Some(upper) => lower.and(input.clone().lt(lit(upper))),
+            None => lower,
+        })
+    })
+}
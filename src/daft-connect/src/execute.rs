@@ -177,7 +177,8 @@ impl ConnectSession {
 
                 let plan = translator.to_logical_plan(input).await?;
 
-                let plan = plan.table_write(&path, file_format, None, None, None)?;
+                let plan =
+                    plan.table_write(&path, file_format, None, None, None, None, None, None, None)?;
 
                 let mut result_stream = this.run_query(plan).await?;
 
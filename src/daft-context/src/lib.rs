@@ -1,11 +1,14 @@
 #![feature(mapped_lock_guards)]
-use std::sync::{Arc, OnceLock, RwLock, RwLockReadGuard};
+use std::sync::{Arc, OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 use common_daft_config::{DaftExecutionConfig, DaftPlanningConfig};
 use common_error::{DaftError, DaftResult};
+use daft_py_runners::Runner;
+#[cfg(feature = "python")]
+use daft_py_runners::RunnerConfig;
 #[cfg(feature = "python")]
 use daft_py_runners::{NativeRunner, PyRunner, RayRunner};
-use daft_py_runners::{Runner, RunnerConfig};
+use daft_session::Session;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -13,6 +16,9 @@ use pyo3::prelude::*;
 mod python;
 
 /// Wrapper around the ContextState to provide a thread-safe interface.
+///
+/// The context owns everything a query is planned and executed with: the configs,
+/// the session (i.e. attached catalogs, tables, and temporary functions) and the runner.
 /// IMPORTANT: Do not create this directly, use `get_context` instead.
 /// This is a singleton, and should only be created once.
 #[derive(Debug, Clone)]
@@ -36,6 +42,8 @@ struct ContextState {
     /// ray -> native
     /// ray -> py
     runner: Option<Arc<Runner>>,
+    /// The current session, which holds the attached catalogs, tables, and temporary functions.
+    session: Session,
 }
 
 #[derive(Debug, Default)]
@@ -73,13 +81,6 @@ impl ContextState {
     }
 }
 
-#[cfg(not(feature = "python"))]
-impl ContextState {
-    fn get_or_create_runner(&mut self) -> DaftResult<Arc<Runner>> {
-        unimplemented!()
-    }
-}
-
 #[cfg(feature = "python")]
 impl DaftContext {
     /// Retrieves the runner.
@@ -130,12 +131,42 @@ impl DaftContext {
             Ok(())
         }
     }
+}
 
+#[cfg(not(feature = "python"))]
+impl DaftContext {
+    pub fn get_or_create_runner(&self) -> DaftResult<Arc<Runner>> {
+        Err(runners_require_python())
+    }
+
+    pub fn runner(&self) -> Option<Arc<Runner>> {
+        self.state().runner.clone()
+    }
+
+    pub fn set_runner(&self, _runner: Arc<Runner>) -> DaftResult<()> {
+        Err(runners_require_python())
+    }
+}
+
+/// Runners execute queries through python, so they can't be created or set without the python feature.
+#[cfg(not(feature = "python"))]
+fn runners_require_python() -> DaftError {
+    DaftError::NotImplemented(
+        "Runners require Daft to be built with the python feature".to_string(),
+    )
+}
+
+impl DaftContext {
     /// Get a read only reference to the state.
     fn state(&self) -> RwLockReadGuard<'_, ContextState> {
         self.state.read().unwrap()
     }
 
+    /// Get a mutable reference to the state.
+    fn state_mut(&self) -> RwLockWriteGuard<'_, ContextState> {
+        self.state.write().unwrap()
+    }
+
     /// get the execution config
     pub fn execution_config(&self) -> Arc<DaftExecutionConfig> {
         self.state().config.execution.clone()
@@ -145,57 +176,43 @@ impl DaftContext {
     pub fn planning_config(&self) -> Arc<DaftPlanningConfig> {
         self.state().config.planning.clone()
     }
-}
 
-#[cfg(not(feature = "python"))]
-impl DaftContext {
-    pub fn get_or_create_runner(&self) -> DaftResult<Arc<Runner>> {
-        unimplemented!()
+    /// set the execution config, which queries executed after this call will use
+    pub fn set_execution_config(&self, config: Arc<DaftExecutionConfig>) {
+        self.state_mut().config.execution = config;
     }
 
-    pub fn runner(&self) -> Option<Arc<Runner>> {
-        unimplemented!()
+    /// set the planning config, which plans built after this call will use
+    pub fn set_planning_config(&self, config: Arc<DaftPlanningConfig>) {
+        self.state_mut().config.planning = config;
     }
 
-    pub fn set_runner(&self, runner: Arc<Runner>) -> DaftResult<()> {
-        unimplemented!()
+    /// get the current session
+    pub fn session(&self) -> Session {
+        self.state().session.clone()
     }
 
-    pub fn state(&self) -> RwLockReadGuard<'_, ContextState> {
-        unimplemented!()
-    }
-
-    pub fn state_mut(&self) -> std::sync::RwLockWriteGuard<'_, ContextState> {
-        unimplemented!()
+    /// set the current session, replacing the attached catalogs, tables, and temporary functions
+    pub fn set_session(&self, session: Session) {
+        self.state_mut().session = session;
     }
 }
 
 static DAFT_CONTEXT: OnceLock<DaftContext> = OnceLock::new();
 
-#[cfg(feature = "python")]
 pub fn get_context() -> DaftContext {
-    match DAFT_CONTEXT.get() {
-        Some(ctx) => ctx.clone(),
-        None => {
+    DAFT_CONTEXT
+        .get_or_init(|| {
             let state = ContextState {
                 config: Config::from_env(),
                 runner: None,
+                session: Session::empty(),
             };
             let state = RwLock::new(state);
             let state = Arc::new(state);
-            let ctx = DaftContext { state };
-
-            DAFT_CONTEXT
-                .set(ctx.clone())
-                .expect("Failed to set DaftContext");
-            ctx
-        }
-    }
-}
-
-#[cfg(not(feature = "python"))]
-pub fn get_context() -> DaftContext {
-    unimplemented!()
+            DaftContext { state }
+        })
+        .clone()
 }
 
 #[cfg(feature = "python")]
@@ -223,7 +240,7 @@ pub fn set_runner_ray(
     _max_task_backlog: Option<usize>,
     _force_client_mode: Option<bool>,
 ) -> DaftResult<DaftContext> {
-    unimplemented!()
+    Err(runners_require_python())
 }
 
 #[cfg(feature = "python")]
@@ -240,7 +257,7 @@ pub fn set_runner_native() -> DaftResult<DaftContext> {
 
 #[cfg(not(feature = "python"))]
 pub fn set_runner_native() -> DaftResult<DaftContext> {
-    unimplemented!()
+    Err(runners_require_python())
 }
 
 #[cfg(feature = "python")]
@@ -257,7 +274,7 @@ pub fn set_runner_py(use_thread_pool: Option<bool>) -> DaftResult<DaftContext> {
 
 #[cfg(not(feature = "python"))]
 pub fn set_runner_py(_use_thread_pool: Option<bool>) -> DaftResult<DaftContext> {
-    unimplemented!()
+    Err(runners_require_python())
 }
 
 #[cfg(feature = "python")]
@@ -341,11 +358,6 @@ fn get_runner_config_from_env() -> RunnerConfig {
     }
 }
 
-#[cfg(not(feature = "python"))]
-fn get_runner_config_from_env() -> RunnerConfig {
-    unimplemented!()
-}
-
 #[cfg(feature = "python")]
 pub fn register_modules(parent: &Bound<PyModule>) -> pyo3::PyResult<()> {
     parent.add_function(wrap_pyfunction!(
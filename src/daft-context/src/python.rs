@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use common_daft_config::{PyDaftExecutionConfig, PyDaftPlanningConfig};
+use daft_session::python::PySession;
 use pyo3::{exceptions::PyRuntimeError, prelude::*};
 
 use crate::{DaftContext, Runner, RunnerConfig};
@@ -55,30 +56,36 @@ impl PyDaftContext {
     }
     #[getter(_daft_execution_config)]
     pub fn get_daft_execution_config(&self) -> PyResult<PyDaftExecutionConfig> {
-        let state = self.inner.state.read().unwrap();
-        let config = state.config.execution.clone();
+        let config = self.inner.execution_config();
         let config = PyDaftExecutionConfig { config };
         Ok(config)
     }
 
     #[getter(_daft_planning_config)]
     pub fn get_daft_planning_config(&self) -> PyResult<PyDaftPlanningConfig> {
-        let state = self.inner.state.read().unwrap();
-        let config = state.config.planning.clone();
+        let config = self.inner.planning_config();
         let config = PyDaftPlanningConfig { config };
         Ok(config)
     }
 
     #[setter(_daft_execution_config)]
     pub fn set_daft_execution_config(&self, config: PyDaftExecutionConfig) {
-        let mut state = self.inner.state.write().unwrap();
-        state.config.execution = config.config;
+        self.inner.set_execution_config(config.config);
     }
 
     #[setter(_daft_planning_config)]
     pub fn set_daft_planning_config(&self, config: PyDaftPlanningConfig) {
-        let mut state = self.inner.state.write().unwrap();
-        state.config.planning = config.config;
+        self.inner.set_planning_config(config.config);
+    }
+
+    #[getter(_session)]
+    pub fn get_session(&self) -> PySession {
+        self.inner.session().into()
+    }
+
+    #[setter(_session)]
+    pub fn set_session(&self, session: PyRef<PySession>) {
+        self.inner.set_session((&*session).into());
     }
 
     #[getter(_runner)]
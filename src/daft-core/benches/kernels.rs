@@ -0,0 +1,374 @@
+use std::hint::black_box;
+
+use common_error::DaftResult;
+use daft_core::{
+    array::ops::DaftCompare,
+    prelude::*,
+    series::{IntoSeries, Series},
+};
+use tango_bench::{
+    benchmark_fn, tango_benchmarks, tango_main, Benchmark, IntoBenchmarks, MeasurementSettings,
+    DEFAULT_SETTINGS,
+};
+
+const N_ROWS: usize = 64 * 1024;
+const NULL_DENSITIES: [(&str, f64); 3] = [
+    ("no_nulls", 0.0),
+    ("10pct_nulls", 0.1),
+    ("50pct_nulls", 0.5),
+];
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Int64,
+    Float64,
+    Utf8,
+}
+
+impl Kind {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Int64 => "i64",
+            Self::Float64 => "f64",
+            Self::Utf8 => "utf8",
+        }
+    }
+}
+
+/// Generates a deterministic series of the given kind with roughly `null_density` nulls.
+fn generate(kind: Kind, len: usize, null_density: f64, seed: u64) -> Series {
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let mut valid = move || null_density == 0.0 || rng.f64() >= null_density;
+    // Derived so that the values of series generated from seeds `s` and `s ^ 1` don't overlap with their validities.
+    let mut values = fastrand::Rng::with_seed(seed ^ 0x9e37_79b9_7f4a_7c15);
+    match kind {
+        Kind::Int64 => Int64Array::from_regular_iter(
+            Field::new("a", DataType::Int64),
+            (0..len).map(|_| valid().then(|| values.i64(-1_000_000..1_000_000))),
+        )
+        .unwrap()
+        .into_series(),
+        Kind::Float64 => Float64Array::from_regular_iter(
+            Field::new("a", DataType::Float64),
+            (0..len).map(|_| valid().then(|| values.f64().mul_add(2e6, -1e6))),
+        )
+        .unwrap()
+        .into_series(),
+        Kind::Utf8 => {
+            let strings = (0..len)
+                .map(|_| {
+                    valid().then(|| {
+                        let n = values.usize(1..24);
+                        std::iter::repeat_with(|| values.alphanumeric())
+                            .take(n)
+                            .collect::<String>()
+                    })
+                })
+                .collect::<arrow2::array::Utf8Array<i64>>();
+            Utf8Array::from(("a", Box::new(strings))).into_series()
+        }
+    }
+}
+
+/// Cartesian product of dtypes and null densities for a given kernel.
+fn cases(kinds: &[Kind]) -> Vec<(Kind, &'static str, f64)> {
+    kinds
+        .iter()
+        .flat_map(|&kind| {
+            NULL_DENSITIES
+                .iter()
+                .map(move |&(density_name, density)| (kind, density_name, density))
+        })
+        .collect()
+}
+
+fn bench_binary_min_max() -> Vec<Benchmark> {
+    let mut benchmarks = vec![];
+    for (_, density_name, density) in cases(&[Kind::Float64]) {
+        for broadcast in [false, true] {
+            let shape = if broadcast {
+                "broadcast"
+            } else {
+                "elementwise"
+            };
+            benchmarks.push(benchmark_fn(
+                format!("binary_min/f64/{density_name}/{shape}"),
+                move |b| {
+                    let lhs = generate(Kind::Float64, N_ROWS, density, b.seed);
+                    let rhs_len = if broadcast { 1 } else { N_ROWS };
+                    let rhs = generate(Kind::Float64, rhs_len, 0.0, b.seed ^ 1);
+                    b.iter(move || {
+                        let lhs = lhs.f64().unwrap();
+                        let rhs = rhs.f64().unwrap();
+                        black_box(lhs.binary_apply(rhs, f64::min).unwrap())
+                    })
+                },
+            ));
+            benchmarks.push(benchmark_fn(
+                format!("binary_max/f64/{density_name}/{shape}"),
+                move |b| {
+                    let lhs = generate(Kind::Float64, N_ROWS, density, b.seed);
+                    let rhs_len = if broadcast { 1 } else { N_ROWS };
+                    let rhs = generate(Kind::Float64, rhs_len, 0.0, b.seed ^ 1);
+                    b.iter(move || {
+                        let lhs = lhs.f64().unwrap();
+                        let rhs = rhs.f64().unwrap();
+                        black_box(lhs.binary_apply(rhs, f64::max).unwrap())
+                    })
+                },
+            ));
+        }
+    }
+    benchmarks
+}
+
+/// Generates a deterministic series of non-zero `i64` divisors with roughly `null_density` nulls.
+fn generate_divisors(len: usize, null_density: f64, seed: u64) -> Series {
+    let mut rng = fastrand::Rng::with_seed(seed);
+    Int64Array::from_regular_iter(
+        Field::new("b", DataType::Int64),
+        (0..len)
+            .map(|_| (null_density == 0.0 || rng.f64() >= null_density).then(|| rng.i64(1..1000))),
+    )
+    .unwrap()
+    .into_series()
+}
+
+fn bench_add_sub_mul() -> Vec<Benchmark> {
+    let mut benchmarks = vec![];
+    for (kind, density_name, density) in cases(&[Kind::Int64, Kind::Float64]) {
+        for broadcast in [false, true] {
+            let shape = if broadcast {
+                "broadcast"
+            } else {
+                "elementwise"
+            };
+            let ops: [(&str, fn(&Series, &Series) -> DaftResult<Series>); 3] = [
+                ("add", |l, r| l + r),
+                ("sub", |l, r| l - r),
+                ("mul", |l, r| l * r),
+            ];
+            for (op_name, op) in ops {
+                benchmarks.push(benchmark_fn(
+                    format!("{op_name}/{}/{density_name}/{shape}", kind.name()),
+                    move |b| {
+                        let lhs = generate(kind, N_ROWS, density, b.seed);
+                        let rhs = generate(
+                            kind,
+                            if broadcast { 1 } else { N_ROWS },
+                            density,
+                            b.seed ^ 1,
+                        );
+                        b.iter(move || black_box(op(&lhs, &rhs).unwrap()))
+                    },
+                ));
+            }
+        }
+    }
+    benchmarks
+}
+
+fn bench_div_rem() -> Vec<Benchmark> {
+    let mut benchmarks = vec![];
+    for (_, density_name, density) in cases(&[Kind::Int64]) {
+        for broadcast in [false, true] {
+            let shape = if broadcast {
+                "broadcast"
+            } else {
+                "elementwise"
+            };
+            benchmarks.push(benchmark_fn(
+                format!("div/i64/{density_name}/{shape}"),
+                move |b| {
+                    let lhs =
+                        generate(Kind::Int64, if broadcast { 1 } else { N_ROWS }, 0.0, b.seed);
+                    let rhs = generate_divisors(N_ROWS, density, b.seed ^ 1);
+                    b.iter(move || black_box((&lhs / &rhs).unwrap()))
+                },
+            ));
+            benchmarks.push(benchmark_fn(
+                format!("rem/i64/{density_name}/{shape}"),
+                move |b| {
+                    let lhs =
+                        generate(Kind::Int64, if broadcast { 1 } else { N_ROWS }, 0.0, b.seed);
+                    let rhs = generate_divisors(N_ROWS, density, b.seed ^ 1);
+                    b.iter(move || black_box((&lhs % &rhs).unwrap()))
+                },
+            ));
+        }
+    }
+    benchmarks
+}
+
+fn bench_clip() -> Vec<Benchmark> {
+    let mut benchmarks = vec![];
+    for (kind, density_name, density) in cases(&[Kind::Int64, Kind::Float64]) {
+        for broadcast in [false, true] {
+            let shape = if broadcast {
+                "broadcast"
+            } else {
+                "elementwise"
+            };
+            benchmarks.push(benchmark_fn(
+                format!("clip/{}/{density_name}/{shape}", kind.name()),
+                move |b| {
+                    let values = generate(kind, N_ROWS, density, b.seed);
+                    let bound_len = if broadcast { 1 } else { N_ROWS };
+                    // Bounds must satisfy lower <= upper, so derive the upper bound by shifting
+                    // the lower one past the generated value range.
+                    let lower = generate(kind, bound_len, density, b.seed ^ 1);
+                    let shift = Int64Array::from(("shift", vec![2_000_000]))
+                        .into_series()
+                        .cast(lower.data_type())
+                        .unwrap();
+                    let upper = (&lower + &shift).unwrap();
+                    b.iter(move || black_box(values.clip(&lower, &upper).unwrap()))
+                },
+            ));
+        }
+    }
+    benchmarks
+}
+
+fn bench_comparison() -> Vec<Benchmark> {
+    let mut benchmarks = vec![];
+    for (kind, density_name, density) in cases(&[Kind::Int64, Kind::Float64, Kind::Utf8]) {
+        for broadcast in [false, true] {
+            let shape = if broadcast {
+                "broadcast"
+            } else {
+                "elementwise"
+            };
+            benchmarks.push(benchmark_fn(
+                format!("equal/{}/{density_name}/{shape}", kind.name()),
+                move |b| {
+                    let lhs = generate(kind, N_ROWS, density, b.seed);
+                    let rhs = generate(
+                        kind,
+                        if broadcast { 1 } else { N_ROWS },
+                        density,
+                        b.seed ^ 1,
+                    );
+                    b.iter(move || black_box(lhs.equal(&rhs).unwrap()))
+                },
+            ));
+            benchmarks.push(benchmark_fn(
+                format!("lt/{}/{density_name}/{shape}", kind.name()),
+                move |b| {
+                    let lhs = generate(kind, N_ROWS, density, b.seed);
+                    let rhs = generate(
+                        kind,
+                        if broadcast { 1 } else { N_ROWS },
+                        density,
+                        b.seed ^ 1,
+                    );
+                    b.iter(move || black_box(lhs.lt(&rhs).unwrap()))
+                },
+            ));
+        }
+    }
+    benchmarks
+}
+
+fn bench_cast() -> Vec<Benchmark> {
+    let targets = [
+        (Kind::Int64, DataType::Float64),
+        (Kind::Int64, DataType::Int32),
+        (Kind::Int64, DataType::Utf8),
+        (Kind::Float64, DataType::Int64),
+        (Kind::Float64, DataType::Utf8),
+    ];
+    let mut benchmarks = vec![];
+    for (kind, target) in targets {
+        for (density_name, density) in NULL_DENSITIES {
+            let target = target.clone();
+            benchmarks.push(benchmark_fn(
+                format!("cast/{}_to_{target}/{density_name}", kind.name()),
+                move |b| {
+                    let series = generate(kind, N_ROWS, density, b.seed);
+                    let target = target.clone();
+                    b.iter(move || black_box(series.cast(&target).unwrap()))
+                },
+            ));
+        }
+    }
+    benchmarks
+}
+
+fn bench_hash() -> Vec<Benchmark> {
+    cases(&[Kind::Int64, Kind::Float64, Kind::Utf8])
+        .into_iter()
+        .map(|(kind, density_name, density)| {
+            benchmark_fn(format!("hash/{}/{density_name}", kind.name()), move |b| {
+                let series = generate(kind, N_ROWS, density, b.seed);
+                b.iter(move || black_box(series.hash(None).unwrap()))
+            })
+        })
+        .collect()
+}
+
+fn bench_filter() -> Vec<Benchmark> {
+    cases(&[Kind::Int64, Kind::Utf8])
+        .into_iter()
+        .map(|(kind, density_name, density)| {
+            benchmark_fn(format!("filter/{}/{density_name}", kind.name()), move |b| {
+                let series = generate(kind, N_ROWS, density, b.seed);
+                let mut rng = fastrand::Rng::with_seed(b.seed ^ 1);
+                let mask = BooleanArray::from((
+                    "mask",
+                    (0..N_ROWS)
+                        .map(|_| rng.bool())
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                ));
+                b.iter(move || black_box(series.filter(&mask).unwrap()))
+            })
+        })
+        .collect()
+}
+
+fn bench_take() -> Vec<Benchmark> {
+    cases(&[Kind::Int64, Kind::Utf8])
+        .into_iter()
+        .map(|(kind, density_name, density)| {
+            benchmark_fn(format!("take/{}/{density_name}", kind.name()), move |b| {
+                let series = generate(kind, N_ROWS, density, b.seed);
+                let mut rng = fastrand::Rng::with_seed(b.seed ^ 1);
+                let idx = UInt64Array::from((
+                    "idx",
+                    (0..N_ROWS)
+                        .map(|_| rng.u64(0..N_ROWS as u64))
+                        .collect::<Vec<_>>(),
+                ))
+                .into_series();
+                b.iter(move || black_box(series.take(&idx).unwrap()))
+            })
+        })
+        .collect()
+}
+
+fn all_benchmarks() -> impl IntoBenchmarks {
+    let mut benchmarks = vec![];
+    benchmarks.extend(bench_binary_min_max());
+    benchmarks.extend(bench_add_sub_mul());
+    benchmarks.extend(bench_div_rem());
+    benchmarks.extend(bench_clip());
+    benchmarks.extend(bench_comparison());
+    benchmarks.extend(bench_cast());
+    benchmarks.extend(bench_hash());
+    benchmarks.extend(bench_filter());
+    benchmarks.extend(bench_take());
+    benchmarks
+}
+
+const SETTINGS: MeasurementSettings = MeasurementSettings {
+    // Enable cache firewall to reduce cache effects
+    cache_firewall: Some(64), // 64KB cache firewall
+    // Enable yielding to reduce scheduler effects
+    yield_before_sample: true,
+    // Rest of settings from default
+    ..DEFAULT_SETTINGS
+};
+
+tango_benchmarks!(all_benchmarks());
+tango_main!(SETTINGS);
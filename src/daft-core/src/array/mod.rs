@@ -201,6 +201,6 @@ mod tests {
         let daft_fld = Arc::new(Field::new("test", DataType::Utf8));
 
         let s = Series::from_arrow(daft_fld, data);
-        assert!(s.is_ok())
+        assert!(s.is_ok());
     }
 }
@@ -1,4 +1,7 @@
-use std::ops::{Add, Div, Mul, Rem, Sub};
+use std::{
+    iter::zip,
+    ops::{Add, Div, Mul, Rem, Sub},
+};
 
 use arrow2::{array::PrimitiveArray, compute::arithmetics::basic};
 use common_error::{DaftError, DaftResult};
@@ -10,6 +13,7 @@ use crate::{
     kernels::utf8::add_utf8_arrays,
     prelude::Decimal128Array,
     series::Series,
+    utils::arrow::arrow_bitmap_and_helper,
 };
 // Permission is hereby granted, free of charge, to any person obtaining a copy
 // of this software and associated documentation files (the "Software"), to deal
@@ -77,7 +81,7 @@ where
 {
     type Output = DaftResult<DataArray<T>>;
     fn add(self, rhs: Self) -> Self::Output {
-        arithmetic_helper(self, rhs, basic::add, |l, r| l + r)
+        arithmetic_with_nulls(self, rhs, |l, r| l + r)
     }
 }
 
@@ -139,7 +143,7 @@ where
 {
     type Output = DaftResult<DataArray<T>>;
     fn sub(self, rhs: Self) -> Self::Output {
-        arithmetic_helper(self, rhs, basic::sub, |l, r| l - r)
+        arithmetic_with_nulls(self, rhs, |l, r| l - r)
     }
 }
 
@@ -150,10 +154,14 @@ where
 {
     type Output = DaftResult<DataArray<T>>;
     fn mul(self, rhs: Self) -> Self::Output {
-        arithmetic_helper(self, rhs, basic::mul, |l, r| l * r)
+        arithmetic_with_nulls(self, rhs, |l, r| l * r)
     }
 }
 
+/// Applies `op` to the rows of `lhs` and `rhs` that are both valid, the result being null wherever either side is.
+///
+/// `op` is never evaluated on the values under null slots since they're arbitrary, e.g. an integer division by the zero
+/// under a null would panic.
 pub fn binary_with_nulls<T, F>(
     lhs: &PrimitiveArray<T>,
     rhs: &PrimitiveArray<T>,
@@ -164,19 +172,71 @@ where
     F: Fn(T, T) -> T,
 {
     assert!(lhs.len() == rhs.len(), "expected same length");
-    let values = lhs.iter().zip(rhs.iter()).map(|(l, r)| match (l, r) {
-        (None, _) => None,
-        (_, None) => None,
-        (Some(l), Some(r)) => Some(op(*l, *r)),
-    });
-    unsafe { PrimitiveArray::<T>::from_trusted_len_iter_unchecked(values) }
+    let validity = arrow_bitmap_and_helper(lhs.validity(), rhs.validity());
+    let values = zip(lhs.values().iter(), rhs.values().iter());
+    let values: Vec<T> = match validity.as_ref().filter(|v| v.unset_bits() > 0) {
+        None => values.map(|(l, r)| op(*l, *r)).collect(),
+        Some(validity) => zip(values, validity.iter())
+            .map(|((l, r), is_valid)| if is_valid { op(*l, *r) } else { T::default() })
+            .collect(),
+    };
+    PrimitiveArray::new(lhs.data_type().clone(), values.into(), validity)
 }
 
-fn rem_with_nulls<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+/// Applies `op` to the valid rows of `array`, keeping its validity.
+fn unary_with_nulls<T, F>(array: &PrimitiveArray<T>, op: F) -> PrimitiveArray<T>
 where
-    T: arrow2::types::NativeType + std::ops::Rem<Output = T>,
+    T: arrow2::types::NativeType,
+    F: Fn(T) -> T,
 {
-    binary_with_nulls(lhs, rhs, |a, b| a % b)
+    let values: Vec<T> = match array.validity().filter(|v| v.unset_bits() > 0) {
+        None => array.values().iter().map(|v| op(*v)).collect(),
+        Some(validity) => zip(array.values().iter(), validity.iter())
+            .map(|(v, is_valid)| if is_valid { op(*v) } else { T::default() })
+            .collect(),
+    };
+    PrimitiveArray::new(
+        array.data_type().clone(),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
+
+/// Like [`arithmetic_helper`], but `op` is only applied to the rows where both sides are valid, working on the raw
+/// values and the validity bitmaps instead of going through an arrow kernel.
+fn arithmetic_with_nulls<T, F>(
+    lhs: &DataArray<T>,
+    rhs: &DataArray<T>,
+    op: F,
+) -> DaftResult<DataArray<T>>
+where
+    T: DaftPrimitiveType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    match (lhs.len(), rhs.len()) {
+        (a, b) if a == b => DataArray::new(
+            lhs.field.clone(),
+            Box::new(binary_with_nulls(lhs.as_arrow(), rhs.as_arrow(), op)),
+        ),
+        // broadcast right path
+        (_, 1) => match rhs.get(0) {
+            None => Ok(DataArray::full_null(lhs.name(), lhs.data_type(), lhs.len())),
+            Some(rhs) => DataArray::new(
+                lhs.field.clone(),
+                Box::new(unary_with_nulls(lhs.as_arrow(), |lhs| op(lhs, rhs))),
+            ),
+        },
+        (1, _) => match lhs.get(0) {
+            None => Ok(DataArray::full_null(rhs.name(), lhs.data_type(), rhs.len())),
+            Some(lhs_value) => DataArray::new(
+                lhs.field.clone(),
+                Box::new(unary_with_nulls(rhs.as_arrow(), |rhs| op(lhs_value, rhs))),
+            ),
+        },
+        (a, b) => Err(DaftError::ValueError(format!(
+            "Cannot apply operation on arrays of different lengths: {a} vs {b}"
+        ))),
+    }
 }
 
 impl<T> Rem for &DataArray<T>
@@ -186,54 +246,10 @@ where
 {
     type Output = DaftResult<DataArray<T>>;
     fn rem(self, rhs: Self) -> Self::Output {
-        if rhs.data().null_count() == 0 {
-            arithmetic_helper(self, rhs, basic::rem, |l, r| l % r)
-        } else {
-            match (self.len(), rhs.len()) {
-                (a, b) if a == b => Ok(DataArray::from((
-                    self.name(),
-                    Box::new(rem_with_nulls(self.as_arrow(), rhs.as_arrow())),
-                ))),
-                // broadcast right path
-                (_, 1) => {
-                    let opt_rhs = rhs.get(0);
-                    match opt_rhs {
-                        None => Ok(DataArray::full_null(
-                            self.name(),
-                            self.data_type(),
-                            self.len(),
-                        )),
-                        Some(rhs) => self.apply(|lhs| lhs % rhs),
-                    }
-                }
-                (1, _) => {
-                    let opt_lhs = self.get(0);
-                    Ok(match opt_lhs {
-                        None => DataArray::full_null(rhs.name(), rhs.data_type(), rhs.len()),
-                        Some(lhs) => {
-                            let values_iter = rhs.as_arrow().iter().map(|v| v.map(|v| lhs % *v));
-                            let arrow_array = unsafe {
-                                PrimitiveArray::from_trusted_len_iter_unchecked(values_iter)
-                            };
-                            DataArray::from((self.name(), Box::new(arrow_array)))
-                        }
-                    })
-                }
-                (a, b) => Err(DaftError::ValueError(format!(
-                    "Cannot apply operation on arrays of different lengths: {a} vs {b}"
-                ))),
-            }
-        }
+        arithmetic_with_nulls(self, rhs, |l, r| l % r)
     }
 }
 
-fn div_with_nulls<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
-where
-    T: arrow2::types::NativeType + Div<Output = T>,
-{
-    binary_with_nulls(lhs, rhs, |a, b| a / b)
-}
-
 impl<T> Div for &DataArray<T>
 where
     T: DaftNumericType,
@@ -241,44 +257,7 @@ where
 {
     type Output = DaftResult<DataArray<T>>;
     fn div(self, rhs: Self) -> Self::Output {
-        if rhs.data().null_count() == 0 {
-            arithmetic_helper(self, rhs, basic::div, |l, r| l / r)
-        } else {
-            match (self.len(), rhs.len()) {
-                (a, b) if a == b => Ok(DataArray::from((
-                    self.name(),
-                    Box::new(div_with_nulls(self.as_arrow(), rhs.as_arrow())),
-                ))),
-                // broadcast right path
-                (_, 1) => {
-                    let opt_rhs = rhs.get(0);
-                    match opt_rhs {
-                        None => Ok(DataArray::full_null(
-                            self.name(),
-                            self.data_type(),
-                            self.len(),
-                        )),
-                        Some(rhs) => self.apply(|lhs| lhs / rhs),
-                    }
-                }
-                (1, _) => {
-                    let opt_lhs = self.get(0);
-                    Ok(match opt_lhs {
-                        None => DataArray::full_null(rhs.name(), rhs.data_type(), rhs.len()),
-                        Some(lhs) => {
-                            let values_iter = rhs.as_arrow().iter().map(|v| v.map(|v| lhs / *v));
-                            let arrow_array = unsafe {
-                                PrimitiveArray::from_trusted_len_iter_unchecked(values_iter)
-                            };
-                            DataArray::from((self.name(), Box::new(arrow_array)))
-                        }
-                    })
-                }
-                (a, b) => Err(DaftError::ValueError(format!(
-                    "Cannot apply operation on arrays of different lengths: {a} vs {b}"
-                ))),
-            }
-        }
+        arithmetic_with_nulls(self, rhs, |l, r| l / r)
     }
 }
 
@@ -299,48 +278,7 @@ impl Div for &Decimal128Array {
                 |l, r| ((l * scale) / r),
             )
         } else {
-            match (self.len(), rhs.len()) {
-                (a, b) if a == b => {
-                    let values = self
-                        .as_arrow()
-                        .iter()
-                        .zip(rhs.as_arrow().iter())
-                        .map(|(l, r)| match (l, r) {
-                            (None, _) => None,
-                            (_, None) => None,
-                            (Some(l), Some(r)) => Some((l * scale) / r),
-                        });
-                    Ok(Decimal128Array::from_iter(self.field.clone(), values))
-                }
-                // broadcast right path
-                (_, 1) => {
-                    let opt_rhs = rhs.get(0);
-                    match opt_rhs {
-                        None => Ok(DataArray::full_null(
-                            self.name(),
-                            self.data_type(),
-                            self.len(),
-                        )),
-                        Some(rhs) => self.apply(|lhs| ((lhs * scale) / rhs)),
-                    }
-                }
-                (1, _) => {
-                    let opt_lhs = self.get(0);
-                    Ok(match opt_lhs {
-                        None => DataArray::full_null(rhs.name(), rhs.data_type(), rhs.len()),
-                        Some(lhs) => {
-                            let values_iter = rhs
-                                .as_arrow()
-                                .iter()
-                                .map(|v| v.map(|v| ((lhs * scale) / *v)));
-                            Decimal128Array::from_iter(self.field.clone(), values_iter)
-                        }
-                    })
-                }
-                (a, b) => Err(DaftError::ValueError(format!(
-                    "Cannot apply operation on arrays of different lengths: {a} vs {b}"
-                ))),
-            }
+            arithmetic_with_nulls(self, rhs, |l, r| (l * scale) / r)
         }
     }
 }
@@ -1,5 +1,5 @@
 use arrow2::{
-    array::{ord::build_compare, Array, PrimitiveArray},
+    array::{Array, PrimitiveArray},
     datatypes::DataType,
     error::Result,
 };
@@ -7,7 +7,7 @@ use common_error::DaftResult;
 use num_traits::Float;
 
 use crate::{
-    kernels::search_sorted::{build_is_valid, cmp_float},
+    kernels::search_sorted::{build_compare_with_nan, build_is_valid, cmp_float},
     series::Series,
 };
 
@@ -44,7 +44,8 @@ fn build_is_equal_with_nan(
     {
         Ok(build_is_equal_float::<f64>(left, right, nulls_equal))
     } else {
-        let comp = build_compare(left, right)?;
+        // Nested values are equal if all their elements are, where null elements equal each other.
+        let comp = build_compare_with_nan(left, right)?;
         Ok(Box::new(move |i, j| comp(i, j).is_eq()))
     }
 }
@@ -1,8 +1,4 @@
-use std::{
-    iter::repeat_n,
-    ops::{Div, Mul},
-    sync::Arc,
-};
+use std::{iter::repeat_n, ops::Div, sync::Arc};
 
 use arrow2::{
     array::Array,
@@ -231,6 +227,52 @@ pub fn timestamp_to_str_tz(val: i64, unit: &TimeUnit, tz: &chrono_tz::Tz) -> Str
     chrono_ts.format(format_str).to_string()
 }
 
+/// Converts the physical values of a temporal array from `from` time units to `to` time units.
+///
+/// Converting to a finer unit errors if a value overflows, rather than wrapping around. Converting to a coarser unit
+/// rounds towards negative infinity, so that values before the epoch stay within the unit of time that contains them.
+fn convert_time_unit(
+    physical: &Int64Array,
+    from: &TimeUnit,
+    to: &TimeUnit,
+) -> DaftResult<Int64Array> {
+    match from.cmp(to) {
+        std::cmp::Ordering::Equal => Ok(physical.clone()),
+        std::cmp::Ordering::Greater => {
+            let factor = to.to_scale_factor() / from.to_scale_factor();
+            let values = physical
+                .as_arrow()
+                .iter()
+                .map(|value| {
+                    value
+                        .map(|value| {
+                            value.checked_mul(factor).ok_or_else(|| {
+                                DaftError::ComputeError(format!(
+                                    "Overflow when converting {value} from {from} to {to}, which is out of range for {to}"
+                                ))
+                            })
+                        })
+                        .transpose()
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            Ok(Int64Array::from_iter(
+                physical.field.clone(),
+                values.into_iter(),
+            ))
+        }
+        std::cmp::Ordering::Less => {
+            let factor = from.to_scale_factor() / to.to_scale_factor();
+            Ok(Int64Array::from_iter(
+                physical.field.clone(),
+                physical
+                    .as_arrow()
+                    .iter()
+                    .map(|value| value.map(|value| value.div_euclid(factor))),
+            ))
+        }
+    }
+}
+
 impl TimestampArray {
     pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
         match dtype {
@@ -242,19 +284,7 @@ impl TimestampArray {
                     DataType::Timestamp(tu, _) => tu,
                     _ => panic!("Wrong dtype for TimestampArray: {}", self.data_type()),
                 };
-                let physical = match self_tu.cmp(tu) {
-                    std::cmp::Ordering::Equal => self.physical.clone(),
-                    std::cmp::Ordering::Greater => {
-                        let factor = tu.to_scale_factor() / self_tu.to_scale_factor();
-                        self.physical
-                            .mul(&Int64Array::from(("factor", vec![factor])))?
-                    }
-                    std::cmp::Ordering::Less => {
-                        let factor = self_tu.to_scale_factor() / tu.to_scale_factor();
-                        self.physical
-                            .div(&Int64Array::from(("factor", vec![factor])))?
-                    }
-                };
+                let physical = convert_time_unit(&self.physical, self_tu, tu)?;
                 Ok(
                     TimestampArray::new(Field::new(self.name(), dtype.clone()), physical)
                         .into_series(),
@@ -319,19 +349,7 @@ impl TimeArray {
                     DataType::Time(tu) => tu,
                     _ => panic!("Wrong dtype for TimeArray: {}", self.data_type()),
                 };
-                let physical = match self_tu.cmp(tu) {
-                    std::cmp::Ordering::Equal => self.physical.clone(),
-                    std::cmp::Ordering::Greater => {
-                        let factor = tu.to_scale_factor() / self_tu.to_scale_factor();
-                        self.physical
-                            .mul(&Int64Array::from(("factor", vec![factor])))?
-                    }
-                    std::cmp::Ordering::Less => {
-                        let factor = self_tu.to_scale_factor() / tu.to_scale_factor();
-                        self.physical
-                            .div(&Int64Array::from(("factor", vec![factor])))?
-                    }
-                };
+                let physical = convert_time_unit(&self.physical, self_tu, tu)?;
                 Ok(TimeArray::new(Field::new(self.name(), dtype.clone()), physical).into_series())
             }
             DataType::Utf8 => {
@@ -367,6 +385,16 @@ impl DurationArray {
                 Ok(NullArray::full_null(self.name(), dtype, self.len()).into_series())
             }
             dtype if dtype == self.data_type() => Ok(self.clone().into_series()),
+            DataType::Duration(tu) => {
+                let DataType::Duration(self_tu) = self.data_type() else {
+                    panic!("Wrong dtype for DurationArray: {}", self.data_type())
+                };
+                let physical = convert_time_unit(&self.physical, self_tu, tu)?;
+                Ok(
+                    DurationArray::new(Field::new(self.name(), dtype.clone()), physical)
+                        .into_series(),
+                )
+            }
             dtype if dtype.is_numeric() => self.physical.cast(dtype),
             DataType::Int64 => Ok(self.physical.clone().into_series()),
             #[cfg(feature = "python")]
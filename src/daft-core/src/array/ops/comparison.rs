@@ -1,6 +1,9 @@
-use std::ops::Not;
+use std::{iter::zip, ops::Not};
 
-use arrow2::{compute::comparison, scalar::PrimitiveScalar};
+use arrow2::{
+    array::PrimitiveArray, bitmap::Bitmap, compute::comparison, scalar::PrimitiveScalar,
+    types::NativeType,
+};
 use common_error::{DaftError, DaftResult};
 use num_traits::{NumCast, ToPrimitive};
 
@@ -31,14 +34,10 @@ where
 
     fn equal(&self, rhs: &Self) -> Self::Output {
         match (self.len(), rhs.len()) {
-            (x, y) if x == y => {
-                let validity =
-                    arrow_bitmap_and_helper(self.as_arrow().validity(), rhs.as_arrow().validity());
-                Ok(BooleanArray::from((
-                    self.name(),
-                    comparison::eq(self.as_arrow(), rhs.as_arrow()).with_validity(validity),
-                )))
-            }
+            (x, y) if x == y => Ok(BooleanArray::from((
+                self.name(),
+                compare_with_nulls(self.as_arrow(), rhs.as_arrow(), |l, r| l == r),
+            ))),
             (l_size, 1) => {
                 if let Some(value) = rhs.get(0) {
                     Ok(self.equal(value))
@@ -148,14 +147,10 @@ where
 
     fn not_equal(&self, rhs: &Self) -> Self::Output {
         match (self.len(), rhs.len()) {
-            (x, y) if x == y => {
-                let validity =
-                    arrow_bitmap_and_helper(self.as_arrow().validity(), rhs.as_arrow().validity());
-                Ok(BooleanArray::from((
-                    self.name(),
-                    comparison::neq(self.as_arrow(), rhs.as_arrow()).with_validity(validity),
-                )))
-            }
+            (x, y) if x == y => Ok(BooleanArray::from((
+                self.name(),
+                compare_with_nulls(self.as_arrow(), rhs.as_arrow(), |l, r| l != r),
+            ))),
             (l_size, 1) => {
                 if let Some(value) = rhs.get(0) {
                     Ok(self.not_equal(value))
@@ -188,14 +183,10 @@ where
 
     fn lt(&self, rhs: &Self) -> Self::Output {
         match (self.len(), rhs.len()) {
-            (x, y) if x == y => {
-                let validity =
-                    arrow_bitmap_and_helper(self.as_arrow().validity(), rhs.as_arrow().validity());
-                Ok(BooleanArray::from((
-                    self.name(),
-                    comparison::lt(self.as_arrow(), rhs.as_arrow()).with_validity(validity),
-                )))
-            }
+            (x, y) if x == y => Ok(BooleanArray::from((
+                self.name(),
+                compare_with_nulls(self.as_arrow(), rhs.as_arrow(), |l, r| l < r),
+            ))),
             (l_size, 1) => {
                 if let Some(value) = rhs.get(0) {
                     Ok(self.lt(value))
@@ -228,14 +219,10 @@ where
 
     fn lte(&self, rhs: &Self) -> Self::Output {
         match (self.len(), rhs.len()) {
-            (x, y) if x == y => {
-                let validity =
-                    arrow_bitmap_and_helper(self.as_arrow().validity(), rhs.as_arrow().validity());
-                Ok(BooleanArray::from((
-                    self.name(),
-                    comparison::lt_eq(self.as_arrow(), rhs.as_arrow()).with_validity(validity),
-                )))
-            }
+            (x, y) if x == y => Ok(BooleanArray::from((
+                self.name(),
+                compare_with_nulls(self.as_arrow(), rhs.as_arrow(), |l, r| l <= r),
+            ))),
             (l_size, 1) => {
                 if let Some(value) = rhs.get(0) {
                     Ok(self.lte(value))
@@ -268,14 +255,10 @@ where
 
     fn gt(&self, rhs: &Self) -> Self::Output {
         match (self.len(), rhs.len()) {
-            (x, y) if x == y => {
-                let validity =
-                    arrow_bitmap_and_helper(self.as_arrow().validity(), rhs.as_arrow().validity());
-                Ok(BooleanArray::from((
-                    self.name(),
-                    comparison::gt(self.as_arrow(), rhs.as_arrow()).with_validity(validity),
-                )))
-            }
+            (x, y) if x == y => Ok(BooleanArray::from((
+                self.name(),
+                compare_with_nulls(self.as_arrow(), rhs.as_arrow(), |l, r| l > r),
+            ))),
             (l_size, 1) => {
                 if let Some(value) = rhs.get(0) {
                     Ok(self.gt(value))
@@ -308,14 +291,10 @@ where
 
     fn gte(&self, rhs: &Self) -> Self::Output {
         match (self.len(), rhs.len()) {
-            (x, y) if x == y => {
-                let validity =
-                    arrow_bitmap_and_helper(self.as_arrow().validity(), rhs.as_arrow().validity());
-                Ok(BooleanArray::from((
-                    self.name(),
-                    comparison::gt_eq(self.as_arrow(), rhs.as_arrow()).with_validity(validity),
-                )))
-            }
+            (x, y) if x == y => Ok(BooleanArray::from((
+                self.name(),
+                compare_with_nulls(self.as_arrow(), rhs.as_arrow(), |l, r| l >= r),
+            ))),
             (l_size, 1) => {
                 if let Some(value) = rhs.get(0) {
                     Ok(self.gte(value))
@@ -347,6 +326,27 @@ where
     }
 }
 
+/// Compares the raw values of `lhs` and `rhs` with `op`, the result being null wherever either side is.
+///
+/// Unlike arithmetic, comparing the arbitrary values under nulls is harmless, so every row is compared without checking
+/// its validity, and the validities are combined once.
+fn compare_with_nulls<T, F>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+    op: F,
+) -> arrow2::array::BooleanArray
+where
+    T: NativeType,
+    F: Fn(T, T) -> bool,
+{
+    assert!(lhs.len() == rhs.len(), "expected same length");
+    let values = Bitmap::from_trusted_len_iter(
+        zip(lhs.values().iter(), rhs.values().iter()).map(|(l, r)| op(*l, *r)),
+    );
+    let validity = arrow_bitmap_and_helper(lhs.validity(), rhs.validity());
+    arrow2::array::BooleanArray::new(arrow2::datatypes::DataType::Boolean, values, validity)
+}
+
 impl<T> DataArray<T>
 where
     T: DaftPrimitiveType,
@@ -354,19 +354,19 @@ where
     fn compare_to_scalar(
         &self,
         rhs: T::Native,
-        func: impl Fn(
-            &dyn arrow2::array::Array,
-            &dyn arrow2::scalar::Scalar,
-        ) -> arrow2::array::BooleanArray,
+        op: impl Fn(T::Native, T::Native) -> bool,
     ) -> BooleanArray {
         let arrow_array = self.as_arrow();
-
-        let scalar = PrimitiveScalar::new(arrow_array.data_type().clone(), Some(rhs));
-
-        let validity = self.as_arrow().validity().cloned();
-
-        let arrow_result = func(self.as_arrow(), &scalar).with_validity(validity);
-        DataArray::from((self.name(), arrow_result))
+        let values =
+            Bitmap::from_trusted_len_iter(arrow_array.values().iter().map(|l| op(*l, rhs)));
+        DataArray::from((
+            self.name(),
+            arrow2::array::BooleanArray::new(
+                arrow2::datatypes::DataType::Boolean,
+                values,
+                arrow_array.validity().cloned(),
+            ),
+        ))
     }
 }
 
@@ -380,37 +380,37 @@ where
     fn equal(&self, rhs: Scalar) -> Self::Output {
         let rhs: T::Native =
             NumCast::from(rhs).expect("could not cast to underlying DataArray type");
-        self.compare_to_scalar(rhs, comparison::eq_scalar)
+        self.compare_to_scalar(rhs, |l, r| l == r)
     }
 
     fn not_equal(&self, rhs: Scalar) -> Self::Output {
         let rhs: T::Native =
             NumCast::from(rhs).expect("could not cast to underlying DataArray type");
-        self.compare_to_scalar(rhs, comparison::neq_scalar)
+        self.compare_to_scalar(rhs, |l, r| l != r)
     }
 
     fn lt(&self, rhs: Scalar) -> Self::Output {
         let rhs: T::Native =
             NumCast::from(rhs).expect("could not cast to underlying DataArray type");
-        self.compare_to_scalar(rhs, comparison::lt_scalar)
+        self.compare_to_scalar(rhs, |l, r| l < r)
     }
 
     fn lte(&self, rhs: Scalar) -> Self::Output {
         let rhs: T::Native =
             NumCast::from(rhs).expect("could not cast to underlying DataArray type");
-        self.compare_to_scalar(rhs, comparison::lt_eq_scalar)
+        self.compare_to_scalar(rhs, |l, r| l <= r)
     }
 
     fn gt(&self, rhs: Scalar) -> Self::Output {
         let rhs: T::Native =
             NumCast::from(rhs).expect("could not cast to underlying DataArray type");
-        self.compare_to_scalar(rhs, comparison::gt_scalar)
+        self.compare_to_scalar(rhs, |l, r| l > r)
     }
 
     fn gte(&self, rhs: Scalar) -> Self::Output {
         let rhs: T::Native =
             NumCast::from(rhs).expect("could not cast to underlying DataArray type");
-        self.compare_to_scalar(rhs, comparison::gt_eq_scalar)
+        self.compare_to_scalar(rhs, |l, r| l >= r)
     }
 
     fn eq_null_safe(&self, rhs: Scalar) -> Self::Output {
@@ -0,0 +1,352 @@
+use std::fmt::Write;
+
+use common_error::{DaftError, DaftResult};
+use serde::{Deserialize, Serialize};
+
+use crate::datatypes::{DataType, Decimal128Array, Field, Utf8Array};
+
+/// How decimals are written as strings, such as "1,234.50" or "1.234,50".
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct DecimalFormat {
+    pub decimal_separator: char,
+    /// The separator between groups of 3 digits of the integer part, if any.
+    pub thousands_separator: Option<char>,
+}
+
+impl Default for DecimalFormat {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            thousands_separator: Some(','),
+        }
+    }
+}
+
+impl DecimalFormat {
+    pub fn try_new(decimal_separator: char, thousands_separator: Option<char>) -> DaftResult<Self> {
+        if decimal_separator.is_ascii_digit()
+            || thousands_separator.is_some_and(|c| c.is_ascii_digit() || c == decimal_separator)
+        {
+            return Err(DaftError::ValueError(format!(
+                "Expected the decimal separator {decimal_separator:?} and thousands separator {thousands_separator:?} to be distinct non-digits"
+            )));
+        }
+        Ok(Self {
+            decimal_separator,
+            thousands_separator,
+        })
+    }
+
+    fn is_thousands_separator(self, c: char) -> bool {
+        match self.thousands_separator {
+            // Spaces in numbers are often non-breaking.
+            Some(' ') => matches!(c, ' ' | '\u{a0}' | '\u{202f}'),
+            Some(separator) => c == separator,
+            None => false,
+        }
+    }
+}
+
+fn is_currency_symbol(c: char) -> bool {
+    matches!(c, '\u{20a0}'..='\u{20c0}')
+        || matches!(
+            c,
+            '$' | '¢'
+                | '£'
+                | '¤'
+                | '¥'
+                | '֏'
+                | '؋'
+                | '৲'
+                | '৳'
+                | '฿'
+                | '៛'
+                | '﷼'
+                | '﹩'
+                | '＄'
+                | '￠'
+                | '￡'
+                | '￥'
+                | '￦'
+        )
+}
+
+fn is_minus_sign(c: char) -> bool {
+    matches!(c, '-' | '\u{2212}')
+}
+
+/// Checks the text around a number, which may hold a sign and a currency, e.g. "-$" or " EUR", returning whether it
+/// has a minus sign, or None if it holds anything else.
+fn parse_affix(affix: &str) -> Option<(bool, bool)> {
+    let mut has_sign = false;
+    let mut is_negative = false;
+    let mut num_letters = 0;
+    for c in affix.chars() {
+        if c == '+' || is_minus_sign(c) {
+            if has_sign {
+                return None;
+            }
+            has_sign = true;
+            is_negative = is_minus_sign(c);
+        } else if c.is_alphabetic() {
+            // Currency codes and abbreviations, such as "USD" or "kr".
+            num_letters += 1;
+        } else if !(c.is_whitespace() || is_currency_symbol(c)) {
+            return None;
+        }
+    }
+    (num_letters <= 3).then_some((has_sign, is_negative))
+}
+
+/// Parses a formatted number, such as "$1,234.56", "(1.234,56 €)" or "USD -5", into the unscaled value of a decimal
+/// with the given precision and scale, or None if it isn't a number or doesn't fit.
+///
+/// Digits beyond the scale are rounded half away from zero.
+fn parse_decimal(s: &str, precision: usize, scale: usize, format: DecimalFormat) -> Option<i128> {
+    let mut s = s.trim();
+    let in_parentheses = s.len() >= 2 && s.starts_with('(') && s.ends_with(')');
+    if in_parentheses {
+        s = s[1..s.len() - 1].trim();
+    }
+
+    // The number spans from its first digit, or a decimal separator right before one, to its last digit.
+    let chars = s.char_indices().collect::<Vec<_>>();
+    let start = chars.iter().enumerate().find_map(|(i, &(pos, c))| {
+        let starts_fraction = c == format.decimal_separator
+            && chars
+                .get(i + 1)
+                .is_some_and(|(_, next)| next.is_ascii_digit());
+        (c.is_ascii_digit() || starts_fraction).then_some(pos)
+    })?;
+    let end = s.rfind(|c: char| c.is_ascii_digit())? + 1;
+    let (prefix_has_sign, prefix_is_negative) = parse_affix(&s[..start])?;
+    let (suffix_has_sign, suffix_is_negative) = parse_affix(&s[end..])?;
+    if (prefix_has_sign && suffix_has_sign)
+        || (in_parentheses && (prefix_has_sign || suffix_has_sign))
+    {
+        return None;
+    }
+    let is_negative = in_parentheses || prefix_is_negative || suffix_is_negative;
+
+    let mut integer_digits = String::new();
+    let mut fraction_digits = String::new();
+    let mut in_fraction = false;
+    for c in s[start..end].chars() {
+        if c.is_ascii_digit() {
+            if in_fraction {
+                fraction_digits.push(c);
+            } else {
+                integer_digits.push(c);
+            }
+        } else if c == format.decimal_separator && !in_fraction {
+            in_fraction = true;
+        } else if !(format.is_thousands_separator(c) && !in_fraction && !integer_digits.is_empty())
+        {
+            return None;
+        }
+    }
+
+    let mut value: i128 = 0;
+    for digit in integer_digits.bytes().chain(
+        fraction_digits
+            .bytes()
+            .chain(std::iter::repeat(b'0'))
+            .take(scale),
+    ) {
+        value = value
+            .checked_mul(10)?
+            .checked_add(i128::from(digit - b'0'))?;
+    }
+    if fraction_digits
+        .as_bytes()
+        .get(scale)
+        .is_some_and(|&digit| digit >= b'5')
+    {
+        value = value.checked_add(1)?;
+    }
+    if value >= 10i128.checked_pow(precision as u32)? {
+        return None;
+    }
+    Some(if is_negative { -value } else { value })
+}
+
+/// Formats the unscaled value of a decimal with the given scale, such as 123456 with a scale of 2 as "1,234.56".
+fn format_decimal(
+    value: i128,
+    scale: usize,
+    format: DecimalFormat,
+    currency_symbol: Option<&str>,
+    accounting: bool,
+) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let digits = format!("{digits:0>width$}", width = scale + 1);
+    let (integer_digits, fraction_digits) = digits.split_at(digits.len() - scale);
+
+    let mut formatted = String::with_capacity(digits.len() * 2);
+    if value < 0 {
+        formatted.push(if accounting { '(' } else { '-' });
+    }
+    formatted.push_str(currency_symbol.unwrap_or_default());
+    for (i, digit) in integer_digits.chars().enumerate() {
+        if let Some(separator) = format.thousands_separator
+            && i > 0
+            && (integer_digits.len() - i) % 3 == 0
+        {
+            formatted.push(separator);
+        }
+        formatted.push(digit);
+    }
+    if scale > 0 {
+        write!(formatted, "{}{fraction_digits}", format.decimal_separator).unwrap();
+    }
+    if value < 0 && accounting {
+        formatted.push(')');
+    }
+    formatted
+}
+
+impl Utf8Array {
+    /// Parses formatted numbers, which may have thousands separators, currencies and signs or parentheses for
+    /// negative numbers, into decimals. Strings that aren't numbers or don't fit in the precision become null.
+    pub fn parse_decimal(
+        &self,
+        precision: usize,
+        scale: usize,
+        format: DecimalFormat,
+    ) -> DaftResult<Decimal128Array> {
+        if !(1..=38).contains(&precision) || scale > precision {
+            return Err(DaftError::ValueError(format!(
+                "Expected a decimal precision between 1 and 38 and a scale of at most the precision, got {precision} and {scale}"
+            )));
+        }
+        let values = self
+            .as_arrow()
+            .iter()
+            .map(|val| parse_decimal(val?, precision, scale, format));
+        Ok(Decimal128Array::from_iter(
+            Field::new(self.name(), DataType::Decimal128(precision, scale)),
+            values,
+        ))
+    }
+}
+
+impl Decimal128Array {
+    /// Formats decimals with thousands separators and an optional currency symbol, wrapping negative values in
+    /// parentheses rather than prefixing them with a minus sign if `accounting` is set.
+    pub fn format_decimal(
+        &self,
+        format: DecimalFormat,
+        currency_symbol: Option<&str>,
+        accounting: bool,
+    ) -> DaftResult<Utf8Array> {
+        let DataType::Decimal128(_, scale) = self.data_type() else {
+            unreachable!("Decimal128Array should have a Decimal128 data type")
+        };
+        let arrow_result = self
+            .as_arrow()
+            .iter()
+            .map(|val| {
+                Some(format_decimal(
+                    *val?,
+                    *scale,
+                    format,
+                    currency_symbol,
+                    accounting,
+                ))
+            })
+            .collect::<arrow2::array::Utf8Array<i64>>();
+        Ok(Utf8Array::from((self.name(), Box::new(arrow_result))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_decimal, parse_decimal, DecimalFormat};
+
+    const US: DecimalFormat = DecimalFormat {
+        decimal_separator: '.',
+        thousands_separator: Some(','),
+    };
+    const EU: DecimalFormat = DecimalFormat {
+        decimal_separator: ',',
+        thousands_separator: Some('.'),
+    };
+
+    #[test]
+    fn check_parse_decimal() {
+        let parse = |s| parse_decimal(s, 12, 2, US);
+        assert_eq!(parse("1234.5"), Some(123_450));
+        assert_eq!(parse("1,234.56"), Some(123_456));
+        assert_eq!(parse("$1,234.56"), Some(123_456));
+        assert_eq!(parse(" -$1,234.56 "), Some(-123_456));
+        assert_eq!(parse("$-1,234.56"), Some(-123_456));
+        assert_eq!(parse("($1,234.56)"), Some(-123_456));
+        assert_eq!(parse("1,234.56-"), Some(-123_456));
+        assert_eq!(parse("USD 1,234.56"), Some(123_456));
+        assert_eq!(parse("+.5"), Some(50));
+        assert_eq!(parse("0.125"), Some(13));
+        assert_eq!(parse("-0.125"), Some(-13));
+        assert_eq!(parse("0.124"), Some(12));
+        assert_eq!(parse("7"), Some(700));
+
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("$"), None);
+        assert_eq!(parse("abc"), None);
+        assert_eq!(parse("1.2.3"), None);
+        assert_eq!(parse("1.234,56"), None);
+        assert_eq!(parse(",123"), None);
+        assert_eq!(parse("--5"), None);
+        assert_eq!(parse("-5-"), None);
+        assert_eq!(parse("(-5)"), None);
+        assert_eq!(parse("5 apples"), None);
+        assert_eq!(parse("1 2"), None);
+        // 10 integer digits don't fit in a precision of 12 with a scale of 2.
+        assert_eq!(parse("9,999,999,999.99"), Some(999_999_999_999));
+        assert_eq!(parse("10,000,000,000"), None);
+        assert_eq!(parse("9,999,999,999.995"), None);
+    }
+
+    #[test]
+    fn check_parse_decimal_formats() {
+        assert_eq!(parse_decimal("1.234,56 €", 10, 2, EU), Some(123_456));
+        assert_eq!(parse_decimal("-1.234,5", 10, 2, EU), Some(-123_450));
+        let swiss = DecimalFormat {
+            decimal_separator: '.',
+            thousands_separator: Some('\''),
+        };
+        assert_eq!(parse_decimal("CHF 1'234.50", 10, 2, swiss), Some(123_450));
+        let french = DecimalFormat {
+            decimal_separator: ',',
+            thousands_separator: Some(' '),
+        };
+        assert_eq!(
+            parse_decimal("1\u{202f}234,5\u{a0}€", 10, 2, french),
+            Some(123_450)
+        );
+        let plain = DecimalFormat {
+            decimal_separator: '.',
+            thousands_separator: None,
+        };
+        assert_eq!(parse_decimal("1234.5", 10, 0, plain), Some(1235));
+        assert_eq!(parse_decimal("1,234.5", 10, 0, plain), None);
+    }
+
+    #[test]
+    fn check_format_decimal() {
+        assert_eq!(format_decimal(123_456, 2, US, None, false), "1,234.56");
+        assert_eq!(
+            format_decimal(-123_456, 2, US, Some("$"), false),
+            "-$1,234.56"
+        );
+        assert_eq!(
+            format_decimal(-123_456, 2, US, Some("$"), true),
+            "($1,234.56)"
+        );
+        assert_eq!(format_decimal(123_456, 2, EU, None, false), "1.234,56");
+        assert_eq!(format_decimal(5, 3, US, None, false), "0.005");
+        assert_eq!(format_decimal(-5, 0, US, None, false), "-5");
+        assert_eq!(format_decimal(1_000_000, 0, US, None, false), "1,000,000");
+        assert_eq!(format_decimal(100_000, 0, US, None, false), "100,000");
+        assert_eq!(format_decimal(0, 2, US, None, true), "0.00");
+    }
+}
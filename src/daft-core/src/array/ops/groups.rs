@@ -14,6 +14,7 @@ use crate::{
         BinaryArray, BooleanArray, DaftIntegerType, DaftNumericType, FixedSizeBinaryArray,
         Float32Array, Float64Array, NullArray, Utf8Array,
     },
+    kernels::hashing::canonicalize_float,
     prelude::Decimal128Array,
 };
 
@@ -100,23 +101,17 @@ impl IntoGroups for Float32Array {
     fn make_groups(&self) -> DaftResult<super::GroupIndicesPair> {
         let array = self.as_arrow();
         if array.null_count() > 0 {
-            make_groups(array.iter().map(move |f| {
-                f.map(|v| {
-                    match v.is_nan() {
-                        true => f32::NAN,
-                        false => *v,
-                    }
-                    .to_bits()
-                })
-            }))
+            make_groups(
+                array
+                    .iter()
+                    .map(|f| f.map(|v| canonicalize_float(*v).to_bits())),
+            )
         } else {
-            make_groups(array.values_iter().map(move |f| {
-                match f.is_nan() {
-                    true => f32::NAN,
-                    false => *f,
-                }
-                .to_bits()
-            }))
+            make_groups(
+                array
+                    .values_iter()
+                    .map(|f| canonicalize_float(*f).to_bits()),
+            )
         }
     }
 }
@@ -125,23 +120,17 @@ impl IntoGroups for Float64Array {
     fn make_groups(&self) -> DaftResult<super::GroupIndicesPair> {
         let array = self.as_arrow();
         if array.null_count() > 0 {
-            make_groups(array.iter().map(move |f| {
-                f.map(|v| {
-                    match v.is_nan() {
-                        true => f64::NAN,
-                        false => *v,
-                    }
-                    .to_bits()
-                })
-            }))
+            make_groups(
+                array
+                    .iter()
+                    .map(|f| f.map(|v| canonicalize_float(*v).to_bits())),
+            )
         } else {
-            make_groups(array.values_iter().map(move |f| {
-                match f.is_nan() {
-                    true => f64::NAN,
-                    false => *f,
-                }
-                .to_bits()
-            }))
+            make_groups(
+                array
+                    .values_iter()
+                    .map(|f| canonicalize_float(*f).to_bits()),
+            )
         }
     }
 }
@@ -1,9 +1,8 @@
-use std::sync::Arc;
-
-use arrow2::types::Index;
 use common_error::{DaftError, DaftResult};
-use daft_schema::{dtype::DataType, field::Field};
-use xxhash_rust::xxh3::{xxh3_64, xxh3_64_with_seed};
+use xxhash_rust::{
+    const_xxh3,
+    xxh3::{xxh3_64, xxh3_64_with_seed},
+};
 
 use super::as_arrow::AsArrow;
 use crate::{
@@ -11,12 +10,11 @@ use crate::{
     datatypes::{
         logical::{DateArray, TimeArray, TimestampArray},
         BinaryArray, BooleanArray, DaftPrimitiveType, Decimal128Array, FixedSizeBinaryArray,
-        Int16Array, Int32Array, Int64Array, Int8Array, NullArray, UInt16Array, UInt32Array,
-        UInt64Array, UInt8Array, Utf8Array,
+        Int16Array, Int32Array, Int64Array, Int8Array, IntervalArray, NullArray, UInt16Array,
+        UInt32Array, UInt64Array, UInt8Array, Utf8Array,
     },
     kernels,
     series::Series,
-    utils::arrow::arrow_bitmap_and_helper,
 };
 
 impl<T> DataArray<T>
@@ -94,6 +92,22 @@ impl NullArray {
     }
 }
 
+impl IntervalArray {
+    pub fn hash(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        let as_arrowed = self.as_arrow();
+
+        let seed = seed.map(|v| v.as_arrow());
+
+        let result = kernels::hashing::hash(as_arrowed, seed)?;
+
+        Ok(DataArray::from((self.name(), Box::new(result))))
+    }
+}
+
+/// The hash of null rows of nested arrays, regardless of the values under them, like the hash of null values of
+/// primitive arrays.
+const NULL_HASH: u64 = const_xxh3::xxh3_64(b"");
+
 fn hash_list(
     name: &str,
     offsets: &[i64],
@@ -103,61 +117,48 @@ fn hash_list(
 ) -> DaftResult<UInt64Array> {
     // first we hash the flat child
     // turning [[stuff], [stuff, stuff], ...] into [[hash], [hash, hash], ...]
-    // then we hash each sublist as bytes, giving us [hash, hash, ...] as desired
-    // if seed is provided, the sublists are hashed with the seed broadcasted
-
-    if let Some(seed_arr) = seed {
-        let combined_validity = arrow_bitmap_and_helper(validity, seed.unwrap().validity());
-        UInt64Array::from_iter(
-            Arc::new(Field::new(name, DataType::UInt64)),
-            u64::range(0, offsets.len() - 1).unwrap().map(|i| {
-                let start = offsets[i as usize] as usize;
-                let end = offsets[i as usize + 1] as usize;
-                // apply the current seed across this row
-                let cur_seed_opt = seed_arr.get(i as usize);
-                let flat_seed = UInt64Array::from_iter(
-                    Arc::new(Field::new("seed", DataType::UInt64)),
-                    std::iter::repeat_n(cur_seed_opt, end - start),
-                );
-                let hashed_child = flat_child
-                    .slice(start, end)
-                    .ok()?
-                    .hash(Some(&flat_seed))
-                    .ok()?;
-                let child_bytes: Vec<u8> = hashed_child
-                    .as_arrow()
-                    .values_iter()
-                    .flat_map(|v| v.to_le_bytes())
-                    .collect();
-                if let Some(cur_seed) = cur_seed_opt {
-                    Some(xxh3_64_with_seed(&child_bytes, cur_seed))
-                } else {
-                    Some(xxh3_64(&child_bytes))
-                }
-            }),
-        )
-        .with_validity(combined_validity)
-    } else {
-        // since we don't have a seed we can hash entire flat child at once
-        let hashed_child = flat_child.hash(None)?;
-        // hashing collects the array anyways so this collect doesn't matter
-        let child_bytes: Vec<u8> = hashed_child
-            .as_arrow()
-            .values_iter()
-            .flat_map(|v| v.to_le_bytes())
-            .collect();
-        const OFFSET: usize = (u64::BITS as usize) / 8; // how many bytes per u64
-        let combined_validity = validity.cloned();
-        UInt64Array::from_iter(
-            Arc::new(Field::new(name, DataType::UInt64)),
-            u64::range(0, offsets.len() - 1).unwrap().map(|i| {
-                let start = (offsets[i as usize] as usize) * OFFSET;
-                let end = (offsets[i as usize + 1] as usize) * OFFSET;
-                Some(xxh3_64(&child_bytes[start..end]))
-            }),
-        )
-        .with_validity(combined_validity)
+    // then we hash each sublist as the little-endian bytes of its hashes, giving us [hash, hash, ...] as desired
+    // if seed is provided, the elements of each sublist and then the sublist are hashed with the seed of its row
+    let num_rows = offsets.len() - 1;
+    if let Some(seed) = seed {
+        if seed.len() != num_rows {
+            return Err(DaftError::ValueError(format!(
+                "seed length does not match array length: {} vs {}",
+                seed.len(),
+                num_rows
+            )));
+        }
     }
+    let seeds = seed.map(|seed| seed.as_arrow().values());
+    let flat_seed = seeds.map(|seeds| {
+        let mut flat_seed = vec![0; flat_child.len()];
+        for (i, seed) in seeds.iter().enumerate() {
+            flat_seed[offsets[i] as usize..offsets[i + 1] as usize].fill(*seed);
+        }
+        UInt64Array::from(("seed", flat_seed))
+    });
+    let hashed_child = flat_child.hash(flat_seed.as_ref())?;
+    // hashing collects the array anyways so this collect doesn't matter
+    let child_bytes: Vec<u8> = hashed_child
+        .as_arrow()
+        .values_iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect();
+    const OFFSET: usize = (u64::BITS as usize) / 8; // how many bytes per u64
+    let hashes = (0..num_rows)
+        .map(|i| {
+            if validity.is_some_and(|validity| !validity.get_bit(i)) {
+                return NULL_HASH;
+            }
+            let start = (offsets[i] as usize) * OFFSET;
+            let end = (offsets[i + 1] as usize) * OFFSET;
+            match seeds {
+                Some(seeds) => xxh3_64_with_seed(&child_bytes[start..end], seeds[i]),
+                None => xxh3_64(&child_bytes[start..end]),
+            }
+        })
+        .collect::<Vec<_>>();
+    UInt64Array::from((name, hashes)).with_validity(validity.cloned())
 }
 
 impl ListArray {
@@ -174,10 +175,9 @@ impl ListArray {
 
 impl FixedSizeListArray {
     pub fn hash(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
-        let size = self.fixed_element_len();
-        let len = self.flat_child.len() as i64;
+        let size = self.fixed_element_len() as i64;
         // see comment on hash_list for why we are collecting
-        let offsets: Vec<i64> = (0..=len).step_by(size).collect();
+        let offsets: Vec<i64> = (0..=self.len() as i64).map(|i| i * size).collect();
         hash_list(
             self.name(),
             &offsets,
@@ -202,8 +202,17 @@ impl StructArray {
         for child in self.children.iter().skip(1) {
             res = child.hash(Some(&res))?;
         }
-        res.rename(self.name())
-            .with_validity(self.validity().cloned())
+        let Some(validity) = self.validity() else {
+            return res.rename(self.name()).with_validity(None);
+        };
+        // the children of null rows may have any values, so null rows get the same hash regardless of them
+        let hashes = res
+            .as_arrow()
+            .values_iter()
+            .zip(validity.iter())
+            .map(|(hash, is_valid)| if is_valid { *hash } else { NULL_HASH })
+            .collect::<Vec<_>>();
+        UInt64Array::from((self.name(), hashes)).with_validity(Some(validity.clone()))
     }
 }
 
@@ -0,0 +1,167 @@
+use std::{collections::hash_map::Entry, hash::BuildHasherDefault};
+
+use common_error::{DaftError, DaftResult};
+use fnv::FnvHashMap;
+
+use super::{as_arrow::AsArrow, IntoGroups};
+use crate::{
+    array::DataArray,
+    datatypes::{
+        BooleanArray, DaftIntegerType, DaftNumericType, DataType, Field, UInt32Array, Utf8Array,
+    },
+};
+
+/// A [`Utf8Array`] stored as the distinct strings it contains and, for every row, the index of its string among them.
+///
+/// Filters, takes and group-bys only move the `u32` keys around, so on columns with many repeated values they never copy
+/// the string payloads. [`InternedUtf8Array::decode`] materializes the strings again.
+#[derive(Clone, Debug)]
+pub struct InternedUtf8Array {
+    /// The index into `values` of the string of every row, or null where the row is null.
+    pub keys: UInt32Array,
+    /// The distinct non-null strings, in the order they first appear.
+    pub values: Utf8Array,
+}
+
+impl Utf8Array {
+    /// Interns the strings of this array, see [`InternedUtf8Array`].
+    pub fn intern(&self) -> DaftResult<InternedUtf8Array> {
+        let mut pool =
+            FnvHashMap::<&str, u32>::with_capacity_and_hasher(256, BuildHasherDefault::default());
+        let mut values = vec![];
+        let keys = self
+            .as_arrow()
+            .iter()
+            .map(|value| {
+                value
+                    .map(|value| match pool.entry(value) {
+                        Entry::Occupied(entry) => Ok(*entry.get()),
+                        Entry::Vacant(entry) => {
+                            let key = u32::try_from(values.len()).map_err(|_| {
+                                DaftError::ComputeError(format!(
+                                    "Cannot intern more than {} distinct strings",
+                                    u32::MAX
+                                ))
+                            })?;
+                            values.push(value);
+                            Ok(*entry.insert(key))
+                        }
+                    })
+                    .transpose()
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        Ok(InternedUtf8Array {
+            keys: UInt32Array::from_regular_iter(
+                Field::new(self.name(), DataType::UInt32),
+                keys.into_iter(),
+            )?,
+            values: Utf8Array::from_values(self.name(), values.into_iter()),
+        })
+    }
+}
+
+impl InternedUtf8Array {
+    pub fn name(&self) -> &str {
+        self.values.name()
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of distinct non-null strings.
+    pub fn num_distinct(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Materializes the strings of every row.
+    pub fn decode(&self) -> DaftResult<Utf8Array> {
+        self.values.take(&self.keys)
+    }
+
+    pub fn filter(&self, mask: &BooleanArray) -> DaftResult<Self> {
+        Ok(Self {
+            keys: self.keys.filter(mask)?,
+            values: self.values.clone(),
+        })
+    }
+
+    pub fn take<I>(&self, idx: &DataArray<I>) -> DaftResult<Self>
+    where
+        I: DaftIntegerType,
+        <I as DaftNumericType>::Native: arrow2::types::Index,
+    {
+        Ok(Self {
+            keys: self.keys.take(idx)?,
+            values: self.values.clone(),
+        })
+    }
+}
+
+impl IntoGroups for InternedUtf8Array {
+    fn make_groups(&self) -> DaftResult<super::GroupIndicesPair> {
+        self.keys.make_groups()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::{as_arrow::AsArrow, IntoGroups},
+        datatypes::{BooleanArray, UInt64Array, Utf8Array},
+    };
+
+    #[test]
+    fn test_intern_round_trip() -> DaftResult<()> {
+        let array = Utf8Array::from_iter(
+            "a",
+            vec![Some("x"), None, Some("y"), Some("x"), None, Some("x")].into_iter(),
+        );
+        let interned = array.intern()?;
+
+        assert_eq!(interned.len(), 6);
+        assert_eq!(interned.num_distinct(), 2);
+        assert_eq!(
+            interned
+                .keys
+                .as_arrow()
+                .iter()
+                .map(|k| k.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(0), None, Some(1), Some(0), None, Some(0)]
+        );
+
+        let decoded = interned.decode()?;
+        assert_eq!(decoded.name(), "a");
+        assert_eq!(decoded.as_arrow(), array.as_arrow());
+        Ok(())
+    }
+
+    #[test]
+    fn test_interned_filter_take_and_groups() -> DaftResult<()> {
+        let array = Utf8Array::from_values("a", ["x", "y", "x", "z", "y"].into_iter());
+        let interned = array.intern()?;
+
+        let mask = BooleanArray::from(("mask", [true, false, true, true, false].as_slice()));
+        let filtered = interned.filter(&mask)?.decode()?;
+        assert_eq!(filtered.as_arrow(), array.filter(&mask)?.as_arrow());
+
+        let idx = UInt64Array::from(("idx", [4u64, 0, 3].as_slice()));
+        let taken = interned.take(&idx)?.decode()?;
+        assert_eq!(taken.as_arrow(), array.take(&idx)?.as_arrow());
+
+        let (mut samples, mut groups) = interned.make_groups()?;
+        samples.sort_unstable();
+        groups.sort_unstable();
+        assert_eq!(samples, vec![0, 1, 3]);
+        assert_eq!(groups, vec![vec![0, 2], vec![1, 4], vec![3]]);
+        Ok(())
+    }
+}
@@ -88,7 +88,7 @@ fn validity_size(validity: Option<&arrow2::bitmap::Bitmap>) -> usize {
 }
 
 fn offset_size(offsets: &arrow2::offset::OffsetsBuffer<i64>) -> usize {
-    offsets.len_proxy() * std::mem::size_of::<i64>()
+    offsets.len() * std::mem::size_of::<i64>()
 }
 
 impl FixedSizeListArray {
@@ -99,9 +99,13 @@ impl FixedSizeListArray {
 
 impl ListArray {
     pub fn size_bytes(&self) -> DaftResult<usize> {
-        Ok(self.flat_child.size_bytes()?
-            + validity_size(self.validity())
-            + offset_size(self.offsets()))
+        // Slicing a list array only slices its offsets, so only count the part of the child they point to.
+        let offsets = self.offsets();
+        let child_size = self
+            .flat_child
+            .slice(*offsets.first() as usize, *offsets.last() as usize)?
+            .size_bytes()?;
+        Ok(child_size + validity_size(self.validity()) + offset_size(offsets))
     }
 }
 
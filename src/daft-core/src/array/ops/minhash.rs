@@ -1,16 +1,102 @@
 use std::{collections::VecDeque, hash::BuildHasher, iter::repeat_with};
 
-use arrow2::array::{MutableArray, MutablePrimitiveArray, PrimitiveArray};
+use arrow2::{
+    array::{MutableArray, MutablePrimitiveArray, PrimitiveArray},
+    bitmap::Bitmap,
+};
 use common_error::{DaftError, DaftResult};
 use daft_minhash::load_simd;
 
 use super::{as_arrow::AsArrow, DaftMinHash};
 use crate::{
-    array::FixedSizeListArray,
-    datatypes::{DataType, Field, Utf8Array},
+    array::{FixedSizeListArray, ListArray},
+    datatypes::{DataType, Field, UInt64Array, Utf8Array},
     series::Series,
 };
 
+// Generate coefficients for MinHash permutation function: (a * x + b) % p
+//
+// The MinHash algorithm uses a hash function of the form (a * x + b) % p,
+// where 'a' and 'b' are permutation coefficients, 'x' is the input hash,
+// and 'p' is typically a large prime number.
+//
+// 1. perm_a (coefficient 'a'):
+//    - Starts from 1 to ensure 'a' is never zero
+//    - A non-zero 'a' is crucial for maintaining the bijective property of the permutation
+//
+//    Example of how bijectivity fails if a = 0:
+//    Let p = 7 (prime number)
+//    If a = 0, b = 3, the function becomes: (0 * x + 3) % 7 = 3
+//    This always outputs 3, regardless of the input x, losing the bijective property
+//
+// 2. perm_b (coefficient 'b'):
+//    - Range: 0 to (i32::MAX as u64) - 1
+//    - Can start from 0 as 'b' can be any value without affecting the permutation property
+//
+// This approach ensures valid and uniformly distributed hash values, which is
+// essential for accurate set similarity estimation in MinHash.
+macro_rules! minhash_permutations {
+    ($seed:expr, $num_hashes:expr) => {{
+        let mut rng = fastrand::Rng::with_seed($seed as u64);
+        let perm_a = repeat_with(|| rng.u64(1..(i32::MAX as u64))).take($num_hashes);
+        let perm_a_simd = load_simd(perm_a, $num_hashes);
+        let perm_b = repeat_with(|| rng.u64(0..(i32::MAX as u64))).take($num_hashes);
+        let perm_b_simd = load_simd(perm_b, $num_hashes);
+        (perm_a_simd, perm_b_simd)
+    }};
+}
+
+fn check_minhash_args(num_hashes: usize, ngram_size: usize) -> DaftResult<()> {
+    if num_hashes == 0 {
+        return Err(DaftError::ValueError(
+            "Number of hashes must be nonzero".into(),
+        ));
+    }
+    if ngram_size == 0 {
+        return Err(DaftError::ValueError("Ngram size must be nonzero".into()));
+    }
+    Ok(())
+}
+
+/// Collects the signature of each row, or None for null rows, into a FixedSizeList of UInt32s.
+fn signatures_to_fixed_size_list(
+    name: &str,
+    num_hashes: usize,
+    len: usize,
+    validity: Option<&Bitmap>,
+    signatures: impl Iterator<Item = DaftResult<Option<Vec<u32>>>>,
+) -> DaftResult<FixedSizeListArray> {
+    let mut output: MutablePrimitiveArray<u32> =
+        MutablePrimitiveArray::with_capacity(num_hashes * len);
+
+    for signature in signatures {
+        match signature? {
+            Some(signature) => output.extend(signature.into_iter().map(Some)),
+            None => {
+                for _ in 0..num_hashes {
+                    output.push_null();
+                }
+            }
+        }
+    }
+
+    let immutable_output: PrimitiveArray<u32> = output.into();
+    let output_series = Series::from_arrow(
+        Field::new(name, DataType::UInt32).into(),
+        Box::new(immutable_output),
+    )?;
+    let field = Field::new(
+        name,
+        DataType::FixedSizeList(Box::new(DataType::UInt32), num_hashes),
+    );
+
+    Ok(FixedSizeListArray::new(
+        field,
+        output_series,
+        validity.cloned(),
+    ))
+}
+
 impl DaftMinHash for Utf8Array {
     type Output = DaftResult<FixedSizeListArray>;
 
@@ -21,82 +107,90 @@ impl DaftMinHash for Utf8Array {
         seed: u32,
         hasher: &impl BuildHasher,
     ) -> Self::Output {
-        if num_hashes == 0 {
-            return Err(DaftError::ValueError(
-                "Number of hashes must be nonzero".into(),
-            ));
-        }
-        if ngram_size == 0 {
-            return Err(DaftError::ValueError("Ngram size must be nonzero".into()));
-        }
-
-        // Generate coefficients for MinHash permutation function: (a * x + b) % p
-        //
-        // The MinHash algorithm uses a hash function of the form (a * x + b) % p,
-        // where 'a' and 'b' are permutation coefficients, 'x' is the input hash,
-        // and 'p' is typically a large prime number.
-        //
-        // 1. perm_a (coefficient 'a'):
-        //    - Starts from 1 to ensure 'a' is never zero
-        //    - A non-zero 'a' is crucial for maintaining the bijective property of the permutation
-        //
-        //    Example of how bijectivity fails if a = 0:
-        //    Let p = 7 (prime number)
-        //    If a = 0, b = 3, the function becomes: (0 * x + 3) % 7 = 3
-        //    This always outputs 3, regardless of the input x, losing the bijective property
-        //
-        // 2. perm_b (coefficient 'b'):
-        //    - Range: 0 to (i32::MAX as u64) - 1
-        //    - Can start from 0 as 'b' can be any value without affecting the permutation property
-        //
-        // This approach ensures valid and uniformly distributed hash values, which is
-        // essential for accurate set similarity estimation in MinHash.
-        let mut rng = fastrand::Rng::with_seed(seed as u64);
-        let perm_a = repeat_with(|| rng.u64(1..(i32::MAX as u64))).take(num_hashes);
-        let perm_a_simd = load_simd(perm_a, num_hashes);
-        let perm_b = repeat_with(|| rng.u64(0..(i32::MAX as u64))).take(num_hashes);
-        let perm_b_simd = load_simd(perm_b, num_hashes);
-
-        let internal_arrow_representation = self.as_arrow();
-        let mut output: MutablePrimitiveArray<u32> =
-            MutablePrimitiveArray::with_capacity(num_hashes * self.len());
+        check_minhash_args(num_hashes, ngram_size)?;
+        let (perm_a_simd, perm_b_simd) = minhash_permutations!(seed, num_hashes);
 
         let mut alloc = VecDeque::new();
+        let signatures = self.as_arrow().iter().map(|elem| {
+            elem.map(|elem| {
+                daft_minhash::minhash_in(
+                    elem,
+                    (&perm_a_simd, &perm_b_simd),
+                    num_hashes,
+                    ngram_size,
+                    hasher,
+                    &mut alloc,
+                )
+            })
+            .transpose()
+        });
 
-        for elem in internal_arrow_representation {
-            let Some(elem) = elem else {
-                for _ in 0..num_hashes {
-                    output.push_null();
-                }
-                continue;
-            };
+        signatures_to_fixed_size_list(
+            self.name(),
+            num_hashes,
+            self.len(),
+            self.validity(),
+            signatures,
+        )
+    }
+}
+
+impl DaftMinHash for ListArray {
+    type Output = DaftResult<FixedSizeListArray>;
 
-            let minhash_res = daft_minhash::minhash_in(
-                elem,
+    /// Computes the MinHash of each list of string tokens, skipping null tokens.
+    ///
+    /// A list of tokens without spaces has the same signature as the string of the tokens joined by spaces.
+    fn minhash(
+        &self,
+        num_hashes: usize,
+        ngram_size: usize,
+        seed: u32,
+        hasher: &impl BuildHasher,
+    ) -> Self::Output {
+        check_minhash_args(num_hashes, ngram_size)?;
+        let (perm_a_simd, perm_b_simd) = minhash_permutations!(seed, num_hashes);
+
+        let tokens = self.flat_child.utf8()?.as_arrow();
+        let mut row_tokens = Vec::new();
+        let mut alloc = String::new();
+        let signatures = self.offsets().windows(2).enumerate().map(|(i, range)| {
+            if self.validity().is_some_and(|validity| !validity.get_bit(i)) {
+                return Ok(None);
+            }
+            row_tokens.clear();
+            row_tokens.extend((range[0] as usize..range[1] as usize).filter_map(|j| tokens.get(j)));
+            daft_minhash::minhash_tokens_in(
+                &row_tokens,
                 (&perm_a_simd, &perm_b_simd),
                 num_hashes,
                 ngram_size,
                 hasher,
                 &mut alloc,
-            )?;
-
-            output.extend(minhash_res.into_iter().map(Some));
-        }
+            )
+            .map(Some)
+        });
 
-        let immutable_output: PrimitiveArray<u32> = output.into();
-        let output_series = Series::from_arrow(
-            Field::new(self.name(), DataType::UInt32).into(),
-            Box::new(immutable_output),
-        )?;
-        let field = Field::new(
+        signatures_to_fixed_size_list(
             self.name(),
-            DataType::FixedSizeList(Box::new(DataType::UInt32), num_hashes),
-        );
-
-        Ok(FixedSizeListArray::new(
-            field,
-            output_series,
-            self.validity().cloned(),
-        ))
+            num_hashes,
+            self.len(),
+            self.validity(),
+            signatures,
+        )
+    }
+}
+
+impl Utf8Array {
+    /// Computes the 64-bit SimHash of each string from the hashes of its word n-grams.
+    pub fn simhash(&self, ngram_size: usize, hasher: &impl BuildHasher) -> DaftResult<UInt64Array> {
+        if ngram_size == 0 {
+            return Err(DaftError::ValueError("Ngram size must be nonzero".into()));
+        }
+        let mut alloc = VecDeque::new();
+        let simhashes = self.as_arrow().iter().map(|elem| {
+            elem.map(|elem| daft_minhash::simhash_in(elem, ngram_size, hasher, &mut alloc))
+        });
+        UInt64Array::from_regular_iter(Field::new(self.name(), DataType::UInt64), simhashes)
     }
 }
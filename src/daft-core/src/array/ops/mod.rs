@@ -20,6 +20,7 @@ mod comparison;
 mod concat;
 mod concat_agg;
 mod count;
+mod decimal;
 mod exp;
 mod filter;
 mod float;
@@ -32,6 +33,7 @@ mod hash;
 mod hll_merge;
 mod hll_sketch;
 mod if_else;
+mod intern;
 mod is_in;
 mod len;
 mod list;
@@ -65,9 +67,11 @@ mod utf8;
 use std::hash::BuildHasher;
 
 use common_error::DaftResult;
+pub use decimal::DecimalFormat;
 pub use hll_sketch::HLL_SKETCH_DTYPE;
+pub use intern::InternedUtf8Array;
 pub use sort::{build_multi_array_bicompare, build_multi_array_compare};
-pub use utf8::{PadPlacement, Utf8NormalizeOptions};
+pub use utf8::{prefix_upper_bound, Collation, PadPlacement, UnicodeForm, Utf8NormalizeOptions};
 
 use crate::count_mode::CountMode;
 
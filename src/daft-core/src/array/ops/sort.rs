@@ -4,7 +4,10 @@ use arrow2::{
 };
 use common_error::DaftResult;
 
-use super::{arrow2::sort::primitive::common::multi_column_idx_sort, as_arrow::AsArrow};
+use super::{
+    arrow2::sort::primitive::common::{idx_sort, multi_column_idx_sort},
+    as_arrow::AsArrow,
+};
 #[cfg(feature = "python")]
 use crate::datatypes::PythonArray;
 use crate::{
@@ -17,9 +20,9 @@ use crate::{
         },
         BinaryArray, BooleanArray, DaftIntegerType, DaftNumericType, Decimal128Array,
         ExtensionArray, FixedSizeBinaryArray, Float32Array, Float64Array, IntervalArray, NullArray,
-        Utf8Array,
+        UInt64Type, Utf8Array,
     },
-    kernels::search_sorted::{build_compare_with_nulls, cmp_float},
+    kernels::search_sorted::{build_compare_with_nan, build_compare_with_nulls, cmp_float},
     series::Series,
 };
 
@@ -708,27 +711,87 @@ impl FixedSizeBinaryArray {
     }
 }
 
-impl FixedSizeListArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for FixedSizeListArray")
-    }
-}
+macro_rules! impl_nested_sort {
+    ($da:ident) => {
+        impl $da {
+            pub fn argsort<I>(
+                &self,
+                descending: bool,
+                nulls_first: bool,
+            ) -> DaftResult<DataArray<I>>
+            where
+                I: DaftIntegerType,
+                <I as DaftNumericType>::Native: arrow2::types::Index,
+            {
+                let arrow_array = self.to_arrow();
+                let cmp = build_compare_with_nan(arrow_array.as_ref(), arrow_array.as_ref())?;
 
-impl ListArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for ListArray")
-    }
-}
+                let result = idx_sort(
+                    self.validity(),
+                    |a: &I::Native, b: &I::Native| cmp(a.to_usize(), b.to_usize()),
+                    self.len(),
+                    descending,
+                    nulls_first,
+                );
 
-impl MapArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for MapArray")
-    }
+                Ok(DataArray::<I>::from((self.name(), Box::new(result))))
+            }
+
+            pub fn argsort_multikey<I>(
+                &self,
+                others: &[Series],
+                descending: &[bool],
+                nulls_first: &[bool],
+            ) -> DaftResult<DataArray<I>>
+            where
+                I: DaftIntegerType,
+                <I as DaftNumericType>::Native: arrow2::types::Index,
+            {
+                let first_desc = *descending.first().unwrap();
+                let first_nulls_first = *nulls_first.first().unwrap();
+
+                let others_cmp = build_multi_array_compare(others, &descending[1..])?;
+
+                let arrow_array = self.to_arrow();
+                let cmp = build_compare_with_nan(arrow_array.as_ref(), arrow_array.as_ref())?;
+
+                let result = multi_column_idx_sort(
+                    self.validity(),
+                    |a: &I::Native, b: &I::Native| {
+                        let a = a.to_usize();
+                        let b = b.to_usize();
+                        let ordering = if first_desc { cmp(b, a) } else { cmp(a, b) };
+                        match ordering {
+                            std::cmp::Ordering::Equal => others_cmp(a, b),
+                            v => v,
+                        }
+                    },
+                    &others_cmp,
+                    self.len(),
+                    first_desc,
+                    first_nulls_first,
+                );
+
+                Ok(DataArray::<I>::from((self.name(), Box::new(result))))
+            }
+
+            pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+                let indices = self.argsort::<UInt64Type>(descending, nulls_first)?;
+                self.take(&indices)
+            }
+        }
+    };
 }
 
-impl StructArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for StructArray")
+// Lists are ordered lexicographically by their elements and structs field by field, see `build_compare_with_nan`.
+impl_nested_sort!(FixedSizeListArray);
+impl_nested_sort!(ListArray);
+impl_nested_sort!(StructArray);
+
+impl MapArray {
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        let new_array = self.physical.sort(descending, nulls_first)?;
+        Ok(Self::new(self.field.clone(), new_array))
     }
 }
 
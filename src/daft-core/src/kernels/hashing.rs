@@ -3,10 +3,11 @@ use arrow2::{
         Array, BinaryArray, BooleanArray, FixedSizeBinaryArray, NullArray, PrimitiveArray,
         Utf8Array,
     },
-    datatypes::{DataType, PhysicalType},
+    datatypes::{DataType, PhysicalType, PrimitiveType},
     error::{Error, Result},
     types::{NativeType, Offset},
 };
+use num_traits::Float;
 use xxhash_rust::{
     const_xxh3,
     xxh3::{xxh3_64, xxh3_64_with_seed},
@@ -38,6 +39,34 @@ fn hash_primitive<T: NativeType>(
     PrimitiveArray::<u64>::new(DataType::UInt64, hashes.into(), None)
 }
 
+/// The canonical value of a float for hashing, grouping and deduplication, so that floats that are equal to each other
+/// in group-bys, distincts and joins are also hashed equally: every NaN is the canonical NaN, and -0.0 is 0.0.
+pub fn canonicalize_float<F: Float>(v: F) -> F {
+    if v.is_nan() {
+        F::nan()
+    } else if v == F::zero() {
+        F::zero()
+    } else {
+        v
+    }
+}
+
+fn hash_float<F: NativeType + Float>(
+    array: &PrimitiveArray<F>,
+    seed: Option<&PrimitiveArray<u64>>,
+) -> PrimitiveArray<u64> {
+    let canonical = PrimitiveArray::<F>::new(
+        array.data_type().clone(),
+        array
+            .values_iter()
+            .map(|v| canonicalize_float(*v))
+            .collect::<Vec<_>>()
+            .into(),
+        array.validity().cloned(),
+    );
+    hash_primitive(&canonical, seed)
+}
+
 fn hash_boolean(array: &BooleanArray, seed: Option<&PrimitiveArray<u64>>) -> PrimitiveArray<u64> {
     const NULL_HASH: u64 = const_xxh3::xxh3_64(b"");
 
@@ -179,6 +208,12 @@ pub fn hash(array: &dyn Array, seed: Option<&PrimitiveArray<u64>>) -> Result<Pri
     Ok(match array.data_type().to_physical_type() {
         PhysicalType::Null => hash_null(array.as_any().downcast_ref().unwrap(), seed),
         PhysicalType::Boolean => hash_boolean(array.as_any().downcast_ref().unwrap(), seed),
+        PhysicalType::Primitive(PrimitiveType::Float32) => {
+            hash_float::<f32>(array.as_any().downcast_ref().unwrap(), seed)
+        }
+        PhysicalType::Primitive(PrimitiveType::Float64) => {
+            hash_float::<f64>(array.as_any().downcast_ref().unwrap(), seed)
+        }
         PhysicalType::Primitive(primitive) => with_match_hashing_primitive_type!(primitive, |$T| {
             hash_primitive::<$T>(array.as_any().downcast_ref().unwrap(), seed)
         }),
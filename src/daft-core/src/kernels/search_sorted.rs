@@ -3,7 +3,8 @@ use std::{cmp::Ordering, iter::zip};
 use arrow2::{
     array::{
         ord::{build_compare, DynComparator},
-        Array, BinaryArray, BooleanArray, FixedSizeBinaryArray, PrimitiveArray, Utf8Array,
+        Array, BinaryArray, BooleanArray, FixedSizeBinaryArray, FixedSizeListArray, ListArray,
+        MapArray, PrimitiveArray, StructArray, Utf8Array,
     },
     datatypes::{DataType, PhysicalType},
     error::{Error, Result},
@@ -419,14 +420,102 @@ fn compare_f64(left: &dyn Array, right: &dyn Array) -> DynComparator {
     Box::new(move |i, j| cmp_float::<f64>(&left.value(i), &right.value(j)))
 }
 
+/// Compares the elements of nested arrays, which may be null, ordering nulls after values like a top-level sort.
+fn build_compare_elements(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    if left.data_type() == &DataType::Null && right.data_type() == &DataType::Null {
+        return Ok(Box::new(|_, _| Ordering::Equal));
+    }
+    build_compare_with_nulls(left, right, false)
+}
+
+/// Compares lists lexicographically by their elements, with lists that are prefixes of others ordered first.
+fn compare_lists(
+    left_range: impl Fn(usize) -> (usize, usize) + Send + Sync + 'static,
+    right_range: impl Fn(usize) -> (usize, usize) + Send + Sync + 'static,
+    elements: DynComparator,
+) -> DynComparator {
+    Box::new(move |i, j| {
+        let (left_start, left_end) = left_range(i);
+        let (right_start, right_end) = right_range(j);
+        zip(left_start..left_end, right_start..right_end)
+            .map(|(l, r)| elements(l, r))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| (left_end - left_start).cmp(&(right_end - right_start)))
+    })
+}
+
+fn compare_list<O: Offset>(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let right = right.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let elements = build_compare_elements(left.values().as_ref(), right.values().as_ref())?;
+    let left_offsets = left.offsets().clone();
+    let right_offsets = right.offsets().clone();
+    Ok(compare_lists(
+        move |i| left_offsets.start_end(i),
+        move |j| right_offsets.start_end(j),
+        elements,
+    ))
+}
+
+fn compare_fixed_size_list(
+    left: &dyn Array,
+    right: &dyn Array,
+    size: usize,
+) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let right = right.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let elements = build_compare_elements(left.values().as_ref(), right.values().as_ref())?;
+    Ok(compare_lists(
+        move |i| (i * size, (i + 1) * size),
+        move |j| (j * size, (j + 1) * size),
+        elements,
+    ))
+}
+
+/// Compares maps as lists of their entries, in order.
+fn compare_map(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<MapArray>().unwrap();
+    let right = right.as_any().downcast_ref::<MapArray>().unwrap();
+    let entries = build_compare_elements(left.field().as_ref(), right.field().as_ref())?;
+    let left_offsets = left.offsets().clone();
+    let right_offsets = right.offsets().clone();
+    Ok(compare_lists(
+        move |i| left_offsets.start_end(i),
+        move |j| right_offsets.start_end(j),
+        entries,
+    ))
+}
+
+/// Compares structs field by field, in the order of their fields.
+fn compare_struct(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<StructArray>().unwrap();
+    let right = right.as_any().downcast_ref::<StructArray>().unwrap();
+    let fields = zip(left.values(), right.values())
+        .map(|(l, r)| build_compare_elements(l.as_ref(), r.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(move |i, j| {
+        fields
+            .iter()
+            .map(|field| field(i, j))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }))
+}
+
 pub fn build_compare_with_nan(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
-    if (left.data_type() == &DataType::Float32) && (right.data_type() == &DataType::Float32) {
-        Ok(compare_f32(left, right))
-    } else if (left.data_type() == &DataType::Float64) && (right.data_type() == &DataType::Float64)
-    {
-        Ok(compare_f64(left, right))
-    } else {
-        return build_compare(left, right);
+    match (left.data_type(), right.data_type()) {
+        (DataType::Float32, DataType::Float32) => Ok(compare_f32(left, right)),
+        (DataType::Float64, DataType::Float64) => Ok(compare_f64(left, right)),
+        (DataType::List(_), DataType::List(_)) => compare_list::<i32>(left, right),
+        (DataType::LargeList(_), DataType::LargeList(_)) => compare_list::<i64>(left, right),
+        (DataType::FixedSizeList(_, size), DataType::FixedSizeList(_, other)) if size == other => {
+            compare_fixed_size_list(left, right, *size)
+        }
+        (DataType::Map(..), DataType::Map(..)) => compare_map(left, right),
+        (DataType::Struct(l), DataType::Struct(r)) if l.len() == r.len() => {
+            compare_struct(left, right)
+        }
+        _ => build_compare(left, right),
     }
 }
 
@@ -17,8 +17,8 @@ use pyo3::{
 use crate::{
     array::{
         ops::{
-            as_arrow::AsArrow, trigonometry::TrigonometricFunction, DaftLogical,
-            Utf8NormalizeOptions,
+            as_arrow::AsArrow, trigonometry::TrigonometricFunction, DaftLogical, DecimalFormat,
+            UnicodeForm, Utf8NormalizeOptions,
         },
         pseudo_arrow::PseudoArrowArray,
         DataArray,
@@ -632,23 +632,92 @@ impl PySeries {
         Ok(self.series.utf8_to_datetime(format, timezone)?.into())
     }
 
+    #[pyo3(signature = (remove_punct, lowercase, nfd_unicode, white_space, form=None))]
     pub fn utf8_normalize(
         &self,
         remove_punct: bool,
         lowercase: bool,
         nfd_unicode: bool,
         white_space: bool,
+        form: Option<&str>,
     ) -> PyResult<Self> {
         let opts = Utf8NormalizeOptions {
             remove_punct,
             lowercase,
             nfd_unicode,
             white_space,
+            form: form.map(str::parse::<UnicodeForm>).transpose()?,
         };
 
         Ok(self.series.utf8_normalize(opts)?.into())
     }
 
+    pub fn utf8_remove_accents(&self) -> PyResult<Self> {
+        Ok(self.series.utf8_remove_accents()?.into())
+    }
+
+    pub fn utf8_ascii_fold(&self) -> PyResult<Self> {
+        Ok(self.series.utf8_ascii_fold()?.into())
+    }
+
+    pub fn utf8_is_email(&self) -> PyResult<Self> {
+        Ok(self.series.utf8_is_email()?.into())
+    }
+
+    pub fn utf8_is_ipv4(&self) -> PyResult<Self> {
+        Ok(self.series.utf8_is_ipv4()?.into())
+    }
+
+    pub fn utf8_is_ipv6(&self) -> PyResult<Self> {
+        Ok(self.series.utf8_is_ipv6()?.into())
+    }
+
+    pub fn utf8_is_phone_number(&self) -> PyResult<Self> {
+        Ok(self.series.utf8_is_phone_number()?.into())
+    }
+
+    pub fn utf8_ip_to_int(&self) -> PyResult<Self> {
+        Ok(self.series.utf8_ip_to_int()?.into())
+    }
+
+    pub fn utf8_extract_domain(&self) -> PyResult<Self> {
+        Ok(self.series.utf8_extract_domain()?.into())
+    }
+
+    pub fn int_to_ip(&self) -> PyResult<Self> {
+        Ok(self.series.int_to_ip()?.into())
+    }
+
+    #[pyo3(signature = (precision, scale, decimal_separator='.', thousands_separator=Some(',')))]
+    pub fn utf8_parse_decimal(
+        &self,
+        precision: usize,
+        scale: usize,
+        decimal_separator: char,
+        thousands_separator: Option<char>,
+    ) -> PyResult<Self> {
+        let format = DecimalFormat::try_new(decimal_separator, thousands_separator)?;
+        Ok(self
+            .series
+            .utf8_parse_decimal(precision, scale, format)?
+            .into())
+    }
+
+    #[pyo3(signature = (decimal_separator='.', thousands_separator=Some(','), currency_symbol=None, accounting=false))]
+    pub fn format_decimal(
+        &self,
+        decimal_separator: char,
+        thousands_separator: Option<char>,
+        currency_symbol: Option<&str>,
+        accounting: bool,
+    ) -> PyResult<Self> {
+        let format = DecimalFormat::try_new(decimal_separator, thousands_separator)?;
+        Ok(self
+            .series
+            .format_decimal(format, currency_symbol, accounting)?
+            .into())
+    }
+
     pub fn utf8_count_matches(
         &self,
         patterns: &Self,
@@ -0,0 +1,44 @@
+use common_arrow_ffi::{self as ffi, arrow_array};
+use common_error::DaftResult;
+
+use super::Series;
+use crate::utils::arrow::{cast_array_for_daft_if_needed, cast_array_from_daft_if_needed};
+
+impl Series {
+    /// Creates a Series from an [arrow-rs](https://docs.rs/arrow-array) array without copying its buffers.
+    pub fn from_arrow_rs(name: &str, array: &dyn arrow_array::Array) -> DaftResult<Self> {
+        let arrow_array = ffi::from_arrow_rs(array)?;
+        let arrow_array = cast_array_for_daft_if_needed(arrow_array);
+        Self::try_from((name, arrow_array))
+    }
+
+    /// Exports this Series as an [arrow-rs](https://docs.rs/arrow-array) array.
+    ///
+    /// Daft-specific types are converted to their Arrow representation, as in the Python `to_arrow`.
+    pub fn to_arrow_rs(&self) -> DaftResult<arrow_array::ArrayRef> {
+        let arrow_array = cast_array_from_daft_if_needed(self.to_arrow());
+        Ok(ffi::to_arrow_rs(arrow_array)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{prelude::*, series::IntoSeries};
+
+    #[test]
+    fn test_arrow_rs_roundtrip() -> DaftResult<()> {
+        let series = Utf8Array::from_iter("a", vec![Some("x"), None, Some("zz")].into_iter())
+            .into_series()
+            .slice(1, 3)?;
+        let converted = series.to_arrow_rs()?;
+        assert_eq!(converted.len(), 2);
+        assert_eq!(converted.null_count(), 1);
+
+        let roundtripped = Series::from_arrow_rs("a", converted.as_ref())?;
+        assert_eq!(roundtripped.data_type(), &DataType::Utf8);
+        assert_eq!(roundtripped.to_arrow(), series.to_arrow());
+        Ok(())
+    }
+}
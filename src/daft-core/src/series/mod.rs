@@ -1,4 +1,6 @@
 mod array_impl;
+#[cfg(feature = "arrow-rs")]
+mod arrow_rs;
 mod from;
 mod ops;
 mod serdes;
@@ -170,7 +172,7 @@ impl Series {
             [field_disp].as_slice(),
             Some([self as &dyn StrValue].as_slice()),
             Some(self.len()),
-            Some(80),
+            Some(78),
         )
     }
 
@@ -431,7 +431,9 @@ mod tests {
 
     use crate::{
         array::ops::full::FullNull,
-        datatypes::{DataType, Float32Array, Float64Array, Int32Array, Int64Array, Utf8Array},
+        datatypes::{
+            DataType, Field, Float32Array, Float64Array, Int32Array, Int64Array, Utf8Array,
+        },
         series::IntoSeries,
     };
 
@@ -510,6 +512,82 @@ mod tests {
         Ok(())
     }
     #[test]
+    fn div_and_rem_int_with_nulls() -> DaftResult<()> {
+        // The values under the nulls of `b` are zeros, which must not be divided by.
+        let a = Int64Array::from_regular_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(7), Some(8), None, Some(5)].into_iter(),
+        )?
+        .into_series();
+        let b = Int64Array::from_regular_iter(
+            Field::new("b", DataType::Int64),
+            vec![Some(2), None, Some(4), None].into_iter(),
+        )?
+        .into_series();
+
+        let c = (&a % &b)?;
+        assert_eq!(
+            c.i64()?.into_iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(1), None, None, None]
+        );
+        let c = (&a / &b)?;
+        assert_eq!(
+            c.f64()?.into_iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(3.5), None, None, None]
+        );
+
+        let scalar = Int64Array::from(("a", vec![9])).into_series();
+        let c = (&scalar % &b)?;
+        assert_eq!(
+            c.i64()?.into_iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(1), None, Some(1), None]
+        );
+        Ok(())
+    }
+    #[test]
+    fn add_sub_mul_int_with_nulls() -> DaftResult<()> {
+        let a = Int64Array::from_regular_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(7), Some(8), None, Some(5)].into_iter(),
+        )?
+        .into_series();
+        let b = Int64Array::from_regular_iter(
+            Field::new("b", DataType::Int64),
+            vec![Some(2), None, Some(4), None].into_iter(),
+        )?
+        .into_series();
+
+        let c = (&a + &b)?;
+        assert_eq!(
+            c.i64()?.into_iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(9), None, None, None]
+        );
+        let c = (&a - &b)?;
+        assert_eq!(
+            c.i64()?.into_iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(5), None, None, None]
+        );
+        let c = (&a * &b)?;
+        assert_eq!(
+            c.i64()?.into_iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(14), None, None, None]
+        );
+
+        let scalar = Int64Array::from(("a", vec![3])).into_series();
+        let c = (&scalar - &b)?;
+        assert_eq!(c.name(), "a");
+        assert_eq!(
+            c.i64()?.into_iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(1), None, Some(-1), None]
+        );
+        let c = (&a * &scalar)?;
+        assert_eq!(
+            c.i64()?.into_iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(21), Some(24), None, Some(15)]
+        );
+        Ok(())
+    }
+    #[test]
     fn add_int_and_int_full_null() -> DaftResult<()> {
         let a = Int64Array::from(("a", vec![1, 2, 3]));
         let b = Int64Array::full_null("b", &DataType::Int64, 3);
@@ -0,0 +1,74 @@
+use arrow2::bitmap::Bitmap;
+use common_error::{DaftError, DaftResult};
+use indexmap::map::RawEntryApiV1;
+
+use crate::{
+    array::{
+        ops::{arrow2::comparison::build_is_equal, full::FullNull},
+        FixedSizeListArray,
+    },
+    datatypes::{DataType, Field, UInt64Array, UInt8Array},
+    prelude::AsArrow,
+    series::{IntoSeries, Series},
+};
+
+impl Series {
+    /// Finds the index of each value in `categories`, or null if the value is null or isn't one of them.
+    ///
+    /// The categories are cast to the type of this series, and repeated categories get the index of their first
+    /// occurrence.
+    pub fn category_indices(&self, categories: &Self) -> DaftResult<UInt64Array> {
+        if matches!(self.data_type(), DataType::Null) {
+            return Ok(UInt64Array::full_null(
+                self.name(),
+                &DataType::UInt64,
+                self.len(),
+            ));
+        }
+        let categories = categories.cast(self.data_type())?;
+        let probe_table = categories.build_probe_table_without_nulls()?;
+        let hashes = self.hash_with_validity(None)?;
+        let comparator = build_is_equal(&*self.to_arrow(), &*categories.to_arrow(), true, false)?;
+        let indices = hashes.as_arrow().iter().enumerate().map(|(idx, hash)| {
+            let hash = *hash?;
+            probe_table
+                .raw_entry_v1()
+                .from_hash(hash, |other| {
+                    (hash == other.hash) && comparator(idx, other.idx as _)
+                })
+                .map(|(category, ())| category.idx)
+        });
+        UInt64Array::from_regular_iter(Field::new(self.name(), DataType::UInt64), indices)
+    }
+
+    /// Encodes each value as a list with a 1 at the index of its category in `categories` and 0s elsewhere.
+    ///
+    /// Values that aren't one of the categories are encoded as all 0s, and nulls are left as nulls.
+    pub fn one_hot(&self, categories: &Self) -> DaftResult<Self> {
+        if categories.is_empty() {
+            return Err(DaftError::ValueError(
+                "Expected at least one category to one-hot encode".to_string(),
+            ));
+        }
+        let num_categories = categories.len();
+        let indices = self.category_indices(categories)?;
+        let mut flat_child = vec![0u8; self.len() * num_categories];
+        for (row, index) in indices.as_arrow().iter().enumerate() {
+            if let Some(&index) = index {
+                flat_child[row * num_categories + index as usize] = 1;
+            }
+        }
+        // Null series don't have a validity bitmap, but all of their values are null.
+        let validity = if matches!(self.data_type(), DataType::Null) {
+            Some(Bitmap::new_zeroed(self.len()))
+        } else {
+            self.validity().cloned()
+        };
+        let field = Field::new(
+            self.name(),
+            DataType::FixedSizeList(Box::new(DataType::UInt8), num_categories),
+        );
+        let flat_child = UInt8Array::from((self.name(), flat_child)).into_series();
+        Ok(FixedSizeListArray::new(field, flat_child, validity).into_series())
+    }
+}
@@ -1,5 +1,5 @@
 use arrow2::bitmap::Bitmap;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 use crate::{
     datatypes::{DataType, Int32Array, UInt64Array},
@@ -9,11 +9,23 @@ use crate::{
 
 impl Series {
     pub fn hash(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        // Logical types are hashed by their physical representation, e.g. maps as lists of key-value structs and
+        // timestamps with time zones as their offsets from the epoch in UTC.
         let s = self.as_physical()?;
-        with_match_hashable_daft_types!(s.data_type(), |$T| {
-            let downcasted = s.downcast::<<$T as DaftDataType>::ArrayType>()?;
-            downcasted.hash(seed)
-        })
+        match s.data_type() {
+            DataType::Interval => s.interval()?.hash(seed),
+            dt if dt.is_python() || matches!(dt, DataType::Extension(..) | DataType::Unknown) => {
+                Err(DaftError::TypeError(format!(
+                    "Cannot hash Series {} of type: {}",
+                    self.name(),
+                    self.data_type()
+                )))
+            }
+            _ => with_match_hashable_daft_types!(s.data_type(), |$T| {
+                let downcasted = s.downcast::<<$T as DaftDataType>::ArrayType>()?;
+                downcasted.hash(seed)
+            }),
+        }
     }
 
     pub fn hash_with_validity(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
@@ -5,8 +5,10 @@ use common_error::{DaftError, DaftResult};
 use daft_schema::field::Field;
 
 use crate::{
-    array::{growable::make_growable, ListArray},
-    datatypes::{DataType, UInt64Array, Utf8Array},
+    array::{
+        growable::make_growable, ops::arrow2::comparison::build_is_equal, ListArray, StructArray,
+    },
+    datatypes::{BooleanArray, DataType, UInt64Array, Utf8Array},
     prelude::{CountMode, Int64Array},
     series::{array_impl::IntoSeries, Series},
 };
@@ -317,4 +319,128 @@ impl Series {
 
         Ok(list_array.into_series())
     }
+
+    /// Encodes each list as runs of consecutive equal elements, as structs of the `value` and its `run_length`.
+    ///
+    /// Consecutive nulls form a run with a null value.
+    ///
+    /// # Example
+    /// ```txt
+    /// [[1, 1, 2, 1], [NULL, NULL], []] -> [[{1, 2}, {2, 1}, {1, 1}], [{NULL, 2}], []]
+    /// ```
+    pub fn list_rle_encode(&self) -> DaftResult<Self> {
+        let input = match self.data_type() {
+            DataType::List(_) => self.clone(),
+            DataType::FixedSizeList(inner_type, _) => {
+                self.cast(&DataType::List(inner_type.clone()))?
+            }
+            dt => {
+                return Err(DaftError::TypeError(format!(
+                    "list_rle_encode not implemented for {}",
+                    dt
+                )))
+            }
+        };
+        let list = input.list()?;
+        let flat_child = &list.flat_child;
+        let comparator =
+            build_is_equal(&*flat_child.to_arrow(), &*flat_child.to_arrow(), true, true)?;
+
+        let mut run_starts = Vec::new();
+        let mut run_lengths = Vec::new();
+        let mut offsets = Vec::with_capacity(list.len() + 1);
+        offsets.push(0i64);
+        for (start, end) in list
+            .offsets()
+            .windows(2)
+            .map(|w| (w[0] as usize, w[1] as usize))
+        {
+            for idx in start..end {
+                if idx > start && comparator(idx - 1, idx) {
+                    *run_lengths.last_mut().unwrap() += 1;
+                } else {
+                    run_starts.push(idx as u64);
+                    run_lengths.push(1u64);
+                }
+            }
+            offsets.push(run_starts.len() as i64);
+        }
+
+        let values = flat_child
+            .take(&UInt64Array::from(("value", run_starts)).into_series())?
+            .rename("value");
+        let run_lengths = UInt64Array::from(("run_length", run_lengths)).into_series();
+        let run_dtype = DataType::Struct(vec![values.field().clone(), run_lengths.field().clone()]);
+        let runs = StructArray::new(
+            Field::new(input.name(), run_dtype.clone()),
+            vec![values, run_lengths],
+            None,
+        )
+        .into_series();
+
+        Ok(ListArray::new(
+            Field::new(input.name(), DataType::List(Box::new(run_dtype))),
+            runs,
+            OffsetsBuffer::try_from(offsets)?,
+            input.validity().cloned(),
+        )
+        .into_series())
+    }
+
+    /// Encodes each list of numbers as its first element followed by the differences between consecutive elements.
+    ///
+    /// Integers are widened to Int64 so that decreasing unsigned values have negative deltas, and differences with
+    /// a null element are null.
+    ///
+    /// # Example
+    /// ```txt
+    /// [[10, 12, 11], [5], []] -> [[10, 2, -1], [5], []]
+    /// ```
+    pub fn list_delta_encode(&self) -> DaftResult<Self> {
+        let input = match self.data_type() {
+            DataType::List(inner_type) | DataType::FixedSizeList(inner_type, _)
+                if inner_type.is_integer() =>
+            {
+                self.cast(&DataType::List(Box::new(DataType::Int64)))?
+            }
+            DataType::List(inner_type) | DataType::FixedSizeList(inner_type, _)
+                if inner_type.is_floating() =>
+            {
+                self.cast(&DataType::List(inner_type.clone()))?
+            }
+            dt => {
+                return Err(DaftError::TypeError(format!(
+                    "list_delta_encode not implemented for {}",
+                    dt
+                )))
+            }
+        };
+        let list = input.list()?;
+        let flat_child = &list.flat_child;
+
+        // Each element's predecessor in its list, or itself if it's the first element.
+        let mut previous = Vec::with_capacity(flat_child.len());
+        let mut is_first = Vec::with_capacity(flat_child.len());
+        for (start, end) in list
+            .offsets()
+            .windows(2)
+            .map(|w| (w[0] as usize, w[1] as usize))
+        {
+            for idx in start..end {
+                previous.push(idx.saturating_sub(1).max(start) as u64);
+                is_first.push(idx == start);
+            }
+        }
+        let previous = flat_child.take(&UInt64Array::from(("previous", previous)).into_series())?;
+        let is_first = BooleanArray::from(("is_first", is_first.as_slice())).into_series();
+        let deltas = flat_child.if_else(&(flat_child - &previous)?, &is_first)?;
+
+        Ok(ListArray::new(
+            input.field().clone(),
+            deltas.rename(flat_child.name()),
+            list.offsets().clone(),
+            input.validity().cloned(),
+        )
+        .into_series())
+    }
 }
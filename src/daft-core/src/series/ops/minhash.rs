@@ -19,10 +19,28 @@ impl Series {
                 .utf8()?
                 .minhash(num_hashes, ngram_size, seed, hasher)?
                 .into_series()),
+            DataType::List(child) if child.as_ref() == &DataType::Utf8 => Ok(self
+                .list()?
+                .minhash(num_hashes, ngram_size, seed, hasher)?
+                .into_series()),
             dt => Err(DaftError::TypeError(format!(
                 "minhash not implemented for {}",
                 dt
             ))),
         }
     }
+
+    pub fn simhash(
+        &self,
+        ngram_size: usize,
+        hasher: &impl std::hash::BuildHasher,
+    ) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self.utf8()?.simhash(ngram_size, hasher)?.into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "simhash not implemented for {}",
+                dt
+            ))),
+        }
+    }
 }
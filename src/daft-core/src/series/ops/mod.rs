@@ -10,6 +10,7 @@ pub mod between;
 pub mod binary;
 pub mod broadcast;
 pub mod cast;
+pub mod category;
 pub mod cbrt;
 pub mod ceil;
 pub mod clip;
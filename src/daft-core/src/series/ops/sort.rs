@@ -1,6 +1,7 @@
 use common_error::{DaftError, DaftResult};
 
 use crate::{
+    datatypes::{DataType, UInt64Type},
     series::{array_impl::IntoSeries, Series},
     with_match_comparable_daft_types,
 };
@@ -8,10 +9,24 @@ use crate::{
 impl Series {
     pub fn argsort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
         let series = self.as_physical()?;
-        with_match_comparable_daft_types!(series.data_type(), |$T| {
-            let downcasted = series.downcast::<<$T as DaftDataType>::ArrayType>()?;
-            Ok(downcasted.argsort::<UInt64Type>(descending, nulls_first)?.into_series())
-        })
+        match series.data_type() {
+            DataType::List(_) => Ok(series
+                .list()?
+                .argsort::<UInt64Type>(descending, nulls_first)?
+                .into_series()),
+            DataType::FixedSizeList(..) => Ok(series
+                .fixed_size_list()?
+                .argsort::<UInt64Type>(descending, nulls_first)?
+                .into_series()),
+            DataType::Struct(_) => Ok(series
+                .struct_()?
+                .argsort::<UInt64Type>(descending, nulls_first)?
+                .into_series()),
+            dtype => with_match_comparable_daft_types!(dtype, |$T| {
+                let downcasted = series.downcast::<<$T as DaftDataType>::ArrayType>()?;
+                Ok(downcasted.argsort::<UInt64Type>(descending, nulls_first)?.into_series())
+            }),
+        }
     }
 
     pub fn argsort_multikey(
@@ -35,11 +50,26 @@ impl Series {
         }
 
         let first = sort_keys.first().unwrap().as_physical()?;
-        with_match_comparable_daft_types!(first.data_type(), |$T| {
-            let downcasted = first.downcast::<<$T as DaftDataType>::ArrayType>()?;
-            let result = downcasted.argsort_multikey::<UInt64Type>(&sort_keys[1..], descending, nulls_first)?;
-            Ok(result.into_series())
-        })
+        let others = &sort_keys[1..];
+        match first.data_type() {
+            DataType::List(_) => Ok(first
+                .list()?
+                .argsort_multikey::<UInt64Type>(others, descending, nulls_first)?
+                .into_series()),
+            DataType::FixedSizeList(..) => Ok(first
+                .fixed_size_list()?
+                .argsort_multikey::<UInt64Type>(others, descending, nulls_first)?
+                .into_series()),
+            DataType::Struct(_) => Ok(first
+                .struct_()?
+                .argsort_multikey::<UInt64Type>(others, descending, nulls_first)?
+                .into_series()),
+            dtype => with_match_comparable_daft_types!(dtype, |$T| {
+                let downcasted = first.downcast::<<$T as DaftDataType>::ArrayType>()?;
+                let result = downcasted.argsort_multikey::<UInt64Type>(others, descending, nulls_first)?;
+                Ok(result.into_series())
+            }),
+        }
     }
 
     pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
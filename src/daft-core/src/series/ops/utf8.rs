@@ -1,7 +1,9 @@
+use std::net::Ipv4Addr;
+
 use common_error::{DaftError, DaftResult};
 
 use crate::{
-    array::ops::{PadPlacement, Utf8NormalizeOptions},
+    array::ops::{as_arrow::AsArrow, Collation, DecimalFormat, PadPlacement, Utf8NormalizeOptions},
     datatypes::*,
     series::{array_impl::IntoSeries, Series},
     with_match_integer_daft_types,
@@ -255,10 +257,98 @@ impl Series {
         self.with_utf8_array(|arr| Ok(arr.to_datetime(format, timezone)?.into_series()))
     }
 
+    pub fn utf8_parse_decimal(
+        &self,
+        precision: usize,
+        scale: usize,
+        format: DecimalFormat,
+    ) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.parse_decimal(precision, scale, format)?.into_series()))
+    }
+
     pub fn utf8_normalize(&self, opts: Utf8NormalizeOptions) -> DaftResult<Self> {
         self.with_utf8_array(|arr| Ok(arr.normalize(opts)?.into_series()))
     }
 
+    pub fn utf8_remove_accents(&self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.remove_accents()?.into_series()))
+    }
+
+    pub fn utf8_ascii_fold(&self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.ascii_fold()?.into_series()))
+    }
+
+    pub fn utf8_collation_key(&self, collation: Collation) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.collation_key(collation)?.into_series()))
+    }
+
+    pub fn utf8_is_email(&self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.is_email()?.into_series()))
+    }
+
+    pub fn utf8_is_ipv4(&self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.is_ipv4()?.into_series()))
+    }
+
+    pub fn utf8_is_ipv6(&self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.is_ipv6()?.into_series()))
+    }
+
+    pub fn utf8_is_phone_number(&self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.is_phone_number()?.into_series()))
+    }
+
+    pub fn utf8_ip_to_int(&self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.ip_to_int()?.into_series()))
+    }
+
+    pub fn utf8_extract_domain(&self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.extract_domain()?.into_series()))
+    }
+
+    /// Formats every integer between 0 and 2^32 - 1 as an IPv4 address, and every other integer as null.
+    pub fn int_to_ip(&self) -> DaftResult<Self> {
+        if !self.data_type().is_integer() {
+            return Err(DaftError::TypeError(format!(
+                "int_to_ip not implemented for type {}",
+                self.data_type()
+            )));
+        }
+        let values = self.cast(&DataType::Int64)?;
+        let arrow_result = values
+            .i64()?
+            .as_arrow()
+            .iter()
+            .map(|val| {
+                let val = u32::try_from(*val?).ok()?;
+                Some(Ipv4Addr::from(val).to_string())
+            })
+            .collect::<arrow2::array::Utf8Array<i64>>();
+        Ok(Utf8Array::from((self.name(), Box::new(arrow_result))).into_series())
+    }
+
+    /// Formats decimals, or integers, with thousands separators and an optional currency symbol.
+    pub fn format_decimal(
+        &self,
+        format: DecimalFormat,
+        currency_symbol: Option<&str>,
+        accounting: bool,
+    ) -> DaftResult<Self> {
+        let values = match self.data_type() {
+            DataType::Decimal128(..) => self.clone(),
+            dtype if dtype.is_integer() => self.cast(&DataType::Decimal128(38, 0))?,
+            dtype => {
+                return Err(DaftError::TypeError(format!(
+                    "format_decimal not implemented for type {dtype}"
+                )))
+            }
+        };
+        Ok(values
+            .decimal128()?
+            .format_decimal(format, currency_symbol, accounting)?
+            .into_series())
+    }
+
     pub fn utf8_count_matches(
         &self,
         patterns: &Self,
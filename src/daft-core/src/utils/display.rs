@@ -144,8 +144,20 @@ pub fn display_decimal128(val: i128, _precision: u8, scale: i8) -> String {
 }
 
 #[must_use]
+/// The maximum number of values of a series literal that are displayed, the rest are elided so that plans with huge
+/// literals, e.g. of `is_in`, stay readable.
+const MAX_DISPLAYED_SERIES_LITERAL_VALUES: usize = 10;
+
 pub fn display_series_literal(series: &Series) -> String {
-    if !series.is_empty() {
+    if series.len() > MAX_DISPLAYED_SERIES_LITERAL_VALUES {
+        format!(
+            "[{}, ... {} more]",
+            (0..MAX_DISPLAYED_SERIES_LITERAL_VALUES)
+                .map(|i| series.str_value(i))
+                .join(", "),
+            series.len() - MAX_DISPLAYED_SERIES_LITERAL_VALUES
+        )
+    } else if !series.is_empty() {
         format!(
             "[{}]",
             (0..series.len()).map(|i| series.str_value(i)).join(", ")
@@ -4,12 +4,9 @@ use crate::datatypes::{DataType, TimeUnit};
 
 // TODO: Deprecate this logic soon!
 
+/// The finer of two time units, which values of either unit can be converted to without losing precision.
 fn get_time_units(tu_l: &TimeUnit, tu_r: &TimeUnit) -> TimeUnit {
-    match (tu_l, tu_r) {
-        (TimeUnit::Nanoseconds, TimeUnit::Microseconds) => TimeUnit::Microseconds,
-        (_, TimeUnit::Milliseconds) => TimeUnit::Milliseconds,
-        _ => *tu_l,
-    }
+    *tu_l.min(tu_r)
 }
 
 /// Computes the supertype of two types.
@@ -161,7 +158,7 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
             (DataType::Duration(lu), DataType::Duration(ru)) => Some(DataType::Duration(get_time_units(lu, ru))),
 
             // Some() timezones that are non equal
-            // we cast from more precision to higher precision as that always fits with occasional loss of precision
+            // we cast to the finer time unit, so that no precision is lost, and error on values that overflow it
             (DataType::Timestamp(tu_l, Some(tz_l)), DataType::Timestamp(tu_r, Some(tz_r)))
                 if !tz_l.is_empty()
                     && !tz_r.is_empty() && tz_l != tz_r =>
@@ -170,7 +167,7 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
                 Some(DataType::Timestamp(tu, Some("UTC".to_string())))
             }
             // None and Some("<tz>") timezones
-            // we cast from more precision to higher precision as that always fits with occasional loss of precision
+            // we cast to the finer time unit, so that no precision is lost, and error on values that overflow it
             (DataType::Timestamp(tu_l, tz_l), DataType::Timestamp(tu_r, tz_r)) if
                 // both are none
                 tz_l.is_none() && tz_r.is_none()
@@ -224,4 +221,24 @@ mod tests {
         assert_eq!(result, None);
         Ok(())
     }
+
+    #[test]
+    fn check_timestamp_units_unify_to_finer_unit() -> DaftResult<()> {
+        let units = [
+            TimeUnit::Seconds,
+            TimeUnit::Milliseconds,
+            TimeUnit::Microseconds,
+            TimeUnit::Nanoseconds,
+        ];
+        for l in &units {
+            for r in &units {
+                let expected = DataType::Timestamp(*l.min(r), None);
+                let l = DataType::Timestamp(*l, None);
+                let r = DataType::Timestamp(*r, None);
+                assert_eq!(get_supertype(&l, &r), Some(expected.clone()));
+                assert_eq!(get_supertype(&r, &l), Some(expected));
+            }
+        }
+        Ok(())
+    }
 }
@@ -16,7 +16,9 @@ use daft_compression::CompressionCodec;
 use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
 use daft_decoding::deserialize::deserialize_column;
 use daft_dsl::optimization::get_required_columns;
-use daft_io::{parse_url, GetResult, IOClient, IOStatsRef, SourceType};
+use daft_io::{
+    is_local_stream, parse_url, GetResult, IOClient, IOStatsRef, LocalStreamRelease, SourceType,
+};
 use daft_recordbatch::RecordBatch;
 use futures::{stream::BoxStream, Stream, StreamExt, TryStreamExt};
 use rayon::{
@@ -156,9 +158,10 @@ pub async fn stream_csv(
     max_chunks_in_flight: Option<usize>,
 ) -> DaftResult<BoxStream<'static, DaftResult<RecordBatch>>> {
     let uri = uri.as_str();
-    let (source_type, _) = parse_url(uri)?;
+    let (source_type, fixed_uri) = parse_url(uri)?;
     let is_compressed = CompressionCodec::from_uri(uri).is_some();
-    if matches!(source_type, SourceType::File) && !is_compressed {
+    // Pipes can't be read in parallel chunks, so they're streamed like remote files.
+    if matches!(source_type, SourceType::File) && !is_compressed && !is_local_stream(&fixed_uri) {
         let stream = stream_csv_local(
             uri,
             convert_options,
@@ -171,6 +174,7 @@ pub async fn stream_csv(
         .await?;
         Ok(Box::pin(stream))
     } else {
+        let release = LocalStreamRelease::new(&fixed_uri);
         let stream = stream_csv_single(
             uri,
             convert_options,
@@ -180,7 +184,12 @@ pub async fn stream_csv(
             io_stats,
             max_chunks_in_flight,
         )
-        .await?;
+        .await?
+        // Release a pipe once the stream of its tables is done with, however it finishes.
+        .map(move |table| {
+            let _release = &release;
+            table
+        });
         Ok(Box::pin(stream))
     }
 }
@@ -233,9 +242,9 @@ async fn read_csv_single_into_table(
     io_stats: Option<IOStatsRef>,
     max_chunks_in_flight: Option<usize>,
 ) -> DaftResult<RecordBatch> {
-    let (source_type, _) = parse_url(uri)?;
+    let (source_type, fixed_uri) = parse_url(uri)?;
     let is_compressed = CompressionCodec::from_uri(uri).is_some();
-    if matches!(source_type, SourceType::File) && !is_compressed {
+    if matches!(source_type, SourceType::File) && !is_compressed && !is_local_stream(&fixed_uri) {
         return read_csv_local(
             uri,
             convert_options,
@@ -247,6 +256,7 @@ async fn read_csv_single_into_table(
         )
         .await;
     }
+    let _release = LocalStreamRelease::new(&fixed_uri);
 
     let predicate = convert_options
         .as_ref()
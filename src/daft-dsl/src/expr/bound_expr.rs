@@ -0,0 +1,60 @@
+use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode};
+use daft_core::prelude::*;
+use derive_more::Display;
+
+use super::{BoundColumn, Column, Expr, ExprRef, PlanRef, ResolvedColumn, UnresolvedColumn};
+
+/// An expression whose columns are bound to the ordinals of their fields in the schema of the input it's evaluated on.
+///
+/// Binding happens once, right before execution, so that columns that aren't in the input are reported then rather
+/// than when the first batch is evaluated, and evaluating every batch takes columns by ordinal instead of looking up
+/// their names.
+#[derive(Display, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BoundExpr(ExprRef);
+
+impl BoundExpr {
+    /// Binds the columns of `expr` to the fields of `schema`.
+    pub fn try_new(expr: ExprRef, schema: &Schema) -> DaftResult<Self> {
+        let bound = expr.transform(|e| match e.as_ref() {
+            Expr::Column(
+                Column::Resolved(ResolvedColumn::Basic(name))
+                | Column::Unresolved(UnresolvedColumn {
+                    name,
+                    plan_ref: PlanRef::Unqualified,
+                    plan_schema: None,
+                }),
+            ) => {
+                let index = schema.get_index(name)?;
+                let field = schema.get_field(name)?.clone();
+                Ok(Transformed::yes(
+                    Expr::Column(Column::Bound(BoundColumn { index, field })).into(),
+                ))
+            }
+            _ => Ok(Transformed::no(e)),
+        })?;
+        Ok(Self(bound.data))
+    }
+
+    /// Binds the columns of all of `exprs` to the fields of `schema`.
+    pub fn bind_all(exprs: &[ExprRef], schema: &Schema) -> DaftResult<Vec<Self>> {
+        exprs
+            .iter()
+            .map(|expr| Self::try_new(expr.clone(), schema))
+            .collect()
+    }
+
+    pub fn inner(&self) -> &ExprRef {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> ExprRef {
+        self.0
+    }
+}
+
+impl AsRef<Expr> for BoundExpr {
+    fn as_ref(&self) -> &Expr {
+        &self.0
+    }
+}
@@ -4,6 +4,89 @@ use itertools::Itertools;
 
 use super::{Expr, ExprRef, Operator};
 
+/// The maximum number of items of an `IN` list or a list expression that are displayed, the rest are elided so that
+/// plans with huge literal lists stay readable.
+pub const MAX_DISPLAYED_ITEMS: usize = 10;
+
+/// The precedence of comparisons, which don't chain like they do in Python.
+const COMPARISON_PRECEDENCE: u8 = 1;
+
+/// The precedence of an operator, following Python's since expressions are displayed with its operators, where a higher
+/// one binds more tightly.
+fn precedence(op: &Operator) -> u8 {
+    match op {
+        Operator::Eq
+        | Operator::EqNullSafe
+        | Operator::NotEq
+        | Operator::Lt
+        | Operator::LtEq
+        | Operator::Gt
+        | Operator::GtEq => COMPARISON_PRECEDENCE,
+        Operator::Or => 2,
+        Operator::Xor => 3,
+        Operator::And => 4,
+        Operator::ShiftLeft | Operator::ShiftRight => 5,
+        Operator::Plus | Operator::Minus => 6,
+        Operator::Multiply | Operator::TrueDivide | Operator::FloorDivide | Operator::Modulus => 7,
+    }
+}
+
+/// The precedence of an expression when it's the operand of a binary operator, or None if it never needs parentheses,
+/// e.g. because it's displayed as a function call.
+fn operand_precedence(expr: &Expr) -> Option<u8> {
+    match expr {
+        Expr::Alias(inner, _) => operand_precedence(inner),
+        Expr::BinaryOp { op, .. } => Some(precedence(op)),
+        // These are displayed with infix keywords.
+        Expr::IsIn(..) | Expr::Between(..) | Expr::InSubquery(..) | Expr::IfElse { .. } => Some(0),
+        _ => None,
+    }
+}
+
+/// Writes an operand, in parentheses if it binds less tightly than the operator it's an operand of.
+///
+/// Operators are left-associative, so a right operand also needs parentheses if it binds as tightly as the operator,
+/// and comparisons don't chain, so their operands always need parentheses if they are comparisons themselves.
+fn write_operand(
+    f: &mut String,
+    op: &Operator,
+    operand: &Expr,
+    is_right: bool,
+) -> std::fmt::Result {
+    let operand = match operand {
+        Expr::Alias(inner, _) => inner.as_ref(),
+        _ => operand,
+    };
+    let op_precedence = precedence(op);
+    let needs_parentheses = operand_precedence(operand).is_some_and(|operand_precedence| {
+        operand_precedence < op_precedence
+            || (operand_precedence == op_precedence
+                && (is_right || op_precedence == COMPARISON_PRECEDENCE))
+    });
+    if needs_parentheses {
+        write!(f, "({operand})")
+    } else {
+        write!(f, "{operand}")
+    }
+}
+
+/// Writes items separated by commas, eliding all but the first [`MAX_DISPLAYED_ITEMS`].
+fn write_items(f: &mut String, items: &[ExprRef]) -> std::fmt::Result {
+    write!(
+        f,
+        "{}",
+        items
+            .iter()
+            .take(MAX_DISPLAYED_ITEMS)
+            .map(|x| x.to_string())
+            .join(", ")
+    )?;
+    if items.len() > MAX_DISPLAYED_ITEMS {
+        write!(f, ", ... {} more", items.len() - MAX_DISPLAYED_ITEMS)?;
+    }
+    Ok(())
+}
+
 /// Display for Expr::BinaryOp
 pub fn expr_binary_op_display_without_formatter(
     op: &Operator,
@@ -11,14 +94,9 @@ pub fn expr_binary_op_display_without_formatter(
     right: &ExprRef,
 ) -> std::result::Result<String, std::fmt::Error> {
     let mut f = String::default();
-    let write_out_expr = |f: &mut String, input: &Expr| match input {
-        Expr::Alias(e, _) => write!(f, "{e}"),
-        Expr::BinaryOp { .. } => write!(f, "[{input}]"),
-        _ => write!(f, "{input}"),
-    };
-    write_out_expr(&mut f, left)?;
+    write_operand(&mut f, op, left, false)?;
     write!(&mut f, " {op} ")?;
-    write_out_expr(&mut f, right)?;
+    write_operand(&mut f, op, right, true)?;
     Ok(f)
 }
 
@@ -28,13 +106,12 @@ pub fn expr_is_in_display_without_formatter(
     inputs: &[ExprRef],
 ) -> std::result::Result<String, std::fmt::Error> {
     let mut f = String::default();
-    write!(&mut f, "{expr} IN (")?;
-    for (i, input) in inputs.iter().enumerate() {
-        if i != 0 {
-            write!(&mut f, ", ")?;
-        }
-        write!(&mut f, "{input}")?;
+    if operand_precedence(expr).is_some() {
+        write!(&mut f, "({expr}) IN (")?;
+    } else {
+        write!(&mut f, "{expr} IN (")?;
     }
+    write_items(&mut f, inputs)?;
     write!(&mut f, ")")?;
     Ok(f)
 }
@@ -44,10 +121,8 @@ pub fn expr_list_display_without_formatter(
     items: &[ExprRef],
 ) -> std::result::Result<String, std::fmt::Error> {
     let mut f = String::default();
-    write!(
-        &mut f,
-        "list({})",
-        items.iter().map(|x| x.to_string()).join(", ")
-    )?;
+    write!(&mut f, "list(")?;
+    write_items(&mut f, items)?;
+    write!(&mut f, ")")?;
     Ok(f)
 }
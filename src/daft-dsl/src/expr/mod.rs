@@ -1,17 +1,20 @@
+mod bound_expr;
 mod display;
+mod sql;
 #[cfg(test)]
 mod tests;
+mod window;
 
 use std::{
     any::Any,
     collections::HashSet,
     fmt::Formatter,
     hash::{DefaultHasher, Hash, Hasher},
-    io::{self, Write},
     str::FromStr,
     sync::Arc,
 };
 
+pub use bound_expr::BoundExpr;
 use common_error::{DaftError, DaftResult};
 use common_hashable_float_wrapper::FloatWrapper;
 use common_treenode::TreeNode;
@@ -26,6 +29,11 @@ use daft_core::{
 };
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
+pub use sql::{split_sql_pushdown, SqlCapabilities};
+pub use window::{
+    has_window, rank, row_number, NullStrategy, OffsetWindowParams, WindowBoundary, WindowExpr,
+    WindowFrame, WindowSpec,
+};
 
 use super::functions::FunctionExpr;
 use crate::{
@@ -108,6 +116,7 @@ impl std::hash::Hash for Subquery {
 pub enum Column {
     Unresolved(UnresolvedColumn),
     Resolved(ResolvedColumn),
+    Bound(BoundColumn),
 }
 
 /// Information about the logical plan node that a column comes from.
@@ -157,6 +166,15 @@ pub enum ResolvedColumn {
     OuterRef(Field),
 }
 
+/// Column bound to the ordinal of its field in the schema of the input that an expression is evaluated on.
+///
+/// Bound columns should only exist in [`BoundExpr`]s, which are created right before execution.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct BoundColumn {
+    pub index: usize,
+    pub field: Field,
+}
+
 impl Display for Column {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let name = match self {
@@ -164,6 +182,7 @@ impl Display for Column {
             Self::Resolved(ResolvedColumn::Basic(name)) => name.to_string(),
             Self::Resolved(ResolvedColumn::JoinSide(name, side)) => format!("{side}.{name}"),
             Self::Resolved(ResolvedColumn::OuterRef(Field { name, .. })) => format!("outer.{name}"),
+            Self::Bound(BoundColumn { index, field }) => format!("{}#{index}", field.name),
         };
 
         write!(f, "col({name})")
@@ -241,6 +260,9 @@ pub enum Expr {
 
     #[display("exists {_0}")]
     Exists(Subquery),
+
+    #[display("{_0} over {_1}")]
+    Window(WindowExpr, WindowSpec),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Hash, Eq)]
@@ -359,6 +381,33 @@ impl AggExpr {
         }
     }
 
+    /// The aggregation that combines the results of this aggregation over disjoint parts of its
+    /// input, given as `partial`, into its result over the whole input.
+    ///
+    /// Returns `None` for aggregations whose results can't be combined without extra state, such as
+    /// mean or count distinct.
+    pub fn merge_partial_results(&self, partial: ExprRef) -> Option<Self> {
+        match self {
+            Self::Count(..) | Self::Sum(_) => Some(Self::Sum(partial)),
+            Self::Min(_) => Some(Self::Min(partial)),
+            Self::Max(_) => Some(Self::Max(partial)),
+            Self::BoolAnd(_) => Some(Self::BoolAnd(partial)),
+            Self::BoolOr(_) => Some(Self::BoolOr(partial)),
+            Self::AnyValue(_, ignore_nulls) => Some(Self::AnyValue(partial, *ignore_nulls)),
+            Self::List(_) | Self::Concat(_) => Some(Self::Concat(partial)),
+            Self::ApproxSketch(_, sketch_type) | Self::MergeSketch(_, sketch_type) => {
+                Some(Self::MergeSketch(partial, *sketch_type))
+            }
+            Self::CountDistinct(_)
+            | Self::ApproxPercentile(_)
+            | Self::ApproxCountDistinct(_)
+            | Self::Mean(_)
+            | Self::Stddev(_)
+            | Self::Set(_)
+            | Self::MapGroups { .. } => None,
+        }
+    }
+
     pub fn semantic_id(&self, schema: &Schema) -> FieldID {
         match self {
             Self::Count(expr, mode) => {
@@ -695,6 +744,14 @@ impl Expr {
         Self::Agg(AggExpr::ApproxCountDistinct(self)).into()
     }
 
+    pub fn approx_sketch(self: ExprRef, sketch_type: SketchType) -> ExprRef {
+        Self::Agg(AggExpr::ApproxSketch(self, sketch_type)).into()
+    }
+
+    pub fn merge_sketch(self: ExprRef, sketch_type: SketchType) -> ExprRef {
+        Self::Agg(AggExpr::MergeSketch(self, sketch_type)).into()
+    }
+
     pub fn approx_percentiles(
         self: ExprRef,
         percentiles: &[f64],
@@ -855,6 +912,11 @@ impl Expr {
                 FieldID::new(format!("outer.{name}"))
             }
 
+            // A bound column refers to the same column as its name.
+            Self::Column(Column::Bound(BoundColumn { field, .. })) => {
+                FieldID::new(field.name.as_str())
+            }
+
             // Base case - literal.
             Self::Literal(value) => FieldID::new(format!("Literal({value:?})")),
 
@@ -934,6 +996,11 @@ impl Expr {
 
                 FieldID::new(format!("(EXISTS {subquery_id})"))
             }
+            Self::Window(window_expr, spec) => {
+                let window_expr_id = window_expr.semantic_id(schema);
+                let spec_id = spec.semantic_id(schema);
+                FieldID::new(format!("{window_expr_id}.over({spec_id})"))
+            }
         }
     }
 
@@ -972,6 +1039,12 @@ impl Expr {
             }
             Self::FillNull(expr, fill_value) => vec![expr.clone(), fill_value.clone()],
             Self::ScalarFunction(sf) => sf.inputs.clone(),
+            // The inputs of the window function, then the partition by and order by expressions.
+            Self::Window(window_expr, spec) => {
+                let mut children = window_expr.children();
+                children.extend(spec.children());
+                children
+            }
         }
     }
 
@@ -1070,12 +1143,20 @@ impl Expr {
                     inputs: children,
                 })
             }
+            Self::Window(window_expr, spec) => {
+                let mut window_expr_children = children;
+                let spec_children = window_expr_children.split_off(window_expr.children().len());
+                Self::Window(
+                    window_expr.with_new_children(window_expr_children),
+                    spec.with_new_children(spec_children),
+                )
+            }
         }
     }
 
     pub fn to_field(&self, schema: &Schema) -> DaftResult<Field> {
         match self {
-            Self::Alias(expr, name) => Ok(Field::new(name.as_ref(), expr.get_type(schema)?)),
+            Self::Alias(expr, name) => Ok(expr.to_field(schema)?.rename(name.as_ref())),
             Self::Agg(agg_expr) => agg_expr.to_field(schema),
             Self::Cast(expr, dtype) => Ok(Field::new(expr.name(), dtype.clone())),
             Self::Column(Column::Unresolved(UnresolvedColumn {
@@ -1099,6 +1180,7 @@ impl Expr {
             }
 
             Self::Column(Column::Resolved(ResolvedColumn::OuterRef(field))) => Ok(field.clone()),
+            Self::Column(Column::Bound(BoundColumn { field, .. })) => Ok(field.clone()),
             Self::Not(expr) => {
                 let child_field = expr.to_field(schema)?;
                 match child_field.dtype {
@@ -1159,74 +1241,22 @@ impl Expr {
             Self::Literal(value) => Ok(Field::new("literal", value.get_type())),
             Self::Function { func, inputs } => func.to_field(inputs.as_slice(), schema, func),
             Self::ScalarFunction(sf) => sf.to_field(schema),
-            Self::BinaryOp { op, left, right } => {
-                let left_field = left.to_field(schema)?;
-                let right_field = right.to_field(schema)?;
-
-                match op {
-                    // Logical operations
-                    Operator::And | Operator::Or | Operator::Xor => {
-                        let result_type = InferDataType::from(&left_field.dtype)
-                            .logical_op(&InferDataType::from(&right_field.dtype))?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-
-                    // Comparison operations
-                    Operator::Lt
-                    | Operator::Gt
-                    | Operator::Eq
-                    | Operator::NotEq
-                    | Operator::LtEq
-                    | Operator::GtEq
-                    | Operator::EqNullSafe => {
-                        let (result_type, _intermediate, _comp_type) =
-                            InferDataType::from(&left_field.dtype)
-                                .comparison_op(&InferDataType::from(&right_field.dtype))?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-
-                    // Arithmetic operations
-                    Operator::Plus => {
-                        let result_type = (InferDataType::from(&left_field.dtype)
-                            + InferDataType::from(&right_field.dtype))?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-                    Operator::Minus => {
-                        let result_type = (InferDataType::from(&left_field.dtype)
-                            - InferDataType::from(&right_field.dtype))?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-                    Operator::Multiply => {
-                        let result_type = (InferDataType::from(&left_field.dtype)
-                            * InferDataType::from(&right_field.dtype))?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-                    Operator::TrueDivide => {
-                        let result_type = (InferDataType::from(&left_field.dtype)
-                            / InferDataType::from(&right_field.dtype))?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-                    Operator::Modulus => {
-                        let result_type = (InferDataType::from(&left_field.dtype)
-                            % InferDataType::from(&right_field.dtype))?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-                    Operator::ShiftLeft => {
-                        let result_type = (InferDataType::from(&left_field.dtype)
-                            << InferDataType::from(&right_field.dtype))?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-                    Operator::ShiftRight => {
-                        let result_type = (InferDataType::from(&left_field.dtype)
-                            >> InferDataType::from(&right_field.dtype))?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-                    Operator::FloorDivide => {
-                        let result_type = (InferDataType::from(&left_field.dtype)
-                            .floor_div(&InferDataType::from(&right_field.dtype)))?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
+            Self::BinaryOp { .. } => {
+                // Long chains of binary operators, like generated disjunctions of thousands of predicates, are usually
+                // left-deep, so walk down their left operands iteratively rather than recursively to not overflow the
+                // stack.
+                let mut spine = vec![];
+                let mut leftmost = self;
+                while let Self::BinaryOp { op, left, right } = leftmost {
+                    spine.push((op, right));
+                    leftmost = left.as_ref();
                 }
+                spine.into_iter().rev().try_fold(
+                    leftmost.to_field(schema)?,
+                    |left_field, (op, right)| {
+                        binary_op_field(op, &left_field, &right.to_field(schema)?)
+                    },
+                )
             }
             Self::IfElse {
                 if_true,
@@ -1267,6 +1297,7 @@ impl Expr {
             }
             Self::InSubquery(expr, _) => Ok(Field::new(expr.name(), DataType::Boolean)),
             Self::Exists(_) => Ok(Field::new("exists", DataType::Boolean)),
+            Self::Window(window_expr, _) => window_expr.to_field(schema),
         }
     }
 
@@ -1281,6 +1312,7 @@ impl Expr {
             Self::Column(Column::Resolved(ResolvedColumn::OuterRef(Field { name, .. }))) => {
                 name.as_ref()
             }
+            Self::Column(Column::Bound(BoundColumn { field, .. })) => field.name.as_ref(),
             Self::Not(expr) => expr.name(),
             Self::IsNull(expr) => expr.name(),
             Self::NotNull(expr) => expr.name(),
@@ -1295,17 +1327,24 @@ impl Expr {
             },
             Self::ScalarFunction(func) => match func.name() {
                 "struct" => "struct", // FIXME: make struct its own expr variant
-                _ => func.inputs.first().unwrap().name(),
+                _ => func
+                    .inputs
+                    .first()
+                    .map_or_else(|| func.name(), |input| input.name()),
             },
-            Self::BinaryOp {
-                op: _,
-                left,
-                right: _,
-            } => left.name(),
+            Self::BinaryOp { .. } => {
+                // Iterate rather than recurse down the left operands of long chains of binary operators.
+                let mut leftmost = self;
+                while let Self::BinaryOp { left, .. } = leftmost {
+                    leftmost = left.as_ref();
+                }
+                leftmost.name()
+            }
             Self::IfElse { if_true, .. } => if_true.name(),
             Self::Subquery(subquery) => subquery.name(),
             Self::InSubquery(expr, _) => expr.name(),
             Self::Exists(subquery) => subquery.name(),
+            Self::Window(window_expr, _) => window_expr.name(),
         }
     }
 
@@ -1326,77 +1365,6 @@ impl Expr {
         }
     }
 
-    pub fn to_sql(&self) -> Option<String> {
-        fn to_sql_inner<W: Write>(expr: &Expr, buffer: &mut W) -> io::Result<()> {
-            match expr {
-                Expr::Column(Column::Resolved(ResolvedColumn::Basic(name))) => {
-                    write!(buffer, "{}", name)
-                }
-                Expr::Literal(lit) => lit.display_sql(buffer),
-                Expr::Alias(inner, ..) => to_sql_inner(inner, buffer),
-                Expr::BinaryOp { op, left, right } => {
-                    to_sql_inner(left, buffer)?;
-                    let op = match op {
-                        Operator::Eq => "=",
-                        Operator::EqNullSafe => "<=>",
-                        Operator::NotEq => "!=",
-                        Operator::Lt => "<",
-                        Operator::LtEq => "<=",
-                        Operator::Gt => ">",
-                        Operator::GtEq => ">=",
-                        Operator::And => "AND",
-                        Operator::Or => "OR",
-                        Operator::ShiftLeft => "<<",
-                        Operator::ShiftRight => ">>",
-                        _ => {
-                            return Err(io::Error::other(
-                                "Unsupported operator for SQL translation",
-                            ))
-                        }
-                    };
-                    write!(buffer, " {} ", op)?;
-                    to_sql_inner(right, buffer)
-                }
-                Expr::Not(inner) => {
-                    write!(buffer, "NOT (")?;
-                    to_sql_inner(inner, buffer)?;
-                    write!(buffer, ")")
-                }
-                Expr::IsNull(inner) => {
-                    write!(buffer, "(")?;
-                    to_sql_inner(inner, buffer)?;
-                    write!(buffer, ") IS NULL")
-                }
-                Expr::NotNull(inner) => {
-                    write!(buffer, "(")?;
-                    to_sql_inner(inner, buffer)?;
-                    write!(buffer, ") IS NOT NULL")
-                }
-                // TODO: Implement SQL translations for these expressions if possible
-                Expr::IfElse { .. }
-                | Expr::Agg(..)
-                | Expr::Cast(..)
-                | Expr::IsIn(..)
-                | Expr::List(..)
-                | Expr::Between(..)
-                | Expr::Function { .. }
-                | Expr::FillNull(..)
-                | Expr::ScalarFunction { .. }
-                | Expr::Subquery(..)
-                | Expr::InSubquery(..)
-                | Expr::Exists(..)
-                | Expr::Column(..) => Err(io::Error::other(
-                    "Unsupported expression for SQL translation",
-                )),
-            }
-        }
-
-        let mut buffer = Vec::new();
-        to_sql_inner(self, &mut buffer)
-            .ok()
-            .and_then(|()| String::from_utf8(buffer).ok())
-    }
-
     /// Returns the literal value if this is a literal expression, otherwise none.
     pub fn as_literal(&self) -> Option<&lit::LiteralValue> {
         match self {
@@ -1422,6 +1390,7 @@ impl Expr {
             Self::Function { .. } => true,
             Self::ScalarFunction(..) => true,
             Self::Agg(_) => true,
+            Self::Window(..) => true,
             Self::IsIn(..) => true,
             Self::Between(..) => true,
             Self::BinaryOp { .. } => true,
@@ -1446,6 +1415,74 @@ impl Expr {
     }
 }
 
+/// The field of the result of a binary operator, given the fields of its operands.
+fn binary_op_field(op: &Operator, left_field: &Field, right_field: &Field) -> DaftResult<Field> {
+    match op {
+        // Logical operations
+        Operator::And | Operator::Or | Operator::Xor => {
+            let result_type = InferDataType::from(&left_field.dtype)
+                .logical_op(&InferDataType::from(&right_field.dtype))?;
+            Ok(Field::new(left_field.name.as_str(), result_type))
+        }
+
+        // Comparison operations
+        Operator::Lt
+        | Operator::Gt
+        | Operator::Eq
+        | Operator::NotEq
+        | Operator::LtEq
+        | Operator::GtEq
+        | Operator::EqNullSafe => {
+            let (result_type, _intermediate, _comp_type) =
+                InferDataType::from(&left_field.dtype)
+                    .comparison_op(&InferDataType::from(&right_field.dtype))?;
+            Ok(Field::new(left_field.name.as_str(), result_type))
+        }
+
+        // Arithmetic operations
+        Operator::Plus => {
+            let result_type =
+                (InferDataType::from(&left_field.dtype) + InferDataType::from(&right_field.dtype))?;
+            Ok(Field::new(left_field.name.as_str(), result_type))
+        }
+        Operator::Minus => {
+            let result_type =
+                (InferDataType::from(&left_field.dtype) - InferDataType::from(&right_field.dtype))?;
+            Ok(Field::new(left_field.name.as_str(), result_type))
+        }
+        Operator::Multiply => {
+            let result_type =
+                (InferDataType::from(&left_field.dtype) * InferDataType::from(&right_field.dtype))?;
+            Ok(Field::new(left_field.name.as_str(), result_type))
+        }
+        Operator::TrueDivide => {
+            let result_type =
+                (InferDataType::from(&left_field.dtype) / InferDataType::from(&right_field.dtype))?;
+            Ok(Field::new(left_field.name.as_str(), result_type))
+        }
+        Operator::Modulus => {
+            let result_type =
+                (InferDataType::from(&left_field.dtype) % InferDataType::from(&right_field.dtype))?;
+            Ok(Field::new(left_field.name.as_str(), result_type))
+        }
+        Operator::ShiftLeft => {
+            let result_type = (InferDataType::from(&left_field.dtype)
+                << InferDataType::from(&right_field.dtype))?;
+            Ok(Field::new(left_field.name.as_str(), result_type))
+        }
+        Operator::ShiftRight => {
+            let result_type = (InferDataType::from(&left_field.dtype)
+                >> InferDataType::from(&right_field.dtype))?;
+            Ok(Field::new(left_field.name.as_str(), result_type))
+        }
+        Operator::FloorDivide => {
+            let result_type = (InferDataType::from(&left_field.dtype)
+                .floor_div(&InferDataType::from(&right_field.dtype)))?;
+            Ok(Field::new(left_field.name.as_str(), result_type))
+        }
+    }
+}
+
 #[derive(Display, Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Hash)]
 pub enum Operator {
     #[display("==")]
@@ -1547,6 +1584,20 @@ pub fn has_agg(expr: &ExprRef) -> bool {
     expr.exists(|e| matches!(e.as_ref(), Expr::Agg(_)))
 }
 
+/// Whether `expr` always evaluates to the same values for the same rows, i.e. it doesn't call any nondeterministic
+/// functions, such as random number generators.
+pub fn is_deterministic(expr: &ExprRef) -> bool {
+    // Walked iteratively, since generated predicates can be chains of thousands of operators.
+    let mut stack = vec![expr.clone()];
+    while let Some(e) = stack.pop() {
+        if matches!(e.as_ref(), Expr::ScalarFunction(func) if !func.udf.is_deterministic()) {
+            return false;
+        }
+        stack.extend(e.children());
+    }
+    true
+}
+
 #[inline]
 pub fn is_actor_pool_udf(expr: &ExprRef) -> bool {
     matches!(
@@ -1580,86 +1631,6 @@ pub fn count_actor_pool_udfs(exprs: &[ExprRef]) -> usize {
         .sum()
 }
 
-pub fn estimated_selectivity(expr: &Expr, schema: &Schema) -> f64 {
-    let estimate = match expr {
-        // Boolean operations that filter rows
-        Expr::BinaryOp { op, left, right } => {
-            let left_selectivity = estimated_selectivity(left, schema);
-            let right_selectivity = estimated_selectivity(right, schema);
-            match op {
-                // Fixed selectivity for all common comparisons
-                Operator::Eq => 0.05,
-                Operator::EqNullSafe => 0.05,
-                Operator::NotEq => 0.95,
-                Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => 0.5,
-
-                // Logical operators with fixed estimates
-                // P(A and B) = P(A) * P(B)
-                Operator::And => left_selectivity * right_selectivity,
-                // P(A or B) = P(A) + P(B) - P(A and B)
-                Operator::Or => left_selectivity
-                    .mul_add(-right_selectivity, left_selectivity + right_selectivity),
-                // P(A xor B) = P(A) + P(B) - 2 * P(A and B)
-                Operator::Xor => 2.0f64.mul_add(
-                    -(left_selectivity * right_selectivity),
-                    left_selectivity + right_selectivity,
-                ),
-
-                // Non-boolean operators don't filter
-                Operator::Plus
-                | Operator::Minus
-                | Operator::Multiply
-                | Operator::TrueDivide
-                | Operator::FloorDivide
-                | Operator::Modulus
-                | Operator::ShiftLeft
-                | Operator::ShiftRight => 1.0,
-            }
-        }
-
-        // Revert selectivity for NOT
-        Expr::Not(expr) => 1.0 - estimated_selectivity(expr, schema),
-
-        // Fixed selectivity for IS NULL and IS NOT NULL, assume not many nulls
-        Expr::IsNull(_) => 0.05,
-        Expr::NotNull(_) => 0.95,
-
-        // All membership operations use same selectivity
-        Expr::IsIn(_, _) | Expr::Between(_, _, _) | Expr::InSubquery(_, _) | Expr::Exists(_) => 0.2,
-
-        // Pass through for expressions that wrap other expressions
-        Expr::Cast(expr, _) | Expr::Alias(expr, _) => estimated_selectivity(expr, schema),
-
-        // Boolean literals
-        Expr::Literal(lit) => match lit {
-            lit::LiteralValue::Boolean(true) => 1.0,
-            lit::LiteralValue::Boolean(false) => 0.0,
-            _ => 1.0,
-        },
-
-        // String contains
-        Expr::ScalarFunction(ScalarFunction { udf, .. }) if udf.name() == "contains" => 0.1,
-
-        // Everything else that could be boolean gets 0.2, non-boolean gets 1.0
-        Expr::ScalarFunction(_)
-        | Expr::Function { .. }
-        | Expr::Column(_)
-        | Expr::IfElse { .. }
-        | Expr::FillNull(_, _) => match expr.to_field(schema) {
-            Ok(field) if field.dtype == DataType::Boolean => 0.2,
-            _ => 1.0,
-        },
-
-        // Everything else doesn't filter
-        Expr::Subquery(_) => 1.0,
-        Expr::Agg(_) => panic!("Aggregates are not allowed in WHERE clauses"),
-        Expr::List(_) => 1.0,
-    };
-
-    // Lower bound to 1% to prevent overly selective estimate
-    estimate.max(0.01)
-}
-
 pub fn exprs_to_schema(exprs: &[ExprRef], input_schema: SchemaRef) -> DaftResult<SchemaRef> {
     let fields = exprs
         .iter()
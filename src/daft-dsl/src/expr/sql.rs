@@ -0,0 +1,283 @@
+//! Translation of filter expressions into SQL, so that they can be pushed down into sources that filter rows with SQL
+//! WHERE clauses, e.g. databases. Counts, minimums and maximums of columns are translated too, so that sources can
+//! compute pushed down aggregations.
+//!
+//! Not every source understands every construct, so the translation takes the [`SqlCapabilities`] of the source, and
+//! [`split_sql_pushdown`] pushes down only the conjuncts of a predicate that the source can evaluate.
+
+use std::io::{self, Write};
+
+use daft_core::count_mode::CountMode;
+use serde::{Deserialize, Serialize};
+
+use super::{AggExpr, Column, Expr, ExprRef, Operator, PlanRef, ResolvedColumn, UnresolvedColumn};
+use crate::functions::ScalarFunction;
+
+/// The SQL constructs, beyond comparisons, boolean logic and null checks of columns and literals, that a source can
+/// evaluate in a WHERE clause.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SqlCapabilities {
+    /// `+`, `-`, `*`, `%`, `<<` and `>>`.
+    pub arithmetic: bool,
+    /// `x IN (...)` with a list of literals.
+    pub is_in: bool,
+    /// `x BETWEEN a AND b`.
+    pub between: bool,
+    /// `x LIKE '...'` and `x ILIKE '...'`.
+    pub like: bool,
+    /// `x IS NOT DISTINCT FROM y`.
+    pub null_safe_equals: bool,
+}
+
+impl SqlCapabilities {
+    /// The capabilities of a source that only supports the basics.
+    #[must_use]
+    pub fn minimal() -> Self {
+        Self {
+            arithmetic: false,
+            is_in: false,
+            between: false,
+            like: false,
+            null_safe_equals: false,
+        }
+    }
+}
+
+impl Default for SqlCapabilities {
+    /// Everything that Daft can translate, which ANSI SQL databases support.
+    fn default() -> Self {
+        Self {
+            arithmetic: true,
+            is_in: true,
+            between: true,
+            like: true,
+            null_safe_equals: true,
+        }
+    }
+}
+
+fn unsupported<T>(what: &str) -> io::Result<T> {
+    Err(io::Error::other(format!(
+        "Unsupported {what} for SQL translation"
+    )))
+}
+
+/// Writes an identifier, quoting it unless it's a plain word.
+fn write_identifier<W: Write>(name: &str, buffer: &mut W) -> io::Result<()> {
+    let mut chars = name.chars();
+    let is_plain = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if is_plain {
+        write!(buffer, "{name}")
+    } else {
+        write!(buffer, "\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+/// Writes an operand of an operator, in parentheses unless it's a column or a literal.
+fn write_operand<W: Write>(
+    expr: &Expr,
+    capabilities: &SqlCapabilities,
+    buffer: &mut W,
+) -> io::Result<()> {
+    match expr {
+        Expr::Column(..) | Expr::Literal(..) => write_sql(expr, capabilities, buffer),
+        Expr::Alias(inner, ..) => write_operand(inner, capabilities, buffer),
+        _ => {
+            write!(buffer, "(")?;
+            write_sql(expr, capabilities, buffer)?;
+            write!(buffer, ")")
+        }
+    }
+}
+
+fn write_sql<W: Write>(
+    expr: &Expr,
+    capabilities: &SqlCapabilities,
+    buffer: &mut W,
+) -> io::Result<()> {
+    match expr {
+        Expr::Column(
+            Column::Resolved(ResolvedColumn::Basic(name))
+            | Column::Unresolved(UnresolvedColumn {
+                name,
+                plan_ref: PlanRef::Unqualified,
+                ..
+            }),
+        ) => write_identifier(name, buffer),
+        Expr::Literal(lit) => lit.display_sql(buffer),
+        Expr::Alias(inner, ..) => write_sql(inner, capabilities, buffer),
+        Expr::BinaryOp { op, left, right } => {
+            let op = match op {
+                Operator::Eq => "=",
+                Operator::EqNullSafe if capabilities.null_safe_equals => "IS NOT DISTINCT FROM",
+                Operator::NotEq => "!=",
+                Operator::Lt => "<",
+                Operator::LtEq => "<=",
+                Operator::Gt => ">",
+                Operator::GtEq => ">=",
+                Operator::And => "AND",
+                Operator::Or => "OR",
+                Operator::Plus if capabilities.arithmetic => "+",
+                Operator::Minus if capabilities.arithmetic => "-",
+                Operator::Multiply if capabilities.arithmetic => "*",
+                Operator::Modulus if capabilities.arithmetic => "%",
+                Operator::ShiftLeft if capabilities.arithmetic => "<<",
+                Operator::ShiftRight if capabilities.arithmetic => ">>",
+                // Division of integers truncates in SQL, unlike `TrueDivide`, and `FloorDivide` has no equivalent.
+                _ => return unsupported("operator"),
+            };
+            write_operand(left, capabilities, buffer)?;
+            write!(buffer, " {op} ")?;
+            write_operand(right, capabilities, buffer)
+        }
+        Expr::Not(inner) => {
+            write!(buffer, "NOT ")?;
+            write_operand(inner, capabilities, buffer)
+        }
+        Expr::IsNull(inner) => {
+            write_operand(inner, capabilities, buffer)?;
+            write!(buffer, " IS NULL")
+        }
+        Expr::NotNull(inner) => {
+            write_operand(inner, capabilities, buffer)?;
+            write!(buffer, " IS NOT NULL")
+        }
+        Expr::IsIn(inner, items)
+            if capabilities.is_in
+                && !items.is_empty()
+                && items.iter().all(|item| item.as_literal().is_some()) =>
+        {
+            write_operand(inner, capabilities, buffer)?;
+            write!(buffer, " IN (")?;
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    write!(buffer, ", ")?;
+                }
+                write_sql(item, capabilities, buffer)?;
+            }
+            write!(buffer, ")")
+        }
+        Expr::Between(inner, lower, upper) if capabilities.between => {
+            write_operand(inner, capabilities, buffer)?;
+            write!(buffer, " BETWEEN ")?;
+            write_operand(lower, capabilities, buffer)?;
+            write!(buffer, " AND ")?;
+            write_operand(upper, capabilities, buffer)
+        }
+        Expr::ScalarFunction(ScalarFunction { udf, inputs })
+            if capabilities.like
+                && matches!(udf.name(), "like" | "ilike")
+                && let [inner, pattern] = inputs.as_slice()
+                && pattern.as_literal().is_some() =>
+        {
+            write_operand(inner, capabilities, buffer)?;
+            write!(buffer, " {} ", udf.name().to_uppercase())?;
+            write_sql(pattern, capabilities, buffer)
+        }
+        Expr::Agg(AggExpr::Count(inner, mode)) if matches!(inner.as_ref(), Expr::Column(..)) => {
+            match mode {
+                CountMode::All => return write!(buffer, "COUNT(*)"),
+                CountMode::Valid => write!(buffer, "COUNT(")?,
+                CountMode::Null => write!(buffer, "COUNT(*) - COUNT(")?,
+            }
+            write_sql(inner, capabilities, buffer)?;
+            write!(buffer, ")")
+        }
+        Expr::Agg(agg @ (AggExpr::Min(inner) | AggExpr::Max(inner)))
+            if matches!(inner.as_ref(), Expr::Column(..)) =>
+        {
+            let name = if matches!(agg, AggExpr::Min(_)) {
+                "MIN"
+            } else {
+                "MAX"
+            };
+            write!(buffer, "{name}(")?;
+            write_sql(inner, capabilities, buffer)?;
+            write!(buffer, ")")
+        }
+        // TODO: Implement SQL translations for these expressions if possible
+        Expr::IfElse { .. }
+        | Expr::Agg(..)
+        | Expr::Cast(..)
+        | Expr::IsIn(..)
+        | Expr::List(..)
+        | Expr::Between(..)
+        | Expr::Function { .. }
+        | Expr::FillNull(..)
+        | Expr::ScalarFunction { .. }
+        | Expr::Subquery(..)
+        | Expr::InSubquery(..)
+        | Expr::Exists(..)
+        | Expr::Window(..)
+        | Expr::Column(..) => unsupported("expression"),
+    }
+}
+
+impl Expr {
+    /// Translates this expression into SQL, or returns None if it uses anything that can't be translated.
+    pub fn to_sql(&self) -> Option<String> {
+        self.to_sql_with_capabilities(&SqlCapabilities::default())
+    }
+
+    /// Translates this expression into SQL that a source with the given capabilities can evaluate, or returns None if
+    /// it uses anything that can't be translated for the source.
+    pub fn to_sql_with_capabilities(&self, capabilities: &SqlCapabilities) -> Option<String> {
+        let mut buffer = Vec::new();
+        write_sql(self, capabilities, &mut buffer)
+            .ok()
+            .and_then(|()| String::from_utf8(buffer).ok())
+    }
+}
+
+/// Splits `predicate` into a SQL WHERE clause of the conjuncts that a source with the given capabilities can evaluate,
+/// and the conjunction of the remaining ones, which have to be evaluated after reading from the source.
+pub fn split_sql_pushdown(
+    predicate: &ExprRef,
+    capabilities: &SqlCapabilities,
+) -> (Option<String>, Option<ExprRef>) {
+    fn conjuncts(expr: &ExprRef, out: &mut Vec<ExprRef>) {
+        match expr.as_ref() {
+            Expr::BinaryOp {
+                op: Operator::And,
+                left,
+                right,
+            } => {
+                conjuncts(left, out);
+                conjuncts(right, out);
+            }
+            _ => out.push(expr.clone()),
+        }
+    }
+
+    let mut all = vec![];
+    conjuncts(predicate, &mut all);
+
+    let mut pushed = vec![];
+    let mut remaining: Option<ExprRef> = None;
+    for conjunct in all {
+        match conjunct.to_sql_with_capabilities(capabilities) {
+            Some(sql) => pushed.push(sql),
+            None => {
+                remaining = Some(match remaining {
+                    Some(remaining) => remaining.and(conjunct),
+                    None => conjunct,
+                });
+            }
+        }
+    }
+    let pushed = match pushed.as_slice() {
+        [] => None,
+        [sql] => Some(sql.clone()),
+        _ => Some(
+            pushed
+                .iter()
+                .map(|sql| format!("({sql})"))
+                .collect::<Vec<_>>()
+                .join(" AND "),
+        ),
+    };
+    (pushed, remaining)
+}
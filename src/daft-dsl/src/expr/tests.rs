@@ -81,3 +81,193 @@ fn check_arithmetic_type_with_columns() -> DaftResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn check_to_sql() {
+    let expr = resolved_col("a")
+        .gt(lit(1))
+        .or(resolved_col("b").eq(lit("it's")))
+        .and(resolved_col("my col").is_in(vec![lit(1), lit(2)]).not());
+    assert_eq!(
+        expr.to_sql().as_deref(),
+        Some("((a > 1) OR (b = 'it''s')) AND (NOT (\"my col\" IN (1, 2)))")
+    );
+
+    let expr = resolved_col("a").between(lit(1), lit(3));
+    assert_eq!(expr.to_sql().as_deref(), Some("a BETWEEN 1 AND 3"));
+    assert_eq!(
+        expr.to_sql_with_capabilities(&SqlCapabilities::minimal()),
+        None
+    );
+
+    // True division truncates integers in SQL, so it isn't translated.
+    let expr = binary_op(Operator::TrueDivide, resolved_col("a"), lit(2)).gt(lit(1));
+    assert_eq!(expr.to_sql(), None);
+}
+
+#[test]
+fn check_aggregation_to_sql() {
+    let a = resolved_col("a");
+    assert_eq!(
+        a.clone().count(CountMode::All).to_sql().as_deref(),
+        Some("COUNT(*)")
+    );
+    assert_eq!(
+        a.clone().count(CountMode::Valid).to_sql().as_deref(),
+        Some("COUNT(a)")
+    );
+    assert_eq!(
+        a.clone().count(CountMode::Null).to_sql().as_deref(),
+        Some("COUNT(*) - COUNT(a)")
+    );
+    assert_eq!(
+        resolved_col("my col").min().alias("m").to_sql().as_deref(),
+        Some("MIN(\"my col\")")
+    );
+    assert_eq!(a.clone().max().to_sql().as_deref(), Some("MAX(a)"));
+
+    // Only aggregations of columns are translated.
+    assert_eq!(
+        binary_op(Operator::Plus, a.clone(), lit(1)).max().to_sql(),
+        None
+    );
+    assert_eq!(a.sum().to_sql(), None);
+}
+
+#[test]
+fn check_split_sql_pushdown() {
+    let supported = resolved_col("a").gt(lit(1));
+    let unsupported = binary_op(Operator::TrueDivide, resolved_col("b"), lit(2)).lt(lit(1));
+    let is_in = resolved_col("c").is_in(vec![lit(1), lit(2)]);
+    let predicate = supported.and(unsupported.clone()).and(is_in.clone());
+
+    let (sql, remaining) = split_sql_pushdown(&predicate, &SqlCapabilities::default());
+    assert_eq!(sql.as_deref(), Some("(a > 1) AND (c IN (1, 2))"));
+    assert_eq!(remaining, Some(unsupported.clone()));
+
+    let (sql, remaining) = split_sql_pushdown(&predicate, &SqlCapabilities::minimal());
+    assert_eq!(sql.as_deref(), Some("a > 1"));
+    assert_eq!(remaining, Some(unsupported.and(is_in)));
+}
+
+#[test]
+fn check_display_parentheses() {
+    let a = resolved_col("a");
+    let b = resolved_col("b");
+    let c = resolved_col("c");
+
+    let sum = binary_op(Operator::Plus, a.clone(), b.clone());
+    let expr = binary_op(Operator::Multiply, sum.clone(), c.clone());
+    assert_eq!(expr.to_string(), "(col(a) + col(b)) * col(c)");
+
+    let expr = binary_op(
+        Operator::Plus,
+        a.clone(),
+        binary_op(Operator::Multiply, b.clone(), c.clone()),
+    );
+    assert_eq!(expr.to_string(), "col(a) + col(b) * col(c)");
+
+    // Operators are left-associative.
+    let expr = binary_op(Operator::Minus, sum.clone(), c.clone());
+    assert_eq!(expr.to_string(), "col(a) + col(b) - col(c)");
+    let expr = binary_op(
+        Operator::Minus,
+        a.clone(),
+        binary_op(Operator::Plus, b.clone(), c.clone()),
+    );
+    assert_eq!(expr.to_string(), "col(a) - (col(b) + col(c))");
+
+    let expr = a
+        .clone()
+        .gt(lit(1))
+        .and(b.clone().eq(lit(2)))
+        .or(c.clone().eq(sum.clone()));
+    assert_eq!(
+        expr.to_string(),
+        "(col(a) > lit(1)) & (col(b) == lit(2)) | (col(c) == col(a) + col(b))"
+    );
+
+    let expr = a.clone().or(b.clone()).and(c.clone());
+    assert_eq!(expr.to_string(), "(col(a) | col(b)) & col(c)");
+
+    let expr = a.clone().eq(b.clone()).eq(c.clone());
+    assert_eq!(expr.to_string(), "(col(a) == col(b)) == col(c)");
+
+    let expr = a.clone().is_in(vec![lit(1)]).and(sum.alias("s").gt(lit(0)));
+    assert_eq!(
+        expr.to_string(),
+        "(col(a) IN (lit(1))) & (col(a) + col(b) > lit(0))"
+    );
+}
+
+#[test]
+fn check_display_truncates_long_lists() {
+    let items = (0..10_000).map(lit).collect::<Vec<_>>();
+    let expr = resolved_col("a").is_in(items.clone());
+    assert_eq!(
+        expr.to_string(),
+        "col(a) IN (lit(0), lit(1), lit(2), lit(3), lit(4), lit(5), lit(6), lit(7), lit(8), lit(9), ... 9990 more)"
+    );
+
+    let expr = Expr::List(items[..3].to_vec());
+    assert_eq!(expr.to_string(), "list(lit(0), lit(1), lit(2))");
+}
+
+#[test]
+fn check_long_binary_op_chain_field() -> DaftResult<()> {
+    let schema = Schema::new(vec![Field::new("a", DataType::Int64)])?;
+
+    let chain = (0..2000).fold(resolved_col("a"), |acc, i| {
+        binary_op(Operator::Plus, acc, lit(i as i64))
+    });
+    assert_eq!(chain.name(), "a");
+    assert_eq!(chain.to_field(&schema)?, Field::new("a", DataType::Int64));
+
+    // The operands are combined in order, so a float anywhere in the chain makes the rest of it a float.
+    let chain = binary_op(Operator::Plus, chain, lit(0.5));
+    let chain = binary_op(Operator::Plus, chain, lit(1i64)).gt(lit(0));
+    assert_eq!(chain.to_field(&schema)?, Field::new("a", DataType::Boolean));
+    let chain = binary_op(Operator::Minus, resolved_col("a"), lit(0.5));
+    assert_eq!(chain.get_type(&schema)?, DataType::Float64);
+    Ok(())
+}
+
+#[test]
+fn check_bind_columns() -> DaftResult<()> {
+    let schema = Schema::new(vec![
+        Field::new("x", DataType::Float64),
+        Field::new("y", DataType::Int64),
+    ])?;
+
+    let bound = BoundExpr::try_new(
+        binary_op(Operator::Plus, resolved_col("y"), unresolved_col("x")),
+        &schema,
+    )?;
+    assert_eq!(
+        bound.inner(),
+        &binary_op(
+            Operator::Plus,
+            Expr::Column(Column::Bound(BoundColumn {
+                index: 1,
+                field: Field::new("y", DataType::Int64),
+            }))
+            .arced(),
+            Expr::Column(Column::Bound(BoundColumn {
+                index: 0,
+                field: Field::new("x", DataType::Float64),
+            }))
+            .arced(),
+        )
+    );
+    assert_eq!(bound.to_string(), "col(y#1) + col(x#0)");
+    assert_eq!(
+        bound.inner().to_field(&schema)?,
+        Field::new("y", DataType::Float64)
+    );
+
+    assert!(matches!(
+        BoundExpr::try_new(resolved_col("z").gt(lit(0)), &schema),
+        Err(DaftError::FieldNotFound(..))
+    ));
+    Ok(())
+}
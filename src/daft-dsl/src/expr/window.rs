@@ -0,0 +1,358 @@
+//! Window functions, which compute a value for every row from the rows of its partition, e.g. its position in the
+//! partition when the rows are ordered by some expressions.
+//!
+//! A window expression is evaluated by partitioning its input by the partition by expressions of its [`WindowSpec`],
+//! sorting every partition by the order by expressions, and evaluating the [`WindowExpr`] over each sorted partition.
+
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use common_error::{DaftError, DaftResult};
+use common_treenode::TreeNode;
+use daft_core::{prelude::*, utils::supertype::try_get_supertype};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use super::{Expr, ExprRef};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct OffsetWindowParams {
+    pub child: ExprRef,
+    /// The number of rows before (lag) or after (lead) the current row to take the value of the child from.
+    pub offset: usize,
+    /// The value for rows without a row at the offset in their partition, null if not given.
+    pub default: Option<ExprRef>,
+}
+
+#[derive(Display, Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum WindowExpr {
+    /// The position of the row in its partition, starting at 1.
+    #[display("row_number()")]
+    RowNumber,
+
+    /// The position of the first row in the partition that the row ties with, starting at 1, so that ties get the
+    /// same rank and leave gaps after them.
+    #[display("rank()")]
+    Rank,
+
+    #[display("lag({}, offset={}{})", _0.child, _0.offset, display_default(&_0.default))]
+    Lag(OffsetWindowParams),
+
+    #[display("lead({}, offset={}{})", _0.child, _0.offset, display_default(&_0.default))]
+    Lead(OffsetWindowParams),
+}
+
+fn display_default(default: &Option<ExprRef>) -> String {
+    match default {
+        Some(default) => format!(", default={default}"),
+        None => String::new(),
+    }
+}
+
+impl WindowExpr {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::RowNumber => "row_number",
+            Self::Rank => "rank",
+            Self::Lag(OffsetWindowParams { child, .. })
+            | Self::Lead(OffsetWindowParams { child, .. }) => child.name(),
+        }
+    }
+
+    pub fn children(&self) -> Vec<ExprRef> {
+        match self {
+            Self::RowNumber | Self::Rank => vec![],
+            Self::Lag(OffsetWindowParams { child, default, .. })
+            | Self::Lead(OffsetWindowParams { child, default, .. }) => {
+                std::iter::once(child).chain(default).cloned().collect()
+            }
+        }
+    }
+
+    pub fn with_new_children(&self, children: Vec<ExprRef>) -> Self {
+        assert_eq!(
+            children.len(),
+            self.children().len(),
+            "Should have same number of children"
+        );
+        let with_new_params = |params: &OffsetWindowParams| {
+            let mut children = children.into_iter();
+            OffsetWindowParams {
+                child: children.next().expect("Should have 1 child"),
+                offset: params.offset,
+                default: params
+                    .default
+                    .as_ref()
+                    .map(|_| children.next().expect("Should have 2 children")),
+            }
+        };
+        match self {
+            Self::RowNumber | Self::Rank => self.clone(),
+            Self::Lag(params) => Self::Lag(with_new_params(params)),
+            Self::Lead(params) => Self::Lead(with_new_params(params)),
+        }
+    }
+
+    pub fn semantic_id(&self, schema: &Schema) -> FieldID {
+        match self {
+            Self::RowNumber => FieldID::new("row_number()"),
+            Self::Rank => FieldID::new("rank()"),
+            Self::Lag(params) => params.semantic_id("lag", schema),
+            Self::Lead(params) => params.semantic_id("lead", schema),
+        }
+    }
+
+    pub fn to_field(&self, schema: &Schema) -> DaftResult<Field> {
+        match self {
+            Self::RowNumber | Self::Rank => Ok(Field::new(self.name(), DataType::UInt64)),
+            Self::Lag(OffsetWindowParams { child, default, .. })
+            | Self::Lead(OffsetWindowParams { child, default, .. }) => {
+                let field = child.to_field(schema)?;
+                let Some(default) = default else {
+                    return Ok(field);
+                };
+                let default_field = default.to_field(schema)?;
+                match try_get_supertype(&field.dtype, &default_field.dtype) {
+                    Ok(supertype) => Ok(Field::new(field.name, supertype)),
+                    Err(_) => Err(DaftError::TypeError(format!(
+                        "Expected the input and default of {self} to be castable to the same supertype, but received {field} and {default_field}",
+                    ))),
+                }
+            }
+        }
+    }
+}
+
+impl OffsetWindowParams {
+    fn semantic_id(&self, function: &str, schema: &Schema) -> FieldID {
+        let child_id = self.child.semantic_id(schema);
+        let default_id = match &self.default {
+            Some(default) => format!(",{}", default.semantic_id(schema)),
+            None => String::new(),
+        };
+        FieldID::new(format!(
+            "{child_id}.{function}({}{default_id})",
+            self.offset
+        ))
+    }
+}
+
+/// A boundary of a [`WindowFrame`], relative to the current row.
+#[derive(Display, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum WindowBoundary {
+    #[display("unbounded preceding")]
+    UnboundedPreceding,
+    #[display("{_0} preceding")]
+    Preceding(usize),
+    #[display("current row")]
+    CurrentRow,
+    #[display("{_0} following")]
+    Following(usize),
+    #[display("unbounded following")]
+    UnboundedFollowing,
+}
+
+/// The rows of the partition of the current row that a window function is computed over, e.g. to compute running or
+/// moving aggregations.
+#[derive(Display, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[display("rows between {start} and {end}")]
+pub struct WindowFrame {
+    pub start: WindowBoundary,
+    pub end: WindowBoundary,
+}
+
+/// How rows with null order by values are ordered in a window.
+#[derive(Display, Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum NullStrategy {
+    /// Nulls are ordered before all other values in ascending order, and after them in descending order.
+    #[display("smallest")]
+    Smallest,
+
+    /// Nulls are ordered after all other values in ascending order, and before them in descending order, like in
+    /// sorts.
+    #[default]
+    #[display("largest")]
+    Largest,
+
+    /// Rows with a null order by value are left out of the window, so that window functions are null for them, and
+    /// e.g. lag and lead skip over them.
+    #[display("skip")]
+    Skip,
+}
+
+impl NullStrategy {
+    /// Whether nulls should be sorted before other values when sorting in the given direction.
+    pub fn nulls_first(self, descending: bool) -> bool {
+        match self {
+            Self::Smallest => !descending,
+            Self::Largest | Self::Skip => descending,
+        }
+    }
+}
+
+impl FromStr for NullStrategy {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        match s {
+            "smallest" => Ok(Self::Smallest),
+            "largest" => Ok(Self::Largest),
+            "skip" => Ok(Self::Skip),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected the null strategy of a window to be one of 'smallest', 'largest' or 'skip', but received '{s}'"
+            ))),
+        }
+    }
+}
+
+/// How the rows of the input of a window function are partitioned and ordered.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct WindowSpec {
+    pub partition_by: Vec<ExprRef>,
+    pub order_by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+    pub null_strategy: NullStrategy,
+    pub frame: Option<WindowFrame>,
+}
+
+impl WindowSpec {
+    pub fn children(&self) -> Vec<ExprRef> {
+        self.partition_by
+            .iter()
+            .chain(&self.order_by)
+            .cloned()
+            .collect()
+    }
+
+    /// Replaces the partition by and then the order by expressions with `children`.
+    pub fn with_new_children(&self, children: Vec<ExprRef>) -> Self {
+        assert_eq!(
+            children.len(),
+            self.partition_by.len() + self.order_by.len(),
+            "Should have same number of children"
+        );
+        let mut partition_by = children;
+        let order_by = partition_by.split_off(self.partition_by.len());
+        Self {
+            partition_by,
+            order_by,
+            descending: self.descending.clone(),
+            null_strategy: self.null_strategy,
+            frame: self.frame,
+        }
+    }
+
+    pub fn semantic_id(&self, schema: &Schema) -> String {
+        let partition_by = self
+            .partition_by
+            .iter()
+            .map(|e| e.semantic_id(schema).id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let order_by = self
+            .order_by
+            .iter()
+            .zip(&self.descending)
+            .map(|(e, desc)| {
+                format!(
+                    "{}{}",
+                    e.semantic_id(schema),
+                    if *desc { " desc" } else { "" }
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let frame = self
+            .frame
+            .map(|frame| format!(",{frame}"))
+            .unwrap_or_default();
+        let null_strategy = match self.null_strategy {
+            NullStrategy::Largest => String::new(),
+            null_strategy => format!(",nulls={null_strategy}"),
+        };
+        format!("partition_by=[{partition_by}],order_by=[{order_by}]{null_strategy}{frame}")
+    }
+}
+
+impl Display for WindowSpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let partition_by = self
+            .partition_by
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let order_by = self
+            .order_by
+            .iter()
+            .zip(&self.descending)
+            .map(|(e, desc)| format!("{e} {}", if *desc { "desc" } else { "asc" }))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "(partition_by=[{partition_by}], order_by=[{order_by}]")?;
+        if self.null_strategy != NullStrategy::Largest {
+            write!(f, ", nulls {}", self.null_strategy)?;
+        }
+        if let Some(frame) = &self.frame {
+            write!(f, ", {frame}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl Expr {
+    /// Evaluates this window function over the partitions and order of `spec`.
+    ///
+    /// Window functions are constructed over their whole input, e.g. by [`row_number`], and this replaces how their
+    /// input is partitioned and ordered.
+    pub fn over(self: ExprRef, spec: WindowSpec) -> DaftResult<ExprRef> {
+        match self.as_ref() {
+            Self::Window(window_expr, _) => Ok(Self::Window(window_expr.clone(), spec).into()),
+            Self::Alias(inner, name) => Ok(inner.clone().over(spec)?.alias(name.clone())),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected a window function such as row_number() to evaluate over a window, but received {self}"
+            ))),
+        }
+    }
+
+    pub fn lag(self: ExprRef, offset: usize, default: Option<ExprRef>) -> ExprRef {
+        Self::Window(
+            WindowExpr::Lag(OffsetWindowParams {
+                child: self,
+                offset,
+                default,
+            }),
+            WindowSpec::default(),
+        )
+        .into()
+    }
+
+    pub fn lead(self: ExprRef, offset: usize, default: Option<ExprRef>) -> ExprRef {
+        Self::Window(
+            WindowExpr::Lead(OffsetWindowParams {
+                child: self,
+                offset,
+                default,
+            }),
+            WindowSpec::default(),
+        )
+        .into()
+    }
+}
+
+/// The position of each row in its window partition, starting at 1.
+pub fn row_number() -> ExprRef {
+    Expr::Window(WindowExpr::RowNumber, WindowSpec::default()).into()
+}
+
+/// The rank of each row in its window partition, starting at 1, with gaps after ties.
+pub fn rank() -> ExprRef {
+    Expr::Window(WindowExpr::Rank, WindowSpec::default()).into()
+}
+
+/// Whether `expr` contains a window function.
+pub fn has_window(expr: &ExprRef) -> bool {
+    expr.exists(|e| matches!(e.as_ref(), Expr::Window(..)))
+}
@@ -45,6 +45,18 @@ pub trait ScalarUDF: Send + Sync + std::fmt::Debug {
     fn name(&self) -> &'static str;
     fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series>;
     fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field>;
+
+    /// Evaluates the function on a batch of `num_rows` rows. Functions that return a value per row without needing
+    /// any inputs, such as random number generators, override this to know how many values to return.
+    fn evaluate_with_num_rows(&self, inputs: &[Series], _num_rows: usize) -> DaftResult<Series> {
+        self.evaluate(inputs)
+    }
+
+    /// Whether the function always returns the same outputs for the same inputs. The optimizer doesn't move, merge
+    /// or fold calls to nondeterministic functions, since that would change their results.
+    fn is_deterministic(&self) -> bool {
+        true
+    }
 }
 
 pub fn scalar_function_semantic_id(func: &ScalarFunction, schema: &Schema) -> FieldID {
@@ -58,9 +70,19 @@ pub fn scalar_function_semantic_id(func: &ScalarFunction, schema: &Schema) -> Fi
     FieldID::new(format!("Function_{func:?}({inputs})"))
 }
 
+impl ScalarFunction {
+    /// The serialized function, which includes its parameters, e.g. the key of a keyed hash, so that calls with
+    /// different parameters aren't considered equal.
+    fn serialized_udf(&self) -> Option<Vec<u8>> {
+        bincode::serialize(&self.udf).ok()
+    }
+}
+
 impl PartialEq for ScalarFunction {
     fn eq(&self, other: &Self) -> bool {
-        self.name() == other.name() && self.inputs == other.inputs
+        self.name() == other.name()
+            && self.inputs == other.inputs
+            && self.serialized_udf() == other.serialized_udf()
     }
 }
 
@@ -69,6 +91,7 @@ impl std::hash::Hash for ScalarFunction {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.name().hash(state);
         self.inputs.hash(state);
+        self.serialized_udf().hash(state);
     }
 }
 
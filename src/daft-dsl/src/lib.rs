@@ -11,17 +11,24 @@ pub mod optimization;
 mod pyobj_serde;
 #[cfg(feature = "python")]
 pub mod python;
+mod selectivity;
 mod treenode;
 pub use common_treenode;
 pub use expr::{
-    binary_op, count_actor_pool_udfs, deduplicate_expr_names, estimated_selectivity,
-    exprs_to_schema, has_agg, is_actor_pool_udf, is_partition_compatible, resolved_col,
-    unresolved_col, AggExpr, ApproxPercentileParams, Column, Expr, ExprRef, Operator, PlanRef,
-    ResolvedColumn, SketchType, Subquery, SubqueryPlan, UnresolvedColumn,
+    binary_op, count_actor_pool_udfs, deduplicate_expr_names, exprs_to_schema, has_agg, has_window,
+    is_actor_pool_udf, is_deterministic, is_partition_compatible, rank, resolved_col, row_number,
+    split_sql_pushdown, unresolved_col, AggExpr, ApproxPercentileParams, BoundColumn, BoundExpr,
+    Column, Expr, ExprRef, NullStrategy, OffsetWindowParams, Operator, PlanRef, ResolvedColumn,
+    SketchType, SqlCapabilities, Subquery, SubqueryPlan, UnresolvedColumn, WindowBoundary,
+    WindowExpr, WindowFrame, WindowSpec,
 };
 pub use lit::{lit, literal_value, literals_to_series, null_lit, Literal, LiteralValue};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
+pub use selectivity::{
+    estimated_selectivity, estimated_selectivity_with_stats, ColumnSelectivityStats, Histogram,
+    SelectivityStats,
+};
 
 #[cfg(feature = "python")]
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
@@ -31,6 +38,8 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_function(wrap_pyfunction!(python::resolved_col, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::lit, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::list_, parent)?)?;
+    parent.add_function(wrap_pyfunction!(python::row_number, parent)?)?;
+    parent.add_function(wrap_pyfunction!(python::rank, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::date_lit, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::time_lit, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::timestamp_lit, parent)?)?;
@@ -294,8 +294,9 @@ impl LiteralValue {
             Self::UInt32(val) => write!(buffer, "{}", val),
             Self::Int64(val) => write!(buffer, "{}", val),
             Self::UInt64(val) => write!(buffer, "{}", val),
-            Self::Float64(val) => write!(buffer, "{}", val),
-            Self::Utf8(val) => write!(buffer, "'{}'", val),
+            Self::Float64(val) if val.is_finite() => write!(buffer, "{}", val),
+            Self::Float64(..) => display_sql_err,
+            Self::Utf8(val) => write!(buffer, "'{}'", val.replace('\'', "''")),
             Self::Date(val) => write!(buffer, "DATE '{}'", display_date32(*val)),
             // The `display_timestamp` function formats a timestamp in the ISO 8601 format: "YYYY-MM-DDTHH:MM:SS.fffff".
             // ANSI SQL standard uses a space instead of 'T'. Some databases do not support 'T', hence it's replaced with a space.
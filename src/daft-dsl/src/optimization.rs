@@ -2,13 +2,23 @@ use std::collections::HashMap;
 
 use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
 
-use crate::{expr::ResolvedColumn, Column, Expr, ExprRef};
+use crate::{
+    expr::{BoundColumn, ResolvedColumn},
+    functions::{struct_::StructExpr, FunctionExpr},
+    Column, Expr, ExprRef,
+};
 
 pub fn get_required_columns(e: &ExprRef) -> Vec<String> {
     let mut cols = vec![];
     e.apply(&mut |expr: &ExprRef| {
-        if let Expr::Column(Column::Resolved(ResolvedColumn::Basic(ref name))) = &**expr {
-            cols.push(name.to_string());
+        match &**expr {
+            Expr::Column(Column::Resolved(ResolvedColumn::Basic(name))) => {
+                cols.push(name.to_string());
+            }
+            Expr::Column(Column::Bound(BoundColumn { field, .. })) => {
+                cols.push(field.name.clone());
+            }
+            _ => {}
         }
         Ok(TreeNodeRecursion::Continue)
     })
@@ -16,6 +26,64 @@ pub fn get_required_columns(e: &ExprRef) -> Vec<String> {
     cols
 }
 
+/// Returns the path of the column or nested struct field that `expr` accesses, if it's a column or a chain of struct
+/// field accesses of one, e.g. `["s", "a"]` for `col("s").struct.get("a")`.
+fn struct_field_path(expr: &Expr) -> Option<Vec<String>> {
+    match expr {
+        Expr::Column(Column::Resolved(ResolvedColumn::Basic(name))) => Some(vec![name.to_string()]),
+        Expr::Function {
+            func: FunctionExpr::Struct(StructExpr::Get(field)),
+            inputs,
+        } if inputs.len() == 1 => struct_field_path(&inputs[0]).map(|mut path| {
+            path.push(field.clone());
+            path
+        }),
+        _ => None,
+    }
+}
+
+/// Returns the struct fields that `exprs` read from each column that they use.
+///
+/// Fields are paths made of the column name followed by the names of nested struct fields, e.g.
+/// `["s", "a"]` for `col("s").struct.get("a")`. Columns that are used other than through struct
+/// field accesses map to `None`, since all of their fields are needed.
+pub fn get_required_struct_fields<'a>(
+    exprs: impl IntoIterator<Item = &'a ExprRef>,
+) -> HashMap<String, Option<Vec<Vec<String>>>> {
+    let mut fields = HashMap::<String, Option<Vec<Vec<String>>>>::new();
+    for e in exprs {
+        e.apply(&mut |expr: &ExprRef| match struct_field_path(expr) {
+            Some(path) if path.len() > 1 => {
+                if let Some(paths) = fields
+                    .entry(path[0].clone())
+                    .or_insert_with(|| Some(vec![]))
+                {
+                    paths.push(path);
+                }
+                Ok(TreeNodeRecursion::Jump)
+            }
+            Some(mut path) => {
+                fields.insert(path.pop().unwrap(), None);
+                Ok(TreeNodeRecursion::Continue)
+            }
+            None => Ok(TreeNodeRecursion::Continue),
+        })
+        .expect("Error occurred when visiting for required struct fields");
+    }
+    fields
+}
+
+/// Returns the column or nested struct field that `e` only renames, e.g. `col("s").struct.get("a")` for
+/// `col("s").struct.get("a").alias("b")`, or None if `e` computes anything else.
+///
+/// References to the output of `e` can be replaced with the returned expression to move them past `e`.
+pub fn get_renamed_field(e: &ExprRef) -> Option<ExprRef> {
+    match e.as_ref() {
+        Expr::Alias(inner, _) => get_renamed_field(inner),
+        _ => struct_field_path(e).map(|_| e.clone()),
+    }
+}
+
 pub fn requires_computation(e: &Expr) -> bool {
     // Returns whether or not this expression runs any computation on the underlying data
     match e {
@@ -36,7 +104,8 @@ pub fn requires_computation(e: &Expr) -> bool {
         | Expr::IfElse { .. }
         | Expr::Subquery { .. }
         | Expr::InSubquery { .. }
-        | Expr::Exists(..) => true,
+        | Expr::Exists(..)
+        | Expr::Window(..) => true,
     }
 }
 
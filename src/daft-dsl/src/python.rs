@@ -21,7 +21,7 @@ use pyo3::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::{Expr, ExprRef, LiteralValue};
+use crate::{Expr, ExprRef, LiteralValue, SketchType, WindowBoundary, WindowFrame, WindowSpec};
 
 #[pyfunction]
 pub fn unresolved_col(name: &str) -> PyExpr {
@@ -187,6 +187,28 @@ pub fn lit(item: Bound<PyAny>) -> PyResult<PyExpr> {
     }
 }
 
+#[pyfunction]
+pub fn row_number() -> PyExpr {
+    crate::row_number().into()
+}
+
+#[pyfunction]
+pub fn rank() -> PyExpr {
+    crate::rank().into()
+}
+
+/// The boundary of a window frame at `offset` rows from the current row, where negative offsets precede it and None is
+/// unbounded in the direction of the boundary.
+fn window_boundary(offset: Option<i64>, is_start: bool) -> WindowBoundary {
+    match offset {
+        None if is_start => WindowBoundary::UnboundedPreceding,
+        None => WindowBoundary::UnboundedFollowing,
+        Some(0) => WindowBoundary::CurrentRow,
+        Some(offset) if offset < 0 => WindowBoundary::Preceding(offset.unsigned_abs() as usize),
+        Some(offset) => WindowBoundary::Following(offset as usize),
+    }
+}
+
 #[pyfunction]
 pub fn list_(items: Vec<PyExpr>) -> PyExpr {
     Expr::List(items.into_iter().map(|item| item.into()).collect()).into()
@@ -311,6 +333,22 @@ impl PyExpr {
         Ok(self.expr.clone().approx_count_distinct().into())
     }
 
+    pub fn hll_sketch(&self) -> PyResult<Self> {
+        Ok(self
+            .expr
+            .clone()
+            .approx_sketch(SketchType::HyperLogLog)
+            .into())
+    }
+
+    pub fn hll_merge(&self) -> PyResult<Self> {
+        Ok(self
+            .expr
+            .clone()
+            .merge_sketch(SketchType::HyperLogLog)
+            .into())
+    }
+
     pub fn approx_percentiles(&self, percentiles: ApproxPercentileInput) -> PyResult<Self> {
         let (percentiles, list_output) = match percentiles {
             ApproxPercentileInput::Single(p) => (vec![p], false),
@@ -373,6 +411,46 @@ impl PyExpr {
         Ok(self.expr.clone().agg_concat().into())
     }
 
+    #[pyo3(signature = (offset, default=None))]
+    pub fn lag(&self, offset: usize, default: Option<Self>) -> PyResult<Self> {
+        Ok(self
+            .expr
+            .clone()
+            .lag(offset, default.map(Into::into))
+            .into())
+    }
+
+    #[pyo3(signature = (offset, default=None))]
+    pub fn lead(&self, offset: usize, default: Option<Self>) -> PyResult<Self> {
+        Ok(self
+            .expr
+            .clone()
+            .lead(offset, default.map(Into::into))
+            .into())
+    }
+
+    #[pyo3(signature = (partition_by, order_by, descending, null_strategy="largest", frame=None))]
+    pub fn over(
+        &self,
+        partition_by: Vec<Self>,
+        order_by: Vec<Self>,
+        descending: Vec<bool>,
+        null_strategy: &str,
+        frame: Option<(Option<i64>, Option<i64>)>,
+    ) -> PyResult<Self> {
+        let spec = WindowSpec {
+            partition_by: partition_by.into_iter().map(Into::into).collect(),
+            order_by: order_by.into_iter().map(Into::into).collect(),
+            descending,
+            null_strategy: null_strategy.parse()?,
+            frame: frame.map(|(start, end)| WindowFrame {
+                start: window_boundary(start, true),
+                end: window_boundary(end, false),
+            }),
+        };
+        Ok(self.expr.clone().over(spec)?.into())
+    }
+
     pub fn __add__(&self, other: &Self) -> PyResult<Self> {
         Ok(crate::binary_op(crate::Operator::Plus, self.into(), other.expr.clone()).into())
     }
@@ -469,10 +547,53 @@ impl PyExpr {
         Ok(self.expr.name())
     }
 
+    /// Aggregation that merges partial results of this aggregation, read from the column with this
+    /// expression's output name.
+    pub fn _merge_partial_aggregation(&self) -> PyResult<Self> {
+        let name = self.expr.name();
+        let agg = match self.expr.as_ref() {
+            Expr::Alias(expr, _) => expr.as_ref(),
+            expr => expr,
+        };
+        let merged = match agg {
+            Expr::Agg(agg) => agg.merge_partial_results(crate::unresolved_col(name)),
+            _ => None,
+        };
+        match merged {
+            Some(merged) => Ok(Expr::Agg(merged).arced().alias(name).into()),
+            None => Err(PyValueError::new_err(format!(
+                "Partial results of {} can't be merged",
+                self.expr
+            ))),
+        }
+    }
+
     pub fn to_sql(&self) -> PyResult<Option<String>> {
         Ok(self.expr.to_sql())
     }
 
+    /// Splits this predicate into SQL for the conjuncts that a source with the given capabilities can evaluate, and the
+    /// conjunction of the rest.
+    #[pyo3(signature = (arithmetic=true, is_in=true, between=true, like=true, null_safe_equals=true))]
+    pub fn split_sql_pushdown(
+        &self,
+        arithmetic: bool,
+        is_in: bool,
+        between: bool,
+        like: bool,
+        null_safe_equals: bool,
+    ) -> (Option<String>, Option<Self>) {
+        let capabilities = crate::SqlCapabilities {
+            arithmetic,
+            is_in,
+            between,
+            like,
+            null_safe_equals,
+        };
+        let (sql, remaining) = crate::split_sql_pushdown(&self.expr, &capabilities);
+        (sql, remaining.map(Into::into))
+    }
+
     pub fn to_field(&self, schema: &PySchema) -> PyResult<PyField> {
         Ok(self.expr.to_field(&schema.schema)?.into())
     }
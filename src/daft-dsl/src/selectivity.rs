@@ -0,0 +1,484 @@
+//! Heuristic estimates of the fraction of rows that pass a filter.
+//!
+//! Without statistics, every kind of predicate gets a fixed selectivity (equality is more selective than a range, which
+//! is more selective than a negation) and boolean combinations of predicates assume they are independent. When the
+//! min, max, number of distinct values or fraction of nulls of a column is known, comparisons between that column and
+//! literals and null checks are estimated from them instead, e.g. `x < 10` over `x` in `[0, 100]` keeps 10% of the rows.
+//! An equi-depth [`Histogram`] of a column replaces the assumption that its values are uniformly distributed between its
+//! bounds, so that range predicates over skewed columns are estimated well too.
+
+use std::collections::HashMap;
+
+use daft_core::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{expr::Expr, functions::ScalarFunction, lit::LiteralValue, Operator};
+
+/// Statistics of the values of a column that refine selectivity estimates.
+///
+/// Bounds are in terms of the physical values of the column, e.g. days since the epoch for a date column.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ColumnSelectivityStats {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub num_distinct: Option<f64>,
+    /// The fraction of the values of the column that are null.
+    pub null_fraction: Option<f64>,
+    pub histogram: Option<Histogram>,
+}
+
+/// An equi-depth histogram of the non-null values of a numeric or temporal column.
+///
+/// The `n + 1` boundaries split the values into `n` buckets that each hold the same number of values, so the boundaries
+/// are the `0/n, 1/n, ..., n/n` quantiles of the column. Like the bounds of [`ColumnSelectivityStats`], they are in terms
+/// of the physical values of the column.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Histogram {
+    boundaries: Vec<f64>,
+}
+
+impl Histogram {
+    /// Creates a histogram from its boundaries, or returns None if there are fewer than two of them or they aren't
+    /// finite and sorted.
+    #[must_use]
+    pub fn new(boundaries: Vec<f64>) -> Option<Self> {
+        let valid = boundaries.len() >= 2
+            && boundaries.iter().all(|b| b.is_finite())
+            && boundaries.windows(2).all(|w| w[0] <= w[1]);
+        valid.then_some(Self { boundaries })
+    }
+
+    #[must_use]
+    pub fn boundaries(&self) -> &[f64] {
+        &self.boundaries
+    }
+
+    #[must_use]
+    pub fn num_buckets(&self) -> usize {
+        self.boundaries.len() - 1
+    }
+
+    #[must_use]
+    pub fn min(&self) -> f64 {
+        self.boundaries[0]
+    }
+
+    #[must_use]
+    pub fn max(&self) -> f64 {
+        self.boundaries[self.num_buckets()]
+    }
+
+    /// The fraction of the values that are less than `value`, assuming they're uniformly distributed within each bucket.
+    #[must_use]
+    pub fn fraction_below(&self, value: f64) -> f64 {
+        if value <= self.min() {
+            return 0.0;
+        }
+        if value > self.max() {
+            return 1.0;
+        }
+        // The first bucket whose upper boundary is at least `value`.
+        let bucket = self.boundaries[1..].partition_point(|&b| b < value);
+        let (lower, upper) = (self.boundaries[bucket], self.boundaries[bucket + 1]);
+        let within = if upper > lower {
+            (value - lower) / (upper - lower)
+        } else {
+            0.0
+        };
+        (bucket as f64 + within) / self.num_buckets() as f64
+    }
+
+    /// The fraction of the values that are equal to `value`, as far as the histogram can tell: a value that fills whole
+    /// buckets by itself is at least as common as those buckets, while the frequency of any other value is unknown.
+    #[must_use]
+    pub fn fraction_equal(&self, value: f64) -> f64 {
+        let full_buckets = self
+            .boundaries
+            .windows(2)
+            .filter(|w| w[0] == value && w[1] == value)
+            .count();
+        full_buckets as f64 / self.num_buckets() as f64
+    }
+
+    /// The value below which a `fraction` of the values are, i.e. the inverse of [`Histogram::fraction_below`].
+    #[must_use]
+    pub fn quantile(&self, fraction: f64) -> f64 {
+        let position = fraction.clamp(0.0, 1.0) * self.num_buckets() as f64;
+        let bucket = (position.floor() as usize).min(self.num_buckets() - 1);
+        let (lower, upper) = (self.boundaries[bucket], self.boundaries[bucket + 1]);
+        (position - bucket as f64).mul_add(upper - lower, lower)
+    }
+
+    /// Estimates the boundaries between `num_partitions` range partitions of the column that hold the same number of
+    /// rows. Values that make up a large share of the column can't be split between partitions, so there are fewer
+    /// distinct boundaries, and thus non-empty partitions, when the column is skewed.
+    #[must_use]
+    pub fn range_boundaries(&self, num_partitions: usize) -> Vec<f64> {
+        let mut boundaries = (1..num_partitions)
+            .map(|i| self.quantile(i as f64 / num_partitions as f64))
+            .collect::<Vec<_>>();
+        boundaries.dedup();
+        boundaries
+    }
+}
+
+/// Statistics of the columns of a filter's input, by column name.
+pub type SelectivityStats = HashMap<String, ColumnSelectivityStats>;
+
+const EQ_SELECTIVITY: f64 = 0.05;
+const RANGE_SELECTIVITY: f64 = 0.5;
+const MEMBERSHIP_SELECTIVITY: f64 = 0.2;
+const SUBSTRING_SELECTIVITY: f64 = 0.1;
+
+/// Returns the value of a literal as it's physically stored, if it's a number.
+fn numeric_value(value: &LiteralValue) -> Option<f64> {
+    match value {
+        LiteralValue::Int8(v) => Some(f64::from(*v)),
+        LiteralValue::UInt8(v) => Some(f64::from(*v)),
+        LiteralValue::Int16(v) => Some(f64::from(*v)),
+        LiteralValue::UInt16(v) => Some(f64::from(*v)),
+        LiteralValue::Int32(v) => Some(f64::from(*v)),
+        LiteralValue::UInt32(v) => Some(f64::from(*v)),
+        LiteralValue::Int64(v) => Some(*v as f64),
+        LiteralValue::UInt64(v) => Some(*v as f64),
+        LiteralValue::Float64(v) => Some(*v),
+        LiteralValue::Date(v) => Some(f64::from(*v)),
+        LiteralValue::Timestamp(v, ..)
+        | LiteralValue::Time(v, _)
+        | LiteralValue::Duration(v, _) => Some(*v as f64),
+        _ => None,
+    }
+}
+
+fn column_stats<'a>(
+    expr: &Expr,
+    stats: &'a SelectivityStats,
+) -> Option<&'a ColumnSelectivityStats> {
+    match expr {
+        Expr::Column(_) => stats.get(expr.name()),
+        _ => None,
+    }
+}
+
+/// The fraction of the values of a column that are less than `value`, from its histogram if it has one and otherwise
+/// assuming they're uniformly distributed.
+fn fraction_below(stats: &ColumnSelectivityStats, value: f64) -> Option<f64> {
+    if let Some(histogram) = &stats.histogram {
+        return Some(histogram.fraction_below(value));
+    }
+    let (min, max) = (stats.min?, stats.max?);
+    if max > min {
+        Some(((value - min) / (max - min)).clamp(0.0, 1.0))
+    } else if value > max {
+        Some(1.0)
+    } else {
+        Some(0.0)
+    }
+}
+
+fn eq_selectivity(stats: Option<&ColumnSelectivityStats>, value: Option<&LiteralValue>) -> f64 {
+    let Some(stats) = stats else {
+        return EQ_SELECTIVITY;
+    };
+    if let Some(value) = value.and_then(numeric_value)
+        && let (Some(min), Some(max)) = (stats.min, stats.max)
+        && (value < min || value > max)
+    {
+        return 0.0;
+    }
+    let selectivity = match stats.num_distinct {
+        Some(num_distinct) if num_distinct >= 1.0 => 1.0 / num_distinct,
+        _ => EQ_SELECTIVITY,
+    };
+    match (&stats.histogram, value.and_then(numeric_value)) {
+        (Some(histogram), Some(value)) => selectivity.max(histogram.fraction_equal(value)),
+        _ => selectivity,
+    }
+}
+
+/// Estimates the selectivity of a comparison, using the statistics of the column if it's between a column and a
+/// literal.
+fn comparison_selectivity(
+    op: Operator,
+    left: &Expr,
+    right: &Expr,
+    stats: &SelectivityStats,
+) -> f64 {
+    // Normalize the comparison so that the column is on the left.
+    let (op, column, value) = if let Some(column) = column_stats(left, stats) {
+        (op, Some(column), right.as_literal())
+    } else if let Some(column) = column_stats(right, stats) {
+        let flipped = match op {
+            Operator::Lt => Operator::Gt,
+            Operator::LtEq => Operator::GtEq,
+            Operator::Gt => Operator::Lt,
+            Operator::GtEq => Operator::LtEq,
+            op => op,
+        };
+        (flipped, Some(column), left.as_literal())
+    } else {
+        (op, None, None)
+    };
+
+    match op {
+        Operator::Eq | Operator::EqNullSafe => eq_selectivity(column, value),
+        Operator::NotEq => 1.0 - eq_selectivity(column, value),
+        Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => {
+            let Some((column, value)) = column.zip(value.and_then(numeric_value)) else {
+                return RANGE_SELECTIVITY;
+            };
+            let Some(below) = fraction_below(column, value) else {
+                return RANGE_SELECTIVITY;
+            };
+            // Only a histogram tells how common the value itself is, which matters for skewed columns.
+            let equal = column
+                .histogram
+                .as_ref()
+                .map_or(0.0, |histogram| histogram.fraction_equal(value));
+            match op {
+                Operator::Lt => below,
+                Operator::LtEq => (below + equal).min(1.0),
+                Operator::Gt => (1.0 - below - equal).max(0.0),
+                _ => 1.0 - below,
+            }
+        }
+        _ => unreachable!("Expected a comparison operator, got {op}"),
+    }
+}
+
+/// Estimates the selectivity of a LIKE pattern, which is that of an equality if it has no wildcards.
+fn like_selectivity(inputs: &[crate::ExprRef], stats: &SelectivityStats) -> f64 {
+    match inputs {
+        [column, pattern]
+            if let Some(LiteralValue::Utf8(pattern)) = pattern.as_literal()
+                && !pattern.contains(['%', '_']) =>
+        {
+            eq_selectivity(column_stats(column, stats), None)
+        }
+        _ => SUBSTRING_SELECTIVITY,
+    }
+}
+
+/// Estimates the fraction of rows for which `expr` is true, see [`estimated_selectivity_with_stats`].
+pub fn estimated_selectivity(expr: &Expr, schema: &Schema) -> f64 {
+    estimated_selectivity_with_stats(expr, schema, &SelectivityStats::new())
+}
+
+/// Estimates the fraction of rows for which `expr` is true, refining the estimates of comparisons between columns and
+/// literals with the statistics of those columns.
+pub fn estimated_selectivity_with_stats(
+    expr: &Expr,
+    schema: &Schema,
+    stats: &SelectivityStats,
+) -> f64 {
+    let estimate = match expr {
+        // Boolean operations that filter rows
+        Expr::BinaryOp { op, left, right } => match op {
+            Operator::Eq
+            | Operator::EqNullSafe
+            | Operator::NotEq
+            | Operator::Lt
+            | Operator::LtEq
+            | Operator::Gt
+            | Operator::GtEq => comparison_selectivity(*op, left, right, stats),
+
+            // Logical operators assume their operands are independent
+            Operator::And | Operator::Or | Operator::Xor => {
+                let left_selectivity = estimated_selectivity_with_stats(left, schema, stats);
+                let right_selectivity = estimated_selectivity_with_stats(right, schema, stats);
+                match op {
+                    // P(A and B) = P(A) * P(B)
+                    Operator::And => left_selectivity * right_selectivity,
+                    // P(A or B) = P(A) + P(B) - P(A and B)
+                    Operator::Or => left_selectivity
+                        .mul_add(-right_selectivity, left_selectivity + right_selectivity),
+                    // P(A xor B) = P(A) + P(B) - 2 * P(A and B)
+                    _ => 2.0f64.mul_add(
+                        -(left_selectivity * right_selectivity),
+                        left_selectivity + right_selectivity,
+                    ),
+                }
+            }
+
+            // Non-boolean operators don't filter
+            Operator::Plus
+            | Operator::Minus
+            | Operator::Multiply
+            | Operator::TrueDivide
+            | Operator::FloorDivide
+            | Operator::Modulus
+            | Operator::ShiftLeft
+            | Operator::ShiftRight => 1.0,
+        },
+
+        // Revert selectivity for NOT
+        Expr::Not(expr) => 1.0 - estimated_selectivity_with_stats(expr, schema, stats),
+
+        // IS NULL and IS NOT NULL use the fraction of nulls in the column if known, otherwise assume not many nulls
+        Expr::IsNull(expr) => column_stats(expr, stats)
+            .and_then(|s| s.null_fraction)
+            .unwrap_or(0.05),
+        Expr::NotNull(expr) => column_stats(expr, stats)
+            .and_then(|s| s.null_fraction)
+            .map_or(0.95, |null_fraction| 1.0 - null_fraction),
+
+        // Membership in a list of values is as selective as one equality per distinct value
+        Expr::IsIn(expr, items) => match column_stats(expr, stats).and_then(|s| s.num_distinct) {
+            Some(num_distinct) if num_distinct >= 1.0 => {
+                (items.len() as f64 / num_distinct).min(1.0)
+            }
+            _ => MEMBERSHIP_SELECTIVITY,
+        },
+        Expr::Between(expr, lower, upper) => {
+            let bounds = column_stats(expr, stats).and_then(|column| {
+                let lower = fraction_below(column, numeric_value(lower.as_literal()?)?)?;
+                let upper = fraction_below(column, numeric_value(upper.as_literal()?)?)?;
+                Some((upper - lower).max(0.0))
+            });
+            bounds.unwrap_or(MEMBERSHIP_SELECTIVITY)
+        }
+        Expr::InSubquery(_, _) | Expr::Exists(_) => MEMBERSHIP_SELECTIVITY,
+
+        // Pass through for expressions that wrap other expressions
+        Expr::Cast(expr, _) | Expr::Alias(expr, _) => {
+            estimated_selectivity_with_stats(expr, schema, stats)
+        }
+
+        // Boolean literals
+        Expr::Literal(lit) => match lit {
+            LiteralValue::Boolean(true) => 1.0,
+            LiteralValue::Boolean(false) => 0.0,
+            _ => 1.0,
+        },
+
+        // String matching
+        Expr::ScalarFunction(ScalarFunction { udf, inputs })
+            if matches!(udf.name(), "like" | "ilike") =>
+        {
+            like_selectivity(inputs, stats)
+        }
+        Expr::ScalarFunction(ScalarFunction { udf, .. })
+            if matches!(udf.name(), "contains" | "startswith" | "endswith") =>
+        {
+            SUBSTRING_SELECTIVITY
+        }
+
+        // Everything else that could be boolean gets 0.2, non-boolean gets 1.0
+        Expr::ScalarFunction(_)
+        | Expr::Function { .. }
+        | Expr::Column(_)
+        | Expr::IfElse { .. }
+        | Expr::FillNull(_, _) => match expr.to_field(schema) {
+            Ok(field) if field.dtype == DataType::Boolean => 0.2,
+            _ => 1.0,
+        },
+
+        // Everything else doesn't filter
+        Expr::Subquery(_) => 1.0,
+        Expr::Agg(_) => panic!("Aggregates are not allowed in WHERE clauses"),
+        Expr::Window(..) => panic!("Window functions are not allowed in WHERE clauses"),
+        Expr::List(_) => 1.0,
+    };
+
+    // Lower bound to 1% to prevent overly selective estimate
+    estimate.max(0.01)
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+
+    use super::{
+        estimated_selectivity, estimated_selectivity_with_stats, ColumnSelectivityStats, Histogram,
+        SelectivityStats,
+    };
+    use crate::{lit, resolved_col};
+
+    fn schema() -> Schema {
+        Schema::new(vec![
+            Field::new("x", DataType::Int64),
+            Field::new("s", DataType::Utf8),
+        ])
+        .unwrap()
+    }
+
+    fn stats() -> SelectivityStats {
+        SelectivityStats::from([(
+            "x".to_string(),
+            ColumnSelectivityStats {
+                min: Some(0.0),
+                max: Some(100.0),
+                num_distinct: Some(50.0),
+                null_fraction: Some(0.5),
+                histogram: None,
+            },
+        )])
+    }
+
+    #[test]
+    fn test_selectivity_without_stats() {
+        let schema = schema();
+        let eq = estimated_selectivity(&resolved_col("x").eq(lit(3i64)), &schema);
+        let range = estimated_selectivity(&resolved_col("x").lt(lit(3i64)), &schema);
+        assert!(eq < range);
+
+        let both = resolved_col("x")
+            .eq(lit(3i64))
+            .and(resolved_col("x").lt(lit(3i64)));
+        assert!((estimated_selectivity(&both, &schema) - eq * range).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_selectivity_with_stats() {
+        let schema = schema();
+        let stats = stats();
+        let estimate = |expr| estimated_selectivity_with_stats(&expr, &schema, &stats);
+
+        assert!((estimate(resolved_col("x").eq(lit(3i64))) - 0.02).abs() < 1e-9);
+        assert!((estimate(resolved_col("x").lt(lit(10i64))) - 0.1).abs() < 1e-9);
+        // Literals on the left compare the other way around.
+        assert!((estimate(lit(10i64).lt(resolved_col("x"))) - 0.9).abs() < 1e-9);
+        assert!((estimate(resolved_col("x").between(lit(20i64), lit(60i64))) - 0.4).abs() < 1e-9);
+        assert!(
+            (estimate(resolved_col("x").is_in(vec![lit(1i64), lit(2i64)])) - 0.04).abs() < 1e-9
+        );
+        // Values outside of the column's range match nothing, down to the lower bound of 1%.
+        assert!((estimate(resolved_col("x").eq(lit(1000i64))) - 0.01).abs() < 1e-9);
+        assert!((estimate(resolved_col("x").is_null()) - 0.5).abs() < 1e-9);
+        // Columns without stats fall back to the fixed estimates.
+        assert!((estimate(resolved_col("s").eq(lit("a"))) - 0.05).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_selectivity_with_histogram() {
+        let schema = schema();
+        // Half of the values are 0, and the rest are spread over [0, 100].
+        let histogram = Histogram::new(vec![0.0, 0.0, 0.0, 50.0, 100.0]).unwrap();
+        let stats = SelectivityStats::from([(
+            "x".to_string(),
+            ColumnSelectivityStats {
+                min: Some(0.0),
+                max: Some(100.0),
+                num_distinct: Some(50.0),
+                histogram: Some(histogram.clone()),
+                ..Default::default()
+            },
+        )]);
+        let estimate = |expr| estimated_selectivity_with_stats(&expr, &schema, &stats);
+
+        assert!((estimate(resolved_col("x").lt(lit(25i64))) - 0.625).abs() < 1e-9);
+        assert!((estimate(resolved_col("x").gt(lit(50i64))) - 0.25).abs() < 1e-9);
+        assert!((estimate(resolved_col("x").gt(lit(0i64))) - 0.5).abs() < 1e-9);
+        assert!((estimate(resolved_col("x").gt_eq(lit(0i64))) - 1.0).abs() < 1e-9);
+        assert!((estimate(resolved_col("x").between(lit(50i64), lit(75i64))) - 0.125).abs() < 1e-9);
+        // The skewed value makes up at least the buckets it fills.
+        assert!((estimate(resolved_col("x").eq(lit(0i64))) - 0.5).abs() < 1e-9);
+        assert!((estimate(resolved_col("x").eq(lit(30i64))) - 0.02).abs() < 1e-9);
+
+        // The skewed value can't be split, so there are fewer range partitions than requested.
+        assert_eq!(histogram.range_boundaries(4), vec![0.0, 50.0]);
+        assert_eq!(histogram.range_boundaries(2), vec![0.0]);
+        assert!(Histogram::new(vec![1.0, 0.0]).is_none());
+        assert!(Histogram::new(vec![1.0]).is_none());
+    }
+}
@@ -0,0 +1,221 @@
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct AssertTrue {
+    pub message: String,
+}
+
+#[typetag::serde]
+impl ScalarUDF for AssertTrue {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "assert_true"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [predicate] => {
+                let field = predicate.to_field(schema)?;
+                match &field.dtype {
+                    DataType::Boolean => Ok(field),
+                    other => Err(DaftError::TypeError(format!(
+                        "Expected assertion `{predicate}` to be a boolean expression, but received {other}"
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [predicate] => {
+                // Nulls count as violations, so that e.g. a range check also rejects missing values.
+                let num_violations = predicate
+                    .bool()?
+                    .into_iter()
+                    .filter(|v| *v != Some(true))
+                    .count();
+                if num_violations > 0 {
+                    return Err(DaftError::ValueError(format!(
+                        "Assertion failed for {num_violations} of {} rows: {}",
+                        predicate.len(),
+                        self.message
+                    )));
+                }
+                Ok(predicate.clone())
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+/// Passes `predicate` through unchanged, failing with `message` if it is false or null for any row.
+#[must_use]
+pub fn assert_true(predicate: ExprRef, message: String) -> ExprRef {
+    ScalarFunction::new(AssertTrue { message }, vec![predicate]).into()
+}
+
+/// The rows that violated the predicates of [`record_violations`] expressions, by the ids of the expressions.
+static RECORDED_VIOLATIONS: LazyLock<Mutex<HashMap<String, Vec<Vec<Series>>>>> =
+    LazyLock::new(Default::default);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct RecordViolations {
+    pub violations_id: String,
+}
+
+#[typetag::serde]
+impl ScalarUDF for RecordViolations {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "record_violations"
+    }
+
+    // Evaluating it records rows, so it must be evaluated every time its query is executed.
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [predicate, ..] => AssertTrue {
+                message: String::new(),
+            }
+            .to_field(&[predicate.clone()], schema),
+            [] => Err(DaftError::SchemaMismatch(
+                "Expected at least 1 input arg, got 0".to_string(),
+            )),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [predicate, columns @ ..] => {
+                let predicate = predicate.bool()?;
+                // Nulls count as violations, like they do for `assert_true`.
+                let holds = BooleanArray::from_iter(
+                    predicate.name(),
+                    predicate.into_iter().map(|v| Some(v == Some(true))),
+                );
+                if holds.into_iter().any(|v| v == Some(false)) {
+                    let violations = BooleanArray::from_iter(
+                        predicate.name(),
+                        holds.into_iter().map(|v| v.map(|v| !v)),
+                    );
+                    let rows = columns
+                        .iter()
+                        .map(|column| column.filter(&violations))
+                        .collect::<DaftResult<Vec<_>>>()?;
+                    RECORDED_VIOLATIONS
+                        .lock()
+                        .unwrap()
+                        .entry(self.violations_id.clone())
+                        .or_default()
+                        .push(rows);
+                }
+                Ok(holds.into_series())
+            }
+            [] => Err(DaftError::ValueError(
+                "Expected at least 1 input arg, got 0".to_string(),
+            )),
+        }
+    }
+}
+
+/// Keeps the rows for which `predicate` is true, recording the values of `columns` in the other rows as violations
+/// under `violations_id`, to be read with [`recorded_violations`].
+#[must_use]
+pub fn record_violations(
+    predicate: ExprRef,
+    columns: Vec<ExprRef>,
+    violations_id: String,
+) -> ExprRef {
+    let inputs = std::iter::once(predicate).chain(columns).collect();
+    ScalarFunction::new(RecordViolations { violations_id }, inputs).into()
+}
+
+/// The values of the columns of the rows recorded by [`record_violations`] expressions with `violations_id`, in
+/// batches.
+#[must_use]
+pub fn recorded_violations(violations_id: &str) -> Vec<Vec<Series>> {
+    RECORDED_VIOLATIONS
+        .lock()
+        .unwrap()
+        .get(violations_id)
+        .cloned()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+    use daft_dsl::functions::ScalarUDF;
+
+    use super::{recorded_violations, AssertTrue, RecordViolations};
+
+    #[test]
+    fn test_assert_true() {
+        let udf = AssertTrue {
+            message: "x must be positive".to_string(),
+        };
+
+        let passing = BooleanArray::from(("x", [true, true].as_slice())).into_series();
+        assert_eq!(udf.evaluate(&[passing.clone()]).unwrap(), passing);
+
+        let failing = BooleanArray::from_iter("x", vec![Some(true), Some(false), None].into_iter())
+            .into_series();
+        let err = udf.evaluate(&[failing]).unwrap_err().to_string();
+        assert!(
+            err.contains("Assertion failed for 2 of 3 rows: x must be positive"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_record_violations() {
+        let udf = RecordViolations {
+            violations_id: "test_record_violations".to_string(),
+        };
+
+        let predicate =
+            BooleanArray::from_iter("x", vec![Some(true), Some(false), None].into_iter())
+                .into_series();
+        let column = Int64Array::from(("x", vec![1, -1, 0])).into_series();
+        let holds = udf.evaluate(&[predicate, column]).unwrap();
+        assert_eq!(
+            holds,
+            BooleanArray::from(("x", [true, false, false].as_slice())).into_series()
+        );
+
+        let violations = recorded_violations("test_record_violations");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0],
+            vec![Int64Array::from(("x", vec![-1, 0])).into_series()]
+        );
+    }
+}
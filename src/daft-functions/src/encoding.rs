@@ -0,0 +1,129 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+/// The number of categories in `categories`, which must be a literal Series.
+fn num_categories(categories: &ExprRef) -> DaftResult<usize> {
+    match categories.as_literal().and_then(|lit| lit.as_series()) {
+        Some(categories) => Ok(categories.len()),
+        None => Err(DaftError::ValueError(format!(
+            "Expected categories to be a literal list of values, got {categories}"
+        ))),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct OneHot {}
+
+#[typetag::serde]
+impl ScalarUDF for OneHot {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "one_hot"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input, categories] => {
+                let field = input.to_field(schema)?;
+                let num_categories = num_categories(categories)?;
+                Ok(Field::new(
+                    field.name,
+                    DataType::FixedSizeList(Box::new(DataType::UInt8), num_categories),
+                ))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input, categories] => input.one_hot(categories),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct LabelEncode {}
+
+#[typetag::serde]
+impl ScalarUDF for LabelEncode {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "label_encode"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input, categories] => {
+                num_categories(categories)?;
+                let field = input.to_field(schema)?;
+                Ok(Field::new(field.name, DataType::UInt64))
+            }
+            [input, categories, labels] => {
+                if num_categories(categories)? != num_categories(labels)? {
+                    return Err(DaftError::ValueError(
+                        "Expected the same number of categories and labels to label encode"
+                            .to_string(),
+                    ));
+                }
+                let field = input.to_field(schema)?;
+                Ok(Field::new(field.name, labels.to_field(schema)?.dtype))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 or 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input, categories] => Ok(input.category_indices(categories)?.into_series()),
+            [input, categories, labels] => {
+                let indices = input.category_indices(categories)?.into_series();
+                Ok(labels.take(&indices)?.rename(input.name()))
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 or 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+/// Encodes each value as a FixedSizeList of UInt8s with a 1 at the index of its category in `categories`.
+///
+/// `categories` must be a literal Series. Values that aren't one of the categories are encoded as all 0s.
+#[must_use]
+pub fn one_hot(input: ExprRef, categories: ExprRef) -> ExprRef {
+    ScalarFunction::new(OneHot {}, vec![input, categories]).into()
+}
+
+/// Encodes each value as the label of its category, or null if it isn't one of the categories.
+///
+/// `categories` must be a literal Series, as must `labels` if it's given. Without `labels`, each category is labeled
+/// with its index.
+#[must_use]
+pub fn label_encode(input: ExprRef, categories: ExprRef, labels: Option<ExprRef>) -> ExprRef {
+    let mut inputs = vec![input, categories];
+    inputs.extend(labels);
+    ScalarFunction::new(LabelEncode {}, inputs).into()
+}
@@ -0,0 +1,79 @@
+use daft_dsl::{ExprRef, LiteralValue};
+
+fn as_float_literal(expr: &ExprRef) -> Option<f64> {
+    match expr.as_literal()? {
+        LiteralValue::Float64(f) => Some(*f),
+        LiteralValue::Int64(i) => Some(*i as f64),
+        _ => None,
+    }
+}
+
+/// Whether the points (`lat`, `lon`) are in the bounding box between the corners (`min_lat`, `min_lon`) and
+/// (`max_lat`, `max_lon`), inclusive.
+///
+/// Boxes with a `min_lon` greater than their `max_lon` cross the antimeridian. The predicate is made of comparisons,
+/// rather than a function, so that it can prune scans by the statistics of the coordinate columns.
+#[must_use]
+pub fn within_bbox(
+    lat: ExprRef,
+    lon: ExprRef,
+    min_lat: ExprRef,
+    min_lon: ExprRef,
+    max_lat: ExprRef,
+    max_lon: ExprRef,
+) -> ExprRef {
+    let within_lat = lat.between(min_lat, max_lat);
+    let within_lon = lon.clone().between(min_lon.clone(), max_lon.clone());
+    let across_antimeridian = lon
+        .clone()
+        .gt_eq(min_lon.clone())
+        .or(lon.lt_eq(max_lon.clone()));
+    let crosses_antimeridian = match (as_float_literal(&min_lon), as_float_literal(&max_lon)) {
+        (Some(min_lon), Some(max_lon)) => Some(min_lon > max_lon),
+        _ => None,
+    };
+    match crosses_antimeridian {
+        Some(false) => within_lat.and(within_lon),
+        Some(true) => within_lat.and(across_antimeridian),
+        None => within_lat.and(within_lon.or(min_lon.gt(max_lon).and(across_antimeridian))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_dsl::{lit, resolved_col};
+
+    use super::within_bbox;
+
+    #[test]
+    fn check_within_bbox_literal_bounds() {
+        let lat = resolved_col("lat");
+        let lon = resolved_col("lon");
+        let bbox = within_bbox(
+            lat.clone(),
+            lon.clone(),
+            lit(10.0),
+            lit(-20.0),
+            lit(30.0),
+            lit(40.0),
+        );
+        let expected = lat
+            .clone()
+            .between(lit(10.0), lit(30.0))
+            .and(lon.clone().between(lit(-20.0), lit(40.0)));
+        assert_eq!(bbox, expected);
+
+        let bbox = within_bbox(
+            lat.clone(),
+            lon.clone(),
+            lit(10.0),
+            lit(170.0),
+            lit(30.0),
+            lit(-170.0),
+        );
+        let expected = lat
+            .between(lit(10.0), lit(30.0))
+            .and(lon.clone().gt_eq(lit(170.0)).or(lon.lt_eq(lit(-170.0))));
+        assert_eq!(bbox, expected);
+    }
+}
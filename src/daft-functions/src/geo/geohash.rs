@@ -0,0 +1,227 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{float_coordinates, point::point_dtype, to_field_coordinates};
+
+const GEOHASH_ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// The longest geohash that can be encoded, which is precise to a few centimeters.
+pub const MAX_GEOHASH_PRECISION: usize = 12;
+
+/// Encodes a point as a geohash of `precision` characters, or None if the point is out of range.
+fn encode_geohash(lat: f64, lon: f64, precision: usize) -> Option<String> {
+    if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+        return None;
+    }
+    let mut lat_range = (-90.0, 90.0);
+    let mut lon_range = (-180.0, 180.0);
+    let mut geohash = String::with_capacity(precision);
+    // Bits alternate between longitude and latitude, starting with longitude.
+    let mut is_lon = true;
+    let mut index = 0;
+    let mut num_bits = 0;
+    while geohash.len() < precision {
+        let (range, value) = if is_lon {
+            (&mut lon_range, lon)
+        } else {
+            (&mut lat_range, lat)
+        };
+        let mid = (range.0 + range.1) / 2.0;
+        index <<= 1;
+        if value >= mid {
+            index |= 1;
+            range.0 = mid;
+        } else {
+            range.1 = mid;
+        }
+        is_lon = !is_lon;
+        num_bits += 1;
+        if num_bits == 5 {
+            geohash.push(GEOHASH_ALPHABET[index] as char);
+            index = 0;
+            num_bits = 0;
+        }
+    }
+    Some(geohash)
+}
+
+/// Decodes a geohash into the center of its cell, or None if it isn't a valid geohash.
+fn decode_geohash(geohash: &str) -> Option<(f64, f64)> {
+    if geohash.is_empty() {
+        return None;
+    }
+    let mut lat_range = (-90.0, 90.0);
+    let mut lon_range = (-180.0, 180.0);
+    let mut is_lon = true;
+    for c in geohash.bytes() {
+        let index = GEOHASH_ALPHABET
+            .iter()
+            .position(|&b| b == c.to_ascii_lowercase())?;
+        for shift in (0..5).rev() {
+            let range = if is_lon {
+                &mut lon_range
+            } else {
+                &mut lat_range
+            };
+            let mid = (range.0 + range.1) / 2.0;
+            if (index >> shift) & 1 == 1 {
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            is_lon = !is_lon;
+        }
+    }
+    Some((
+        (lat_range.0 + lat_range.1) / 2.0,
+        (lon_range.0 + lon_range.1) / 2.0,
+    ))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct GeohashEncode {
+    pub precision: usize,
+}
+
+#[typetag::serde]
+impl ScalarUDF for GeohashEncode {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "geohash_encode"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        let field = to_field_coordinates(self, inputs, schema, 2)?;
+        Ok(Field::new(field.name, DataType::Utf8))
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        let coordinates = float_coordinates(inputs)?;
+        let [lat, lon] = coordinates.as_slice() else {
+            return Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            )));
+        };
+        let arrow_result = (0..lat.len())
+            .map(|i| encode_geohash(lat.get(i)?, lon.get(i)?, self.precision))
+            .collect::<arrow2::array::Utf8Array<i64>>();
+        Ok(Utf8Array::from((inputs[0].name(), Box::new(arrow_result))).into_series())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct GeohashDecode {}
+
+#[typetag::serde]
+impl ScalarUDF for GeohashDecode {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "geohash_decode"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let field = input.to_field(schema)?;
+                if field.dtype == DataType::Utf8 {
+                    Ok(Field::new(field.name, point_dtype()))
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Expected input to geohash_decode to be utf8, got {}",
+                        field.dtype
+                    )))
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => {
+                let points = input
+                    .utf8()?
+                    .as_arrow()
+                    .iter()
+                    .map(|geohash| decode_geohash(geohash?))
+                    .collect::<Vec<_>>();
+                let lat = points.iter().map(|point| point.map(|(lat, _)| lat));
+                let lon = points.iter().map(|point| point.map(|(_, lon)| lon));
+                let validity =
+                    arrow2::bitmap::Bitmap::from_iter(points.iter().map(Option::is_some));
+                let children = vec![
+                    Float64Array::from_iter(Field::new("lat", DataType::Float64), lat)
+                        .into_series(),
+                    Float64Array::from_iter(Field::new("lon", DataType::Float64), lon)
+                        .into_series(),
+                ];
+                let field = Field::new(input.name(), point_dtype());
+                Ok(StructArray::new(field, children, Some(validity)).into_series())
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+/// Encodes the points (`lat`, `lon`) as geohashes of `precision` characters.
+#[must_use]
+pub fn geohash_encode(lat: ExprRef, lon: ExprRef, precision: usize) -> ExprRef {
+    ScalarFunction::new(GeohashEncode { precision }, vec![lat, lon]).into()
+}
+
+/// Decodes geohashes into the points at the centers of their cells.
+#[must_use]
+pub fn geohash_decode(input: ExprRef) -> ExprRef {
+    ScalarFunction::new(GeohashDecode {}, vec![input]).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_geohash, encode_geohash};
+
+    #[test]
+    fn check_encode_geohash() {
+        assert_eq!(
+            encode_geohash(57.64911, 10.40744, 11).as_deref(),
+            Some("u4pruydqqvj")
+        );
+        assert_eq!(encode_geohash(42.605, -5.603, 5).as_deref(), Some("ezs42"));
+        assert_eq!(encode_geohash(-90.0, -180.0, 3).as_deref(), Some("000"));
+        assert_eq!(encode_geohash(90.0, 180.0, 3).as_deref(), Some("zzz"));
+        assert_eq!(encode_geohash(91.0, 0.0, 5), None);
+        assert_eq!(encode_geohash(0.0, f64::NAN, 5), None);
+    }
+
+    #[test]
+    fn check_decode_geohash() {
+        let (lat, lon) = decode_geohash("ezs42").unwrap();
+        assert!((lat - 42.605).abs() < 0.01 && (lon + 5.603).abs() < 0.01);
+        assert_eq!(decode_geohash("EZS42"), decode_geohash("ezs42"));
+        assert_eq!(decode_geohash("s"), Some((22.5, 22.5)));
+        // The letters a, i, l and o aren't in the geohash alphabet.
+        assert_eq!(decode_geohash("ezs4a"), None);
+        assert_eq!(decode_geohash(""), None);
+
+        let geohash = encode_geohash(-33.8688, 151.2093, 9).unwrap();
+        let (lat, lon) = decode_geohash(&geohash).unwrap();
+        assert!((lat + 33.8688).abs() < 1e-4 && (lon - 151.2093).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,85 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{float_coordinates, to_field_coordinates};
+
+/// The mean radius of the Earth in kilometers.
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// The great-circle distance in kilometers between two points, given in degrees.
+fn haversine(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let delta_lat = lat2 - lat1;
+    let delta_lon = (lon2 - lon1).to_radians();
+    let a =
+        (delta_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
+    // Rounding can push `a` slightly above 1 for antipodal points.
+    2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct HaversineDistance {}
+
+#[typetag::serde]
+impl ScalarUDF for HaversineDistance {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "haversine_distance"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        let field = to_field_coordinates(self, inputs, schema, 4)?;
+        Ok(Field::new(field.name, DataType::Float64))
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        let coordinates = float_coordinates(inputs)?;
+        let [lat1, lon1, lat2, lon2] = coordinates.as_slice() else {
+            return Err(DaftError::ValueError(format!(
+                "Expected 4 input args, got {}",
+                inputs.len()
+            )));
+        };
+        let arrow_result = (0..lat1.len())
+            .map(|i| {
+                Some(haversine(
+                    lat1.get(i)?,
+                    lon1.get(i)?,
+                    lat2.get(i)?,
+                    lon2.get(i)?,
+                ))
+            })
+            .collect::<arrow2::array::Float64Array>();
+        Ok(Float64Array::from((inputs[0].name(), Box::new(arrow_result))).into_series())
+    }
+}
+
+/// The great-circle distance in kilometers between the points (`lat1`, `lon1`) and (`lat2`, `lon2`), in degrees.
+#[must_use]
+pub fn haversine_distance(lat1: ExprRef, lon1: ExprRef, lat2: ExprRef, lon2: ExprRef) -> ExprRef {
+    ScalarFunction::new(HaversineDistance {}, vec![lat1, lon1, lat2, lon2]).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::haversine;
+
+    #[test]
+    fn check_haversine() {
+        // San Francisco to Los Angeles.
+        let distance = haversine(37.7749, -122.4194, 34.0522, -118.2437);
+        assert!((distance - 559.12).abs() < 0.01, "{distance}");
+        assert!(haversine(51.5, -0.12, 51.5, -0.12).abs() < f64::EPSILON);
+        // Antipodal points are half of the circumference apart.
+        let distance = haversine(0.0, 0.0, 0.0, 180.0);
+        assert!((distance - std::f64::consts::PI * 6371.0088).abs() < 1e-6);
+    }
+}
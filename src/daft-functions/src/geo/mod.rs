@@ -0,0 +1,60 @@
+pub mod bbox;
+pub mod geohash;
+pub mod haversine;
+pub mod point;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{DataType, Field, Float64Array, Schema},
+    series::Series,
+};
+use daft_dsl::{functions::ScalarUDF, ExprRef};
+
+/// Checks that all of the inputs to `f` are numeric coordinates, returning the field of the first one.
+fn to_field_coordinates(
+    f: &dyn ScalarUDF,
+    inputs: &[ExprRef],
+    schema: &Schema,
+    num_inputs: usize,
+) -> DaftResult<Field> {
+    if inputs.len() != num_inputs {
+        return Err(DaftError::SchemaMismatch(format!(
+            "Expected {num_inputs} input args for {}, got {}",
+            f.name(),
+            inputs.len()
+        )));
+    }
+    let fields = inputs
+        .iter()
+        .map(|input| input.to_field(schema))
+        .collect::<DaftResult<Vec<_>>>()?;
+    if let Some(field) = fields.iter().find(|field| !field.dtype.is_numeric()) {
+        return Err(DaftError::TypeError(format!(
+            "Expected coordinates of {} to be numeric, got {} for {}",
+            f.name(),
+            field.dtype,
+            field.name
+        )));
+    }
+    Ok(fields.into_iter().next().unwrap())
+}
+
+/// Casts coordinates to Float64, broadcasting single values, such as literals, to the length of the others.
+fn float_coordinates(inputs: &[Series]) -> DaftResult<Vec<Float64Array>> {
+    let len = inputs.iter().map(Series::len).max().unwrap_or(0);
+    inputs
+        .iter()
+        .map(|input| {
+            let input = match input.len() {
+                1 if len != 1 => input.broadcast(len)?,
+                n if n == len => input.clone(),
+                n => {
+                    return Err(DaftError::ValueError(format!(
+                        "Expected coordinates to have the same length, got {n} and {len}"
+                    )))
+                }
+            };
+            Ok(input.cast(&DataType::Float64)?.f64()?.clone())
+        })
+        .collect()
+}
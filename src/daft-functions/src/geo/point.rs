@@ -0,0 +1,22 @@
+use daft_core::prelude::{DataType, Field};
+use daft_dsl::ExprRef;
+
+use crate::to_struct::to_struct;
+
+/// The type of points, which are structs of a `lat` and a `lon` in degrees.
+#[must_use]
+pub fn point_dtype() -> DataType {
+    DataType::Struct(vec![
+        Field::new("lat", DataType::Float64),
+        Field::new("lon", DataType::Float64),
+    ])
+}
+
+/// Builds points out of latitudes and longitudes in degrees.
+#[must_use]
+pub fn point(lat: ExprRef, lon: ExprRef) -> ExprRef {
+    to_struct(vec![
+        lat.cast(&DataType::Float64).alias("lat"),
+        lon.cast(&DataType::Float64).alias("lon"),
+    ])
+}
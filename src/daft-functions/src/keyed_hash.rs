@@ -0,0 +1,144 @@
+use std::fmt::{Debug, Formatter};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+/// A secret key, which is redacted when displayed so that it doesn't show up in plans or logs.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct SecretKey(Vec<u8>);
+
+impl Debug for SecretKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecretKey(<redacted>)")
+    }
+}
+
+impl From<Vec<u8>> for SecretKey {
+    fn from(key: Vec<u8>) -> Self {
+        Self(key)
+    }
+}
+
+/// HMAC-SHA256 of strings with a secret key, as hex strings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct HmacSha256 {
+    pub key: SecretKey,
+}
+
+#[typetag::serde]
+impl ScalarUDF for HmacSha256 {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "hmac_sha256"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let field = input.to_field(schema)?;
+                match &field.dtype {
+                    DataType::Utf8 => Ok(field),
+                    other => Err(DaftError::TypeError(format!(
+                        "Expected input to hmac_sha256 to be utf8, but received {other}"
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => {
+                let mac = Hmac::<Sha256>::new_from_slice(&self.key.0)
+                    .map_err(|e| DaftError::ValueError(format!("Invalid HMAC key: {e}")))?;
+                let hashes = input.utf8()?.as_arrow().iter().map(|value| {
+                    value.map(|value| {
+                        let mut mac = mac.clone();
+                        mac.update(value.as_bytes());
+                        mac.finalize()
+                            .into_bytes()
+                            .iter()
+                            .map(|byte| format!("{byte:02x}"))
+                            .collect::<String>()
+                    })
+                });
+                Ok(Utf8Array::from_iter(input.name(), hashes).into_series())
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+/// Hashes each string of `input` with HMAC-SHA256 keyed by `key`, returning hex strings.
+///
+/// Unlike a salted hash, the hashes can't be computed, and so the values can't be recovered by hashing guesses,
+/// without the key.
+#[must_use]
+pub fn hmac_sha256(input: ExprRef, key: Vec<u8>) -> ExprRef {
+    ScalarFunction::new(HmacSha256 { key: key.into() }, vec![input]).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+    use daft_dsl::{functions::ScalarUDF, resolved_col};
+
+    use super::{hmac_sha256, HmacSha256};
+
+    #[test]
+    fn test_hmac_sha256() {
+        // Test case 2 of RFC 4231.
+        let udf = HmacSha256 {
+            key: b"Jefe".to_vec().into(),
+        };
+        let input = Utf8Array::from_iter(
+            "x",
+            vec![Some("what do ya want for nothing?"), None].into_iter(),
+        )
+        .into_series();
+        let expected = Utf8Array::from_iter(
+            "x",
+            vec![
+                Some("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"),
+                None,
+            ]
+            .into_iter(),
+        )
+        .into_series();
+        assert_eq!(udf.evaluate(&[input]).unwrap(), expected);
+        assert_eq!(
+            format!("{udf:?}"),
+            "HmacSha256 { key: SecretKey(<redacted>) }"
+        );
+    }
+
+    #[test]
+    fn test_hmac_sha256_with_different_keys_not_equal() {
+        let input = resolved_col("x");
+        assert_eq!(
+            hmac_sha256(input.clone(), b"a".to_vec()),
+            hmac_sha256(input.clone(), b"a".to_vec())
+        );
+        assert_ne!(
+            hmac_sha256(input.clone(), b"a".to_vec()),
+            hmac_sha256(input, b"b".to_vec())
+        );
+    }
+}
@@ -1,15 +1,20 @@
+pub mod assert_true;
 pub mod binary;
 pub mod coalesce;
 pub mod count_matches;
 pub mod distance;
+pub mod encoding;
 pub mod float;
+pub mod geo;
 pub mod hash;
 pub mod image;
+pub mod keyed_hash;
 pub mod list;
 pub mod minhash;
 pub mod numeric;
 #[cfg(feature = "python")]
 pub mod python;
+pub mod random;
 pub mod sequence;
 pub mod temporal;
 pub mod to_struct;
@@ -0,0 +1,135 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::{DataType, Field, Schema, Series};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ListRleEncode {}
+
+#[typetag::serde]
+impl ScalarUDF for ListRleEncode {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "list_rle_encode"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        let [data] = inputs else {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            )));
+        };
+
+        let data_field = data.to_field(schema)?;
+
+        let inner_type = match &data_field.dtype {
+            DataType::List(inner_type) | DataType::FixedSizeList(inner_type, _) => inner_type,
+            _ => {
+                return Err(DaftError::TypeError(format!(
+                    "Expected list or fixed size list, got {}",
+                    data_field.dtype
+                )));
+            }
+        };
+
+        let run_type = DataType::Struct(vec![
+            Field::new("value", inner_type.as_ref().clone()),
+            Field::new("run_length", DataType::UInt64),
+        ]);
+
+        Ok(Field::new(
+            data_field.name,
+            DataType::List(Box::new(run_type)),
+        ))
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        let [data] = inputs else {
+            return Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            )));
+        };
+
+        data.list_rle_encode()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ListDeltaEncode {}
+
+#[typetag::serde]
+impl ScalarUDF for ListDeltaEncode {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "list_delta_encode"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        let [data] = inputs else {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            )));
+        };
+
+        let data_field = data.to_field(schema)?;
+
+        let delta_type = match &data_field.dtype {
+            DataType::List(inner_type) | DataType::FixedSizeList(inner_type, _)
+                if inner_type.is_integer() =>
+            {
+                DataType::Int64
+            }
+            DataType::List(inner_type) | DataType::FixedSizeList(inner_type, _)
+                if inner_type.is_floating() =>
+            {
+                inner_type.as_ref().clone()
+            }
+            _ => {
+                return Err(DaftError::TypeError(format!(
+                    "Expected list of numbers, got {}",
+                    data_field.dtype
+                )));
+            }
+        };
+
+        Ok(Field::new(
+            data_field.name,
+            DataType::List(Box::new(delta_type)),
+        ))
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        let [data] = inputs else {
+            return Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            )));
+        };
+
+        data.list_delta_encode()
+    }
+}
+
+/// Encodes each list as structs of the values of its runs of equal elements and their lengths.
+#[must_use]
+pub fn list_rle_encode(expr: ExprRef) -> ExprRef {
+    ScalarFunction::new(ListRleEncode {}, vec![expr]).into()
+}
+
+/// Encodes each list of numbers as its first element followed by the differences between consecutive elements.
+#[must_use]
+pub fn list_delta_encode(expr: ExprRef) -> ExprRef {
+    ScalarFunction::new(ListDeltaEncode {}, vec![expr]).into()
+}
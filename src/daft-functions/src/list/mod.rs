@@ -4,6 +4,7 @@ mod chunk;
 mod count;
 mod count_distinct;
 mod distinct;
+mod encode;
 mod explode;
 mod get;
 mod join;
@@ -22,6 +23,10 @@ pub use chunk::{list_chunk as chunk, ListChunk};
 pub use count::{list_count as count, ListCount};
 pub use count_distinct::{list_count_distinct as count_distinct, ListCountDistinct};
 pub use distinct::{list_distinct as distinct, ListDistinct};
+pub use encode::{
+    list_delta_encode as delta_encode, list_rle_encode as rle_encode, ListDeltaEncode,
+    ListRleEncode,
+};
 pub use explode::{explode, Explode};
 pub use get::{list_get as get, ListGet};
 pub use join::{list_join as join, ListJoin};
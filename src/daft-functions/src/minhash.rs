@@ -59,8 +59,12 @@ impl ScalarUDF for MinHashFunction {
                         field.name,
                         DataType::FixedSizeList(Box::new(DataType::UInt32), self.num_hashes),
                     )),
+                    DataType::List(child) if child.as_ref() == &DataType::Utf8 => Ok(Field::new(
+                        field.name,
+                        DataType::FixedSizeList(Box::new(DataType::UInt32), self.num_hashes),
+                    )),
                     _ => Err(DaftError::TypeError(format!(
-                        "Expects input to minhash to be utf8, but received {field}",
+                        "Expects input to minhash to be utf8 or a list of utf8, but received {field}",
                     ))),
                 },
                 Err(e) => Err(e),
@@ -92,3 +96,241 @@ pub fn minhash(
     )
     .into()
 }
+
+/// Broadcasts a single value, such as a literal, to `len` rows.
+fn broadcast_to(series: &Series, len: usize) -> DaftResult<Series> {
+    match series.len() {
+        n if n == len => Ok(series.clone()),
+        1 => series.broadcast(len),
+        n => Err(DaftError::ValueError(format!(
+            "Expected inputs to have the same length, got {n} and {len}"
+        ))),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct MinHashSimilarity {}
+
+#[typetag::serde]
+impl ScalarUDF for MinHashSimilarity {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "minhash_similarity"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        let [left, right] = inputs else {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            )));
+        };
+        let left = left.to_field(schema)?;
+        let right = right.to_field(schema)?;
+        match (&left.dtype, &right.dtype) {
+            (
+                DataType::FixedSizeList(left_child, left_size),
+                DataType::FixedSizeList(right_child, right_size),
+            ) if left_child.as_ref() == &DataType::UInt32
+                && right_child.as_ref() == &DataType::UInt32
+                && left_size == right_size =>
+            {
+                Ok(Field::new(left.name, DataType::Float64))
+            }
+            _ => Err(DaftError::TypeError(format!(
+                "Expects inputs to minhash_similarity to be minhash signatures of the same size, but received {} and {}",
+                left.dtype, right.dtype
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        let [left, right] = inputs else {
+            return Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            )));
+        };
+        let len = left.len().max(right.len());
+        let left_signatures = broadcast_to(left, len)?;
+        let right_signatures = broadcast_to(right, len)?;
+        let left_signatures = left_signatures.fixed_size_list()?;
+        let right_signatures = right_signatures.fixed_size_list()?;
+        let num_hashes = left_signatures.fixed_element_len();
+        let left_hashes = left_signatures.flat_child.u32()?.as_arrow().values();
+        let right_hashes = right_signatures.flat_child.u32()?.as_arrow().values();
+        let is_valid = |signatures: &FixedSizeListArray, i: usize| {
+            signatures
+                .validity()
+                .is_none_or(|validity| validity.get_bit(i))
+        };
+
+        // The fraction of matching hashes estimates the Jaccard similarity of the sets the signatures came from.
+        let similarities = (0..len).map(|i| {
+            if !is_valid(left_signatures, i) || !is_valid(right_signatures, i) {
+                return None;
+            }
+            let range = i * num_hashes..(i + 1) * num_hashes;
+            let num_matches = left_hashes[range.clone()]
+                .iter()
+                .zip(&right_hashes[range])
+                .filter(|(left, right)| left == right)
+                .count();
+            Some(num_matches as f64 / num_hashes as f64)
+        });
+        Ok(Float64Array::from_regular_iter(
+            Field::new(left.name(), DataType::Float64),
+            similarities,
+        )?
+        .into_series())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct SimHashFunction {
+    pub ngram_size: usize,
+    pub seed: u32,
+    pub hash_function: HashFunctionKind,
+}
+
+#[typetag::serde]
+impl ScalarUDF for SimHashFunction {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "simhash"
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        let [input] = inputs else {
+            return Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            )));
+        };
+
+        match self.hash_function {
+            HashFunctionKind::MurmurHash3 => {
+                let hasher = MurBuildHasher::new(self.seed);
+                input.simhash(self.ngram_size, &hasher)
+            }
+            HashFunctionKind::XxHash => {
+                let hasher = xxhash_rust::xxh64::Xxh64Builder::new(self.seed as u64);
+                input.simhash(self.ngram_size, &hasher)
+            }
+            HashFunctionKind::Sha1 => {
+                let hasher = BuildHasherDefault::<Sha1Hasher>::default();
+                input.simhash(self.ngram_size, &hasher)
+            }
+        }
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let field = data.to_field(schema)?;
+                match &field.dtype {
+                    DataType::Utf8 => Ok(Field::new(field.name, DataType::UInt64)),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expects input to simhash to be utf8, but received {field}",
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct SimHashDistance {}
+
+#[typetag::serde]
+impl ScalarUDF for SimHashDistance {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "simhash_distance"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        let [left, right] = inputs else {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            )));
+        };
+        let left = left.to_field(schema)?;
+        let right = right.to_field(schema)?;
+        if left.dtype.is_integer() && right.dtype.is_integer() {
+            Ok(Field::new(left.name, DataType::UInt32))
+        } else {
+            Err(DaftError::TypeError(format!(
+                "Expects inputs to simhash_distance to be simhashes, but received {} and {}",
+                left.dtype, right.dtype
+            )))
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        let [left, right] = inputs else {
+            return Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            )));
+        };
+        let len = left.len().max(right.len());
+        let left_simhashes = broadcast_to(left, len)?.cast(&DataType::UInt64)?;
+        let right_simhashes = broadcast_to(right, len)?.cast(&DataType::UInt64)?;
+        // The number of differing bits, which is small for near-duplicates.
+        let distances = left_simhashes
+            .u64()?
+            .as_arrow()
+            .iter()
+            .zip(right_simhashes.u64()?.as_arrow().iter())
+            .map(|(left, right)| Some((left? ^ right?).count_ones()));
+        Ok(
+            UInt32Array::from_regular_iter(Field::new(left.name(), DataType::UInt32), distances)?
+                .into_series(),
+        )
+    }
+}
+
+/// Estimates the Jaccard similarity of the sets of n-grams that two MinHash signatures were computed from.
+#[must_use]
+pub fn minhash_similarity(left: ExprRef, right: ExprRef) -> ExprRef {
+    ScalarFunction::new(MinHashSimilarity {}, vec![left, right]).into()
+}
+
+#[must_use]
+pub fn simhash(
+    input: ExprRef,
+    ngram_size: usize,
+    seed: u32,
+    hash_function: HashFunctionKind,
+) -> ExprRef {
+    ScalarFunction::new(
+        SimHashFunction {
+            ngram_size,
+            seed,
+            hash_function,
+        },
+        vec![input],
+    )
+    .into()
+}
+
+/// The number of bits that differ between two SimHashes.
+#[must_use]
+pub fn simhash_distance(left: ExprRef, right: ExprRef) -> ExprRef {
+    ScalarFunction::new(SimHashDistance {}, vec![left, right]).into()
+}
@@ -0,0 +1,228 @@
+use common_error::{DaftError, DaftResult};
+use common_hashable_float_wrapper::FloatWrapper;
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+/// The 1-based bucket of `value` among `num_buckets` equal-width buckets between `low` and `high`,
+/// with 0 for values before the first bucket and `num_buckets + 1` for values after the last one.
+///
+/// Like SQL's `width_bucket`, `low` may be greater than `high`, in which case the buckets count down from `low`.
+fn bucket_of_width(value: f64, low: f64, high: f64, num_buckets: i64) -> DaftResult<Option<i64>> {
+    if value.is_nan() {
+        return Ok(None);
+    }
+    if !low.is_finite() || !high.is_finite() || low == high {
+        return Err(DaftError::ValueError(format!(
+            "Expected width_bucket bounds to be finite and different, got {low} and {high}"
+        )));
+    }
+    let (offset, width) = if low < high {
+        if value < low {
+            return Ok(Some(0));
+        } else if value >= high {
+            return Ok(Some(num_buckets + 1));
+        }
+        (value - low, high - low)
+    } else {
+        if value > low {
+            return Ok(Some(0));
+        } else if value <= high {
+            return Ok(Some(num_buckets + 1));
+        }
+        (low - value, low - high)
+    };
+    // Rounding can push values just inside the last bucket past it.
+    let bucket = (offset / width * num_buckets as f64) as i64 + 1;
+    Ok(Some(bucket.min(num_buckets)))
+}
+
+/// Casts the inputs to Float64, broadcasting single values, such as literal bounds, to the length of the others.
+fn float_inputs(inputs: &[Series]) -> DaftResult<Vec<Float64Array>> {
+    let len = inputs.iter().map(Series::len).max().unwrap_or(0);
+    inputs
+        .iter()
+        .map(|input| {
+            let input = match input.len() {
+                1 if len != 1 => input.broadcast(len)?,
+                n if n == len => input.clone(),
+                n => {
+                    return Err(DaftError::ValueError(format!(
+                        "Expected inputs to have the same length, got {n} and {len}"
+                    )))
+                }
+            };
+            Ok(input.cast(&DataType::Float64)?.f64()?.clone())
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct WidthBucket {
+    pub num_buckets: i64,
+}
+
+#[typetag::serde]
+impl ScalarUDF for WidthBucket {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "width_bucket"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        if inputs.len() != 3 {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Expected 3 input arguments (value, low, high), got {}",
+                inputs.len()
+            )));
+        }
+        let fields = inputs
+            .iter()
+            .map(|input| input.to_field(schema))
+            .collect::<DaftResult<Vec<_>>>()?;
+        if let Some(field) = fields.iter().find(|field| !field.dtype.is_numeric()) {
+            return Err(DaftError::TypeError(format!(
+                "Expected inputs to width_bucket to be numeric, got {} for {}",
+                field.dtype, field.name
+            )));
+        }
+        Ok(Field::new(fields[0].name.clone(), DataType::Int64))
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        let inputs_f64 = float_inputs(inputs)?;
+        let [value, low, high] = inputs_f64.as_slice() else {
+            return Err(DaftError::ValueError(format!(
+                "Expected 3 input arguments (value, low, high), got {}",
+                inputs.len()
+            )));
+        };
+        let arrow_result = (0..value.len())
+            .map(|i| match (value.get(i), low.get(i), high.get(i)) {
+                (Some(value), Some(low), Some(high)) => {
+                    bucket_of_width(value, low, high, self.num_buckets)
+                }
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<arrow2::array::Int64Array>>()?;
+        Ok(Int64Array::from((inputs[0].name(), Box::new(arrow_result))).into_series())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct QuantileBucket {
+    /// The sorted boundaries between buckets, such as the quartiles of the values for 4 buckets.
+    pub boundaries: Vec<FloatWrapper<f64>>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for QuantileBucket {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "quantile_bucket"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let field = input.to_field(schema)?;
+                if field.dtype.is_numeric() {
+                    Ok(Field::new(field.name, DataType::Int64))
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Expected input to quantile_bucket to be numeric, got {}",
+                        field.dtype
+                    )))
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => {
+                let values = input.cast(&DataType::Float64)?;
+                // Values equal to a boundary go in the bucket above it, so there are boundaries.len() + 1 buckets.
+                let arrow_result = values
+                    .f64()?
+                    .as_arrow()
+                    .iter()
+                    .map(|value| {
+                        let value = *value?;
+                        if value.is_nan() {
+                            return None;
+                        }
+                        let bucket = self
+                            .boundaries
+                            .partition_point(|boundary| boundary.0 <= value);
+                        Some(bucket as i64 + 1)
+                    })
+                    .collect::<arrow2::array::Int64Array>();
+                Ok(Int64Array::from((input.name(), Box::new(arrow_result))).into_series())
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+/// Assigns each value to one of `num_buckets` equal-width buckets between `low` and `high`, numbered from 1.
+#[must_use]
+pub fn width_bucket(input: ExprRef, low: ExprRef, high: ExprRef, num_buckets: i64) -> ExprRef {
+    ScalarFunction::new(WidthBucket { num_buckets }, vec![input, low, high]).into()
+}
+
+/// Assigns each value to the bucket between consecutive `boundaries`, numbered from 1.
+///
+/// With the `n - 1` quantiles of the values as boundaries, such as those from an approximate percentile sketch,
+/// this splits them into `n` buckets of roughly equal size.
+#[must_use]
+pub fn quantile_bucket(input: ExprRef, boundaries: &[f64]) -> ExprRef {
+    let mut boundaries = boundaries
+        .iter()
+        .copied()
+        .filter(|boundary| !boundary.is_nan())
+        .collect::<Vec<_>>();
+    boundaries.sort_by(f64::total_cmp);
+    let boundaries = boundaries.into_iter().map(FloatWrapper).collect();
+    ScalarFunction::new(QuantileBucket { boundaries }, vec![input]).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bucket_of_width;
+
+    #[test]
+    fn check_bucket_of_width() {
+        assert_eq!(bucket_of_width(5.35, 0.024, 10.06, 5).unwrap(), Some(3));
+        assert_eq!(bucket_of_width(-1.0, 0.0, 10.0, 5).unwrap(), Some(0));
+        assert_eq!(bucket_of_width(0.0, 0.0, 10.0, 5).unwrap(), Some(1));
+        assert_eq!(bucket_of_width(10.0, 0.0, 10.0, 5).unwrap(), Some(6));
+        assert_eq!(
+            bucket_of_width(f64::INFINITY, 0.0, 10.0, 5).unwrap(),
+            Some(6)
+        );
+        assert_eq!(bucket_of_width(f64::NAN, 0.0, 10.0, 5).unwrap(), None);
+        // Buckets count down when the bounds are reversed.
+        assert_eq!(bucket_of_width(5.35, 10.06, 0.024, 5).unwrap(), Some(3));
+        assert_eq!(bucket_of_width(10.06, 10.06, 0.024, 5).unwrap(), Some(1));
+        assert_eq!(bucket_of_width(0.024, 10.06, 0.024, 5).unwrap(), Some(6));
+        assert!(bucket_of_width(1.0, 2.0, 2.0, 5).is_err());
+        assert!(bucket_of_width(1.0, 0.0, f64::INFINITY, 5).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::DecimalFormat,
+    prelude::{DataType, Field, Schema},
+    series::Series,
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+use super::evaluate_single_numeric;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct FormatDecimal {
+    pub format: DecimalFormat,
+    pub currency_symbol: Option<String>,
+    pub accounting: bool,
+}
+
+#[typetag::serde]
+impl ScalarUDF for FormatDecimal {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "format_decimal"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let field = data.to_field(schema)?;
+                if matches!(field.dtype, DataType::Decimal128(..)) || field.dtype.is_integer() {
+                    Ok(Field::new(field.name, DataType::Utf8))
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Expected input to format_decimal to be a decimal or an integer, got {}",
+                        field.dtype
+                    )))
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        evaluate_single_numeric(inputs, |data| {
+            data.format_decimal(
+                self.format,
+                self.currency_symbol.as_deref(),
+                self.accounting,
+            )
+        })
+    }
+}
+
+#[must_use]
+pub fn format_decimal(
+    input: ExprRef,
+    format: DecimalFormat,
+    currency_symbol: Option<String>,
+    accounting: bool,
+) -> ExprRef {
+    ScalarFunction::new(
+        FormatDecimal {
+            format,
+            currency_symbol,
+            accounting,
+        },
+        vec![input],
+    )
+    .into()
+}
@@ -0,0 +1,55 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{DataType, Field, Schema},
+    series::Series,
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+use super::evaluate_single_numeric;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct IntToIp {}
+
+#[typetag::serde]
+impl ScalarUDF for IntToIp {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "int_to_ip"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let field = data.to_field(schema)?;
+                if field.dtype.is_integer() {
+                    Ok(Field::new(field.name, DataType::Utf8))
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Expected input to int_to_ip to be an integer, got {}",
+                        field.dtype
+                    )))
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        evaluate_single_numeric(inputs, Series::int_to_ip)
+    }
+}
+
+#[must_use]
+pub fn int_to_ip(input: ExprRef) -> ExprRef {
+    ScalarFunction::new(IntToIp {}, vec![input]).into()
+}
@@ -1,9 +1,12 @@
 pub mod abs;
+pub mod bucket;
 pub mod cbrt;
 pub mod ceil;
 pub mod clip;
 pub mod exp;
 pub mod floor;
+pub mod format_decimal;
+pub mod int_to_ip;
 pub mod log;
 pub mod round;
 pub mod sign;
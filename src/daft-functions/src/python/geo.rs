@@ -0,0 +1,19 @@
+use daft_dsl::python::PyExpr;
+use pyo3::{exceptions::PyValueError, pyfunction, PyResult};
+
+use crate::geo::geohash::MAX_GEOHASH_PRECISION;
+
+simple_python_wrapper!(point, crate::geo::point::point, [lat: PyExpr, lon: PyExpr]);
+simple_python_wrapper!(haversine_distance, crate::geo::haversine::haversine_distance, [lat1: PyExpr, lon1: PyExpr, lat2: PyExpr, lon2: PyExpr]);
+simple_python_wrapper!(geohash_decode, crate::geo::geohash::geohash_decode, [expr: PyExpr]);
+simple_python_wrapper!(within_bbox, crate::geo::bbox::within_bbox, [lat: PyExpr, lon: PyExpr, min_lat: PyExpr, min_lon: PyExpr, max_lat: PyExpr, max_lon: PyExpr]);
+
+#[pyfunction]
+pub fn geohash_encode(lat: PyExpr, lon: PyExpr, precision: usize) -> PyResult<PyExpr> {
+    if !(1..=MAX_GEOHASH_PRECISION).contains(&precision) {
+        return Err(PyValueError::new_err(format!(
+            "geohash precision must be between 1 and {MAX_GEOHASH_PRECISION}, got {precision}"
+        )));
+    }
+    Ok(crate::geo::geohash::geohash_encode(lat.into(), lon.into(), precision).into())
+}
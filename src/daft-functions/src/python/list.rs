@@ -16,6 +16,8 @@ simple_python_wrapper!(list_sum, crate::list::sum, [expr: PyExpr]);
 simple_python_wrapper!(list_value_counts, crate::list::value_counts, [expr: PyExpr]);
 simple_python_wrapper!(list_bool_and, crate::list::bool_and, [expr: PyExpr]);
 simple_python_wrapper!(list_bool_or, crate::list::bool_or, [expr: PyExpr]);
+simple_python_wrapper!(list_rle_encode, crate::list::rle_encode, [expr: PyExpr]);
+simple_python_wrapper!(list_delta_encode, crate::list::delta_encode, [expr: PyExpr]);
 
 #[pyfunction]
 pub fn list_distinct(expr: PyExpr) -> PyResult<PyExpr> {
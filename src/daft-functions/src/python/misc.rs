@@ -1,7 +1,10 @@
+use daft_core::python::PySeries;
 use daft_dsl::python::PyExpr;
 use daft_hash::HashFunctionKind;
 use pyo3::{exceptions::PyValueError, pyfunction, PyResult};
 
+use crate::random::RandomDistribution;
+
 simple_python_wrapper!(utf8_count_matches, crate::count_matches::utf8_count_matches, [expr: PyExpr, patterns: PyExpr, whole_words: bool, case_sensitive: bool]);
 
 #[pyfunction]
@@ -11,6 +14,36 @@ pub fn to_struct(inputs: Vec<PyExpr>) -> PyResult<PyExpr> {
     Ok(crate::to_struct::to_struct(inputs).into())
 }
 
+#[pyfunction(signature = (seed=None, row_seed=None))]
+pub fn rand(seed: Option<u64>, row_seed: Option<PyExpr>) -> PyExpr {
+    crate::random::random(RandomDistribution::Uniform, seed, row_seed.map(Into::into)).into()
+}
+
+#[pyfunction(signature = (seed=None, row_seed=None))]
+pub fn randn(seed: Option<u64>, row_seed: Option<PyExpr>) -> PyExpr {
+    crate::random::random(RandomDistribution::Normal, seed, row_seed.map(Into::into)).into()
+}
+
+#[pyfunction(signature = (low, high, seed=None, row_seed=None))]
+pub fn randint(
+    low: i64,
+    high: i64,
+    seed: Option<u64>,
+    row_seed: Option<PyExpr>,
+) -> PyResult<PyExpr> {
+    if low >= high {
+        return Err(PyValueError::new_err(format!(
+            "randint low must be less than high, got {low} and {high}"
+        )));
+    }
+    Ok(crate::random::random(
+        RandomDistribution::Integer { low, high },
+        seed,
+        row_seed.map(Into::into),
+    )
+    .into())
+}
+
 #[pyfunction]
 pub fn minhash(
     expr: PyExpr,
@@ -43,7 +76,55 @@ pub fn minhash(
     Ok(expr.into())
 }
 
+#[pyfunction]
+pub fn minhash_similarity(left: PyExpr, right: PyExpr) -> PyExpr {
+    crate::minhash::minhash_similarity(left.into(), right.into()).into()
+}
+
+#[pyfunction]
+pub fn simhash(expr: PyExpr, ngram_size: i64, seed: i64, hash_function: &str) -> PyResult<PyExpr> {
+    let hash_function: HashFunctionKind = hash_function.parse()?;
+
+    if ngram_size <= 0 {
+        return Err(PyValueError::new_err(format!(
+            "ngram_size must be positive: {ngram_size}"
+        )));
+    }
+
+    let expr =
+        crate::minhash::simhash(expr.into(), ngram_size as usize, seed as u32, hash_function);
+    Ok(expr.into())
+}
+
+#[pyfunction]
+pub fn simhash_distance(left: PyExpr, right: PyExpr) -> PyExpr {
+    crate::minhash::simhash_distance(left.into(), right.into()).into()
+}
+
 #[pyfunction(signature = (expr, seed=None))]
 pub fn hash(expr: PyExpr, seed: Option<PyExpr>) -> PyResult<PyExpr> {
     Ok(crate::hash::hash(expr.into(), seed.map(Into::into)).into())
 }
+
+#[pyfunction]
+pub fn hmac_sha256(expr: PyExpr, key: Vec<u8>) -> PyExpr {
+    crate::keyed_hash::hmac_sha256(expr.into(), key).into()
+}
+
+#[pyfunction]
+pub fn one_hot(expr: PyExpr, categories: PyExpr) -> PyExpr {
+    crate::encoding::one_hot(expr.into(), categories.into()).into()
+}
+
+#[pyfunction(signature = (expr, categories, labels=None))]
+pub fn label_encode(expr: PyExpr, categories: PyExpr, labels: Option<PyExpr>) -> PyExpr {
+    crate::encoding::label_encode(expr.into(), categories.into(), labels.map(Into::into)).into()
+}
+
+#[pyfunction]
+pub fn recorded_violations(violations_id: &str) -> Vec<Vec<PySeries>> {
+    crate::assert_true::recorded_violations(violations_id)
+        .into_iter()
+        .map(|columns| columns.into_iter().map(Into::into).collect())
+        .collect()
+}
@@ -16,6 +16,7 @@ mod binary;
 mod coalesce;
 mod distance;
 mod float;
+mod geo;
 mod image;
 mod list;
 mod misc;
@@ -52,6 +53,12 @@ pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
     add!(float::not_nan);
     add!(float::fill_nan);
 
+    add!(geo::point);
+    add!(geo::haversine_distance);
+    add!(geo::geohash_encode);
+    add!(geo::geohash_decode);
+    add!(geo::within_bbox);
+
     add!(image::image_crop);
     add!(image::image_to_mode);
     add!(image::image_decode);
@@ -74,11 +81,23 @@ pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
     add!(list::list_distinct);
     add!(list::list_bool_and);
     add!(list::list_bool_or);
+    add!(list::list_rle_encode);
+    add!(list::list_delta_encode);
 
     add!(misc::to_struct);
     add!(misc::utf8_count_matches);
     add!(misc::hash);
+    add!(misc::hmac_sha256);
     add!(misc::minhash);
+    add!(misc::minhash_similarity);
+    add!(misc::simhash);
+    add!(misc::simhash_distance);
+    add!(misc::rand);
+    add!(misc::randn);
+    add!(misc::randint);
+    add!(misc::one_hot);
+    add!(misc::label_encode);
+    add!(misc::recorded_violations);
 
     add!(numeric::abs);
     add!(numeric::cbrt);
@@ -87,6 +106,10 @@ pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
     add!(numeric::exp);
     add!(numeric::expm1);
     add!(numeric::floor);
+    add!(numeric::format_decimal);
+    add!(numeric::int_to_ip);
+    add!(numeric::quantile_bucket);
+    add!(numeric::width_bucket);
     add!(numeric::sign);
     add!(numeric::sqrt);
     add!(numeric::log2);
@@ -133,13 +156,20 @@ pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
     add!(uri::url_download);
     add!(uri::url_upload);
 
+    add!(utf8::utf8_ascii_fold);
     add!(utf8::utf8_capitalize);
     add!(utf8::utf8_contains);
     add!(utf8::utf8_endswith);
     add!(utf8::utf8_extract);
     add!(utf8::utf8_extract_all);
+    add!(utf8::utf8_extract_domain);
     add!(utf8::utf8_find);
     add!(utf8::utf8_ilike);
+    add!(utf8::utf8_ip_to_int);
+    add!(utf8::utf8_is_email);
+    add!(utf8::utf8_is_ipv4);
+    add!(utf8::utf8_is_ipv6);
+    add!(utf8::utf8_is_phone_number);
     add!(utf8::utf8_left);
     add!(utf8::utf8_length);
     add!(utf8::utf8_length_bytes);
@@ -148,6 +178,7 @@ pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
     add!(utf8::utf8_lpad);
     add!(utf8::utf8_lstrip);
     add!(utf8::utf8_match);
+    add!(utf8::utf8_remove_accents);
     add!(utf8::utf8_repeat);
     add!(utf8::utf8_replace);
     add!(utf8::utf8_reverse);
@@ -159,6 +190,7 @@ pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
     add!(utf8::utf8_substr);
     add!(utf8::utf8_upper);
     add!(utf8::utf8_normalize);
+    add!(utf8::utf8_parse_decimal);
     add!(utf8::utf8_to_date);
     add!(utf8::utf8_to_datetime);
 
@@ -1,3 +1,4 @@
+use daft_core::array::ops::DecimalFormat;
 use daft_dsl::python::PyExpr;
 use pyo3::{exceptions::PyValueError, pyfunction, PyResult};
 
@@ -8,6 +9,7 @@ simple_python_wrapper!(clip, crate::numeric::clip::clip, [expr: PyExpr, min: PyE
 simple_python_wrapper!(exp, crate::numeric::exp::exp, [expr: PyExpr]);
 simple_python_wrapper!(expm1, crate::numeric::exp::expm1, [expr: PyExpr]);
 simple_python_wrapper!(floor, crate::numeric::floor::floor, [expr: PyExpr]);
+simple_python_wrapper!(int_to_ip, crate::numeric::int_to_ip::int_to_ip, [expr: PyExpr]);
 simple_python_wrapper!(sign, crate::numeric::sign::sign, [expr: PyExpr]);
 simple_python_wrapper!(sqrt, crate::numeric::sqrt::sqrt, [expr: PyExpr]);
 simple_python_wrapper!(log2, crate::numeric::log::log2, [expr: PyExpr]);
@@ -43,3 +45,39 @@ pub fn round(expr: PyExpr, decimal: i32) -> PyResult<PyExpr> {
     }
     Ok(crate::numeric::round::round(expr.into(), Some(decimal)).into())
 }
+
+#[pyfunction(signature = (expr, decimal_separator='.', thousands_separator=Some(','), currency_symbol=None, accounting=false))]
+pub fn format_decimal(
+    expr: PyExpr,
+    decimal_separator: char,
+    thousands_separator: Option<char>,
+    currency_symbol: Option<String>,
+    accounting: bool,
+) -> PyResult<PyExpr> {
+    let format = DecimalFormat::try_new(decimal_separator, thousands_separator)?;
+    Ok(crate::numeric::format_decimal::format_decimal(
+        expr.into(),
+        format,
+        currency_symbol,
+        accounting,
+    )
+    .into())
+}
+
+#[pyfunction]
+pub fn width_bucket(expr: PyExpr, low: PyExpr, high: PyExpr, num_buckets: i64) -> PyResult<PyExpr> {
+    if num_buckets <= 0 {
+        return Err(PyValueError::new_err(format!(
+            "num_buckets must be positive: {num_buckets}"
+        )));
+    }
+    Ok(
+        crate::numeric::bucket::width_bucket(expr.into(), low.into(), high.into(), num_buckets)
+            .into(),
+    )
+}
+
+#[pyfunction]
+pub fn quantile_bucket(expr: PyExpr, boundaries: Vec<f64>) -> PyExpr {
+    crate::numeric::bucket::quantile_bucket(expr.into(), &boundaries).into()
+}
@@ -1,14 +1,21 @@
-use daft_core::array::ops::Utf8NormalizeOptions;
+use daft_core::array::ops::{DecimalFormat, UnicodeForm, Utf8NormalizeOptions};
 use daft_dsl::python::PyExpr;
 use pyo3::{pyfunction, PyResult};
 
+simple_python_wrapper!(utf8_ascii_fold, crate::utf8::ascii_fold, [input: PyExpr]);
 simple_python_wrapper!(utf8_capitalize, crate::utf8::capitalize, [input: PyExpr]);
 simple_python_wrapper!(utf8_contains, crate::utf8::contains, [input: PyExpr, pattern: PyExpr]);
 simple_python_wrapper!(utf8_endswith, crate::utf8::endswith, [input: PyExpr, pattern: PyExpr]);
 simple_python_wrapper!(utf8_extract, crate::utf8::extract, [input: PyExpr, pattern: PyExpr, index: usize]);
 simple_python_wrapper!(utf8_extract_all, crate::utf8::extract_all, [input: PyExpr, pattern: PyExpr, index: usize]);
+simple_python_wrapper!(utf8_extract_domain, crate::utf8::extract_domain, [input: PyExpr]);
 simple_python_wrapper!(utf8_find, crate::utf8::find, [input: PyExpr, substr: PyExpr]);
 simple_python_wrapper!(utf8_ilike, crate::utf8::ilike, [input: PyExpr, pattern: PyExpr]);
+simple_python_wrapper!(utf8_ip_to_int, crate::utf8::ip_to_int, [input: PyExpr]);
+simple_python_wrapper!(utf8_is_email, crate::utf8::is_email, [input: PyExpr]);
+simple_python_wrapper!(utf8_is_ipv4, crate::utf8::is_ipv4, [input: PyExpr]);
+simple_python_wrapper!(utf8_is_ipv6, crate::utf8::is_ipv6, [input: PyExpr]);
+simple_python_wrapper!(utf8_is_phone_number, crate::utf8::is_phone_number, [input: PyExpr]);
 simple_python_wrapper!(utf8_left, crate::utf8::left, [input: PyExpr, nchars: PyExpr]);
 simple_python_wrapper!(utf8_length, crate::utf8::length, [input: PyExpr]);
 simple_python_wrapper!(utf8_length_bytes, crate::utf8::length_bytes, [input: PyExpr]);
@@ -17,6 +24,7 @@ simple_python_wrapper!(utf8_lower, crate::utf8::lower, [input: PyExpr]);
 simple_python_wrapper!(utf8_lpad, crate::utf8::lpad, [input: PyExpr, length: PyExpr, pad: PyExpr]);
 simple_python_wrapper!(utf8_lstrip, crate::utf8::lstrip, [input: PyExpr]);
 simple_python_wrapper!(utf8_match, crate::utf8::match_, [input: PyExpr, pattern: PyExpr]);
+simple_python_wrapper!(utf8_remove_accents, crate::utf8::remove_accents, [input: PyExpr]);
 simple_python_wrapper!(utf8_repeat, crate::utf8::repeat, [input: PyExpr, ntimes: PyExpr]);
 simple_python_wrapper!(utf8_replace, crate::utf8::replace, [input: PyExpr, pattern: PyExpr, replacement: PyExpr, regex: bool]);
 simple_python_wrapper!(utf8_reverse, crate::utf8::reverse, [input: PyExpr]);
@@ -28,13 +36,14 @@ simple_python_wrapper!(utf8_startswith, crate::utf8::startswith, [input: PyExpr,
 simple_python_wrapper!(utf8_substr, crate::utf8::substr, [input: PyExpr, start: PyExpr, length: PyExpr]);
 simple_python_wrapper!(utf8_upper, crate::utf8::upper, [input: PyExpr]);
 
-#[pyfunction]
+#[pyfunction(signature = (expr, remove_punct, lowercase, nfd_unicode, white_space, form=None))]
 pub fn utf8_normalize(
     expr: PyExpr,
     remove_punct: bool,
     lowercase: bool,
     nfd_unicode: bool,
     white_space: bool,
+    form: Option<&str>,
 ) -> PyResult<PyExpr> {
     Ok(crate::utf8::normalize(
         expr.into(),
@@ -43,11 +52,24 @@ pub fn utf8_normalize(
             lowercase,
             nfd_unicode,
             white_space,
+            form: form.map(str::parse::<UnicodeForm>).transpose()?,
         },
     )
     .into())
 }
 
+#[pyfunction(signature = (expr, precision, scale, decimal_separator='.', thousands_separator=Some(',')))]
+pub fn utf8_parse_decimal(
+    expr: PyExpr,
+    precision: usize,
+    scale: usize,
+    decimal_separator: char,
+    thousands_separator: Option<char>,
+) -> PyResult<PyExpr> {
+    let format = DecimalFormat::try_new(decimal_separator, thousands_separator)?;
+    Ok(crate::utf8::parse_decimal(expr.into(), precision, scale, format).into())
+}
+
 #[pyfunction]
 pub fn utf8_to_date(expr: PyExpr, format: &str) -> PyResult<PyExpr> {
     Ok(crate::utf8::to_date(expr.into(), format).into())
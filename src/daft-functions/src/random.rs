@@ -0,0 +1,206 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+/// The distribution that random values are drawn from.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum RandomDistribution {
+    /// Floats in [0, 1).
+    Uniform,
+    /// Floats from the standard normal distribution.
+    Normal,
+    /// Integers in [low, high).
+    Integer { low: i64, high: i64 },
+}
+
+impl RandomDistribution {
+    fn dtype(self) -> DataType {
+        match self {
+            Self::Uniform | Self::Normal => DataType::Float64,
+            Self::Integer { .. } => DataType::Int64,
+        }
+    }
+
+    /// Draws a value for each row from its generator, or null for rows without one.
+    fn sample(self, name: &str, generators: impl Iterator<Item = Option<SplitMix64>>) -> Series {
+        let field = Field::new(name, self.dtype());
+        match self {
+            Self::Uniform => Float64Array::from_iter(
+                field,
+                generators.map(|generator| generator.map(|mut generator| generator.next_f64())),
+            )
+            .into_series(),
+            Self::Normal => Float64Array::from_iter(
+                field,
+                generators.map(|generator| generator.map(|mut generator| generator.next_normal())),
+            )
+            .into_series(),
+            Self::Integer { low, high } => Int64Array::from_iter(
+                field,
+                generators.map(|generator| {
+                    generator.map(|mut generator| generator.next_in_range(low, high))
+                }),
+            )
+            .into_series(),
+        }
+    }
+}
+
+/// A small, fast generator that can be cheaply seeded for every row.
+#[derive(Clone, Copy)]
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A float in [0, 1) with 53 random bits.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// A float from the standard normal distribution, using the Box-Muller transform.
+    fn next_normal(&mut self) -> f64 {
+        // Flip the first float into (0, 1] so that its logarithm is finite.
+        let u1 = 1.0 - self.next_f64();
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+    }
+
+    /// An integer in [low, high), which must not be empty.
+    fn next_in_range(&mut self, low: i64, high: i64) -> i64 {
+        let span = (i128::from(high) - i128::from(low)) as u128;
+        let offset = (u128::from(self.next_u64()) * span) >> 64;
+        (i128::from(low) + offset as i128) as i64
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct RandomFunction {
+    pub distribution: RandomDistribution,
+    pub seed: Option<u64>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for RandomFunction {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        match self.distribution {
+            RandomDistribution::Uniform => "rand",
+            RandomDistribution::Normal => "randn",
+            RandomDistribution::Integer { .. } => "randint",
+        }
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [] => Ok(Field::new(self.name(), self.distribution.dtype())),
+            [row_seed] => {
+                let field = row_seed.to_field(schema)?;
+                Ok(Field::new(field.name, self.distribution.dtype()))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected at most 1 input arg for {}, got {}",
+                self.name(),
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [row_seed] => self.evaluate_with_num_rows(inputs, row_seed.len()),
+            _ => Err(DaftError::ValueError(format!(
+                "{} without a per-row seed can only be evaluated on a batch of rows",
+                self.name()
+            ))),
+        }
+    }
+
+    fn evaluate_with_num_rows(&self, inputs: &[Series], num_rows: usize) -> DaftResult<Series> {
+        match inputs {
+            [] => {
+                let mut rng = SplitMix64(self.seed.unwrap_or_else(rand::random));
+                let generators = (0..num_rows).map(|_| Some(SplitMix64(rng.next_u64())));
+                Ok(self.distribution.sample(self.name(), generators))
+            }
+            [row_seed] => {
+                // Each row's value only depends on the hash of its seed, so it doesn't change with how the rows are
+                // split into partitions or batches.
+                let seed = SplitMix64(self.seed.unwrap_or_default()).next_u64();
+                let hashes = row_seed.hash(None)?;
+                let generators = hashes
+                    .as_arrow()
+                    .values_iter()
+                    .enumerate()
+                    .map(|(i, hash)| row_seed.is_valid(i).then_some(SplitMix64(hash ^ seed)));
+                Ok(self.distribution.sample(row_seed.name(), generators))
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected at most 1 input arg for {}, got {}",
+                self.name(),
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+}
+
+/// Random values from `distribution`, one per row. With a `row_seed`, each row's value is derived from the hash of
+/// its seed and is reproducible, otherwise values are drawn from a generator seeded by `seed`, or randomly if None.
+#[must_use]
+pub fn random(
+    distribution: RandomDistribution,
+    seed: Option<u64>,
+    row_seed: Option<ExprRef>,
+) -> ExprRef {
+    ScalarFunction::new(
+        RandomFunction { distribution, seed },
+        row_seed.into_iter().collect(),
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SplitMix64;
+
+    #[test]
+    fn check_random_values_in_range() {
+        let mut generator = SplitMix64(42);
+        for _ in 0..1000 {
+            let value = generator.next_f64();
+            assert!((0.0..1.0).contains(&value));
+            assert!(generator.next_normal().is_finite());
+            assert!((-3..4).contains(&generator.next_in_range(-3, 4)));
+            assert_eq!(generator.next_in_range(7, 8), 7);
+        }
+        let value = generator.next_in_range(i64::MIN, i64::MAX);
+        assert!(value < i64::MAX);
+    }
+
+    #[test]
+    fn check_random_values_are_reproducible() {
+        let values = |seed| {
+            let mut generator = SplitMix64(seed);
+            (0..10).map(|_| generator.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(values(1), values(1));
+        assert_ne!(values(1), values(2));
+    }
+}
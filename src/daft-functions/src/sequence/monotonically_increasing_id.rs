@@ -34,6 +34,11 @@ impl ScalarUDF for MonotonicallyIncreasingId {
             "monotonically_increasing_id should be rewritten into a separate plan step by the optimizer. If you're seeing this error, the DetectMonotonicId optimization rule may not have been applied.".to_string(),
         ))
     }
+
+    fn is_deterministic(&self) -> bool {
+        // Ids depend on the positions of rows, which change if filters are moved past them.
+        false
+    }
 }
 
 #[must_use]
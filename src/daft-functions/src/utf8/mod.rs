@@ -1,10 +1,18 @@
+mod ascii_fold;
 mod capitalize;
+mod collation_key;
 mod contains;
 mod endswith;
 mod extract;
 mod extract_all;
+mod extract_domain;
 mod find;
 mod ilike;
+mod ip_to_int;
+mod is_email;
+mod is_ipv4;
+mod is_ipv6;
+mod is_phone_number;
 mod left;
 mod length;
 mod length_bytes;
@@ -14,6 +22,8 @@ mod lpad;
 mod lstrip;
 mod match_;
 mod normalize;
+mod parse_decimal;
+mod remove_accents;
 mod repeat;
 mod replace;
 mod reverse;
@@ -27,13 +37,21 @@ mod to_date;
 mod to_datetime;
 mod upper;
 
+pub use ascii_fold::{utf8_ascii_fold as ascii_fold, Utf8AsciiFold};
 pub use capitalize::{utf8_capitalize as capitalize, Utf8Capitalize};
+pub use collation_key::{utf8_collation_key as collation_key, Utf8CollationKey};
 pub use contains::{utf8_contains as contains, Utf8Contains};
 pub use endswith::{utf8_endswith as endswith, Utf8Endswith};
 pub use extract::{utf8_extract as extract, Utf8Extract};
 pub use extract_all::{utf8_extract_all as extract_all, Utf8ExtractAll};
+pub use extract_domain::{utf8_extract_domain as extract_domain, Utf8ExtractDomain};
 pub use find::{utf8_find as find, Utf8Find};
 pub use ilike::{utf8_ilike as ilike, Utf8Ilike};
+pub use ip_to_int::{utf8_ip_to_int as ip_to_int, Utf8IpToInt};
+pub use is_email::{utf8_is_email as is_email, Utf8IsEmail};
+pub use is_ipv4::{utf8_is_ipv4 as is_ipv4, Utf8IsIpv4};
+pub use is_ipv6::{utf8_is_ipv6 as is_ipv6, Utf8IsIpv6};
+pub use is_phone_number::{utf8_is_phone_number as is_phone_number, Utf8IsPhoneNumber};
 pub use left::{utf8_left as left, Utf8Left};
 pub use length::{utf8_length as length, Utf8Length};
 pub use length_bytes::{utf8_length_bytes as length_bytes, Utf8LengthBytes};
@@ -43,6 +61,8 @@ pub use lpad::{utf8_lpad as lpad, Utf8Lpad};
 pub use lstrip::{utf8_lstrip as lstrip, Utf8Lstrip};
 pub use match_::{utf8_match as match_, Utf8Match};
 pub use normalize::{utf8_normalize as normalize, Utf8Normalize};
+pub use parse_decimal::{utf8_parse_decimal as parse_decimal, Utf8ParseDecimal};
+pub use remove_accents::{utf8_remove_accents as remove_accents, Utf8RemoveAccents};
 pub use repeat::{utf8_repeat as repeat, Utf8Repeat};
 pub use replace::{utf8_replace as replace, Utf8Replace};
 pub use reverse::{utf8_reverse as reverse, Utf8Reverse};
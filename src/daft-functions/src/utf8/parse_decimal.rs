@@ -0,0 +1,80 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::DecimalFormat,
+    prelude::{DataType, Field, Schema},
+    series::Series,
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Utf8ParseDecimal {
+    pub precision: usize,
+    pub scale: usize,
+    pub format: DecimalFormat,
+}
+
+#[typetag::serde]
+impl ScalarUDF for Utf8ParseDecimal {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "parse_decimal"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let field = data.to_field(schema)?;
+                if field.dtype == DataType::Utf8 {
+                    Ok(Field::new(
+                        field.name,
+                        DataType::Decimal128(self.precision, self.scale),
+                    ))
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Expected input to parse_decimal to be utf8, got {}",
+                        field.dtype
+                    )))
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data] => data.utf8_parse_decimal(self.precision, self.scale, self.format),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn utf8_parse_decimal(
+    input: ExprRef,
+    precision: usize,
+    scale: usize,
+    format: DecimalFormat,
+) -> ExprRef {
+    ScalarFunction::new(
+        Utf8ParseDecimal {
+            precision,
+            scale,
+            format,
+        },
+        vec![input],
+    )
+    .into()
+}
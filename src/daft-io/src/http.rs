@@ -10,13 +10,13 @@ use common_io_config::HTTPConfig;
 use futures::{stream::BoxStream, TryStreamExt};
 use hyper::header;
 use regex::Regex;
-use reqwest::header::{CONTENT_LENGTH, RANGE};
+use reqwest::header::{CONTENT_LENGTH, ETAG, RANGE};
 use snafu::{IntoError, ResultExt, Snafu};
 use url::Position;
 
 use super::object_io::{GetResult, ObjectSource};
 use crate::{
-    object_io::{FileMetadata, FileType, LSResult},
+    object_io::{FileMetadata, FileType, LSResult, ObjectHead},
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
     FileFormat,
@@ -242,6 +242,10 @@ impl ObjectSource for HttpSource {
     }
 
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        Ok(self.head(uri, io_stats).await?.size)
+    }
+
+    async fn head(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<ObjectHead> {
         let request = self.client.head(uri);
         let response = request
             .send()
@@ -256,18 +260,23 @@ impl ObjectSource for HttpSource {
         }
 
         let headers = response.headers();
-        match headers.get(CONTENT_LENGTH) {
+        let size = match headers.get(CONTENT_LENGTH) {
             Some(v) => {
                 let size_bytes = String::from_utf8(v.as_bytes().to_vec()).with_context(|_| {
                     UnableToParseUtf8HeaderSnafu::<String> { path: uri.into() }
                 })?;
 
-                Ok(size_bytes
+                size_bytes
                     .parse()
-                    .with_context(|_| UnableToParseIntegerSnafu::<String> { path: uri.into() })?)
+                    .with_context(|_| UnableToParseIntegerSnafu::<String> { path: uri.into() })?
             }
-            None => Err(Error::UnableToDetermineSize { path: uri.into() }.into()),
-        }
+            None => return Err(Error::UnableToDetermineSize { path: uri.into() }.into()),
+        };
+        let etag = headers
+            .get(ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        Ok(ObjectHead { size, etag })
     }
 
     async fn glob(
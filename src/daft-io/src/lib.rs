@@ -26,8 +26,9 @@ use std::{borrow::Cow, collections::HashMap, hash::Hash, ops::Range, sync::Arc};
 use common_error::{DaftError, DaftResult};
 pub use common_io_config::{AzureConfig, IOConfig, S3Config};
 use futures::stream::BoxStream;
+pub use local::{is_local_stream, LocalStreamRelease};
 use object_io::StreamingRetryParams;
-pub use object_io::{FileMetadata, GetResult};
+pub use object_io::{FileMetadata, GetResult, ObjectHead};
 #[cfg(feature = "python")]
 pub use python::register_modules;
 use s3_like::S3LikeSource;
@@ -292,6 +293,16 @@ impl IOClient {
         source.get_size(path.as_ref(), io_stats).await
     }
 
+    pub async fn single_url_head(
+        &self,
+        input: String,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<ObjectHead> {
+        let (_, path) = parse_url(&input)?;
+        let source = self.get_source(&input).await?;
+        source.head(path.as_ref(), io_stats).await
+    }
+
     pub async fn single_url_download(
         &self,
         index: usize,
@@ -383,8 +394,15 @@ impl std::fmt::Display for SourceType {
     }
 }
 
+/// The path that stands for standard input when reading.
+pub const STDIN_PATH: &str = "-";
+
 pub fn parse_url(input: &str) -> Result<(SourceType, Cow<'_, str>)> {
     let mut fixed_input = Cow::Borrowed(input);
+    // `-` stands for standard input, as is customary for Unix tools.
+    if input == STDIN_PATH {
+        return Ok((SourceType::File, Cow::Borrowed("file:///dev/stdin")));
+    }
     // handle tilde `~` expansion
     if input.starts_with("~/") {
         return home::home_dir()
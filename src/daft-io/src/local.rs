@@ -1,8 +1,9 @@
 use std::{
+    collections::HashMap,
     io::{SeekFrom, Write},
     ops::Range,
     path::PathBuf,
-    sync::Arc,
+    sync::{Arc, LazyLock, Mutex},
 };
 
 use async_trait::async_trait;
@@ -17,7 +18,7 @@ use super::{
     Result,
 };
 use crate::{
-    object_io::{self, FileMetadata, LSResult},
+    object_io::{self, FileMetadata, LSResult, ObjectHead},
     stats::IOStatsRef,
     FileFormat,
 };
@@ -78,6 +79,13 @@ enum Error {
 
     #[snafu(display("Unable to convert URL \"{}\" to local file path", path))]
     InvalidFilePath { path: String },
+
+    #[snafu(display(
+        "Unable to read {} again, since it is a pipe and more than {} bytes of it were already read",
+        path,
+        MAX_PIPE_REPLAY_BYTES
+    ))]
+    PipeAlreadyRead { path: String },
 }
 
 impl From<Error> for super::Error {
@@ -136,6 +144,150 @@ pub struct LocalFile {
     pub range: Option<Range<usize>>,
 }
 
+/// Whether the local file at `uri` is a pipe or another stream, such as standard input, rather than a regular file.
+///
+/// Streams can only be read once from start to end, so they need to be read without seeking or knowing their size.
+#[must_use]
+pub fn is_local_stream(uri: &str) -> bool {
+    let path = uri.strip_prefix("file://").unwrap_or(uri);
+    std::fs::metadata(path).is_ok_and(|meta| is_stream_file_type(meta.file_type()))
+}
+
+#[cfg(unix)]
+fn is_stream_file_type(file_type: std::fs::FileType) -> bool {
+    use std::os::unix::fs::FileTypeExt;
+    file_type.is_fifo() || file_type.is_char_device()
+}
+
+#[cfg(not(unix))]
+fn is_stream_file_type(_file_type: std::fs::FileType) -> bool {
+    false
+}
+
+/// The number of bytes that are read from a pipe at a time.
+const PIPE_READ_CHUNK_SIZE: usize = 64 * 1024;
+
+/// The most bytes of a pipe that are kept to be read again, which is enough for schema inference to read the start
+/// of the pipe before the whole pipe is read.
+const MAX_PIPE_REPLAY_BYTES: usize = 64 * 1024 * 1024;
+
+struct Pipe {
+    file: tokio::fs::File,
+    /// The number of bytes read from the pipe so far.
+    num_bytes_read: usize,
+    /// All the chunks read from the pipe so far, until they grow past [`MAX_PIPE_REPLAY_BYTES`].
+    replay: Option<Vec<Bytes>>,
+}
+
+/// The pipes that are being read, which stay open so that their writers aren't cut off between reads, until the scans
+/// that read them release them.
+static OPEN_PIPES: LazyLock<Mutex<HashMap<PathBuf, Arc<tokio::sync::Mutex<Pipe>>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Releases the pipe at a local path when dropped, so that the pipe is closed and the next read of the path waits for
+/// a new writer rather than replaying what was read.
+///
+/// Scans hold one for as long as they read a pipe, so that the pipe is released however the scan finishes. Schema
+/// inference doesn't, since the scan that follows it reads the same pipe.
+pub struct LocalStreamRelease {
+    path: PathBuf,
+}
+
+impl LocalStreamRelease {
+    /// Returns a release for `uri` if it's a pipe or another stream, and `None` otherwise.
+    #[must_use]
+    pub fn new(uri: &str) -> Option<Self> {
+        let path = uri.strip_prefix("file://").unwrap_or(uri);
+        is_local_stream(path).then(|| Self { path: path.into() })
+    }
+}
+
+impl Drop for LocalStreamRelease {
+    fn drop(&mut self) {
+        OPEN_PIPES.lock().unwrap().remove(&self.path);
+    }
+}
+
+/// Streams the contents of the pipe at `path` from its start.
+///
+/// The chunks of the pipe that were already read by a previous stream, such as the one that inferred the schema, are
+/// replayed before the rest of the pipe is read.
+async fn stream_pipe(path: PathBuf) -> Result<BoxStream<'static, super::Result<Bytes>>> {
+    let open_pipe = OPEN_PIPES.lock().unwrap().get(&path).cloned();
+    let pipe = match open_pipe {
+        Some(pipe) => pipe,
+        None => {
+            // Opening a pipe waits for its writer, so the registry can't be locked meanwhile.
+            let file = tokio::fs::File::open(&path)
+                .await
+                .context(UnableToOpenFileSnafu {
+                    path: path.to_string_lossy(),
+                })?;
+            let pipe = Arc::new(tokio::sync::Mutex::new(Pipe {
+                file,
+                num_bytes_read: 0,
+                replay: Some(vec![]),
+            }));
+            OPEN_PIPES
+                .lock()
+                .unwrap()
+                .entry(path.clone())
+                .or_insert(pipe)
+                .clone()
+        }
+    };
+    // Each stream tracks the next chunk to replay and the number of bytes that it has streamed.
+    let stream = futures::stream::try_unfold(
+        (pipe, 0usize, 0usize),
+        move |(pipe, next_chunk, num_bytes_streamed)| {
+            let path = path.clone();
+            async move {
+                let mut pipe_guard = pipe.lock().await;
+                if num_bytes_streamed < pipe_guard.num_bytes_read {
+                    let Some(chunk) = pipe_guard
+                        .replay
+                        .as_ref()
+                        .and_then(|replay| replay.get(next_chunk).cloned())
+                    else {
+                        return Err(Error::PipeAlreadyRead {
+                            path: path.to_string_lossy().to_string(),
+                        }
+                        .into());
+                    };
+                    drop(pipe_guard);
+                    let num_bytes_streamed = num_bytes_streamed + chunk.len();
+                    return Ok(Some((chunk, (pipe, next_chunk + 1, num_bytes_streamed))));
+                }
+                // Read whatever the writer has written so far rather than waiting for a full chunk.
+                let mut buf = vec![0; PIPE_READ_CHUNK_SIZE];
+                let num_bytes =
+                    pipe_guard
+                        .file
+                        .read(&mut buf)
+                        .await
+                        .context(UnableToReadBytesSnafu {
+                            path: path.to_string_lossy(),
+                        })?;
+                if num_bytes == 0 {
+                    return Ok(None);
+                }
+                buf.truncate(num_bytes);
+                let chunk = Bytes::from(buf);
+                pipe_guard.num_bytes_read += chunk.len();
+                let num_bytes_read = pipe_guard.num_bytes_read;
+                if num_bytes_read > MAX_PIPE_REPLAY_BYTES {
+                    pipe_guard.replay = None;
+                } else if let Some(replay) = pipe_guard.replay.as_mut() {
+                    replay.push(chunk.clone());
+                }
+                drop(pipe_guard);
+                Ok::<_, super::Error>(Some((chunk, (pipe, next_chunk + 1, num_bytes_read))))
+            }
+        },
+    );
+    Ok(stream.boxed())
+}
+
 #[async_trait]
 impl ObjectSource for LocalSource {
     async fn get(
@@ -146,6 +298,15 @@ impl ObjectSource for LocalSource {
     ) -> super::Result<GetResult> {
         const LOCAL_PROTOCOL: &str = "file://";
         if let Some(uri) = uri.strip_prefix(LOCAL_PROTOCOL) {
+            if is_local_stream(uri) {
+                if range.is_some() {
+                    return Err(super::Error::InvalidArgument {
+                        msg: format!("Unable to read a range of {uri}, since it is a pipe"),
+                    });
+                }
+                let stream = stream_pipe(uri.into()).await?;
+                return Ok(GetResult::Stream(stream, None, None, None));
+            }
             Ok(GetResult::File(LocalFile {
                 path: uri.into(),
                 range,
@@ -177,7 +338,11 @@ impl ObjectSource for LocalSource {
         }
     }
 
-    async fn get_size(&self, uri: &str, _io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+    async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        Ok(self.head(uri, io_stats).await?.size)
+    }
+
+    async fn head(&self, uri: &str, _io_stats: Option<IOStatsRef>) -> super::Result<ObjectHead> {
         const LOCAL_PROTOCOL: &str = "file://";
         let Some(uri) = uri.strip_prefix(LOCAL_PROTOCOL) else {
             return Err(Error::InvalidFilePath { path: uri.into() }.into());
@@ -192,8 +357,21 @@ impl ObjectSource for LocalSource {
             Err(super::Error::NotAFile {
                 path: uri.to_owned(),
             })
+        } else if is_stream_file_type(meta.file_type()) {
+            Err(super::Error::UnableToDetermineSize {
+                path: uri.to_owned(),
+            })
         } else {
-            Ok(meta.len() as usize)
+            // Local files don't have ETags, so derive one from the modification time.
+            let etag = meta
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| format!("{}-{}", meta.len(), d.as_nanos()));
+            Ok(ObjectHead {
+                size: meta.len() as usize,
+                etag,
+            })
         }
     }
 
@@ -277,11 +455,11 @@ impl ObjectSource for LocalSource {
                 path: uri.to_string(),
             }
         })?;
-        if meta.file_type().is_file() {
-            // Provided uri points to a file, so only return that file.
+        if meta.file_type().is_file() || is_stream_file_type(meta.file_type()) {
+            // Provided uri points to a file, so only return that file. Streams such as pipes have no size.
             return Ok(futures::stream::iter([Ok(FileMetadata {
                 filepath: format!("{LOCAL_PROTOCOL}{uri}"),
-                size: Some(meta.len()),
+                size: meta.file_type().is_file().then(|| meta.len()),
                 filetype: object_io::FileType::File,
             })])
             .boxed());
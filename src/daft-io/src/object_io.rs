@@ -165,6 +165,14 @@ pub struct FileMetadata {
     pub size: Option<u64>,
     pub filetype: FileType,
 }
+/// The size of an object, along with its ETag if the source provides one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectHead {
+    pub size: usize,
+    /// Changes whenever the object is overwritten, so it can be used to validate cached data.
+    pub etag: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct LSResult {
     pub files: Vec<FileMetadata>,
@@ -191,6 +199,13 @@ pub trait ObjectSource: Sync + Send {
 
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize>;
 
+    async fn head(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<ObjectHead> {
+        Ok(ObjectHead {
+            size: self.get_size(uri, io_stats).await?,
+            etag: None,
+        })
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
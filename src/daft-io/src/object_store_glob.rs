@@ -30,8 +30,14 @@ const GLOB_DELIMITER: &str = "/";
 
 // NOTE: We use the following suffixes to filter out Spark/Databricks marker files
 const MARKER_SUFFIXES: [&str; 1] = [".crc"];
-// NOTE: We use the following file names to filter out Spark/Databricks marker files
-const MARKER_FILES: [&str; 3] = ["_metadata", "_common_metadata", "_success"];
+// NOTE: We use the following file names to filter out Spark/Databricks marker files, as well as the statistics that
+// `daft.analyze_parquet` persists next to a dataset
+const MARKER_FILES: [&str; 4] = [
+    "_metadata",
+    "_common_metadata",
+    "_success",
+    "_daft_statistics.json",
+];
 // NOTE: We use the following prefixes to filter out Spark/Databricks marker files
 const MARKER_PREFIXES: [&str; 2] = ["_started", "_committed"];
 
@@ -35,7 +35,7 @@ use url::{ParseError, Position};
 
 use super::object_io::{GetResult, ObjectSource};
 use crate::{
-    object_io::{FileMetadata, FileType, LSResult},
+    object_io::{FileMetadata, FileType, LSResult, ObjectHead},
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
     FileFormat, InvalidArgumentSnafu, SourceType,
@@ -727,7 +727,7 @@ impl S3LikeSource {
         permit: SemaphorePermit<'async_recursion>,
         uri: &str,
         region: &Region,
-    ) -> super::Result<usize> {
+    ) -> super::Result<ObjectHead> {
         log::debug!("S3 head at {uri} in region: {region}");
         let (_scheme, bucket, key) = parse_url(uri)?;
 
@@ -773,7 +773,10 @@ impl S3LikeSource {
             };
 
             match response {
-                Ok(v) => Ok(v.content_length() as usize),
+                Ok(v) => Ok(ObjectHead {
+                    size: v.content_length() as usize,
+                    etag: v.e_tag().map(str::to_string),
+                }),
                 Err(SdkError::ServiceError(err)) => {
                     let bad_response = err.raw().http();
                     match bad_response.status() {
@@ -1064,6 +1067,10 @@ impl ObjectSource for S3LikeSource {
     }
 
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        Ok(self.head(uri, io_stats).await?.size)
+    }
+
+    async fn head(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<ObjectHead> {
         let permit = self
             .connection_pool_sema
             .acquire()
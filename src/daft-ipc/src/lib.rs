@@ -0,0 +1,32 @@
+//! Reading of Arrow IPC files, which are also known as Feather (version 2) files.
+mod read;
+mod schema;
+
+use common_error::DaftError;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::ExprRef;
+pub use read::{read_ipc_bulk, stream_ipc};
+pub use schema::read_ipc_schema;
+use snafu::Snafu;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("Error joining spawned task: {}", source))]
+    JoinError { source: tokio::task::JoinError },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::JoinError { source } => Self::External(Box::new(source)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IpcConvertOptions {
+    pub limit: Option<usize>,
+    pub include_columns: Option<Vec<String>>,
+    pub schema: SchemaRef,
+    pub predicate: Option<ExprRef>,
+}
@@ -0,0 +1,181 @@
+use std::{io::Cursor, sync::Arc};
+
+use arrow2::io::ipc::read::{read_file_metadata, FileReader};
+use common_error::DaftResult;
+use common_runtime::get_io_runtime;
+use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
+use daft_dsl::optimization::get_required_columns;
+use daft_io::{IOClient, IOStatsRef};
+use daft_recordbatch::RecordBatch;
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use snafu::futures::TryFutureExt;
+
+use crate::{IpcConvertOptions, JoinSnafu};
+
+pub fn read_ipc_bulk(
+    uris: &[&str],
+    convert_options: IpcConvertOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+    num_parallel_tasks: usize,
+) -> DaftResult<Vec<RecordBatch>> {
+    let runtime_handle = get_io_runtime(multithreaded_io);
+    let tables = runtime_handle.block_on_current_thread(async move {
+        // Launch a read task per URI, throttling the number of concurrent file reads to num_parallel tasks.
+        let task_stream = futures::stream::iter(uris.iter().map(|uri| {
+            let (uri, convert_options, io_client, io_stats) = (
+                (*uri).to_string(),
+                convert_options.clone(),
+                io_client.clone(),
+                io_stats.clone(),
+            );
+            tokio::task::spawn(async move {
+                read_ipc_single_into_table(uri.as_str(), convert_options, io_client, io_stats).await
+            })
+            .context(JoinSnafu {})
+        }));
+        let mut remaining_rows = convert_options.limit.map(|limit| limit as i64);
+        task_stream
+            // Limit the number of file reads we have in flight at any given time.
+            .buffered(num_parallel_tasks)
+            // Terminate the stream if we have already reached the row limit. With the upstream buffering, we will still read up to
+            // num_parallel_tasks redundant files.
+            .try_take_while(|result| {
+                match (result, remaining_rows) {
+                    // Limit has been met, early-terminate.
+                    (_, Some(rows_left)) if rows_left <= 0 => futures::future::ready(Ok(false)),
+                    // Limit has not yet been met, update remaining limit slack and continue.
+                    (Ok(table), Some(rows_left)) => {
+                        remaining_rows = Some(rows_left - table.len() as i64);
+                        futures::future::ready(Ok(true))
+                    }
+                    // (1) No limit, never early-terminate.
+                    // (2) Encountered error, propagate error to try_collect to allow it to short-circuit.
+                    (_, None) | (Err(_), _) => futures::future::ready(Ok(true)),
+                }
+            })
+            .try_collect::<Vec<_>>()
+            .await
+    })?;
+
+    tables.into_iter().collect::<DaftResult<Vec<_>>>()
+}
+
+async fn read_ipc_single_into_table(
+    uri: &str,
+    convert_options: IpcConvertOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<RecordBatch> {
+    let schema = convert_options.schema.clone();
+    let tables = stream_ipc(uri, io_client, io_stats, convert_options)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    if tables.is_empty() {
+        RecordBatch::empty(Some(schema))
+    } else {
+        RecordBatch::concat(tables.as_slice())
+    }
+}
+
+/// Streams the record batches of an Arrow IPC file, one table per record batch.
+///
+/// The file is fetched with a single read, and only the columns that are included or that the predicate needs are
+/// decoded from it.
+pub async fn stream_ipc(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    convert_options: IpcConvertOptions,
+) -> DaftResult<BoxStream<'static, DaftResult<RecordBatch>>> {
+    let bytes = io_client
+        .single_url_get(uri.to_string(), None, io_stats)
+        .await?
+        .bytes()
+        .await?;
+    let mut reader = Cursor::new(bytes);
+    let metadata = read_file_metadata(&mut reader)?;
+    let file_columns = metadata
+        .schema
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect::<Vec<_>>();
+
+    // Columns that aren't in the file, such as partition columns, are filled in by the caller.
+    let output_columns = convert_options.include_columns.as_ref().map(|columns| {
+        file_columns
+            .iter()
+            .filter(|name| columns.iter().any(|c| c == *name))
+            .map(|name| (*name).to_string())
+            .collect::<Vec<_>>()
+    });
+    let predicate = convert_options.predicate.clone();
+    let projection = output_columns.as_ref().map(|output_columns| {
+        let mut read_columns = output_columns.clone();
+        if let Some(predicate) = &predicate {
+            read_columns.extend(get_required_columns(predicate));
+        }
+        let projection = file_columns
+            .iter()
+            .enumerate()
+            .filter(|(_, name)| read_columns.iter().any(|c| c == *name))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        // A record batch without any columns has no rows, so a column is still read to count them.
+        if projection.is_empty() && !file_columns.is_empty() {
+            vec![0]
+        } else {
+            projection
+        }
+    });
+
+    // With a predicate, the limit can only be applied to the rows that pass it.
+    let limit = convert_options.limit;
+    let reader = FileReader::new(
+        reader,
+        metadata,
+        projection,
+        limit.filter(|_| predicate.is_none()),
+    );
+    let read_schema: SchemaRef = Arc::new(Schema::try_from(reader.schema())?);
+    let batches = reader.map(move |chunk| {
+        let chunk = chunk?;
+        let num_rows = chunk.len();
+        let columns = chunk
+            .into_arrays()
+            .into_iter()
+            .zip(read_schema.fields.values())
+            .map(|(array, field)| {
+                Series::try_from_field_and_arrow_array(
+                    field.clone(),
+                    cast_array_for_daft_if_needed(array),
+                )
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let table = RecordBatch::new_with_size(read_schema.clone(), columns, num_rows)?;
+        let table = match &predicate {
+            Some(predicate) => table.filter(&[predicate.clone()])?,
+            None => table,
+        };
+        match &output_columns {
+            Some(output_columns) => table.get_columns(output_columns.as_slice()),
+            None => Ok(table),
+        }
+    });
+
+    let mut remaining_rows = limit;
+    let batches = batches.map_while(move |table| match (table, remaining_rows) {
+        // Limit has been met, early-terminate.
+        (_, Some(0)) => None,
+        (Ok(table), Some(rows_left)) => {
+            let num_rows = table.len().min(rows_left);
+            remaining_rows = Some(rows_left - num_rows);
+            Some(table.head(num_rows))
+        }
+        (table, _) => Some(table),
+    });
+    Ok(futures::stream::iter(batches).boxed())
+}
@@ -0,0 +1,72 @@
+use std::{io::Cursor, sync::Arc};
+
+use arrow2::io::ipc::read::read_file_metadata;
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::Schema;
+use daft_io::{IOClient, IOStatsRef};
+
+/// The magic bytes that an Arrow IPC file starts and ends with.
+const MAGIC: &[u8; 6] = b"ARROW1";
+
+/// The magic bytes at the start of a file, padded to 8 bytes.
+const HEADER_LEN: usize = 8;
+
+/// The length of the footer followed by the magic bytes at the end of a file.
+const TRAILER_LEN: usize = 10;
+
+/// Reads the schema of an Arrow IPC file from its footer, without fetching any of its record batches.
+pub async fn read_ipc_schema(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let size = io_client
+        .single_url_get_size(uri.to_string(), io_stats.clone())
+        .await?;
+    if size < HEADER_LEN + TRAILER_LEN {
+        return Err(not_an_ipc_file(uri));
+    }
+    let trailer = io_client
+        .single_url_get(
+            uri.to_string(),
+            Some(size - TRAILER_LEN..size),
+            io_stats.clone(),
+        )
+        .await?
+        .bytes()
+        .await?;
+    let footer_len = i32::from_le_bytes(trailer[..4].try_into().unwrap());
+    let footer_len = match usize::try_from(footer_len) {
+        Ok(footer_len)
+            if trailer[4..] == MAGIC[..] && HEADER_LEN + footer_len + TRAILER_LEN <= size =>
+        {
+            footer_len
+        }
+        _ => return Err(not_an_ipc_file(uri)),
+    };
+    let footer_end = size - TRAILER_LEN;
+    let footer = io_client
+        .single_url_get(
+            uri.to_string(),
+            Some(footer_end - footer_len..footer_end),
+            io_stats,
+        )
+        .await?
+        .bytes()
+        .await?;
+
+    // The footer holds the schema, so the record batches between the header and the footer aren't needed to read it.
+    let mut file = Vec::with_capacity(HEADER_LEN + footer_len + TRAILER_LEN);
+    file.extend_from_slice(MAGIC);
+    file.resize(HEADER_LEN, 0);
+    file.extend_from_slice(&footer);
+    file.extend_from_slice(&trailer);
+    let metadata = read_file_metadata(&mut Cursor::new(file))?;
+    Schema::try_from(&metadata.schema)
+}
+
+fn not_an_ipc_file(uri: &str) -> DaftError {
+    DaftError::ValueError(format!(
+        "{uri} is not an Arrow IPC file, which must end with its footer and the magic bytes ARROW1"
+    ))
+}
@@ -4,7 +4,7 @@ pub mod pylib {
     use daft_core::python::PySchema;
     use daft_io::{get_io_client, python::IOConfig, IOStatsContext};
     use daft_recordbatch::python::PyRecordBatch;
-    use pyo3::{pyfunction, PyResult, Python};
+    use pyo3::{pyfunction, types::PyBytes, Bound, PyResult, Python};
 
     use crate::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
 
@@ -88,4 +88,14 @@ pub mod pylib {
             Ok(Arc::new(schema).into())
         })
     }
+
+    /// Serializes the rows of `table` as JSON Lines.
+    #[pyfunction]
+    pub fn write_json_lines<'py>(
+        py: Python<'py>,
+        table: &PyRecordBatch,
+    ) -> PyResult<Bound<'py, PyBytes>> {
+        let bytes = py.allow_threads(|| crate::write::write_json_lines(&table.table))?;
+        Ok(PyBytes::new(py, &bytes))
+    }
 }
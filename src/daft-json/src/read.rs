@@ -5,7 +5,9 @@ use common_runtime::get_io_runtime;
 use daft_compression::CompressionCodec;
 use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
 use daft_dsl::optimization::get_required_columns;
-use daft_io::{parse_url, GetResult, IOClient, IOStatsRef, SourceType};
+use daft_io::{
+    is_local_stream, parse_url, GetResult, IOClient, IOStatsRef, LocalStreamRelease, SourceType,
+};
 use daft_recordbatch::RecordBatch;
 use futures::{stream::BoxStream, Stream, StreamExt, TryStreamExt};
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
@@ -180,7 +182,8 @@ async fn read_json_single_into_table(
 ) -> DaftResult<RecordBatch> {
     let (source_type, fixed_uri) = parse_url(uri)?;
     let is_compressed = CompressionCodec::from_uri(uri).is_some();
-    if matches!(source_type, SourceType::File) && !is_compressed {
+    // The local reader seeks within the file, which pipes don't support.
+    if matches!(source_type, SourceType::File) && !is_compressed && !is_local_stream(&fixed_uri) {
         return read_json_local(
             fixed_uri.as_ref(),
             convert_options,
@@ -189,6 +192,7 @@ async fn read_json_single_into_table(
             max_chunks_in_flight,
         );
     }
+    let _release = LocalStreamRelease::new(&fixed_uri);
 
     let predicate = convert_options.as_ref().and_then(|p| p.predicate.clone());
 
@@ -296,6 +300,9 @@ pub async fn stream_json(
     io_stats: Option<IOStatsRef>,
     max_chunks_in_flight: Option<usize>,
 ) -> DaftResult<BoxStream<'static, DaftResult<RecordBatch>>> {
+    let (_, fixed_uri) = parse_url(&uri)?;
+    // Release a pipe once the stream of its tables is done with, however it finishes.
+    let release = LocalStreamRelease::new(&fixed_uri);
     let predicate = convert_options
         .as_ref()
         .and_then(|opts| opts.predicate.clone());
@@ -348,6 +355,7 @@ pub async fn stream_json(
         .try_buffered(max_chunks_in_flight);
 
     let filtered_tables = tables.map(move |table| {
+        let _release = &release;
         let table = table?;
         if let Some(predicate) = &predicate {
             let filtered = table?.filter(&[predicate.clone()])?;
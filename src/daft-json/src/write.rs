@@ -0,0 +1,107 @@
+use arrow2::{
+    array::{Array, StructArray},
+    io::ndjson::write::{FallibleStreamingIterator, Serializer},
+};
+use common_error::DaftResult;
+use daft_core::prelude::*;
+use daft_recordbatch::RecordBatch;
+
+use crate::Error;
+
+/// Whether columns of `dtype` can be serialized to JSON as they are, rather than as strings.
+fn is_json_serializable(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::Null
+        | DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Utf8
+        | DataType::Date
+        | DataType::Timestamp(..)
+        | DataType::Duration(..) => true,
+        DataType::List(child) | DataType::FixedSizeList(child, _) => is_json_serializable(child),
+        DataType::Struct(fields) => fields
+            .iter()
+            .all(|field| is_json_serializable(&field.dtype)),
+        _ => false,
+    }
+}
+
+/// Serializes the rows of `table` as JSON Lines, one JSON object per row.
+///
+/// Columns of types that JSON can't represent directly, such as decimals and binary, are written as strings.
+pub fn write_json_lines(table: &RecordBatch) -> DaftResult<Vec<u8>> {
+    let mut fields = Vec::with_capacity(table.num_columns());
+    let mut arrays = Vec::with_capacity(table.num_columns());
+    for idx in 0..table.num_columns() {
+        let column = table.get_column_by_index(idx)?;
+        let column = if is_json_serializable(column.data_type()) {
+            column.clone()
+        } else {
+            column.cast(&DataType::Utf8)?
+        };
+        let array = column.to_arrow();
+        fields.push(arrow2::datatypes::Field::new(
+            column.name(),
+            array.data_type().clone(),
+            true,
+        ));
+        arrays.push(array);
+    }
+    let rows: Box<dyn Array> = Box::new(StructArray::new(
+        arrow2::datatypes::DataType::Struct(fields),
+        arrays,
+        None,
+    ));
+    let mut serializer = Serializer::new(std::iter::once(Ok(rows)), vec![]);
+    let mut bytes = vec![];
+    while let Some(chunk) = serializer
+        .next()
+        .map_err(|source| Error::ArrowError { source })?
+    {
+        bytes.extend_from_slice(chunk);
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+    use daft_recordbatch::RecordBatch;
+
+    use super::write_json_lines;
+
+    #[test]
+    fn test_write_json_lines() -> common_error::DaftResult<()> {
+        let table = RecordBatch::from_nonempty_columns(vec![
+            Int64Array::from_iter(
+                Field::new("a", DataType::Int64),
+                vec![Some(1), None].into_iter(),
+            )
+            .into_series(),
+            Utf8Array::from_iter("b", vec![Some("x"), Some("y\"z")].into_iter()).into_series(),
+        ])?;
+        let bytes = write_json_lines(&table)?;
+        assert_eq!(
+            String::from_utf8(bytes).unwrap(),
+            "{\"a\":1,\"b\":\"x\"}\n{\"a\":null,\"b\":\"y\\\"z\"}\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_json_lines_empty() -> common_error::DaftResult<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64)])?;
+        let table = RecordBatch::empty(Some(schema.into()))?;
+        assert!(write_json_lines(&table)?.is_empty());
+        Ok(())
+    }
+}
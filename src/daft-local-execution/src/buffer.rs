@@ -8,6 +8,8 @@ pub struct RowBasedBuffer {
     pub buffer: VecDeque<Arc<MicroPartition>>,
     pub curr_len: usize,
     pub threshold: usize,
+    max_threshold: usize,
+    max_bytes: Option<usize>,
 }
 
 impl RowBasedBuffer {
@@ -17,13 +19,30 @@ impl RowBasedBuffer {
             buffer: VecDeque::new(),
             curr_len: 0,
             threshold,
+            max_threshold: threshold,
+            max_bytes: None,
         }
     }
 
+    // Also limit the morsels to roughly `max_bytes`, by lowering the threshold to the number of rows that fit in it
+    pub fn with_max_bytes(mut self, max_bytes: Option<usize>) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
     // Push a morsel to the buffer
-    pub fn push(&mut self, part: &Arc<MicroPartition>) {
+    pub fn push(&mut self, part: &Arc<MicroPartition>) -> DaftResult<()> {
+        if let Some(max_bytes) = self.max_bytes
+            && !part.is_empty()
+            && let Some(size_bytes) = part.size_bytes()?
+        {
+            // The size of the rows is estimated from the latest morsel, so that it follows changes in the input.
+            let row_size = size_bytes.div_ceil(part.len()).max(1);
+            self.threshold = (max_bytes / row_size).clamp(1, self.max_threshold);
+        }
         self.curr_len += part.len();
         self.buffer.push_back(part.clone());
+        Ok(())
     }
 
     // Pop enough morsels that reach the threshold
@@ -39,17 +39,28 @@ pub(crate) struct SpawnedDispatchResult {
 /// Used if the operator requires maintaining the order of the input.
 pub(crate) struct RoundRobinDispatcher {
     morsel_size: Option<usize>,
+    max_morsel_bytes: Option<usize>,
 }
 
 impl RoundRobinDispatcher {
     pub(crate) fn new(morsel_size: Option<usize>) -> Self {
-        Self { morsel_size }
+        Self {
+            morsel_size,
+            max_morsel_bytes: None,
+        }
+    }
+
+    /// Also limits the size in bytes of the morsels, when they're buffered to `morsel_size` rows.
+    pub(crate) fn with_max_morsel_bytes(mut self, max_morsel_bytes: Option<usize>) -> Self {
+        self.max_morsel_bytes = max_morsel_bytes;
+        self
     }
 
     async fn dispatch_inner(
         worker_senders: Vec<Sender<Arc<MicroPartition>>>,
         input_receivers: Vec<CountingReceiver>,
         morsel_size: Option<usize>,
+        max_morsel_bytes: Option<usize>,
     ) -> DaftResult<()> {
         let mut next_worker_idx = 0;
         let mut send_to_next_worker = |data: Arc<MicroPartition>| {
@@ -59,10 +70,12 @@ impl RoundRobinDispatcher {
         };
 
         for receiver in input_receivers {
-            let mut buffer = morsel_size.map(RowBasedBuffer::new);
+            let mut buffer = morsel_size.map(|morsel_size| {
+                RowBasedBuffer::new(morsel_size).with_max_bytes(max_morsel_bytes)
+            });
             while let Some(morsel) = receiver.recv().await {
                 if let Some(buffer) = &mut buffer {
-                    buffer.push(&morsel);
+                    buffer.push(&morsel)?;
                     if let Some(ready) = buffer.pop_enough()? {
                         for r in ready {
                             if send_to_next_worker(r).await.is_err() {
@@ -97,8 +110,15 @@ impl DispatchSpawner for RoundRobinDispatcher {
         let (worker_senders, worker_receivers): (Vec<_>, Vec<_>) =
             (0..num_workers).map(|_| create_channel(0)).unzip();
         let morsel_size = self.morsel_size;
+        let max_morsel_bytes = self.max_morsel_bytes;
         let task = runtime_handle.spawn(async move {
-            Self::dispatch_inner(worker_senders, input_receivers, morsel_size).await
+            Self::dispatch_inner(
+                worker_senders,
+                input_receivers,
+                morsel_size,
+                max_morsel_bytes,
+            )
+            .await
         });
 
         SpawnedDispatchResult {
@@ -112,23 +132,36 @@ impl DispatchSpawner for RoundRobinDispatcher {
 /// Used if the operator does not require maintaining the order of the input.
 pub(crate) struct UnorderedDispatcher {
     morsel_size: Option<usize>,
+    max_morsel_bytes: Option<usize>,
 }
 
 impl UnorderedDispatcher {
     pub(crate) fn new(morsel_size: Option<usize>) -> Self {
-        Self { morsel_size }
+        Self {
+            morsel_size,
+            max_morsel_bytes: None,
+        }
+    }
+
+    /// Also limits the size in bytes of the morsels, when they're buffered to `morsel_size` rows.
+    pub(crate) fn with_max_morsel_bytes(mut self, max_morsel_bytes: Option<usize>) -> Self {
+        self.max_morsel_bytes = max_morsel_bytes;
+        self
     }
 
     async fn dispatch_inner(
         worker_sender: Sender<Arc<MicroPartition>>,
         input_receivers: Vec<CountingReceiver>,
         morsel_size: Option<usize>,
+        max_morsel_bytes: Option<usize>,
     ) -> DaftResult<()> {
         for receiver in input_receivers {
-            let mut buffer = morsel_size.map(RowBasedBuffer::new);
+            let mut buffer = morsel_size.map(|morsel_size| {
+                RowBasedBuffer::new(morsel_size).with_max_bytes(max_morsel_bytes)
+            });
             while let Some(morsel) = receiver.recv().await {
                 if let Some(buffer) = &mut buffer {
-                    buffer.push(&morsel);
+                    buffer.push(&morsel)?;
                     if let Some(ready) = buffer.pop_enough()? {
                         for r in ready {
                             if worker_sender.send(r).await.is_err() {
@@ -163,9 +196,11 @@ impl DispatchSpawner for UnorderedDispatcher {
         let (worker_sender, worker_receiver) = create_channel(num_workers);
         let worker_receivers = vec![worker_receiver; num_workers];
         let morsel_size = self.morsel_size;
+        let max_morsel_bytes = self.max_morsel_bytes;
 
-        let dispatch_task = runtime_handle
-            .spawn(async move { Self::dispatch_inner(worker_sender, receiver, morsel_size).await });
+        let dispatch_task = runtime_handle.spawn(async move {
+            Self::dispatch_inner(worker_sender, receiver, morsel_size, max_morsel_bytes).await
+        });
 
         SpawnedDispatchResult {
             worker_receivers,
@@ -225,16 +225,22 @@ impl IntermediateOperator for ActorPoolProjectOperator {
         runtime_handle: &ExecutionRuntimeContext,
         maintain_order: bool,
     ) -> Arc<dyn DispatchSpawner> {
+        // A batch size set on the UDF takes precedence over the default limit on the size of morsels.
+        let morsel_size = self
+            .batch_size
+            .unwrap_or_else(|| runtime_handle.default_morsel_size());
+        let max_morsel_bytes = runtime_handle
+            .default_morsel_size_bytes()
+            .filter(|_| self.batch_size.is_none());
         if maintain_order {
-            Arc::new(RoundRobinDispatcher::new(Some(
-                self.batch_size
-                    .unwrap_or_else(|| runtime_handle.default_morsel_size()),
-            )))
+            Arc::new(
+                RoundRobinDispatcher::new(Some(morsel_size))
+                    .with_max_morsel_bytes(max_morsel_bytes),
+            )
         } else {
-            Arc::new(UnorderedDispatcher::new(Some(
-                self.batch_size
-                    .unwrap_or_else(|| runtime_handle.default_morsel_size()),
-            )))
+            Arc::new(
+                UnorderedDispatcher::new(Some(morsel_size)).with_max_morsel_bytes(max_morsel_bytes),
+            )
         }
     }
 }
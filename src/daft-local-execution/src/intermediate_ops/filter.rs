@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use daft_dsl::ExprRef;
+use daft_dsl::{BoundExpr, ExprRef};
 use daft_micropartition::MicroPartition;
 use tracing::{instrument, Span};
 
@@ -15,8 +15,10 @@ pub struct FilterOperator {
 }
 
 impl FilterOperator {
-    pub fn new(predicate: ExprRef) -> Self {
-        Self { predicate }
+    pub fn new(predicate: BoundExpr) -> Self {
+        Self {
+            predicate: predicate.into_inner(),
+        }
     }
 }
 
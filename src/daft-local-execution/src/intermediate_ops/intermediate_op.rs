@@ -65,13 +65,15 @@ pub trait IntermediateOperator: Send + Sync {
         maintain_order: bool,
     ) -> Arc<dyn DispatchSpawner> {
         if maintain_order {
-            Arc::new(RoundRobinDispatcher::new(Some(
-                runtime_handle.default_morsel_size(),
-            )))
+            Arc::new(
+                RoundRobinDispatcher::new(Some(runtime_handle.default_morsel_size()))
+                    .with_max_morsel_bytes(runtime_handle.default_morsel_size_bytes()),
+            )
         } else {
-            Arc::new(UnorderedDispatcher::new(Some(
-                runtime_handle.default_morsel_size(),
-            )))
+            Arc::new(
+                UnorderedDispatcher::new(Some(runtime_handle.default_morsel_size()))
+                    .with_max_morsel_bytes(runtime_handle.default_morsel_size_bytes()),
+            )
         }
     }
 }
@@ -189,6 +191,9 @@ impl TreeDisplay for IntermediateNode {
                 writeln!(display, "{}", multiline_display).unwrap();
                 if let StatsState::Materialized(stats) = &self.plan_stats {
                     writeln!(display, "Stats = {}", stats).unwrap();
+                    if let Some(semantic_id) = &stats.semantic_id {
+                        writeln!(display, "Semantic ID = {semantic_id}").unwrap();
+                    }
                 }
                 if matches!(level, DisplayLevel::Verbose) {
                     writeln!(display).unwrap();
@@ -242,8 +247,12 @@ impl PipelineNode for IntermediateNode {
             node_name: self.name(),
         })?;
         let (destination_sender, destination_receiver) = create_channel(0);
-        let counting_sender =
-            CountingSender::new(destination_sender, self.runtime_stats.clone(), progress_bar);
+        let counting_sender = CountingSender::new(
+            destination_sender,
+            self.runtime_stats.clone(),
+            runtime_handle.memory_manager(),
+            progress_bar,
+        );
 
         let dispatch_spawner = self
             .intermediate_op
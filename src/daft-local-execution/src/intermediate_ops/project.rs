@@ -1,7 +1,7 @@
 use std::{cmp::max, sync::Arc};
 
 use common_error::{DaftError, DaftResult};
-use daft_dsl::{functions::python::get_resource_request, ExprRef};
+use daft_dsl::{functions::python::get_resource_request, BoundExpr, ExprRef};
 use daft_micropartition::MicroPartition;
 use itertools::Itertools;
 use tracing::{instrument, Span};
@@ -27,7 +27,11 @@ pub struct ProjectOperator {
 }
 
 impl ProjectOperator {
-    pub fn new(projection: Vec<ExprRef>) -> DaftResult<Self> {
+    pub fn new(projection: Vec<BoundExpr>) -> DaftResult<Self> {
+        let projection = projection
+            .into_iter()
+            .map(BoundExpr::into_inner)
+            .collect::<Vec<_>>();
         let memory_request = get_resource_request(&projection)
             .and_then(|req| req.memory_bytes())
             .map(|m| m as u64)
@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use common_daft_config::DaftExecutionConfig;
+use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode};
+use daft_logical_plan::{ops::Iterate, LogicalPlan, LogicalPlanBuilder, LogicalPlanRef};
+use daft_micropartition::{
+    partitioning::{MicroPartitionSet, PartitionCacheEntry, PartitionSetCache},
+    MicroPartitionRef,
+};
+
+use crate::{runner::PartitionSetKeys, NativeExecutor};
+
+impl NativeExecutor {
+    /// Runs the iterations of every [`Iterate`] in a plan, replacing each with an in-memory scan of its result.
+    ///
+    /// The results are put into `psets` under the result keys of the iterations, which are added to `results` so that
+    /// they're removed once the plan has been run.
+    pub(crate) fn run_iterations(
+        &self,
+        builder: &LogicalPlanBuilder,
+        psets: &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+        cfg: &Arc<DaftExecutionConfig>,
+        results: &mut PartitionSetKeys<'_>,
+    ) -> DaftResult<LogicalPlanBuilder> {
+        let plan = builder
+            .build()
+            .transform_up(|node| {
+                let LogicalPlan::Iterate(iterate) = node.as_ref() else {
+                    return Ok(Transformed::no(node));
+                };
+                // The input has already been run if it has iterations itself.
+                let parts = self.iterate(builder, iterate, psets, cfg)?;
+
+                let num_partitions = parts.len();
+                let num_rows = parts.iter().map(|part| part.len()).sum();
+                let size_bytes = parts
+                    .iter()
+                    .map(|part| Ok(part.size_bytes()?.unwrap_or(0)))
+                    .sum::<DaftResult<usize>>()?;
+                let pset = Arc::new(MicroPartitionSet::from(parts));
+                psets.put_partition_set(&iterate.result_key, &pset);
+                results.push(iterate.result_key.clone());
+
+                let scan = LogicalPlanBuilder::in_memory_scan(
+                    &iterate.result_key,
+                    PartitionCacheEntry::new_rust(iterate.result_key.clone(), pset),
+                    iterate.input.schema(),
+                    num_partitions,
+                    size_bytes,
+                    num_rows,
+                )?
+                .build();
+                Ok(Transformed::yes(Arc::new(
+                    Arc::unwrap_or_clone(scan).with_materialized_stats(),
+                )))
+            })?
+            .data;
+        Ok(builder.with_new_plan(plan))
+    }
+
+    /// Runs the iterations of `iterate` until they converge, returning the partitions of the last result.
+    fn iterate(
+        &self,
+        builder: &LogicalPlanBuilder,
+        iterate: &Iterate,
+        psets: &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+        cfg: &Arc<DaftExecutionConfig>,
+    ) -> DaftResult<Vec<MicroPartitionRef>> {
+        // The plans of an iteration aren't part of the plan that was optimized, and are only optimized once for all
+        // of the iterations.
+        let body = builder.with_new_plan(iterate.body.clone()).optimize()?;
+        let convergence = builder
+            .with_new_plan(iterate.convergence.clone())
+            .optimize()?;
+
+        let mut keys = PartitionSetKeys::new(psets);
+        keys.push(iterate.previous_key.clone());
+        keys.push(iterate.result_key.clone());
+
+        let mut result = self.collect(&builder.with_new_plan(iterate.input.clone()), psets, cfg)?;
+        for _ in 0..iterate.max_iters {
+            // The cache only holds on to the partition sets while they're being read.
+            let previous = Arc::new(MicroPartitionSet::from(result.clone()));
+            psets.put_partition_set(&iterate.previous_key, &previous);
+            let next = self.collect(&body, psets, cfg)?;
+            let next_pset = Arc::new(MicroPartitionSet::from(next.clone()));
+            psets.put_partition_set(&iterate.result_key, &next_pset);
+            let converged = self.collect(&convergence, psets, cfg)?;
+
+            result = next;
+            if is_converged(&converged)? {
+                break;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Runs a plan to completion, returning its partitions.
+    fn collect(
+        &self,
+        builder: &LogicalPlanBuilder,
+        psets: &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+        cfg: &Arc<DaftExecutionConfig>,
+    ) -> DaftResult<Vec<MicroPartitionRef>> {
+        self.run(builder, psets, cfg.clone(), None)?
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Replaces every [`Iterate`] in a plan with a scan of its result, which is what the plan is run on once the iterations
+/// have run, e.g. to display the pipeline that runs it.
+pub(crate) fn without_iterations(plan: LogicalPlanRef) -> LogicalPlanRef {
+    plan.transform_up(|node| {
+        let LogicalPlan::Iterate(iterate) = node.as_ref() else {
+            return Ok(Transformed::no(node));
+        };
+        let scan =
+            LogicalPlanBuilder::iteration_scan(&iterate.result_key, iterate.input.schema()).build();
+        Ok(Transformed::yes(Arc::new(
+            Arc::unwrap_or_clone(scan).with_materialized_stats(),
+        )))
+    })
+    .expect("replacing iterations doesn't fail")
+    .data
+}
+
+/// Whether the single Boolean row of a convergence plan is true.
+fn is_converged(parts: &[MicroPartitionRef]) -> DaftResult<bool> {
+    for part in parts {
+        for batch in part.get_tables()?.iter() {
+            if !batch.is_empty() {
+                return Ok(batch.get_column_by_index(0)?.bool()?.get(0) == Some(true));
+            }
+        }
+    }
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_daft_config::DaftExecutionConfig;
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{binary_op, lit, resolved_col, Operator};
+    use daft_logical_plan::LogicalPlanBuilder;
+    use daft_micropartition::partitioning::{
+        InMemoryPartitionSetCache, MicroPartitionSet, PartitionCacheEntry, PartitionSet,
+        PartitionSetCache,
+    };
+    use daft_recordbatch::RecordBatch;
+
+    use crate::NativeExecutor;
+
+    #[test]
+    fn test_iterate_until_converged() -> DaftResult<()> {
+        let psets = InMemoryPartitionSetCache::empty();
+        let batch = RecordBatch::from_nonempty_columns(vec![
+            Int64Array::from(("x", vec![1])).into_series()
+        ])?;
+        let schema = batch.schema.clone();
+        let pset = Arc::new(MicroPartitionSet::from_tables(0, vec![batch])?);
+        psets.put_partition_set("input", &pset);
+        let input = LogicalPlanBuilder::in_memory_scan(
+            "input",
+            PartitionCacheEntry::new_rust("input".to_string(), pset.clone()),
+            schema.clone(),
+            pset.num_partitions(),
+            pset.size_bytes()?,
+            1,
+        )?;
+
+        // Increments x until it's at least 4.
+        let body = LogicalPlanBuilder::iteration_scan("previous", schema.clone()).select(vec![
+            binary_op(Operator::Plus, resolved_col("x"), lit(1)).alias("x"),
+        ])?;
+        let convergence = LogicalPlanBuilder::iteration_scan("result", schema)
+            .select(vec![resolved_col("x").gt_eq(lit(4)).alias("converged")])?;
+
+        for (max_iters, expected) in [(10, 4), (2, 3)] {
+            let plan = input
+                .iterate(
+                    &body,
+                    &convergence,
+                    "previous".to_string(),
+                    "result".to_string(),
+                    max_iters,
+                )?
+                .optimize()?;
+            let parts = NativeExecutor::new()
+                .run(
+                    &plan,
+                    &psets,
+                    Arc::new(DaftExecutionConfig::default()),
+                    None,
+                )?
+                .into_iter()
+                .collect::<DaftResult<Vec<_>>>()?;
+            let batches = parts
+                .iter()
+                .map(|part| Ok(part.get_tables()?.as_ref().clone()))
+                .collect::<DaftResult<Vec<_>>>()?;
+            let batch = RecordBatch::concat(&batches.concat())?;
+            assert_eq!(
+                batch.get_column("x")?,
+                &Int64Array::from(("x", vec![expected])).into_series()
+            );
+            // The results of the iterations are removed from the cache once they've been read.
+            assert_eq!(psets.partition_sets.len(), 1);
+        }
+        Ok(())
+    }
+}
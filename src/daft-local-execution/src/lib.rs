@@ -4,10 +4,13 @@ mod buffer;
 mod channel;
 mod dispatcher;
 mod intermediate_ops;
+mod iterate;
 mod pipeline;
 mod progress_bar;
 mod resource_manager;
+mod retry;
 mod run;
+mod runner;
 mod runtime_stats;
 mod sinks;
 mod sources;
@@ -25,6 +28,7 @@ use common_runtime::{RuntimeRef, RuntimeTask};
 use progress_bar::{OperatorProgressBar, ProgressBarColor, ProgressBarManager};
 use resource_manager::MemoryManager;
 pub use run::{ExecutionEngineResult, NativeExecutor};
+pub use runner::{NodeResources, PartitionIterator, PlanRunner};
 use runtime_stats::{RuntimeStatsContext, TimedFuture};
 use snafu::{futures::TryFutureExt, ResultExt, Snafu};
 use tracing::Instrument;
@@ -32,6 +36,10 @@ use tracing::Instrument;
 pub static NUM_CPUS: LazyLock<usize> =
     LazyLock::new(|| std::thread::available_parallelism().unwrap().get());
 
+/// The smallest morsel size that a limit caps the operators before it to, so that selective filters
+/// before small limits don't spend most of their time on per-morsel overhead.
+const MIN_LIMITED_MORSEL_SIZE: usize = 1024;
+
 /// The `OperatorOutput` enum represents the output of an operator.
 /// It can be either `Ready` or `Pending`.
 /// If the output is `Ready`, the value is immediately available.
@@ -120,6 +128,8 @@ impl RuntimeHandle {
 pub(crate) struct ExecutionRuntimeContext {
     worker_set: TaskSet<crate::Result<()>>,
     default_morsel_size: usize,
+    default_morsel_size_bytes: Option<usize>,
+    morsel_size_limit: Option<usize>,
     memory_manager: Arc<MemoryManager>,
     progress_bar_manager: Option<Arc<dyn ProgressBarManager>>,
 }
@@ -128,12 +138,15 @@ impl ExecutionRuntimeContext {
     #[must_use]
     pub fn new(
         default_morsel_size: usize,
+        default_morsel_size_bytes: Option<usize>,
         memory_manager: Arc<MemoryManager>,
         progress_bar_manager: Option<Arc<dyn ProgressBarManager>>,
     ) -> Self {
         Self {
             worker_set: TaskSet::new(),
             default_morsel_size,
+            default_morsel_size_bytes,
+            morsel_size_limit: None,
             memory_manager,
             progress_bar_manager,
         }
@@ -158,7 +171,37 @@ impl ExecutionRuntimeContext {
 
     #[must_use]
     pub fn default_morsel_size(&self) -> usize {
-        self.default_morsel_size
+        match self.morsel_size_limit {
+            Some(limit) => self
+                .default_morsel_size
+                .min(limit.max(MIN_LIMITED_MORSEL_SIZE)),
+            None => self.default_morsel_size,
+        }
+    }
+
+    /// The largest size in bytes of the morsels buffered for operators, if it's limited on top of their rows.
+    #[must_use]
+    pub fn default_morsel_size_bytes(&self) -> Option<usize> {
+        self.default_morsel_size_bytes
+    }
+
+    /// The most rows that the operators being started need to produce, if a limit after them bounds it.
+    #[must_use]
+    pub fn morsel_size_limit(&self) -> Option<usize> {
+        self.morsel_size_limit
+    }
+
+    /// Runs `f` with `limit` as the morsel size limit, so that the operators it starts buffer smaller morsels
+    /// when only a few rows are needed, and restores the previous limit afterwards.
+    pub fn with_morsel_size_limit<T>(
+        &mut self,
+        limit: Option<usize>,
+        f: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        let previous_limit = std::mem::replace(&mut self.morsel_size_limit, limit);
+        let result = f(self);
+        self.morsel_size_limit = previous_limit;
+        result
     }
 
     pub fn make_progress_bar(
@@ -220,6 +263,10 @@ impl ExecutionTaskSpawner {
         }
     }
 
+    pub fn memory_manager(&self) -> &Arc<MemoryManager> {
+        &self.memory_manager
+    }
+
     pub fn spawn_with_memory_request<F, O>(
         &self,
         memory_request: u64,
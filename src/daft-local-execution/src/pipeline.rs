@@ -3,18 +3,22 @@ use std::sync::Arc;
 use common_daft_config::DaftExecutionConfig;
 use common_display::{
     ascii::fmt_tree_gitstyle,
+    json::fmt_tree_json,
     mermaid::{MermaidDisplayVisitor, SubgraphOptions},
     tree::TreeDisplay,
     DisplayLevel,
 };
 use common_error::{DaftError, DaftResult};
 use common_file_formats::FileFormat;
-use daft_core::{join::JoinSide, prelude::Schema};
-use daft_dsl::{join::get_common_join_cols, resolved_col};
+use daft_core::{
+    join::JoinSide,
+    prelude::{Schema, SchemaRef},
+};
+use daft_dsl::{join::get_common_join_cols, resolved_col, BoundExpr};
 use daft_local_plan::{
-    ActorPoolProject, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate, HashJoin,
-    InMemoryScan, Limit, LocalPhysicalPlan, MonotonicallyIncreasingId, PhysicalWrite, Pivot,
-    Project, Sample, Sort, UnGroupedAggregate, Unpivot,
+    ActorPoolProject, Concat, CrossJoin, EmptyScan, Explode, Filter, FusedScanOp, HashAggregate,
+    HashJoin, InMemoryScan, Limit, LocalPhysicalPlan, MonotonicallyIncreasingId, PhysicalWrite,
+    Pivot, Project, Sample, Sort, UnGroupedAggregate, Unpivot, Window,
 };
 use daft_logical_plan::{stats::StatsState, JoinType};
 use daft_micropartition::{
@@ -48,9 +52,13 @@ use crate::{
         pivot::PivotSink,
         sort::SortSink,
         streaming_sink::StreamingSinkNode,
+        window::WindowSink,
         write::{WriteFormat, WriteSink},
     },
-    sources::{empty_scan::EmptyScanSource, in_memory::InMemorySource, source::SourceNode},
+    sources::{
+        empty_scan::EmptyScanSource, in_memory::InMemorySource, scan_task::FusedOp,
+        source::SourceNode,
+    },
     state_bridge::BroadcastStateBridge,
     ExecutionRuntimeContext, PipelineCreationSnafu,
 };
@@ -91,6 +99,47 @@ pub fn viz_pipeline_ascii(root: &dyn PipelineNode, simple: bool) -> String {
     s
 }
 
+pub fn viz_pipeline_json(root: &dyn PipelineNode, simple: bool) -> String {
+    let level = if simple {
+        DisplayLevel::Compact
+    } else {
+        DisplayLevel::Default
+    };
+    fmt_tree_json(root.as_tree_display(), level)
+}
+
+/// Binds the ops fused into a scan, each against the schema of the morsels it receives, and splits off the fused limit,
+/// which is always last.
+fn bind_fused_scan_ops(
+    fused_ops: &[FusedScanOp],
+    schema: &SchemaRef,
+) -> DaftResult<(Vec<FusedOp>, Option<usize>)> {
+    let mut schema = schema.clone();
+    let mut bound_ops = Vec::with_capacity(fused_ops.len());
+    let mut limit = None;
+    for op in fused_ops {
+        match op {
+            FusedScanOp::Filter { predicate, .. } => {
+                let predicate = BoundExpr::try_new(predicate.clone(), &schema)?;
+                bound_ops.push(FusedOp::Filter(predicate.into_inner()));
+            }
+            FusedScanOp::Project {
+                projection,
+                schema: output_schema,
+                ..
+            } => {
+                let projection = BoundExpr::bind_all(projection, &schema)?;
+                bound_ops.push(FusedOp::Project(
+                    projection.into_iter().map(BoundExpr::into_inner).collect(),
+                ));
+                schema = output_schema.clone();
+            }
+            FusedScanOp::Limit { num_rows, .. } => limit = Some(*num_rows as usize),
+        }
+    }
+    Ok((bound_ops, limit))
+}
+
 pub fn physical_plan_to_pipeline(
     physical_plan: &LocalPhysicalPlan,
     psets: &(impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized),
@@ -99,6 +148,7 @@ pub fn physical_plan_to_pipeline(
     use daft_local_plan::PhysicalScan;
 
     use crate::sources::scan_task::ScanTaskSource;
+
     let out: Box<dyn PipelineNode> = match physical_plan {
         LocalPhysicalPlan::EmptyScan(EmptyScan {
             schema,
@@ -107,20 +157,32 @@ pub fn physical_plan_to_pipeline(
             let source = EmptyScanSource::new(schema.clone());
             SourceNode::new(source.arced(), stats_state.clone()).boxed()
         }
-        LocalPhysicalPlan::PhysicalScan(PhysicalScan {
-            scan_tasks,
-            pushdowns,
-            schema,
-            stats_state,
-        }) => {
+        LocalPhysicalPlan::PhysicalScan(
+            scan @ PhysicalScan {
+                scan_tasks,
+                pushdowns,
+                schema,
+                fused_ops,
+                ..
+            },
+        ) => {
             let scan_tasks = scan_tasks
                 .iter()
                 .map(|task| task.clone().as_any_arc().downcast().unwrap())
                 .collect::<Vec<ScanTaskRef>>();
+            let (fused_ops, fused_limit) =
+                bind_fused_scan_ops(fused_ops, schema).with_context(|_| PipelineCreationSnafu {
+                    plan_name: physical_plan.name(),
+                })?;
 
             let scan_task_source =
-                ScanTaskSource::new(scan_tasks, pushdowns.clone(), schema.clone(), cfg);
-            SourceNode::new(scan_task_source.arced(), stats_state.clone()).boxed()
+                ScanTaskSource::new(scan_tasks, pushdowns.clone(), schema.clone(), cfg)
+                    .with_fused_ops(fused_ops, fused_limit, scan.output_schema().clone());
+            SourceNode::new(
+                scan_task_source.arced(),
+                physical_plan.get_stats_state().clone(),
+            )
+            .boxed()
         }
         LocalPhysicalPlan::InMemoryScan(InMemoryScan { info, stats_state }) => {
             let cache_key: Arc<str> = info.cache_key.clone().into();
@@ -140,11 +202,11 @@ pub fn physical_plan_to_pipeline(
             stats_state,
             ..
         }) => {
-            let proj_op = ProjectOperator::new(projection.clone()).with_context(|_| {
-                PipelineCreationSnafu {
+            let proj_op = BoundExpr::bind_all(projection, input.schema())
+                .and_then(ProjectOperator::new)
+                .with_context(|_| PipelineCreationSnafu {
                     plan_name: physical_plan.name(),
-                }
-            })?;
+                })?;
             let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
             IntermediateNode::new(Arc::new(proj_op), vec![child_node], stats_state.clone()).boxed()
         }
@@ -177,7 +239,13 @@ pub fn physical_plan_to_pipeline(
             stats_state,
             ..
         }) => {
-            let filter_op = FilterOperator::new(predicate.clone());
+            let predicate =
+                BoundExpr::try_new(predicate.clone(), input.schema()).with_context(|_| {
+                    PipelineCreationSnafu {
+                        plan_name: physical_plan.name(),
+                    }
+                })?;
+            let filter_op = FilterOperator::new(predicate);
             let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
             IntermediateNode::new(Arc::new(filter_op), vec![child_node], stats_state.clone())
                 .boxed()
@@ -296,10 +364,33 @@ pub fn physical_plan_to_pipeline(
             stats_state,
             ..
         }) => {
-            let sort_sink = SortSink::new(sort_by.clone(), descending.clone(), nulls_first.clone());
+            let sort_sink = SortSink::new(
+                sort_by.clone(),
+                descending.clone(),
+                nulls_first.clone(),
+                cfg,
+            );
             let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
             BlockingSinkNode::new(Arc::new(sort_sink), child_node, stats_state.clone()).boxed()
         }
+        LocalPhysicalPlan::Window(Window {
+            input,
+            window_functions,
+            partition_by,
+            order_by,
+            null_strategy,
+            stats_state,
+            ..
+        }) => {
+            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let window_sink = WindowSink::new(
+                window_functions.clone(),
+                partition_by.clone(),
+                order_by.clone(),
+                *null_strategy,
+            );
+            BlockingSinkNode::new(Arc::new(window_sink), child_node, stats_state.clone()).boxed()
+        }
         LocalPhysicalPlan::MonotonicallyIncreasingId(MonotonicallyIncreasingId {
             input,
             column_name,
@@ -590,6 +681,10 @@ pub fn physical_plan_to_pipeline(
                 (FileFormat::Parquet, false) => WriteFormat::Parquet,
                 (FileFormat::Csv, true) => WriteFormat::PartitionedCsv,
                 (FileFormat::Csv, false) => WriteFormat::Csv,
+                (FileFormat::Json, true) => WriteFormat::PartitionedJson,
+                (FileFormat::Json, false) => WriteFormat::Json,
+                (FileFormat::ArrowIpc, true) => WriteFormat::PartitionedIpc,
+                (FileFormat::ArrowIpc, false) => WriteFormat::Ipc,
                 (_, _) => panic!("Unsupported file format"),
             };
             let write_sink = WriteSink::new(
@@ -1,7 +1,11 @@
-use std::sync::{Arc, Mutex, OnceLock};
+use std::{
+    pin::pin,
+    sync::{Arc, Mutex, OnceLock, Weak},
+};
 
 use common_error::{DaftError, DaftResult};
 use common_system_info::SystemInfo;
+use daft_micropartition::MicroPartition;
 use tokio::sync::Notify;
 
 pub(crate) static MEMORY_MANAGER: OnceLock<Arc<MemoryManager>> = OnceLock::new();
@@ -23,6 +27,8 @@ pub(crate) fn get_or_init_memory_manager() -> &'static Arc<MemoryManager> {
 pub(crate) struct MemoryPermit<'a> {
     bytes: u64,
     manager: &'a MemoryManager,
+    // Held for as long as this permit so the bytes also stay reserved on the parent manager.
+    _parent: Option<Box<MemoryPermit<'a>>>,
 }
 
 impl Drop for MemoryPermit<'_> {
@@ -39,12 +45,33 @@ impl Drop for MemoryPermit<'_> {
 
 struct MemoryState {
     available_bytes: u64,
+    peak_reserved_bytes: u64,
+}
+
+impl MemoryState {
+    fn new(total_bytes: u64) -> Self {
+        Self {
+            available_bytes: total_bytes,
+            peak_reserved_bytes: 0,
+        }
+    }
+}
+
+/// The morsels emitted by the operators of a query that are still alive, e.g. because they're buffered in channels
+/// or in the states of blocking sinks.
+#[derive(Default)]
+struct HeldMorsels {
+    morsels: Vec<(Weak<MicroPartition>, u64)>,
+    held_bytes: u64,
+    peak_held_bytes: u64,
 }
 
 pub(crate) struct MemoryManager {
     total_bytes: u64,
     state: Mutex<MemoryState>,
     notify: Notify,
+    parent: Option<Arc<MemoryManager>>,
+    held_morsels: Mutex<HeldMorsels>,
 }
 
 impl Default for MemoryManager {
@@ -53,10 +80,10 @@ impl Default for MemoryManager {
         let total_mem = system_info.calculate_total_memory();
         Self {
             total_bytes: total_mem,
-            state: Mutex::new(MemoryState {
-                available_bytes: total_mem,
-            }),
+            state: Mutex::new(MemoryState::new(total_mem)),
             notify: Notify::new(),
+            parent: None,
+            held_morsels: Default::default(),
         }
     }
 }
@@ -66,46 +93,115 @@ impl MemoryManager {
         if let Some(custom_limit) = custom_memory_limit() {
             Self {
                 total_bytes: custom_limit,
-                state: Mutex::new(MemoryState {
-                    available_bytes: custom_limit,
-                }),
+                state: Mutex::new(MemoryState::new(custom_limit)),
                 notify: Notify::new(),
+                parent: None,
+                enforce_limit: false,
             }
         } else {
             Self::default()
         }
     }
 
+    /// Creates a manager for a single query. Every reservation is also made against `parent`,
+    /// so concurrent queries still share the process-wide budget, while the query itself is
+    /// capped at `limit_bytes` (or the parent's budget if no limit is given).
+    ///
+    /// A request only fails if it's larger than the limit on its own. Otherwise it waits until
+    /// the query's other reservations are released, like requests on the parent do.
+    pub fn for_query(parent: Arc<Self>, limit_bytes: Option<u64>) -> Self {
+        let total_bytes =
+            limit_bytes.map_or(parent.total_bytes, |limit| limit.min(parent.total_bytes));
+        Self {
+            total_bytes,
+            state: Mutex::new(MemoryState::new(total_bytes)),
+            notify: Notify::new(),
+            parent: Some(parent),
+            held_morsels: Default::default(),
+        }
+    }
+
+    /// The number of bytes that may be reserved at the same time on this manager.
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+
+    /// The highest number of bytes that were reserved at the same time on this manager.
+    pub fn peak_reserved_bytes(&self) -> u64 {
+        self.state.lock().unwrap().peak_reserved_bytes
+    }
+
+    /// The highest number of bytes of the morsels passed to [`Self::track_morsel`] that were alive at the same time.
+    pub fn peak_held_bytes(&self) -> u64 {
+        self.held_morsels.lock().unwrap().peak_held_bytes
+    }
+
+    /// Counts the bytes of a morsel emitted by an operator as held until the morsel is dropped.
+    ///
+    /// Morsels that are dropped are noticed the next time a morsel is tracked, so the peak is
+    /// only measured when morsels are emitted.
+    pub fn track_morsel(&self, morsel: &Arc<MicroPartition>) {
+        let Ok(Some(bytes)) = morsel.size_bytes() else {
+            return;
+        };
+        let mut held = self.held_morsels.lock().unwrap();
+        let mut released_bytes = 0;
+        held.morsels.retain(|(held_morsel, held_bytes)| {
+            let alive = held_morsel.strong_count() > 0;
+            if !alive {
+                released_bytes += held_bytes;
+            }
+            alive
+        });
+        held.held_bytes = held.held_bytes - released_bytes + bytes as u64;
+        held.peak_held_bytes = held.peak_held_bytes.max(held.held_bytes);
+        held.morsels.push((Arc::downgrade(morsel), bytes as u64));
+    }
+
     pub async fn request_bytes(&self, bytes: u64) -> DaftResult<MemoryPermit> {
         if bytes == 0 {
             return Ok(MemoryPermit {
                 bytes: 0,
                 manager: self,
+                _parent: None,
             });
         }
 
         if bytes > self.total_bytes {
             return Err(DaftError::ComputeError(format!(
-                "Cannot request {} bytes, only {} available",
+                "Memory limit exceeded: cannot request {} bytes, the limit is {} bytes",
                 bytes, self.total_bytes
             )));
         }
 
-        loop {
+        // Wait for this manager's budget before taking any of the parent's, so a query that's
+        // waiting for its own reservations to be released doesn't hold back other queries.
+        let mut permit = loop {
+            let mut notified = pin!(self.notify.notified());
+            // Register for notifications before checking, so releases in between aren't missed.
+            notified.as_mut().enable();
             if let Some(permit) = self.try_request_bytes(bytes) {
-                return Ok(permit);
+                break permit;
             }
-            self.notify.notified().await;
+            notified.await;
+        };
+        if let Some(parent) = &self.parent {
+            permit._parent = Some(Box::new(Box::pin(parent.request_bytes(bytes)).await?));
         }
+        Ok(permit)
     }
 
     fn try_request_bytes(&self, bytes: u64) -> Option<MemoryPermit> {
         let mut state = self.state.lock().unwrap();
         if state.available_bytes >= bytes {
             state.available_bytes -= bytes;
+            state.peak_reserved_bytes = state
+                .peak_reserved_bytes
+                .max(self.total_bytes - state.available_bytes);
             Some(MemoryPermit {
                 bytes,
                 manager: self,
+                _parent: None,
             })
         } else {
             None
@@ -117,6 +213,8 @@ impl MemoryManager {
 mod tests {
     use std::time::Duration;
 
+    use daft_core::prelude::*;
+    use daft_recordbatch::RecordBatch;
     use tokio::time;
 
     use super::*;
@@ -191,6 +289,104 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_peak_reserved_bytes() {
+        let manager = MemoryManager::new();
+        let permit1 = manager.request_bytes(3).await.unwrap();
+        let permit2 = manager.request_bytes(4).await.unwrap();
+        drop(permit1);
+        drop(permit2);
+        let _permit3 = manager.request_bytes(5).await.unwrap();
+
+        assert_eq!(manager.peak_reserved_bytes(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_query_memory_limit() {
+        let parent = Arc::new(MemoryManager::new());
+        let manager = MemoryManager::for_query(parent.clone(), Some(10));
+
+        let result = manager.request_bytes(11).await;
+        assert!(
+            matches!(result, Err(DaftError::ComputeError(msg)) if msg.contains("Memory limit exceeded"))
+        );
+
+        // Reservations on the query manager are also held on the parent until released.
+        let permit = manager.request_bytes(10).await.unwrap();
+        {
+            let state = parent.state.lock().unwrap();
+            assert_eq!(state.available_bytes, parent.total_bytes - 10);
+        }
+        drop(permit);
+        {
+            let state = parent.state.lock().unwrap();
+            assert_eq!(state.available_bytes, parent.total_bytes);
+        }
+        assert_eq!(manager.peak_reserved_bytes(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_query_memory_limit_on_concurrent_reservations() {
+        let parent = Arc::new(MemoryManager::new());
+        let manager = Arc::new(MemoryManager::for_query(parent.clone(), Some(10)));
+
+        let permit1 = manager.request_bytes(6).await.unwrap();
+        // Each request fits in the limit, but not together with the reservation that's still
+        // held, so the second one waits for the first to be released instead of failing.
+        let manager_clone = manager.clone();
+        let wait_handle = tokio::spawn(async move {
+            let _permit = manager_clone.request_bytes(6).await.unwrap();
+        });
+        time::sleep(Duration::from_millis(50)).await;
+        assert!(!wait_handle.is_finished());
+        // The waiting request doesn't hold any bytes on the parent.
+        {
+            let state = parent.state.lock().unwrap();
+            assert_eq!(state.available_bytes, parent.total_bytes - 6);
+        }
+
+        drop(permit1);
+        wait_handle.await.unwrap();
+        assert_eq!(manager.peak_reserved_bytes(), 6);
+    }
+
+    #[test]
+    fn test_peak_held_bytes() -> DaftResult<()> {
+        let manager = MemoryManager::new();
+        let morsel = |n: i64| -> DaftResult<Arc<MicroPartition>> {
+            let table = RecordBatch::from_nonempty_columns(vec![Int64Array::from((
+                "a",
+                (0..n).collect::<Vec<_>>(),
+            ))
+            .into_series()])?;
+            Ok(Arc::new(MicroPartition::new_loaded(
+                table.schema.clone(),
+                Arc::new(vec![table]),
+                None,
+            )))
+        };
+        let morsel1 = morsel(10)?;
+        let morsel2 = morsel(20)?;
+        let bytes1 = morsel1.size_bytes()?.unwrap() as u64;
+        let bytes2 = morsel2.size_bytes()?.unwrap() as u64;
+
+        manager.track_morsel(&morsel1);
+        manager.track_morsel(&morsel2);
+        assert_eq!(manager.peak_held_bytes(), bytes1 + bytes2);
+
+        // Dropped morsels are no longer counted as held.
+        drop(morsel1);
+        drop(morsel2);
+        let morsel3 = morsel(5)?;
+        manager.track_morsel(&morsel3);
+        assert_eq!(
+            manager.held_morsels.lock().unwrap().held_bytes,
+            morsel3.size_bytes()?.unwrap() as u64
+        );
+        assert_eq!(manager.peak_held_bytes(), bytes1 + bytes2);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_waiting_for_memory() {
         let manager = Arc::new(MemoryManager::new());
@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use common_daft_config::DaftExecutionConfig;
+use common_error::DaftError;
+use daft_scan::ScanTask;
+
+/// How an operator retries work that failed with a transient error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RetryPolicy {
+    /// The total number of attempts, including the first one.
+    pub max_attempts: usize,
+    /// The time to wait before the first retry, which doubles with every further retry.
+    pub initial_backoff: Duration,
+}
+
+impl RetryPolicy {
+    const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+    /// Scan tasks are only retried if the scan they belong to asks for it, since the IO layer already
+    /// retries individual requests.
+    pub fn for_scan_task(scan_task: &ScanTask, cfg: &DaftExecutionConfig) -> Self {
+        Self {
+            max_attempts: scan_task.storage_config.scan_task_max_attempts.max(1),
+            initial_backoff: Duration::from_millis(cfg.scan_task_retry_backoff_ms),
+        }
+    }
+
+    /// Whether an operation that failed with `err` on attempt number `attempt` (starting at 1)
+    /// should be tried again.
+    pub fn should_retry(&self, attempt: usize, err: &DaftError) -> bool {
+        attempt < self.max_attempts && is_retryable(err)
+    }
+
+    /// The time to wait after attempt number `attempt` (starting at 1) failed.
+    pub fn backoff(&self, attempt: usize) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16) as u32;
+        self.initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(Self::MAX_BACKOFF)
+    }
+}
+
+/// Whether `err` is likely to go away if the failed operation is tried again, e.g. timeouts,
+/// dropped connections and throttling or server errors from object storage.
+pub(crate) fn is_retryable(err: &DaftError) -> bool {
+    use std::io::ErrorKind;
+
+    match err {
+        DaftError::ConnectTimeout(_)
+        | DaftError::ReadTimeout(_)
+        | DaftError::ByteStreamError(_)
+        | DaftError::SocketError(_)
+        | DaftError::ThrottledIo(_)
+        | DaftError::MiscTransient(_) => true,
+        DaftError::IoError(err) => matches!(
+            err.kind(),
+            ErrorKind::ConnectionRefused
+                | ErrorKind::ConnectionReset
+                | ErrorKind::ConnectionAborted
+                | ErrorKind::BrokenPipe
+                | ErrorKind::TimedOut
+                | ErrorKind::Interrupted
+                | ErrorKind::UnexpectedEof
+        ),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use common_error::DaftError;
+
+    use super::{is_retryable, RetryPolicy};
+
+    #[test]
+    fn test_retryable_errors() {
+        assert!(is_retryable(&DaftError::MiscTransient("500".into())));
+        assert!(is_retryable(&DaftError::ThrottledIo("503".into())));
+        assert!(is_retryable(&DaftError::IoError(std::io::Error::from(
+            std::io::ErrorKind::ConnectionReset
+        ))));
+        assert!(!is_retryable(&DaftError::IoError(std::io::Error::from(
+            std::io::ErrorKind::PermissionDenied
+        ))));
+        assert!(!is_retryable(&DaftError::FileNotFound {
+            path: "s3://bucket/file.parquet".to_string(),
+            source: "404".into(),
+        }));
+        assert!(!is_retryable(&DaftError::ValueError("bad".to_string())));
+    }
+
+    #[test]
+    fn test_retry_policy() {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+        };
+        let err = DaftError::SocketError("connection closed".into());
+        assert!(policy.should_retry(1, &err));
+        assert!(policy.should_retry(2, &err));
+        assert!(!policy.should_retry(3, &err));
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(100), RetryPolicy::MAX_BACKOFF);
+    }
+}
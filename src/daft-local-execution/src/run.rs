@@ -10,7 +10,7 @@ use common_daft_config::DaftExecutionConfig;
 use common_display::{mermaid::MermaidDisplayOptions, DisplayLevel};
 use common_error::DaftResult;
 use common_tracing::refresh_chrome_trace;
-use daft_local_plan::translate;
+use daft_local_plan::{translate, LocalPhysicalPlanRef};
 use daft_logical_plan::LogicalPlanBuilder;
 use daft_micropartition::{
     partitioning::{InMemoryPartitionSetCache, MicroPartitionSet, PartitionSetCache},
@@ -30,9 +30,13 @@ use {
 
 use crate::{
     channel::{create_channel, Receiver},
-    pipeline::{physical_plan_to_pipeline, viz_pipeline_ascii, viz_pipeline_mermaid},
+    iterate::without_iterations,
+    pipeline::{
+        physical_plan_to_pipeline, viz_pipeline_ascii, viz_pipeline_json, viz_pipeline_mermaid,
+    },
     progress_bar::{make_progress_bar_manager, ProgressBarManager},
-    resource_manager::get_or_init_memory_manager,
+    resource_manager::{get_or_init_memory_manager, MemoryManager},
+    runner::PartitionSetKeys,
     Error, ExecutionRuntimeContext,
 };
 
@@ -147,6 +151,17 @@ impl PyNativeExecutor {
             .executor
             .repr_mermaid(&logical_plan_builder.builder, cfg.config, options))
     }
+
+    pub fn repr_json(
+        &self,
+        logical_plan_builder: &PyLogicalPlanBuilder,
+        cfg: PyDaftExecutionConfig,
+        simple: bool,
+    ) -> PyResult<String> {
+        Ok(self
+            .executor
+            .repr_json(&logical_plan_builder.builder, cfg.config, simple))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -188,18 +203,37 @@ impl NativeExecutor {
         self
     }
 
+    /// Runs an optimized logical plan.
+    ///
+    /// The iterations of any [`Iterate`](daft_logical_plan::ops::Iterate) in the plan are run first, one after
+    /// another, and the plan is then run on their results.
     pub fn run(
         &self,
         logical_plan_builder: &LogicalPlanBuilder,
-        psets: &(impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized),
+        psets: &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
         cfg: Arc<DaftExecutionConfig>,
         results_buffer_size: Option<usize>,
     ) -> DaftResult<ExecutionEngineResult> {
-        let logical_plan = logical_plan_builder.build();
+        // The pipeline holds on to the results of the iterations once it's been built.
+        let mut iteration_results = PartitionSetKeys::new(psets);
+        let logical_plan = self
+            .run_iterations(logical_plan_builder, psets, &cfg, &mut iteration_results)?
+            .build();
         let physical_plan = translate(&logical_plan)?;
+        self.run_local_plan(&physical_plan, psets, cfg, results_buffer_size)
+    }
+
+    /// Runs an already translated local physical plan.
+    pub fn run_local_plan(
+        &self,
+        physical_plan: &LocalPhysicalPlanRef,
+        psets: &(impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized),
+        cfg: Arc<DaftExecutionConfig>,
+        results_buffer_size: Option<usize>,
+    ) -> DaftResult<ExecutionEngineResult> {
         refresh_chrome_trace();
         let cancel = self.cancel.clone();
-        let pipeline = physical_plan_to_pipeline(&physical_plan, psets, &cfg)?;
+        let pipeline = physical_plan_to_pipeline(physical_plan, psets, &cfg)?;
         let (tx, rx) = create_channel(results_buffer_size.unwrap_or(0));
 
         let rt = self.runtime.clone();
@@ -217,9 +251,13 @@ impl NativeExecutor {
                 )
             });
             let execution_task = async {
-                let memory_manager = get_or_init_memory_manager();
+                let memory_manager = Arc::new(MemoryManager::for_query(
+                    get_or_init_memory_manager().clone(),
+                    cfg.memory_limit_bytes.map(|limit| limit as u64),
+                ));
                 let mut runtime_handle = ExecutionRuntimeContext::new(
                     cfg.default_morsel_size,
+                    cfg.default_morsel_size_bytes,
                     memory_manager.clone(),
                     pb_manager,
                 );
@@ -227,7 +265,11 @@ impl NativeExecutor {
 
                 while let Some(val) = receiver.recv().await {
                     if tx.send(val).await.is_err() {
-                        break;
+                        // The consumer stopped iterating early (e.g. a `head()`), so stop the
+                        // pipeline instead of computing the rest of the result.
+                        drop(receiver);
+                        runtime_handle.shutdown().await;
+                        return Ok(());
                     }
                 }
 
@@ -244,6 +286,11 @@ impl NativeExecutor {
                         _ => {}
                     }
                 }
+                log::info!(
+                    "Peak memory reserved by query: {} bytes, peak memory held by its operators: {} bytes",
+                    memory_manager.peak_reserved_bytes(),
+                    memory_manager.peak_held_bytes()
+                );
                 if enable_explain_analyze {
                     let curr_ms = SystemTime::now()
                         .duration_since(UNIX_EPOCH)
@@ -261,6 +308,12 @@ impl NativeExecutor {
                             Default::default()
                         )
                     )?;
+                    writeln!(
+                        file,
+                        "\nPeak memory reserved: {} bytes\nPeak memory held: {} bytes",
+                        memory_manager.peak_reserved_bytes(),
+                        memory_manager.peak_held_bytes()
+                    )?;
                 }
                 Ok(())
             };
@@ -294,7 +347,7 @@ impl NativeExecutor {
         cfg: Arc<DaftExecutionConfig>,
         simple: bool,
     ) -> String {
-        let logical_plan = logical_plan_builder.build();
+        let logical_plan = without_iterations(logical_plan_builder.build());
         let physical_plan = translate(&logical_plan).unwrap();
         let pipeline_node =
             physical_plan_to_pipeline(&physical_plan, &InMemoryPartitionSetCache::empty(), &cfg)
@@ -309,7 +362,7 @@ impl NativeExecutor {
         cfg: Arc<DaftExecutionConfig>,
         options: MermaidDisplayOptions,
     ) -> String {
-        let logical_plan = logical_plan_builder.build();
+        let logical_plan = without_iterations(logical_plan_builder.build());
         let physical_plan = translate(&logical_plan).unwrap();
         let pipeline_node =
             physical_plan_to_pipeline(&physical_plan, &InMemoryPartitionSetCache::empty(), &cfg)
@@ -327,6 +380,21 @@ impl NativeExecutor {
             options.subgraph_options,
         )
     }
+
+    fn repr_json(
+        &self,
+        logical_plan_builder: &LogicalPlanBuilder,
+        cfg: Arc<DaftExecutionConfig>,
+        simple: bool,
+    ) -> String {
+        let logical_plan = without_iterations(logical_plan_builder.build());
+        let physical_plan = translate(&logical_plan).unwrap();
+        let pipeline_node =
+            physical_plan_to_pipeline(&physical_plan, &InMemoryPartitionSetCache::empty(), &cfg)
+                .unwrap();
+
+        viz_pipeline_json(pipeline_node.as_ref(), simple)
+    }
 }
 
 impl Drop for NativeExecutor {
@@ -438,3 +506,88 @@ impl IntoIterator for ExecutionEngineResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+
+    use common_daft_config::DaftExecutionConfig;
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{binary_op, lit, resolved_col, Operator};
+    use daft_logical_plan::LogicalPlanBuilder;
+    use daft_micropartition::{
+        partitioning::{
+            InMemoryPartitionSetCache, MicroPartitionSet, PartitionCacheEntry, PartitionSet,
+            PartitionSetCache,
+        },
+        MicroPartition,
+    };
+    use daft_recordbatch::RecordBatch;
+
+    use crate::NativeExecutor;
+
+    #[test]
+    fn test_dropping_results_stops_execution() -> DaftResult<()> {
+        let num_partitions = 10_000;
+        let batch = RecordBatch::from_nonempty_columns(vec![
+            Int64Array::from(("x", vec![1])).into_series()
+        ])?;
+        let schema = batch.schema.clone();
+        let part = Arc::new(MicroPartition::new_loaded(
+            schema.clone(),
+            Arc::new(vec![batch]),
+            None,
+        ));
+        let pset = Arc::new(MicroPartitionSet::from(vec![part; num_partitions]));
+        let psets = InMemoryPartitionSetCache::empty();
+        psets.put_partition_set("input", &pset);
+        let plan = LogicalPlanBuilder::in_memory_scan(
+            "input",
+            PartitionCacheEntry::new_rust("input".to_string(), pset.clone()),
+            schema,
+            pset.num_partitions(),
+            pset.size_bytes()?,
+            num_partitions,
+        )?
+        .select(vec![
+            binary_op(Operator::Plus, resolved_col("x"), lit(1)).alias("x")
+        ])?
+        .optimize()?;
+
+        // Every partition is its own morsel, and only one result is buffered, so the pipeline can't get far ahead of
+        // the consumer.
+        let cfg = DaftExecutionConfig {
+            default_morsel_size: 1,
+            ..Default::default()
+        };
+        let held = Arc::strong_count(&pset);
+        let mut results = NativeExecutor::new()
+            .run(&plan, &psets, Arc::new(cfg), Some(1))?
+            .into_iter();
+        let first = results.next().expect("expected a result")?;
+        assert_eq!(first.len(), 1);
+
+        // Stop after the first result, like a `head()` would.
+        let handle = results.handle.take().unwrap();
+        drop(results);
+
+        // The executor stops instead of running the rest of the pipeline for a consumer that's gone.
+        let deadline = Instant::now() + Duration::from_secs(30);
+        while !handle.is_finished() {
+            assert!(
+                Instant::now() < deadline,
+                "execution didn't stop after its results were dropped"
+            );
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        handle.join().expect("Execution engine thread panicked")?;
+
+        // The pipeline has released its input, although most of it was never read.
+        assert_eq!(Arc::strong_count(&pset), held);
+        Ok(())
+    }
+}
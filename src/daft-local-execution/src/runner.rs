@@ -0,0 +1,663 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+
+use common_daft_config::DaftExecutionConfig;
+use common_error::{DaftError, DaftResult};
+use common_system_info::SystemInfo;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::ExprRef;
+use daft_local_plan::translate_physical;
+use daft_logical_plan::{
+    partitioning::{ClusteringSpec, HashClusteringConfig},
+    InMemoryInfo, LogicalPlanRef,
+};
+use daft_micropartition::{
+    partitioning::{MicroPartitionSet, PartitionCacheEntry, PartitionSetCache},
+    MicroPartitionRef,
+};
+use daft_physical_plan::{
+    logical_to_physical,
+    ops::{ShuffleExchange, ShuffleExchangeStrategy},
+    split_into_stages, AdaptivePlanner, MaterializedResults, PhysicalPlan, PhysicalPlanRef,
+    QueryStageOutput, StageId, StageOutput, StageStats,
+};
+use daft_shuffles::{
+    reduce_merge, InMemoryShuffleService, ShuffleId, ShuffleService, ShuffleServiceRef,
+};
+
+use crate::NativeExecutor;
+
+/// An iterator over the partitions produced by a submitted plan.
+pub type PartitionIterator = Box<dyn Iterator<Item = DaftResult<MicroPartitionRef>> + Send>;
+
+/// The resources available to a runner on one of its nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeResources {
+    pub node_id: String,
+    pub num_cpus: usize,
+    pub num_gpus: usize,
+    pub memory_bytes: usize,
+}
+
+impl NodeResources {
+    /// The resources of the machine this process is running on.
+    pub fn local() -> Self {
+        let system_info = SystemInfo::default();
+        Self {
+            node_id: "local".to_string(),
+            num_cpus: system_info
+                .calculate_cpu_count()
+                .map_or(*crate::NUM_CPUS, |cpus| cpus as usize),
+            num_gpus: 0,
+            memory_bytes: system_info.calculate_total_memory() as usize,
+        }
+    }
+}
+
+/// A backend that executes physical plans.
+///
+/// Plans are submitted one stage at a time, so a runner only needs to know how to execute a
+/// (serialized) physical plan and hand back its partitions; [`PlanRunner::execute`] takes care of
+/// splitting a query into stages and feeding each stage's results into the next one.
+///
+/// This API is experimental: none of the python runners execute their queries through it yet, and
+/// the native runner runs whole local plans with [`NativeExecutor::run`] instead.
+///
+/// Not to be confused with `daft_py_runners::Runner`, which is the python runner a context
+/// executes its queries with.
+pub trait PlanRunner: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Submits a physical plan (e.g. a single stage) for execution, returning its partitions.
+    ///
+    /// In-memory scans in the plan are resolved against `psets`.
+    fn submit(
+        &self,
+        plan: PhysicalPlanRef,
+        psets: &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+        cfg: Arc<DaftExecutionConfig>,
+    ) -> DaftResult<PartitionIterator>;
+
+    /// Submits a physical plan and waits for all of its partitions.
+    fn collect(
+        &self,
+        plan: PhysicalPlanRef,
+        psets: &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+        cfg: Arc<DaftExecutionConfig>,
+    ) -> DaftResult<Vec<MicroPartitionRef>> {
+        self.submit(plan, psets, cfg)?.collect()
+    }
+
+    /// The resources of each node that plans can be scheduled on.
+    fn node_resources(&self) -> Vec<NodeResources>;
+
+    /// The service that the outputs of hash partitioned stages are shuffled through, which is
+    /// asked for once per query. Defaults to a new in-memory service.
+    fn shuffle_service(&self) -> ShuffleServiceRef {
+        Arc::new(InMemoryShuffleService::default())
+    }
+
+    /// Runs a logical plan to completion, submitting one stage at a time.
+    ///
+    /// With AQE, each stage is planned after the stages before it have run, using their
+    /// statistics; otherwise the physical plan is split into a
+    /// [`StageGraph`](daft_physical_plan::StageGraph) up front. The
+    /// results of each intermediate stage are put into `psets` until the final stage has been
+    /// submitted, and the final stage's partitions are returned.
+    fn execute(
+        &self,
+        logical_plan: LogicalPlanRef,
+        psets: &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+        cfg: Arc<DaftExecutionConfig>,
+    ) -> DaftResult<PartitionIterator> {
+        if cfg.enable_aqe {
+            execute_adaptive(self, logical_plan, psets, cfg)
+        } else {
+            execute_stage_graph(self, logical_plan, psets, cfg)
+        }
+    }
+}
+
+/// Used to keep the partition set keys and shuffles of concurrently executed stage graphs apart.
+static STAGE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// The keys of the partition sets that a query put into a cache, which are removed from it when
+/// this is dropped, whether the query succeeded or not.
+pub(crate) struct PartitionSetKeys<'a> {
+    psets: &'a dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+    keys: Vec<String>,
+}
+
+impl<'a> PartitionSetKeys<'a> {
+    pub(crate) fn new(
+        psets: &'a dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+    ) -> Self {
+        Self {
+            psets,
+            keys: vec![],
+        }
+    }
+
+    /// Removes the partition set under `key` when this is dropped.
+    pub(crate) fn push(&mut self, key: String) {
+        self.keys.push(key);
+    }
+}
+
+impl Drop for PartitionSetKeys<'_> {
+    fn drop(&mut self) {
+        for key in &self.keys {
+            self.psets.rm_partition_set(key);
+        }
+    }
+}
+
+fn execute_adaptive<R: PlanRunner + ?Sized>(
+    runner: &R,
+    logical_plan: LogicalPlanRef,
+    psets: &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+    cfg: Arc<DaftExecutionConfig>,
+) -> DaftResult<PartitionIterator> {
+    let mut planner = AdaptivePlanner::new(logical_plan, cfg.clone());
+    // The final stage's in-memory scans hold on to the stage results themselves, so they're removed
+    // from `psets` once it has been submitted, or as soon as a stage fails.
+    let mut stage_keys = PartitionSetKeys::new(psets);
+    loop {
+        match planner.next_stage()? {
+            QueryStageOutput::Partial {
+                physical_plan,
+                stage_id,
+            } => {
+                let stage_id = stage_id.ok_or_else(|| {
+                    DaftError::InternalError(
+                        "Expected partial stage to have a stage id".to_string(),
+                    )
+                })?;
+                let start = Instant::now();
+                let key = format!("stage-{stage_id}");
+                stage_keys.push(key.clone());
+                let in_memory_info =
+                    materialize_stage(runner, physical_plan, key, stage_id, psets, cfg.clone())?;
+
+                planner.update_stats(
+                    StageStats {
+                        time_taken: start.elapsed(),
+                        size_bytes: Some(in_memory_info.size_bytes),
+                        num_rows: Some(in_memory_info.num_rows),
+                    },
+                    Some(stage_id),
+                )?;
+                planner.update(MaterializedResults {
+                    stage_id,
+                    in_memory_info,
+                })?;
+            }
+            QueryStageOutput::Final { physical_plan } => {
+                return runner.submit(physical_plan, psets, cfg);
+            }
+        }
+    }
+}
+
+fn execute_stage_graph<R: PlanRunner + ?Sized>(
+    runner: &R,
+    logical_plan: LogicalPlanRef,
+    psets: &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+    cfg: Arc<DaftExecutionConfig>,
+) -> DaftResult<PartitionIterator> {
+    let physical_plan = logical_to_physical(logical_plan, cfg.clone())?;
+    let graph = split_into_stages(&physical_plan)?;
+    let shuffle_service = runner.shuffle_service();
+
+    let mut outputs = HashMap::new();
+    // See `execute_adaptive`.
+    let mut stage_keys = PartitionSetKeys::new(psets);
+    let (root, stages) = graph
+        .stages()
+        .split_last()
+        .expect("StageGraph should have at least one stage");
+    for stage in stages {
+        let id = STAGE_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let key = format!("stage-graph-{id}");
+        stage_keys.push(key.clone());
+        let plan = stage.plan_with_inputs(&outputs)?;
+        let in_memory_info = match &stage.output {
+            StageOutput::Shuffle(strategy) => match hash_shuffle_target(strategy) {
+                Some(HashClusteringConfig { by, num_partitions }) => shuffle_stage(
+                    runner,
+                    plan,
+                    (by.as_slice(), *num_partitions),
+                    (shuffle_service.as_ref(), id),
+                    key,
+                    stage.id,
+                    psets,
+                    cfg.clone(),
+                )?,
+                // Other exchanges are left to the runner.
+                None => materialize_stage(
+                    runner,
+                    PhysicalPlan::ShuffleExchange(ShuffleExchange {
+                        input: plan,
+                        strategy: strategy.clone(),
+                    })
+                    .arced(),
+                    key,
+                    stage.id,
+                    psets,
+                    cfg.clone(),
+                )?,
+            },
+            StageOutput::Result => {
+                materialize_stage(runner, plan, key, stage.id, psets, cfg.clone())?
+            }
+        };
+        outputs.insert(stage.id, in_memory_info);
+    }
+    runner.submit(root.plan_with_inputs(&outputs)?, psets, cfg)
+}
+
+/// Runs an intermediate stage, putting its results into `psets` under `key`.
+fn materialize_stage<R: PlanRunner + ?Sized>(
+    runner: &R,
+    plan: PhysicalPlanRef,
+    key: String,
+    stage_id: StageId,
+    psets: &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+    cfg: Arc<DaftExecutionConfig>,
+) -> DaftResult<InMemoryInfo> {
+    let schema = translate_physical(&plan)?.schema().clone();
+    let parts = runner.collect(plan, psets, cfg)?;
+    put_stage_output(parts, schema, key, stage_id, psets)
+}
+
+/// The hash partitioning that a shuffle exchange repartitions its input into, if it does.
+fn hash_shuffle_target(strategy: &ShuffleExchangeStrategy) -> Option<&HashClusteringConfig> {
+    match strategy {
+        ShuffleExchangeStrategy::NaiveFullyMaterializingMapReduce { target_spec }
+        | ShuffleExchangeStrategy::MapReduceWithPreShuffleMerge { target_spec, .. } => {
+            match target_spec.as_ref() {
+                ClusteringSpec::Hash(config) => Some(config),
+                _ => None,
+            }
+        }
+        ShuffleExchangeStrategy::SplitOrCoalesceToTargetNum { .. } => None,
+    }
+}
+
+/// Runs an intermediate stage whose output is hash partitioned by `by` into `num_partitions`
+/// partitions, exchanging it through `shuffle_service` as shuffle `shuffle_id`.
+///
+/// Each partition of the stage's output is split up and written to the service as it is
+/// produced, and the reduce partitions are put into `psets` under `key`.
+#[allow(clippy::too_many_arguments)]
+fn shuffle_stage<R: PlanRunner + ?Sized>(
+    runner: &R,
+    plan: PhysicalPlanRef,
+    (by, num_partitions): (&[ExprRef], usize),
+    (shuffle_service, shuffle_id): (&dyn ShuffleService, ShuffleId),
+    key: String,
+    stage_id: StageId,
+    psets: &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+    cfg: Arc<DaftExecutionConfig>,
+) -> DaftResult<InMemoryInfo> {
+    let schema = translate_physical(&plan)?.schema().clone();
+    let reduce_parts = runner
+        .submit(plan, psets, cfg)?
+        .enumerate()
+        .try_for_each(|(map_id, part)| {
+            let map_output = part?
+                .partition_by_hash(by, num_partitions)?
+                .into_iter()
+                .map(Arc::new)
+                .collect();
+            shuffle_service.write_map_output(shuffle_id, map_id, map_output)
+        })
+        .and_then(|()| {
+            (0..num_partitions)
+                .map(|reduce_id| {
+                    reduce_merge(shuffle_service, shuffle_id, reduce_id, schema.clone())
+                        .map(Arc::new)
+                })
+                .collect::<DaftResult<Vec<_>>>()
+        });
+    shuffle_service.remove_shuffle(shuffle_id)?;
+    put_stage_output(reduce_parts?, schema, key, stage_id, psets)
+}
+
+fn put_stage_output(
+    parts: Vec<MicroPartitionRef>,
+    schema: SchemaRef,
+    key: String,
+    stage_id: StageId,
+    psets: &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+) -> DaftResult<InMemoryInfo> {
+    let num_partitions = parts.len();
+    let num_rows = parts.iter().map(|part| part.len()).sum();
+    let size_bytes = parts
+        .iter()
+        .map(|part| Ok(part.size_bytes()?.unwrap_or(0)))
+        .sum::<DaftResult<usize>>()?;
+    let pset = Arc::new(MicroPartitionSet::from(parts));
+    psets.put_partition_set(&key, &pset);
+    Ok(InMemoryInfo::new(
+        schema,
+        key.clone(),
+        Some(PartitionCacheEntry::new_rust(key, pset)),
+        num_partitions,
+        size_bytes,
+        num_rows,
+        None,
+        Some(stage_id),
+    ))
+}
+
+/// The native executor runs every stage on the local machine.
+///
+/// Like the trait itself, this is experimental and so far only exercised by its tests.
+impl PlanRunner for NativeExecutor {
+    fn name(&self) -> &'static str {
+        "native"
+    }
+
+    fn submit(
+        &self,
+        plan: PhysicalPlanRef,
+        psets: &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+        cfg: Arc<DaftExecutionConfig>,
+    ) -> DaftResult<PartitionIterator> {
+        let local_plan = translate_physical(&plan)?;
+        let results = self.run_local_plan(&local_plan, psets, cfg, None)?;
+        Ok(Box::new(results.into_iter()))
+    }
+
+    fn node_resources(&self) -> Vec<NodeResources> {
+        vec![NodeResources::local()]
+    }
+
+    /// All shuffled partitions are held in this process's memory between the map and reduce
+    /// sides of a shuffle, so they're kept compressed until they're fetched.
+    fn shuffle_service(&self) -> ShuffleServiceRef {
+        Arc::new(InMemoryShuffleService::compressed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use common_daft_config::DaftExecutionConfig;
+    use common_error::{DaftError, DaftResult};
+    use daft_core::prelude::*;
+    use daft_dsl::resolved_col;
+    use daft_logical_plan::{JoinOptions, JoinType, LogicalPlanBuilder};
+    use daft_micropartition::{
+        partitioning::{
+            InMemoryPartitionSetCache, MicroPartitionSet, PartitionCacheEntry, PartitionSet,
+            PartitionSetCache,
+        },
+        MicroPartitionRef,
+    };
+    use daft_physical_plan::PhysicalPlanRef;
+    use daft_recordbatch::RecordBatch;
+    use daft_shuffles::{
+        InMemoryShuffleService, ReduceInputIterator, ShuffleId, ShuffleService, ShuffleServiceRef,
+    };
+
+    use super::{NodeResources, PartitionIterator, PlanRunner};
+    use crate::NativeExecutor;
+
+    /// An in-memory shuffle service that counts the map outputs written to it and the shuffles
+    /// removed from it.
+    #[derive(Debug, Default)]
+    struct CountingShuffleService {
+        inner: InMemoryShuffleService,
+        num_map_outputs: AtomicUsize,
+        num_removed_shuffles: AtomicUsize,
+    }
+
+    impl ShuffleService for CountingShuffleService {
+        fn write_map_output(
+            &self,
+            shuffle_id: ShuffleId,
+            map_id: usize,
+            partitions: Vec<MicroPartitionRef>,
+        ) -> DaftResult<()> {
+            self.num_map_outputs.fetch_add(1, Ordering::SeqCst);
+            self.inner.write_map_output(shuffle_id, map_id, partitions)
+        }
+
+        fn fetch_reduce_input(
+            &self,
+            shuffle_id: ShuffleId,
+            reduce_id: usize,
+        ) -> DaftResult<ReduceInputIterator> {
+            self.inner.fetch_reduce_input(shuffle_id, reduce_id)
+        }
+
+        fn remove_shuffle(&self, shuffle_id: ShuffleId) -> DaftResult<()> {
+            self.num_removed_shuffles.fetch_add(1, Ordering::SeqCst);
+            self.inner.remove_shuffle(shuffle_id)
+        }
+    }
+
+    /// The native executor, shuffling through a [`CountingShuffleService`].
+    struct CountingRunner {
+        executor: NativeExecutor,
+        shuffle_service: Arc<CountingShuffleService>,
+    }
+
+    impl PlanRunner for CountingRunner {
+        fn name(&self) -> &'static str {
+            "counting"
+        }
+
+        fn submit(
+            &self,
+            plan: PhysicalPlanRef,
+            psets: &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+            cfg: Arc<DaftExecutionConfig>,
+        ) -> DaftResult<PartitionIterator> {
+            self.executor.submit(plan, psets, cfg)
+        }
+
+        fn node_resources(&self) -> Vec<NodeResources> {
+            self.executor.node_resources()
+        }
+
+        fn shuffle_service(&self) -> ShuffleServiceRef {
+            self.shuffle_service.clone()
+        }
+    }
+
+    /// The native executor, failing every submission after the first `num_successes`.
+    struct FailingRunner {
+        executor: NativeExecutor,
+        num_successes: usize,
+        num_submits: AtomicUsize,
+    }
+
+    impl PlanRunner for FailingRunner {
+        fn name(&self) -> &'static str {
+            "failing"
+        }
+
+        fn submit(
+            &self,
+            plan: PhysicalPlanRef,
+            psets: &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+            cfg: Arc<DaftExecutionConfig>,
+        ) -> DaftResult<PartitionIterator> {
+            if self.num_submits.fetch_add(1, Ordering::SeqCst) >= self.num_successes {
+                return Err(DaftError::InternalError("Stage failed".to_string()));
+            }
+            self.executor.submit(plan, psets, cfg)
+        }
+
+        fn node_resources(&self) -> Vec<NodeResources> {
+            self.executor.node_resources()
+        }
+    }
+
+    fn in_memory_table(
+        psets: &InMemoryPartitionSetCache,
+        key: &str,
+        columns: Vec<Series>,
+    ) -> DaftResult<LogicalPlanBuilder> {
+        let batch = RecordBatch::from_nonempty_columns(columns)?;
+        let schema = batch.schema.clone();
+        let num_rows = batch.len();
+        let pset = Arc::new(MicroPartitionSet::from_tables(0, vec![batch])?);
+        psets.put_partition_set(key, &pset);
+        LogicalPlanBuilder::in_memory_scan(
+            key,
+            PartitionCacheEntry::new_rust(key.to_string(), pset.clone()),
+            schema,
+            pset.num_partitions(),
+            pset.size_bytes()?,
+            num_rows,
+        )
+    }
+
+    #[test]
+    fn test_native_runner_runs_stages() -> DaftResult<()> {
+        let psets = InMemoryPartitionSetCache::empty();
+        let left = in_memory_table(
+            &psets,
+            "left",
+            vec![Int64Array::from(("id", vec![1, 2, 3])).into_series()],
+        )?;
+        let right = in_memory_table(
+            &psets,
+            "right",
+            vec![
+                Int64Array::from(("key", vec![2, 3, 4])).into_series(),
+                Int64Array::from(("value", vec![20, 30, 40])).into_series(),
+            ],
+        )?;
+        let plan = left
+            .join(
+                right,
+                vec![resolved_col("id")],
+                vec![resolved_col("key")],
+                JoinType::Inner,
+                None,
+                JoinOptions::default(),
+            )?
+            .sort(vec![resolved_col("id")], vec![false], vec![false])?
+            .optimize()?
+            .build();
+
+        let runner = NativeExecutor::new();
+        assert_eq!(runner.node_resources().len(), 1);
+
+        // Stages are planned adaptively with AQE, and split into a stage graph up front without.
+        for enable_aqe in [true, false] {
+            let cfg = Arc::new(DaftExecutionConfig {
+                enable_aqe,
+                ..Default::default()
+            });
+            let parts = runner
+                .execute(plan.clone(), &psets, cfg)?
+                .collect::<DaftResult<Vec<_>>>()?;
+            let batches = parts
+                .iter()
+                .map(|part| Ok(part.get_tables()?.as_ref().clone()))
+                .collect::<DaftResult<Vec<_>>>()?;
+            let batch = RecordBatch::concat(&batches.concat())?;
+            assert_eq!(
+                batch.get_column("value")?,
+                &Int64Array::from(("value", vec![20, 30])).into_series()
+            );
+            // Only the input tables are left in the cache.
+            assert_eq!(psets.partition_sets.len(), 2);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_failed_stage_removes_stage_outputs() -> DaftResult<()> {
+        let psets = InMemoryPartitionSetCache::empty();
+        let left = in_memory_table(
+            &psets,
+            "left",
+            vec![Int64Array::from(("id", vec![1, 2, 3])).into_series()],
+        )?;
+        let right = in_memory_table(
+            &psets,
+            "right",
+            vec![Int64Array::from(("key", vec![2, 3, 4])).into_series()],
+        )?;
+        // Joined in a later stage than the inputs with AQE, and shuffled in a separate stage without.
+        let plan = left
+            .join(
+                right,
+                vec![resolved_col("id")],
+                vec![resolved_col("key")],
+                JoinType::Inner,
+                None,
+                JoinOptions::default(),
+            )?
+            .hash_repartition(Some(3), vec![resolved_col("id")])?
+            .optimize()?
+            .build();
+
+        for enable_aqe in [true, false] {
+            let cfg = Arc::new(DaftExecutionConfig {
+                enable_aqe,
+                ..Default::default()
+            });
+            // The first stage is materialized, and a later one fails.
+            let runner = FailingRunner {
+                executor: NativeExecutor::new(),
+                num_successes: 1,
+                num_submits: AtomicUsize::new(0),
+            };
+            assert!(runner.execute(plan.clone(), &psets, cfg).is_err());
+            assert!(runner.num_submits.load(Ordering::SeqCst) > 1);
+            // Only the input tables are left in the cache.
+            assert_eq!(psets.partition_sets.len(), 2);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_stage_graph_shuffles_through_service() -> DaftResult<()> {
+        let psets = InMemoryPartitionSetCache::empty();
+        let plan = in_memory_table(
+            &psets,
+            "table",
+            vec![Int64Array::from(("id", vec![1, 2, 3, 4, 5])).into_series()],
+        )?
+        .hash_repartition(Some(3), vec![resolved_col("id")])?
+        .optimize()?
+        .build();
+
+        let shuffle_service = Arc::new(CountingShuffleService::default());
+        let runner = CountingRunner {
+            executor: NativeExecutor::new(),
+            shuffle_service: shuffle_service.clone(),
+        };
+        let parts = runner
+            .execute(plan, &psets, Arc::new(DaftExecutionConfig::default()))?
+            .collect::<DaftResult<Vec<_>>>()?;
+        assert_eq!(parts.iter().map(|part| part.len()).sum::<usize>(), 5);
+
+        // The single input partition is the only map output, and the shuffle is dropped after.
+        assert_eq!(shuffle_service.num_map_outputs.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            shuffle_service.num_removed_shuffles.load(Ordering::SeqCst),
+            1
+        );
+        assert_eq!(psets.partition_sets.len(), 1);
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@ use tracing::{instrument::Instrumented, Instrument};
 use crate::{
     channel::{Receiver, Sender},
     progress_bar::OperatorProgressBar,
+    resource_manager::MemoryManager,
 };
 
 #[derive(Default)]
@@ -158,6 +159,7 @@ impl<F: Future> Future for TimedFuture<F> {
 pub struct CountingSender {
     sender: Sender<Arc<MicroPartition>>,
     rt: Arc<RuntimeStatsContext>,
+    memory_manager: Arc<MemoryManager>,
     progress_bar: Option<Arc<OperatorProgressBar>>,
 }
 
@@ -165,17 +167,20 @@ impl CountingSender {
     pub(crate) fn new(
         sender: Sender<Arc<MicroPartition>>,
         rt: Arc<RuntimeStatsContext>,
+        memory_manager: Arc<MemoryManager>,
         progress_bar: Option<Arc<OperatorProgressBar>>,
     ) -> Self {
         Self {
             sender,
             rt,
+            memory_manager,
             progress_bar,
         }
     }
     #[inline]
     pub(crate) async fn send(&self, v: Arc<MicroPartition>) -> Result<(), SendError> {
         self.rt.mark_rows_emitted(v.len() as u64);
+        self.memory_manager.track_morsel(&v);
         if let Some(ref pb) = self.progress_bar {
             pb.render();
         }
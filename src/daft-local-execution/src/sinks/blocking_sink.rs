@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use common_display::tree::TreeDisplay;
 use common_error::DaftResult;
@@ -6,7 +6,7 @@ use common_runtime::get_compute_runtime;
 use daft_logical_plan::stats::StatsState;
 use daft_micropartition::MicroPartition;
 use snafu::ResultExt;
-use tracing::{info_span, instrument};
+use tracing::{info_span, instrument, Span};
 
 use crate::{
     channel::{create_channel, Receiver},
@@ -27,9 +27,26 @@ pub enum BlockingSinkStatus {
     Finished(Box<dyn BlockingSinkState>),
 }
 
+/// Partitions that a blocking sink produces one at a time when it's finalized, so that its results don't all need to
+/// be held in memory at once.
+pub(crate) trait FinalizedPartitions: Send {
+    /// The number of bytes of memory to reserve while producing the next partition.
+    fn next_memory_request(&self) -> u64;
+
+    /// Produces the next partition, or None if there are no more.
+    fn next_partition(&mut self) -> DaftResult<Option<Arc<MicroPartition>>>;
+}
+
+pub(crate) enum BlockingSinkFinalizeOutput {
+    /// The results of the sink as a single partition, if it has any.
+    Finished(Option<Arc<MicroPartition>>),
+    /// The results of the sink as partitions that are produced as they're sent on. Only the node of the sink takes
+    /// them, but they're behind a mutex because operator outputs must be `Sync`.
+    Partitions(Mutex<Box<dyn FinalizedPartitions>>),
+}
+
 pub(crate) type BlockingSinkSinkResult = OperatorOutput<DaftResult<BlockingSinkStatus>>;
-pub(crate) type BlockingSinkFinalizeResult =
-    OperatorOutput<DaftResult<Option<Arc<MicroPartition>>>>;
+pub(crate) type BlockingSinkFinalizeResult = OperatorOutput<DaftResult<BlockingSinkFinalizeOutput>>;
 pub trait BlockingSink: Send + Sync {
     fn sink(
         &self,
@@ -49,9 +66,10 @@ pub trait BlockingSink: Send + Sync {
         &self,
         runtime_handle: &ExecutionRuntimeContext,
     ) -> Arc<dyn DispatchSpawner> {
-        Arc::new(UnorderedDispatcher::new(Some(
-            runtime_handle.default_morsel_size(),
-        )))
+        Arc::new(
+            UnorderedDispatcher::new(Some(runtime_handle.default_morsel_size()))
+                .with_max_morsel_bytes(runtime_handle.default_morsel_size_bytes()),
+        )
     }
     fn max_concurrency(&self) -> usize;
 }
@@ -142,6 +160,9 @@ impl TreeDisplay for BlockingSinkNode {
                 writeln!(display, "{}", multiline_display).unwrap();
                 if let StatsState::Materialized(stats) = &self.plan_stats {
                     writeln!(display, "Stats = {}", stats).unwrap();
+                    if let Some(semantic_id) = &stats.semantic_id {
+                        writeln!(display, "Semantic ID = {semantic_id}").unwrap();
+                    }
                 }
                 if matches!(level, DisplayLevel::Verbose) {
                     let rt_result = self.runtime_stats.result();
@@ -177,7 +198,13 @@ impl PipelineNode for BlockingSinkNode {
             true,
             self.runtime_stats.clone(),
         );
-        let child_results_receiver = self.child.start(false, runtime_handle)?;
+        // Blocking sinks consume all of their input, so a limit after them doesn't bound the morsels they need.
+        let (child_results_receiver, dispatch_spawner) =
+            runtime_handle.with_morsel_size_limit(None, |runtime_handle| {
+                let child_results_receiver = self.child.start(false, runtime_handle)?;
+                let dispatch_spawner = self.op.dispatch_spawner(runtime_handle);
+                Ok::<_, crate::Error>((child_results_receiver, dispatch_spawner))
+            })?;
         let counting_receiver = CountingReceiver::new(
             child_results_receiver,
             self.runtime_stats.clone(),
@@ -185,14 +212,17 @@ impl PipelineNode for BlockingSinkNode {
         );
 
         let (destination_sender, destination_receiver) = create_channel(0);
-        let counting_sender =
-            CountingSender::new(destination_sender, self.runtime_stats.clone(), progress_bar);
+        let counting_sender = CountingSender::new(
+            destination_sender,
+            self.runtime_stats.clone(),
+            runtime_handle.memory_manager(),
+            progress_bar,
+        );
 
         let op = self.op.clone();
         let runtime_stats = self.runtime_stats.clone();
         let num_workers = op.max_concurrency();
 
-        let dispatch_spawner = op.dispatch_spawner(runtime_handle);
         let spawned_dispatch_result = dispatch_spawner.spawn_dispatch(
             vec![counting_receiver],
             num_workers,
@@ -228,9 +258,35 @@ impl PipelineNode for BlockingSinkNode {
                     runtime_stats.clone(),
                     info_span!("BlockingSink::Finalize"),
                 );
-                let finalized_result = op.finalize(finished_states, &spawner).await??;
-                if let Some(res) = finalized_result {
-                    let _ = counting_sender.send(res).await;
+                match op.finalize(finished_states, &spawner).await?? {
+                    BlockingSinkFinalizeOutput::Finished(Some(res)) => {
+                        let _ = counting_sender.send(res).await;
+                    }
+                    BlockingSinkFinalizeOutput::Finished(None) => {}
+                    BlockingSinkFinalizeOutput::Partitions(partitions) => {
+                        let mut partitions = partitions
+                            .into_inner()
+                            .expect("Finalized partitions should not be poisoned");
+                        loop {
+                            let memory_request = partitions.next_memory_request();
+                            let (rest, next) = spawner
+                                .spawn_with_memory_request(
+                                    memory_request,
+                                    async move {
+                                        let next = partitions.next_partition()?;
+                                        Ok((partitions, next))
+                                    },
+                                    Span::current(),
+                                )
+                                .await??;
+                            partitions = rest;
+                            let Some(partition) = next else { break };
+                            // Stop producing partitions once nothing downstream takes them anymore.
+                            if counting_sender.send(partition).await.is_err() {
+                                break;
+                            }
+                        }
+                    }
                 }
                 Ok(())
             },
@@ -65,13 +65,15 @@ impl StreamingSink for ConcatSink {
         maintain_order: bool,
     ) -> Arc<dyn DispatchSpawner> {
         if maintain_order {
-            Arc::new(RoundRobinDispatcher::new(Some(
-                runtime_handle.default_morsel_size(),
-            )))
+            Arc::new(
+                RoundRobinDispatcher::new(Some(runtime_handle.default_morsel_size()))
+                    .with_max_morsel_bytes(runtime_handle.default_morsel_size_bytes()),
+            )
         } else {
-            Arc::new(UnorderedDispatcher::new(Some(
-                runtime_handle.default_morsel_size(),
-            )))
+            Arc::new(
+                UnorderedDispatcher::new(Some(runtime_handle.default_morsel_size()))
+                    .with_max_morsel_bytes(runtime_handle.default_morsel_size_bytes()),
+            )
         }
     }
 }
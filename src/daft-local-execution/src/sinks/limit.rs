@@ -107,6 +107,10 @@ impl StreamingSink for LimitSink {
         1
     }
 
+    fn morsel_size_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
     fn dispatch_spawner(
         &self,
         _runtime_handle: &ExecutionRuntimeContext,
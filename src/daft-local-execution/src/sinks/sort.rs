@@ -1,34 +1,86 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
+use common_daft_config::DaftExecutionConfig;
 use common_error::DaftResult;
-use daft_dsl::ExprRef;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::{resolved_col, ExprRef};
+use daft_io::IOStatsContext;
 use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+use daft_shuffles::{SpillFile, SpilledBatches};
 use itertools::Itertools;
 use tracing::{instrument, Span};
 
 use super::blocking_sink::{
-    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
-    BlockingSinkStatus,
+    BlockingSink, BlockingSinkFinalizeOutput, BlockingSinkFinalizeResult, BlockingSinkSinkResult,
+    BlockingSinkState, BlockingSinkStatus, FinalizedPartitions,
 };
 use crate::{ExecutionTaskSpawner, NUM_CPUS};
 
 enum SortState {
-    Building(Vec<Arc<MicroPartition>>),
+    Building {
+        parts: Vec<Arc<MicroPartition>>,
+        /// The number of bytes of `parts`.
+        buffered_bytes: usize,
+        /// Sorted runs of the parts that were buffered before, which were spilled to disk.
+        runs: Vec<SpillFile>,
+    },
     Done,
 }
 
 impl SortState {
-    fn push(&mut self, part: Arc<MicroPartition>) {
-        if let Self::Building(ref mut parts) = self {
+    fn push(&mut self, part: Arc<MicroPartition>) -> DaftResult<()> {
+        if let Self::Building {
+            parts,
+            buffered_bytes,
+            ..
+        } = self
+        {
+            *buffered_bytes += part.size_bytes()?.unwrap_or(0);
             parts.push(part);
+            Ok(())
         } else {
             panic!("SortSink should be in Building state");
         }
     }
 
-    fn finalize(&mut self) -> Vec<Arc<MicroPartition>> {
-        let res = if let Self::Building(ref mut parts) = self {
-            std::mem::take(parts)
+    fn buffered_bytes(&self) -> usize {
+        if let Self::Building { buffered_bytes, .. } = self {
+            *buffered_bytes
+        } else {
+            panic!("SortSink should be in Building state");
+        }
+    }
+
+    /// Sorts the buffered parts and spills them to disk as a sorted run.
+    fn spill(&mut self, params: &SortParams) -> DaftResult<()> {
+        if let Self::Building {
+            parts,
+            buffered_bytes,
+            runs,
+        } = self
+        {
+            let sorted = MicroPartition::concat(std::mem::take(parts))?.sort(
+                &params.sort_by,
+                &params.descending,
+                &params.nulls_first,
+            )?;
+            let batches = split_into_batches(&sorted, params.spill_batch_size)?;
+            runs.push(SpillFile::try_new(&MicroPartition::new_loaded(
+                sorted.schema(),
+                Arc::new(batches),
+                None,
+            ))?);
+            *buffered_bytes = 0;
+            Ok(())
+        } else {
+            panic!("SortSink should be in Building state");
+        }
+    }
+
+    fn finalize(&mut self) -> (Vec<Arc<MicroPartition>>, Vec<SpillFile>) {
+        let res = if let Self::Building { parts, runs, .. } = self {
+            (std::mem::take(parts), std::mem::take(runs))
         } else {
             panic!("SortSink should be in Building state");
         };
@@ -43,22 +95,250 @@ impl BlockingSinkState for SortState {
     }
 }
 
+/// The minimum number of rows to split into ranges that are sorted in parallel, below which sorting all of them at once
+/// is faster.
+const MIN_ROWS_TO_SORT_IN_RANGES: usize = 64 * 1024;
+
 struct SortParams {
     sort_by: Vec<ExprRef>,
     descending: Vec<bool>,
     nulls_first: Vec<bool>,
+    /// The number of keys to sample per range when choosing the boundaries of the ranges.
+    oversampling_factor: usize,
+    /// The number of rows of the batches that sorted runs are spilled and read back in.
+    spill_batch_size: usize,
+}
+
+impl SortParams {
+    /// Splits `input` into `num_ranges` contiguous ranges of the sort keys, in sorted order, which can be sorted
+    /// independently. The boundaries of the ranges are chosen from a sample of the keys.
+    fn split_into_ranges(
+        &self,
+        input: MicroPartition,
+        num_ranges: usize,
+    ) -> DaftResult<Vec<MicroPartition>> {
+        // Ranges place nulls where sorting with the default null ordering would put them.
+        if num_ranges <= 1
+            || input.len() < MIN_ROWS_TO_SORT_IN_RANGES
+            || self.nulls_first != self.descending
+        {
+            return Ok(vec![input]);
+        }
+
+        // Like for distributed sorts, the boundaries are chosen from the keys without nulls.
+        let keys = input
+            .sample_by_size(num_ranges * self.oversampling_factor, false, Some(0))?
+            .eval_expression_list(&self.sort_by)?;
+        let key_columns = keys
+            .column_names()
+            .into_iter()
+            .map(resolved_col)
+            .collect::<Vec<_>>();
+        let non_null = key_columns
+            .iter()
+            .map(|c| c.clone().not_null())
+            .reduce(|l, r| l.and(r))
+            .expect("sort should have at least one key");
+        let boundaries = keys
+            .filter(&[non_null])?
+            .sort(&key_columns, &self.descending, &self.nulls_first)?
+            .quantiles(num_ranges)?;
+        let boundaries =
+            boundaries.concat_or_get(IOStatsContext::new("SortSink::split_into_ranges"))?;
+        match boundaries.as_slice() {
+            [boundaries] => input.partition_by_range(&self.sort_by, boundaries, &self.descending),
+            _ => Ok(vec![input]),
+        }
+    }
+}
+
+/// Merges sorted runs into sorted partitions of about `spill_batch_size` rows, holding only about a batch of each run
+/// at a time besides the partition being merged.
+struct SortedRunsMerge {
+    params: Arc<SortParams>,
+    schema: SchemaRef,
+    /// The runs that haven't been read to the end, with the last row read from each of them.
+    unfinished: Vec<(SpilledBatches, RecordBatch)>,
+    /// Rows that were read but not merged yet.
+    pending: Vec<RecordBatch>,
+    /// The number of bytes of the largest batch read from the runs.
+    max_batch_bytes: usize,
+    /// The number of bytes that may be reserved at once, which memory requests are capped at.
+    memory_limit: u64,
+    /// The files of the spilled runs, which are deleted once the merge is dropped.
+    _spill_files: Vec<SpillFile>,
+}
+
+impl SortedRunsMerge {
+    fn try_new(
+        params: Arc<SortParams>,
+        runs: Vec<SpilledBatches>,
+        schema: SchemaRef,
+        memory_limit: u64,
+        spill_files: Vec<SpillFile>,
+    ) -> DaftResult<Self> {
+        let mut max_batch_bytes = 0;
+        let mut unfinished = vec![];
+        let mut pending = vec![];
+        for mut batches in runs {
+            if let Some(batch) = next_batch(&mut batches, &mut max_batch_bytes)? {
+                unfinished.push((batches, last_row(&batch)?));
+                pending.push(batch);
+            }
+        }
+        Ok(Self {
+            params,
+            schema,
+            unfinished,
+            pending,
+            max_batch_bytes,
+            memory_limit,
+            _spill_files: spill_files,
+        })
+    }
+
+    /// Merges the pending rows that sort before all the rows that weren't read yet, and reads the next batch of the run
+    /// that bounded them. Returns None once all the rows were merged.
+    fn merge_step(&mut self) -> DaftResult<Option<RecordBatch>> {
+        let params = &self.params;
+        if self.unfinished.is_empty() {
+            let pending = std::mem::take(&mut self.pending);
+            if pending.is_empty() {
+                return Ok(None);
+            }
+            let merged = RecordBatch::concat(&pending)?.sort(
+                &params.sort_by,
+                &params.descending,
+                &params.nulls_first,
+            )?;
+            return Ok(Some(merged));
+        }
+
+        // The rows of a run that weren't read yet sort after the last row read from it, so all pending rows up to the
+        // smallest of those rows can be merged.
+        let last_rows = RecordBatch::concat(
+            &self
+                .unfinished
+                .iter()
+                .map(|(_, row)| row)
+                .collect::<Vec<_>>(),
+        )?;
+        let smallest = last_rows
+            .argsort(&params.sort_by, &params.descending, &params.nulls_first)?
+            .u64()?
+            .get(0)
+            .expect("there should be a last row for every unfinished run")
+            as usize;
+
+        // Sort a copy of that row along with the pending rows to find the rows that sort before it.
+        self.pending.push(self.unfinished[smallest].1.clone());
+        let candidates = RecordBatch::concat(&self.pending)?;
+        let order = candidates.argsort(&params.sort_by, &params.descending, &params.nulls_first)?;
+        let bound = order
+            .u64()?
+            .as_slice()
+            .iter()
+            .position(|&i| i as usize == candidates.len() - 1)
+            .expect("the copied row should be sorted with the pending rows");
+        let merged = candidates.take(&order.slice(0, bound)?)?;
+        self.pending = vec![candidates.take(&order.slice(bound + 1, order.len())?)?];
+
+        let (batches, last) = &mut self.unfinished[smallest];
+        match next_batch(batches, &mut self.max_batch_bytes)? {
+            Some(batch) => {
+                *last = last_row(&batch)?;
+                self.pending.push(batch);
+            }
+            None => {
+                self.unfinished.swap_remove(smallest);
+            }
+        }
+        Ok(Some(merged))
+    }
+}
+
+impl FinalizedPartitions for SortedRunsMerge {
+    fn next_memory_request(&self) -> u64 {
+        // Merging holds about a batch of every run and of the partition being merged, and sorting copies them.
+        let bytes = 2 * (self.unfinished.len() + 1) * self.max_batch_bytes;
+        (bytes as u64).min(self.memory_limit)
+    }
+
+    fn next_partition(&mut self) -> DaftResult<Option<Arc<MicroPartition>>> {
+        let mut merged = vec![];
+        let mut num_rows = 0;
+        while num_rows < self.params.spill_batch_size {
+            let Some(batch) = self.merge_step()? else {
+                break;
+            };
+            if !batch.is_empty() {
+                num_rows += batch.len();
+                merged.push(batch);
+            }
+        }
+        if merged.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Arc::new(MicroPartition::new_loaded(
+            self.schema.clone(),
+            Arc::new(merged),
+            None,
+        ))))
+    }
 }
+
+/// The next non-empty batch of a sorted run, keeping track of the number of bytes of the largest batch read.
+fn next_batch(
+    batches: &mut SpilledBatches,
+    max_batch_bytes: &mut usize,
+) -> DaftResult<Option<RecordBatch>> {
+    for batch in batches.by_ref() {
+        let batch = batch?;
+        if !batch.is_empty() {
+            *max_batch_bytes = (*max_batch_bytes).max(batch.size_bytes()?);
+            return Ok(Some(batch));
+        }
+    }
+    Ok(None)
+}
+
+fn last_row(batch: &RecordBatch) -> DaftResult<RecordBatch> {
+    batch.slice(batch.len() - 1, batch.len())
+}
+
+/// Splits a partition into record batches of at most `batch_size` rows.
+fn split_into_batches(
+    partition: &MicroPartition,
+    batch_size: usize,
+) -> DaftResult<Vec<RecordBatch>> {
+    let tables = partition.get_tables()?;
+    let mut batches = vec![];
+    for table in tables.iter() {
+        for start in (0..table.len()).step_by(batch_size.max(1)) {
+            batches.push(table.slice(start, (start + batch_size).min(table.len()))?);
+        }
+    }
+    Ok(batches)
+}
+
 pub struct SortSink {
     params: Arc<SortParams>,
 }
 
 impl SortSink {
-    pub fn new(sort_by: Vec<ExprRef>, descending: Vec<bool>, nulls_first: Vec<bool>) -> Self {
+    pub fn new(
+        sort_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        cfg: &DaftExecutionConfig,
+    ) -> Self {
         Self {
             params: Arc::new(SortParams {
                 sort_by,
                 descending,
                 nulls_first,
+                oversampling_factor: cfg.sort_boundary_oversampling_factor,
+                spill_batch_size: cfg.default_morsel_size,
             }),
         }
     }
@@ -70,14 +350,42 @@ impl BlockingSink for SortSink {
         &self,
         input: Arc<MicroPartition>,
         mut state: Box<dyn BlockingSinkState>,
-        _spawner: &ExecutionTaskSpawner,
+        spawner: &ExecutionTaskSpawner,
     ) -> BlockingSinkSinkResult {
-        state
+        let sort_state = state
             .as_any_mut()
             .downcast_mut::<SortState>()
-            .expect("SortSink should have sort state")
-            .push(input);
-        Ok(BlockingSinkStatus::NeedMoreInput(state)).into()
+            .expect("SortSink should have sort state");
+        if let Err(e) = sort_state.push(input) {
+            return Err(e).into();
+        }
+
+        // Every worker may buffer an equal share of the memory of the query, leaving as much again for sorting the
+        // buffered parts, before spilling them to disk as a sorted run.
+        let spill_threshold =
+            spawner.memory_manager().total_bytes() / (2 * self.max_concurrency() as u64);
+        if (sort_state.buffered_bytes() as u64) < spill_threshold {
+            return Ok(BlockingSinkStatus::NeedMoreInput(state)).into();
+        }
+
+        // Sorting the buffered parts copies them, so as much memory is reserved while spilling them, up to the
+        // worker's share so that the reservations of all workers fit in the budget.
+        let memory_request = (sort_state.buffered_bytes() as u64).min(2 * spill_threshold);
+        let params = self.params.clone();
+        spawner
+            .spawn_with_memory_request(
+                memory_request,
+                async move {
+                    state
+                        .as_any_mut()
+                        .downcast_mut::<SortState>()
+                        .expect("SortSink should have sort state")
+                        .spill(&params)?;
+                    Ok(BlockingSinkStatus::NeedMoreInput(state))
+                },
+                Span::current(),
+            )
+            .into()
     }
 
     #[instrument(skip_all, name = "SortSink::finalize")]
@@ -87,23 +395,76 @@ impl BlockingSink for SortSink {
         spawner: &ExecutionTaskSpawner,
     ) -> BlockingSinkFinalizeResult {
         let params = self.params.clone();
+        let num_ranges = self.max_concurrency();
+        let memory_limit = spawner.memory_manager().total_bytes();
         spawner
             .spawn(
                 async move {
-                    let parts = states.into_iter().flat_map(|mut state| {
-                        let state = state
+                    let mut parts = vec![];
+                    let mut runs = vec![];
+                    for mut state in states {
+                        let (state_parts, state_runs) = state
                             .as_any_mut()
                             .downcast_mut::<SortState>()
-                            .expect("State type mismatch");
-                        state.finalize()
-                    });
+                            .expect("State type mismatch")
+                            .finalize();
+                        parts.extend(state_parts);
+                        runs.extend(state_runs);
+                    }
+
+                    // If any parts were spilled, merge their sorted runs with the parts that are still in memory.
+                    if let Some(first_run) = runs.first() {
+                        let schema = first_run.schema();
+                        let mut sorted_runs = runs
+                            .iter()
+                            .map(SpillFile::read_batches)
+                            .collect::<DaftResult<Vec<_>>>()?;
+                        if !parts.is_empty() {
+                            let sorted = MicroPartition::concat(parts)?.sort(
+                                &params.sort_by,
+                                &params.descending,
+                                &params.nulls_first,
+                            )?;
+                            let batches = split_into_batches(&sorted, params.spill_batch_size)?;
+                            sorted_runs.push(Box::new(batches.into_iter().map(DaftResult::Ok)));
+                        }
+                        // The merged rows are produced a partition at a time as they're taken downstream.
+                        let merge = SortedRunsMerge::try_new(
+                            params.clone(),
+                            sorted_runs,
+                            schema,
+                            memory_limit,
+                            runs,
+                        )?;
+                        return Ok(BlockingSinkFinalizeOutput::Partitions(Mutex::new(
+                            Box::new(merge),
+                        )));
+                    }
+
                     let concated = MicroPartition::concat(parts)?;
-                    let sorted = Arc::new(concated.sort(
-                        &params.sort_by,
-                        &params.descending,
-                        &params.nulls_first,
-                    )?);
-                    Ok(Some(sorted))
+                    let ranges = params.split_into_ranges(concated, num_ranges)?;
+
+                    let mut sort_tasks = tokio::task::JoinSet::new();
+                    for (i, range) in ranges.into_iter().enumerate() {
+                        let params = params.clone();
+                        sort_tasks.spawn(async move {
+                            let sorted = range.sort(
+                                &params.sort_by,
+                                &params.descending,
+                                &params.nulls_first,
+                            )?;
+                            DaftResult::Ok((i, sorted))
+                        });
+                    }
+                    let mut sorted_ranges = sort_tasks
+                        .join_all()
+                        .await
+                        .into_iter()
+                        .collect::<DaftResult<Vec<_>>>()?;
+                    sorted_ranges.sort_by_key(|(i, _)| *i);
+                    let sorted =
+                        MicroPartition::concat(sorted_ranges.iter().map(|(_, range)| range))?;
+                    Ok(BlockingSinkFinalizeOutput::Finished(Some(Arc::new(sorted))))
                 },
                 Span::current(),
             )
@@ -137,10 +498,115 @@ impl BlockingSink for SortSink {
     }
 
     fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
-        Ok(Box::new(SortState::Building(Vec::new())))
+        Ok(Box::new(SortState::Building {
+            parts: Vec::new(),
+            buffered_bytes: 0,
+            runs: Vec::new(),
+        }))
     }
 
     fn max_concurrency(&self) -> usize {
         *NUM_CPUS
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_daft_config::DaftExecutionConfig;
+    use common_error::DaftResult;
+    use common_runtime::get_compute_runtime;
+    use daft_core::prelude::*;
+    use daft_dsl::resolved_col;
+    use daft_io::IOStatsContext;
+    use daft_micropartition::MicroPartition;
+    use daft_recordbatch::RecordBatch;
+    use tracing::Span;
+
+    use super::SortSink;
+    use crate::{
+        resource_manager::{get_or_init_memory_manager, MemoryManager},
+        runtime_stats::RuntimeStatsContext,
+        sinks::blocking_sink::{
+            BlockingSink, BlockingSinkFinalizeOutput, BlockingSinkStatus, FinalizedPartitions,
+        },
+        ExecutionTaskSpawner, NUM_CPUS,
+    };
+
+    fn micropartition(values: Vec<i64>) -> DaftResult<Arc<MicroPartition>> {
+        let batch =
+            RecordBatch::from_nonempty_columns(
+                vec![Int64Array::from(("x", values)).into_series()],
+            )?;
+        Ok(Arc::new(MicroPartition::new_loaded(
+            batch.schema.clone(),
+            Arc::new(vec![batch]),
+            None,
+        )))
+    }
+
+    /// Tests that a sort over its memory budget spills its input as sorted runs, and merges them into partitions of
+    /// bounded size within the budget.
+    #[tokio::test]
+    async fn test_sort_spills_and_merges_runs() -> DaftResult<()> {
+        let cfg = DaftExecutionConfig {
+            default_morsel_size: 100,
+            ..Default::default()
+        };
+        let sink = SortSink::new(vec![resolved_col("x")], vec![false], vec![false], &cfg);
+        // Every part of 1000 Int64 values, i.e. 8000 bytes, is over the spill threshold of 4000 bytes per worker.
+        let memory_limit = 2 * *NUM_CPUS as u64 * 4000;
+        let memory_manager = Arc::new(MemoryManager::for_query(
+            get_or_init_memory_manager().clone(),
+            Some(memory_limit),
+        ));
+        let spawner = ExecutionTaskSpawner::new(
+            get_compute_runtime(),
+            memory_manager,
+            RuntimeStatsContext::new(),
+            Span::current(),
+        );
+
+        let mut state = sink.make_state()?;
+        for i in 0..10 {
+            // The values of the parts are interleaved, so that merging them reads from every run.
+            let part = micropartition((0..1000).map(|j| j * 10 + (9 - i)).collect())?;
+            state = match sink.sink(part, state, &spawner).await?? {
+                BlockingSinkStatus::NeedMoreInput(state) => state,
+                BlockingSinkStatus::Finished(_) => panic!("Sort should take all of its input"),
+            };
+        }
+        let BlockingSinkFinalizeOutput::Partitions(partitions) =
+            sink.finalize(vec![state], &spawner).await??
+        else {
+            panic!("Expected the spilled runs to be merged a partition at a time");
+        };
+
+        let mut partitions = partitions.into_inner().unwrap();
+        let mut values = vec![];
+        let mut num_partitions = 0;
+        loop {
+            assert!(partitions.next_memory_request() <= memory_limit);
+            let Some(partition) = partitions.next_partition()? else {
+                break;
+            };
+            // A partition is merged from at most a batch of 100 rows of each run past the batch size.
+            assert!(partition.len() < 100 + 10 * 100);
+            num_partitions += 1;
+            for batch in partition.concat_or_get(IOStatsContext::new("test"))?.iter() {
+                values.extend(
+                    batch
+                        .get_column("x")?
+                        .i64()?
+                        .as_arrow()
+                        .values_iter()
+                        .copied(),
+                );
+            }
+        }
+        assert!(num_partitions > 1);
+        assert_eq!(values, (0..10_000).collect::<Vec<_>>());
+        Ok(())
+    }
+}
@@ -68,6 +68,12 @@ pub trait StreamingSink: Send + Sync {
         *NUM_CPUS
     }
 
+    /// The most rows that this sink needs from its children, if it stops early like a limit does.
+    /// Operators before this sink then buffer smaller morsels, so less data is read and held in memory.
+    fn morsel_size_limit(&self) -> Option<usize> {
+        None
+    }
+
     fn dispatch_spawner(
         &self,
         runtime_handle: &ExecutionRuntimeContext,
@@ -184,6 +190,9 @@ impl TreeDisplay for StreamingSinkNode {
                 writeln!(display, "{}", multiline_display).unwrap();
                 if let StatsState::Materialized(stats) = &self.plan_stats {
                     writeln!(display, "Stats = {}", stats).unwrap();
+                    if let Some(semantic_id) = &stats.semantic_id {
+                        writeln!(display, "Semantic ID = {semantic_id}").unwrap();
+                    }
                 }
                 if matches!(level, DisplayLevel::Verbose) {
                     let rt_result = self.runtime_stats.result();
@@ -224,9 +233,18 @@ impl PipelineNode for StreamingSinkNode {
             true,
             self.runtime_stats.clone(),
         );
+        let morsel_size_limit = self
+            .op
+            .morsel_size_limit()
+            .into_iter()
+            .chain(runtime_handle.morsel_size_limit())
+            .min();
         let mut child_result_receivers = Vec::with_capacity(self.children.len());
         for child in &self.children {
-            let child_result_receiver = child.start(maintain_order, runtime_handle)?;
+            let child_result_receiver = runtime_handle
+                .with_morsel_size_limit(morsel_size_limit, |runtime_handle| {
+                    child.start(maintain_order, runtime_handle)
+                })?;
             child_result_receivers.push(CountingReceiver::new(
                 child_result_receiver,
                 self.runtime_stats.clone(),
@@ -235,8 +253,12 @@ impl PipelineNode for StreamingSinkNode {
         }
 
         let (destination_sender, destination_receiver) = create_channel(0);
-        let counting_sender =
-            CountingSender::new(destination_sender, self.runtime_stats.clone(), progress_bar);
+        let counting_sender = CountingSender::new(
+            destination_sender,
+            self.runtime_stats.clone(),
+            runtime_handle.memory_manager(),
+            progress_bar,
+        );
 
         let op = self.op.clone();
         let runtime_stats = self.runtime_stats.clone();
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_dsl::{ExprRef, NullStrategy};
+use daft_micropartition::MicroPartition;
+use itertools::Itertools;
+use tracing::{instrument, Span};
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeOutput, BlockingSinkFinalizeResult, BlockingSinkSinkResult,
+    BlockingSinkState, BlockingSinkStatus,
+};
+use crate::ExecutionTaskSpawner;
+
+enum WindowState {
+    Accumulating(Vec<Arc<MicroPartition>>),
+    Done,
+}
+
+impl WindowState {
+    fn push(&mut self, part: Arc<MicroPartition>) {
+        if let Self::Accumulating(ref mut parts) = self {
+            parts.push(part);
+        } else {
+            panic!("WindowSink should be in Accumulating state");
+        }
+    }
+
+    fn finalize(&mut self) -> Vec<Arc<MicroPartition>> {
+        let res = if let Self::Accumulating(ref mut parts) = self {
+            std::mem::take(parts)
+        } else {
+            panic!("WindowSink should be in Accumulating state");
+        };
+        *self = Self::Done;
+        res
+    }
+}
+
+impl BlockingSinkState for WindowState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct WindowParams {
+    window_functions: Vec<ExprRef>,
+    partition_by: Vec<ExprRef>,
+    order_by: Vec<ExprRef>,
+    null_strategy: NullStrategy,
+}
+
+pub struct WindowSink {
+    window_params: Arc<WindowParams>,
+}
+
+impl WindowSink {
+    pub fn new(
+        window_functions: Vec<ExprRef>,
+        partition_by: Vec<ExprRef>,
+        order_by: Vec<ExprRef>,
+        null_strategy: NullStrategy,
+    ) -> Self {
+        Self {
+            window_params: Arc::new(WindowParams {
+                window_functions,
+                partition_by,
+                order_by,
+                null_strategy,
+            }),
+        }
+    }
+}
+
+impl BlockingSink for WindowSink {
+    #[instrument(skip_all, name = "WindowSink::sink")]
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        _spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkSinkResult {
+        state
+            .as_any_mut()
+            .downcast_mut::<WindowState>()
+            .expect("WindowSink should have WindowState")
+            .push(input);
+        Ok(BlockingSinkStatus::NeedMoreInput(state)).into()
+    }
+
+    #[instrument(skip_all, name = "WindowSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkFinalizeResult {
+        let window_params = self.window_params.clone();
+        spawner
+            .spawn(
+                async move {
+                    let all_parts = states.into_iter().flat_map(|mut state| {
+                        state
+                            .as_any_mut()
+                            .downcast_mut::<WindowState>()
+                            .expect("WindowSink should have WindowState")
+                            .finalize()
+                    });
+                    let concated = MicroPartition::concat(all_parts)?;
+                    let windowed = concated.window(
+                        &window_params.window_functions,
+                        &window_params.partition_by,
+                        &window_params.order_by,
+                        window_params.null_strategy,
+                    )?;
+                    Ok(BlockingSinkFinalizeOutput::Finished(Some(Arc::new(
+                        windowed,
+                    ))))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "Window"
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut display = vec![format!(
+            "Window: {}",
+            self.window_params
+                .window_functions
+                .iter()
+                .map(|e| e.to_string())
+                .join(", ")
+        )];
+        if !self.window_params.partition_by.is_empty() {
+            display.push(format!(
+                "Partition by = {}",
+                self.window_params
+                    .partition_by
+                    .iter()
+                    .map(|e| e.to_string())
+                    .join(", ")
+            ));
+        }
+        if !self.window_params.order_by.is_empty() {
+            display.push(format!(
+                "Order by = {}",
+                self.window_params
+                    .order_by
+                    .iter()
+                    .map(|e| e.to_string())
+                    .join(", ")
+            ));
+        }
+        if self.window_params.null_strategy != NullStrategy::Largest {
+            display.push(format!("Nulls = {}", self.window_params.null_strategy));
+        }
+        display
+    }
+
+    fn max_concurrency(&self) -> usize {
+        // The input is sorted, so it's accumulated by a single worker that receives it in order.
+        1
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(WindowState::Accumulating(vec![])))
+    }
+}
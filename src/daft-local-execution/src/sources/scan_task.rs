@@ -1,36 +1,91 @@
 use std::{
     collections::{HashMap, HashSet},
     sync::Arc,
+    time::Instant,
 };
 
 use async_trait::async_trait;
 use common_daft_config::DaftExecutionConfig;
 use common_display::{tree::TreeDisplay, DisplayAs, DisplayLevel};
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
-use common_runtime::get_io_runtime;
+use common_runtime::{get_compute_runtime, get_io_runtime};
 use common_scan_info::{Pushdowns, ScanTaskLike};
 use daft_core::prelude::{AsArrow, Int64Array, SchemaRef, Utf8Array};
 use daft_csv::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
+use daft_dsl::ExprRef;
 use daft_io::IOStatsRef;
+use daft_ipc::IpcConvertOptions;
 use daft_json::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
-use daft_micropartition::MicroPartition;
+use daft_micropartition::{add_parquet_row_index, parquet_row_index_read_columns, MicroPartition};
 use daft_parquet::read::{read_parquet_bulk_async, ParquetSchemaInferenceOptions};
 use daft_scan::{ChunkSpec, ScanTask};
 use daft_warc::WarcConvertOptions;
-use futures::{Stream, StreamExt, TryStreamExt};
+use futures::{future::BoxFuture, stream::BoxStream, FutureExt, Stream, StreamExt, TryStreamExt};
 use snafu::ResultExt;
 use tracing::instrument;
 
 use crate::{
+    retry::RetryPolicy,
+    runtime_stats::{RuntimeStats, RuntimeStatsContext},
     sources::source::{Source, SourceStream},
     NUM_CPUS,
 };
 
+/// A filter or projection that is applied to the morsels of a scan as they're read, instead of by a separate operator.
+#[derive(Clone)]
+pub(crate) enum FusedOp {
+    Filter(ExprRef),
+    Project(Vec<ExprRef>),
+}
+
+impl FusedOp {
+    fn apply(&self, input: &MicroPartition) -> DaftResult<MicroPartition> {
+        match self {
+            Self::Filter(predicate) => input.filter(&[predicate.clone()]),
+            Self::Project(projection) => input.eval_expression_list(projection),
+        }
+    }
+}
+
+/// Applies `f` to `input`, recording the rows it receives and emits and its CPU time in `runtime_stats`.
+fn apply_with_stats(
+    runtime_stats: &RuntimeStatsContext,
+    input: &MicroPartition,
+    f: impl FnOnce(&MicroPartition) -> DaftResult<MicroPartition>,
+) -> DaftResult<Arc<MicroPartition>> {
+    let start = Instant::now();
+    let output = f(input)?;
+    runtime_stats.record_elapsed_cpu_time(start.elapsed());
+    runtime_stats.mark_rows_received(input.len() as u64);
+    runtime_stats.mark_rows_emitted(output.len() as u64);
+    Ok(Arc::new(output))
+}
+
+impl std::fmt::Display for FusedOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Filter(predicate) => write!(f, "Filter: {predicate}"),
+            Self::Project(projection) => write!(
+                f,
+                "Project: {}",
+                projection
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
 pub struct ScanTaskSource {
     scan_tasks: Vec<Arc<ScanTask>>,
     num_parallel_tasks: usize,
     schema: SchemaRef,
+    retry_policies: Vec<RetryPolicy>,
+    fused_ops: Arc<Vec<(FusedOp, Arc<RuntimeStatsContext>)>>,
+    fused_limit: Option<(usize, Arc<RuntimeStatsContext>)>,
 }
 
 impl ScanTaskSource {
@@ -74,13 +129,39 @@ impl ScanTaskSource {
             None => Self::MAX_PARALLEL_SCAN_TASKS,
         };
         num_parallel_tasks = num_parallel_tasks.min(scan_tasks.len());
+        let retry_policies = scan_tasks
+            .iter()
+            .map(|scan_task| RetryPolicy::for_scan_task(scan_task, cfg))
+            .collect();
         Self {
             scan_tasks,
             num_parallel_tasks,
             schema,
+            retry_policies,
+            fused_ops: Arc::new(vec![]),
+            fused_limit: None,
         }
     }
 
+    /// Applies `fused_ops`, in order, to the morsels as they're read and then stops reading after `fused_limit` rows,
+    /// which produces morsels with `schema`.
+    pub(crate) fn with_fused_ops(
+        mut self,
+        fused_ops: Vec<FusedOp>,
+        fused_limit: Option<usize>,
+        schema: SchemaRef,
+    ) -> Self {
+        self.fused_ops = Arc::new(
+            fused_ops
+                .into_iter()
+                .map(|op| (op, RuntimeStatsContext::new()))
+                .collect(),
+        );
+        self.fused_limit = fused_limit.map(|limit| (limit, RuntimeStatsContext::new()));
+        self.schema = schema;
+        self
+    }
+
     pub fn arced(self) -> Arc<dyn Source> {
         Arc::new(self) as Arc<dyn Source>
     }
@@ -96,33 +177,80 @@ impl Source for ScanTaskSource {
     ) -> DaftResult<SourceStream<'static>> {
         let io_runtime = get_io_runtime(true);
         let delete_map = get_delete_map(&self.scan_tasks).await?.map(Arc::new);
+        let scan_tasks = self
+            .scan_tasks
+            .clone()
+            .into_iter()
+            .zip(self.retry_policies.clone());
         let stream_of_streams =
-            futures::stream::iter(self.scan_tasks.clone().into_iter().map(move |scan_task| {
+            futures::stream::iter(scan_tasks.map(move |(scan_task, retry_policy)| {
                 let io_stats = io_stats.clone();
                 let delete_map = delete_map.clone();
-                io_runtime.spawn(stream_scan_task(
+                io_runtime.spawn(stream_scan_task_with_retries(
                     scan_task,
                     io_stats,
                     delete_map,
                     maintain_order,
+                    retry_policy,
                 ))
             }));
 
-        match maintain_order {
+        let stream: SourceStream<'static> = match maintain_order {
             true => {
                 let buffered_and_flattened = stream_of_streams
                     .buffered(self.num_parallel_tasks)
                     .map(|r| r?)
                     .try_flatten();
-                Ok(Box::pin(buffered_and_flattened))
+                Box::pin(buffered_and_flattened)
             }
             false => {
                 let buffered_and_flattened = stream_of_streams
                     .then(|r| async { r.await? })
                     .try_flatten_unordered(self.num_parallel_tasks);
-                Ok(Box::pin(buffered_and_flattened))
+                Box::pin(buffered_and_flattened)
             }
-        }
+        };
+        let stream: SourceStream<'static> = if self.fused_ops.is_empty() {
+            stream
+        } else {
+            // Apply the fused ops to several morsels at once on the compute runtime, like the operators they replace.
+            let compute_runtime = get_compute_runtime();
+            let fused_ops = self.fused_ops.clone();
+            let applied = stream.map_ok(move |morsel| {
+                let fused_ops = fused_ops.clone();
+                let task = compute_runtime.spawn(async move {
+                    fused_ops
+                        .iter()
+                        .try_fold(morsel, |morsel, (op, runtime_stats)| {
+                            apply_with_stats(runtime_stats, &morsel, |morsel| op.apply(morsel))
+                        })
+                });
+                async move { task.await? }
+            });
+            match maintain_order {
+                true => Box::pin(applied.try_buffered(*NUM_CPUS)),
+                false => Box::pin(applied.try_buffer_unordered(*NUM_CPUS)),
+            }
+        };
+        let Some((limit, runtime_stats)) = self.fused_limit.clone() else {
+            return Ok(stream);
+        };
+
+        // The limit is applied across morsels, so the stream ends, and stops reading, once it's reached.
+        let limited = stream.scan(limit, move |remaining, morsel| {
+            if *remaining == 0 {
+                return futures::future::ready(None);
+            }
+            let morsel = morsel.and_then(|morsel| {
+                apply_with_stats(&runtime_stats, &morsel, |morsel| {
+                    let output = morsel.head(*remaining)?;
+                    *remaining -= output.len();
+                    Ok(output)
+                })
+            });
+            futures::future::ready(Some(morsel))
+        });
+        Ok(Box::pin(limited))
     }
 
     fn name(&self) -> &'static str {
@@ -139,6 +267,17 @@ impl Source for ScanTaskSource {
     fn schema(&self) -> &SchemaRef {
         &self.schema
     }
+
+    fn fused_runtime_stats(&self) -> Vec<(String, RuntimeStats)> {
+        let ops = self
+            .fused_ops
+            .iter()
+            .map(|(op, runtime_stats)| (format!("Fused {op}"), runtime_stats.result()));
+        let limit = self.fused_limit.iter().map(|(limit, runtime_stats)| {
+            (format!("Fused Limit: {limit}"), runtime_stats.result())
+        });
+        ops.chain(limit).collect()
+    }
 }
 
 impl TreeDisplay for ScanTaskSource {
@@ -181,6 +320,12 @@ Estimated Scan Bytes = {total_bytes}
                     s.push_str(&pushdown.display_as(DisplayLevel::Compact));
                     s.push('\n');
                 }
+                for (op, _) in self.fused_ops.iter() {
+                    writeln!(s, "Fused {op}").unwrap();
+                }
+                if let Some((limit, _)) = &self.fused_limit {
+                    writeln!(s, "Fused Limit: {limit}").unwrap();
+                }
 
                 let schema = self.scan_tasks[0].schema();
                 writeln!(
@@ -225,7 +370,7 @@ Estimated Scan Bytes = {total_bytes}
     }
 }
 
-// Read all iceberg delete files and return a map of file paths to delete positions
+// Read all iceberg delete files and Delta Lake deletion vectors and return a map of file paths to delete positions
 async fn get_delete_map(
     scan_tasks: &[Arc<ScanTask>],
 ) -> DaftResult<Option<HashMap<String, Vec<i64>>>> {
@@ -239,7 +384,16 @@ async fn get_delete_map(
                 .cloned()
         })
         .collect::<HashSet<_>>();
-    if delete_files.is_empty() {
+    let deletion_vectors = scan_tasks
+        .iter()
+        .flat_map(|st| {
+            st.sources.iter().filter_map(|source| {
+                let deletion_vector = source.get_deletion_vector()?.clone();
+                Some((source.get_path().to_string(), deletion_vector))
+            })
+        })
+        .collect::<Vec<_>>();
+    if delete_files.is_empty() && deletion_vectors.is_empty() {
         return Ok(None);
     }
 
@@ -256,6 +410,16 @@ async fn get_delete_map(
                 .flat_map(|st| st.sources.iter().map(|s| s.get_path().to_string()))
                 .map(|path| (path, vec![]))
                 .collect::<std::collections::HashMap<_, _>>();
+            for (path, deletion_vector) in deletion_vectors {
+                let deleted_rows = deletion_vector
+                    .read_deleted_rows(io_client.clone(), None)
+                    .await?;
+                delete_map.entry(path).or_default().extend(deleted_rows);
+            }
+            if delete_files.is_empty() {
+                return Ok(Some(delete_map));
+            }
+
             let columns_to_read = Some(vec!["file_path".to_string(), "pos".to_string()]);
             let result = read_parquet_bulk_async(
                 delete_files.into_iter().collect(),
@@ -297,6 +461,139 @@ async fn get_delete_map(
         .await?
 }
 
+type MicroPartitionStream = BoxStream<'static, DaftResult<Arc<MicroPartition>>>;
+
+/// Starts a new attempt at reading a scan task.
+type OpenScanTaskStream =
+    Box<dyn FnMut() -> BoxFuture<'static, DaftResult<MicroPartitionStream>> + Send>;
+
+struct RetryingScanTaskStream {
+    open: OpenScanTaskStream,
+    maintain_order: bool,
+    retry_policy: RetryPolicy,
+    stream: Option<MicroPartitionStream>,
+    attempt: usize,
+    rows_emitted: usize,
+    rows_to_skip: usize,
+    failed: bool,
+}
+
+impl RetryingScanTaskStream {
+    fn new(open: OpenScanTaskStream, maintain_order: bool, retry_policy: RetryPolicy) -> Self {
+        Self {
+            open,
+            maintain_order,
+            retry_policy,
+            stream: None,
+            attempt: 1,
+            rows_emitted: 0,
+            rows_to_skip: 0,
+            failed: false,
+        }
+    }
+
+    fn into_stream(self) -> MicroPartitionStream {
+        futures::stream::unfold(self, |mut state| async move {
+            let next = state.next().await?;
+            Some((next, state))
+        })
+        .boxed()
+    }
+
+    /// Whether the scan task should be read again after the current attempt failed with `err`.
+    ///
+    /// A retry skips the rows that were already emitted, which is only correct if the task
+    /// produces its rows in the same order every time.
+    fn should_retry(&self, err: &DaftError) -> bool {
+        (self.maintain_order || self.rows_emitted == 0)
+            && self.retry_policy.should_retry(self.attempt, err)
+    }
+
+    async fn backoff(&mut self, err: &DaftError) {
+        let backoff = self.retry_policy.backoff(self.attempt);
+        log::warn!(
+            "Attempt {} of {} to read scan task failed, retrying in {:?}: {err}",
+            self.attempt,
+            self.retry_policy.max_attempts,
+            backoff,
+        );
+        tokio::time::sleep(backoff).await;
+        self.attempt += 1;
+        self.stream = None;
+        self.rows_to_skip = self.rows_emitted;
+    }
+
+    async fn next(&mut self) -> Option<DaftResult<Arc<MicroPartition>>> {
+        loop {
+            if self.failed {
+                return None;
+            }
+            let Some(stream) = self.stream.as_mut() else {
+                match (self.open)().await {
+                    Ok(stream) => self.stream = Some(stream),
+                    Err(err) if self.should_retry(&err) => self.backoff(&err).await,
+                    Err(err) => {
+                        self.failed = true;
+                        return Some(Err(err));
+                    }
+                }
+                continue;
+            };
+            match stream.next().await? {
+                // Drop the rows that a failed attempt already emitted.
+                Ok(mp) if self.rows_to_skip > 0 && mp.len() <= self.rows_to_skip => {
+                    self.rows_to_skip -= mp.len();
+                }
+                Ok(mp) => {
+                    let mp = if self.rows_to_skip > 0 {
+                        let skip = std::mem::take(&mut self.rows_to_skip);
+                        match mp.slice(skip, mp.len()) {
+                            Ok(sliced) => Arc::new(sliced),
+                            Err(err) => {
+                                self.failed = true;
+                                return Some(Err(err));
+                            }
+                        }
+                    } else {
+                        mp
+                    };
+                    self.rows_emitted += mp.len();
+                    return Some(Ok(mp));
+                }
+                Err(err) if self.should_retry(&err) => self.backoff(&err).await,
+                Err(err) => {
+                    self.failed = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+/// Streams a scan task, reading it again if it fails with a transient error, e.g. a 500 from S3.
+async fn stream_scan_task_with_retries(
+    scan_task: Arc<ScanTask>,
+    io_stats: IOStatsRef,
+    delete_map: Option<Arc<HashMap<String, Vec<i64>>>>,
+    maintain_order: bool,
+    retry_policy: RetryPolicy,
+) -> DaftResult<impl Stream<Item = DaftResult<Arc<MicroPartition>>> + Send> {
+    let open: OpenScanTaskStream = Box::new(move || {
+        let scan_task = scan_task.clone();
+        let io_stats = io_stats.clone();
+        let delete_map = delete_map.clone();
+        async move {
+            Ok(
+                stream_scan_task(scan_task, io_stats, delete_map, maintain_order)
+                    .await?
+                    .boxed(),
+            )
+        }
+        .boxed()
+    });
+    Ok(RetryingScanTaskStream::new(open, maintain_order, retry_policy).into_stream())
+}
+
 async fn stream_scan_task(
     scan_task: Arc<ScanTask>,
     io_stats: IOStatsRef,
@@ -332,7 +629,7 @@ async fn stream_scan_task(
     };
 
     if scan_task.sources.len() != 1 {
-        return Err(common_error::DaftError::TypeError(
+        return Err(DaftError::TypeError(
             "Streaming reads only supported for single source ScanTasks".to_string(),
         ));
     }
@@ -347,6 +644,86 @@ async fn stream_scan_task(
     );
     let io_client = daft_io::get_io_client(scan_task.storage_config.multithreaded_io, io_config)?;
     let table_stream = match scan_task.file_format_config.as_ref() {
+        FileFormatConfig::Parquet(ParquetSourceConfig {
+            coerce_int96_timestamp_unit,
+            field_id_mapping,
+            chunk_size,
+            row_index_column: Some(row_index_column),
+            ..
+        }) => {
+            // Deletes and filters can only be applied once the row index is known, so the reader must
+            // see every row, in order.
+            let inference_options =
+                ParquetSchemaInferenceOptions::new(Some(*coerce_int96_timestamp_unit));
+            let read_columns = parquet_row_index_read_columns(
+                file_column_names.as_deref(),
+                row_index_column,
+                scan_task.pushdowns.filters.as_ref(),
+            );
+            let delete_rows = delete_map
+                .as_ref()
+                .and_then(|m| m.get(url).cloned())
+                .filter(|rows| !rows.is_empty());
+            let limit = scan_task
+                .pushdowns
+                .limit
+                .filter(|_| scan_task.pushdowns.filters.is_none() && delete_rows.is_none());
+            let metadata = scan_task
+                .sources
+                .first()
+                .and_then(|s| s.get_parquet_metadata().cloned());
+            let stream = daft_parquet::read::stream_parquet(
+                url,
+                read_columns
+                    .as_ref()
+                    .map(|cols| cols.iter().map(String::as_str).collect::<Vec<_>>())
+                    .as_deref(),
+                scan_task
+                    .pushdowns
+                    .struct_fields
+                    .as_deref()
+                    .map(Vec::as_slice),
+                limit,
+                None,
+                None,
+                io_client,
+                Some(io_stats),
+                &inference_options,
+                field_id_mapping.clone(),
+                metadata,
+                true,
+                None,
+                *chunk_size,
+            )
+            .await?;
+
+            let row_index_column = row_index_column.clone();
+            let filters = scan_task.pushdowns.filters.clone();
+            let output_columns = file_column_names.as_ref().map(|cols| {
+                cols.iter()
+                    .map(|col| (*col).to_string())
+                    .collect::<Vec<_>>()
+            });
+            let mut offset = 0;
+            stream
+                .map(move |table| {
+                    let table = table?;
+                    let start = offset;
+                    offset += table.len() as u64;
+                    add_parquet_row_index(
+                        &table,
+                        start,
+                        &row_index_column,
+                        delete_rows.as_deref(),
+                        filters.as_ref(),
+                        output_columns
+                            .as_ref()
+                            .map(|cols| cols.iter().map(String::as_str).collect::<Vec<_>>())
+                            .as_deref(),
+                    )
+                })
+                .boxed()
+        }
         FileFormatConfig::Parquet(ParquetSourceConfig {
             coerce_int96_timestamp_unit,
             field_id_mapping,
@@ -369,6 +746,11 @@ async fn stream_scan_task(
             daft_parquet::read::stream_parquet(
                 url,
                 file_column_names.as_deref(),
+                scan_task
+                    .pushdowns
+                    .struct_fields
+                    .as_deref()
+                    .map(Vec::as_slice),
                 scan_task.pushdowns.limit,
                 row_groups,
                 scan_task.pushdowns.filters.clone(),
@@ -463,6 +845,17 @@ async fn stream_scan_task(
             };
             daft_warc::stream_warc(url, io_client, Some(io_stats), convert_options, None).await?
         }
+        FileFormatConfig::ArrowIpc(_) => {
+            let convert_options = IpcConvertOptions {
+                limit: scan_task.pushdowns.limit,
+                include_columns: file_column_names
+                    .as_ref()
+                    .map(|cols| cols.iter().map(|col| (*col).to_string()).collect()),
+                schema: scan_task.schema.clone(),
+                predicate: scan_task.pushdowns.filters.clone(),
+            };
+            daft_ipc::stream_ipc(url, io_client, Some(io_stats), convert_options).await?
+        }
         #[cfg(feature = "python")]
         FileFormatConfig::Database(common_file_formats::DatabaseSourceConfig { sql, conn }) => {
             use pyo3::Python;
@@ -518,3 +911,104 @@ async fn stream_scan_task(
         Ok(mp)
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use common_error::{DaftError, DaftResult};
+    use daft_core::prelude::*;
+    use daft_io::IOStatsContext;
+    use daft_micropartition::MicroPartition;
+    use daft_recordbatch::RecordBatch;
+    use futures::{FutureExt, StreamExt};
+
+    use super::{OpenScanTaskStream, RetryingScanTaskStream};
+    use crate::retry::RetryPolicy;
+
+    fn micropartition(values: std::ops::Range<i64>) -> DaftResult<Arc<MicroPartition>> {
+        let batch = RecordBatch::from_nonempty_columns(vec![Int64Array::from((
+            "x",
+            values.collect::<Vec<_>>(),
+        ))
+        .into_series()])?;
+        Ok(Arc::new(MicroPartition::new_loaded(
+            batch.schema.clone(),
+            Arc::new(vec![batch]),
+            None,
+        )))
+    }
+
+    /// Opens a stream of `0..8`, which fails after the first 5 rows on the first attempt and is chunked
+    /// differently on later attempts.
+    fn flaky_open(attempts: Arc<AtomicUsize>) -> OpenScanTaskStream {
+        Box::new(move || {
+            let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+            let parts = if attempt == 0 {
+                vec![
+                    micropartition(0..3),
+                    micropartition(3..5),
+                    Err(DaftError::MiscTransient("500".into())),
+                ]
+            } else {
+                vec![micropartition(0..4), micropartition(4..8)]
+            };
+            async move { Ok(futures::stream::iter(parts).boxed()) }.boxed()
+        })
+    }
+
+    async fn collect_values(stream: RetryingScanTaskStream) -> DaftResult<Vec<Option<i64>>> {
+        let mut values = vec![];
+        for mp in stream.into_stream().collect::<Vec<_>>().await {
+            for batch in mp?.concat_or_get(IOStatsContext::new("test"))?.iter() {
+                values.extend(
+                    batch
+                        .get_column("x")?
+                        .i64()?
+                        .as_arrow()
+                        .iter()
+                        .map(|v| v.copied()),
+                );
+            }
+        }
+        Ok(values)
+    }
+
+    /// Tests that a scan task that fails partway through is read again from where it failed.
+    #[tokio::test]
+    async fn test_retry_resumes_after_emitted_rows() -> DaftResult<()> {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let policy = RetryPolicy {
+            max_attempts: 2,
+            initial_backoff: Duration::ZERO,
+        };
+        let stream = RetryingScanTaskStream::new(flaky_open(attempts.clone()), true, policy);
+        let values = collect_values(stream).await?;
+        assert_eq!(values, (0..8).map(Some).collect::<Vec<_>>());
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    /// Tests that a scan task isn't retried by default, or once it has emitted rows in an unspecified order.
+    #[tokio::test]
+    async fn test_no_retry() -> DaftResult<()> {
+        for (max_attempts, maintain_order) in [(1, true), (2, false)] {
+            let attempts = Arc::new(AtomicUsize::new(0));
+            let policy = RetryPolicy {
+                max_attempts,
+                initial_backoff: Duration::ZERO,
+            };
+            let stream =
+                RetryingScanTaskStream::new(flaky_open(attempts.clone()), maintain_order, policy);
+            assert!(collect_values(stream).await.is_err());
+            assert_eq!(attempts.load(Ordering::SeqCst), 1);
+        }
+        Ok(())
+    }
+}
@@ -13,7 +13,7 @@ use crate::{
     channel::{create_channel, Receiver},
     pipeline::PipelineNode,
     progress_bar::ProgressBarColor,
-    runtime_stats::{CountingSender, RuntimeStatsContext},
+    runtime_stats::{CountingSender, RuntimeStats, RuntimeStatsContext},
     ExecutionRuntimeContext,
 };
 
@@ -29,6 +29,10 @@ pub trait Source: Send + Sync {
         io_stats: IOStatsRef,
     ) -> DaftResult<SourceStream<'static>>;
     fn schema(&self) -> &SchemaRef;
+    /// The runtime stats of the ops that the source applies to its morsels itself, such as those fused into a scan.
+    fn fused_runtime_stats(&self) -> Vec<(String, RuntimeStats)> {
+        vec![]
+    }
 }
 
 pub(crate) struct SourceNode {
@@ -70,6 +74,9 @@ impl TreeDisplay for SourceNode {
 
                 if let StatsState::Materialized(stats) = &self.plan_stats {
                     writeln!(display, "Stats = {}", stats).unwrap();
+                    if let Some(semantic_id) = &stats.semantic_id {
+                        writeln!(display, "Semantic ID = {semantic_id}").unwrap();
+                    }
                 }
 
                 if matches!(level, DisplayLevel::Verbose) {
@@ -77,6 +84,10 @@ impl TreeDisplay for SourceNode {
 
                     writeln!(display).unwrap();
                     rt_result.display(&mut display, false, true, false).unwrap();
+                    for (name, rt_result) in self.source.fused_runtime_stats() {
+                        writeln!(display, "{name}:").unwrap();
+                        rt_result.display(&mut display, true, true, true).unwrap();
+                    }
                     let bytes_read = self.io_stats.load_bytes_read();
                     writeln!(
                         display,
@@ -118,8 +129,12 @@ impl PipelineNode for SourceNode {
         let source = self.source.clone();
         let io_stats = self.io_stats.clone();
         let (destination_sender, destination_receiver) = create_channel(0);
-        let counting_sender =
-            CountingSender::new(destination_sender, self.runtime_stats.clone(), progress_bar);
+        let counting_sender = CountingSender::new(
+            destination_sender,
+            self.runtime_stats.clone(),
+            runtime_handle.memory_manager(),
+            progress_bar,
+        );
         runtime_handle.spawn(
             async move {
                 let mut has_data = false;
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use common_resource_request::ResourceRequest;
 use common_scan_info::{Pushdowns, ScanTaskLikeRef};
 use daft_core::prelude::*;
-use daft_dsl::{AggExpr, ExprRef};
+use daft_dsl::{functions::python::get_udf_names, AggExpr, ExprRef, NullStrategy};
 use daft_logical_plan::{
     stats::{PlanStats, StatsState},
     InMemoryInfo, OutputFileInfo,
@@ -25,6 +25,7 @@ pub enum LocalPhysicalPlan {
     // Split(Split),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
+    Window(Window),
     // Coalesce(Coalesce),
     // Flatten(Flatten),
     // FanoutRandom(FanoutRandom),
@@ -63,7 +64,6 @@ impl LocalPhysicalPlan {
     pub fn get_stats_state(&self) -> &StatsState {
         match self {
             Self::InMemoryScan(InMemoryScan { stats_state, .. })
-            | Self::PhysicalScan(PhysicalScan { stats_state, .. })
             | Self::EmptyScan(EmptyScan { stats_state, .. })
             | Self::Project(Project { stats_state, .. })
             | Self::ActorPoolProject(ActorPoolProject { stats_state, .. })
@@ -74,6 +74,7 @@ impl LocalPhysicalPlan {
             | Self::Sort(Sort { stats_state, .. })
             | Self::Sample(Sample { stats_state, .. })
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { stats_state, .. })
+            | Self::Window(Window { stats_state, .. })
             | Self::UnGroupedAggregate(UnGroupedAggregate { stats_state, .. })
             | Self::HashAggregate(HashAggregate { stats_state, .. })
             | Self::Pivot(Pivot { stats_state, .. })
@@ -84,6 +85,10 @@ impl LocalPhysicalPlan {
             #[cfg(feature = "python")]
             Self::CatalogWrite(CatalogWrite { stats_state, .. })
             | Self::LanceWrite(LanceWrite { stats_state, .. }) => stats_state,
+            Self::PhysicalScan(scan) => scan
+                .fused_ops
+                .last()
+                .map_or(&scan.stats_state, FusedScanOp::stats_state),
         }
     }
 
@@ -109,10 +114,37 @@ impl LocalPhysicalPlan {
             pushdowns,
             schema,
             stats_state,
+            fused_ops: vec![],
         })
         .arced()
     }
 
+    /// Fuses `op` into `input` if `input` is a scan, so that `op` is applied to morsels as they're read instead of by
+    /// a separate operator. Nothing is fused on top of a fused limit.
+    fn fuse_into_scan(
+        input: &LocalPhysicalPlanRef,
+        op: FusedScanOp,
+    ) -> Option<LocalPhysicalPlanRef> {
+        let Self::PhysicalScan(scan) = input.as_ref() else {
+            return None;
+        };
+        if matches!(scan.fused_ops.last(), Some(FusedScanOp::Limit { .. })) {
+            return None;
+        }
+        let mut fused_ops = scan.fused_ops.clone();
+        fused_ops.push(op);
+        Some(
+            Self::PhysicalScan(PhysicalScan {
+                scan_tasks: scan.scan_tasks.clone(),
+                pushdowns: scan.pushdowns.clone(),
+                schema: scan.schema.clone(),
+                stats_state: scan.stats_state.clone(),
+                fused_ops,
+            })
+            .arced(),
+        )
+    }
+
     pub(crate) fn empty_scan(schema: SchemaRef) -> LocalPhysicalPlanRef {
         Self::EmptyScan(EmptyScan {
             schema,
@@ -126,6 +158,15 @@ impl LocalPhysicalPlan {
         predicate: ExprRef,
         stats_state: StatsState,
     ) -> LocalPhysicalPlanRef {
+        if get_udf_names(&predicate).is_empty() {
+            let op = FusedScanOp::Filter {
+                predicate: predicate.clone(),
+                stats_state: stats_state.clone(),
+            };
+            if let Some(scan) = Self::fuse_into_scan(&input, op) {
+                return scan;
+            }
+        }
         let schema = input.schema().clone();
         Self::Filter(Filter {
             input,
@@ -141,6 +182,13 @@ impl LocalPhysicalPlan {
         num_rows: i64,
         stats_state: StatsState,
     ) -> LocalPhysicalPlanRef {
+        let op = FusedScanOp::Limit {
+            num_rows,
+            stats_state: stats_state.clone(),
+        };
+        if let Some(scan) = Self::fuse_into_scan(&input, op) {
+            return scan;
+        }
         let schema = input.schema().clone();
         Self::Limit(Limit {
             input,
@@ -172,6 +220,16 @@ impl LocalPhysicalPlan {
         schema: SchemaRef,
         stats_state: StatsState,
     ) -> LocalPhysicalPlanRef {
+        if projection.iter().all(|expr| get_udf_names(expr).is_empty()) {
+            let op = FusedScanOp::Project {
+                projection: projection.clone(),
+                schema: schema.clone(),
+                stats_state: stats_state.clone(),
+            };
+            if let Some(scan) = Self::fuse_into_scan(&input, op) {
+                return scan;
+            }
+        }
         Self::Project(Project {
             input,
             projection,
@@ -326,6 +384,27 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn window(
+        input: LocalPhysicalPlanRef,
+        window_functions: Vec<ExprRef>,
+        partition_by: Vec<ExprRef>,
+        order_by: Vec<ExprRef>,
+        null_strategy: NullStrategy,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::Window(Window {
+            input,
+            window_functions,
+            partition_by,
+            order_by,
+            null_strategy,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn hash_join(
         left: LocalPhysicalPlanRef,
@@ -435,8 +514,8 @@ impl LocalPhysicalPlan {
 
     pub fn schema(&self) -> &SchemaRef {
         match self {
-            Self::PhysicalScan(PhysicalScan { schema, .. })
-            | Self::EmptyScan(EmptyScan { schema, .. })
+            Self::PhysicalScan(scan) => scan.output_schema(),
+            Self::EmptyScan(EmptyScan { schema, .. })
             | Self::Filter(Filter { schema, .. })
             | Self::Limit(Limit { schema, .. })
             | Self::Project(Project { schema, .. })
@@ -451,7 +530,8 @@ impl LocalPhysicalPlan {
             | Self::Explode(Explode { schema, .. })
             | Self::Unpivot(Unpivot { schema, .. })
             | Self::Concat(Concat { schema, .. })
-            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. }) => schema,
+            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. })
+            | Self::Window(Window { schema, .. }) => schema,
             Self::PhysicalWrite(PhysicalWrite { file_schema, .. }) => file_schema,
             Self::InMemoryScan(InMemoryScan { info, .. }) => &info.source_schema,
             #[cfg(feature = "python")]
@@ -474,6 +554,50 @@ pub struct PhysicalScan {
     pub pushdowns: Pushdowns,
     pub schema: SchemaRef,
     pub stats_state: StatsState,
+    /// Ops applied, in order, to the morsels read by the scan.
+    pub fused_ops: Vec<FusedScanOp>,
+}
+
+impl PhysicalScan {
+    /// The schema of the morsels emitted by the scan, after its fused ops are applied.
+    pub fn output_schema(&self) -> &SchemaRef {
+        self.fused_ops
+            .iter()
+            .rev()
+            .find_map(|op| match op {
+                FusedScanOp::Project { schema, .. } => Some(schema),
+                _ => None,
+            })
+            .unwrap_or(&self.schema)
+    }
+}
+
+/// A filter, projection or limit that's fused into a [`PhysicalScan`].
+#[derive(Debug, Clone)]
+pub enum FusedScanOp {
+    Filter {
+        predicate: ExprRef,
+        stats_state: StatsState,
+    },
+    Project {
+        projection: Vec<ExprRef>,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    },
+    Limit {
+        num_rows: i64,
+        stats_state: StatsState,
+    },
+}
+
+impl FusedScanOp {
+    pub fn stats_state(&self) -> &StatsState {
+        match self {
+            Self::Filter { stats_state, .. }
+            | Self::Project { stats_state, .. }
+            | Self::Limit { stats_state, .. } => stats_state,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -550,6 +674,19 @@ pub struct MonotonicallyIncreasingId {
     pub stats_state: StatsState,
 }
 
+/// Appends the results of window functions to the rows of the input, which must be sorted by `partition_by` and then
+/// `order_by`.
+#[derive(Debug)]
+pub struct Window {
+    pub input: LocalPhysicalPlanRef,
+    pub window_functions: Vec<ExprRef>,
+    pub partition_by: Vec<ExprRef>,
+    pub order_by: Vec<ExprRef>,
+    pub null_strategy: NullStrategy,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct UnGroupedAggregate {
     pub input: LocalPhysicalPlanRef,
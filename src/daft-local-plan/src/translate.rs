@@ -2,9 +2,14 @@ use std::sync::Arc;
 
 use common_error::{DaftError, DaftResult};
 use common_scan_info::ScanState;
-use daft_core::join::JoinStrategy;
-use daft_dsl::{join::normalize_join_keys, ExprRef};
-use daft_logical_plan::{JoinType, LogicalPlan, LogicalPlanRef, SourceInfo};
+use daft_core::{join::JoinStrategy, prelude::*, utils::supertype::try_get_supertype};
+use daft_dsl::{
+    exprs_to_schema,
+    join::{infer_join_schema, normalize_join_keys},
+    AggExpr, Expr, ExprRef,
+};
+use daft_logical_plan::{stats::StatsState, JoinType, LogicalPlan, LogicalPlanRef, SourceInfo};
+use daft_physical_plan::{ops, PhysicalPlan, PhysicalPlanRef};
 
 use super::plan::{LocalPhysicalPlan, LocalPhysicalPlanRef};
 
@@ -48,6 +53,14 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 filter.stats_state.clone(),
             ))
         }
+        LogicalPlan::Assert(assert) => {
+            let input = translate(&assert.input)?;
+            Ok(LocalPhysicalPlan::filter(
+                input,
+                assert.filter_predicate(),
+                assert.stats_state.clone(),
+            ))
+        }
         LogicalPlan::Limit(limit) => {
             let input = translate(&limit.input)?;
             Ok(LocalPhysicalPlan::limit(
@@ -213,6 +226,48 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 monotonically_increasing_id.stats_state.clone(),
             ))
         }
+        LogicalPlan::Window(window) => {
+            let input = translate(&window.input)?;
+            let spec = &window.window_spec;
+            // Window functions are evaluated over the input sorted by the partition by and then the order by
+            // expressions, so that every partition is a run of sorted rows.
+            let sort_by = spec
+                .partition_by
+                .iter()
+                .chain(&spec.order_by)
+                .cloned()
+                .collect::<Vec<_>>();
+            let input = if sort_by.is_empty() {
+                input
+            } else {
+                let descending = std::iter::repeat_n(false, spec.partition_by.len())
+                    .chain(spec.descending.iter().copied())
+                    .collect::<Vec<_>>();
+                let nulls_first = std::iter::repeat_n(false, spec.partition_by.len())
+                    .chain(
+                        spec.descending
+                            .iter()
+                            .map(|desc| spec.null_strategy.nulls_first(*desc)),
+                    )
+                    .collect::<Vec<_>>();
+                LocalPhysicalPlan::sort(
+                    input,
+                    sort_by,
+                    descending,
+                    nulls_first,
+                    window.stats_state.clone(),
+                )
+            };
+            Ok(LocalPhysicalPlan::window(
+                input,
+                window.window_functions.clone(),
+                spec.partition_by.clone(),
+                spec.order_by.clone(),
+                spec.null_strategy,
+                window.schema.clone(),
+                window.stats_state.clone(),
+            ))
+        }
         LogicalPlan::Sink(sink) => {
             use daft_logical_plan::SinkInfo;
             let input = translate(&sink.input)?;
@@ -258,6 +313,9 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 explode.stats_state.clone(),
             ))
         }
+        LogicalPlan::Iterate(_) => Err(DaftError::InternalError(
+            "Iterate should already be run by the executor".to_string(),
+        )),
         LogicalPlan::Intersect(_) => Err(DaftError::InternalError(
             "Intersect should already be optimized away".to_string(),
         )),
@@ -269,3 +327,444 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
         )),
     }
 }
+
+/// Translates a (distributed) physical plan, e.g. a stage emitted by the adaptive planner, into a
+/// local physical plan so that it can be run by the local executor.
+///
+/// Shuffle exchanges are no-ops on a single node, and sort-merge and broadcast joins are run as
+/// hash joins. Previous stage scans must be replaced with the stage's materialized results first.
+pub fn translate_physical(plan: &PhysicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
+    // Physical plans don't carry stats, and the local executor doesn't need them.
+    let stats_state = StatsState::NotMaterialized;
+    match plan.as_ref() {
+        PhysicalPlan::InMemoryScan(ops::InMemoryScan {
+            schema,
+            in_memory_info,
+            ..
+        }) => {
+            let mut info = in_memory_info.clone();
+            info.source_schema = schema.clone();
+            Ok(LocalPhysicalPlan::in_memory_scan(info, stats_state))
+        }
+        PhysicalPlan::TabularScan(ops::TabularScan { scan_tasks, .. }) => {
+            let Some(first) = scan_tasks.first() else {
+                return Err(DaftError::InternalError(
+                    "TabularScan should have at least one scan task".to_string(),
+                ));
+            };
+            Ok(LocalPhysicalPlan::physical_scan(
+                scan_tasks.clone(),
+                first.pushdowns().clone(),
+                first.materialized_schema(),
+                stats_state,
+            ))
+        }
+        PhysicalPlan::EmptyScan(ops::EmptyScan { schema, .. }) => {
+            Ok(LocalPhysicalPlan::empty_scan(schema.clone()))
+        }
+        PhysicalPlan::PreviousStageScan(_) => Err(DaftError::InternalError(
+            "PreviousStageScan should be replaced with the previous stage's results before translation"
+                .to_string(),
+        )),
+        PhysicalPlan::Project(ops::Project {
+            input, projection, ..
+        }) => {
+            let input = translate_physical(input)?;
+            let schema = exprs_to_schema(projection, input.schema().clone())?;
+            Ok(LocalPhysicalPlan::project(
+                input,
+                projection.clone(),
+                schema,
+                stats_state,
+            ))
+        }
+        PhysicalPlan::ActorPoolProject(ops::ActorPoolProject {
+            input, projection, ..
+        }) => {
+            let input = translate_physical(input)?;
+            let schema = exprs_to_schema(projection, input.schema().clone())?;
+            Ok(LocalPhysicalPlan::actor_pool_project(
+                input,
+                projection.clone(),
+                schema,
+                stats_state,
+            ))
+        }
+        PhysicalPlan::Filter(ops::Filter {
+            input, predicate, ..
+        }) => {
+            let input = translate_physical(input)?;
+            Ok(LocalPhysicalPlan::filter(
+                input,
+                predicate.clone(),
+                stats_state,
+            ))
+        }
+        PhysicalPlan::Limit(ops::Limit { input, limit, .. }) => {
+            let input = translate_physical(input)?;
+            Ok(LocalPhysicalPlan::limit(input, *limit, stats_state))
+        }
+        PhysicalPlan::Explode(ops::Explode {
+            input, to_explode, ..
+        }) => {
+            let input = translate_physical(input)?;
+            let explode_exprs = to_explode
+                .iter()
+                .cloned()
+                .map(daft_functions::list::explode)
+                .collect::<Vec<_>>();
+            let explode_schema = exprs_to_schema(&explode_exprs, input.schema().clone())?;
+            let fields = input
+                .schema()
+                .fields
+                .iter()
+                .map(|(name, field)| explode_schema.fields.get(name).unwrap_or(field))
+                .cloned()
+                .collect::<Vec<_>>();
+            Ok(LocalPhysicalPlan::explode(
+                input,
+                to_explode.clone(),
+                Schema::new(fields)?.into(),
+                stats_state,
+            ))
+        }
+        PhysicalPlan::Unpivot(ops::Unpivot {
+            input,
+            ids,
+            values,
+            variable_name,
+            value_name,
+            ..
+        }) => {
+            let input = translate_physical(input)?;
+            let input_schema = input.schema();
+            let value_dtype = values
+                .iter()
+                .map(|expr| Ok(expr.to_field(input_schema)?.dtype))
+                .reduce(|a, b| try_get_supertype(&a?, &b?))
+                .ok_or_else(|| {
+                    DaftError::ValueError(
+                        "Unpivot requires at least one value column".to_string(),
+                    )
+                })??;
+            let fields = ids
+                .iter()
+                .map(|id| id.to_field(input_schema))
+                .chain([
+                    Ok(Field::new(variable_name, DataType::Utf8)),
+                    Ok(Field::new(value_name, value_dtype)),
+                ])
+                .collect::<DaftResult<Vec<_>>>()?;
+            Ok(LocalPhysicalPlan::unpivot(
+                input,
+                ids.clone(),
+                values.clone(),
+                variable_name.clone(),
+                value_name.clone(),
+                Schema::new(fields)?.into(),
+                stats_state,
+            ))
+        }
+        PhysicalPlan::Sort(ops::Sort {
+            input,
+            sort_by,
+            descending,
+            nulls_first,
+            ..
+        }) => {
+            let input = translate_physical(input)?;
+            Ok(LocalPhysicalPlan::sort(
+                input,
+                sort_by.clone(),
+                descending.clone(),
+                nulls_first.clone(),
+                stats_state,
+            ))
+        }
+        PhysicalPlan::Sample(ops::Sample {
+            input,
+            fraction,
+            with_replacement,
+            seed,
+        }) => {
+            let input = translate_physical(input)?;
+            Ok(LocalPhysicalPlan::sample(
+                input,
+                *fraction,
+                *with_replacement,
+                *seed,
+                stats_state,
+            ))
+        }
+        PhysicalPlan::MonotonicallyIncreasingId(ops::MonotonicallyIncreasingId {
+            input,
+            column_name,
+        }) => {
+            let input = translate_physical(input)?;
+            let fields = std::iter::once(Field::new(column_name, DataType::UInt64))
+                .chain(input.schema().fields.values().cloned())
+                .collect();
+            Ok(LocalPhysicalPlan::monotonically_increasing_id(
+                input,
+                column_name.clone(),
+                Schema::new(fields)?.into(),
+                stats_state,
+            ))
+        }
+        PhysicalPlan::Window(ops::Window {
+            input,
+            window_functions,
+            window_spec,
+        }) => {
+            let input = translate_physical(input)?;
+            let function_schema = exprs_to_schema(window_functions, input.schema().clone())?;
+            let fields = input
+                .schema()
+                .fields
+                .values()
+                .chain(function_schema.fields.values())
+                .cloned()
+                .collect();
+            Ok(LocalPhysicalPlan::window(
+                input,
+                window_functions.clone(),
+                window_spec.partition_by.clone(),
+                window_spec.order_by.clone(),
+                window_spec.null_strategy,
+                Schema::new(fields)?.into(),
+                stats_state,
+            ))
+        }
+        PhysicalPlan::Aggregate(ops::Aggregate {
+            input,
+            aggregations,
+            groupby,
+        }) => {
+            let input = translate_physical(input)?;
+            let aggregations = aggregations
+                .iter()
+                .map(|agg| Expr::Agg(agg.clone()).arced())
+                .collect::<Vec<_>>();
+            let schema = exprs_to_schema(
+                &[groupby.as_slice(), aggregations.as_slice()].concat(),
+                input.schema().clone(),
+            )?;
+            if groupby.is_empty() {
+                Ok(LocalPhysicalPlan::ungrouped_aggregate(
+                    input,
+                    aggregations,
+                    schema,
+                    stats_state,
+                ))
+            } else {
+                Ok(LocalPhysicalPlan::hash_aggregate(
+                    input,
+                    aggregations,
+                    groupby.clone(),
+                    schema,
+                    stats_state,
+                ))
+            }
+        }
+        PhysicalPlan::Pivot(ops::Pivot {
+            input,
+            group_by,
+            pivot_column,
+            value_column,
+            names,
+        }) => {
+            let input = translate_physical(input)?;
+            let value_dtype = value_column.to_field(input.schema())?.dtype;
+            let fields = group_by
+                .iter()
+                .map(|expr| expr.to_field(input.schema()))
+                .chain(names.iter().map(|name| Ok(Field::new(name, value_dtype.clone()))))
+                .collect::<DaftResult<Vec<_>>>()?;
+            // The values were already aggregated before the pivot, so there is one per pivot cell.
+            let aggregation = AggExpr::AnyValue(value_column.clone(), false);
+            Ok(LocalPhysicalPlan::pivot(
+                input,
+                group_by.clone(),
+                pivot_column.clone(),
+                value_column.clone(),
+                aggregation,
+                names.clone(),
+                Schema::new(fields)?.into(),
+                stats_state,
+            ))
+        }
+        PhysicalPlan::Concat(ops::Concat { input, other }) => {
+            let input = translate_physical(input)?;
+            let other = translate_physical(other)?;
+            Ok(LocalPhysicalPlan::concat(input, other, stats_state))
+        }
+        PhysicalPlan::HashJoin(ops::HashJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            null_equals_nulls,
+            join_type,
+        }) => translate_physical_hash_join(
+            left,
+            right,
+            left_on,
+            right_on,
+            null_equals_nulls.clone(),
+            *join_type,
+        ),
+        PhysicalPlan::SortMergeJoin(ops::SortMergeJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            join_type,
+            ..
+        }) => translate_physical_hash_join(left, right, left_on, right_on, None, *join_type),
+        PhysicalPlan::BroadcastJoin(ops::BroadcastJoin {
+            broadcaster,
+            receiver,
+            left_on,
+            right_on,
+            null_equals_nulls,
+            join_type,
+            is_swapped,
+        }) => {
+            let (left, right) = if *is_swapped {
+                (receiver, broadcaster)
+            } else {
+                (broadcaster, receiver)
+            };
+            translate_physical_hash_join(
+                left,
+                right,
+                left_on,
+                right_on,
+                null_equals_nulls.clone(),
+                *join_type,
+            )
+        }
+        PhysicalPlan::CrossJoin(ops::CrossJoin { left, right, .. }) => {
+            let left = translate_physical(left)?;
+            let right = translate_physical(right)?;
+            let schema = infer_join_schema(left.schema(), right.schema(), JoinType::Inner)?;
+            Ok(LocalPhysicalPlan::cross_join(
+                left,
+                right,
+                schema,
+                stats_state,
+            ))
+        }
+        PhysicalPlan::TabularWriteParquet(ops::TabularWriteParquet {
+            schema,
+            file_info,
+            input,
+        })
+        | PhysicalPlan::TabularWriteJson(ops::TabularWriteJson {
+            schema,
+            file_info,
+            input,
+        })
+        | PhysicalPlan::TabularWriteCsv(ops::TabularWriteCsv {
+            schema,
+            file_info,
+            input,
+        })
+        | PhysicalPlan::TabularWriteIpc(ops::TabularWriteIpc {
+            schema,
+            file_info,
+            input,
+        }) => {
+            let input = translate_physical(input)?;
+            let data_schema = input.schema().clone();
+            Ok(LocalPhysicalPlan::physical_write(
+                input,
+                data_schema,
+                schema.clone(),
+                file_info.clone(),
+                stats_state,
+            ))
+        }
+        PhysicalPlan::ShuffleExchange(ops::ShuffleExchange { input, .. }) => {
+            // All partitions end up on the one node anyway.
+            translate_physical(input)
+        }
+        #[cfg(feature = "python")]
+        PhysicalPlan::IcebergWrite(ops::IcebergWrite {
+            schema,
+            iceberg_info,
+            input,
+        }) => {
+            let input = translate_physical(input)?;
+            let data_schema = input.schema().clone();
+            Ok(LocalPhysicalPlan::catalog_write(
+                input,
+                daft_logical_plan::CatalogType::Iceberg(iceberg_info.clone()),
+                data_schema,
+                schema.clone(),
+                stats_state,
+            ))
+        }
+        #[cfg(feature = "python")]
+        PhysicalPlan::DeltaLakeWrite(ops::DeltaLakeWrite {
+            schema,
+            delta_lake_info,
+            input,
+        }) => {
+            let input = translate_physical(input)?;
+            let data_schema = input.schema().clone();
+            Ok(LocalPhysicalPlan::catalog_write(
+                input,
+                daft_logical_plan::CatalogType::DeltaLake(delta_lake_info.clone()),
+                data_schema,
+                schema.clone(),
+                stats_state,
+            ))
+        }
+        #[cfg(feature = "python")]
+        PhysicalPlan::LanceWrite(ops::LanceWrite {
+            schema,
+            lance_info,
+            input,
+        }) => {
+            let input = translate_physical(input)?;
+            let data_schema = input.schema().clone();
+            Ok(LocalPhysicalPlan::lance_write(
+                input,
+                lance_info.clone(),
+                data_schema,
+                schema.clone(),
+                stats_state,
+            ))
+        }
+    }
+}
+
+fn translate_physical_hash_join(
+    left: &PhysicalPlanRef,
+    right: &PhysicalPlanRef,
+    left_on: &[ExprRef],
+    right_on: &[ExprRef],
+    null_equals_nulls: Option<Vec<bool>>,
+    join_type: JoinType,
+) -> DaftResult<LocalPhysicalPlanRef> {
+    let left = translate_physical(left)?;
+    let right = translate_physical(right)?;
+    let (left_on, right_on) = normalize_join_keys(
+        left_on.to_vec(),
+        right_on.to_vec(),
+        left.schema().clone(),
+        right.schema().clone(),
+    )?;
+    let schema = infer_join_schema(left.schema(), right.schema(), join_type)?;
+    Ok(LocalPhysicalPlan::hash_join(
+        left,
+        right,
+        left_on,
+        right_on,
+        null_equals_nulls,
+        join_type,
+        schema,
+        StatsState::NotMaterialized,
+    ))
+}
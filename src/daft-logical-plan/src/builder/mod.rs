@@ -3,6 +3,7 @@ mod resolve_expr;
 use std::{
     collections::{HashMap, HashSet},
     future::Future,
+    hash::{DefaultHasher, Hash, Hasher},
     sync::Arc,
 };
 
@@ -11,9 +12,15 @@ use common_display::mermaid::MermaidDisplayOptions;
 use common_error::{DaftError, DaftResult};
 use common_file_formats::FileFormat;
 use common_io_config::IOConfig;
-use common_scan_info::{PhysicalScanInfo, Pushdowns, ScanOperatorRef};
-use daft_core::join::{JoinStrategy, JoinType};
-use daft_dsl::{resolved_col, ExprRef};
+use common_scan_info::{PhysicalScanInfo, Pushdowns, ScanOperatorRef, ScanState};
+use common_treenode::{TreeNode, TreeNodeRecursion};
+use daft_core::{
+    array::ops::Collation,
+    join::{JoinStrategy, JoinType},
+    prelude::DataType,
+};
+use daft_dsl::{resolved_col, ExprRef, Subquery};
+use daft_functions::utf8::collation_key;
 use daft_schema::schema::{Schema, SchemaRef};
 use indexmap::IndexSet;
 use resolve_expr::ExprResolver;
@@ -33,9 +40,10 @@ use crate::{
     ops::{self, join::JoinOptions, SetQuantifier, UnionStrategy},
     optimization::OptimizerBuilder,
     partitioning::{
-        HashRepartitionConfig, IntoPartitionsConfig, RandomShuffleConfig, RepartitionSpec,
+        HashRepartitionConfig, IntoPartitionsConfig, RandomShuffleConfig, RangeRepartitionConfig,
+        RepartitionSpec,
     },
-    sink_info::{OutputFileInfo, SinkInfo},
+    sink_info::{OutputFileInfo, ParquetEncryptionInfo, SinkInfo},
     source_info::{InMemoryInfo, SourceInfo},
     LogicalPlanRef,
 };
@@ -154,6 +162,24 @@ impl LogicalPlanBuilder {
         Ok(Self::from(Arc::new(logical_plan)))
     }
 
+    /// Creates an in-memory scan of the results of the iterations of an [`Iterate`](ops::Iterate), which the executor
+    /// puts into its partition sets under `partition_key` before running the plans that read them.
+    pub fn iteration_scan(partition_key: &str, schema: Arc<Schema>) -> Self {
+        let source_info = SourceInfo::InMemory(InMemoryInfo::new(
+            schema.clone(),
+            partition_key.into(),
+            None,
+            1,
+            0,
+            0,
+            None,
+            None,
+        ));
+        let logical_plan: LogicalPlan = ops::Source::new(schema, source_info.into()).into();
+
+        Self::from(Arc::new(logical_plan))
+    }
+
     /// Creates a `LogicalPlan::Source` from a scan handle.
     pub fn table_scan(
         scan_operator: ScanOperatorRef,
@@ -211,6 +237,7 @@ impl LogicalPlanBuilder {
         let expr_resolver = ExprResolver::builder()
             .allow_actor_pool_udf(true)
             .allow_monotonic_id(true)
+            .allow_window(true)
             .build();
 
         let to_select = expr_resolver.resolve(to_select, self.plan.clone())?;
@@ -223,6 +250,7 @@ impl LogicalPlanBuilder {
         let expr_resolver = ExprResolver::builder()
             .allow_actor_pool_udf(true)
             .allow_monotonic_id(true)
+            .allow_window(true)
             .build();
 
         let columns = expr_resolver.resolve(columns, self.plan.clone())?;
@@ -308,7 +336,7 @@ impl LogicalPlanBuilder {
     }
 
     pub fn filter(&self, predicate: ExprRef) -> DaftResult<Self> {
-        let expr_resolver = ExprResolver::default();
+        let expr_resolver = ExprResolver::builder().allow_monotonic_id(true).build();
 
         let predicate = expr_resolver.resolve_single(predicate, self.plan.clone())?;
 
@@ -316,6 +344,46 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    pub fn assert_that(
+        &self,
+        predicate: ExprRef,
+        message: String,
+        violations_id: Option<String>,
+    ) -> DaftResult<Self> {
+        let expr_resolver = ExprResolver::default();
+
+        let predicate = expr_resolver.resolve_single(predicate, self.plan.clone())?;
+
+        let logical_plan: LogicalPlan =
+            ops::Assert::try_new(self.plan.clone(), predicate, message, violations_id)?.into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
+    /// Repeatedly runs `body` on the result of its previous run, starting with this plan, until `convergence` is true
+    /// or `max_iters` iterations have run.
+    ///
+    /// `body` and `convergence` read the results of the iterations from [`Self::iteration_scan`]s of `previous_key`
+    /// and `result_key`.
+    pub fn iterate(
+        &self,
+        body: &Self,
+        convergence: &Self,
+        previous_key: String,
+        result_key: String,
+        max_iters: usize,
+    ) -> DaftResult<Self> {
+        let logical_plan: LogicalPlan = ops::Iterate::try_new(
+            self.plan.clone(),
+            body.build(),
+            convergence.build(),
+            previous_key,
+            result_key,
+            max_iters,
+        )?
+        .into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
     pub fn limit(&self, limit: i64, eager: bool) -> DaftResult<Self> {
         let logical_plan: LogicalPlan = ops::Limit::new(self.plan.clone(), limit, eager).into();
         Ok(self.with_new_plan(logical_plan))
@@ -374,7 +442,56 @@ impl LogicalPlanBuilder {
         let sort_by = expr_resolver.resolve(sort_by, self.plan.clone())?;
 
         let logical_plan: LogicalPlan =
-            ops::Sort::try_new(self.plan.clone(), sort_by, descending, nulls_first)?.into();
+            ops::Sort::try_new(self.plan.clone(), sort_by, descending, nulls_first, false)?.into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
+    /// Sort keys that order strings by the given collations, with a collation for each of `sort_by`.
+    ///
+    /// String keys are replaced with collation keys whose byte order is the order of the strings under their
+    /// collation, while keys of other types and keys with the binary collation are left as they are.
+    pub fn collate_sort_keys(
+        &self,
+        sort_by: Vec<ExprRef>,
+        collations: &[Collation],
+    ) -> DaftResult<Vec<ExprRef>> {
+        if sort_by.len() != collations.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected a collation for each of the {} sort keys, but received {}",
+                sort_by.len(),
+                collations.len()
+            )));
+        }
+        let schema = self.schema();
+        sort_by
+            .into_iter()
+            .zip(collations)
+            .map(|(expr, collation)| {
+                Ok(
+                    if *collation != Collation::Binary
+                        && expr.to_field(&schema)?.dtype == DataType::Utf8
+                    {
+                        collation_key(expr, *collation)
+                    } else {
+                        expr
+                    },
+                )
+            })
+            .collect()
+    }
+
+    pub fn sort_within_partitions(
+        &self,
+        sort_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+    ) -> DaftResult<Self> {
+        let expr_resolver = ExprResolver::default();
+
+        let sort_by = expr_resolver.resolve(sort_by, self.plan.clone())?;
+
+        let logical_plan: LogicalPlan =
+            ops::Sort::try_new(self.plan.clone(), sort_by, descending, nulls_first, true)?.into();
         Ok(self.with_new_plan(logical_plan))
     }
 
@@ -395,6 +512,42 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    pub fn range_repartition(
+        &self,
+        num_partitions: Option<usize>,
+        partition_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+    ) -> DaftResult<Self> {
+        if partition_by.is_empty() {
+            return Err(DaftError::ValueError(
+                "Repartitioning by range requires at least one column/expression to partition by"
+                    .to_string(),
+            ));
+        }
+        if partition_by.len() != descending.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected as many descending flags as partition by expressions, got {} and {}",
+                descending.len(),
+                partition_by.len()
+            )));
+        }
+
+        let expr_resolver = ExprResolver::default();
+
+        let partition_by = expr_resolver.resolve(partition_by, self.plan.clone())?;
+
+        let logical_plan: LogicalPlan = ops::Repartition::new(
+            self.plan.clone(),
+            RepartitionSpec::Range(RangeRepartitionConfig::new(
+                num_partitions,
+                partition_by,
+                descending,
+            )),
+        )
+        .into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
     pub fn random_shuffle(&self, num_partitions: Option<usize>) -> DaftResult<Self> {
         let logical_plan: LogicalPlan = ops::Repartition::new(
             self.plan.clone(),
@@ -636,6 +789,7 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn table_write(
         &self,
         root_dir: &str,
@@ -643,6 +797,10 @@ impl LogicalPlanBuilder {
         partition_cols: Option<Vec<ExprRef>>,
         compression: Option<String>,
         io_config: Option<IOConfig>,
+        job_id: Option<String>,
+        bloom_filter_columns: Option<Vec<String>>,
+        row_group_size_bytes: Option<usize>,
+        encryption: Option<ParquetEncryptionInfo>,
     ) -> DaftResult<Self> {
         let expr_resolver = ExprResolver::default();
 
@@ -650,13 +808,52 @@ impl LogicalPlanBuilder {
             .map(|cols| expr_resolver.resolve(cols, self.plan.clone()))
             .transpose()?;
 
-        let sink_info = SinkInfo::OutputFileInfo(OutputFileInfo::new(
+        if let Some(bloom_filter_columns) = &bloom_filter_columns {
+            if file_format != FileFormat::Parquet {
+                return Err(DaftError::ValueError(format!(
+                    "Bloom filters are only supported for Parquet writes, but got: {file_format:?}"
+                )));
+            }
+            let schema = self.schema();
+            for column in bloom_filter_columns {
+                schema.get_field(column)?;
+            }
+        }
+
+        if let Some(row_group_size_bytes) = row_group_size_bytes {
+            if file_format != FileFormat::Parquet {
+                return Err(DaftError::ValueError(format!(
+                    "Row group sizes are only supported for Parquet writes, but got: {file_format:?}"
+                )));
+            }
+            if row_group_size_bytes == 0 {
+                return Err(DaftError::ValueError(
+                    "Row group size must be positive".to_string(),
+                ));
+            }
+        }
+
+        if file_format == FileFormat::ArrowIpc
+            && let Some(compression) = &compression
+            && !["lz4", "zstd"].contains(&compression.as_str())
+        {
+            return Err(DaftError::ValueError(format!(
+                "Arrow IPC writes only support lz4 and zstd compression, but got: {compression}"
+            )));
+        }
+
+        let mut file_info = OutputFileInfo::new(
             root_dir.into(),
             file_format,
             partition_cols,
             compression,
             io_config,
-        ));
+            job_id,
+        );
+        file_info.bloom_filter_columns = bloom_filter_columns;
+        file_info.row_group_size_bytes = row_group_size_bytes;
+        file_info.encryption = encryption;
+        let sink_info = SinkInfo::OutputFileInfo(file_info);
 
         let logical_plan: LogicalPlan =
             ops::Sink::try_new(self.plan.clone(), sink_info.into())?.into();
@@ -767,6 +964,11 @@ impl LogicalPlanBuilder {
                     |builder| builder.reorder_joins(),
                 )
                 .simplify_expressions()
+                .without_rules(
+                    cfg.as_ref()
+                        .map(|conf| conf.disabled_optimizer_rules.as_slice())
+                        .unwrap_or_default(),
+                )
                 .build();
 
             let optimized_plan = optimizer.optimize(
@@ -820,6 +1022,11 @@ impl LogicalPlanBuilder {
                 |builder| builder.reorder_joins(),
             )
             .simplify_expressions()
+            .without_rules(
+                cfg.as_ref()
+                    .map(|conf| conf.disabled_optimizer_rules.as_slice())
+                    .unwrap_or_default(),
+            )
             .build();
 
         let optimized_plan = optimizer.optimize(
@@ -852,18 +1059,87 @@ impl LogicalPlanBuilder {
         self.plan.clone()
     }
 
+    /// Checks whether the values of `expr` are in the single column of this plan.
+    ///
+    /// Filters on the resulting expression are planned as semi joins with this plan, or anti joins when negated.
+    pub fn in_subquery(&self, expr: ExprRef) -> DaftResult<ExprRef> {
+        let num_columns = self.schema().len();
+        if num_columns != 1 {
+            return Err(DaftError::ValueError(format!(
+                "Expected IN subquery to have one output column, received: {num_columns}"
+            )));
+        }
+        Ok(expr.in_subquery(Subquery { plan: self.build() }))
+    }
+
     pub fn schema(&self) -> SchemaRef {
         self.plan.schema()
     }
 
+    /// A hash of the plan within the current process. Equal plans have equal fingerprints, but plans with equal
+    /// fingerprints may differ, so they must be compared to tell whether they compute the same query.
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.plan.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Whether the results of this plan can be reused instead of executing it again, i.e. it doesn't write any data
+    /// and produces the same rows every time that it reads the same data.
+    pub fn is_cacheable(&self) -> bool {
+        !self.plan.exists(|node| {
+            matches!(node.as_ref(), LogicalPlan::Sink(..)) || !node.is_deterministic()
+        })
+    }
+
+    /// The path and current version of every file read by the materialized scans of this plan, see
+    /// [`ScanTaskLike::file_versions`](common_scan_info::ScanTaskLike::file_versions).
+    pub fn source_file_versions(&self) -> DaftResult<Vec<(String, Option<String>)>> {
+        let mut versions = vec![];
+        self.plan.apply(|node| {
+            if let LogicalPlan::Source(ops::Source { source_info, .. }) = node.as_ref() {
+                if let SourceInfo::Physical(PhysicalScanInfo {
+                    scan_state: ScanState::Tasks(scan_tasks),
+                    ..
+                }) = source_info.as_ref()
+                {
+                    for scan_task in scan_tasks.iter() {
+                        versions.extend(scan_task.file_versions()?);
+                    }
+                }
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })?;
+        Ok(versions)
+    }
+
+    /// The approximate in-memory size of the output of this plan, estimated by optimizing it and
+    /// propagating the sizes of its sources through the selectivity of its operators.
+    pub fn estimated_size_bytes(&self) -> DaftResult<usize> {
+        Ok(self
+            .optimize()?
+            .plan
+            .materialized_stats()
+            .approx_stats
+            .size_bytes)
+    }
+
     pub fn repr_ascii(&self, simple: bool) -> String {
         self.plan.repr_ascii(simple)
     }
 
+    pub fn repr_ascii_verbose(&self) -> String {
+        self.plan.repr_ascii_verbose()
+    }
+
     pub fn repr_mermaid(&self, opts: MermaidDisplayOptions) -> String {
         use common_display::mermaid::MermaidDisplay;
         self.plan.repr_mermaid(opts)
     }
+
+    pub fn repr_json(&self, simple: bool) -> String {
+        self.plan.repr_json(simple)
+    }
 }
 
 /// A Python-facing wrapper of the LogicalPlanBuilder.
@@ -889,6 +1165,25 @@ fn pyexprs_to_exprs(vec: Vec<PyExpr>) -> Vec<ExprRef> {
     vec.into_iter().map(|e| e.into()).collect()
 }
 
+#[cfg(feature = "python")]
+fn pyexprs_to_collated_exprs(
+    builder: &LogicalPlanBuilder,
+    vec: Vec<PyExpr>,
+    collations: Option<Vec<String>>,
+) -> DaftResult<Vec<ExprRef>> {
+    let exprs = pyexprs_to_exprs(vec);
+    match collations {
+        Some(collations) => {
+            let collations = collations
+                .iter()
+                .map(|collation| collation.parse())
+                .collect::<DaftResult<Vec<Collation>>>()?;
+            builder.collate_sort_keys(exprs, &collations)
+        }
+        None => Ok(exprs),
+    }
+}
+
 #[cfg(feature = "python")]
 #[pymethods]
 impl PyLogicalPlanBuilder {
@@ -912,6 +1207,11 @@ impl PyLogicalPlanBuilder {
         .into())
     }
 
+    #[staticmethod]
+    pub fn iteration_scan(partition_key: &str, schema: PySchema) -> Self {
+        LogicalPlanBuilder::iteration_scan(partition_key, schema.into()).into()
+    }
+
     pub fn with_planning_config(
         &self,
         daft_planning_config: PyDaftPlanningConfig,
@@ -939,6 +1239,43 @@ impl PyLogicalPlanBuilder {
         Ok(self.builder.filter(predicate.expr)?.into())
     }
 
+    pub fn in_subquery(&self, expr: PyExpr) -> PyResult<PyExpr> {
+        Ok(self.builder.in_subquery(expr.expr)?.into())
+    }
+
+    #[pyo3(signature = (predicate, message, violations_id=None))]
+    pub fn assert_that(
+        &self,
+        predicate: PyExpr,
+        message: String,
+        violations_id: Option<String>,
+    ) -> PyResult<Self> {
+        Ok(self
+            .builder
+            .assert_that(predicate.expr, message, violations_id)?
+            .into())
+    }
+
+    pub fn iterate(
+        &self,
+        body: &Self,
+        convergence: &Self,
+        previous_key: String,
+        result_key: String,
+        max_iters: usize,
+    ) -> PyResult<Self> {
+        Ok(self
+            .builder
+            .iterate(
+                &body.builder,
+                &convergence.builder,
+                previous_key,
+                result_key,
+                max_iters,
+            )?
+            .into())
+    }
+
     pub fn limit(&self, limit: i64, eager: bool) -> PyResult<Self> {
         Ok(self.builder.limit(limit, eager)?.into())
     }
@@ -968,15 +1305,30 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    #[pyo3(signature = (sort_by, descending, nulls_first, collations=None))]
     pub fn sort(
         &self,
         sort_by: Vec<PyExpr>,
         descending: Vec<bool>,
         nulls_first: Vec<bool>,
+        collations: Option<Vec<String>>,
+    ) -> PyResult<Self> {
+        let sort_by = pyexprs_to_collated_exprs(&self.builder, sort_by, collations)?;
+        Ok(self.builder.sort(sort_by, descending, nulls_first)?.into())
+    }
+
+    #[pyo3(signature = (sort_by, descending, nulls_first, collations=None))]
+    pub fn sort_within_partitions(
+        &self,
+        sort_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        collations: Option<Vec<String>>,
     ) -> PyResult<Self> {
+        let sort_by = pyexprs_to_collated_exprs(&self.builder, sort_by, collations)?;
         Ok(self
             .builder
-            .sort(pyexprs_to_exprs(sort_by), descending, nulls_first)?
+            .sort_within_partitions(sort_by, descending, nulls_first)?
             .into())
     }
 
@@ -992,6 +1344,19 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    #[pyo3(signature = (partition_by, descending, num_partitions=None))]
+    pub fn range_repartition(
+        &self,
+        partition_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+        num_partitions: Option<usize>,
+    ) -> PyResult<Self> {
+        Ok(self
+            .builder
+            .range_repartition(num_partitions, pyexprs_to_exprs(partition_by), descending)?
+            .into())
+    }
+
     #[pyo3(signature = (num_partitions=None))]
     pub fn random_shuffle(&self, num_partitions: Option<usize>) -> PyResult<Self> {
         Ok(self.builder.random_shuffle(num_partitions)?.into())
@@ -1128,12 +1493,17 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (
         root_dir,
         file_format,
         partition_cols=None,
         compression=None,
-        io_config=None
+        io_config=None,
+        job_id=None,
+        bloom_filter_columns=None,
+        row_group_size_bytes=None,
+        encryption=None
     ))]
     pub fn table_write(
         &self,
@@ -1142,6 +1512,10 @@ impl PyLogicalPlanBuilder {
         partition_cols: Option<Vec<PyExpr>>,
         compression: Option<String>,
         io_config: Option<common_io_config::python::IOConfig>,
+        job_id: Option<String>,
+        bloom_filter_columns: Option<Vec<String>>,
+        row_group_size_bytes: Option<usize>,
+        encryption: Option<PyObject>,
     ) -> PyResult<Self> {
         Ok(self
             .builder
@@ -1151,6 +1525,12 @@ impl PyLogicalPlanBuilder {
                 partition_cols.map(pyexprs_to_exprs),
                 compression,
                 io_config.map(|cfg| cfg.config),
+                job_id,
+                bloom_filter_columns,
+                row_group_size_bytes,
+                encryption.map(|config| ParquetEncryptionInfo {
+                    config: config.into(),
+                }),
             )?
             .into())
     }
@@ -1264,13 +1644,42 @@ impl PyLogicalPlanBuilder {
         py.allow_threads(|| Ok(self.builder.optimize()?.into()))
     }
 
-    pub fn repr_ascii(&self, simple: bool) -> PyResult<String> {
-        Ok(self.builder.repr_ascii(simple))
+    pub fn fingerprint(&self) -> u64 {
+        self.builder.fingerprint()
+    }
+
+    pub fn plan_eq(&self, other: &Self) -> bool {
+        self.builder.plan == other.builder.plan
+    }
+
+    pub fn is_cacheable(&self) -> bool {
+        self.builder.is_cacheable()
+    }
+
+    pub fn source_file_versions(&self, py: Python) -> PyResult<Vec<(String, Option<String>)>> {
+        py.allow_threads(|| Ok(self.builder.source_file_versions()?))
+    }
+
+    pub fn estimated_size_bytes(&self, py: Python) -> PyResult<usize> {
+        py.allow_threads(|| Ok(self.builder.estimated_size_bytes()?))
+    }
+
+    #[pyo3(signature = (simple, verbose=false))]
+    pub fn repr_ascii(&self, simple: bool, verbose: bool) -> PyResult<String> {
+        if verbose && !simple {
+            Ok(self.builder.repr_ascii_verbose())
+        } else {
+            Ok(self.builder.repr_ascii(simple))
+        }
     }
 
     pub fn repr_mermaid(&self, opts: MermaidDisplayOptions) -> String {
         self.builder.repr_mermaid(opts)
     }
+
+    pub fn repr_json(&self, simple: bool) -> String {
+        self.builder.repr_json(simple)
+    }
 }
 
 impl From<LogicalPlanBuilder> for PyLogicalPlanBuilder {
@@ -5,7 +5,7 @@ use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
 use daft_core::prelude::*;
 use daft_dsl::{
     functions::{struct_::StructExpr, FunctionExpr},
-    has_agg, is_actor_pool_udf, resolved_col, AggExpr, Column, Expr, ExprRef, PlanRef,
+    has_agg, has_window, is_actor_pool_udf, resolved_col, AggExpr, Column, Expr, ExprRef, PlanRef,
     ResolvedColumn, UnresolvedColumn,
 };
 use typed_builder::TypedBuilder;
@@ -19,6 +19,26 @@ fn contains_monotonic_id(expr: &ExprRef) -> bool {
     })
 }
 
+/// Checks that the window functions of an expression are ones that can be planned.
+fn validate_window_functions(expr: &ExprRef) -> DaftResult<()> {
+    expr.apply(|e| {
+        if let Expr::Window(window_expr, spec) = e.as_ref() {
+            if e.children().iter().any(has_window) {
+                return Err(DaftError::ValueError(format!(
+                    "Window functions cannot be nested: {e}"
+                )));
+            }
+            if spec.frame.is_some() {
+                return Err(DaftError::ValueError(format!(
+                    "Window frames are not supported for {window_expr}, which is computed over whole partitions"
+                )));
+            }
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })?;
+    Ok(())
+}
+
 /// Duplicate an expression tree for each wildcard match in a column or struct get.
 fn expand_wildcard(expr: ExprRef, plan: LogicalPlanRef) -> DaftResult<Vec<ExprRef>> {
     let mut wildcard_expansion = None;
@@ -200,6 +220,8 @@ pub struct ExprResolver<'a> {
     allow_actor_pool_udf: bool,
     #[builder(default)]
     allow_monotonic_id: bool,
+    #[builder(default)]
+    allow_window: bool,
     #[builder(via_mutators, mutators(
         pub fn in_agg_context(&mut self, in_agg_context: bool) {
             // workaround since typed_builder can't have defaults for mutator requirements
@@ -226,10 +248,20 @@ impl ExprResolver<'_> {
 
         if !self.allow_monotonic_id && contains_monotonic_id(&expr) {
             return Err(DaftError::ValueError(
-                "monotonically_increasing_id() is only allowed in projections".to_string(),
+                "monotonically_increasing_id() is only allowed in projections and filters"
+                    .to_string(),
             ));
         }
 
+        if has_window(&expr) {
+            if !self.allow_window {
+                return Err(DaftError::ValueError(format!(
+                    "Window functions are only allowed in projections: {expr}"
+                )));
+            }
+            validate_window_functions(&expr)?;
+        }
+
         expand_wildcard(expr, plan.clone())?
             .into_iter()
             .map(|e| resolve_unresolved_columns(e, plan.clone()))
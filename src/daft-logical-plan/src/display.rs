@@ -6,7 +6,16 @@ impl TreeDisplay for crate::LogicalPlan {
     fn display_as(&self, level: DisplayLevel) -> String {
         match level {
             DisplayLevel::Compact => self.name().to_string(),
-            DisplayLevel::Default | DisplayLevel::Verbose => self.multiline_display().join("\n"),
+            DisplayLevel::Default => self.multiline_display().join("\n"),
+            DisplayLevel::Verbose => {
+                let mut lines = self.multiline_display();
+                // Sources already display their output schema.
+                if !matches!(self, Self::Source(_)) {
+                    lines.push(format!("Output schema = {}", self.schema().short_string()));
+                }
+                lines.push(format!("Semantic ID = {}", self.semantic_id()));
+                lines.join("\n")
+            }
         }
     }
 
@@ -242,4 +251,30 @@ end
         assert_eq!(mermaid_repr, expected);
         Ok(())
     }
+
+    #[test]
+    fn test_semantic_id() -> DaftResult<()> {
+        let filtered = |value: i32| -> DaftResult<LogicalPlanRef> {
+            Ok(LogicalPlanBuilder::from(plan_1())
+                .filter(resolved_col("id").eq(lit(value)))?
+                .build())
+        };
+
+        // Equal plans have the same ID even when they are built separately, and different ones don't.
+        let plan = filtered(1)?;
+        assert_eq!(plan.semantic_id(), filtered(1)?.semantic_id());
+        assert_ne!(plan.semantic_id(), filtered(2)?.semantic_id());
+        assert!(plan.semantic_id().starts_with("Filter-"));
+
+        // The ID of a node depends on its children.
+        let plan_2_filtered = LogicalPlanBuilder::from(plan_2())
+            .filter(resolved_col("id").eq(lit(1)))?
+            .build();
+        assert_ne!(plan.semantic_id(), plan_2_filtered.semantic_id());
+
+        assert!(plan
+            .repr_ascii_verbose()
+            .contains(&format!("Semantic ID = {}", plan.semantic_id())));
+        Ok(())
+    }
 }
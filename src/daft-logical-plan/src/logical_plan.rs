@@ -1,20 +1,26 @@
 use std::{
     any::Any,
-    hash::{Hash, Hasher},
+    hash::{DefaultHasher, Hash, Hasher},
     num::NonZeroUsize,
     sync::Arc,
 };
 
-use common_display::ascii::AsciiTreeDisplay;
+use common_display::{ascii::AsciiTreeDisplay, DisplayLevel};
 use common_error::{DaftError, DaftResult};
+use common_scan_info::PhysicalScanInfo;
 use common_treenode::TreeNodeRecursion;
-use daft_dsl::{optimization::get_required_columns, Subquery, SubqueryPlan};
+use daft_dsl::{
+    is_deterministic, optimization::get_required_columns, ExprRef, Subquery, SubqueryPlan,
+};
 use daft_schema::schema::SchemaRef;
 use indexmap::IndexSet;
 use snafu::Snafu;
 
 pub use crate::ops::*;
-use crate::stats::{PlanStats, StatsState};
+use crate::{
+    source_info::SourceInfo,
+    stats::{PlanStats, StatsState},
+};
 
 /// Logical plan for a Daft query.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -23,6 +29,7 @@ pub enum LogicalPlan {
     Project(Project),
     ActorPoolProject(ActorPoolProject),
     Filter(Filter),
+    Assert(Assert),
     Limit(Limit),
     Explode(Explode),
     Unpivot(Unpivot),
@@ -38,6 +45,8 @@ pub enum LogicalPlan {
     Sink(Sink),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
+    Window(Window),
+    Iterate(Iterate),
     SubqueryAlias(SubqueryAlias),
 }
 
@@ -84,6 +93,7 @@ impl LogicalPlan {
                 projected_schema, ..
             }) => projected_schema.clone(),
             Self::Filter(Filter { input, .. }) => input.schema(),
+            Self::Assert(Assert { input, .. }) => input.schema(),
             Self::Limit(Limit { input, .. }) => input.schema(),
             Self::Explode(Explode {
                 exploded_schema, ..
@@ -103,6 +113,8 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. }) => {
                 schema.clone()
             }
+            Self::Window(Window { schema, .. }) => schema.clone(),
+            Self::Iterate(Iterate { input, .. }) => input.schema(),
             Self::SubqueryAlias(SubqueryAlias { input, .. }) => input.schema(),
         }
     }
@@ -132,6 +144,14 @@ impl LogicalPlan {
                     .cloned()
                     .collect()]
             }
+            Self::Assert(assert) => match assert.violations_id {
+                // All the columns of violating rows are recorded.
+                Some(_) => vec![assert.input.schema().names().into_iter().collect()],
+                None => vec![get_required_columns(&assert.predicate)
+                    .iter()
+                    .cloned()
+                    .collect()],
+            },
             Self::Sort(sort) => {
                 let res = sort.sort_by.iter().flat_map(get_required_columns).collect();
                 vec![res]
@@ -193,6 +213,14 @@ impl LogicalPlan {
                     .collect();
                 vec![res]
             }
+            Self::Window(window) => {
+                let res = window
+                    .window_functions
+                    .iter()
+                    .flat_map(get_required_columns)
+                    .collect();
+                vec![res]
+            }
             Self::Join(join) => {
                 let left = join.left_on.iter().flat_map(get_required_columns).collect();
                 let right = join
@@ -202,6 +230,8 @@ impl LogicalPlan {
                     .collect();
                 vec![left, right]
             }
+            // Each iteration reads all the columns of the previous one's result.
+            Self::Iterate(iterate) => vec![iterate.input.schema().names().into_iter().collect()],
             Self::Intersect(_) => vec![IndexSet::new(), IndexSet::new()],
             Self::Union(_) => vec![IndexSet::new(), IndexSet::new()],
             Self::Source(_) => todo!(),
@@ -210,12 +240,89 @@ impl LogicalPlan {
         }
     }
 
+    /// Whether the node produces the same rows every time it's executed on the same inputs, i.e. all of its
+    /// expressions are deterministic and it doesn't sample rows without a seed.
+    pub fn is_deterministic(&self) -> bool {
+        let exprs: Vec<ExprRef> = match self {
+            Self::Source(Source { source_info, .. }) => match source_info.as_ref() {
+                SourceInfo::Physical(PhysicalScanInfo { pushdowns, .. }) => pushdowns
+                    .filters
+                    .iter()
+                    .chain(pushdowns.partition_filters.iter())
+                    .cloned()
+                    .collect(),
+                _ => vec![],
+            },
+            Self::Project(Project { projection, .. })
+            | Self::ActorPoolProject(ActorPoolProject { projection, .. }) => projection.clone(),
+            // Recording violations is a side effect of executing it.
+            Self::Assert(Assert {
+                violations_id: Some(_),
+                ..
+            }) => return false,
+            Self::Filter(Filter { predicate, .. }) | Self::Assert(Assert { predicate, .. }) => {
+                vec![predicate.clone()]
+            }
+            Self::Sort(Sort { sort_by, .. }) => sort_by.clone(),
+            Self::Repartition(Repartition {
+                repartition_spec, ..
+            }) => repartition_spec.repartition_by(),
+            Self::Explode(Explode { to_explode, .. }) => to_explode.clone(),
+            Self::Unpivot(Unpivot { ids, values, .. }) => {
+                ids.iter().chain(values.iter()).cloned().collect()
+            }
+            Self::Aggregate(Aggregate {
+                aggregations,
+                groupby,
+                ..
+            }) => aggregations.iter().chain(groupby.iter()).cloned().collect(),
+            Self::Pivot(Pivot {
+                group_by,
+                pivot_column,
+                value_column,
+                aggregation,
+                ..
+            }) => group_by
+                .iter()
+                .chain([pivot_column, value_column])
+                .cloned()
+                .chain(aggregation.children())
+                .collect(),
+            Self::Window(Window {
+                window_functions, ..
+            }) => window_functions.clone(),
+            Self::Join(Join {
+                left_on, right_on, ..
+            }) => left_on.iter().chain(right_on.iter()).cloned().collect(),
+            Self::Sample(Sample { seed, .. }) => return seed.is_some(),
+            Self::Iterate(Iterate {
+                body, convergence, ..
+            }) => {
+                use common_treenode::TreeNode;
+
+                // An iteration is as deterministic as the plans it runs.
+                return !body.exists(|node| !node.is_deterministic())
+                    && !convergence.exists(|node| !node.is_deterministic());
+            }
+            Self::Limit(..)
+            | Self::Distinct(..)
+            | Self::Concat(..)
+            | Self::Intersect(..)
+            | Self::Union(..)
+            | Self::Sink(..)
+            | Self::MonotonicallyIncreasingId(..)
+            | Self::SubqueryAlias(..) => vec![],
+        };
+        exprs.iter().all(is_deterministic)
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             Self::Source(..) => "Source",
             Self::Project(..) => "Project",
             Self::ActorPoolProject(..) => "ActorPoolProject",
             Self::Filter(..) => "Filter",
+            Self::Assert(..) => "Assert",
             Self::Limit(..) => "Limit",
             Self::Explode(..) => "Explode",
             Self::Unpivot(..) => "Unpivot",
@@ -231,6 +338,8 @@ impl LogicalPlan {
             Self::Sink(..) => "Sink",
             Self::Sample(..) => "Sample",
             Self::MonotonicallyIncreasingId(..) => "MonotonicallyIncreasingId",
+            Self::Window(..) => "Window",
+            Self::Iterate(..) => "Iterate",
             Self::SubqueryAlias(..) => "Alias",
         }
     }
@@ -241,6 +350,7 @@ impl LogicalPlan {
             | Self::Project(Project { stats_state, .. })
             | Self::ActorPoolProject(ActorPoolProject { stats_state, .. })
             | Self::Filter(Filter { stats_state, .. })
+            | Self::Assert(Assert { stats_state, .. })
             | Self::Limit(Limit { stats_state, .. })
             | Self::Explode(Explode { stats_state, .. })
             | Self::Unpivot(Unpivot { stats_state, .. })
@@ -253,9 +363,43 @@ impl LogicalPlan {
             | Self::Join(Join { stats_state, .. })
             | Self::Sink(Sink { stats_state, .. })
             | Self::Sample(Sample { stats_state, .. })
-            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { stats_state, .. }) => {
-                stats_state
+            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { stats_state, .. })
+            | Self::Window(Window { stats_state, .. })
+            | Self::Iterate(Iterate { stats_state, .. }) => stats_state,
+            Self::Intersect(_) => {
+                panic!("Intersect nodes should be optimized away before stats are materialized")
+            }
+            Self::Union(_) => {
+                panic!("Union nodes should be optimized away before stats are materialized")
             }
+            Self::SubqueryAlias(_) => {
+                panic!("Alias nodes should be optimized away before stats are materialized")
+            }
+        }
+    }
+
+    fn stats_state_mut(&mut self) -> &mut StatsState {
+        match self {
+            Self::Source(Source { stats_state, .. })
+            | Self::Project(Project { stats_state, .. })
+            | Self::ActorPoolProject(ActorPoolProject { stats_state, .. })
+            | Self::Filter(Filter { stats_state, .. })
+            | Self::Assert(Assert { stats_state, .. })
+            | Self::Limit(Limit { stats_state, .. })
+            | Self::Explode(Explode { stats_state, .. })
+            | Self::Unpivot(Unpivot { stats_state, .. })
+            | Self::Sort(Sort { stats_state, .. })
+            | Self::Repartition(Repartition { stats_state, .. })
+            | Self::Distinct(Distinct { stats_state, .. })
+            | Self::Aggregate(Aggregate { stats_state, .. })
+            | Self::Pivot(Pivot { stats_state, .. })
+            | Self::Concat(Concat { stats_state, .. })
+            | Self::Join(Join { stats_state, .. })
+            | Self::Sink(Sink { stats_state, .. })
+            | Self::Sample(Sample { stats_state, .. })
+            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { stats_state, .. })
+            | Self::Window(Window { stats_state, .. })
+            | Self::Iterate(Iterate { stats_state, .. }) => stats_state,
             Self::Intersect(_) => {
                 panic!("Intersect nodes should be optimized away before stats are materialized")
             }
@@ -275,11 +419,23 @@ impl LogicalPlan {
     // Materializes stats over logical plans. If stats are already materialized, this function recomputes stats, which might be
     // useful if stats become stale during query planning.
     pub fn with_materialized_stats(self) -> Self {
+        let mut plan = self.with_materialized_stats_inner();
+        let semantic_id = plan.semantic_id();
+        let stats_state = plan.stats_state_mut();
+        if let StatsState::Materialized(stats) = stats_state {
+            let stats = PlanStats::clone(stats).with_semantic_id(semantic_id);
+            *stats_state = StatsState::Materialized(stats.into());
+        }
+        plan
+    }
+
+    fn with_materialized_stats_inner(self) -> Self {
         match self {
             Self::Source(plan) => Self::Source(plan.with_materialized_stats()),
             Self::Project(plan) => Self::Project(plan.with_materialized_stats()),
             Self::ActorPoolProject(plan) => Self::ActorPoolProject(plan.with_materialized_stats()),
             Self::Filter(plan) => Self::Filter(plan.with_materialized_stats()),
+            Self::Assert(plan) => Self::Assert(plan.with_materialized_stats()),
             Self::Limit(plan) => Self::Limit(plan.with_materialized_stats()),
             Self::Explode(plan) => Self::Explode(plan.with_materialized_stats()),
             Self::Unpivot(plan) => Self::Unpivot(plan.with_materialized_stats()),
@@ -304,15 +460,38 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(plan) => {
                 Self::MonotonicallyIncreasingId(plan.with_materialized_stats())
             }
+            Self::Window(plan) => Self::Window(plan.with_materialized_stats()),
+            Self::Iterate(plan) => Self::Iterate(plan.with_materialized_stats()),
         }
     }
 
+    /// A deterministic ID of this node, derived from its type, its configuration and the IDs of its children, like the
+    /// [`FieldID`](daft_schema::field::FieldID)s of expressions are derived from their structure.
+    ///
+    /// Unlike the node itself, it doesn't depend on any state of the process, like the addresses of Python objects, so it
+    /// identifies the same node across runs of a query, e.g. to correlate the metrics, cached results and logs of a node.
+    pub fn semantic_id(&self) -> String {
+        let mut hasher = DefaultHasher::new();
+        self.name().hash(&mut hasher);
+        for line in self.multiline_display() {
+            // Stats are estimates about the node, not part of it.
+            if !line.starts_with("Stats = ") {
+                line.hash(&mut hasher);
+            }
+        }
+        for child in self.children() {
+            child.semantic_id().hash(&mut hasher);
+        }
+        format!("{}-{:016x}", self.name(), hasher.finish())
+    }
+
     pub fn multiline_display(&self) -> Vec<String> {
         match self {
             Self::Source(source) => source.multiline_display(),
             Self::Project(projection) => projection.multiline_display(),
             Self::ActorPoolProject(projection) => projection.multiline_display(),
             Self::Filter(filter) => filter.multiline_display(),
+            Self::Assert(assert) => assert.multiline_display(),
             Self::Limit(limit) => limit.multiline_display(),
             Self::Explode(explode) => explode.multiline_display(),
             Self::Unpivot(unpivot) => unpivot.multiline_display(),
@@ -330,6 +509,8 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(monotonically_increasing_id) => {
                 monotonically_increasing_id.multiline_display()
             }
+            Self::Window(window) => window.multiline_display(),
+            Self::Iterate(iterate) => iterate.multiline_display(),
             Self::SubqueryAlias(alias) => alias.multiline_display(),
         }
     }
@@ -340,6 +521,7 @@ impl LogicalPlan {
             Self::Project(Project { input, .. }) => vec![input],
             Self::ActorPoolProject(ActorPoolProject { input, .. }) => vec![input],
             Self::Filter(Filter { input, .. }) => vec![input],
+            Self::Assert(Assert { input, .. }) => vec![input],
             Self::Limit(Limit { input, .. }) => vec![input],
             Self::Explode(Explode { input, .. }) => vec![input],
             Self::Unpivot(Unpivot { input, .. }) => vec![input],
@@ -357,6 +539,8 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => {
                 vec![input]
             }
+            Self::Window(Window { input, .. }) => vec![input],
+            Self::Iterate(Iterate { input, .. }) => vec![input],
             Self::SubqueryAlias(SubqueryAlias { input, .. }) => vec![input],
         }
     }
@@ -370,15 +554,18 @@ impl LogicalPlan {
                 ).unwrap()),
                 Self::ActorPoolProject(ActorPoolProject {projection, ..}) => Self::ActorPoolProject(ActorPoolProject::try_new(input.clone(), projection.clone()).unwrap()),
                 Self::Filter(Filter { predicate, .. }) => Self::Filter(Filter::try_new(input.clone(), predicate.clone()).unwrap()),
+                Self::Assert(Assert { predicate, message, violations_id, .. }) => Self::Assert(Assert::try_new(input.clone(), predicate.clone(), message.clone(), violations_id.clone()).unwrap()),
                 Self::Limit(Limit { limit, eager, .. }) => Self::Limit(Limit::new(input.clone(), *limit, *eager)),
                 Self::Explode(Explode { to_explode, .. }) => Self::Explode(Explode::try_new(input.clone(), to_explode.clone()).unwrap()),
-                Self::Sort(Sort { sort_by, descending, nulls_first, .. }) => Self::Sort(Sort::try_new(input.clone(), sort_by.clone(), descending.clone(), nulls_first.clone()).unwrap()),
+                Self::Sort(Sort { sort_by, descending, nulls_first, within_partitions, .. }) => Self::Sort(Sort::try_new(input.clone(), sort_by.clone(), descending.clone(), nulls_first.clone(), *within_partitions).unwrap()),
                 Self::Repartition(Repartition {  repartition_spec: scheme_config, .. }) => Self::Repartition(Repartition::new(input.clone(), scheme_config.clone())),
                 Self::Distinct(_) => Self::Distinct(Distinct::new(input.clone())),
                 Self::Aggregate(Aggregate { aggregations, groupby, ..}) => Self::Aggregate(Aggregate::try_new(input.clone(), aggregations.clone(), groupby.clone()).unwrap()),
                 Self::Pivot(Pivot { group_by, pivot_column, value_column, aggregation, names, ..}) => Self::Pivot(Pivot::try_new(input.clone(), group_by.clone(), pivot_column.clone(), value_column.clone(), aggregation.into(), names.clone()).unwrap()),
                 Self::Sink(Sink { sink_info, .. }) => Self::Sink(Sink::try_new(input.clone(), sink_info.clone()).unwrap()),
                 Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId {column_name, .. }) => Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId::try_new(input.clone(), Some(column_name)).unwrap()),
+                Self::Window(Window { window_functions, window_spec, .. }) => Self::Window(Window::try_new(input.clone(), window_functions.clone(), window_spec.clone()).unwrap()),
+                Self::Iterate(Iterate { body, convergence, previous_key, result_key, max_iters, .. }) => Self::Iterate(Iterate::try_new(input.clone(), body.clone(), convergence.clone(), previous_key.clone(), result_key.clone(), *max_iters).unwrap()),
                 Self::Unpivot(Unpivot {ids, values, variable_name, value_name, output_schema, ..}) =>
                     Self::Unpivot(Unpivot::new(input.clone(), ids.clone(), values.clone(), variable_name.clone(), value_name.clone(), output_schema.clone())),
                 Self::Sample(Sample {fraction, with_replacement, seed, ..}) => Self::Sample(Sample::new(input.clone(), *fraction, *with_replacement, *seed)),
@@ -430,6 +617,19 @@ impl LogicalPlan {
         s
     }
 
+    /// Like [`Self::repr_ascii`], but also shows the resolved output schema and the semantic ID of every node.
+    pub fn repr_ascii_verbose(&self) -> String {
+        let mut s = String::new();
+        self.fmt_tree_with_level(&mut s, DisplayLevel::Verbose)
+            .unwrap();
+        s
+    }
+
+    /// The plan as a JSON tree, see [`JsonDisplay`](common_display::json::JsonDisplay).
+    pub fn repr_json(&self, simple: bool) -> String {
+        common_display::json::JsonDisplay::repr_json(self, simple)
+    }
+
     pub fn repr_indent(&self) -> String {
         let mut s = String::new();
         self.fmt_tree_indent_style(0, &mut s).unwrap();
@@ -514,6 +714,7 @@ impl LogicalPlan {
             | Self::Project(Project { plan_id, .. })
             | Self::ActorPoolProject(ActorPoolProject { plan_id, .. })
             | Self::Filter(Filter { plan_id, .. })
+            | Self::Assert(Assert { plan_id, .. })
             | Self::Limit(Limit { plan_id, .. })
             | Self::Explode(Explode { plan_id, .. })
             | Self::Unpivot(Unpivot { plan_id, .. })
@@ -529,6 +730,8 @@ impl LogicalPlan {
             | Self::Sink(Sink { plan_id, .. })
             | Self::Sample(Sample { plan_id, .. })
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { plan_id, .. })
+            | Self::Window(Window { plan_id, .. })
+            | Self::Iterate(Iterate { plan_id, .. })
             | Self::SubqueryAlias(SubqueryAlias { plan_id, .. }) => plan_id,
         }
     }
@@ -541,6 +744,7 @@ impl LogicalPlan {
                 Self::ActorPoolProject(project.clone().with_plan_id(plan_id))
             }
             Self::Filter(filter) => Self::Filter(filter.clone().with_plan_id(plan_id)),
+            Self::Assert(assert) => Self::Assert(assert.clone().with_plan_id(plan_id)),
             Self::Limit(limit) => Self::Limit(limit.clone().with_plan_id(plan_id)),
             Self::Explode(explode) => Self::Explode(explode.clone().with_plan_id(plan_id)),
             Self::Unpivot(unpivot) => Self::Unpivot(unpivot.clone().with_plan_id(plan_id)),
@@ -562,6 +766,8 @@ impl LogicalPlan {
                     monotonically_increasing_id.clone().with_plan_id(plan_id),
                 )
             }
+            Self::Window(window) => Self::Window(window.clone().with_plan_id(plan_id)),
+            Self::Iterate(iterate) => Self::Iterate(iterate.clone().with_plan_id(plan_id)),
             Self::SubqueryAlias(alias) => Self::SubqueryAlias(alias.clone().with_plan_id(plan_id)),
         }
     }
@@ -651,6 +857,7 @@ macro_rules! impl_from_data_struct_for_logical_plan {
 impl_from_data_struct_for_logical_plan!(Source);
 impl_from_data_struct_for_logical_plan!(Project);
 impl_from_data_struct_for_logical_plan!(Filter);
+impl_from_data_struct_for_logical_plan!(Assert);
 impl_from_data_struct_for_logical_plan!(Limit);
 impl_from_data_struct_for_logical_plan!(Explode);
 impl_from_data_struct_for_logical_plan!(Unpivot);
@@ -666,3 +873,5 @@ impl_from_data_struct_for_logical_plan!(Join);
 impl_from_data_struct_for_logical_plan!(Sink);
 impl_from_data_struct_for_logical_plan!(Sample);
 impl_from_data_struct_for_logical_plan!(MonotonicallyIncreasingId);
+impl_from_data_struct_for_logical_plan!(Window);
+impl_from_data_struct_for_logical_plan!(Iterate);
@@ -12,7 +12,7 @@ use itertools::Itertools;
 
 use crate::{
     logical_plan::{Error, Result},
-    stats::StatsState,
+    stats::{PlanStats, StatsState},
     LogicalPlan,
 };
 
@@ -52,7 +52,12 @@ impl ActorPoolProject {
     pub(crate) fn with_materialized_stats(mut self) -> Self {
         // TODO(desmond): We can do better estimations with the projection schema. For now, reuse the old logic.
         let input_stats = self.input.materialized_stats();
-        self.stats_state = StatsState::Materialized(input_stats.clone().into());
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(input_stats.approx_stats.clone())
+                .with_num_distinct(input_stats.projected_num_distinct(&self.projection))
+                .with_histograms(input_stats.projected_histograms(&self.projection))
+                .into(),
+        );
         self
     }
 
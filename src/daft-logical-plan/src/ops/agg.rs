@@ -73,8 +73,11 @@ impl Aggregate {
                 acc_selectivity,
             }
         } else {
-            // Assume high cardinality for group by columns, and 80% of rows are unique.
-            let est_num_groups = input_stats.approx_stats.num_rows * 4 / 5;
+            // Use the NDVs of the group by columns if we know them. Otherwise, assume high cardinality for group by
+            // columns, and 80% of rows are unique.
+            let est_num_groups = input_stats
+                .exprs_num_distinct(&self.groupby)
+                .unwrap_or(input_stats.approx_stats.num_rows * 4 / 5);
             ApproxStats {
                 num_rows: est_num_groups,
                 size_bytes: est_bytes_per_row * est_num_groups,
@@ -82,7 +85,11 @@ impl Aggregate {
                     / input_stats.approx_stats.num_rows as f64,
             }
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_num_distinct(input_stats.projected_num_distinct(&self.groupby))
+                .into(),
+        );
         self
     }
 
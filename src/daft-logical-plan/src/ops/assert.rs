@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use daft_core::prelude::*;
+use daft_dsl::{resolved_col, ExprRef};
+use daft_functions::assert_true::{assert_true, record_violations};
+use snafu::ResultExt;
+
+use crate::{
+    logical_plan::{self, CreationSnafu},
+    stats::StatsState,
+    LogicalPlan,
+};
+
+/// Checks that a predicate holds for every row of its input, failing the query otherwise.
+///
+/// Unlike a [`Filter`](crate::ops::Filter), it doesn't drop any rows, and it is never moved past other operators, so
+/// that it is evaluated on exactly the rows of its input.
+///
+/// With a `violations_id`, it doesn't fail the query. It drops the rows that violate the predicate instead, and records
+/// all of their columns under the id as it executes.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Assert {
+    pub plan_id: Option<usize>,
+    // Upstream node.
+    pub input: Arc<LogicalPlan>,
+    // The Boolean expression that must be true for every row.
+    pub predicate: ExprRef,
+    // The message to fail with if the predicate doesn't hold.
+    pub message: String,
+    // The id to record violating rows under, instead of failing.
+    pub violations_id: Option<String>,
+    pub stats_state: StatsState,
+}
+
+impl Assert {
+    pub(crate) fn try_new(
+        input: Arc<LogicalPlan>,
+        predicate: ExprRef,
+        message: String,
+        violations_id: Option<String>,
+    ) -> logical_plan::Result<Self> {
+        let dtype = predicate.to_field(&input.schema())?.dtype;
+
+        if !matches!(dtype, DataType::Boolean) {
+            return Err(DaftError::ValueError(format!(
+                "Expected assertion {predicate} to resolve to type Boolean, but received: {}",
+                dtype
+            )))
+            .context(CreationSnafu);
+        }
+        Ok(Self {
+            plan_id: None,
+            input,
+            predicate,
+            message,
+            violations_id,
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    pub fn with_plan_id(mut self, plan_id: usize) -> Self {
+        self.plan_id = Some(plan_id);
+        self
+    }
+
+    /// The predicate of the filter that the assertion is executed as, which either fails the query or records the
+    /// violating rows, and keeps the rows for which the predicate holds.
+    pub fn filter_predicate(&self) -> ExprRef {
+        match &self.violations_id {
+            Some(violations_id) => record_violations(
+                self.predicate.clone(),
+                self.input
+                    .schema()
+                    .names()
+                    .iter()
+                    .map(|name| resolved_col(name.as_str()))
+                    .collect(),
+                violations_id.clone(),
+            ),
+            None => assert_true(self.predicate.clone(), self.message.clone()),
+        }
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        // An assertion either fails the query or passes all of its input through, and when violations are
+        // recorded instead, its input is an upper bound.
+        let input_stats = self.input.materialized_stats();
+        self.stats_state = StatsState::Materialized(input_stats.clone().into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![
+            format!("Assert: {}", self.predicate),
+            format!("Message = {}", self.message),
+        ];
+        if let Some(violations_id) = &self.violations_id {
+            res.push(format!("Violations = {violations_id}"));
+        }
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
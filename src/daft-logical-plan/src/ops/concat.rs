@@ -5,7 +5,7 @@ use snafu::ResultExt;
 
 use crate::{
     logical_plan::{self, CreationSnafu},
-    stats::{PlanStats, StatsState},
+    stats::{union_num_distinct, PlanStats, StatsState},
     LogicalPlan,
 };
 
@@ -51,7 +51,23 @@ impl Concat {
         let input_stats = self.input.materialized_stats();
         let other_stats = self.other.materialized_stats();
         let approx_stats = &input_stats.approx_stats + &other_stats.approx_stats;
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        let num_distinct = input_stats
+            .num_distinct
+            .iter()
+            .filter_map(|(column, &input_distinct)| {
+                let other_distinct = *other_stats.num_distinct.get(column)?;
+                let num_distinct = union_num_distinct(
+                    (input_stats.approx_stats.num_rows, input_distinct),
+                    (other_stats.approx_stats.num_rows, other_distinct),
+                );
+                Some((column.clone(), num_distinct))
+            })
+            .collect();
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_num_distinct(num_distinct)
+                .into(),
+        );
         self
     }
 
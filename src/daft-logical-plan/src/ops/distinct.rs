@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use daft_dsl::{resolved_col, ExprRef};
+
 use crate::{
     stats::{ApproxStats, PlanStats, StatsState},
     LogicalPlan,
@@ -28,12 +30,20 @@ impl Distinct {
     }
 
     pub(crate) fn with_materialized_stats(mut self) -> Self {
-        // TODO(desmond): We can simply use NDVs here. For now, do a naive estimation.
         let input_stats = self.input.materialized_stats();
         let est_bytes_per_row =
             input_stats.approx_stats.size_bytes / (input_stats.approx_stats.num_rows.max(1));
-        // Assume high cardinality, 80% of rows are distinct.
-        let est_distinct_values = input_stats.approx_stats.num_rows * 4 / 5;
+        // Use the NDVs of the columns if we know them. Otherwise, assume high cardinality, 80% of rows are distinct.
+        let columns = self
+            .input
+            .schema()
+            .names()
+            .into_iter()
+            .map(resolved_col)
+            .collect::<Vec<ExprRef>>();
+        let est_distinct_values = input_stats
+            .exprs_num_distinct(&columns)
+            .unwrap_or(input_stats.approx_stats.num_rows * 4 / 5);
         let acc_selectivity = if input_stats.approx_stats.num_rows == 0 {
             0.0
         } else {
@@ -45,7 +55,11 @@ impl Distinct {
             size_bytes: est_distinct_values * est_bytes_per_row,
             acc_selectivity,
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_num_distinct(input_stats.num_distinct.clone())
+                .into(),
+        );
         self
     }
 
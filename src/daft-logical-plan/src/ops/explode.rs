@@ -73,7 +73,18 @@ impl Explode {
             size_bytes: input_stats.approx_stats.size_bytes,
             acc_selectivity,
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        // Exploding repeats the values of the other columns, so it doesn't change their NDVs.
+        let num_distinct = input_stats
+            .num_distinct
+            .iter()
+            .filter(|(column, _)| !self.to_explode.iter().any(|e| e.name() == column.as_str()))
+            .map(|(column, &num_distinct)| (column.clone(), num_distinct))
+            .collect();
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_num_distinct(num_distinct)
+                .into(),
+        );
         self
     }
 
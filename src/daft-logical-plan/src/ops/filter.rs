@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use common_error::DaftError;
 use daft_core::prelude::*;
-use daft_dsl::{estimated_selectivity, ExprRef};
+use daft_dsl::{estimated_selectivity_with_stats, ExprRef};
 use snafu::ResultExt;
 
 use crate::{
@@ -52,7 +52,11 @@ impl Filter {
         // Assume no row/column pruning in cardinality-affecting operations.
         // TODO(desmond): We can do better estimations here. For now, reuse the old logic.
         let input_stats = self.input.materialized_stats();
-        let estimated_selectivity = estimated_selectivity(&self.predicate, &self.input.schema());
+        let estimated_selectivity = estimated_selectivity_with_stats(
+            &self.predicate,
+            &self.input.schema(),
+            &input_stats.selectivity_stats(),
+        );
         let approx_stats = ApproxStats {
             num_rows: (input_stats.approx_stats.num_rows as f64 * estimated_selectivity).ceil()
                 as usize,
@@ -60,7 +64,12 @@ impl Filter {
                 as usize,
             acc_selectivity: input_stats.approx_stats.acc_selectivity * estimated_selectivity,
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_num_distinct(input_stats.num_distinct.clone())
+                .with_histograms(input_stats.histograms.clone())
+                .into(),
+        );
         self
     }
 
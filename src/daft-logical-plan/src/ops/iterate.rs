@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use daft_core::prelude::*;
+use snafu::ResultExt;
+
+use crate::{
+    logical_plan::{self, CreationSnafu},
+    stats::StatsState,
+    LogicalPlan,
+};
+
+/// Repeatedly runs a plan on the result of its previous run, starting with the input, until the results converge or a
+/// maximum number of iterations is reached.
+///
+/// The body and convergence plans aren't children of this node: they read the results of the iterations from
+/// in-memory scans of `previous_key` and `result_key`, which the executor fills in before running them.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Iterate {
+    pub plan_id: Option<usize>,
+    // Upstream node.
+    pub input: Arc<LogicalPlan>,
+    // The plan of a single iteration, which reads the result of the previous one from `previous_key`.
+    pub body: Arc<LogicalPlan>,
+    // A plan of a single Boolean row that's true once the iterations have converged, which reads the result of the
+    // previous iteration from `previous_key` and that of the latest one from `result_key`.
+    pub convergence: Arc<LogicalPlan>,
+    pub previous_key: String,
+    pub result_key: String,
+    pub max_iters: usize,
+    pub stats_state: StatsState,
+}
+
+impl Iterate {
+    pub(crate) fn try_new(
+        input: Arc<LogicalPlan>,
+        body: Arc<LogicalPlan>,
+        convergence: Arc<LogicalPlan>,
+        previous_key: String,
+        result_key: String,
+        max_iters: usize,
+    ) -> logical_plan::Result<Self> {
+        if max_iters == 0 {
+            return Err(DaftError::ValueError(
+                "Expected max_iters to be at least 1, got 0".to_string(),
+            ))
+            .context(CreationSnafu);
+        }
+        // The result of each iteration is the input of the next one.
+        let input_schema = input.schema();
+        let body_schema = body.schema();
+        if input_schema != body_schema {
+            return Err(DaftError::ValueError(format!(
+                "Expected an iteration to keep the schema of its input, {}, but got: {}",
+                input_schema, body_schema
+            )))
+            .context(CreationSnafu);
+        }
+        let convergence_schema = convergence.schema();
+        if convergence_schema.fields.len() != 1
+            || convergence_schema
+                .fields
+                .values()
+                .any(|field| field.dtype != DataType::Boolean)
+        {
+            return Err(DaftError::ValueError(format!(
+                "Expected the convergence of an iteration to resolve to a single Boolean column, but got: {}",
+                convergence_schema
+            )))
+            .context(CreationSnafu);
+        }
+        Ok(Self {
+            plan_id: None,
+            input,
+            body,
+            convergence,
+            previous_key,
+            result_key,
+            max_iters,
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    pub fn with_plan_id(mut self, plan_id: usize) -> Self {
+        self.plan_id = Some(plan_id);
+        self
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        // The size of the result isn't known until the iterations have run, so the input's is used as an estimate.
+        let input_stats = self.input.materialized_stats();
+        self.stats_state = StatsState::Materialized(input_stats.clone().into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![
+            format!("Iterate: max iterations = {}", self.max_iters),
+            format!("Previous = {}", self.previous_key),
+            format!("Result = {}", self.result_key),
+        ];
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
@@ -211,10 +211,93 @@ impl Join {
     }
 
     pub(crate) fn with_materialized_stats(mut self) -> Self {
-        // Assume a Primary-key + Foreign-Key join which would yield the max of the two tables.
-        // TODO(desmond): We can do better estimations here. For now, use the old logic.
         let left_stats = self.left.materialized_stats();
         let right_stats = self.right.materialized_stats();
+        let approx_stats = if let Some(left_distinct) = left_stats.exprs_num_distinct(&self.left_on)
+            && let Some(right_distinct) = right_stats.exprs_num_distinct(&self.right_on)
+        {
+            self.approx_stats_from_num_distinct(
+                left_stats,
+                right_stats,
+                left_distinct,
+                right_distinct,
+            )
+        } else {
+            self.approx_stats_from_pk_fk(left_stats, right_stats)
+        };
+
+        let mut num_distinct = HashMap::new();
+        let sides = match self.join_type {
+            JoinType::Semi | JoinType::Anti => vec![left_stats],
+            _ => vec![left_stats, right_stats],
+        };
+        for (column, &ndv) in sides.into_iter().flat_map(|stats| &stats.num_distinct) {
+            if self.output_schema.has_field(column) {
+                num_distinct
+                    .entry(column.clone())
+                    .and_modify(|existing: &mut usize| *existing = (*existing).min(ndv))
+                    .or_insert(ndv);
+            }
+        }
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_num_distinct(num_distinct)
+                .into(),
+        );
+        self
+    }
+
+    /// Estimates the output of the join from the NDVs of the join keys on each side, assuming that every key value on
+    /// the side with fewer of them has matches on the other side, and that rows are spread evenly over key values.
+    fn approx_stats_from_num_distinct(
+        &self,
+        left_stats: &PlanStats,
+        right_stats: &PlanStats,
+        left_distinct: usize,
+        right_distinct: usize,
+    ) -> ApproxStats {
+        let left_rows = left_stats.approx_stats.num_rows as f64;
+        let right_rows = right_stats.approx_stats.num_rows as f64;
+        let left_row_bytes = left_stats.approx_stats.size_bytes as f64 / left_rows.max(1.0);
+        let right_row_bytes = right_stats.approx_stats.size_bytes as f64 / right_rows.max(1.0);
+        let inner_rows = left_rows * right_rows / left_distinct.max(right_distinct).max(1) as f64;
+        // The fraction of rows on each side with a key value that has a match on the other side.
+        let left_matched = (right_distinct as f64 / left_distinct.max(1) as f64).min(1.0);
+        let right_matched = (left_distinct as f64 / right_distinct.max(1) as f64).min(1.0);
+        let left_unmatched_rows = left_rows * (1.0 - left_matched);
+        let right_unmatched_rows = right_rows * (1.0 - right_matched);
+        let (num_rows, row_bytes) = match self.join_type {
+            JoinType::Inner => (inner_rows, left_row_bytes + right_row_bytes),
+            JoinType::Left => (
+                inner_rows + left_unmatched_rows,
+                left_row_bytes + right_row_bytes,
+            ),
+            JoinType::Right => (
+                inner_rows + right_unmatched_rows,
+                left_row_bytes + right_row_bytes,
+            ),
+            JoinType::Outer => (
+                inner_rows + left_unmatched_rows + right_unmatched_rows,
+                left_row_bytes + right_row_bytes,
+            ),
+            JoinType::Semi => (left_rows * left_matched, left_row_bytes),
+            JoinType::Anti => (left_unmatched_rows, left_row_bytes),
+        };
+        ApproxStats {
+            num_rows: num_rows.ceil() as usize,
+            size_bytes: (num_rows * row_bytes).ceil() as usize,
+            acc_selectivity: left_stats.approx_stats.acc_selectivity
+                * right_stats.approx_stats.acc_selectivity,
+        }
+    }
+
+    /// Estimates the output of the join when the NDVs of its keys aren't known.
+    fn approx_stats_from_pk_fk(
+        &self,
+        left_stats: &PlanStats,
+        right_stats: &PlanStats,
+    ) -> ApproxStats {
+        // Assume a Primary-key + Foreign-Key join which would yield the max of the two tables.
         // We assume that if one side of a join had its cardinality reduced by some operations
         // (e.g. filters, limits, aggregations), then assuming a pk-fk join, the total number of
         // rows output from the join will be reduced proportionally. Hence, apply the right side's
@@ -227,14 +310,12 @@ impl Join {
             left_stats.approx_stats.size_bytes as f64 * right_stats.approx_stats.acc_selectivity;
         let right_size =
             right_stats.approx_stats.size_bytes as f64 * left_stats.approx_stats.acc_selectivity;
-        let approx_stats = ApproxStats {
+        ApproxStats {
             num_rows: left_num_rows.max(right_num_rows).ceil() as usize,
             size_bytes: left_size.max(right_size).ceil() as usize,
             acc_selectivity: left_stats.approx_stats.acc_selectivity
                 * right_stats.approx_stats.acc_selectivity,
-        };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
-        self
+        }
     }
 
     pub fn multiline_display(&self) -> Vec<String> {
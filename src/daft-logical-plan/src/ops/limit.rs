@@ -57,7 +57,12 @@ impl Limit {
             },
             acc_selectivity: input_stats.approx_stats.acc_selectivity * limit_selectivity,
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_num_distinct(input_stats.num_distinct.clone())
+                .with_histograms(input_stats.histograms.clone())
+                .into(),
+        );
         self
     }
 
@@ -1,9 +1,11 @@
 mod actor_pool_project;
 mod agg;
+mod assert;
 mod concat;
 mod distinct;
 mod explode;
 mod filter;
+mod iterate;
 pub mod join;
 mod limit;
 mod monotonically_increasing_id;
@@ -17,13 +19,16 @@ mod sort;
 mod source;
 mod summarize;
 mod unpivot;
+mod window;
 
 pub use actor_pool_project::ActorPoolProject;
 pub use agg::Aggregate;
+pub use assert::Assert;
 pub use concat::Concat;
 pub use distinct::Distinct;
 pub use explode::Explode;
 pub use filter::Filter;
+pub use iterate::Iterate;
 pub use join::Join;
 pub use limit::Limit;
 pub use monotonically_increasing_id::MonotonicallyIncreasingId;
@@ -37,3 +42,4 @@ pub use sort::Sort;
 pub use source::Source;
 pub use summarize::summarize;
 pub use unpivot::Unpivot;
+pub use window::Window;
@@ -11,7 +11,7 @@ use itertools::Itertools;
 
 use crate::{
     logical_plan::{self},
-    stats::StatsState,
+    stats::{PlanStats, StatsState},
     LogicalPlan,
 };
 
@@ -67,7 +67,12 @@ impl Project {
     pub(crate) fn with_materialized_stats(mut self) -> Self {
         // TODO(desmond): We can do better estimations with the projection schema. For now, reuse the old logic.
         let input_stats = self.input.materialized_stats();
-        self.stats_state = StatsState::Materialized(input_stats.clone().into());
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(input_stats.approx_stats.clone())
+                .with_num_distinct(input_stats.projected_num_distinct(&self.projection))
+                .with_histograms(input_stats.projected_histograms(&self.projection))
+                .into(),
+        );
         self
     }
 
@@ -229,6 +234,8 @@ fn replace_column_with_semantic_id(
             Expr::Column(_) | Expr::Literal(_) | Expr::Subquery(_) | Expr::Exists(_) => {
                 Transformed::no(e)
             }
+            // Window functions are planned as Window ops rather than evaluated in projections.
+            Expr::Window(..) => Transformed::no(e),
             Expr::Agg(agg_expr) => replace_column_with_semantic_id_aggexpr(
                 agg_expr.clone(),
                 subexprs_to_replace,
@@ -65,7 +65,12 @@ impl Sample {
         let approx_stats = input_stats
             .approx_stats
             .apply(|v| ((v as f64) * self.fraction) as usize);
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_num_distinct(input_stats.num_distinct.clone())
+                .with_histograms(input_stats.histograms.clone())
+                .into(),
+        );
         self
     }
 
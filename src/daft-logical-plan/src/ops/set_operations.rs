@@ -5,7 +5,7 @@ use daft_core::{count_mode::CountMode, join::JoinType, utils::supertype::get_sup
 use daft_dsl::{lit, null_lit, resolved_col, ExprRef};
 use daft_functions::list::{explode, list_fill};
 use daft_schema::{dtype::DataType, field::Field, schema::SchemaRef};
-use indexmap::IndexSet;
+use indexmap::IndexMap;
 use snafu::ResultExt;
 
 use super::{Aggregate, Concat, Distinct, Filter, Project};
@@ -356,32 +356,37 @@ impl Union {
                 }
             }
             UnionStrategy::ByName => {
-                let lhs_fields = lhs_schema.fields.values().cloned().collect::<IndexSet<_>>();
-                let rhs_fields = rhs_schema.fields.values().cloned().collect::<IndexSet<_>>();
-                let all_fields = lhs_fields
-                    .union(&rhs_fields)
-                    .cloned()
-                    .collect::<IndexSet<_>>();
-                let lhs_with_columns = all_fields
-                    .iter()
-                    .map(|f| {
-                        if lhs_fields.contains(f) {
-                            resolved_col(f.name.clone())
-                        } else {
-                            null_lit().cast(&f.dtype).alias(f.name.clone())
-                        }
-                    })
-                    .collect::<Vec<_>>();
-                let rhs_with_columns = all_fields
-                    .iter()
-                    .map(|f| {
-                        if rhs_fields.contains(f) {
-                            resolved_col(f.name.clone())
-                        } else {
-                            null_lit().cast(&f.dtype).alias(f.name.clone())
-                        }
-                    })
-                    .collect::<Vec<_>>();
+                // Columns are matched by name, in the order they first appear in either side, and columns that are
+                // missing from a side are filled with nulls.
+                let mut all_fields = IndexMap::<String, Field>::new();
+                for field in lhs_schema.fields.values().chain(rhs_schema.fields.values()) {
+                    let dtype = match all_fields.get(&field.name) {
+                        Some(existing) => get_supertype(&existing.dtype, &field.dtype).ok_or_else(|| {
+                            logical_plan::Error::CreationError {
+                                source: DaftError::SchemaMismatch(format!(
+                                    "Unable to find a common supertype for column {} to union by name: {} and {} have no common supertype",
+                                    field.name, existing.dtype, field.dtype
+                                )),
+                            }
+                        })?,
+                        None => field.dtype.clone(),
+                    };
+                    all_fields.insert(field.name.clone(), Field::new(field.name.clone(), dtype));
+                }
+                let with_columns = |schema: &SchemaRef| {
+                    all_fields
+                        .values()
+                        .map(|f| match schema.fields.get(&f.name) {
+                            Some(existing) if existing.dtype == f.dtype => {
+                                resolved_col(f.name.clone())
+                            }
+                            Some(_) => resolved_col(f.name.clone()).cast(&f.dtype),
+                            None => null_lit().cast(&f.dtype).alias(f.name.clone()),
+                        })
+                        .collect::<Vec<_>>()
+                };
+                let lhs_with_columns = with_columns(&lhs_schema);
+                let rhs_with_columns = with_columns(&rhs_schema);
                 let lhs = Project::try_new(self.lhs.clone(), lhs_with_columns)?;
                 let rhs = Project::try_new(self.rhs.clone(), rhs_with_columns)?;
                 let concat = LogicalPlan::Concat(Concat::try_new(lhs.into(), rhs.into())?);
@@ -16,6 +16,8 @@ pub struct Sort {
     pub sort_by: Vec<ExprRef>,
     pub descending: Vec<bool>,
     pub nulls_first: Vec<bool>,
+    /// Whether each partition is sorted independently, rather than the partitions being ordered as well.
+    pub within_partitions: bool,
     pub stats_state: StatsState,
 }
 
@@ -25,6 +27,7 @@ impl Sort {
         sort_by: Vec<ExprRef>,
         descending: Vec<bool>,
         nulls_first: Vec<bool>,
+        within_partitions: bool,
     ) -> logical_plan::Result<Self> {
         if sort_by.is_empty() {
             return Err(DaftError::ValueError(
@@ -52,6 +55,7 @@ impl Sort {
             sort_by,
             descending,
             nulls_first,
+            within_partitions,
             stats_state: StatsState::NotMaterialized,
         })
     }
@@ -86,7 +90,11 @@ impl Sort {
                 )
             })
             .join(", ");
-        res.push(format!("Sort: Sort by = {}", pairs));
+        if self.within_partitions {
+            res.push(format!("Sort within partitions: Sort by = {}", pairs));
+        } else {
+            res.push(format!("Sort: Sort by = {}", pairs));
+        }
         if let StatsState::Materialized(stats) = &self.stats_state {
             res.push(format!("Stats = {}", stats));
         }
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use common_error::DaftResult;
 use common_scan_info::{PhysicalScanInfo, ScanState};
@@ -6,7 +6,10 @@ use daft_schema::schema::SchemaRef;
 
 use crate::{
     source_info::{InMemoryInfo, PlaceHolderInfo, SourceInfo},
-    stats::{ApproxStats, PlanStats, StatsState},
+    stats::{
+        extrapolate_num_distinct, selectivity_stats, union_num_distinct, ApproxStats, PlanStats,
+        StatsState,
+    },
 };
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -61,22 +64,26 @@ impl Source {
     }
 
     pub(crate) fn with_materialized_stats(mut self) -> Self {
-        let approx_stats = match &*self.source_info {
+        let stats = match &*self.source_info {
             SourceInfo::InMemory(InMemoryInfo {
                 size_bytes,
                 num_rows,
                 ..
-            }) => ApproxStats {
+            }) => PlanStats::new(ApproxStats {
                 num_rows: *num_rows,
                 size_bytes: *size_bytes,
                 acc_selectivity: 1.0,
-            },
+            }),
             SourceInfo::Physical(physical_scan_info) => match &physical_scan_info.scan_state {
                 ScanState::Operator(_) => {
                     panic!("Scan nodes should be materialized before stats are materialized")
                 }
                 ScanState::Tasks(scan_tasks) => {
                     let mut approx_stats = ApproxStats::empty();
+                    // The rows of all scan tasks before filters, and for every column, the NDV over the scan tasks
+                    // that know it along with the number of rows it was counted over.
+                    let mut total_rows = 0;
+                    let mut sampled_num_distinct = HashMap::<String, (usize, usize)>::new();
                     for st in scan_tasks.iter() {
                         if let Some(num_rows) = st.num_rows() {
                             approx_stats.num_rows += num_rows;
@@ -85,16 +92,62 @@ impl Source {
                         }
                         approx_stats.size_bytes +=
                             st.estimate_in_memory_size_bytes(None).unwrap_or(0);
+
+                        let task_rows = st
+                            .upper_bound_rows()
+                            .or_else(|| st.approx_num_rows(None).map(|n| n as usize))
+                            .unwrap_or(0);
+                        total_rows += task_rows;
+                        for (column, &num_distinct) in st.num_distinct().into_iter().flatten() {
+                            sampled_num_distinct
+                                .entry(column.clone())
+                                .and_modify(|sampled| {
+                                    *sampled = (
+                                        sampled.0 + task_rows,
+                                        union_num_distinct(*sampled, (task_rows, num_distinct)),
+                                    );
+                                })
+                                .or_insert((task_rows, num_distinct));
+                        }
                     }
+                    // Statistics of the whole dataset from analyzing it are more accurate than the sampled NDVs.
+                    let (num_distinct, histograms, column_stats) =
+                        match &physical_scan_info.statistics {
+                            Some(statistics) => (
+                                statistics.num_distinct(),
+                                statistics.histograms(),
+                                statistics.selectivity_stats(),
+                            ),
+                            None => {
+                                let num_distinct = sampled_num_distinct
+                                    .into_iter()
+                                    .map(|(column, (sample_rows, num_distinct))| {
+                                        let num_distinct = extrapolate_num_distinct(
+                                            sample_rows,
+                                            num_distinct,
+                                            total_rows,
+                                        );
+                                        (column, num_distinct)
+                                    })
+                                    .collect();
+                                let column_stats = selectivity_stats(&num_distinct);
+                                (num_distinct, HashMap::new(), column_stats)
+                            }
+                        };
                     approx_stats.acc_selectivity = physical_scan_info
                         .pushdowns
-                        .estimated_selectivity(self.output_schema.as_ref());
-                    approx_stats
+                        .estimated_selectivity_with_stats(
+                            self.output_schema.as_ref(),
+                            &column_stats,
+                        );
+                    PlanStats::new(approx_stats)
+                        .with_num_distinct(num_distinct)
+                        .with_histograms(histograms)
                 }
             },
-            SourceInfo::PlaceHolder(_) => ApproxStats::empty(),
+            SourceInfo::PlaceHolder(_) => PlanStats::empty(),
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self.stats_state = StatsState::Materialized(stats.into());
         self
     }
 
@@ -107,6 +160,7 @@ impl Source {
                 scan_state,
                 partitioning_keys,
                 pushdowns,
+                ..
             }) => {
                 use itertools::Itertools;
                 res.extend(scan_state.multiline_display());
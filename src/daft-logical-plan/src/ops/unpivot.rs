@@ -102,7 +102,11 @@ impl Unpivot {
             size_bytes: input_stats.approx_stats.size_bytes,
             acc_selectivity: input_stats.approx_stats.acc_selectivity * num_values as f64,
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_num_distinct(input_stats.projected_num_distinct(&self.ids))
+                .into(),
+        );
         self
     }
 
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use daft_core::prelude::*;
+use daft_dsl::{exprs_to_schema, Expr, ExprRef, WindowSpec};
+use itertools::Itertools;
+use snafu::ResultExt;
+
+use crate::{logical_plan, logical_plan::CreationSnafu, stats::StatsState, LogicalPlan};
+
+/// Appends the results of window functions to the rows of the input.
+///
+/// All the window functions are over the same window, so they are computed by partitioning the input by the partition
+/// by expressions of the window, sorting the partitions by the order by expressions, and evaluating the functions over
+/// each sorted partition.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Window {
+    pub plan_id: Option<usize>,
+    // Upstream node.
+    pub input: Arc<LogicalPlan>,
+    /// The window functions, each aliased to the name of the column of its results.
+    pub window_functions: Vec<ExprRef>,
+    pub window_spec: WindowSpec,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
+impl Window {
+    pub(crate) fn try_new(
+        input: Arc<LogicalPlan>,
+        window_functions: Vec<ExprRef>,
+        window_spec: WindowSpec,
+    ) -> logical_plan::Result<Self> {
+        for function in &window_functions {
+            let spec = match function.as_ref() {
+                Expr::Alias(inner, _) => match inner.as_ref() {
+                    Expr::Window(_, spec) => Some(spec),
+                    _ => None,
+                },
+                _ => None,
+            };
+            if spec != Some(&window_spec) {
+                return Err(DaftError::ValueError(format!(
+                    "Expected an aliased window function over {window_spec}, but received {function}"
+                )))
+                .context(CreationSnafu);
+            }
+        }
+        if window_spec.descending.len() != window_spec.order_by.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected a sort direction for every order by expression of {window_spec}"
+            )))
+            .context(CreationSnafu);
+        }
+        if window_spec.frame.is_some() {
+            return Err(DaftError::ValueError(format!(
+                "Window frames are not supported for row_number, rank, lag and lead, which are computed over whole partitions: {window_spec}"
+            )))
+            .context(CreationSnafu);
+        }
+
+        let order_by_schema = exprs_to_schema(&window_spec.order_by, input.schema())?;
+        for (field, expr) in order_by_schema.fields.values().zip(&window_spec.order_by) {
+            if let dt @ (DataType::Null | DataType::Binary) = &field.dtype {
+                return Err(DaftError::ValueError(format!(
+                    "Cannot order a window by expression {expr} with type: {dt}",
+                )))
+                .context(CreationSnafu);
+            }
+        }
+
+        let function_schema = exprs_to_schema(&window_functions, input.schema())?;
+        let fields = input
+            .schema()
+            .fields
+            .values()
+            .chain(function_schema.fields.values())
+            .cloned()
+            .collect();
+        let schema = Schema::new(fields)?;
+
+        Ok(Self {
+            plan_id: None,
+            input,
+            window_functions,
+            window_spec,
+            schema: Arc::new(schema),
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    pub fn with_plan_id(mut self, plan_id: usize) -> Self {
+        self.plan_id = Some(plan_id);
+        self
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        // Window functions add columns to every row, without changing the cardinality.
+        let input_stats = self.input.materialized_stats();
+        self.stats_state = StatsState::Materialized(input_stats.clone().into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        // The window is displayed once rather than with every function.
+        let functions = self
+            .window_functions
+            .iter()
+            .map(|e| match e.as_ref() {
+                Expr::Alias(inner, name) if let Expr::Window(window_expr, _) = inner.as_ref() => {
+                    format!("{window_expr} as {name}")
+                }
+                _ => e.to_string(),
+            })
+            .join(", ");
+        let mut res = vec![format!("Window: {functions}")];
+        if !self.window_spec.partition_by.is_empty() {
+            res.push(format!(
+                "Partition by = {}",
+                self.window_spec
+                    .partition_by
+                    .iter()
+                    .map(|e| e.to_string())
+                    .join(", ")
+            ));
+        }
+        if !self.window_spec.order_by.is_empty() {
+            res.push(format!(
+                "Order by = {}",
+                self.window_spec
+                    .order_by
+                    .iter()
+                    .zip(&self.window_spec.descending)
+                    .map(|(e, desc)| format!(
+                        "({}, {})",
+                        e,
+                        if *desc { "descending" } else { "ascending" }
+                    ))
+                    .join(", ")
+            ));
+        }
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
@@ -6,10 +6,12 @@ use common_treenode::Transformed;
 use super::{
     logical_plan_tracker::LogicalPlanTracker,
     rules::{
-        DetectMonotonicId, DropRepartition, EliminateCrossJoin, EliminateSubqueryAliasRule,
-        EnrichWithStats, FilterNullJoinKey, LiftProjectFromAgg, MaterializeScans, OptimizerRule,
-        PushDownFilter, PushDownLimit, PushDownProjection, ReorderJoins, SimplifyExpressionsRule,
-        SplitActorPoolProjects, UnnestPredicateSubquery, UnnestScalarSubquery,
+        AggregateFromStats, DetectMonotonicId, DistinctFromPartitions, DropRepartition,
+        EliminateCrossJoin, EliminateFalseFilters, EliminateSubqueryAliasRule, EnrichWithStats,
+        ExtractWindowFunctions, FilterNullJoinKey, LiftProjectFromAgg, MaterializeScans,
+        OptimizerRule, PushDownAggregation, PushDownFilter, PushDownLimit, PushDownProjection,
+        ReorderJoins, SimplifyExpressionsRule, SplitActorPoolProjects, UnnestPredicateSubquery,
+        UnnestScalarSubquery,
     },
 };
 use crate::LogicalPlan;
@@ -36,9 +38,17 @@ impl Default for OptimizerConfig {
     }
 }
 
-pub trait OptimizerRuleInBatch: OptimizerRule + std::fmt::Debug {}
+pub trait OptimizerRuleInBatch: OptimizerRule + std::fmt::Debug {
+    /// The name of the rule's type, e.g. `PushDownFilter`, by which it can be disabled.
+    fn name(&self) -> &'static str;
+}
 
-impl<T: OptimizerRule + std::fmt::Debug> OptimizerRuleInBatch for T {}
+impl<T: OptimizerRule + std::fmt::Debug> OptimizerRuleInBatch for T {
+    fn name(&self) -> &'static str {
+        let type_name = std::any::type_name::<T>();
+        type_name.rsplit("::").next().unwrap_or(type_name)
+    }
+}
 
 /// A batch of logical optimization rules.
 #[derive(Debug)]
@@ -100,6 +110,7 @@ impl Default for OptimizerBuilder {
                         Box::new(EliminateSubqueryAliasRule::new()),
                         Box::new(SplitActorPoolProjects::new()),
                         Box::new(DetectMonotonicId::new()),
+                        Box::new(ExtractWindowFunctions::new()),
                     ],
                     RuleExecutionStrategy::FixedPoint(None),
                 ),
@@ -136,6 +147,12 @@ impl Default for OptimizerBuilder {
                     vec![Box::new(PushDownLimit::new())],
                     RuleExecutionStrategy::FixedPoint(Some(3)),
                 ),
+                // --- Aggregation pushdowns ---
+                // This needs to run after the other pushdowns, which the scan tasks apply before aggregating.
+                RuleBatch::new(
+                    vec![Box::new(PushDownAggregation::new())],
+                    RuleExecutionStrategy::Once,
+                ),
                 // --- Simplify expressions before scans are materialized ---
                 RuleBatch::new(
                     vec![Box::new(SimplifyExpressionsRule::new())],
@@ -146,6 +163,19 @@ impl Default for OptimizerBuilder {
                     vec![Box::new(MaterializeScans::new())],
                     RuleExecutionStrategy::Once,
                 ),
+                // --- Skip scans of always-false filters ---
+                RuleBatch::new(
+                    vec![Box::new(EliminateFalseFilters::new())],
+                    RuleExecutionStrategy::Once,
+                ),
+                // --- Answer aggregations from scan metadata ---
+                RuleBatch::new(
+                    vec![
+                        Box::new(AggregateFromStats::new()),
+                        Box::new(DistinctFromPartitions::new()),
+                    ],
+                    RuleExecutionStrategy::Once,
+                ),
                 // --- Enrich logical plan with stats ---
                 RuleBatch::new(
                     vec![Box::new(EnrichWithStats::new())],
@@ -193,6 +223,31 @@ impl OptimizerBuilder {
         self
     }
 
+    /// Removes the rules with the given names from all rule batches, e.g. to debug a rule that produces a wrong plan.
+    pub fn without_rules(mut self, names: &[String]) -> Self {
+        if names.is_empty() {
+            return self;
+        }
+        for name in names {
+            if !self
+                .rule_batches
+                .iter()
+                .any(|batch| batch.rules.iter().any(|rule| rule.name() == name))
+            {
+                log::warn!(
+                    "Cannot disable optimizer rule {name}, which is not a rule of the optimizer"
+                );
+            }
+        }
+        for batch in &mut self.rule_batches {
+            batch
+                .rules
+                .retain(|rule| !names.iter().any(|name| rule.name() == name));
+        }
+        self.rule_batches.retain(|batch| !batch.rules.is_empty());
+        self
+    }
+
     pub fn with_optimizer_config(mut self, config: OptimizerConfig) -> Self {
         self.config = config;
         self
@@ -323,7 +378,7 @@ mod tests {
     use daft_core::prelude::*;
     use daft_dsl::{lit, unresolved_col};
 
-    use super::{Optimizer, OptimizerConfig, RuleBatch, RuleExecutionStrategy};
+    use super::{Optimizer, OptimizerBuilder, OptimizerConfig, RuleBatch, RuleExecutionStrategy};
     use crate::{
         ops::{Filter, Project},
         optimization::rules::OptimizerRule,
@@ -356,6 +411,32 @@ mod tests {
         Ok(())
     }
 
+    /// Tests that rules can be disabled by name, which drops the batches that are left without rules.
+    #[test]
+    fn disable_rules_by_name() {
+        let rule_names = |optimizer: &Optimizer| {
+            optimizer
+                .rule_batches
+                .iter()
+                .flat_map(|batch| batch.rules.iter().map(|rule| rule.name()))
+                .collect::<Vec<_>>()
+        };
+        let optimizer = OptimizerBuilder::default().build();
+        assert!(rule_names(&optimizer).contains(&"PushDownFilter"));
+        assert!(rule_names(&optimizer).contains(&"MaterializeScans"));
+
+        let optimizer = OptimizerBuilder::default()
+            .without_rules(&["PushDownFilter".to_string(), "MaterializeScans".to_string()])
+            .build();
+        assert!(!rule_names(&optimizer).contains(&"PushDownFilter"));
+        assert!(!rule_names(&optimizer).contains(&"MaterializeScans"));
+        assert!(rule_names(&optimizer).contains(&"PushDownProjection"));
+        assert_eq!(
+            optimizer.rule_batches.len(),
+            OptimizerBuilder::default().build().rule_batches.len() - 1
+        );
+    }
+
     #[derive(Debug)]
     struct NoOp {}
 
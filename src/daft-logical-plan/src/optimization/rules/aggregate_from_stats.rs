@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_scan_info::{PhysicalScanInfo, ScanState, ScanTaskLikeRef};
+use common_treenode::{Transformed, TreeNode};
+use daft_core::{count_mode::CountMode, series::Series};
+use daft_dsl::{AggExpr, Column, Expr, ExprRef, LiteralValue, ResolvedColumn};
+
+use super::OptimizerRule;
+use crate::{
+    ops::{Aggregate, Project, Source},
+    source_info::SourceInfo,
+    stats::StatsState,
+    LogicalPlan,
+};
+
+/// Optimization rule for answering global aggregations of scans from the metadata and statistics of their scan tasks
+/// when they fully determine the results, e.g. counts of rows, or minimums and maximums of partition columns, so that
+/// no data has to be read.
+///
+/// All scan nodes MUST be materialized before this rule is applied.
+#[derive(Default, Debug)]
+pub struct AggregateFromStats {}
+
+impl AggregateFromStats {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for AggregateFromStats {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_up(|node| {
+            let LogicalPlan::Aggregate(Aggregate {
+                input,
+                aggregations,
+                groupby,
+                output_schema,
+                ..
+            }) = node.as_ref()
+            else {
+                return Ok(Transformed::no(node));
+            };
+            let LogicalPlan::Source(source) = input.as_ref() else {
+                return Ok(Transformed::no(node));
+            };
+            let SourceInfo::Physical(
+                scan_info @ PhysicalScanInfo {
+                    scan_state: ScanState::Tasks(scan_tasks),
+                    ..
+                },
+            ) = source.source_info.as_ref()
+            else {
+                return Ok(Transformed::no(node));
+            };
+            if !groupby.is_empty() {
+                return Ok(Transformed::no(node));
+            }
+            let Some(values) = aggregations
+                .iter()
+                .map(|agg| answer_from_stats(agg, scan_tasks))
+                .collect::<Option<Vec<_>>>()
+            else {
+                return Ok(Transformed::no(node));
+            };
+
+            // A global aggregation yields a single row even when nothing is scanned, whose values are replaced with
+            // the answers.
+            let empty_scan = Source {
+                source_info: SourceInfo::Physical(PhysicalScanInfo {
+                    scan_state: ScanState::Tasks(Arc::new(vec![])),
+                    ..scan_info.clone()
+                })
+                .into(),
+                stats_state: StatsState::NotMaterialized,
+                ..source.clone()
+            };
+            let single_row =
+                Aggregate::try_new(empty_scan.into(), vec![aggregations[0].clone()], vec![])?;
+            let projection = values
+                .into_iter()
+                .zip(output_schema.fields.values())
+                .map(|(value, field)| {
+                    Arc::new(Expr::Literal(value))
+                        .cast(&field.dtype)
+                        .alias(field.name.as_str())
+                })
+                .collect();
+            let project = Project::try_new(single_row.into(), projection)?;
+            Ok(Transformed::yes(project.into()))
+        })
+    }
+}
+
+/// The result of an aggregation over all the rows of the scan tasks, if their metadata and statistics determine it.
+fn answer_from_stats(agg: &ExprRef, scan_tasks: &[ScanTaskLikeRef]) -> Option<LiteralValue> {
+    let agg = match agg.as_ref() {
+        Expr::Alias(inner, _) => inner.as_ref(),
+        agg => agg,
+    };
+    match agg {
+        Expr::Agg(AggExpr::Count(_, CountMode::All)) => {
+            let num_rows = scan_tasks
+                .iter()
+                .map(|task| task.num_rows())
+                .sum::<Option<usize>>()?;
+            Some(LiteralValue::UInt64(num_rows as u64))
+        }
+        Expr::Agg(agg @ (AggExpr::Min(child) | AggExpr::Max(child))) => {
+            let Expr::Column(Column::Resolved(ResolvedColumn::Basic(name))) = child.as_ref() else {
+                return None;
+            };
+            let is_min = matches!(agg, AggExpr::Min(_));
+            let mut bounds = vec![];
+            for task in scan_tasks {
+                // The bounds of a task only matter if it has rows.
+                if task.num_rows()? == 0 {
+                    continue;
+                }
+                let (lower, upper) = task.column_bounds(name)?;
+                bounds.push(if is_min { lower } else { upper }.to_series());
+            }
+            if bounds.is_empty() {
+                return Some(LiteralValue::Null);
+            }
+            let bounds = Series::concat(&bounds.iter().collect::<Vec<_>>()).ok()?;
+            let value = if is_min {
+                bounds.min(None)
+            } else {
+                bounds.max(None)
+            }
+            .ok()?;
+            Some(LiteralValue::Series(value))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use common_scan_info::{ScanOperatorRef, ScanState};
+    use daft_core::prelude::*;
+    use daft_dsl::{lit, unresolved_col};
+
+    use crate::{
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            rules::AggregateFromStats,
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{dummy_scan_node, dummy_scan_operator_with_size},
+        LogicalPlan, LogicalPlanBuilder, SourceInfo,
+    };
+
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![Box::new(AggregateFromStats::new())],
+                RuleExecutionStrategy::Once,
+            )],
+        )
+    }
+
+    /// A scan node whose scan tasks are materialized, optionally dropping all of them.
+    fn materialized_scan_node(
+        scan_op: ScanOperatorRef,
+        drop_tasks: bool,
+    ) -> DaftResult<LogicalPlanBuilder> {
+        let LogicalPlan::Source(source) = Arc::unwrap_or_clone(dummy_scan_node(scan_op).build())
+        else {
+            unreachable!("Expected a scan node")
+        };
+        let mut source = source.build_materialized_scan_source()?;
+        if drop_tasks {
+            let SourceInfo::Physical(mut scan_info) = Arc::unwrap_or_clone(source.source_info)
+            else {
+                unreachable!("Expected a physical scan")
+            };
+            scan_info.scan_state = ScanState::Tasks(Arc::new(vec![]));
+            source.source_info = Arc::new(SourceInfo::Physical(scan_info));
+        }
+        Ok(LogicalPlanBuilder::from(Arc::new(LogicalPlan::Source(
+            source,
+        ))))
+    }
+
+    fn scan_op(num_rows_per_task: Option<usize>) -> ScanOperatorRef {
+        dummy_scan_operator_with_size(
+            vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Utf8),
+            ],
+            num_rows_per_task,
+        )
+    }
+
+    /// Tests that counts of rows are answered from the numbers of rows of the scan tasks.
+    #[test]
+    fn count_answered_from_metadata() -> DaftResult<()> {
+        let count = unresolved_col("a").count(CountMode::All).alias("count");
+        let plan = materialized_scan_node(scan_op(Some(10)), false)?
+            .aggregate(vec![count.clone()], vec![])?
+            .build();
+        let expected = materialized_scan_node(scan_op(Some(10)), true)?
+            .aggregate(vec![count], vec![])?
+            .select(vec![lit(10u64).cast(&DataType::UInt64).alias("count")])?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that aggregations that the scan tasks don't know the answers to are left as they are.
+    #[test]
+    fn aggregation_not_answered_without_stats() -> DaftResult<()> {
+        // The number of rows of the scan tasks isn't known.
+        let count = vec![unresolved_col("a").count(CountMode::All).alias("count")];
+        let plan = materialized_scan_node(scan_op(None), false)?
+            .aggregate(count.clone(), vec![])?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+
+        // The bounds of a column that isn't a partition column aren't known.
+        let aggregations = vec![
+            unresolved_col("a").count(CountMode::All).alias("count"),
+            unresolved_col("a").max().alias("max"),
+        ];
+        let plan = materialized_scan_node(scan_op(Some(10)), false)?
+            .aggregate(aggregations, vec![])?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+
+        // Counts of the rows of groups aren't known either.
+        let plan = materialized_scan_node(scan_op(Some(10)), false)?
+            .aggregate(count, vec![unresolved_col("b")])?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
@@ -2,11 +2,11 @@ use std::sync::Arc;
 
 use common_error::DaftResult;
 use common_treenode::{Transformed, TreeNode};
-use daft_dsl::{Column, Expr, ExprRef, ResolvedColumn};
+use daft_dsl::{resolved_col, Column, Expr, ExprRef, ResolvedColumn};
 
 use crate::{
     logical_plan::{LogicalPlan, Project},
-    ops::MonotonicallyIncreasingId,
+    ops::{Filter, MonotonicallyIncreasingId},
     optimization::rules::OptimizerRule,
 };
 
@@ -58,6 +58,22 @@ impl DetectMonotonicId {
             .data)
     }
 
+    /// Helper function to pick a name for the column of ids that isn't already a column of the input, preferably `id`
+    fn id_column_name(input: &LogicalPlan) -> String {
+        let schema = input.schema();
+        std::iter::once("id".to_string())
+            .chain((0..).map(|i| format!("id_{i}")))
+            .find(|name| !schema.has_field(name))
+            .expect("Expected an unused column name")
+    }
+
+    /// Helper function to add a column of monotonically increasing ids to the input
+    fn with_id_column(input: &Arc<LogicalPlan>, column_name: &str) -> DaftResult<Arc<LogicalPlan>> {
+        Ok(Arc::new(LogicalPlan::MonotonicallyIncreasingId(
+            MonotonicallyIncreasingId::try_new(input.clone(), Some(column_name))?,
+        )))
+    }
+
     /// Helper function to replace all monotonically_increasing_id() expressions with column references
     fn replace_monotonic_id_expressions(
         projection: &[ExprRef],
@@ -74,36 +90,35 @@ impl OptimizerRule for DetectMonotonicId {
     fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
         plan.transform_down(|node| {
             match node.as_ref() {
-                LogicalPlan::Project(project) => {
-                    // Check if any expression contains monotonically_increasing_id()
-                    if Self::contains_monotonic_id(project) {
-                        // Use a fixed column name for the monotonic ID
-                        let column_name = "id";
+                // Check if any expression contains monotonically_increasing_id()
+                LogicalPlan::Project(project) if Self::contains_monotonic_id(project) => {
+                    let column_name = Self::id_column_name(&project.input);
+                    let monotonic_plan = Self::with_id_column(&project.input, &column_name)?;
 
-                        // Create a single MonotonicallyIncreasingId operation
-                        let monotonic_plan = Arc::new(LogicalPlan::MonotonicallyIncreasingId(
-                            MonotonicallyIncreasingId::try_new(
-                                project.input.clone(),
-                                Some(column_name),
-                            )?,
-                        ));
+                    // Replace all monotonically_increasing_id() expressions with column references
+                    let new_projection =
+                        Self::replace_monotonic_id_expressions(&project.projection, &column_name)?;
 
-                        // Replace all monotonically_increasing_id() expressions with column references
-                        let new_projection = Self::replace_monotonic_id_expressions(
-                            &project.projection,
-                            column_name,
-                        )?;
-
-                        // Create a new Project operation with the updated projection list
-                        let final_plan = Arc::new(LogicalPlan::Project(Project::try_new(
-                            monotonic_plan,
-                            new_projection,
-                        )?));
+                    // Create a new Project operation with the updated projection list
+                    let final_plan = Project::try_new(monotonic_plan, new_projection)?;
+                    Ok(Transformed::yes(final_plan.into()))
+                }
+                LogicalPlan::Filter(filter) if Self::is_monotonic_id_expr(&filter.predicate) => {
+                    let column_name = Self::id_column_name(&filter.input);
+                    let monotonic_plan = Self::with_id_column(&filter.input, &column_name)?;
+                    let predicate = Self::replace_monotonic_id(&filter.predicate, &column_name)?;
+                    let filter_plan = Filter::try_new(monotonic_plan, predicate)?;
 
-                        Ok(Transformed::yes(final_plan))
-                    } else {
-                        Ok(Transformed::no(node))
-                    }
+                    // The ids are only used to filter the rows, so they're dropped afterwards.
+                    let columns = filter
+                        .input
+                        .schema()
+                        .names()
+                        .into_iter()
+                        .map(resolved_col)
+                        .collect();
+                    let final_plan = Project::try_new(filter_plan.into(), columns)?;
+                    Ok(Transformed::yes(final_plan.into()))
                 }
                 _ => Ok(Transformed::no(node)),
             }
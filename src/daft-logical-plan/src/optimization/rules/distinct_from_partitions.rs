@@ -0,0 +1,255 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_scan_info::{PhysicalScanInfo, ScanState, ScanTaskLikeRef};
+use common_treenode::{Transformed, TreeNode};
+use daft_dsl::LiteralValue;
+use indexmap::IndexMap;
+
+use super::OptimizerRule;
+use crate::{
+    ops::{Distinct, Source},
+    source_info::SourceInfo,
+    stats::StatsState,
+    LogicalPlan,
+};
+
+/// Optimization rule for distincts of the partition columns of scans, e.g. of hive-partitioned datasets.
+///
+/// Every row of a scan task has the same partition values, so the distinct values are the partition values of the
+/// tasks with rows. Rather than reading and deduplicating all the rows, each task only reads its first row, and of
+/// the tasks with the same partition values, only one that's known to have rows is read.
+///
+/// All scan nodes MUST be materialized before this rule is applied.
+#[derive(Default, Debug)]
+pub struct DistinctFromPartitions {}
+
+impl DistinctFromPartitions {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for DistinctFromPartitions {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_up(|node| {
+            let LogicalPlan::Distinct(distinct) = node.as_ref() else {
+                return Ok(Transformed::no(node));
+            };
+            let LogicalPlan::Source(source) = distinct.input.as_ref() else {
+                return Ok(Transformed::no(node));
+            };
+            let SourceInfo::Physical(
+                scan_info @ PhysicalScanInfo {
+                    scan_state: ScanState::Tasks(scan_tasks),
+                    ..
+                },
+            ) = source.source_info.as_ref()
+            else {
+                return Ok(Transformed::no(node));
+            };
+            let Some(scan_tasks) =
+                first_row_per_partition(scan_tasks, &source.output_schema.names())
+            else {
+                return Ok(Transformed::no(node));
+            };
+
+            let source = Source {
+                source_info: SourceInfo::Physical(PhysicalScanInfo {
+                    scan_state: ScanState::Tasks(Arc::new(scan_tasks)),
+                    ..scan_info.clone()
+                })
+                .into(),
+                stats_state: StatsState::NotMaterialized,
+                ..source.clone()
+            };
+            let distinct = Distinct {
+                input: source.into(),
+                stats_state: StatsState::NotMaterialized,
+                ..distinct.clone()
+            };
+            Ok(Transformed::yes(distinct.into()))
+        })
+    }
+}
+
+/// The scan tasks that need to be read to find the distinct values of `columns`, each limited to its first row, if
+/// they're partition columns of all the tasks.
+fn first_row_per_partition(
+    scan_tasks: &[ScanTaskLikeRef],
+    columns: &[String],
+) -> Option<Vec<ScanTaskLikeRef>> {
+    if columns.is_empty() {
+        return None;
+    }
+    let mut partitions = IndexMap::<Vec<LiteralValue>, Vec<&ScanTaskLikeRef>>::new();
+    for task in scan_tasks {
+        let values = columns
+            .iter()
+            .map(|column| task.partition_value(column))
+            .collect::<Option<Vec<_>>>()?;
+        partitions.entry(values).or_default().push(task);
+    }
+    let scan_tasks = partitions
+        .into_values()
+        .flat_map(|tasks| {
+            // Tasks whose numbers of rows aren't known, e.g. because of filters, may not have any rows, and neither
+            // may tasks with deleted rows, whatever the metadata of their files says.
+            match tasks.iter().find(|task| {
+                !task.has_deleted_rows() && task.num_rows().is_some_and(|num_rows| num_rows > 0)
+            }) {
+                Some(task) => vec![*task],
+                None => tasks
+                    .into_iter()
+                    .filter(|task| task.num_rows() != Some(0))
+                    .collect(),
+            }
+        })
+        .map(|task| {
+            let limit = task.pushdowns().limit.map_or(1, |limit| limit.min(1));
+            task.with_pushdowns(task.pushdowns().with_limit(Some(limit)))
+        })
+        .collect();
+    Some(scan_tasks)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::Arc};
+
+    use common_error::DaftResult;
+    use common_scan_info::{
+        test::DummyScanTask, PhysicalScanInfo, Pushdowns, ScanState, ScanTaskLikeRef,
+    };
+    use daft_core::prelude::*;
+    use daft_dsl::LiteralValue;
+
+    use crate::{
+        ops::Source,
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            rules::DistinctFromPartitions,
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::dummy_scan_operator,
+        LogicalPlan, LogicalPlanBuilder, SourceInfo,
+    };
+
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![Box::new(DistinctFromPartitions::new())],
+                RuleExecutionStrategy::Once,
+            )],
+        )
+    }
+
+    fn schema() -> SchemaRef {
+        Arc::new(
+            Schema::new(vec![
+                Field::new("part", DataType::Int64),
+                Field::new("a", DataType::Utf8),
+            ])
+            .unwrap(),
+        )
+    }
+
+    /// A scan task of the partition `part`, optionally limited to `limit` rows.
+    fn scan_task(
+        part: i64,
+        num_rows: Option<usize>,
+        has_deleted_rows: bool,
+        limit: Option<usize>,
+    ) -> ScanTaskLikeRef {
+        Arc::new(DummyScanTask {
+            schema: schema(),
+            pushdowns: Pushdowns::default().with_limit(limit),
+            num_rows,
+            size_bytes: None,
+            partition_values: BTreeMap::from([("part".to_string(), LiteralValue::Int64(part))]),
+            has_deleted_rows,
+        })
+    }
+
+    /// A distinct of the column `column` of a scan with the scan tasks `scan_tasks`, which only reads that column.
+    fn distinct_of_scan(
+        scan_tasks: Vec<ScanTaskLikeRef>,
+        column: &str,
+    ) -> DaftResult<Arc<LogicalPlan>> {
+        let schema = schema();
+        let output_schema = Arc::new(Schema::new(vec![schema.get_field(column)?.clone()])?);
+        let scan_op = dummy_scan_operator(schema.fields.values().cloned().collect());
+        let pushdowns = Pushdowns::default().with_columns(Some(Arc::new(vec![column.to_string()])));
+        let mut scan_info = PhysicalScanInfo::new(scan_op, schema, vec![], pushdowns);
+        scan_info.scan_state = ScanState::Tasks(Arc::new(scan_tasks));
+        let source = LogicalPlan::Source(Source::new(
+            output_schema,
+            SourceInfo::Physical(scan_info).into(),
+        ));
+        Ok(LogicalPlanBuilder::from(Arc::new(source))
+            .distinct()?
+            .build())
+    }
+
+    /// Tests that of the tasks of each partition, only one that's known to have rows is read, and only its first row.
+    #[test]
+    fn distinct_partitions_read_first_rows() -> DaftResult<()> {
+        let plan = distinct_of_scan(
+            vec![
+                scan_task(1, None, false, None),
+                scan_task(1, Some(10), false, None),
+                scan_task(2, Some(0), false, None),
+                scan_task(2, None, false, None),
+                scan_task(3, Some(0), false, None),
+            ],
+            "part",
+        )?;
+        let expected = distinct_of_scan(
+            vec![
+                scan_task(1, Some(10), false, Some(1)),
+                scan_task(2, None, false, Some(1)),
+            ],
+            "part",
+        )?;
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that tasks with deleted rows aren't taken to have rows, as all the rows that their metadata counts may
+    /// be deleted.
+    #[test]
+    fn distinct_partitions_with_deleted_rows() -> DaftResult<()> {
+        let plan = distinct_of_scan(
+            vec![
+                scan_task(1, Some(10), true, None),
+                scan_task(1, Some(10), false, None),
+                scan_task(2, Some(10), true, None),
+                scan_task(2, None, false, None),
+            ],
+            "part",
+        )?;
+        let expected = distinct_of_scan(
+            vec![
+                scan_task(1, Some(10), false, Some(1)),
+                scan_task(2, Some(10), true, Some(1)),
+                scan_task(2, None, false, Some(1)),
+            ],
+            "part",
+        )?;
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that distincts of columns that aren't partition columns are left as they are.
+    #[test]
+    fn distinct_of_non_partition_column() -> DaftResult<()> {
+        let plan = distinct_of_scan(vec![scan_task(1, Some(10), false, None)], "a")?;
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
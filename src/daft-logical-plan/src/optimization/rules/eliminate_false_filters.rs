@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_scan_info::{PhysicalScanInfo, ScanState};
+use common_treenode::{Transformed, TreeNode};
+use daft_algebra::boolean::split_conjunction;
+use daft_dsl::{Expr, ExprRef, LiteralValue};
+
+use super::OptimizerRule;
+use crate::{
+    ops::{Filter, Source},
+    source_info::SourceInfo,
+    stats::StatsState,
+    LogicalPlan,
+};
+
+/// Optimization rule for scans whose rows are all filtered out by an always-false predicate, e.g. `WHERE 1 = 0` or a
+/// predicate that's simplified to `false`.
+///
+/// Such scans are replaced with scans without any scan tasks, which are planned as empty scans, so that no data is
+/// read. The predicate may be in a filter directly over the scan or pushed down into the scan.
+///
+/// All scan nodes MUST be materialized before this rule is applied.
+#[derive(Default, Debug)]
+pub struct EliminateFalseFilters {}
+
+impl EliminateFalseFilters {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for EliminateFalseFilters {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_up(|node| {
+            let source = match node.as_ref() {
+                LogicalPlan::Filter(Filter {
+                    input, predicate, ..
+                }) if is_always_false(predicate) => match input.as_ref() {
+                    LogicalPlan::Source(source) => source,
+                    _ => return Ok(Transformed::no(node)),
+                },
+                LogicalPlan::Source(source) if has_false_pushdowns(source) => source,
+                _ => return Ok(Transformed::no(node)),
+            };
+            match empty_scan(source) {
+                Some(empty_scan) => Ok(Transformed::yes(empty_scan.into())),
+                None => Ok(Transformed::no(node)),
+            }
+        })
+    }
+}
+
+/// Whether the predicate is false for all rows, i.e. one of its conjuncts is a literal `false`.
+///
+/// Pushed down filters aren't simplified, so they may be conjunctions with a literal `false`.
+fn is_always_false(predicate: &ExprRef) -> bool {
+    split_conjunction(predicate).iter().any(|conjunct| {
+        matches!(
+            conjunct.as_ref(),
+            Expr::Literal(LiteralValue::Boolean(false))
+        )
+    })
+}
+
+fn has_false_pushdowns(source: &Source) -> bool {
+    let SourceInfo::Physical(PhysicalScanInfo { pushdowns, .. }) = source.source_info.as_ref()
+    else {
+        return false;
+    };
+    [&pushdowns.filters, &pushdowns.partition_filters]
+        .into_iter()
+        .flatten()
+        .any(is_always_false)
+}
+
+/// The scan without any scan tasks, if its scan tasks are materialized.
+fn empty_scan(source: &Source) -> Option<Source> {
+    let SourceInfo::Physical(
+        scan_info @ PhysicalScanInfo {
+            scan_state: ScanState::Tasks(_),
+            pushdowns,
+            ..
+        },
+    ) = source.source_info.as_ref()
+    else {
+        return None;
+    };
+    let pushdowns = pushdowns.with_filters(None).with_partition_filters(None);
+    Some(Source {
+        source_info: SourceInfo::Physical(PhysicalScanInfo {
+            scan_state: ScanState::Tasks(Arc::new(vec![])),
+            ..scan_info.with_pushdowns(pushdowns)
+        })
+        .into(),
+        stats_state: StatsState::NotMaterialized,
+        ..source.clone()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use common_scan_info::{Pushdowns, ScanOperatorRef, ScanState};
+    use daft_core::prelude::*;
+    use daft_dsl::{lit, resolved_col, unresolved_col};
+
+    use crate::{
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            rules::EliminateFalseFilters,
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{dummy_scan_node_with_pushdowns, dummy_scan_operator},
+        LogicalPlan, LogicalPlanBuilder, SourceInfo,
+    };
+
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![Box::new(EliminateFalseFilters::new())],
+                RuleExecutionStrategy::Once,
+            )],
+        )
+    }
+
+    /// A scan node with the given pushdowns whose scan tasks are materialized, optionally dropping all of them.
+    fn materialized_scan_node(
+        scan_op: ScanOperatorRef,
+        pushdowns: Pushdowns,
+        drop_tasks: bool,
+    ) -> DaftResult<LogicalPlanBuilder> {
+        let LogicalPlan::Source(source) =
+            Arc::unwrap_or_clone(dummy_scan_node_with_pushdowns(scan_op, pushdowns).build())
+        else {
+            unreachable!("Expected a scan node")
+        };
+        let mut source = source.build_materialized_scan_source()?;
+        if drop_tasks {
+            let SourceInfo::Physical(mut scan_info) = Arc::unwrap_or_clone(source.source_info)
+            else {
+                unreachable!("Expected a physical scan")
+            };
+            scan_info.scan_state = ScanState::Tasks(Arc::new(vec![]));
+            source.source_info = Arc::new(SourceInfo::Physical(scan_info));
+        }
+        Ok(LogicalPlanBuilder::from(Arc::new(LogicalPlan::Source(
+            source,
+        ))))
+    }
+
+    fn scan_op() -> ScanOperatorRef {
+        dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ])
+    }
+
+    /// Tests that an always-false filter over a scan is replaced with an empty scan.
+    #[test]
+    fn false_filter_replaced_with_empty_scan() -> DaftResult<()> {
+        let plan = materialized_scan_node(scan_op(), Default::default(), false)?
+            .filter(lit(false))?
+            .build();
+        let expected = materialized_scan_node(scan_op(), Default::default(), true)?.build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that a scan with an always-false pushed down filter is replaced with an empty scan.
+    #[test]
+    fn false_pushdown_replaced_with_empty_scan() -> DaftResult<()> {
+        let pushdowns =
+            Pushdowns::default().with_filters(Some(resolved_col("a").lt(lit(2)).and(lit(false))));
+        let plan = materialized_scan_node(scan_op(), pushdowns, false)?.build();
+        let expected = materialized_scan_node(scan_op(), Default::default(), true)?.build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that filters that may be true are left as they are.
+    #[test]
+    fn other_filters_not_eliminated() -> DaftResult<()> {
+        let plan = materialized_scan_node(scan_op(), Default::default(), false)?
+            .filter(unresolved_col("a").lt(lit(2)))?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+
+        let pushdowns = Pushdowns::default().with_filters(Some(lit(true)));
+        let plan = materialized_scan_node(scan_op(), pushdowns, false)?.build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
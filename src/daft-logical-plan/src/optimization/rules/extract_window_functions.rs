@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
+use daft_dsl::{resolved_col, Expr, ExprRef, WindowSpec};
+use indexmap::{IndexMap, IndexSet};
+
+use crate::{
+    logical_plan::{LogicalPlan, Project},
+    ops::Window,
+    optimization::rules::OptimizerRule,
+};
+
+/// Optimization rule that extracts the window functions of Project operations into Window operations below them, one
+/// per distinct window, and replaces them in the projection with the columns of their results.
+#[derive(Default, Debug)]
+pub struct ExtractWindowFunctions {}
+
+impl ExtractWindowFunctions {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for ExtractWindowFunctions {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_down(|node| {
+            let LogicalPlan::Project(project) = node.as_ref() else {
+                return Ok(Transformed::no(node));
+            };
+            let mut windows: IndexMap<WindowSpec, IndexSet<ExprRef>> = IndexMap::new();
+            for expr in &project.projection {
+                expr.apply(|e| {
+                    if let Expr::Window(_, spec) = e.as_ref() {
+                        windows.entry(spec.clone()).or_default().insert(e.clone());
+                        Ok(TreeNodeRecursion::Jump)
+                    } else {
+                        Ok(TreeNodeRecursion::Continue)
+                    }
+                })?;
+            }
+            if windows.is_empty() {
+                return Ok(Transformed::no(node));
+            }
+
+            // The results of a window function are named by its semantic ID, like common subexpressions are.
+            let input_schema = project.input.schema();
+            let mut input = project.input.clone();
+            for (spec, functions) in windows {
+                let window_functions = functions
+                    .iter()
+                    .map(|function| function.alias(function.semantic_id(&input_schema).id))
+                    .collect();
+                input = Window::try_new(input, window_functions, spec)?.into();
+            }
+
+            let projection = project
+                .projection
+                .iter()
+                .map(|expr| {
+                    let replaced = expr
+                        .clone()
+                        .transform_down(|e| match e.as_ref() {
+                            Expr::Window(..) => Ok(Transformed::yes(resolved_col(
+                                e.semantic_id(&input_schema).id,
+                            ))),
+                            _ => Ok(Transformed::no(e)),
+                        })?
+                        .data;
+                    // Keep the name of the expression, which may have been the name of a window function.
+                    Ok(if replaced.name() == expr.name() {
+                        replaced
+                    } else {
+                        replaced.alias(expr.name())
+                    })
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            Ok(Transformed::yes(
+                Project::try_new(input, projection)?.into(),
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{rank, resolved_col, row_number, unresolved_col, WindowSpec};
+
+    use crate::{
+        ops::Window,
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            rules::ExtractWindowFunctions,
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan, LogicalPlanBuilder,
+    };
+
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![Box::new(ExtractWindowFunctions::new())],
+                RuleExecutionStrategy::Once,
+            )],
+        )
+    }
+
+    /// Tests that window functions over the same window are extracted into one Window operation, and replaced with the
+    /// columns of their results under their names.
+    #[test]
+    fn window_functions_extracted() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]);
+        let spec = WindowSpec {
+            partition_by: vec![resolved_col("a")],
+            order_by: vec![resolved_col("b")],
+            descending: vec![false],
+            null_strategy: Default::default(),
+            frame: None,
+        };
+        let row_number = row_number().over(spec.clone())?;
+        let rank = rank().over(spec.clone())?;
+        let plan = dummy_scan_node(scan_op.clone())
+            .select(vec![
+                unresolved_col("a"),
+                row_number.clone(),
+                rank.clone().alias("r"),
+            ])?
+            .build();
+
+        let input = dummy_scan_node(scan_op).build();
+        let schema = input.schema();
+        let row_number_id = row_number.semantic_id(&schema).id;
+        let rank_id = rank.semantic_id(&schema).id;
+        let window: Arc<LogicalPlan> = Window::try_new(
+            input,
+            vec![
+                row_number.alias(row_number_id.clone()),
+                rank.alias(rank_id.clone()),
+            ],
+            spec,
+        )?
+        .into();
+        let expected = LogicalPlanBuilder::from(window)
+            .select(vec![
+                resolved_col("a"),
+                resolved_col(row_number_id).alias("row_number"),
+                resolved_col(rank_id).alias("r"),
+            ])?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that projections without window functions are left as they are.
+    #[test]
+    fn projection_without_window_functions_unchanged() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![Field::new("a", DataType::Int64)]);
+        let plan = dummy_scan_node(scan_op)
+            .select(vec![unresolved_col("a").alias("b")])?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
@@ -1,11 +1,16 @@
+mod aggregate_from_stats;
 mod detect_monotonic_id;
+mod distinct_from_partitions;
 mod drop_repartition;
 mod eliminate_cross_join;
+mod eliminate_false_filters;
 mod eliminate_subquery_alias;
 mod enrich_with_stats;
+mod extract_window_functions;
 mod filter_null_join_key;
 mod lift_project_from_agg;
 mod materialize_scans;
+mod push_down_aggregation;
 mod push_down_filter;
 mod push_down_limit;
 mod push_down_projection;
@@ -15,14 +20,19 @@ mod simplify_expressions;
 mod split_actor_pool_projects;
 mod unnest_subquery;
 
+pub use aggregate_from_stats::AggregateFromStats;
 pub use detect_monotonic_id::DetectMonotonicId;
+pub use distinct_from_partitions::DistinctFromPartitions;
 pub use drop_repartition::DropRepartition;
 pub use eliminate_cross_join::EliminateCrossJoin;
+pub use eliminate_false_filters::EliminateFalseFilters;
 pub use eliminate_subquery_alias::EliminateSubqueryAliasRule;
 pub use enrich_with_stats::EnrichWithStats;
+pub use extract_window_functions::ExtractWindowFunctions;
 pub use filter_null_join_key::FilterNullJoinKey;
 pub use lift_project_from_agg::LiftProjectFromAgg;
 pub use materialize_scans::MaterializeScans;
+pub use push_down_aggregation::PushDownAggregation;
 pub use push_down_filter::PushDownFilter;
 pub use push_down_limit::PushDownLimit;
 pub use push_down_projection::PushDownProjection;
@@ -0,0 +1,270 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_scan_info::{PhysicalScanInfo, ScanState};
+use common_treenode::{Transformed, TreeNode};
+use daft_dsl::{lit, resolved_col, AggExpr, Column, Expr, ExprRef, ResolvedColumn};
+
+use super::OptimizerRule;
+use crate::{
+    ops::{Aggregate, Project, Source},
+    source_info::SourceInfo,
+    stats::StatsState,
+    LogicalPlan,
+};
+
+/// Optimization rule for pushing global counts, minimums and maximums of columns down into scans whose scan tasks can
+/// compute them without reading the rows, e.g. with database queries.
+///
+/// The scan tasks then yield partial results, which are merged by summing the counts and taking the minimums and
+/// maximums of the minimums and maximums.
+///
+/// This rule must be applied before scan nodes are materialized.
+#[derive(Default, Debug)]
+pub struct PushDownAggregation {}
+
+impl PushDownAggregation {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for PushDownAggregation {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_up(|node| {
+            let LogicalPlan::Aggregate(Aggregate {
+                input,
+                aggregations,
+                groupby,
+                output_schema,
+                ..
+            }) = node.as_ref()
+            else {
+                return Ok(Transformed::no(node));
+            };
+            let LogicalPlan::Source(source) = input.as_ref() else {
+                return Ok(Transformed::no(node));
+            };
+            let SourceInfo::Physical(
+                scan_info @ PhysicalScanInfo {
+                    scan_state: ScanState::Operator(scan_op),
+                    pushdowns,
+                    ..
+                },
+            ) = source.source_info.as_ref()
+            else {
+                return Ok(Transformed::no(node));
+            };
+            // Aggregations of a limited number of rows depend on which rows are read.
+            if !groupby.is_empty() || pushdowns.limit.is_some() || pushdowns.aggregations.is_some()
+            {
+                return Ok(Transformed::no(node));
+            }
+            for agg in aggregations {
+                if merge_aggregation(agg).is_none()
+                    || !scan_op.0.can_absorb_aggregation(agg, pushdowns)?
+                {
+                    return Ok(Transformed::no(node));
+                }
+            }
+
+            // The scan tasks yield partial results in columns named like the aggregations.
+            let partial_source = Source {
+                output_schema: output_schema.clone(),
+                source_info: SourceInfo::Physical(PhysicalScanInfo {
+                    pushdowns: pushdowns.with_aggregations(Some(Arc::new(aggregations.clone()))),
+                    ..scan_info.clone()
+                })
+                .into(),
+                stats_state: StatsState::NotMaterialized,
+                ..source.clone()
+            };
+            let merges = aggregations
+                .iter()
+                .zip(output_schema.fields.values())
+                .map(|(agg, field)| {
+                    let merge = merge_aggregation(agg).expect("Aggregation should be mergeable");
+                    let partial = resolved_col(field.name.as_str());
+                    Arc::new(Expr::Agg(merge(partial))).alias(field.name.as_str())
+                })
+                .collect();
+            let merged = Aggregate::try_new(partial_source.into(), merges, vec![])?;
+
+            // Counts are zero rather than null when there are no scan tasks, and the merged results are cast back to
+            // the types of the aggregations.
+            let projection = aggregations
+                .iter()
+                .zip(output_schema.fields.values())
+                .map(|(agg, field)| {
+                    let merged = resolved_col(field.name.as_str());
+                    let merged = if is_count(agg) {
+                        merged.fill_null(lit(0u64))
+                    } else {
+                        merged
+                    };
+                    merged.cast(&field.dtype).alias(field.name.as_str())
+                })
+                .collect();
+            let project = Project::try_new(merged.into(), projection)?;
+            Ok(Transformed::yes(project.into()))
+        })
+    }
+}
+
+fn unalias(agg: &ExprRef) -> &Expr {
+    match agg.as_ref() {
+        Expr::Alias(inner, _) => inner.as_ref(),
+        agg => agg,
+    }
+}
+
+fn is_count(agg: &ExprRef) -> bool {
+    matches!(unalias(agg), Expr::Agg(AggExpr::Count(..)))
+}
+
+/// The aggregation that merges the partial results of `agg`, if it's a count, minimum or maximum of a column.
+fn merge_aggregation(agg: &ExprRef) -> Option<fn(ExprRef) -> AggExpr> {
+    let Expr::Agg(agg) = unalias(agg) else {
+        return None;
+    };
+    let (AggExpr::Count(child, _) | AggExpr::Min(child) | AggExpr::Max(child)) = agg else {
+        return None;
+    };
+    if !matches!(
+        child.as_ref(),
+        Expr::Column(Column::Resolved(ResolvedColumn::Basic(_)))
+    ) {
+        return None;
+    }
+    match agg {
+        AggExpr::Count(..) => Some(AggExpr::Sum),
+        AggExpr::Min(_) => Some(AggExpr::Min),
+        _ => Some(AggExpr::Max),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use common_scan_info::{PhysicalScanInfo, Pushdowns, ScanOperatorRef};
+    use daft_core::prelude::*;
+    use daft_dsl::{lit, unresolved_col};
+
+    use crate::{
+        ops::Source,
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            rules::PushDownAggregation,
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{
+            dummy_scan_node, dummy_scan_node_with_pushdowns, dummy_scan_operator_with_aggregations,
+        },
+        LogicalPlan, LogicalPlanBuilder, SourceInfo,
+    };
+
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![Box::new(PushDownAggregation::new())],
+                RuleExecutionStrategy::Once,
+            )],
+        )
+    }
+
+    fn scan_op() -> ScanOperatorRef {
+        dummy_scan_operator_with_aggregations(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ])
+    }
+
+    /// Tests that counts, minimums and maximums are computed by the scan tasks and merged.
+    #[test]
+    fn aggregations_pushed_into_scan() -> DaftResult<()> {
+        let scan_op = scan_op();
+        let plan = dummy_scan_node(scan_op.clone())
+            .aggregate(
+                vec![
+                    unresolved_col("a").count(CountMode::Valid).alias("count"),
+                    unresolved_col("a").min().alias("min"),
+                    unresolved_col("b").max().alias("max"),
+                ],
+                vec![],
+            )?
+            .build();
+        let LogicalPlan::Aggregate(aggregate) = plan.as_ref() else {
+            unreachable!("Expected an aggregation")
+        };
+
+        let partial_scan = LogicalPlan::Source(Source::new(
+            aggregate.output_schema.clone(),
+            SourceInfo::Physical(PhysicalScanInfo::new(
+                scan_op.clone(),
+                scan_op.0.schema(),
+                vec![],
+                Pushdowns::default()
+                    .with_aggregations(Some(Arc::new(aggregate.aggregations.clone()))),
+            ))
+            .into(),
+        ));
+        let expected = LogicalPlanBuilder::from(Arc::new(partial_scan))
+            .aggregate(
+                vec![
+                    unresolved_col("count").sum().alias("count"),
+                    unresolved_col("min").min().alias("min"),
+                    unresolved_col("max").max().alias("max"),
+                ],
+                vec![],
+            )?
+            .select(vec![
+                unresolved_col("count")
+                    .fill_null(lit(0u64))
+                    .cast(&DataType::UInt64)
+                    .alias("count"),
+                unresolved_col("min").cast(&DataType::Int64).alias("min"),
+                unresolved_col("max").cast(&DataType::Utf8).alias("max"),
+            ])?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that aggregations that aren't counts, minimums or maximums of columns, grouped aggregations, and
+    /// aggregations of limited scans are left as they are.
+    #[test]
+    fn aggregations_not_pushed_into_scan() -> DaftResult<()> {
+        let plan = dummy_scan_node(scan_op())
+            .aggregate(
+                vec![
+                    unresolved_col("a").count(CountMode::All).alias("count"),
+                    unresolved_col("a").sum().alias("sum"),
+                ],
+                vec![],
+            )?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+
+        let plan = dummy_scan_node(scan_op())
+            .aggregate(
+                vec![unresolved_col("a").min().alias("min")],
+                vec![unresolved_col("b")],
+            )?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+
+        let plan =
+            dummy_scan_node_with_pushdowns(scan_op(), Pushdowns::default().with_limit(Some(10)))
+                .aggregate(vec![unresolved_col("a").max().alias("max")], vec![])?
+                .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
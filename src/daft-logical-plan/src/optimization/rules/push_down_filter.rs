@@ -9,8 +9,9 @@ use common_treenode::{DynTreeNode, Transformed, TreeNode};
 use daft_algebra::boolean::{combine_conjunction, split_conjunction, to_cnf};
 use daft_core::join::JoinType;
 use daft_dsl::{
-    optimization::{get_required_columns, replace_columns_with_expressions},
-    resolved_col, ExprRef,
+    is_deterministic,
+    optimization::{get_renamed_field, get_required_columns, replace_columns_with_expressions},
+    ExprRef,
 };
 
 use super::OptimizerRule;
@@ -43,11 +44,16 @@ impl PushDownFilter {
         plan: Arc<LogicalPlan>,
     ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
         let filter = match plan.as_ref() {
-            LogicalPlan::Filter(filter) => filter,
+            // Moving a nondeterministic predicate, such as `rand() < 0.5`, changes which rows it's evaluated on.
+            LogicalPlan::Filter(filter) if is_deterministic(&filter.predicate) => filter,
             _ => return Ok(Transformed::no(plan)),
         };
         let child_plan = filter.input.as_ref();
         let new_plan = match child_plan {
+            // Filtering rows out first would change which rows a nondeterministic predicate is evaluated on.
+            LogicalPlan::Filter(child_filter) if !is_deterministic(&child_filter.predicate) => {
+                return Ok(Transformed::no(plan));
+            }
             LogicalPlan::Filter(child_filter) => {
                 // Combine filters.
                 //
@@ -136,7 +142,12 @@ impl PushDownFilter {
                         assert!(data_filter.is_some() || partition_filter.is_some());
 
                         let new_pushdowns = if let Some(data_filter) = data_filter {
-                            external_info.pushdowns.with_filters(Some(data_filter))
+                            // The filter may read struct fields that were pruned from the source, so
+                            // read whole structs until projection pushdown prunes them again.
+                            external_info
+                                .pushdowns
+                                .with_filters(Some(data_filter))
+                                .with_struct_fields(None)
                         } else {
                             external_info.pushdowns.clone()
                         };
@@ -169,19 +180,22 @@ impl PushDownFilter {
                     }
                 }
             }
+            // Filtering rows out first would also change which rows nondeterministic projections are evaluated on.
+            LogicalPlan::Project(child_project)
+                if !child_project.projection.iter().all(is_deterministic) =>
+            {
+                return Ok(Transformed::no(plan));
+            }
             LogicalPlan::Project(child_project) => {
                 // Commute filter with projection if predicate only depends on projection columns that
-                // don't involve compute.
+                // don't involve compute, i.e. input columns or their struct fields, possibly renamed.
                 //
                 // Filter-Projection --> {Filter-}Projection-Filter
                 let predicates = split_conjunction(&filter.predicate);
                 let projection_input_mapping = child_project
                     .projection
                     .iter()
-                    .filter_map(|e| {
-                        e.input_mapping()
-                            .map(|s| (e.name().to_string(), resolved_col(s)))
-                    })
+                    .filter_map(|e| get_renamed_field(e).map(|field| (e.name().to_string(), field)))
                     .collect::<HashMap<String, ExprRef>>();
                 // Split predicate expressions into those that don't depend on projection compute (can_push) and those
                 // that do (can_not_push).
@@ -357,8 +371,8 @@ mod tests {
     use common_error::DaftResult;
     use common_scan_info::Pushdowns;
     use daft_core::prelude::*;
-    use daft_dsl::{lit, resolved_col};
-    use daft_functions::uri::download::UrlDownloadArgs;
+    use daft_dsl::{functions::struct_::get as struct_get, lit, resolved_col};
+    use daft_functions::{random::RandomDistribution, uri::download::UrlDownloadArgs};
     use rstest::rstest;
 
     use crate::{
@@ -455,6 +469,67 @@ mod tests {
         Ok(())
     }
 
+    /// Tests that a nondeterministic Filter isn't pushed into the scan or combined with the Filter below it.
+    #[test]
+    fn nondeterministic_filter_not_pushed_down() -> DaftResult<()> {
+        let pred = daft_functions::random::random(RandomDistribution::Uniform, Some(42), None)
+            .lt(lit(0.5));
+        let plan = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]))
+        .filter(pred.clone())?
+        .filter(resolved_col("a").lt(lit(2)))?
+        .build();
+        let expected = plan.clone();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that an Assert isn't pushed into the scan, and that Filters aren't pushed below it, so that it's evaluated
+    /// on exactly the rows of its input.
+    #[test]
+    fn filter_not_pushed_down_through_assert() -> DaftResult<()> {
+        let plan = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]))
+        .assert_that(
+            resolved_col("a").lt(lit(2)),
+            "a must be less than 2".to_string(),
+            None,
+        )?
+        .filter(resolved_col("a").gt(lit(0)))?
+        .build();
+        let expected = plan.clone();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that a Filter doesn't commute with a Projection of a nondeterministic expression, which would otherwise be
+    /// evaluated on fewer rows.
+    #[test]
+    fn filter_not_commuting_with_nondeterministic_projection() -> DaftResult<()> {
+        let scan_plan = dummy_scan_node_with_pushdowns(
+            dummy_scan_operator(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Utf8),
+            ]),
+            Pushdowns::default().with_limit(Some(1)),
+        );
+        let proj = vec![
+            resolved_col("a"),
+            daft_functions::random::random(RandomDistribution::Uniform, None, None).alias("r"),
+        ];
+        let plan = scan_plan
+            .select(proj)?
+            .filter(resolved_col("a").lt(lit(2)))?
+            .build();
+        let expected = plan.clone();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
     /// Tests that Filter commutes with Projections.
     #[rstest]
     fn filter_commutes_with_projection(
@@ -515,6 +590,51 @@ mod tests {
         Ok(())
     }
 
+    /// Tests that a Filter on renamed columns and struct fields commutes with the Projection that renames them, so
+    /// that it can be pushed into the scan in terms of the source columns.
+    #[rstest]
+    fn filter_commutes_with_projection_renaming_struct_field(
+        #[values(false, true)] push_into_scan: bool,
+    ) -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new(
+                "s",
+                DataType::Struct(vec![
+                    Field::new("x", DataType::Int64),
+                    Field::new("y", DataType::Utf8),
+                ]),
+            ),
+        ]);
+        let scan_plan = dummy_scan_node_with_pushdowns(
+            scan_op.clone(),
+            Pushdowns::default().with_limit(if push_into_scan { None } else { Some(1) }),
+        );
+        let proj = vec![
+            resolved_col("a").alias("b"),
+            struct_get(resolved_col("s"), "x").alias("x"),
+        ];
+        let plan = scan_plan
+            .select(proj.clone())?
+            .filter(
+                resolved_col("x")
+                    .lt(lit(2))
+                    .and(resolved_col("b").eq(lit(1))),
+            )?
+            .build();
+        let pred = struct_get(resolved_col("s"), "x")
+            .lt(lit(2))
+            .and(resolved_col("a").eq(lit(1)));
+        let expected_scan_filter = if push_into_scan {
+            dummy_scan_node_with_pushdowns(scan_op, Pushdowns::default().with_filters(Some(pred)))
+        } else {
+            scan_plan.filter(pred)?
+        };
+        let expected = expected_scan_filter.select(proj)?.build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
     /// Tests that Filter does not commute with a Projection if the projection expression involves compute.
     #[test]
     fn filter_does_not_commute_with_projection_if_compute() -> DaftResult<()> {
@@ -5,7 +5,10 @@ use common_treenode::{DynTreeNode, Transformed, TreeNode};
 use daft_core::prelude::*;
 use daft_dsl::{
     is_actor_pool_udf,
-    optimization::{get_required_columns, replace_columns_with_expressions, requires_computation},
+    optimization::{
+        get_required_columns, get_required_struct_fields, replace_columns_with_expressions,
+        requires_computation,
+    },
     resolved_col, Column, Expr, ExprRef, ResolvedColumn,
 };
 use indexmap::IndexSet;
@@ -146,6 +149,11 @@ impl PushDownProjection {
                 };
                 match source.source_info.as_ref() {
                     SourceInfo::Physical(external_info) => {
+                        let struct_fields = required_struct_fields(
+                            projection,
+                            external_info.pushdowns.filters.as_ref(),
+                            &upstream_schema,
+                        );
                         if required_columns.len() < upstream_schema.names().len() {
                             let pruned_upstream_schema = upstream_schema
                                 .fields
@@ -156,11 +164,16 @@ impl PushDownProjection {
                             let schema = Schema::new(pruned_upstream_schema)?;
                             let new_source: LogicalPlan = Source::new(
                                 schema.into(),
-                                Arc::new(SourceInfo::Physical(external_info.with_pushdowns(
-                                    external_info.pushdowns.with_columns(Some(Arc::new(
-                                        required_columns.iter().cloned().collect(),
-                                    ))),
-                                ))),
+                                Arc::new(SourceInfo::Physical(
+                                    external_info.with_pushdowns(
+                                        external_info
+                                            .pushdowns
+                                            .with_columns(Some(Arc::new(
+                                                required_columns.iter().cloned().collect(),
+                                            )))
+                                            .with_struct_fields(struct_fields),
+                                    ),
+                                )),
                             )
                             .into();
                             let new_plan = Arc::new(plan.with_new_children(&[new_source.into()]));
@@ -169,6 +182,18 @@ impl PushDownProjection {
                                 .try_optimize_node(new_plan.clone())?
                                 .or(Transformed::yes(new_plan));
                             Ok(new_plan)
+                        } else if struct_fields != external_info.pushdowns.struct_fields {
+                            // Only the struct fields that are read from the source changed.
+                            let new_source: LogicalPlan = Source::new(
+                                source.output_schema.clone(),
+                                Arc::new(SourceInfo::Physical(external_info.with_pushdowns(
+                                    external_info.pushdowns.with_struct_fields(struct_fields),
+                                ))),
+                            )
+                            .into();
+                            Ok(Transformed::yes(Arc::new(
+                                plan.with_new_children(&[new_source.into()]),
+                            )))
                         } else {
                             Ok(Transformed::no(plan))
                         }
@@ -348,6 +373,7 @@ impl PushDownProjection {
             | LogicalPlan::Repartition(..)
             | LogicalPlan::Limit(..)
             | LogicalPlan::Filter(..)
+            | LogicalPlan::Assert(..)
             | LogicalPlan::Sample(..)
             | LogicalPlan::Explode(..) => {
                 // Get required columns from projection and upstream.
@@ -384,6 +410,41 @@ impl PushDownProjection {
                     .or(Transformed::yes(new_plan));
                 Ok(new_plan)
             }
+            LogicalPlan::Window(..) => {
+                // Like above, except that the results of the window functions aren't columns of the input.
+                let grand_upstream_plan = &upstream_plan.arc_children()[0];
+                let grand_upstream_schema = grand_upstream_plan.schema();
+                let combined_dependencies = plan
+                    .required_columns()
+                    .iter()
+                    .flatten()
+                    .chain(upstream_plan.required_columns().iter().flatten())
+                    .filter(|name| grand_upstream_schema.has_field(name))
+                    .cloned()
+                    .collect::<IndexSet<_>>();
+
+                // Skip optimization if no columns would be pruned.
+                if grand_upstream_schema.len() == combined_dependencies.len() {
+                    return Ok(Transformed::no(plan));
+                }
+
+                let new_subprojection: LogicalPlan = {
+                    let pushdown_column_exprs = combined_dependencies
+                        .into_iter()
+                        .map(resolved_col)
+                        .collect::<Vec<_>>();
+
+                    Project::try_new(grand_upstream_plan.clone(), pushdown_column_exprs)?.into()
+                };
+
+                let new_upstream = upstream_plan.with_new_children(&[new_subprojection.into()]);
+                let new_plan = Arc::new(plan.with_new_children(&[new_upstream.into()]));
+                // Retry optimization now that the upstream node is different.
+                let new_plan = self
+                    .try_optimize_node(new_plan.clone())?
+                    .or(Transformed::yes(new_plan));
+                Ok(new_plan)
+            }
             LogicalPlan::Unpivot(unpivot) => {
                 let combined_dependencies = plan
                     .required_columns()
@@ -540,6 +601,11 @@ impl PushDownProjection {
                 // since Intersect implicitly requires all parent columns.
                 Ok(Transformed::no(plan))
             }
+            LogicalPlan::Iterate(_) => {
+                // Cannot push down past an Iterate,
+                // since its iterations read all the columns of its input.
+                Ok(Transformed::no(plan))
+            }
             LogicalPlan::Pivot(_) | LogicalPlan::MonotonicallyIncreasingId(_) => {
                 // Cannot push down past a Pivot/MonotonicallyIncreasingId because it changes the schema.
                 Ok(Transformed::no(plan))
@@ -699,6 +765,25 @@ impl PushDownProjection {
     }
 }
 
+/// Returns the paths of the fields that `projection` and the source's `filters` read from struct
+/// columns of the source, for the struct columns that are only used through struct field accesses.
+fn required_struct_fields(
+    projection: &Project,
+    filters: Option<&ExprRef>,
+    source_schema: &Schema,
+) -> Option<Arc<Vec<Vec<String>>>> {
+    let mut required_fields =
+        get_required_struct_fields(projection.projection.iter().chain(filters));
+    let struct_fields = source_schema
+        .fields
+        .iter()
+        .filter(|(_, field)| matches!(field.dtype, DataType::Struct(..)))
+        .filter_map(|(name, _)| required_fields.remove(name).flatten())
+        .flat_map(|paths| paths.into_iter().sorted().dedup())
+        .collect::<Vec<_>>();
+    (!struct_fields.is_empty()).then(|| Arc::new(struct_fields))
+}
+
 impl OptimizerRule for PushDownProjection {
     fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
         plan.transform_down(|node| self.try_optimize_node(node))
@@ -716,6 +801,7 @@ mod tests {
     use daft_dsl::{
         functions::{
             python::{MaybeInitializedUDF, PythonUDF, RuntimePyObject},
+            struct_::get as struct_get,
             FunctionExpr,
         },
         lit, resolved_col, unresolved_col, Expr, ExprRef,
@@ -879,6 +965,50 @@ mod tests {
         Ok(())
     }
 
+    /// Projection<-Source, with struct columns that are only partially read
+    #[test]
+    fn test_projection_source_struct_fields() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new(
+                "s",
+                DataType::Struct(vec![
+                    Field::new("a", DataType::Int64),
+                    Field::new("b", DataType::Utf8),
+                ]),
+            ),
+            Field::new(
+                "t",
+                DataType::Struct(vec![Field::new("c", DataType::Int64)]),
+            ),
+            Field::new("i", DataType::Int64),
+            Field::new("j", DataType::Int64),
+        ]);
+        let proj = vec![
+            struct_get(unresolved_col("s"), "a").add(unresolved_col("i")),
+            unresolved_col("t"),
+        ];
+        let plan = dummy_scan_node(scan_op.clone())
+            .select(proj.clone())?
+            .build();
+
+        let expected = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default()
+                .with_columns(Some(Arc::new(vec![
+                    "s".to_string(),
+                    "i".to_string(),
+                    "t".to_string(),
+                ])))
+                .with_struct_fields(Some(Arc::new(vec![vec!["s".to_string(), "a".to_string()]]))),
+        )
+        .select(proj)?
+        .build();
+
+        assert_optimized_plan_eq(plan, expected)?;
+
+        Ok(())
+    }
+
     /// Projection<-Projection column pruning
     #[test]
     fn test_projection_projection() -> DaftResult<()> {
@@ -972,6 +1102,37 @@ mod tests {
         Ok(())
     }
 
+    /// Projection<-Limit<-Sort<-Source prunes the columns that are read from the source
+    #[test]
+    fn test_projection_pushdown_past_sort_and_limit() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Utf8),
+        ]);
+        let proj = vec![unresolved_col("a")];
+        let plan = dummy_scan_node(scan_op.clone())
+            .sort(vec![unresolved_col("b")], vec![false], vec![false])?
+            .limit(10, false)?
+            .select(proj.clone())?
+            .build();
+
+        let proj_pushdown = vec!["a".to_string(), "b".to_string()];
+        let expected = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default().with_columns(Some(Arc::new(proj_pushdown))),
+        )
+        .sort(vec![unresolved_col("b")], vec![false], vec![false])?
+        .select(vec![resolved_col("a")])?
+        .limit(10, false)?
+        .select(proj)?
+        .build();
+
+        assert_optimized_plan_eq(plan, expected)?;
+
+        Ok(())
+    }
+
     /// Projection does not push down past monotonically increasing id
     #[test]
     fn test_projection_no_pushdown_monotonically_increasing_id() -> DaftResult<()> {
@@ -1137,6 +1298,8 @@ mod tests {
                 partition_filters: None,
                 columns: Some(Arc::new(vec!["a".to_string()])),
                 filters: None,
+                struct_fields: None,
+                aggregations: None,
             },
         )
         .build();
@@ -1181,6 +1344,8 @@ mod tests {
                     "Feb".to_string(),
                 ])),
                 filters: None,
+                struct_fields: None,
+                aggregations: None,
             },
         )
         .build();
@@ -303,7 +303,7 @@ impl OptimizerRule for UnnestPredicateSubquery {
                     return Ok(Transformed::no(node));
                 }
 
-                let new_input = subqueries.into_iter().try_fold(input.clone(), |curr_input, PredicateSubquery { subquery, in_expr, join_type }| {
+                let new_input = subqueries.into_iter().try_fold(input.clone(), |mut curr_input, PredicateSubquery { subquery, in_expr, join_type }| {
                     let subquery_plan = downcast_subquery(&subquery);
                     let subquery_schema = subquery_plan.schema();
 
@@ -316,6 +316,15 @@ impl OptimizerRule for UnnestPredicateSubquery {
                             return Err(DaftError::ValueError(format!("Expected IN subquery to have one output column, received: {}", subquery_col_names.len())));
                         };
 
+                        // `NOT IN` is null for null values, as `is_in` on a list is, so their rows are filtered out
+                        // rather than kept by the anti join for having no match.
+                        if join_type == JoinType::Anti {
+                            curr_input = Arc::new(LogicalPlan::Filter(Filter::try_new(
+                                curr_input,
+                                in_expr.clone().not_null(),
+                            )?));
+                        }
+
                         input_on.push(in_expr);
                         subquery_on.push(resolved_col(output_col.as_str()));
                     }
@@ -499,12 +508,15 @@ fn pull_up_correlated_cols(
 
         // ops that cannot pull up correlated columns
         LogicalPlan::ActorPoolProject(..)
+        | LogicalPlan::Assert(..)
+        | LogicalPlan::Iterate(..)
         | LogicalPlan::Limit(..)
         | LogicalPlan::Sample(..)
         | LogicalPlan::Source(..)
         | LogicalPlan::Explode(..)
         | LogicalPlan::Unpivot(..)
         | LogicalPlan::Pivot(..)
+        | LogicalPlan::Window(..)
         | LogicalPlan::Concat(..)
         | LogicalPlan::Join(..)
         | LogicalPlan::Sink(..) => {
@@ -732,6 +744,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn uncorrelated_negated_predicate_subquery() -> DaftResult<()> {
+        let tbl1 = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("key", DataType::Int64),
+            Field::new("val", DataType::Int64),
+        ]));
+
+        let tbl2 = dummy_scan_node(dummy_scan_operator(vec![Field::new(
+            "key",
+            DataType::Int64,
+        )]));
+
+        let plan = tbl1
+            .filter(
+                Arc::new(Expr::InSubquery(
+                    unresolved_col("key"),
+                    Subquery { plan: tbl2.build() },
+                ))
+                .not(),
+            )?
+            .select(vec![unresolved_col("val")])?
+            .build();
+
+        // Null keys are filtered out before the anti join, rather than kept for having no match.
+        let expected = tbl1
+            .filter(unresolved_col("key").not_null())?
+            .join(
+                tbl2,
+                vec![unresolved_col("key")],
+                vec![unresolved_col("key")],
+                JoinType::Anti,
+                None,
+                Default::default(),
+            )?
+            .select(vec![unresolved_col("val")])?
+            .build();
+
+        assert_predicate_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
     #[test]
     fn correlated_predicate_subquery() -> DaftResult<()> {
         let tbl1 = dummy_scan_node(dummy_scan_operator(vec![
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum RepartitionSpec {
     Hash(HashRepartitionConfig),
+    Range(RangeRepartitionConfig),
     Random(RandomShuffleConfig),
     IntoPartitions(IntoPartitionsConfig),
 }
@@ -17,6 +18,7 @@ impl RepartitionSpec {
     pub fn var_name(&self) -> &'static str {
         match self {
             Self::Hash(_) => "Hash",
+            Self::Range(_) => "Range",
             Self::Random(_) => "Random",
             Self::IntoPartitions(_) => "IntoPartitions",
         }
@@ -24,7 +26,8 @@ impl RepartitionSpec {
 
     pub fn repartition_by(&self) -> Vec<ExprRef> {
         match self {
-            Self::Hash(HashRepartitionConfig { by, .. }) => by.clone(),
+            Self::Hash(HashRepartitionConfig { by, .. })
+            | Self::Range(RangeRepartitionConfig { by, .. }) => by.clone(),
             _ => vec![],
         }
     }
@@ -32,6 +35,7 @@ impl RepartitionSpec {
     pub fn multiline_display(&self) -> Vec<String> {
         match self {
             Self::Hash(conf) => conf.multiline_display(),
+            Self::Range(conf) => conf.multiline_display(),
             Self::Random(conf) => conf.multiline_display(),
             Self::IntoPartitions(conf) => conf.multiline_display(),
         }
@@ -45,6 +49,15 @@ impl RepartitionSpec {
                     by.clone(),
                 ))
             }
+            Self::Range(RangeRepartitionConfig {
+                num_partitions,
+                by,
+                descending,
+            }) => ClusteringSpec::Range(RangeClusteringConfig::new(
+                num_partitions.unwrap_or(upstream_num_partitions),
+                by.clone(),
+                descending.clone(),
+            )),
             Self::Random(RandomShuffleConfig { num_partitions }) => ClusteringSpec::Random(
                 RandomClusteringConfig::new(num_partitions.unwrap_or(upstream_num_partitions)),
             ),
@@ -77,6 +90,37 @@ impl HashRepartitionConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
+pub struct RangeRepartitionConfig {
+    pub num_partitions: Option<usize>,
+    pub by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+}
+
+impl RangeRepartitionConfig {
+    pub fn new(num_partitions: Option<usize>, by: Vec<ExprRef>, descending: Vec<bool>) -> Self {
+        Self {
+            num_partitions,
+            by,
+            descending,
+        }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!("Num partitions = {:?}", self.num_partitions));
+        res.push(format!(
+            "By = {}",
+            self.by
+                .iter()
+                .zip(self.descending.iter())
+                .map(|(e, d)| format!("({}, {})", e, if *d { "descending" } else { "ascending" }))
+                .join(", ")
+        ));
+        res
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
 pub struct RandomShuffleConfig {
     pub num_partitions: Option<usize>,
@@ -328,8 +372,8 @@ fn translate_clustering_spec_expr(
 
             Ok(expr.in_subquery(subquery.clone()))
         }
-        // Cannot have agg exprs or references to other tables in clustering specs.
-        Expr::Agg(_) | Expr::Column(..) => Err(()),
+        // Cannot have agg exprs, window exprs or references to other tables in clustering specs.
+        Expr::Agg(_) | Expr::Window(..) | Expr::Column(..) => Err(()),
     }
 }
 
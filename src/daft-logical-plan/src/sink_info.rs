@@ -4,7 +4,7 @@ use common_file_formats::FileFormat;
 use common_io_config::IOConfig;
 #[cfg(feature = "python")]
 use common_py_serde::{deserialize_py_object, serialize_py_object};
-use daft_dsl::ExprRef;
+use daft_dsl::{functions::python::RuntimePyObject, ExprRef};
 use derivative::Derivative;
 use itertools::Itertools;
 #[cfg(feature = "python")]
@@ -26,6 +26,21 @@ pub struct OutputFileInfo {
     pub partition_cols: Option<Vec<ExprRef>>,
     pub compression: Option<String>,
     pub io_config: Option<IOConfig>,
+    /// Identifies the write job; when set, it prefixes the names of all files written by the job.
+    pub job_id: Option<String>,
+    /// Columns of written Parquet files that get a bloom filter, for pruning row groups on equality predicates.
+    pub bloom_filter_columns: Option<Vec<String>>,
+    /// Target in-memory size in bytes of the row groups of written Parquet files, overriding the execution config.
+    pub row_group_size_bytes: Option<usize>,
+    pub encryption: Option<ParquetEncryptionInfo>,
+}
+
+/// Parquet modular encryption settings for written files, held as a `daft.io.ParquetEncryptionConfig`.
+///
+/// The config is compared and hashed by value, so that writes with different keys are different plans.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ParquetEncryptionInfo {
+    pub config: RuntimePyObject,
 }
 
 #[cfg(feature = "python")]
@@ -152,6 +167,7 @@ impl OutputFileInfo {
         partition_cols: Option<Vec<ExprRef>>,
         compression: Option<String>,
         io_config: Option<IOConfig>,
+        job_id: Option<String>,
     ) -> Self {
         Self {
             root_dir,
@@ -159,6 +175,10 @@ impl OutputFileInfo {
             partition_cols,
             compression,
             io_config,
+            job_id,
+            bloom_filter_columns: None,
+            row_group_size_bytes: None,
+            encryption: None,
         }
     }
 
@@ -174,6 +194,21 @@ impl OutputFileInfo {
             res.push(format!("Compression = {}", compression));
         }
         res.push(format!("Root dir = {}", self.root_dir));
+        if let Some(ref job_id) = self.job_id {
+            res.push(format!("Job ID = {}", job_id));
+        }
+        if let Some(ref bloom_filter_columns) = self.bloom_filter_columns {
+            res.push(format!(
+                "Bloom filter columns = {}",
+                bloom_filter_columns.join(", ")
+            ));
+        }
+        if let Some(row_group_size_bytes) = self.row_group_size_bytes {
+            res.push(format!("Row group size = {row_group_size_bytes} bytes"));
+        }
+        if self.encryption.is_some() {
+            res.push("Encrypted = true".to_string());
+        }
         match &self.io_config {
             None => res.push("IOConfig = None".to_string()),
             Some(io_config) => res.push(format!("IOConfig = {}", io_config)),
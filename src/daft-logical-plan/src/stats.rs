@@ -1,6 +1,7 @@
-use std::{fmt::Display, hash::Hash, ops::Deref};
+use std::{collections::HashMap, fmt::Display, hash::Hash, ops::Deref};
 
 use common_display::utils::bytes_to_human_readable;
+use daft_dsl::{ColumnSelectivityStats, ExprRef, Histogram, SelectivityStats};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
@@ -20,20 +21,168 @@ impl StatsState {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PlanStats {
-    // Currently we're only putting cardinality stats in the plan stats.
-    // In the future we want to start including column stats, including min, max, NDVs, etc.
+    // Currently we're only putting cardinality stats and NDVs in the plan stats.
+    // In the future we want to start including more column stats, such as min and max.
     pub approx_stats: ApproxStats,
+    // The approximate number of distinct non-null values of the output columns for which it is known.
+    #[serde(default)]
+    pub num_distinct: HashMap<String, usize>,
+    // Equi-depth histograms of the output columns for which they are known, from analyzing the scanned datasets.
+    #[serde(default)]
+    pub histograms: HashMap<String, Histogram>,
+    // The semantic ID of the logical plan node these stats were materialized for, which is displayed with the nodes of
+    // the physical plans that the node is translated into, so that they can be correlated with each other.
+    #[serde(default)]
+    pub semantic_id: Option<String>,
 }
 
 impl PlanStats {
     pub fn new(approx_stats: ApproxStats) -> Self {
-        Self { approx_stats }
+        Self {
+            approx_stats,
+            num_distinct: HashMap::new(),
+            histograms: HashMap::new(),
+            semantic_id: None,
+        }
     }
 
     pub fn empty() -> Self {
-        Self {
-            approx_stats: ApproxStats::empty(),
+        Self::new(ApproxStats::empty())
+    }
+
+    /// Sets the NDVs of the output columns, capping them at the number of output rows.
+    #[must_use]
+    pub fn with_num_distinct(mut self, num_distinct: HashMap<String, usize>) -> Self {
+        let num_rows = self.approx_stats.num_rows;
+        self.num_distinct = num_distinct
+            .into_iter()
+            .map(|(column, num_distinct)| (column, num_distinct.min(num_rows)))
+            .collect();
+        self
+    }
+
+    #[must_use]
+    pub fn with_semantic_id(mut self, semantic_id: String) -> Self {
+        self.semantic_id = Some(semantic_id);
+        self
+    }
+
+    #[must_use]
+    pub fn with_histograms(mut self, histograms: HashMap<String, Histogram>) -> Self {
+        self.histograms = histograms;
+        self
+    }
+
+    /// Returns the NDV of `expr` if it's a possibly aliased output column whose NDV is known.
+    pub fn expr_num_distinct(&self, expr: &ExprRef) -> Option<usize> {
+        expr.input_mapping()
+            .and_then(|column| self.num_distinct.get(&column).copied())
+    }
+
+    /// Returns the approximate number of distinct combinations of the values of `exprs`, if the NDV of every one of them
+    /// is known. Columns are assumed to be independent, so this is the product of their NDVs capped at the number of rows.
+    pub fn exprs_num_distinct(&self, exprs: &[ExprRef]) -> Option<usize> {
+        exprs
+            .iter()
+            .map(|expr| self.expr_num_distinct(expr))
+            .try_fold(1usize, |acc, num_distinct| {
+                num_distinct.map(|num_distinct| acc.saturating_mul(num_distinct.max(1)))
+            })
+            .map(|num_distinct| num_distinct.min(self.approx_stats.num_rows))
+    }
+
+    /// Returns the NDVs of the output columns of a projection of `exprs` on the plan these stats are for.
+    pub fn projected_num_distinct(&self, exprs: &[ExprRef]) -> HashMap<String, usize> {
+        exprs
+            .iter()
+            .filter_map(|expr| {
+                self.expr_num_distinct(expr)
+                    .map(|num_distinct| (expr.name().to_string(), num_distinct))
+            })
+            .collect()
+    }
+
+    /// Returns the histograms of the output columns of a projection of `exprs` on the plan these stats are for.
+    pub fn projected_histograms(&self, exprs: &[ExprRef]) -> HashMap<String, Histogram> {
+        exprs
+            .iter()
+            .filter_map(|expr| {
+                let histogram = self.histograms.get(&expr.input_mapping()?)?;
+                Some((expr.name().to_string(), histogram.clone()))
+            })
+            .collect()
+    }
+
+    /// Returns the column stats used to estimate the selectivity of predicates on the output of the plan.
+    pub fn selectivity_stats(&self) -> SelectivityStats {
+        let mut stats = selectivity_stats(&self.num_distinct);
+        for (column, histogram) in &self.histograms {
+            let column_stats = stats.entry(column.clone()).or_default();
+            column_stats.min = Some(histogram.min());
+            column_stats.max = Some(histogram.max());
+            column_stats.histogram = Some(histogram.clone());
         }
+        stats
+    }
+
+    /// Estimates how many of `num_partitions` range partitions by `sort_by` would hold any rows, from the histogram of
+    /// the sort key. Skewed keys have values that can't be split between partitions, so fewer partitions are needed.
+    pub fn estimated_range_partitions(&self, sort_by: &[ExprRef], num_partitions: usize) -> usize {
+        match sort_by {
+            [key]
+                if let Some(histogram) = key
+                    .input_mapping()
+                    .and_then(|column| self.histograms.get(&column)) =>
+            {
+                (histogram.range_boundaries(num_partitions).len() + 1).min(num_partitions)
+            }
+            _ => num_partitions,
+        }
+    }
+}
+
+/// Returns the column stats used to estimate the selectivity of predicates on columns with the given NDVs.
+pub fn selectivity_stats(num_distinct: &HashMap<String, usize>) -> SelectivityStats {
+    num_distinct
+        .iter()
+        .map(|(column, &num_distinct)| {
+            let stats = ColumnSelectivityStats {
+                num_distinct: Some(num_distinct as f64),
+                ..Default::default()
+            };
+            (column.clone(), stats)
+        })
+        .collect()
+}
+
+/// Returns whether a column with `num_distinct` distinct values in `num_rows` rows is close to unique, like a key.
+fn is_near_unique(num_rows: usize, num_distinct: usize) -> bool {
+    num_distinct.saturating_mul(10) >= num_rows.saturating_mul(9)
+}
+
+/// Estimates the NDV of a column over the union of two sets of rows, given the number of rows and the NDV of the column
+/// in each. NDVs can't be merged exactly: the values of a column that is close to unique in both sets are assumed not to
+/// repeat between them, while those of any other column are assumed to be shared between them.
+pub fn union_num_distinct(left: (usize, usize), right: (usize, usize)) -> usize {
+    let ((left_rows, left_distinct), (right_rows, right_distinct)) = (left, right);
+    if is_near_unique(left_rows, left_distinct) && is_near_unique(right_rows, right_distinct) {
+        left_distinct + right_distinct
+    } else {
+        left_distinct.max(right_distinct)
+    }
+}
+
+/// Estimates the NDV of a column over `total_rows` rows from its NDV over a sample of `sample_rows` of them, with the
+/// same assumptions as [`union_num_distinct`].
+pub fn extrapolate_num_distinct(
+    sample_rows: usize,
+    num_distinct: usize,
+    total_rows: usize,
+) -> usize {
+    if sample_rows == 0 || total_rows <= sample_rows || !is_near_unique(sample_rows, num_distinct) {
+        num_distinct
+    } else {
+        (num_distinct as f64 * total_rows as f64 / sample_rows as f64).ceil() as usize
     }
 }
 
@@ -153,3 +302,98 @@ impl Add for &ApproxStats {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use daft_dsl::{binary_op, lit, resolved_col, Histogram, Operator};
+
+    use super::{extrapolate_num_distinct, union_num_distinct, ApproxStats, PlanStats};
+
+    #[test]
+    fn test_num_distinct_of_exprs() {
+        let stats = PlanStats::new(ApproxStats {
+            num_rows: 1000,
+            size_bytes: 8000,
+            acc_selectivity: 1.0,
+        })
+        .with_num_distinct(HashMap::from([
+            ("a".to_string(), 10),
+            ("b".to_string(), 20),
+            ("c".to_string(), 5000),
+        ]));
+
+        // NDVs are capped at the number of rows.
+        assert_eq!(stats.num_distinct["c"], 1000);
+        assert_eq!(
+            stats.expr_num_distinct(&resolved_col("a").alias("x")),
+            Some(10)
+        );
+        assert_eq!(
+            stats.exprs_num_distinct(&[resolved_col("a"), resolved_col("b")]),
+            Some(200)
+        );
+        assert_eq!(
+            stats.exprs_num_distinct(&[resolved_col("a"), resolved_col("c")]),
+            Some(1000)
+        );
+        // Neither computed expressions nor columns without NDVs have a known NDV.
+        assert_eq!(
+            stats.exprs_num_distinct(&[resolved_col("a"), resolved_col("d")]),
+            None
+        );
+        assert_eq!(
+            stats.exprs_num_distinct(&[binary_op(Operator::Plus, resolved_col("a"), lit(1))]),
+            None
+        );
+
+        let projected = stats.projected_num_distinct(&[
+            resolved_col("b").alias("x"),
+            binary_op(Operator::Plus, resolved_col("a"), lit(1)),
+        ]);
+        assert_eq!(projected, HashMap::from([("x".to_string(), 20)]));
+    }
+
+    #[test]
+    fn test_union_and_extrapolate_num_distinct() {
+        // Close to unique columns are assumed not to share values.
+        assert_eq!(union_num_distinct((100, 95), (200, 200)), 295);
+        assert_eq!(extrapolate_num_distinct(100, 95, 1000), 950);
+        // Other columns are assumed to share their values.
+        assert_eq!(union_num_distinct((100, 10), (200, 200)), 200);
+        assert_eq!(extrapolate_num_distinct(100, 10, 1000), 10);
+    }
+
+    #[test]
+    fn test_histograms() {
+        let skewed = Histogram::new(vec![0.0, 0.0, 0.0, 0.0, 100.0]).unwrap();
+        let uniform = Histogram::new(vec![0.0, 25.0, 50.0, 75.0, 100.0]).unwrap();
+        let stats = PlanStats::new(ApproxStats {
+            num_rows: 1000,
+            size_bytes: 8000,
+            acc_selectivity: 1.0,
+        })
+        .with_histograms(HashMap::from([
+            ("a".to_string(), skewed),
+            ("b".to_string(), uniform),
+        ]));
+
+        // Most of the values of the skewed column are in a single range partition.
+        assert_eq!(stats.estimated_range_partitions(&[resolved_col("a")], 8), 3);
+        assert_eq!(
+            stats.estimated_range_partitions(&[resolved_col("b").alias("x")], 8),
+            8
+        );
+        assert_eq!(
+            stats.estimated_range_partitions(&[resolved_col("a"), resolved_col("b")], 8),
+            8
+        );
+
+        let projected = stats.projected_histograms(&[resolved_col("b").alias("x")]);
+        assert_eq!(projected.keys().collect::<Vec<_>>(), vec!["x"]);
+        let selectivity_stats = stats.selectivity_stats();
+        assert_eq!(selectivity_stats["b"].min, Some(0.0));
+        assert_eq!(selectivity_stats["b"].max, Some(100.0));
+    }
+}
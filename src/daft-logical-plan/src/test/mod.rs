@@ -21,6 +21,20 @@ pub fn dummy_scan_operator_with_size(
         schema,
         num_scan_tasks: 1,
         num_rows_per_task,
+        size_bytes_per_task: None,
+        can_absorb_aggregations: false,
+    }))
+}
+
+/// Create a dummy scan node containing the provided fields in its schema, whose scan tasks can compute aggregations.
+pub fn dummy_scan_operator_with_aggregations(fields: Vec<Field>) -> ScanOperatorRef {
+    let schema = Arc::new(Schema::new(fields).unwrap());
+    ScanOperatorRef(Arc::new(DummyScanOperator {
+        schema,
+        num_scan_tasks: 1,
+        num_rows_per_task: None,
+        size_bytes_per_task: None,
+        can_absorb_aggregations: true,
     }))
 }
 
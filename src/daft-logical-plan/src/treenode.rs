@@ -8,7 +8,7 @@ use daft_dsl::ExprRef;
 
 use crate::{
     ops::Source,
-    partitioning::{HashRepartitionConfig, RepartitionSpec},
+    partitioning::{HashRepartitionConfig, RangeRepartitionConfig, RepartitionSpec},
     LogicalPlan, SourceInfo,
 };
 
@@ -102,6 +102,27 @@ impl LogicalPlan {
                         })
                         .into()
                     }),
+                RepartitionSpec::Range(RangeRepartitionConfig {
+                    num_partitions,
+                    by,
+                    descending,
+                }) => by
+                    .iter()
+                    .cloned()
+                    .map_and_collect(|expr| f(expr, &input.schema()))?
+                    .update_data(|expr| {
+                        Self::Repartition(Repartition {
+                            plan_id: *plan_id,
+                            input: input.clone(),
+                            repartition_spec: RepartitionSpec::Range(RangeRepartitionConfig {
+                                num_partitions: *num_partitions,
+                                by: expr,
+                                descending: descending.clone(),
+                            }),
+                            stats_state: stats_state.clone(),
+                        })
+                        .into()
+                    }),
                 _ => Transformed::no(self.clone()),
             },
             Self::ActorPoolProject(ActorPoolProject {
@@ -130,6 +151,7 @@ impl LogicalPlan {
                 sort_by,
                 descending,
                 nulls_first,
+                within_partitions,
                 stats_state,
             }) => sort_by
                 .iter()
@@ -142,6 +164,7 @@ impl LogicalPlan {
                         sort_by: new_sort_by,
                         descending: descending.clone(),
                         nulls_first: nulls_first.clone(),
+                        within_partitions: *within_partitions,
                         stats_state: stats_state.clone(),
                     })
                     .into()
@@ -7,7 +7,9 @@ use snafu::Snafu;
 mod micropartition;
 mod ops;
 
-pub use micropartition::{MicroPartition, MicroPartitionRef};
+pub use micropartition::{
+    add_parquet_row_index, parquet_row_index_read_columns, MicroPartition, MicroPartitionRef,
+};
 
 #[cfg(feature = "python")]
 pub mod python;
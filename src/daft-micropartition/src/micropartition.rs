@@ -15,8 +15,9 @@ use common_runtime::get_io_runtime;
 use common_scan_info::Pushdowns;
 use daft_core::prelude::*;
 use daft_csv::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
-use daft_dsl::ExprRef;
+use daft_dsl::{optimization::get_required_columns, ExprRef};
 use daft_io::{IOClient, IOConfig, IOStatsContext, IOStatsRef};
+use daft_ipc::IpcConvertOptions;
 use daft_json::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
 use daft_parquet::read::{
     read_parquet_bulk, read_parquet_metadata_bulk, ParquetSchemaInferenceOptions,
@@ -131,11 +132,29 @@ fn materialize_scan_task(
             coerce_int96_timestamp_unit,
             field_id_mapping,
             chunk_size,
+            row_index_column,
             ..
         }) => {
             let inference_options =
                 ParquetSchemaInferenceOptions::new(Some(*coerce_int96_timestamp_unit));
 
+            // Deletes and filters can only be applied once the row index is known, so the reader must see every row.
+            let (row_index_read_columns, filters) = match row_index_column {
+                Some(row_index_column) => (
+                    parquet_row_index_read_columns(
+                        file_column_names.as_deref(),
+                        row_index_column,
+                        scan_task.pushdowns.filters.as_ref(),
+                    ),
+                    None,
+                ),
+                None => (None, scan_task.pushdowns.filters.clone()),
+            };
+            let read_column_names = match &row_index_read_columns {
+                Some(columns) => Some(columns.iter().map(String::as_str).collect()),
+                None => file_column_names.clone(),
+            };
+
             // TODO: This is a hardcoded magic value but should be configurable
             let num_parallel_tasks = 8;
 
@@ -152,7 +171,7 @@ fn materialize_scan_task(
                 .into_iter()
                 .collect::<Vec<_>>();
 
-            let delete_map = read_delete_files(
+            let mut delete_map = read_delete_files(
                 iceberg_delete_files.as_slice(),
                 urls.as_slice(),
                 io_client.clone(),
@@ -162,6 +181,14 @@ fn materialize_scan_task(
                 &inference_options,
             )
             .context(DaftCoreComputeSnafu)?;
+            read_deletion_vectors(
+                scan_task.sources.as_slice(),
+                &mut delete_map,
+                io_client.clone(),
+                io_stats.clone(),
+                multithreaded_io,
+            )
+            .context(DaftCoreComputeSnafu)?;
 
             let row_groups = parquet_sources_to_row_groups(scan_task.sources.as_slice());
             let metadatas = scan_task
@@ -169,13 +196,28 @@ fn materialize_scan_task(
                 .iter()
                 .map(|s| s.get_parquet_metadata().cloned())
                 .collect::<Option<Vec<_>>>();
-            daft_parquet::read::read_parquet_bulk(
+            // With a row index, deletes are applied after reading, so the limit can only be pushed into the reader
+            // if nothing is filtered out afterwards.
+            let limit = match row_index_column {
+                Some(_)
+                    if scan_task.pushdowns.filters.is_some()
+                        || delete_map.values().any(|rows| !rows.is_empty()) =>
+                {
+                    None
+                }
+                _ => scan_task.pushdowns.limit,
+            };
+            let (delete_map, row_index_delete_map) = match row_index_column {
+                Some(_) => (None, Some(delete_map)),
+                None => (Some(delete_map), None),
+            };
+            let tables = daft_parquet::read::read_parquet_bulk(
                 urls.as_slice(),
-                file_column_names.as_deref(),
+                read_column_names.as_deref(),
                 None,
-                scan_task.pushdowns.limit,
+                limit,
                 row_groups,
-                scan_task.pushdowns.filters.clone(),
+                filters,
                 io_client,
                 io_stats,
                 num_parallel_tasks,
@@ -183,10 +225,31 @@ fn materialize_scan_task(
                 &inference_options,
                 field_id_mapping.clone(),
                 metadatas,
-                Some(delete_map),
+                delete_map,
                 *chunk_size,
             )
-            .context(DaftCoreComputeSnafu)?
+            .context(DaftCoreComputeSnafu)?;
+            match row_index_column {
+                Some(row_index_column) => tables
+                    .iter()
+                    .zip(urls.iter())
+                    .map(|(table, url)| {
+                        add_parquet_row_index(
+                            table,
+                            0,
+                            row_index_column,
+                            row_index_delete_map
+                                .as_ref()
+                                .and_then(|m| m.get(*url))
+                                .map(Vec::as_slice),
+                            scan_task.pushdowns.filters.as_ref(),
+                            file_column_names.as_deref(),
+                        )
+                    })
+                    .collect::<DaftResult<Vec<_>>>()
+                    .context(DaftCoreComputeSnafu)?,
+                None => tables,
+            }
         }
 
         // ****************
@@ -296,6 +359,30 @@ fn materialize_scan_task(
             )
             .context(DaftCoreComputeSnafu)?
         }
+
+        // ****************
+        // Native Arrow IPC Reads
+        // ****************
+        FileFormatConfig::ArrowIpc(_) => {
+            let convert_options = IpcConvertOptions {
+                limit: scan_task.pushdowns.limit,
+                include_columns: file_column_names
+                    .as_ref()
+                    .map(|cols| cols.iter().map(|col| (*col).to_string()).collect()),
+                schema: scan_task.schema.clone(),
+                predicate: scan_task.pushdowns.filters.clone(),
+            };
+            let uris = urls.collect::<Vec<_>>();
+            daft_ipc::read_ipc_bulk(
+                uris.as_slice(),
+                convert_options,
+                io_client,
+                io_stats,
+                scan_task.storage_config.multithreaded_io,
+                8,
+            )
+            .context(DaftCoreComputeSnafu)?
+        }
         #[cfg(feature = "python")]
         FileFormatConfig::Database(DatabaseSourceConfig { sql, conn }) => {
             let predicate = scan_task
@@ -405,6 +492,7 @@ impl MicroPartition {
             state: Mutex::new(TableState::Loaded(record_batches)),
             metadata: TableMetadata {
                 length: tables_len_sum,
+                ..Default::default()
             },
             statistics,
         }
@@ -427,6 +515,7 @@ impl MicroPartition {
                         .limit
                         .map(|limit| TableMetadata {
                             length: metadata.length.min(limit),
+                            num_distinct: metadata.num_distinct.clone(),
                         })
                         .unwrap_or_else(|| metadata.clone()),
                     statistics.clone(),
@@ -435,6 +524,7 @@ impl MicroPartition {
 
             // CASE: ScanTask does not provide metadata, but the file format supports metadata retrieval
             // We can perform an eager **metadata** read to create an unloaded MicroPartition
+            // Deletion vectors are only applied when the ScanTask itself is materialized, so those files are read eagerly.
             (
                 _,
                 _,
@@ -442,9 +532,14 @@ impl MicroPartition {
                     coerce_int96_timestamp_unit,
                     ref field_id_mapping,
                     chunk_size,
+                    row_index_column: None,
                     ..
                 }),
-            ) => {
+            ) if scan_task
+                .sources
+                .iter()
+                .all(|source| source.get_deletion_vector().is_none()) =>
+            {
                 let uris = scan_task
                     .sources
                     .iter()
@@ -819,6 +914,71 @@ fn get_file_column_names<'a>(
     }
 }
 
+/// Returns the columns to read from a Parquet file that is scanned with a row index column.
+///
+/// The scan's filters are only applied once the row index has been added (see
+/// [`add_parquet_row_index`]), so the columns they reference are read as well.
+pub fn parquet_row_index_read_columns(
+    file_column_names: Option<&[&str]>,
+    row_index_column: &str,
+    filters: Option<&ExprRef>,
+) -> Option<Vec<String>> {
+    file_column_names.map(|columns| {
+        let mut columns = columns
+            .iter()
+            .map(|col| (*col).to_string())
+            .collect::<Vec<_>>();
+        for col in filters.map(get_required_columns).unwrap_or_default() {
+            if !columns.contains(&col) {
+                columns.push(col);
+            }
+        }
+        columns.retain(|col| col != row_index_column);
+        columns
+    })
+}
+
+/// Adds `row_index_column` to a table holding the rows of a Parquet file from row `offset` onwards.
+///
+/// The file's deleted rows and the scan's filters are only applied once the index has been added, since the reader
+/// would otherwise skew the index by dropping rows. Columns that were only read for the filters (see
+/// [`parquet_row_index_read_columns`]) are then dropped, keeping only `file_column_names` if given.
+pub fn add_parquet_row_index(
+    table: &RecordBatch,
+    offset: u64,
+    row_index_column: &str,
+    delete_rows: Option<&[i64]>,
+    filters: Option<&ExprRef>,
+    file_column_names: Option<&[&str]>,
+) -> DaftResult<RecordBatch> {
+    let mut table = table.add_monotonically_increasing_id(0, offset, row_index_column)?;
+    if let Some(delete_rows) = delete_rows
+        && !delete_rows.is_empty()
+    {
+        let delete_rows = delete_rows
+            .iter()
+            .map(|row| *row as u64)
+            .collect::<HashSet<_>>();
+        let mask = BooleanArray::from_iter(
+            "mask",
+            (offset..offset + table.len() as u64).map(|row| Some(!delete_rows.contains(&row))),
+        );
+        table = table.mask_filter(&mask.into_series())?;
+    }
+    if let Some(filters) = filters {
+        table = table.filter(&[filters.clone()])?;
+    }
+    match file_column_names {
+        Some(columns) => table.get_columns(
+            &columns
+                .iter()
+                .filter(|col| table.schema.has_field(col))
+                .collect::<Vec<_>>(),
+        ),
+        None => Ok(table),
+    }
+}
+
 fn read_delete_files(
     delete_files: &[&str],
     uris: &[&str],
@@ -875,6 +1035,33 @@ fn read_delete_files(
     Ok(delete_map)
 }
 
+/// Adds the positions of the rows deleted by the Delta Lake deletion vectors of `sources` to `delete_map`.
+fn read_deletion_vectors(
+    sources: &[DataSource],
+    delete_map: &mut HashMap<String, Vec<i64>>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+) -> DaftResult<()> {
+    let deletion_vectors = sources
+        .iter()
+        .filter_map(|source| Some((source.get_path(), source.get_deletion_vector()?)))
+        .collect::<Vec<_>>();
+    if deletion_vectors.is_empty() {
+        return Ok(());
+    }
+    let runtime_handle = get_io_runtime(multithreaded_io);
+    let deleted_rows = runtime_handle.block_on_current_thread(futures::future::try_join_all(
+        deletion_vectors.iter().map(|(_, deletion_vector)| {
+            deletion_vector.read_deleted_rows(io_client.clone(), io_stats.clone())
+        }),
+    ))?;
+    for ((path, _), rows) in deletion_vectors.into_iter().zip(deleted_rows) {
+        delete_map.entry(path.to_string()).or_default().extend(rows);
+    }
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 fn read_parquet_into_loaded_micropartition<T: AsRef<str>>(
     io_client: Arc<IOClient>,
@@ -1035,19 +1222,10 @@ pub fn read_parquet_into_micropartition<T: AsRef<str>>(
             .collect::<DaftResult<Vec<_>>>()?;
         (metadata, schemas)
     } else {
-        let metadata = runtime_handle
-            .block_on_current_thread(async move {
-                read_parquet_metadata_bulk(
-                    uris,
-                    meta_io_client,
-                    meta_io_stats,
-                    meta_field_id_mapping,
-                )
+        let metadata = runtime_handle.block_on_current_thread(async move {
+            read_parquet_metadata_bulk(uris, meta_io_client, meta_io_stats, meta_field_id_mapping)
                 .await
-            })?
-            .into_iter()
-            .map(Arc::new)
-            .collect::<Vec<_>>();
+        })?;
 
         let schemas = metadata
             .iter()
@@ -1134,6 +1312,7 @@ pub fn read_parquet_into_micropartition<T: AsRef<str>>(
                     chunk_spec: rgs.map(ChunkSpec::Parquet),
                     size_bytes: Some(size_bytes),
                     iceberg_delete_files: None,
+                    deletion_vector: None,
                     metadata: None,
                     partition_spec: partition_spec.cloned(),
                     statistics: None,
@@ -1145,6 +1324,7 @@ pub fn read_parquet_into_micropartition<T: AsRef<str>>(
                 field_id_mapping,
                 row_groups,
                 chunk_size,
+                row_index_column: None,
             })
             .into(),
             scan_task_daft_schema,
@@ -1170,7 +1350,10 @@ pub fn read_parquet_into_micropartition<T: AsRef<str>>(
 
         Ok(MicroPartition::new_unloaded(
             Arc::new(scan_task),
-            TableMetadata { length: total_rows },
+            TableMetadata {
+                length: total_rows,
+                ..Default::default()
+            },
             casted_stats,
         ))
     } else {
@@ -1299,14 +1482,15 @@ mod tests {
 
     use common_error::DaftResult;
     use daft_core::{
-        datatypes::{DataType, Field, Int32Array},
+        datatypes::{DataType, Field, Int32Array, UInt64Array},
         prelude::Schema,
         series::IntoSeries,
     };
+    use daft_dsl::{lit, resolved_col};
     use daft_recordbatch::RecordBatch;
     use futures::StreamExt;
 
-    use crate::MicroPartition;
+    use crate::{add_parquet_row_index, MicroPartition};
 
     #[tokio::test]
     async fn test_mp_stream() -> DaftResult<()> {
@@ -1331,4 +1515,33 @@ mod tests {
         assert_eq!(tbl, table2);
         Ok(())
     }
+
+    #[test]
+    fn test_add_parquet_row_index() -> DaftResult<()> {
+        let table = RecordBatch::from_nonempty_columns(vec![
+            Int32Array::from_values("a", vec![10, 11, 12, 13].into_iter()).into_series(),
+            Int32Array::from_values("b", vec![20, 21, 22, 23].into_iter()).into_series(),
+        ])?;
+
+        // The rows are rows 4 to 7 of the file, and row 5 is deleted. The index must still count the deleted row,
+        // and `a` was only read for the filter, so it's dropped.
+        let table = add_parquet_row_index(
+            &table,
+            4,
+            "row",
+            Some([1, 5].as_slice()),
+            Some(&resolved_col("a").lt(lit(13))),
+            Some(["b", "row"].as_slice()),
+        )?;
+        assert_eq!(table.schema.names(), vec!["b", "row"]);
+        assert_eq!(
+            table.get_column("b")?,
+            &Int32Array::from_values("b", vec![20, 22].into_iter()).into_series()
+        );
+        assert_eq!(
+            table.get_column("row")?,
+            &UInt64Array::from_values("row", vec![4, 6].into_iter()).into_series()
+        );
+        Ok(())
+    }
 }
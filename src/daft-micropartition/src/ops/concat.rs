@@ -61,7 +61,10 @@ impl MicroPartition {
         Ok(Self {
             schema: first_schema.clone(),
             state: Mutex::new(TableState::Loaded(all_tables.into())),
-            metadata: TableMetadata { length: new_len },
+            metadata: TableMetadata {
+                length: new_len,
+                ..Default::default()
+            },
             statistics: all_stats,
         })
     }
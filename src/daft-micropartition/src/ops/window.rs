@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_dsl::{ExprRef, NullStrategy};
+use daft_io::IOStatsContext;
+use daft_recordbatch::RecordBatch;
+
+use crate::micropartition::MicroPartition;
+
+impl MicroPartition {
+    /// Appends the results of window functions to the rows of this partition, which must already be sorted by the
+    /// `partition_by` and then the `order_by` expressions of their window.
+    pub fn window(
+        &self,
+        window_functions: &[ExprRef],
+        partition_by: &[ExprRef],
+        order_by: &[ExprRef],
+        null_strategy: NullStrategy,
+    ) -> DaftResult<Self> {
+        let io_stats = IOStatsContext::new("MicroPartition::window");
+
+        let tables = self.concat_or_get(io_stats)?;
+        match tables.as_slice() {
+            [] => {
+                let empty_table = RecordBatch::empty(Some(self.schema.clone()))?;
+                let windowed =
+                    empty_table.window(window_functions, partition_by, order_by, null_strategy)?;
+                Ok(Self::empty(Some(windowed.schema)))
+            }
+            [single] => {
+                let windowed =
+                    single.window(window_functions, partition_by, order_by, null_strategy)?;
+                Ok(Self::new_loaded(
+                    windowed.schema.clone(),
+                    Arc::new(vec![windowed]),
+                    None,
+                ))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -207,6 +207,38 @@ impl PyMicroPartition {
         })
     }
 
+    pub fn window(
+        &self,
+        py: Python,
+        window_functions: Vec<PyExpr>,
+        partition_by: Vec<PyExpr>,
+        order_by: Vec<PyExpr>,
+        null_strategy: &str,
+    ) -> PyResult<Self> {
+        let null_strategy = null_strategy.parse::<daft_dsl::NullStrategy>()?;
+        let window_functions: Vec<daft_dsl::ExprRef> = window_functions
+            .into_iter()
+            .map(std::convert::Into::into)
+            .collect();
+        let partition_by: Vec<daft_dsl::ExprRef> = partition_by
+            .into_iter()
+            .map(std::convert::Into::into)
+            .collect();
+        let order_by: Vec<daft_dsl::ExprRef> =
+            order_by.into_iter().map(std::convert::Into::into).collect();
+        py.allow_threads(|| {
+            Ok(self
+                .inner
+                .window(
+                    window_functions.as_slice(),
+                    partition_by.as_slice(),
+                    order_by.as_slice(),
+                    null_strategy,
+                )?
+                .into())
+        })
+    }
+
     pub fn argsort(
         &self,
         py: Python,
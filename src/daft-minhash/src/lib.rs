@@ -305,20 +305,93 @@ pub fn minhash_in(
     hasher: &impl BuildHasher,
     alloc: &mut VecDeque<isize>,
 ) -> DaftResult<Vec<u32>> {
-    let (perm_a_simd, perm_b_simd) = perm_simd;
-    let num_simd_vectors = num_hashes.div_ceil(SIMD_LANES);
+    let hashes = s
+        .windowed_words_in(word_ngram_size, alloc)
+        .map(|w| hash_ngram(w.as_bytes(), hasher));
 
-    let mut min_hash_values: Vec<SimdU64> = vec![MAX_HASH_SIMD; num_simd_vectors];
+    Ok(minhash_of_hashes(hashes, perm_simd, num_hashes))
+}
+
+/// Computes the MinHash signature of a list of tokens using SIMD operations.
+///
+/// The n-grams of the tokens are joined by single spaces before hashing, so a list of tokens
+/// without spaces has the same signature as the string of the tokens joined by spaces. Like for
+/// strings, fewer tokens than the n-gram size form a single n-gram.
+pub fn minhash_tokens_in(
+    tokens: &[&str],
+    perm_simd: (&[SimdU64], &[SimdU64]),
+    num_hashes: usize,
+    word_ngram_size: usize,
+    hasher: &impl BuildHasher,
+    alloc: &mut String,
+) -> DaftResult<Vec<u32>> {
+    let hashes = tokens
+        .windows(word_ngram_size.min(tokens.len()).max(1))
+        .map(|ngram| {
+            alloc.clear();
+            for (i, token) in ngram.iter().enumerate() {
+                if i > 0 {
+                    alloc.push(' ');
+                }
+                alloc.push_str(token);
+            }
+            hash_ngram(alloc.as_bytes(), hasher)
+        });
 
-    let hashes = s.windowed_words_in(word_ngram_size, alloc).map(|w| {
+    Ok(minhash_of_hashes(hashes, perm_simd, num_hashes))
+}
+
+/// Computes the 64-bit SimHash of a string from the hashes of its word n-grams.
+///
+/// Each bit of the SimHash is set if most of the n-gram hashes have it set, so strings that share
+/// most of their n-grams have SimHashes that differ in only a few bits.
+pub fn simhash_in(
+    s: &str,
+    word_ngram_size: usize,
+    hasher: &impl BuildHasher,
+    alloc: &mut VecDeque<isize>,
+) -> u64 {
+    let mut bit_counts = [0i64; u64::BITS as usize];
+    for ngram in s.windowed_words_in(word_ngram_size, alloc) {
         let mut h = hasher.build_hasher();
-        h.write(w.as_bytes());
+        h.write(ngram.as_bytes());
+        let hash = h.finish();
+        for (bit, count) in bit_counts.iter_mut().enumerate() {
+            if (hash >> bit) & 1 == 1 {
+                *count += 1;
+            } else {
+                *count -= 1;
+            }
+        }
+    }
+    bit_counts
+        .iter()
+        .enumerate()
+        .filter(|(_, &count)| count > 0)
+        .fold(0, |simhash, (bit, _)| simhash | (1 << bit))
+}
+
+/// Hashes an n-gram to the 32-bit value that its permutations are computed from.
+#[inline(always)]
+fn hash_ngram(ngram: &[u8], hasher: &impl BuildHasher) -> u64 {
+    let mut h = hasher.build_hasher();
+    h.write(ngram);
 
-        let (&le, _) = h.finish().to_le_bytes().split_array_ref::<4>();
-        let result = u32::from_le_bytes(le);
+    let (&le, _) = h.finish().to_le_bytes().split_array_ref::<4>();
+    let result = u32::from_le_bytes(le);
 
-        u64::from(result)
-    });
+    u64::from(result)
+}
+
+fn minhash_of_hashes(
+    hashes: impl Iterator<Item = u64>,
+    perm_simd: (&[SimdU64], &[SimdU64]),
+    num_hashes: usize,
+) -> Vec<u32> {
+    let (perm_a_simd, perm_b_simd) = perm_simd;
+    let num_simd_vectors = num_hashes.div_ceil(SIMD_LANES);
+
+    let mut min_hash_values: Vec<SimdU64> = vec![MAX_HASH_SIMD; num_simd_vectors];
 
     let mut chunks = hashes.array_chunks::<SIMD_LANES>();
 
@@ -334,14 +407,12 @@ pub fn minhash_in(
     }
 
     // Convert SIMD results to a flat vector of u32 values
-    let minhash_signature: Vec<u32> = min_hash_values
+    min_hash_values
         .iter()
         .flat_map(Simd::as_array)
         .take(num_hashes)
         .map(|&x| x as u32)
-        .collect();
-
-    Ok(minhash_signature)
+        .collect()
 }
 
 // cargo bench --package daft-minhash
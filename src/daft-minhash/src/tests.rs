@@ -464,6 +464,65 @@ proptest! {
     }
 }
 
+#[test]
+fn test_minhash_tokens_matches_joined_string() {
+    let (perm_a_simd, perm_b_simd) = load_permutations(16, 16);
+    let hasher = Xxh64Builder::new(XX_HASH_SEED);
+    let mut alloc = String::new();
+
+    for (text, ngram_size) in [
+        ("the quick brown fox jumped over the lazy dog", 3),
+        ("the quick brown fox", 1),
+        ("too short", 5),
+    ] {
+        let tokens = text.split(' ').collect::<Vec<_>>();
+        let from_tokens = minhash_tokens_in(
+            &tokens,
+            (&perm_a_simd, &perm_b_simd),
+            16,
+            ngram_size,
+            &hasher,
+            &mut alloc,
+        )
+        .unwrap();
+        let from_text =
+            minhash(text, (&perm_a_simd, &perm_b_simd), 16, ngram_size, &hasher).unwrap();
+        assert_eq!(from_tokens, from_text);
+    }
+
+    let empty = minhash_tokens_in(
+        &[],
+        (&perm_a_simd, &perm_b_simd),
+        16,
+        3,
+        &hasher,
+        &mut alloc,
+    )
+    .unwrap();
+    assert_eq!(
+        empty,
+        minhash("", (&perm_a_simd, &perm_b_simd), 16, 3, &hasher).unwrap()
+    );
+}
+
+#[test]
+fn test_simhash() {
+    let hasher = Xxh64Builder::new(XX_HASH_SEED);
+    let mut alloc = VecDeque::new();
+    let mut simhash = |text: &str| simhash_in(text, 2, &hasher, &mut alloc);
+
+    let original = simhash("the quick brown fox jumped over the lazy dog and ran far away");
+    let similar = simhash("the quick brown fox jumped over the lazy dog and ran far off");
+    let different = simhash("a completely unrelated sentence about something else entirely");
+
+    assert_eq!(
+        original,
+        simhash("the quick brown fox jumped over the lazy dog and ran far away")
+    );
+    assert!((original ^ similar).count_ones() < (original ^ different).count_ones());
+    assert_eq!(simhash(""), 0);
+}
+
 fn generate_permutations(seed: u64, num_hashes: usize) -> (Vec<u64>, Vec<u64>) {
     let mut rng = Rng::with_seed(seed);
     let perm_a = repeat_with(|| rng.u64(1..(i32::MAX as u64)))
@@ -0,0 +1,319 @@
+//! Prunes row groups using the bloom filters of their column chunks.
+//!
+//! Bloom filters only answer whether a value may be in a column chunk, so they are used for predicates that are a
+//! conjunction containing equality or `is_in` comparisons between a column and literals. A row group is skipped if, for
+//! any such comparison, none of the literals may be in the column chunk.
+
+use std::{
+    collections::HashMap,
+    io::{Read, Seek},
+    sync::Arc,
+};
+
+use daft_dsl::{Column, Expr, ExprRef, LiteralValue, Operator, ResolvedColumn};
+use daft_io::{IOClient, IOStatsRef};
+use futures::future::try_join_all;
+use parquet2::{
+    bloom_filter::{hash_byte, hash_native, is_in_set, read, read_header},
+    metadata::{ColumnChunkMetaData, FileMetaData, RowGroupMetaData},
+    schema::types::PhysicalType,
+};
+use snafu::ResultExt;
+
+use crate::{file::RowGroupRange, UnableToReadBloomFilterSnafu};
+
+/// The number of bytes fetched at the start of a remote bloom filter, which is enough for its header.
+const BLOOM_FILTER_HEADER_PREFETCH: usize = 64;
+
+/// Returns, for every column that `predicate` requires to be equal to one of a set of literals, those literals.
+fn equality_literals(predicate: &ExprRef) -> HashMap<String, Vec<LiteralValue>> {
+    fn column_name(expr: &Expr) -> Option<&str> {
+        match expr {
+            Expr::Column(Column::Resolved(ResolvedColumn::Basic(name))) => Some(name),
+            _ => None,
+        }
+    }
+
+    fn visit(expr: &Expr, literals: &mut HashMap<String, Vec<LiteralValue>>) {
+        match expr {
+            Expr::BinaryOp {
+                op: Operator::And,
+                left,
+                right,
+            } => {
+                visit(left, literals);
+                visit(right, literals);
+            }
+            Expr::BinaryOp {
+                op: Operator::Eq,
+                left,
+                right,
+            } => {
+                let pair = match (column_name(left), right.as_literal()) {
+                    (Some(name), Some(value)) => Some((name, value)),
+                    _ => column_name(right).zip(left.as_literal()),
+                };
+                if let Some((name, value)) = pair {
+                    literals.insert(name.to_string(), vec![value.clone()]);
+                }
+            }
+            Expr::IsIn(child, items) => {
+                if let Some(name) = column_name(child)
+                    && let Some(values) = items
+                        .iter()
+                        .map(|item| item.as_literal().cloned())
+                        .collect::<Option<Vec<_>>>()
+                {
+                    literals.insert(name.to_string(), values);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut literals = HashMap::new();
+    visit(predicate, &mut literals);
+    literals
+}
+
+/// Hashes `value` the way it's hashed when inserted into the bloom filter of a column of `physical_type`, or returns
+/// None if its representation in such a column isn't known.
+fn hash_literal(value: &LiteralValue, physical_type: PhysicalType) -> Option<u64> {
+    let integer = match value {
+        LiteralValue::Int8(v) => Some(i128::from(*v)),
+        LiteralValue::UInt8(v) => Some(i128::from(*v)),
+        LiteralValue::Int16(v) => Some(i128::from(*v)),
+        LiteralValue::UInt16(v) => Some(i128::from(*v)),
+        LiteralValue::Int32(v) => Some(i128::from(*v)),
+        LiteralValue::UInt32(v) => Some(i128::from(*v)),
+        LiteralValue::Int64(v) => Some(i128::from(*v)),
+        LiteralValue::UInt64(v) => Some(i128::from(*v)),
+        _ => None,
+    };
+    match (physical_type, value) {
+        (PhysicalType::Int32, _) => integer.and_then(|v| i32::try_from(v).ok()).map(hash_native),
+        (PhysicalType::Int64, _) => integer.and_then(|v| i64::try_from(v).ok()).map(hash_native),
+        (PhysicalType::ByteArray, LiteralValue::Utf8(v)) => Some(hash_byte(v)),
+        (PhysicalType::ByteArray, LiteralValue::Binary(v)) => Some(hash_byte(v)),
+        _ => None,
+    }
+}
+
+/// Returns whether any of `values` may be in the column chunk whose bloom filter is `bitset`.
+fn bitset_may_contain(bitset: &[u8], physical_type: PhysicalType, values: &[LiteralValue]) -> bool {
+    values.iter().any(|value| {
+        if matches!(value, LiteralValue::Null) {
+            // Nulls are never equal to anything, nor are they part of the bloom filter.
+            return false;
+        }
+        hash_literal(value, physical_type).is_none_or(|hash| is_in_set(bitset, hash))
+    })
+}
+
+/// Returns the column chunks of `rg` that have a bloom filter which can be probed for the literals of a column,
+/// along with those literals.
+fn prunable_column_chunks<'a>(
+    rg: &'a RowGroupMetaData,
+    literals: &'a HashMap<String, Vec<LiteralValue>>,
+) -> impl Iterator<Item = (&'a ColumnChunkMetaData, &'a [LiteralValue])> {
+    rg.columns().iter().filter_map(|column| {
+        let [name] = column.descriptor().path_in_schema.as_slice() else {
+            return None;
+        };
+        let values = literals.get(name)?;
+        let physical_type = column.physical_type();
+        let can_hash = values.iter().all(|value| {
+            matches!(value, LiteralValue::Null) || hash_literal(value, physical_type).is_some()
+        });
+        (column.metadata().bloom_filter_offset.is_some() && can_hash)
+            .then_some((column, values.as_slice()))
+    })
+}
+
+/// Drops the row groups of `row_ranges` whose bloom filters show that they have no rows matching `predicate`, reading
+/// the bloom filters from a local file.
+pub(crate) fn prune_row_groups_with_bloom_filters<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetaData,
+    row_ranges: Vec<RowGroupRange>,
+    predicate: &ExprRef,
+    uri: &str,
+) -> super::Result<Vec<RowGroupRange>> {
+    let literals = equality_literals(predicate);
+    if literals.is_empty() {
+        return Ok(row_ranges);
+    }
+
+    let mut bitset = vec![];
+    let mut pruned = Vec::with_capacity(row_ranges.len());
+    'row_groups: for rg_range in row_ranges {
+        let rg = metadata.row_groups.get(&rg_range.row_group_index).unwrap();
+        for (column, values) in prunable_column_chunks(rg, &literals) {
+            read(column, reader, &mut bitset).context(UnableToReadBloomFilterSnafu {
+                path: uri.to_string(),
+            })?;
+            if !bitset.is_empty() && !bitset_may_contain(&bitset, column.physical_type(), values) {
+                continue 'row_groups;
+            }
+        }
+        pruned.push(rg_range);
+    }
+    Ok(pruned)
+}
+
+/// Reads the bitset of the bloom filter of `column` from a remote file, or returns an empty bitset if the filter isn't
+/// supported.
+async fn read_bloom_filter_async(
+    column: &ColumnChunkMetaData,
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> super::Result<Vec<u8>> {
+    let offset = column.metadata().bloom_filter_offset.unwrap() as usize;
+    let header = io_client
+        .single_url_get(
+            uri.into(),
+            Some(offset..offset + BLOOM_FILTER_HEADER_PREFETCH),
+            io_stats.clone(),
+        )
+        .await?
+        .bytes()
+        .await?;
+    let Some((header_length, num_bytes)) =
+        read_header(&header).context(UnableToReadBloomFilterSnafu {
+            path: uri.to_string(),
+        })?
+    else {
+        return Ok(vec![]);
+    };
+
+    let start = offset + header_length;
+    let bitset = io_client
+        .single_url_get(uri.into(), Some(start..start + num_bytes), io_stats)
+        .await?
+        .bytes()
+        .await?;
+    Ok(bitset.to_vec())
+}
+
+/// Drops the row groups of `row_ranges` whose bloom filters show that they have no rows matching `predicate`, fetching
+/// the bloom filters of all row groups concurrently.
+pub(crate) async fn prune_row_groups_with_bloom_filters_async(
+    metadata: &FileMetaData,
+    row_ranges: Vec<RowGroupRange>,
+    predicate: &ExprRef,
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> super::Result<Vec<RowGroupRange>> {
+    let literals = equality_literals(predicate);
+    if literals.is_empty() {
+        return Ok(row_ranges);
+    }
+
+    let may_match = try_join_all(row_ranges.iter().map(|rg_range| {
+        let rg = metadata.row_groups.get(&rg_range.row_group_index).unwrap();
+        let literals = &literals;
+        let io_client = io_client.clone();
+        let io_stats = io_stats.clone();
+        async move {
+            for (column, values) in prunable_column_chunks(rg, literals) {
+                let bitset =
+                    read_bloom_filter_async(column, uri, io_client.clone(), io_stats.clone())
+                        .await?;
+                if !bitset.is_empty()
+                    && !bitset_may_contain(&bitset, column.physical_type(), values)
+                {
+                    return super::Result::Ok(false);
+                }
+            }
+            Ok(true)
+        }
+    }))
+    .await?;
+
+    Ok(row_ranges
+        .into_iter()
+        .zip(may_match)
+        .filter_map(|(rg_range, may_match)| may_match.then_some(rg_range))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_dsl::{lit, resolved_col, LiteralValue};
+    use parquet2::{
+        bloom_filter::{hash_byte, hash_native, insert},
+        schema::types::PhysicalType,
+    };
+
+    use super::{bitset_may_contain, equality_literals};
+
+    #[test]
+    fn test_equality_literals() {
+        let predicate = resolved_col("a")
+            .eq(lit(1i64))
+            .and(lit("x").eq(resolved_col("b")))
+            .and(resolved_col("c").gt(lit(3i64)))
+            .and(resolved_col("d").is_in(vec![lit(4i64), lit(5i64)]));
+        let literals = equality_literals(&predicate);
+
+        assert_eq!(literals.len(), 3);
+        assert_eq!(literals["a"], vec![LiteralValue::Int64(1)]);
+        assert_eq!(literals["b"], vec![LiteralValue::Utf8("x".to_string())]);
+        assert_eq!(
+            literals["d"],
+            vec![LiteralValue::Int64(4), LiteralValue::Int64(5)]
+        );
+
+        // Disjunctions can't prune on either side.
+        let predicate = resolved_col("a")
+            .eq(lit(1i64))
+            .or(resolved_col("b").eq(lit(2i64)));
+        assert!(equality_literals(&predicate).is_empty());
+    }
+
+    #[test]
+    fn test_bitset_may_contain() {
+        let mut bitset = vec![0; 1024];
+        for i in 0..100i64 {
+            insert(&mut bitset, hash_native(i));
+        }
+        assert!(bitset_may_contain(
+            &bitset,
+            PhysicalType::Int64,
+            &[LiteralValue::Int64(42)]
+        ));
+        // Literals of narrower types are widened to the physical type of the column.
+        assert!(bitset_may_contain(
+            &bitset,
+            PhysicalType::Int64,
+            &[LiteralValue::Int32(42)]
+        ));
+        assert!(!bitset_may_contain(
+            &bitset,
+            PhysicalType::Int64,
+            &[LiteralValue::Int64(1_000_000), LiteralValue::Null]
+        ));
+        assert!(bitset_may_contain(
+            &bitset,
+            PhysicalType::Int64,
+            &[LiteralValue::Int64(1_000_000), LiteralValue::Int64(7)]
+        ));
+
+        let mut bitset = vec![0; 1024];
+        for i in 0..100 {
+            insert(&mut bitset, hash_byte(format!("value_{i}")));
+        }
+        assert!(bitset_may_contain(
+            &bitset,
+            PhysicalType::ByteArray,
+            &[LiteralValue::Utf8("value_7".to_string())]
+        ));
+        assert!(!bitset_may_contain(
+            &bitset,
+            PhysicalType::ByteArray,
+            &[LiteralValue::Utf8("missing".to_string())]
+        ));
+    }
+}
@@ -4,7 +4,10 @@ use std::{
     sync::Arc,
 };
 
-use arrow2::io::parquet::read::{column_iter_to_arrays, schema::infer_schema_with_options};
+use arrow2::io::parquet::read::{
+    column_iter_to_arrays, get_pruned_field_columns, is_field_column,
+    schema::infer_schema_with_options,
+};
 use common_error::DaftResult;
 use common_runtime::get_compute_runtime;
 use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
@@ -22,13 +25,14 @@ use snafu::ResultExt;
 use tokio_stream::wrappers::ReceiverStream;
 
 use crate::{
+    bloom_filter::prune_row_groups_with_bloom_filters_async,
     determine_parquet_parallelism,
-    metadata::read_parquet_metadata,
+    metadata::read_parquet_metadata_cached,
     read::ParquetSchemaInferenceOptions,
     read_planner::{CoalescePass, RangesContainer, ReadPlanner, SplitLargeRequestPass},
     statistics,
     stream_reader::spawn_column_iters_to_table_task,
-    utils::combine_stream,
+    utils::{combine_stream, prune_struct_fields},
     JoinSnafu, OneShotRecvSnafu, UnableToConvertRowGroupMetadataToStatsSnafu,
     UnableToConvertSchemaToDaftSnafu, UnableToCreateParquetPageStreamSnafu,
     UnableToParseSchemaFromMetadataSnafu, UnableToRunExpressionOnStatsSnafu, PARQUET_MORSEL_SIZE,
@@ -36,8 +40,9 @@ use crate::{
 
 pub struct ParquetReaderBuilder {
     pub uri: String,
-    pub metadata: parquet2::metadata::FileMetaData,
+    pub metadata: Arc<parquet2::metadata::FileMetaData>,
     selected_columns: Option<HashSet<String>>,
+    struct_fields: Option<Vec<Vec<String>>>,
     row_start_offset: usize,
     limit: Option<usize>,
     row_groups: Option<Vec<i64>>,
@@ -205,16 +210,17 @@ impl ParquetReaderBuilder {
         io_stats: Option<IOStatsRef>,
         field_id_mapping: Option<Arc<BTreeMap<i32, Field>>>,
     ) -> super::Result<Self> {
-        // TODO(sammy): We actually don't need this since we can do negative offsets when reading the metadata
-        let size = io_client
-            .single_url_get_size(uri.into(), io_stats.clone())
+        // TODO(sammy): We actually don't need the size since we can do negative offsets when reading the metadata
+        let head = io_client
+            .single_url_head(uri.into(), io_stats.clone())
             .await?;
         let metadata =
-            read_parquet_metadata(uri, size, io_client, io_stats, field_id_mapping).await?;
+            read_parquet_metadata_cached(uri, &head, io_client, io_stats, field_id_mapping).await?;
         Ok(Self {
             uri: uri.into(),
             metadata,
             selected_columns: None,
+            struct_fields: None,
             row_start_offset: 0,
             limit: None,
             row_groups: None,
@@ -224,7 +230,7 @@ impl ParquetReaderBuilder {
         })
     }
 
-    pub fn metadata(&self) -> &parquet2::metadata::FileMetaData {
+    pub fn metadata(&self) -> &Arc<parquet2::metadata::FileMetaData> {
         &self.metadata
     }
 
@@ -233,6 +239,12 @@ impl ParquetReaderBuilder {
         Ok(self)
     }
 
+    /// Only reads the given fields of struct columns, see [`crate::utils::prune_struct_fields`].
+    pub fn prune_struct_fields(mut self, struct_fields: &[Vec<String>]) -> Self {
+        self.struct_fields = Some(struct_fields.to_vec());
+        self
+    }
+
     pub fn limit(
         mut self,
         start_offset: Option<usize>,
@@ -278,6 +290,9 @@ impl ParquetReaderBuilder {
                 .fields
                 .retain(|f| names_to_keep.contains(f.name.as_str()));
         }
+        if let Some(struct_fields) = &self.struct_fields {
+            arrow_schema = prune_struct_fields(arrow_schema, struct_fields);
+        }
 
         let daft_schema =
             Schema::try_from(&arrow_schema).with_context(|_| UnableToConvertSchemaToDaftSnafu {
@@ -326,14 +341,14 @@ impl ParquetFileReader {
 
     fn new(
         uri: String,
-        metadata: parquet2::metadata::FileMetaData,
+        metadata: Arc<parquet2::metadata::FileMetaData>,
         arrow_schema: arrow2::datatypes::Schema,
         row_ranges: Vec<RowGroupRange>,
         chunk_size: Option<usize>,
     ) -> super::Result<Self> {
         Ok(Self {
             uri,
-            metadata: Arc::new(metadata),
+            metadata,
             arrow_schema: arrow_schema.into(),
             row_ranges: Arc::new(row_ranges),
             chunk_size,
@@ -344,6 +359,26 @@ impl ParquetFileReader {
         &self.arrow_schema
     }
 
+    /// Drops the row groups that the bloom filters of their column chunks show to have no rows matching `predicate`.
+    pub async fn prune_row_groups_with_bloom_filters(
+        mut self,
+        predicate: &ExprRef,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Self> {
+        let row_ranges = prune_row_groups_with_bloom_filters_async(
+            &self.metadata,
+            self.row_ranges.as_ref().clone(),
+            predicate,
+            &self.uri,
+            io_client,
+            io_stats,
+        )
+        .await?;
+        self.row_ranges = Arc::new(row_ranges);
+        Ok(self)
+    }
+
     fn naive_read_plan(&self) -> super::Result<ReadPlanner> {
         let arrow_fields = &self.arrow_schema.fields;
 
@@ -358,11 +393,7 @@ impl ParquetFileReader {
 
             let columns = rg.columns();
             for field in arrow_fields {
-                let field_name = field.name.clone();
-                let filtered_cols = columns
-                    .iter()
-                    .filter(|x| x.descriptor().path_in_schema[0] == field_name)
-                    .collect::<Vec<_>>();
+                let filtered_cols = get_pruned_field_columns(columns, field);
 
                 for col in filtered_cols {
                     let (start, len) = col.byte_range();
@@ -443,11 +474,7 @@ impl ParquetFileReader {
                                 .get(&row_range.row_group_index)
                                 .expect("Row Group index should be in bounds");
                             let num_rows = rg.num_rows().min(row_range.start + row_range.num_rows);
-                            let filtered_columns = rg
-                                .columns()
-                                .iter()
-                                .filter(|x| x.descriptor().path_in_schema[0] == field.name)
-                                .collect::<Vec<_>>();
+                            let filtered_columns = get_pruned_field_columns(rg.columns(), &field);
                             let mut decompressed_iters = Vec::with_capacity(filtered_columns.len());
                             let mut ptypes = Vec::with_capacity(filtered_columns.len());
                             let mut num_values = Vec::with_capacity(filtered_columns.len());
@@ -579,11 +606,12 @@ impl ParquetFileReader {
                         let num_rows = rg.num_rows().min(row_range.start + row_range.num_rows);
                         let chunk_size = self.chunk_size.unwrap_or(Self::DEFAULT_CHUNK_SIZE);
                         let columns = rg.columns();
-                        let field_name = &field.name;
                         let filtered_cols_idx = columns
                             .iter()
                             .enumerate()
-                            .filter(|(_, x)| &x.descriptor().path_in_schema[0] == field_name)
+                            .filter(|(_, x)| {
+                                is_field_column(&x.descriptor().path_in_schema, &field)
+                            })
                             .map(|(i, _)| i)
                             .collect::<Vec<_>>();
 
@@ -763,11 +791,12 @@ impl ParquetFileReader {
                         let num_rows = rg.num_rows().min(row_range.start + row_range.num_rows);
                         let chunk_size = self.chunk_size.unwrap_or(PARQUET_MORSEL_SIZE);
                         let columns = rg.columns();
-                        let field_name = &field.name;
                         let filtered_cols_idx = columns
                             .iter()
                             .enumerate()
-                            .filter(|(_, x)| &x.descriptor().path_in_schema[0] == field_name)
+                            .filter(|(_, x)| {
+                                is_field_column(&x.descriptor().path_in_schema, &field)
+                            })
                             .map(|(i, _)| i)
                             .collect::<Vec<_>>();
 
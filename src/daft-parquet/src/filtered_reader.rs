@@ -0,0 +1,382 @@
+//! Reads row groups of local Parquet files by evaluating a predicate before decoding all of their columns.
+//!
+//! The columns that the predicate references are decoded and evaluated first. The resulting row selection is then
+//! used to skip the remaining columns of row groups without matches entirely, and, if the file has a page index, to
+//! only decode the pages and rows of the remaining columns that were selected.
+
+use std::{
+    collections::HashSet,
+    io::{Read, Seek},
+};
+
+use arrow2::{
+    datatypes::{DataType as ArrowDataType, Field},
+    io::parquet::read::{self, is_field_column},
+};
+use common_error::DaftResult;
+use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
+use daft_dsl::{optimization::get_required_columns, ExprRef};
+use daft_recordbatch::RecordBatch;
+use parquet2::{
+    indexes::{select_pages, FilteredPage, Interval},
+    metadata::RowGroupMetaData,
+};
+use snafu::ResultExt;
+
+/// Returns the columns of `schema` that `predicate` references, or None if the predicate can't be evaluated on the
+/// decoded columns of a row group on its own, e.g. because it doesn't reference any columns.
+pub(crate) fn predicate_columns(
+    predicate: &ExprRef,
+    schema: &arrow2::datatypes::Schema,
+) -> Option<HashSet<String>> {
+    let required = get_required_columns(predicate)
+        .into_iter()
+        .collect::<HashSet<_>>();
+    let available = schema
+        .fields
+        .iter()
+        .filter(|f| required.contains(&f.name))
+        .count();
+    (!required.is_empty() && available == required.len()).then_some(required)
+}
+
+/// Reads a whole row group, only returning the rows that match `predicate`.
+///
+/// `predicate_columns` must be the result of [`predicate_columns`] for `predicate` and `schema`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn read_row_group_filtered<R: Read + Seek>(
+    reader: &mut R,
+    rg_metadata: &RowGroupMetaData,
+    schema: &arrow2::datatypes::Schema,
+    daft_schema: &SchemaRef,
+    predicate: &ExprRef,
+    predicate_columns: &HashSet<String>,
+    chunk_size: usize,
+    uri: &str,
+) -> DaftResult<RecordBatch> {
+    let num_rows = rg_metadata.num_rows();
+    let (predicate_fields, other_fields): (Vec<_>, Vec<_>) = schema
+        .fields
+        .iter()
+        .cloned()
+        .partition(|f| predicate_columns.contains(&f.name));
+
+    let predicate_series = decode_fields(
+        reader,
+        rg_metadata,
+        predicate_fields,
+        daft_schema,
+        chunk_size,
+        num_rows,
+        None,
+        uri,
+    )?;
+    let predicate_table = RecordBatch::from_nonempty_columns(predicate_series)?;
+    let mask = predicate_table
+        .eval_expression_list(&[predicate.clone()])?
+        .get_column_by_index(0)?
+        .clone();
+    let predicate_table = predicate_table.mask_filter(&mask)?;
+
+    let intervals = selected_intervals(mask.bool()?);
+    let num_selected = intervals.iter().map(|i| i.length).sum::<usize>();
+
+    let other_series = if num_selected == 0 {
+        other_fields
+            .iter()
+            .map(|f| empty_series(f, daft_schema))
+            .collect::<DaftResult<Vec<_>>>()?
+    } else if num_selected == num_rows {
+        decode_fields(
+            reader,
+            rg_metadata,
+            other_fields,
+            daft_schema,
+            chunk_size,
+            num_rows,
+            None,
+            uri,
+        )?
+    } else if let Some(pages) = select_field_pages(reader, rg_metadata, &other_fields, &intervals)
+        .with_context(|_| super::UnableToReadParquetRowGroupSnafu {
+        path: uri.to_string(),
+    })? {
+        decode_fields(
+            reader,
+            rg_metadata,
+            other_fields,
+            daft_schema,
+            chunk_size,
+            num_selected,
+            Some(pages),
+            uri,
+        )?
+    } else {
+        // Without a page index every page has to be decoded, but the rows that didn't match are still dropped before
+        // the row group is assembled.
+        decode_fields(
+            reader,
+            rg_metadata,
+            other_fields,
+            daft_schema,
+            chunk_size,
+            num_rows,
+            None,
+            uri,
+        )?
+        .iter()
+        .map(|s| s.filter(mask.bool()?))
+        .collect::<DaftResult<Vec<_>>>()?
+    };
+
+    let columns = schema
+        .fields
+        .iter()
+        .map(|f| match predicate_table.get_column(&f.name) {
+            Ok(series) => Ok(series.clone()),
+            Err(_) => other_series
+                .iter()
+                .find(|s| s.name() == f.name)
+                .cloned()
+                .ok_or_else(|| {
+                    common_error::DaftError::InternalError(format!(
+                        "Column {} was not read from {uri}",
+                        f.name
+                    ))
+                }),
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    RecordBatch::new_with_size(
+        Schema::new(columns.iter().map(|s| s.field().clone()).collect())?,
+        columns,
+        num_selected,
+    )
+}
+
+/// Decodes `fields` from a row group into one Series each.
+#[allow(clippy::too_many_arguments)]
+fn decode_fields<R: Read + Seek>(
+    reader: &mut R,
+    rg_metadata: &RowGroupMetaData,
+    fields: Vec<Field>,
+    daft_schema: &SchemaRef,
+    chunk_size: usize,
+    num_rows: usize,
+    pages: Option<Vec<Vec<Vec<FilteredPage>>>>,
+    uri: &str,
+) -> DaftResult<Vec<Series>> {
+    // Decoding selected rows of pages in more than one chunk drops rows at chunk boundaries, so those are always
+    // decoded at once.
+    let chunk_size = pages.is_none().then_some(chunk_size);
+    let column_iters = read::read_columns_many(
+        reader,
+        rg_metadata,
+        fields.clone(),
+        chunk_size,
+        Some(num_rows),
+        pages,
+    )
+    .with_context(|_| super::UnableToReadParquetRowGroupSnafu {
+        path: uri.to_string(),
+    })?;
+
+    column_iters
+        .into_iter()
+        .zip(fields)
+        .map(|(column_iter, field)| {
+            let series = column_iter
+                .map(|arr| {
+                    Series::try_from((field.name.as_str(), cast_array_for_daft_if_needed(arr?)))
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            if series.is_empty() {
+                empty_series(&field, daft_schema)
+            } else {
+                Series::concat(&series.iter().collect::<Vec<_>>())
+            }
+        })
+        .collect()
+}
+
+fn empty_series(field: &Field, daft_schema: &SchemaRef) -> DaftResult<Series> {
+    let daft_field = daft_schema.get_field(&field.name)?;
+    Ok(Series::empty(&field.name, &daft_field.dtype))
+}
+
+/// Converts a row selection mask into the runs of selected rows.
+fn selected_intervals(mask: &BooleanArray) -> Vec<Interval> {
+    let mut intervals: Vec<Interval> = vec![];
+    for (idx, selected) in mask.as_arrow().iter().enumerate() {
+        if selected != Some(true) {
+            continue;
+        }
+        match intervals.last_mut() {
+            Some(last) if last.start + last.length == idx => last.length += 1,
+            _ => intervals.push(Interval::new(idx, 1)),
+        }
+    }
+    intervals
+}
+
+/// Selects the pages and rows within them of every column of `fields` that contain the `intervals` of rows.
+///
+/// Returns None if the row group has no page index, or if any of the fields is nested, since page locations are
+/// only a function of the row numbers for columns without repetition.
+fn select_field_pages<R: Read + Seek>(
+    reader: &mut R,
+    rg_metadata: &RowGroupMetaData,
+    fields: &[Field],
+    intervals: &[Interval],
+) -> arrow2::error::Result<Option<Vec<Vec<Vec<FilteredPage>>>>> {
+    let has_offset_index = rg_metadata
+        .columns()
+        .iter()
+        .all(|c| c.column_chunk().offset_index_offset.is_some());
+    let is_nested = fields.iter().any(|f| {
+        matches!(
+            f.data_type.to_logical_type(),
+            ArrowDataType::List(_)
+                | ArrowDataType::LargeList(_)
+                | ArrowDataType::FixedSizeList(..)
+                | ArrowDataType::Struct(_)
+                | ArrowDataType::Map(..)
+        )
+    });
+    if !has_offset_index || is_nested {
+        return Ok(None);
+    }
+
+    let num_rows = rg_metadata.num_rows();
+    let locations = read::read_pages_locations(reader, rg_metadata.columns())?;
+    fields
+        .iter()
+        .map(|field| {
+            rg_metadata
+                .columns()
+                .iter()
+                .zip(&locations)
+                .filter(|(column, _)| is_field_column(&column.descriptor().path_in_schema, field))
+                .map(|(_, locations)| Ok(select_pages(intervals, locations, num_rows)?))
+                .collect()
+        })
+        .collect::<arrow2::error::Result<Vec<_>>>()
+        .map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, sync::Arc};
+
+    use arrow2::{
+        array::{Int64Array, MutableDictionaryArray, MutableUtf8Array, TryExtend, Utf8Array},
+        chunk::Chunk,
+        datatypes::{DataType as ArrowDataType, Field, Schema as ArrowSchema},
+        io::parquet::{read, write},
+    };
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{lit, resolved_col};
+
+    use super::{predicate_columns, read_row_group_filtered};
+
+    fn write_file() -> Vec<u8> {
+        let schema = ArrowSchema::from(vec![
+            Field::new("id", ArrowDataType::Int64, true),
+            Field::new("name", ArrowDataType::Utf8, true),
+            Field::new(
+                "category",
+                ArrowDataType::Dictionary(
+                    arrow2::datatypes::IntegerType::Int32,
+                    Box::new(ArrowDataType::Utf8),
+                    false,
+                ),
+                true,
+            ),
+        ]);
+        let ids = Int64Array::from_iter((0..10_000).map(|i| (i % 7 != 0).then_some(i)));
+        let names = Utf8Array::<i32>::from_iter(
+            (0..10_000).map(|i| (i % 5 != 0).then(|| format!("name_{i}"))),
+        );
+        let mut categories = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+        categories
+            .try_extend((0..10_000).map(|i| Some(format!("category_{}", i % 3))))
+            .unwrap();
+        let categories: arrow2::array::DictionaryArray<i32> = categories.into();
+        let chunk = Chunk::new(vec![ids.boxed(), names.boxed(), categories.boxed()]);
+
+        let options = write::WriteOptions {
+            write_statistics: true,
+            compression: write::CompressionOptions::Uncompressed,
+            version: write::Version::V2,
+            data_pagesize_limit: Some(1024),
+        };
+        let encodings = vec![
+            vec![write::Encoding::Plain],
+            vec![write::Encoding::Plain],
+            vec![write::Encoding::RleDictionary],
+        ];
+        let row_groups = write::RowGroupIterator::try_new(
+            vec![Ok(chunk)].into_iter(),
+            &schema,
+            options,
+            encodings,
+        )
+        .unwrap();
+
+        let mut writer = write::FileWriter::try_new(Cursor::new(vec![]), schema, options).unwrap();
+        for group in row_groups {
+            writer.write(group.unwrap()).unwrap();
+        }
+        writer.end(None).unwrap();
+        writer.into_inner().into_inner()
+    }
+
+    #[test]
+    fn test_read_row_group_filtered() -> DaftResult<()> {
+        let mut reader = Cursor::new(write_file());
+        let metadata = read::read_metadata(&mut reader)?;
+        let mut schema = read::infer_schema(&metadata)?;
+        // Read the dictionary encoded column as plain strings, as Daft does.
+        schema.fields[2].data_type = ArrowDataType::Utf8;
+        let daft_schema = Arc::new(Schema::try_from(&schema)?);
+
+        for (predicate, expected) in [
+            (
+                resolved_col("id").gt_eq(lit(9_000i64)),
+                (9_000..10_000).filter(|i| i % 7 != 0).collect::<Vec<i64>>(),
+            ),
+            (
+                resolved_col("id")
+                    .lt(lit(20i64))
+                    .or(resolved_col("id").eq(lit(5_000i64))),
+                (0..20).chain([5_000]).filter(|i| i % 7 != 0).collect(),
+            ),
+            (resolved_col("id").lt(lit(0i64)), vec![]),
+        ] {
+            let columns = predicate_columns(&predicate, &schema).unwrap();
+            let table = read_row_group_filtered(
+                &mut reader,
+                &metadata.row_groups[0],
+                &schema,
+                &daft_schema,
+                &predicate,
+                &columns,
+                128,
+                "memory",
+            )?;
+
+            assert_eq!(table.len(), expected.len());
+            let ids = table.get_column("id")?.i64()?;
+            let names = table.get_column("name")?.utf8()?;
+            let categories = table.get_column("category")?.utf8()?;
+            for (idx, id) in expected.iter().enumerate() {
+                assert_eq!(ids.get(idx), Some(*id));
+                let name = (id % 5 != 0).then(|| format!("name_{id}"));
+                assert_eq!(names.get(idx), name.as_deref());
+                let category = format!("category_{}", id % 3);
+                assert_eq!(categories.get(idx), Some(category.as_str()));
+            }
+        }
+        Ok(())
+    }
+}
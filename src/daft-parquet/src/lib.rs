@@ -7,7 +7,9 @@ use common_error::DaftError;
 use daft_core::prelude::SchemaRef;
 use snafu::Snafu;
 
+mod bloom_filter;
 mod file;
+mod filtered_reader;
 pub mod metadata;
 #[cfg(feature = "python")]
 pub mod python;
@@ -80,6 +82,12 @@ pub enum Error {
         source: arrow2::error::Error,
     },
 
+    #[snafu(display("Unable to read bloom filter for parquet file {}: {}", path, source))]
+    UnableToReadBloomFilter {
+        path: String,
+        source: parquet2::error::Error,
+    },
+
     #[snafu(display("Unable to create page stream for parquet file {}: {}", path, source))]
     UnableToCreateParquetPageStream {
         path: String,
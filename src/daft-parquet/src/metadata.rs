@@ -1,11 +1,15 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, LazyLock, Mutex},
+};
 
 use common_error::DaftResult;
 use daft_core::datatypes::Field;
 use daft_dsl::common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
-use daft_io::{IOClient, IOStatsRef};
+use daft_io::{IOClient, IOStatsRef, ObjectHead};
+use indexmap::IndexMap;
 pub use parquet2::metadata::{FileMetaData, RowGroupMetaData};
-use parquet2::{read::deserialize_metadata, schema::types::ParquetType};
+use parquet2::{metadata::RowGroupList, read::deserialize_metadata, schema::types::ParquetType};
 use snafu::ResultExt;
 
 use crate::{Error, JoinSnafu, UnableToParseMetadataSnafu};
@@ -219,6 +223,62 @@ pub(crate) async fn read_parquet_metadata(
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
     field_id_mapping: Option<Arc<BTreeMap<i32, Field>>>,
+) -> super::Result<FileMetaData> {
+    let file_metadata = fetch_parquet_metadata(uri, size, io_client, io_stats).await?;
+    if let Some(field_id_mapping) = field_id_mapping {
+        apply_field_ids_to_parquet_file_metadata(file_metadata, field_id_mapping.as_ref())
+    } else {
+        Ok(file_metadata)
+    }
+}
+
+/// Like [`read_parquet_metadata`], but reuses the footer of a previous read of the same version of
+/// the file, see [`MetadataCache`].
+pub(crate) async fn read_parquet_metadata_cached(
+    uri: &str,
+    head: &ObjectHead,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    field_id_mapping: Option<Arc<BTreeMap<i32, Field>>>,
+) -> super::Result<Arc<FileMetaData>> {
+    let Some(etag) = &head.etag else {
+        return read_parquet_metadata(uri, head.size, io_client, io_stats, field_id_mapping)
+            .await
+            .map(Arc::new);
+    };
+    let key = (cache_path(uri), etag.clone());
+
+    let cached = METADATA_CACHE.lock().unwrap().get(&key);
+    let file_metadata = if let Some(file_metadata) = cached {
+        file_metadata
+    } else {
+        let file_metadata =
+            Arc::new(fetch_parquet_metadata(uri, head.size, io_client, io_stats).await?);
+        METADATA_CACHE
+            .lock()
+            .unwrap()
+            .insert(key, file_metadata.clone());
+        file_metadata
+    };
+
+    // Field IDs are applied to a copy, since they differ between scans of the same file.
+    if let Some(field_id_mapping) = field_id_mapping {
+        apply_field_ids_to_parquet_file_metadata(
+            Arc::unwrap_or_clone(file_metadata),
+            field_id_mapping.as_ref(),
+        )
+        .map(Arc::new)
+    } else {
+        Ok(file_metadata)
+    }
+}
+
+/// Reads and parses the footer of a Parquet file.
+async fn fetch_parquet_metadata(
+    uri: &str,
+    size: usize,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
 ) -> super::Result<FileMetaData> {
     const FOOTER_SIZE: usize = 8;
     const PARQUET_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'1'];
@@ -290,13 +350,221 @@ pub(crate) async fn read_parquet_metadata(
     .context(JoinSnafu {
         path: uri.to_string(),
     })?
-    .context(UnableToParseMetadataSnafu { path: uri });
+    .context(UnableToParseMetadataSnafu { path: uri })?;
 
-    if let Some(field_id_mapping) = field_id_mapping {
-        apply_field_ids_to_parquet_file_metadata(file_metadata?, field_id_mapping.as_ref())
-    } else {
-        file_metadata
+    Ok(file_metadata)
+}
+
+/// The key of the footer metadata under which Daft records the approximate number of distinct non-null values of each
+/// column of the files it writes, as a JSON object from column names to counts.
+pub const NUM_DISTINCT_METADATA_KEY: &str = "daft.num_distinct";
+
+/// Returns the approximate number of distinct values of each column recorded in the footer of a file, or an empty map
+/// if the file wasn't written with them.
+pub fn num_distinct_from_metadata(metadata: &FileMetaData) -> BTreeMap<String, usize> {
+    metadata
+        .key_value_metadata
+        .iter()
+        .flatten()
+        .find(|kv| kv.key == NUM_DISTINCT_METADATA_KEY)
+        .and_then(|kv| kv.value.as_deref())
+        .and_then(|value| serde_json::from_str(value).ok())
+        .unwrap_or_default()
+}
+
+/// The name of the file that summarizes the footers of all the Parquet files of a dataset, which can be written next to
+/// them so that reads of the dataset don't need to list its files or fetch their footers.
+pub const SUMMARY_METADATA_FILE_NAME: &str = "_metadata";
+
+/// Splits the footer of a `_metadata` summary file into the footers of the files that it summarizes, along with their
+/// paths relative to the directory of the summary, in the order they appear in it.
+///
+/// Returns None if a row group of the summary doesn't name the file that it's in.
+pub fn split_summary_metadata(summary: &FileMetaData) -> Option<Vec<(String, FileMetaData)>> {
+    let mut files = IndexMap::<String, RowGroupList>::new();
+    for row_group in summary.row_groups.values() {
+        let file_path = row_group.columns().first()?.file_path().clone()?;
+        let row_groups = files.entry(file_path).or_default();
+        row_groups.insert(row_groups.len(), row_group.clone());
     }
+    let files = files
+        .into_iter()
+        .map(|(file_path, row_groups)| {
+            let num_rows = row_groups.values().map(RowGroupMetaData::num_rows).sum();
+            (
+                file_path,
+                summary.clone_with_row_groups(num_rows, row_groups),
+            )
+        })
+        .collect();
+    Some(files)
+}
+
+/// Normalizes `uri` so that local files are cached under the same key however their path is written.
+fn cache_path(uri: &str) -> String {
+    daft_io::parse_url(uri).map_or_else(|_| uri.to_string(), |(_, path)| path.into_owned())
+}
+
+/// The default bound on the estimated size of the metadata in the [`MetadataCache`].
+const DEFAULT_METADATA_CACHE_CAPACITY_BYTES: usize = 256 * 1024 * 1024;
+
+static METADATA_CACHE: LazyLock<Mutex<MetadataCache>> =
+    LazyLock::new(|| Mutex::new(MetadataCache::new(DEFAULT_METADATA_CACHE_CAPACITY_BYTES)));
+
+struct CachedMetadata {
+    metadata: Arc<FileMetaData>,
+    size_bytes: usize,
+    last_used: u64,
+}
+
+/// Parsed metadata of the Parquet files that were read before, shared by all queries in the process
+/// so that repeatedly scanning the same dataset doesn't fetch and parse every footer again.
+///
+/// Entries are keyed by path and ETag, so a file that was overwritten is read again. Files from
+/// sources that don't provide ETags are never cached. The cache is bounded by the estimated
+/// in-memory size of the parsed metadata, see [`estimated_size_bytes`], and evicts the least
+/// recently used files first.
+struct MetadataCache {
+    entries: HashMap<(String, String), CachedMetadata>,
+    // The keys of the entries by when they were last used, from least to most recently.
+    recency: BTreeMap<u64, (String, String)>,
+    size_bytes: usize,
+    capacity_bytes: usize,
+    clock: u64,
+}
+
+impl MetadataCache {
+    fn new(capacity_bytes: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            size_bytes: 0,
+            capacity_bytes,
+            clock: 0,
+        }
+    }
+
+    fn get(&mut self, key: &(String, String)) -> Option<Arc<FileMetaData>> {
+        let entry = self.entries.get_mut(key)?;
+        self.clock += 1;
+        let key = self.recency.remove(&entry.last_used).unwrap();
+        self.recency.insert(self.clock, key);
+        entry.last_used = self.clock;
+        Some(entry.metadata.clone())
+    }
+
+    fn insert(&mut self, key: (String, String), metadata: Arc<FileMetaData>) {
+        let size_bytes = estimated_size_bytes(&metadata);
+        if size_bytes > self.capacity_bytes {
+            return;
+        }
+        self.clock += 1;
+        let entry = CachedMetadata {
+            metadata,
+            size_bytes,
+            last_used: self.clock,
+        };
+        self.recency.insert(self.clock, key.clone());
+        if let Some(old) = self.entries.insert(key, entry) {
+            self.recency.remove(&old.last_used);
+            self.size_bytes -= old.size_bytes;
+        }
+        self.size_bytes += size_bytes;
+        self.evict();
+    }
+
+    fn invalidate(&mut self, uri: Option<&str>) {
+        self.entries
+            .retain(|(path, _), _| uri.is_some_and(|uri| uri != path));
+        self.recency
+            .retain(|_, (path, _)| uri.is_some_and(|uri| uri != path));
+        self.size_bytes = self.entries.values().map(|e| e.size_bytes).sum();
+    }
+
+    fn set_capacity(&mut self, capacity_bytes: usize) {
+        self.capacity_bytes = capacity_bytes;
+        self.evict();
+    }
+
+    fn evict(&mut self) {
+        while self.size_bytes > self.capacity_bytes {
+            let Some((_, key)) = self.recency.pop_first() else {
+                break;
+            };
+            let entry = self.entries.remove(&key).unwrap();
+            self.size_bytes -= entry.size_bytes;
+        }
+    }
+}
+
+/// Estimates how much memory parsed metadata takes up, which can be many times the size of the
+/// footer it was parsed from: every column chunk of every row group keeps its own copy of the
+/// descriptor of its column along with its statistics.
+fn estimated_size_bytes(metadata: &FileMetaData) -> usize {
+    fn strings_size(strings: &[String]) -> usize {
+        strings
+            .iter()
+            .map(|s| std::mem::size_of::<String>() + s.len())
+            .sum()
+    }
+
+    let columns_size = metadata
+        .schema_descr
+        .columns()
+        .iter()
+        .map(|column| std::mem::size_of_val(column) + strings_size(&column.path_in_schema))
+        .sum::<usize>();
+    let key_value_size = metadata.key_value_metadata.as_ref().map_or(0, |kvs| {
+        kvs.iter()
+            .map(|kv| {
+                std::mem::size_of_val(kv) + kv.key.len() + kv.value.as_ref().map_or(0, String::len)
+            })
+            .sum()
+    });
+    let row_groups_size = metadata
+        .row_groups
+        .values()
+        .map(|rg| {
+            std::mem::size_of_val(rg)
+                + rg.columns()
+                    .iter()
+                    .map(|chunk| {
+                        let descriptor = chunk.descriptor();
+                        let column_metadata = chunk.metadata();
+                        let statistics_size =
+                            column_metadata.statistics.as_ref().map_or(0, |stats| {
+                                [&stats.max, &stats.min, &stats.max_value, &stats.min_value]
+                                    .into_iter()
+                                    .map(|value| value.as_ref().map_or(0, Vec::len))
+                                    .sum()
+                            });
+                        std::mem::size_of_val(chunk)
+                            + strings_size(&descriptor.path_in_schema)
+                            + strings_size(&column_metadata.path_in_schema)
+                            + std::mem::size_of_val(column_metadata.encodings.as_slice())
+                            + statistics_size
+                    })
+                    .sum::<usize>()
+        })
+        .sum::<usize>();
+
+    std::mem::size_of::<FileMetaData>()
+        + metadata.created_by.as_ref().map_or(0, String::len)
+        + columns_size
+        + key_value_size
+        + row_groups_size
+}
+
+/// Sets the bound on the estimated in-memory size of the Parquet metadata that's cached across
+/// queries. A capacity of 0 disables the cache.
+pub fn set_metadata_cache_capacity(capacity_bytes: usize) {
+    METADATA_CACHE.lock().unwrap().set_capacity(capacity_bytes);
+}
+
+/// Drops the cached metadata of the Parquet file at `uri`, or of all files if `uri` is `None`.
+pub fn invalidate_metadata_cache(uri: Option<&str>) {
+    let path = uri.map(cache_path);
+    METADATA_CACHE.lock().unwrap().invalidate(path.as_deref());
 }
 
 #[cfg(test)]
@@ -306,7 +574,9 @@ mod tests {
     use common_error::DaftResult;
     use daft_io::{IOClient, IOConfig};
 
-    use super::read_parquet_metadata;
+    use super::{
+        estimated_size_bytes, read_parquet_metadata, read_parquet_metadata_cached, MetadataCache,
+    };
 
     #[tokio::test]
     async fn test_parquet_metadata_from_s3() -> DaftResult<()> {
@@ -322,4 +592,60 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_parquet_metadata_cache() -> DaftResult<()> {
+        let file = format!(
+            "file://{}/../../tests/assets/parquet-data/mvp.parquet",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let head = io_client.single_url_head(file.clone(), None).await?;
+        let etag = head.etag.clone().expect("local files should have an etag");
+
+        let metadata =
+            read_parquet_metadata_cached(&file, &head, io_client.clone(), None, None).await?;
+        assert_eq!(metadata.num_rows, 100);
+        let cached = super::METADATA_CACHE
+            .lock()
+            .unwrap()
+            .get(&(super::cache_path(&file), etag.clone()))
+            .expect("metadata should be cached");
+        assert_eq!(cached.num_rows, 100);
+
+        // Hits share the cached metadata instead of copying it.
+        let hit = read_parquet_metadata_cached(&file, &head, io_client.clone(), None, None).await?;
+        assert!(Arc::ptr_eq(&hit, &cached));
+
+        let size_bytes = estimated_size_bytes(&cached);
+
+        // The least recently used file is evicted once the metadata exceeds the capacity.
+        let (a, b, c) = (
+            (file.clone(), "a".to_string()),
+            (file.clone(), "b".to_string()),
+            (file.clone(), "c".to_string()),
+        );
+        let mut cache = MetadataCache::new(size_bytes * 5 / 2);
+        cache.insert(a.clone(), cached.clone());
+        cache.insert(b.clone(), cached.clone());
+        assert!(cache.get(&a).is_some());
+        cache.insert(c.clone(), cached.clone());
+        assert!(cache.get(&b).is_none());
+        assert!(cache.get(&a).is_some() && cache.get(&c).is_some());
+        assert_eq!(cache.size_bytes, size_bytes * 2);
+
+        // Metadata that doesn't fit at all isn't cached.
+        cache.set_capacity(size_bytes - 1);
+        assert!(cache.entries.is_empty() && cache.recency.is_empty());
+        cache.insert(b.clone(), cached);
+        assert!(cache.get(&b).is_none());
+
+        cache.set_capacity(size_bytes * 5 / 2);
+        cache.insert(a, hit);
+        cache.invalidate(Some(&file));
+        assert!(cache.entries.is_empty() && cache.recency.is_empty());
+        assert_eq!(cache.size_bytes, 0);
+
+        Ok(())
+    }
 }
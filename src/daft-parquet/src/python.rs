@@ -332,6 +332,16 @@ pub mod pylib {
             )
         })
     }
+
+    #[pyfunction]
+    pub fn set_parquet_metadata_cache_capacity(capacity_bytes: usize) {
+        crate::metadata::set_metadata_cache_capacity(capacity_bytes);
+    }
+
+    #[pyfunction(signature = (uri=None))]
+    pub fn invalidate_parquet_metadata_cache(uri: Option<&str>) {
+        crate::metadata::invalidate_metadata_cache(uri);
+    }
 }
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_function(wrap_pyfunction!(pylib::read_parquet, parent)?)?;
@@ -343,5 +353,13 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_function(wrap_pyfunction!(pylib::read_parquet_bulk, parent)?)?;
     parent.add_function(wrap_pyfunction!(pylib::read_parquet_schema, parent)?)?;
     parent.add_function(wrap_pyfunction!(pylib::read_parquet_statistics, parent)?)?;
+    parent.add_function(wrap_pyfunction!(
+        pylib::set_parquet_metadata_cache_capacity,
+        parent
+    )?)?;
+    parent.add_function(wrap_pyfunction!(
+        pylib::invalidate_parquet_metadata_cache,
+        parent
+    )?)?;
     Ok(())
 }
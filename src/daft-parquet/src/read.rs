@@ -18,6 +18,7 @@ use daft_core::python::PyTimeUnit;
 use daft_dsl::{optimization::get_required_columns, ExprRef};
 use daft_io::{parse_url, IOClient, IOStatsRef, SourceType};
 use daft_recordbatch::RecordBatch;
+use daft_stats::TruthValue;
 use futures::{
     future::{join_all, try_join_all},
     stream::BoxStream,
@@ -28,7 +29,7 @@ use parquet2::metadata::FileMetaData;
 use serde::{Deserialize, Serialize};
 use snafu::ResultExt;
 
-use crate::{file::ParquetReaderBuilder, JoinSnafu};
+use crate::{file::ParquetReaderBuilder, row_group_metadata_to_table_stats, JoinSnafu};
 
 #[cfg(feature = "python")]
 #[derive(Clone)]
@@ -236,9 +237,16 @@ async fn read_parquet_single(
         let builder = builder.set_chunk_size(chunk_size);
 
         let parquet_reader = builder.build()?;
+        let parquet_reader = if let Some(ref predicate) = predicate {
+            parquet_reader
+                .prune_row_groups_with_bloom_filters(predicate, io_client.clone(), io_stats.clone())
+                .await?
+        } else {
+            parquet_reader
+        };
         let ranges = parquet_reader.prebuffer_ranges(io_client, io_stats)?;
         Ok((
-            Arc::new(metadata),
+            metadata,
             parquet_reader.read_from_ranges_into_table(ranges).await?,
         ))
     }?;
@@ -365,6 +373,7 @@ async fn read_parquet_single(
 async fn stream_parquet_single(
     uri: String,
     columns: Option<&[&str]>,
+    struct_fields: Option<&[Vec<String>]>,
     num_rows: Option<usize>,
     row_groups: Option<Vec<i64>>,
     predicate: Option<ExprRef>,
@@ -379,6 +388,8 @@ async fn stream_parquet_single(
 ) -> DaftResult<impl Stream<Item = DaftResult<RecordBatch>> + Send> {
     let field_id_mapping_provided = field_id_mapping.is_some();
     let columns_to_return = columns.map(|s| s.iter().map(|s| (*s).to_string()).collect_vec());
+    // Field ids can rename nested fields, so struct field paths can't be resolved against the file.
+    let struct_fields = struct_fields.filter(|_| !field_id_mapping_provided);
     let num_rows_to_return = num_rows;
     let mut num_rows_to_read = num_rows;
     let mut columns_to_read = columns.map(|s| s.iter().map(|s| (*s).to_string()).collect_vec());
@@ -409,6 +420,7 @@ async fn stream_parquet_single(
             fixed_uri.as_ref(),
             columns_to_return,
             columns_to_read,
+            struct_fields.map(<[_]>::to_vec),
             num_rows_to_return,
             num_rows_to_read,
             delete_rows,
@@ -440,6 +452,12 @@ async fn stream_parquet_single(
             builder
         };
 
+        let builder = if let Some(struct_fields) = struct_fields {
+            builder.prune_struct_fields(struct_fields)
+        } else {
+            builder
+        };
+
         if row_groups.is_some() && num_rows_to_read.is_some() {
             return Err(common_error::DaftError::ValueError("Both `row_groups` and `num_rows` is set at the same time. We only support setting one set or the other.".to_string()));
         }
@@ -459,9 +477,16 @@ async fn stream_parquet_single(
         };
 
         let parquet_reader = builder.build()?;
+        let parquet_reader = if let Some(ref predicate) = predicate {
+            parquet_reader
+                .prune_row_groups_with_bloom_filters(predicate, io_client.clone(), io_stats.clone())
+                .await?
+        } else {
+            parquet_reader
+        };
         let ranges = parquet_reader.prebuffer_ranges(io_client, io_stats)?;
         Ok((
-            Arc::new(metadata),
+            metadata,
             parquet_reader
                 .read_from_ranges_into_table_stream(
                     ranges,
@@ -584,7 +609,7 @@ async fn read_parquet_single_into_arrow(
         let (all_arrays, num_rows_read) = parquet_reader
             .read_from_ranges_into_arrow_arrays(ranges)
             .await?;
-        (Arc::new(metadata), schema, all_arrays, num_rows_read)
+        (metadata, schema, all_arrays, num_rows_read)
     };
 
     let rows_per_row_groups = metadata
@@ -868,6 +893,7 @@ pub async fn read_parquet_bulk_async(
 pub async fn stream_parquet(
     uri: &str,
     columns: Option<&[&str]>,
+    struct_fields: Option<&[Vec<String>]>,
     num_rows: Option<usize>,
     row_groups: Option<Vec<i64>>,
     predicate: Option<ExprRef>,
@@ -883,6 +909,7 @@ pub async fn stream_parquet(
     let stream = stream_parquet_single(
         uri.to_string(),
         columns,
+        struct_fields,
         num_rows,
         row_groups,
         predicate,
@@ -968,33 +995,67 @@ pub async fn read_parquet_schema_and_metadata(
     io_stats: Option<IOStatsRef>,
     schema_inference_options: ParquetSchemaInferenceOptions,
     field_id_mapping: Option<Arc<BTreeMap<i32, Field>>>,
-) -> DaftResult<(Schema, FileMetaData)> {
+) -> DaftResult<(Schema, Arc<FileMetaData>)> {
     let builder =
         ParquetReaderBuilder::from_uri(uri, io_client.clone(), io_stats, field_id_mapping).await?;
     let builder = builder.set_infer_schema_options(schema_inference_options);
 
     let metadata = builder.metadata;
-    let arrow_schema = infer_schema_with_options(&metadata, Some(schema_inference_options.into()))?;
-    let schema = Schema::try_from(&arrow_schema)?;
+    let schema = infer_schema_from_metadata(&metadata, schema_inference_options)?;
     Ok((schema, metadata))
 }
 
+/// Infers the schema of a Parquet file from its footer.
+pub fn infer_schema_from_metadata(
+    metadata: &FileMetaData,
+    schema_inference_options: ParquetSchemaInferenceOptions,
+) -> DaftResult<Schema> {
+    let arrow_schema = infer_schema_with_options(metadata, Some(schema_inference_options.into()))?;
+    Ok(Schema::try_from(&arrow_schema)?)
+}
+
 pub async fn read_parquet_metadata(
     uri: &str,
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
     field_id_mapping: Option<Arc<BTreeMap<i32, Field>>>,
-) -> DaftResult<parquet2::metadata::FileMetaData> {
+) -> DaftResult<Arc<parquet2::metadata::FileMetaData>> {
     let builder =
         ParquetReaderBuilder::from_uri(uri, io_client, io_stats, field_id_mapping).await?;
     Ok(builder.metadata)
 }
+
+/// The indices of the row groups of a Parquet file whose min/max statistics don't rule out that some of their rows
+/// satisfy `predicate`.
+///
+/// Row groups are kept whenever the predicate can't be evaluated on their statistics, e.g. because it references
+/// columns that aren't in the file, such as partition columns.
+pub fn row_groups_matching_predicate(
+    metadata: &FileMetaData,
+    predicate: &ExprRef,
+    schema_inference_options: ParquetSchemaInferenceOptions,
+) -> DaftResult<Vec<usize>> {
+    let arrow_schema = infer_schema_with_options(metadata, Some(schema_inference_options.into()))?;
+    let schema = Schema::try_from(&arrow_schema)?;
+    Ok(metadata
+        .row_groups
+        .iter()
+        .filter(|(_, rg)| {
+            row_group_metadata_to_table_stats(rg, &schema)
+                .ok()
+                .and_then(|stats| stats.eval_expression(predicate).ok())
+                .is_none_or(|evaled| evaled.to_truth_value() != TruthValue::False)
+        })
+        .map(|(i, _)| *i)
+        .collect())
+}
+
 pub async fn read_parquet_metadata_bulk(
     uris: &[&str],
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
     field_id_mapping: Option<Arc<BTreeMap<i32, Field>>>,
-) -> DaftResult<Vec<parquet2::metadata::FileMetaData>> {
+) -> DaftResult<Vec<Arc<parquet2::metadata::FileMetaData>>> {
     let handles_iter = uris.iter().map(|uri| {
         let owned_string = (*uri).to_string();
         let owned_client = io_client.clone();
@@ -1169,6 +1230,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
                 io_client,
                 None,
                 &Default::default(),
@@ -1199,9 +1261,9 @@ mod tests {
 
         runtime_handle.block_on(async move {
             let metadata = read_parquet_metadata(&file, io_client, None, None).await?;
-            let serialized = bincode::serialize(&metadata).unwrap();
+            let serialized = bincode::serialize(metadata.as_ref()).unwrap();
             let deserialized = bincode::deserialize::<FileMetaData>(&serialized).unwrap();
-            assert_eq!(metadata, deserialized);
+            assert_eq!(*metadata, deserialized);
             Ok(())
         })?
     }
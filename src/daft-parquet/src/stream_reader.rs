@@ -23,11 +23,13 @@ use snafu::ResultExt;
 use tokio_stream::wrappers::ReceiverStream;
 
 use crate::{
+    bloom_filter::prune_row_groups_with_bloom_filters,
     determine_parquet_parallelism,
     file::{build_row_ranges, RowGroupRange},
+    filtered_reader::{predicate_columns, read_row_group_filtered},
     read::{ArrowChunk, ArrowChunkIters, ParquetSchemaInferenceOptions},
     stream_reader::read::schema::infer_schema_with_options,
-    utils::combine_stream,
+    utils::{combine_stream, prune_struct_fields},
     UnableToConvertSchemaToDaftSnafu, PARQUET_MORSEL_SIZE,
 };
 
@@ -279,24 +281,28 @@ impl<R> Drop for CountingReader<R> {
     }
 }
 
+/// The file, schema and row groups of a local Parquet read, before any row group is read.
+struct LocalParquetReadPlan {
+    reader: CountingReader<File>,
+    uri: String,
+    metadata: Arc<parquet2::metadata::FileMetaData>,
+    schema: arrow2::datatypes::Schema,
+    daft_schema: SchemaRef,
+    row_ranges: Vec<RowGroupRange>,
+}
+
 #[allow(clippy::too_many_arguments)]
-#[allow(clippy::type_complexity)]
-pub fn local_parquet_read_into_column_iters(
+fn plan_local_parquet_read(
     uri: &str,
     columns: Option<&[String]>,
+    struct_fields: Option<&[Vec<String>]>,
     num_rows: Option<usize>,
     row_groups: Option<&[i64]>,
     predicate: Option<ExprRef>,
     schema_infer_options: ParquetSchemaInferenceOptions,
     metadata: Option<Arc<parquet2::metadata::FileMetaData>>,
-    chunk_size: usize,
     io_stats: Option<IOStatsRef>,
-) -> super::Result<(
-    Arc<parquet2::metadata::FileMetaData>,
-    SchemaRef,
-    Vec<RowGroupRange>,
-    impl Iterator<Item = super::Result<ArrowChunkIters>>,
-)> {
+) -> super::Result<LocalParquetReadPlan> {
     const LOCAL_PROTOCOL: &str = "file://";
     let uri = uri
         .strip_prefix(LOCAL_PROTOCOL)
@@ -340,6 +346,10 @@ pub fn local_parquet_read_into_column_iters(
             path: uri.to_string(),
         })?;
     let schema = prune_fields_from_schema(schema, columns)?;
+    let schema = match struct_fields {
+        Some(struct_fields) => prune_struct_fields(schema, struct_fields),
+        None => schema,
+    };
     let daft_schema =
         Schema::try_from(&schema).with_context(|_| UnableToConvertSchemaToDaftSnafu {
             path: uri.to_string(),
@@ -349,12 +359,50 @@ pub fn local_parquet_read_into_column_iters(
         num_rows,
         0,
         row_groups,
-        predicate,
+        predicate.clone(),
         &daft_schema,
         &metadata,
         &uri,
     )?;
+    let row_ranges = match &predicate {
+        Some(predicate) => prune_row_groups_with_bloom_filters(
+            &mut reader,
+            &metadata,
+            row_ranges,
+            predicate,
+            &uri,
+        )?,
+        None => row_ranges,
+    };
+
+    Ok(LocalParquetReadPlan {
+        reader,
+        uri,
+        metadata,
+        schema,
+        daft_schema: Arc::new(daft_schema),
+        row_ranges,
+    })
+}
 
+#[allow(clippy::type_complexity)]
+fn plan_into_column_iters(
+    plan: LocalParquetReadPlan,
+    chunk_size: usize,
+) -> (
+    Arc<parquet2::metadata::FileMetaData>,
+    SchemaRef,
+    Vec<RowGroupRange>,
+    impl Iterator<Item = super::Result<ArrowChunkIters>>,
+) {
+    let LocalParquetReadPlan {
+        mut reader,
+        uri,
+        metadata,
+        schema,
+        daft_schema,
+        row_ranges,
+    } = plan;
     let all_row_groups = metadata.row_groups.clone();
 
     // Read all the required row groups into memory sequentially
@@ -376,12 +424,7 @@ pub fn local_parquet_read_into_column_iters(
         reader.update_count();
         Ok(single_rg_column_iter)
     });
-    Ok((
-        metadata,
-        Arc::new(daft_schema),
-        row_ranges,
-        column_iters_per_rg,
-    ))
+    (metadata, daft_schema, row_ranges, column_iters_per_rg)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -448,11 +491,17 @@ pub fn local_parquet_read_into_arrow(
         num_rows,
         start_offset.unwrap_or(0),
         row_groups,
-        predicate,
+        predicate.clone(),
         &daft_schema,
         &metadata,
         uri,
     )?;
+    let row_ranges = match &predicate {
+        Some(predicate) => {
+            prune_row_groups_with_bloom_filters(&mut reader, &metadata, row_ranges, predicate, uri)?
+        }
+        None => row_ranges,
+    };
 
     let columns_iters_per_rg = row_ranges
         .iter()
@@ -590,6 +639,7 @@ pub async fn local_parquet_stream(
     uri: &str,
     original_columns: Option<Vec<String>>,
     columns: Option<Vec<String>>,
+    struct_fields: Option<Vec<Vec<String>>>,
     original_num_rows: Option<usize>,
     num_rows: Option<usize>,
     delete_rows: Option<Vec<i64>>,
@@ -605,18 +655,42 @@ pub async fn local_parquet_stream(
     BoxStream<'static, DaftResult<RecordBatch>>,
 )> {
     let chunk_size = chunk_size.unwrap_or(PARQUET_MORSEL_SIZE);
-    let (metadata, schema_ref, row_ranges, column_iters) = local_parquet_read_into_column_iters(
+    let plan = plan_local_parquet_read(
         uri,
         columns.as_deref(),
+        struct_fields.as_deref(),
         num_rows,
         row_groups.as_deref(),
         predicate.clone(),
         schema_infer_options,
         metadata,
-        chunk_size,
-        io_stats,
+        io_stats.clone(),
     )?;
 
+    // Evaluate the predicate on the columns it references before decoding the others, so that rows that don't match
+    // are never fully materialized. Deletes and row limits are applied by row position, so they need the unfiltered
+    // row groups.
+    if let Some(predicate) = &predicate
+        && delete_rows.is_none()
+        && num_rows.is_none()
+        && plan.row_ranges.iter().all(|rg_range| rg_range.start == 0)
+        && let Some(predicate_columns) = predicate_columns(predicate, &plan.schema)
+    {
+        let stream = local_parquet_filtered_stream(
+            plan,
+            predicate.clone(),
+            predicate_columns,
+            original_columns,
+            original_num_rows,
+            maintain_order,
+            io_stats,
+            chunk_size,
+        );
+        return Ok(stream);
+    }
+
+    let (metadata, schema_ref, row_ranges, column_iters) = plan_into_column_iters(plan, chunk_size);
+
     // We use a semaphore to limit the number of concurrent row group deserialization tasks.
     // Set the maximum number of concurrent tasks to ceil(number of available threads / columns).
     let num_parallel_tasks = determine_parquet_parallelism(&schema_ref);
@@ -672,6 +746,118 @@ pub async fn local_parquet_stream(
     Ok((metadata, combined))
 }
 
+/// Streams the row groups of a local Parquet file, reading each of them with [`read_row_group_filtered`] on its own
+/// handle to the file so that row groups are read and filtered in parallel.
+#[allow(clippy::too_many_arguments)]
+fn local_parquet_filtered_stream(
+    plan: LocalParquetReadPlan,
+    predicate: ExprRef,
+    predicate_columns: HashSet<String>,
+    original_columns: Option<Vec<String>>,
+    original_num_rows: Option<usize>,
+    maintain_order: bool,
+    io_stats: Option<IOStatsRef>,
+    chunk_size: usize,
+) -> (
+    Arc<parquet2::metadata::FileMetaData>,
+    BoxStream<'static, DaftResult<RecordBatch>>,
+) {
+    let LocalParquetReadPlan {
+        uri,
+        metadata,
+        schema,
+        daft_schema,
+        row_ranges,
+        ..
+    } = plan;
+
+    let num_parallel_tasks = determine_parquet_parallelism(&daft_schema);
+    let semaphore = Arc::new(tokio::sync::Semaphore::new(num_parallel_tasks));
+    let compute_runtime = get_compute_runtime();
+    let schema = Arc::new(schema);
+    let predicate_columns = Arc::new(predicate_columns);
+    let owned_metadata = metadata.clone();
+
+    let (output_senders, output_receivers): (Vec<_>, Vec<_>) = row_ranges
+        .iter()
+        .map(|_| tokio::sync::mpsc::channel(max(PARQUET_MORSEL_SIZE / chunk_size, 1)))
+        .unzip();
+
+    let parquet_task = compute_runtime.clone().spawn(async move {
+        let mut table_tasks = Vec::with_capacity(row_ranges.len());
+        for (rg_range, sender) in row_ranges.into_iter().zip(output_senders) {
+            let permit = semaphore.clone().acquire_owned().await.unwrap();
+            let uri = uri.clone();
+            let metadata = owned_metadata.clone();
+            let schema = schema.clone();
+            let daft_schema = daft_schema.clone();
+            let predicate = predicate.clone();
+            let predicate_columns = predicate_columns.clone();
+            let original_columns = original_columns.clone();
+            let io_stats = io_stats.clone();
+            table_tasks.push(compute_runtime.spawn(async move {
+                let table = (|| {
+                    let file = File::open(&uri)
+                        .with_context(|_| super::InternalIOSnafu { path: uri.clone() })?;
+                    let mut reader = CountingReader {
+                        reader: file,
+                        count: 0,
+                        io_stats,
+                    };
+                    let mut table = read_row_group_filtered(
+                        &mut reader,
+                        metadata.row_groups.get(&rg_range.row_group_index).unwrap(),
+                        &schema,
+                        &daft_schema,
+                        &predicate,
+                        &predicate_columns,
+                        chunk_size,
+                        &uri,
+                    )?;
+                    if let Some(oc) = &original_columns {
+                        table = table.get_columns(oc)?;
+                    }
+                    if let Some(nr) = original_num_rows {
+                        table = table.head(nr)?;
+                    }
+                    DaftResult::Ok(table)
+                })();
+                drop(permit);
+
+                match table {
+                    Ok(table) => {
+                        for start in (0..table.len()).step_by(chunk_size) {
+                            let end = (start + chunk_size).min(table.len());
+                            if sender.send(table.slice(start, end)).await.is_err() {
+                                break;
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        let _ = sender.send(Err(e)).await;
+                    }
+                }
+                DaftResult::Ok(())
+            }));
+        }
+
+        futures::future::try_join_all(table_tasks)
+            .await?
+            .into_iter()
+            .collect::<DaftResult<()>>()?;
+
+        DaftResult::Ok(())
+    });
+
+    let stream_of_streams =
+        futures::stream::iter(output_receivers.into_iter().map(ReceiverStream::new));
+    let combined = match maintain_order {
+        true => combine_stream(stream_of_streams.flatten(), parquet_task).boxed(),
+        false => combine_stream(stream_of_streams.flatten_unordered(None), parquet_task).boxed(),
+    };
+    (metadata, combined)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn local_parquet_read_into_arrow_async(
     uri: &str,
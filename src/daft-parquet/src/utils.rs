@@ -24,3 +24,116 @@ pub(crate) fn combine_stream<T, E>(
         }
     })
 }
+
+/// Prunes the struct columns of `schema` down to the fields in `struct_fields`. Every path is a
+/// column name followed by the names of nested struct fields, e.g. `["s", "a"]` for field `a` of
+/// struct column `s`. Columns without a path, or with a path that ends at the column itself, are kept
+/// as they are.
+pub(crate) fn prune_struct_fields(
+    schema: arrow2::datatypes::Schema,
+    struct_fields: &[Vec<String>],
+) -> arrow2::datatypes::Schema {
+    let fields = schema
+        .fields
+        .into_iter()
+        .map(|field| {
+            let paths = struct_fields
+                .iter()
+                .filter(|path| path.first() == Some(&field.name))
+                .map(|path| &path[1..])
+                .collect::<Vec<_>>();
+            if paths.is_empty() {
+                field
+            } else {
+                let data_type = prune_struct_type(&field.data_type, &paths);
+                arrow2::datatypes::Field { data_type, ..field }
+            }
+        })
+        .collect::<Vec<_>>();
+    arrow2::datatypes::Schema::from(fields).with_metadata(schema.metadata)
+}
+
+fn prune_struct_type(
+    data_type: &arrow2::datatypes::DataType,
+    paths: &[&[String]],
+) -> arrow2::datatypes::DataType {
+    use arrow2::datatypes::DataType;
+
+    let DataType::Struct(fields) = data_type else {
+        return data_type.clone();
+    };
+    if paths.iter().any(|path| path.is_empty()) {
+        return data_type.clone();
+    }
+    let pruned = fields
+        .iter()
+        .filter_map(|f| {
+            let paths = paths
+                .iter()
+                .filter(|path| path[0] == f.name)
+                .map(|path| &path[1..])
+                .collect::<Vec<_>>();
+            (!paths.is_empty()).then(|| arrow2::datatypes::Field {
+                data_type: prune_struct_type(&f.data_type, &paths),
+                ..f.clone()
+            })
+        })
+        .collect::<Vec<_>>();
+    // Structs can't be read without any fields, so read the whole struct if none of the requested
+    // fields exist in the file.
+    if pruned.is_empty() {
+        data_type.clone()
+    } else {
+        DataType::Struct(pruned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow2::datatypes::{DataType, Field, Schema};
+
+    use super::prune_struct_fields;
+
+    #[test]
+    fn test_prune_struct_fields() {
+        let inner = DataType::Struct(vec![
+            Field::new("x", DataType::Int64, true),
+            Field::new("y", DataType::Utf8, true),
+        ]);
+        let schema = Schema::from(vec![
+            Field::new(
+                "s",
+                DataType::Struct(vec![
+                    Field::new("a", DataType::Int64, true),
+                    Field::new("b", inner.clone(), true),
+                    Field::new("c", DataType::Utf8, true),
+                ]),
+                true,
+            ),
+            Field::new("t", inner, true),
+            Field::new("i", DataType::Int64, true),
+        ]);
+        let path = |p: &[&str]| p.iter().map(|s| (*s).to_string()).collect::<Vec<_>>();
+
+        let pruned = prune_struct_fields(
+            schema.clone(),
+            &[path(&["s", "b", "y"]), path(&["s", "a"]), path(&["t"])],
+        );
+        assert_eq!(
+            pruned.fields[0].data_type,
+            DataType::Struct(vec![
+                Field::new("a", DataType::Int64, true),
+                Field::new(
+                    "b",
+                    DataType::Struct(vec![Field::new("y", DataType::Utf8, true)]),
+                    true
+                ),
+            ])
+        );
+        assert_eq!(pruned.fields[1..], schema.fields[1..]);
+
+        // Paths to fields that don't exist keep the whole struct.
+        let pruned = prune_struct_fields(schema.clone(), &[path(&["s", "missing"])]);
+        assert_eq!(pruned.fields, schema.fields);
+    }
+}
@@ -20,6 +20,7 @@ impl TreeDisplay for PhysicalPlan {
             Self::Sort(sort) => sort.display_as(level),
             Self::Sample(sample) => sample.display_as(level),
             Self::MonotonicallyIncreasingId(id) => id.display_as(level),
+            Self::Window(window) => window.display_as(level),
             Self::ShuffleExchange(shuffle_exchange) => shuffle_exchange.display_as(level),
             Self::Aggregate(aggr) => aggr.display_as(level),
             Self::Pivot(pivot) => pivot.display_as(level),
@@ -31,6 +32,7 @@ impl TreeDisplay for PhysicalPlan {
             Self::TabularWriteParquet(write) => write.display_as(level),
             Self::TabularWriteJson(write) => write.display_as(level),
             Self::TabularWriteCsv(write) => write.display_as(level),
+            Self::TabularWriteIpc(write) => write.display_as(level),
             #[cfg(feature = "python")]
             Self::IcebergWrite(write) => write.display_as(level),
             #[cfg(feature = "python")]
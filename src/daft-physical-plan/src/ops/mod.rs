@@ -13,6 +13,7 @@ mod hash_join;
 #[cfg(feature = "python")]
 mod iceberg_write;
 mod in_memory;
+mod ipc;
 mod json;
 #[cfg(feature = "python")]
 mod lance_write;
@@ -28,6 +29,7 @@ mod shuffle_exchange;
 mod sort;
 mod sort_merge_join;
 mod unpivot;
+mod window;
 
 pub use actor_pool_project::ActorPoolProject;
 pub use agg::Aggregate;
@@ -44,6 +46,7 @@ pub use hash_join::HashJoin;
 #[cfg(feature = "python")]
 pub use iceberg_write::IcebergWrite;
 pub use in_memory::InMemoryScan;
+pub use ipc::TabularWriteIpc;
 pub use json::TabularWriteJson;
 #[cfg(feature = "python")]
 pub use lance_write::LanceWrite;
@@ -59,6 +62,7 @@ pub use shuffle_exchange::{ShuffleExchange, ShuffleExchangeFactory, ShuffleExcha
 pub use sort::Sort;
 pub use sort_merge_join::SortMergeJoin;
 pub use unpivot::Unpivot;
+pub use window::Window;
 
 #[macro_export]
 /// Implement the `common_display::tree::TreeDisplay` trait for the given struct
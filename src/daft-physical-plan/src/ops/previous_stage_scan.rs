@@ -7,18 +7,30 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PreviousStageScan {
     pub clustering_spec: Arc<ClusteringSpec>,
+    /// The stage whose output this scan reads, if the plan has been split into a stage graph.
+    pub source_stage_id: Option<usize>,
 }
 
 impl PreviousStageScan {
     pub(crate) fn new(clustering_spec: Arc<ClusteringSpec>) -> Self {
-        Self { clustering_spec }
+        Self {
+            clustering_spec,
+            source_stage_id: None,
+        }
+    }
+
+    pub(crate) fn with_source_stage_id(mut self, stage_id: usize) -> Self {
+        self.source_stage_id = Some(stage_id);
+        self
     }
 
     pub fn multiline_display(&self) -> Vec<String> {
-        vec![
-            "PreviousStageScan".to_string(),
-            self.clustering_spec.multiline_display().join(", "),
-        ]
+        let mut res = vec!["PreviousStageScan".to_string()];
+        if let Some(stage_id) = self.source_stage_id {
+            res.push(format!("Stage ID = {stage_id}"));
+        }
+        res.push(self.clustering_spec.multiline_display().join(", "));
+        res
     }
 
     pub fn clustering_spec(&self) -> &Arc<ClusteringSpec> {
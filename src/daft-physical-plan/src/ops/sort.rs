@@ -12,6 +12,8 @@ pub struct Sort {
     pub descending: Vec<bool>,
     pub nulls_first: Vec<bool>,
     pub num_partitions: usize,
+    /// Whether each partition of the input is sorted on its own, without repartitioning by range.
+    pub within_partitions: bool,
 }
 
 impl Sort {
@@ -21,6 +23,7 @@ impl Sort {
         descending: Vec<bool>,
         nulls_first: Vec<bool>,
         num_partitions: usize,
+        within_partitions: bool,
     ) -> Self {
         Self {
             input,
@@ -28,6 +31,7 @@ impl Sort {
             descending,
             nulls_first,
             num_partitions,
+            within_partitions,
         }
     }
 
@@ -49,7 +53,11 @@ impl Sort {
                 )
             })
             .join(", ");
-        res.push(format!("Sort: Sort by = {}", pairs));
+        if self.within_partitions {
+            res.push(format!("Sort within partitions: Sort by = {}", pairs));
+        } else {
+            res.push(format!("Sort: Sort by = {}", pairs));
+        }
         res.push(format!("Num partitions = {}", self.num_partitions));
         res
     }
@@ -6,7 +6,7 @@ use daft_logical_plan::partitioning::{ClusteringSpec, HashClusteringConfig};
 use crate::{
     ops::{
         ActorPoolProject, Aggregate, Explode, HashJoin, Project, ShuffleExchange,
-        ShuffleExchangeStrategy, Unpivot,
+        ShuffleExchangeStrategy, Sort, Unpivot,
     },
     optimization::{plan_context::PlanContext, rules::PhysicalOptimizerRule},
     PhysicalPlan, PhysicalPlanRef,
@@ -145,9 +145,12 @@ impl PhysicalOptimizerRule for ReorderPartitionKeys {
                 PhysicalPlan::Limit(..) |
                 PhysicalPlan::Sample(..) |
                 PhysicalPlan::MonotonicallyIncreasingId(..) |
+                PhysicalPlan::Window(..) |
+                PhysicalPlan::Sort(Sort { within_partitions: true, .. }) |
                 PhysicalPlan::Pivot(..) |
                 PhysicalPlan::TabularWriteCsv(..) |
                 PhysicalPlan::TabularWriteJson(..) |
+                PhysicalPlan::TabularWriteIpc(..) |
                 PhysicalPlan::TabularWriteParquet(..) => Ok(Transformed::no(c.propagate())),
 
                 // the rest should have been dealt with earlier
@@ -10,6 +10,8 @@ use planner::PhysicalPlanTranslator;
 pub use planner::{AdaptivePlanner, MaterializedResults, QueryStageOutput, StageStats};
 
 use crate::{optimization::optimizer::PhysicalOptimizer, PhysicalPlanRef};
+mod stages;
+pub use stages::{split_into_stages, Stage, StageGraph, StageId, StageInput, StageOutput};
 mod translate;
 pub use translate::{extract_agg_expr, populate_aggregation_stages};
 
@@ -0,0 +1,263 @@
+use std::{collections::HashMap, sync::Arc};
+
+use common_error::{DaftError, DaftResult};
+use common_treenode::{Transformed, TreeNode};
+use daft_logical_plan::{ClusteringSpec, InMemoryInfo};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ops::{InMemoryScan, PreviousStageScan, ShuffleExchange, ShuffleExchangeStrategy},
+    PhysicalPlan, PhysicalPlanRef,
+};
+
+pub type StageId = usize;
+
+/// An edge of the stage graph: a stage reads the shuffled output of `stage_id`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageInput {
+    pub stage_id: StageId,
+    /// The partitioning of the input after the shuffle, i.e. as the consuming stage sees it.
+    pub clustering_spec: Arc<ClusteringSpec>,
+}
+
+/// What happens to a stage's output partitions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StageOutput {
+    /// The partitions are fanned out and merged with the given strategy for the downstream stages.
+    Shuffle(ShuffleExchangeStrategy),
+    /// The partitions are the results of the query.
+    Result,
+}
+
+/// A unit of work that can be run without exchanging data between partitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stage {
+    pub id: StageId,
+    /// The stage's plan, which reads its inputs through [`PreviousStageScan`]s.
+    pub plan: PhysicalPlanRef,
+    pub inputs: Vec<StageInput>,
+    pub output: StageOutput,
+    /// The partitioning of the output, after it has been shuffled if it is.
+    pub output_clustering_spec: Arc<ClusteringSpec>,
+}
+
+impl Stage {
+    /// The partitioning of the stage's output before it is shuffled.
+    pub fn clustering_spec(&self) -> Arc<ClusteringSpec> {
+        self.plan.clustering_spec()
+    }
+
+    /// The plan to run for this stage, given the materialized outputs of its input stages.
+    ///
+    /// Each [`PreviousStageScan`] is replaced with an in-memory scan of its input stage's output.
+    /// The plan's output still has to be exchanged as given by [`Stage::output`].
+    pub fn plan_with_inputs(
+        &self,
+        inputs: &HashMap<StageId, InMemoryInfo>,
+    ) -> DaftResult<PhysicalPlanRef> {
+        Ok(self
+            .plan
+            .clone()
+            .transform_up(|node| match node.as_ref() {
+                PhysicalPlan::PreviousStageScan(scan) => {
+                    let in_memory_info = scan
+                        .source_stage_id
+                        .and_then(|stage_id| inputs.get(&stage_id))
+                        .ok_or_else(|| {
+                            DaftError::InternalError(format!(
+                                "Missing the output of the input stage {:?} of stage {}",
+                                scan.source_stage_id, self.id
+                            ))
+                        })?;
+                    Ok(Transformed::yes(
+                        PhysicalPlan::InMemoryScan(InMemoryScan::new(
+                            in_memory_info.source_schema.clone(),
+                            in_memory_info.clone(),
+                            scan.clustering_spec().clone(),
+                        ))
+                        .arced(),
+                    ))
+                }
+                _ => Ok(Transformed::no(node)),
+            })?
+            .data)
+    }
+}
+
+/// A DAG of stages, in an order in which they can be run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StageGraph {
+    stages: Vec<Stage>,
+}
+
+impl StageGraph {
+    /// Stages in topological order: every stage comes after the stages it reads from.
+    pub fn stages(&self) -> &[Stage] {
+        &self.stages
+    }
+
+    pub fn get(&self, stage_id: StageId) -> Option<&Stage> {
+        self.stages.get(stage_id)
+    }
+
+    /// The stage that produces the query's results.
+    pub fn root(&self) -> &Stage {
+        self.stages
+            .last()
+            .expect("StageGraph should have at least one stage")
+    }
+
+    /// The stages that read the output of `stage_id`.
+    pub fn consumers(&self, stage_id: StageId) -> impl Iterator<Item = &Stage> {
+        self.stages
+            .iter()
+            .filter(move |stage| stage.inputs.iter().any(|input| input.stage_id == stage_id))
+    }
+
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+}
+
+/// Splits a physical plan into stages at its shuffle exchanges.
+///
+/// The input of each shuffle exchange becomes a stage whose output is shuffled, and the exchange
+/// itself is replaced with a [`PreviousStageScan`] of that stage in the stage that consumes it.
+pub fn split_into_stages(plan: &PhysicalPlanRef) -> DaftResult<StageGraph> {
+    let mut stages = vec![];
+    let (plan, inputs) = split_at_shuffles(plan, &mut stages)?;
+    let output_clustering_spec = plan.clustering_spec();
+    stages.push(Stage {
+        id: stages.len(),
+        plan,
+        inputs,
+        output: StageOutput::Result,
+        output_clustering_spec,
+    });
+    Ok(StageGraph { stages })
+}
+
+fn split_at_shuffles(
+    plan: &PhysicalPlanRef,
+    stages: &mut Vec<Stage>,
+) -> DaftResult<(PhysicalPlanRef, Vec<StageInput>)> {
+    if let PhysicalPlan::ShuffleExchange(shuffle_exchange @ ShuffleExchange { input, strategy }) =
+        plan.as_ref()
+    {
+        let (input, inputs) = split_at_shuffles(input, stages)?;
+        let stage_id = stages.len();
+        let clustering_spec = shuffle_exchange.clustering_spec();
+        stages.push(Stage {
+            id: stage_id,
+            plan: input,
+            inputs,
+            output: StageOutput::Shuffle(strategy.clone()),
+            output_clustering_spec: clustering_spec.clone(),
+        });
+        let scan = PhysicalPlan::PreviousStageScan(
+            PreviousStageScan::new(clustering_spec.clone()).with_source_stage_id(stage_id),
+        );
+        return Ok((
+            scan.arced(),
+            vec![StageInput {
+                stage_id,
+                clustering_spec,
+            }],
+        ));
+    }
+
+    let children = plan.children();
+    if children.is_empty() {
+        return Ok((plan.clone(), vec![]));
+    }
+    let mut new_children = Vec::with_capacity(children.len());
+    let mut inputs = vec![];
+    for child in children {
+        let (child, child_inputs) = split_at_shuffles(&Arc::new(child.clone()), stages)?;
+        new_children.push(child);
+        inputs.extend(child_inputs);
+    }
+    if inputs.is_empty() {
+        return Ok((plan.clone(), inputs));
+    }
+    Ok((plan.with_new_children(&new_children).arced(), inputs))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{assert_matches::assert_matches, sync::Arc};
+
+    use common_daft_config::DaftExecutionConfig;
+    use common_error::DaftResult;
+    use daft_core::{join::JoinStrategy, prelude::*};
+    use daft_dsl::resolved_col;
+    use daft_logical_plan::JoinType;
+
+    use super::{split_into_stages, StageOutput};
+    use crate::{
+        physical_planner::logical_to_physical,
+        test::{dummy_scan_node, dummy_scan_operator},
+        PhysicalPlan,
+    };
+
+    #[test]
+    fn test_split_hash_join_into_stages() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let fields = vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ];
+        let right = dummy_scan_node(dummy_scan_operator(fields.clone()))
+            .hash_repartition(Some(4), vec![resolved_col("b")])?;
+        let logical_plan = dummy_scan_node(dummy_scan_operator(fields))
+            .hash_repartition(Some(3), vec![resolved_col("b")])?
+            .join(
+                right,
+                vec![resolved_col("a")],
+                vec![resolved_col("a")],
+                JoinType::Inner,
+                Some(JoinStrategy::Hash),
+                Default::default(),
+            )?
+            .build();
+        let physical_plan = logical_to_physical(logical_plan, cfg)?;
+        let graph = split_into_stages(&physical_plan)?;
+
+        // Each side is shuffled twice: once by the repartition and once more for the join.
+        assert_eq!(graph.len(), 5);
+        let root = graph.root();
+        assert_matches!(root.output, StageOutput::Result);
+        assert_eq!(root.inputs.len(), 2);
+        for input in &root.inputs {
+            let input_stage = graph.get(input.stage_id).unwrap();
+            assert!(input_stage.id < root.id);
+            assert_matches!(input_stage.output, StageOutput::Shuffle(_));
+            assert_eq!(input.clustering_spec.num_partitions(), 4);
+            assert_eq!(graph.consumers(input_stage.id).count(), 1);
+        }
+        assert_matches!(
+            root.plan.as_ref(),
+            PhysicalPlan::HashJoin(join)
+                if matches!(join.left.as_ref(), PhysicalPlan::PreviousStageScan(_))
+                    && matches!(join.right.as_ref(), PhysicalPlan::PreviousStageScan(_))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_without_shuffles() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let logical_plan =
+            dummy_scan_node(dummy_scan_operator(vec![Field::new("a", DataType::Int64)])).build();
+        let physical_plan = logical_to_physical(logical_plan, cfg)?;
+        let graph = split_into_stages(&physical_plan)?;
+        assert_eq!(graph.len(), 1);
+        assert!(graph.root().inputs.is_empty());
+        assert_eq!(graph.root().plan, physical_plan);
+        Ok(())
+    }
+}
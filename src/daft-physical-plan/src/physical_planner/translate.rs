@@ -27,12 +27,14 @@ use daft_logical_plan::{
         MonotonicallyIncreasingId as LogicalMonotonicallyIncreasingId, Pivot as LogicalPivot,
         Project as LogicalProject, Repartition as LogicalRepartition, Sample as LogicalSample,
         Sink as LogicalSink, Sort as LogicalSort, Source, Unpivot as LogicalUnpivot,
+        Window as LogicalWindow,
     },
     partitioning::{
         ClusteringSpec, HashClusteringConfig, RangeClusteringConfig, UnknownClusteringConfig,
     },
     sink_info::{OutputFileInfo, SinkInfo},
     source_info::{PlaceHolderInfo, SourceInfo},
+    stats::{ApproxStats, StatsState},
 };
 
 use crate::{ops::*, PhysicalPlan, PhysicalPlanRef};
@@ -124,13 +126,36 @@ pub(super) fn translate_single_logical_node(
             predicate, input, ..
         }) => {
             let input_physical = physical_children.pop().expect("requires 1 input");
+            let num_input_partitions = input_physical.clustering_spec().num_partitions();
             let estimated_selectivity = estimated_selectivity(predicate, &input.schema());
-            Ok(PhysicalPlan::Filter(Filter::new(
+            let filter = PhysicalPlan::Filter(Filter::new(
                 input_physical,
                 predicate.clone(),
                 estimated_selectivity,
             ))
-            .arced())
+            .arced();
+            // Coalesce partitions that the filter is expected to leave much smaller than the target size.
+            let num_partitions = estimated_shuffle_partitions(
+                &filter.approximate_stats(),
+                num_input_partitions,
+                cfg,
+            );
+            if num_partitions < num_input_partitions {
+                Ok(PhysicalPlan::ShuffleExchange(
+                    ShuffleExchangeFactory::new(filter).get_split_or_coalesce(num_partitions),
+                )
+                .arced())
+            } else {
+                Ok(filter)
+            }
+        }
+        LogicalPlan::Assert(assert) => {
+            // Executed as a filter that fails or records violations instead of dropping rows.
+            let input_physical = physical_children.pop().expect("requires 1 input");
+            Ok(
+                PhysicalPlan::Filter(Filter::new(input_physical, assert.filter_predicate(), 1.0))
+                    .arced(),
+            )
         }
         LogicalPlan::Limit(LogicalLimit { limit, eager, .. }) => {
             let input_physical = physical_children.pop().expect("requires 1 input");
@@ -166,19 +191,34 @@ pub(super) fn translate_single_logical_node(
             .arced())
         }
         LogicalPlan::Sort(LogicalSort {
+            input,
             sort_by,
             descending,
             nulls_first,
+            within_partitions,
             ..
         }) => {
             let input_physical = physical_children.pop().expect("requires 1 input");
-            let num_partitions = input_physical.clustering_spec().num_partitions();
+            let num_partitions = if *within_partitions {
+                input_physical.clustering_spec().num_partitions()
+            } else {
+                estimated_shuffle_partitions(
+                    &input_physical.approximate_stats(),
+                    estimated_range_partitions(
+                        input,
+                        sort_by,
+                        input_physical.clustering_spec().num_partitions(),
+                    ),
+                    cfg,
+                )
+            };
             Ok(PhysicalPlan::Sort(Sort::new(
                 input_physical,
                 sort_by.clone(),
                 descending.clone(),
                 nulls_first.clone(),
                 num_partitions,
+                *within_partitions,
             ))
             .arced())
         }
@@ -226,8 +266,15 @@ pub(super) fn translate_single_logical_node(
                             ),
                         )
                     }
-                    ClusteringSpec::Range(_) => {
-                        unreachable!("Repartitioning by range is not supported")
+                    ClusteringSpec::Range(RangeClusteringConfig { by, descending, .. }) => {
+                        PhysicalPlan::ShuffleExchange(
+                            ShuffleExchangeFactory::new(input_physical).get_range_partitioning(
+                                by,
+                                descending,
+                                num_partitions,
+                                Some(cfg),
+                            ),
+                        )
                     }
                 };
                 Ok(repartitioned_plan.arced())
@@ -243,8 +290,13 @@ pub(super) fn translate_single_logical_node(
                 .collect::<Vec<ExprRef>>();
             let agg_op =
                 PhysicalPlan::Aggregate(Aggregate::new(input_physical, vec![], col_exprs.clone()));
-            let num_partitions = agg_op.clustering_spec().num_partitions();
-            if num_partitions > 1 {
+            let num_input_partitions = agg_op.clustering_spec().num_partitions();
+            if num_input_partitions > 1 {
+                let num_partitions = estimated_shuffle_partitions(
+                    &agg_op.approximate_stats(),
+                    num_input_partitions,
+                    cfg,
+                );
                 let shuffle_op = PhysicalPlan::ShuffleExchange(
                     ShuffleExchangeFactory::new(agg_op.into()).get_hash_partitioning(
                         col_exprs.clone(),
@@ -320,13 +372,18 @@ pub(super) fn translate_single_logical_node(
                         )
                         .into()
                     } else {
+                        let num_partitions = estimated_shuffle_partitions(
+                            &first_stage_agg.approximate_stats(),
+                            min(
+                                num_input_partitions,
+                                cfg.shuffle_aggregation_default_partitions,
+                            ),
+                            cfg,
+                        );
                         PhysicalPlan::ShuffleExchange(
                             ShuffleExchangeFactory::new(first_stage_agg).get_hash_partitioning(
                                 groupby.clone(),
-                                min(
-                                    num_input_partitions,
-                                    cfg.shuffle_aggregation_default_partitions,
-                                ),
+                                num_partitions,
                                 Some(cfg),
                             ),
                         )
@@ -388,15 +445,20 @@ pub(super) fn translate_single_logical_node(
                         )
                         .into()
                     } else {
+                        let num_partitions = estimated_shuffle_partitions(
+                            &first_stage_agg.approximate_stats(),
+                            min(
+                                num_input_partitions,
+                                cfg.shuffle_aggregation_default_partitions,
+                            ),
+                            cfg,
+                        );
                         PhysicalPlan::ShuffleExchange(
                             ShuffleExchangeFactory::new(first_stage_agg).get_hash_partitioning(
                                 // NOTE: For the shuffle of a pivot operation, we don't include the pivot column for the hashing as we need
                                 // to ensure that all rows with the same group_by column values are hashed to the same partition.
                                 group_by.clone(),
-                                min(
-                                    num_input_partitions,
-                                    cfg.shuffle_aggregation_default_partitions,
-                                ),
+                                num_partitions,
                                 Some(cfg),
                             ),
                         )
@@ -469,6 +531,10 @@ pub(super) fn translate_single_logical_node(
                         FileFormat::Warc => Err(common_error::DaftError::ValueError(
                             "Warc sink not yet implemented".to_string(),
                         )),
+                        FileFormat::ArrowIpc => Ok(PhysicalPlan::TabularWriteIpc(
+                            TabularWriteIpc::new(schema.clone(), file_info.clone(), input_physical),
+                        )
+                        .arced()),
                     }
                 }
                 #[cfg(feature = "python")]
@@ -508,6 +574,84 @@ pub(super) fn translate_single_logical_node(
                 .arced(),
             )
         }
+        LogicalPlan::Window(LogicalWindow {
+            window_functions,
+            window_spec,
+            ..
+        }) => {
+            let input_physical = physical_children.pop().expect("requires 1 input");
+            let num_partitions = input_physical.clustering_spec().num_partitions();
+            let input_clustering_spec = input_physical.clustering_spec();
+            // Every partition of the window has to be within a single partition of the input.
+            let partitioned = if window_spec.partition_by.is_empty() {
+                if num_partitions > 1 {
+                    PhysicalPlan::ShuffleExchange(
+                        ShuffleExchangeFactory::new(input_physical).get_split_or_coalesce(1),
+                    )
+                    .arced()
+                } else {
+                    input_physical
+                }
+            } else if num_partitions == 1
+                || (matches!(input_clustering_spec.as_ref(), ClusteringSpec::Hash(..))
+                    && is_partition_compatible(
+                        &input_clustering_spec.partition_by(),
+                        &window_spec.partition_by,
+                    ))
+            {
+                input_physical
+            } else {
+                PhysicalPlan::ShuffleExchange(
+                    ShuffleExchangeFactory::new(input_physical).get_hash_partitioning(
+                        window_spec.partition_by.clone(),
+                        num_partitions,
+                        Some(cfg),
+                    ),
+                )
+                .arced()
+            };
+            // The partitions of the window are then contiguous and ordered after sorting each partition.
+            let sort_by = window_spec
+                .partition_by
+                .iter()
+                .chain(&window_spec.order_by)
+                .cloned()
+                .collect::<Vec<_>>();
+            let sorted = if sort_by.is_empty() {
+                partitioned
+            } else {
+                let descending = std::iter::repeat_n(false, window_spec.partition_by.len())
+                    .chain(window_spec.descending.iter().copied())
+                    .collect::<Vec<_>>();
+                let nulls_first = std::iter::repeat_n(false, window_spec.partition_by.len())
+                    .chain(
+                        window_spec
+                            .descending
+                            .iter()
+                            .map(|desc| window_spec.null_strategy.nulls_first(*desc)),
+                    )
+                    .collect::<Vec<_>>();
+                let num_partitions = partitioned.clustering_spec().num_partitions();
+                PhysicalPlan::Sort(Sort::new(
+                    partitioned,
+                    sort_by,
+                    descending,
+                    nulls_first,
+                    num_partitions,
+                    true,
+                ))
+                .arced()
+            };
+            Ok(PhysicalPlan::Window(Window::new(
+                sorted,
+                window_functions.clone(),
+                window_spec.clone(),
+            ))
+            .arced())
+        }
+        LogicalPlan::Iterate(_) => Err(DaftError::NotImplemented(
+            "Iterating a plan is only supported on the native runner".to_string(),
+        )),
         LogicalPlan::Intersect(_) => Err(DaftError::InternalError(
             "Intersect should already be optimized away".to_string(),
         )),
@@ -983,6 +1127,66 @@ pub fn populate_aggregation_stages(
     (first_stage_aggs, second_stage_aggs, final_exprs)
 }
 
+/// Estimates how many of `num_partitions` range partitions of the output of `plan` by `sort_by` would hold any rows,
+/// from the histograms in its stats.
+fn estimated_range_partitions(
+    plan: &LogicalPlan,
+    sort_by: &[ExprRef],
+    num_partitions: usize,
+) -> usize {
+    match plan.stats_state() {
+        StatsState::Materialized(stats) => {
+            stats.estimated_range_partitions(sort_by, num_partitions)
+        }
+        StatsState::NotMaterialized => num_partitions,
+    }
+}
+
+/// The number of partitions to shuffle data with the approximate `stats` into, instead of the `num_partitions` that
+/// the planner would use otherwise.
+///
+/// With a target partition size configured, this is as many partitions as it takes to hold the data at that size, but
+/// no more than `num_partitions`. Data without a size estimate, such as the output of a previous stage, keeps
+/// `num_partitions`.
+fn estimated_shuffle_partitions(
+    stats: &ApproxStats,
+    num_partitions: usize,
+    cfg: &DaftExecutionConfig,
+) -> usize {
+    match cfg.target_partition_size_bytes {
+        Some(target_size_bytes) if stats.size_bytes > 0 => stats
+            .size_bytes
+            .div_ceil(target_size_bytes.max(1))
+            .clamp(1, num_partitions.max(1)),
+        _ => num_partitions,
+    }
+}
+
+/// If the clustering spec is range-partitioned in ascending order on a sequence of expressions that has the join keys
+/// `on` as a prefix in any order, the indices of the join keys in the order of that prefix.
+fn sort_partitioned_key_order(
+    clustering_spec: &ClusteringSpec,
+    on: &[ExprRef],
+) -> Option<Vec<usize>> {
+    let ClusteringSpec::Range(RangeClusteringConfig { by, descending, .. }) = clustering_spec
+    else {
+        return None;
+    };
+    if on.is_empty() || by.len() < on.len() {
+        return None;
+    }
+    // TODO(Clark): Add support for descending sort orders.
+    if descending[..on.len()].iter().any(|v| *v) {
+        return None;
+    }
+    let mut order: Vec<usize> = Vec::with_capacity(on.len());
+    for e in &by[..on.len()] {
+        let i = (0..on.len()).find(|i| &on[*i] == e && !order.contains(i))?;
+        order.push(i);
+    }
+    Some(order)
+}
+
 fn translate_join(
     physical_children: &mut Vec<Arc<PhysicalPlan>>,
     join_plan: &LogicalPlan,
@@ -1031,32 +1235,6 @@ fn translate_join(
         matches!(right_clustering_spec.as_ref(), ClusteringSpec::Hash(..))
             && is_partition_compatible(&right_clustering_spec.partition_by(), &right_on);
 
-    // Left-side of join is considered to be sort-partitioned on the join key if it is sort-partitioned on a
-    // sequence of expressions that has the join key as a prefix.
-    let is_left_sort_partitioned =
-        if let ClusteringSpec::Range(RangeClusteringConfig { by, descending, .. }) =
-            left_clustering_spec.as_ref()
-        {
-            by.len() >= left_on.len()
-                        && by.iter().zip(left_on.iter()).all(|(e1, e2)| e1 == e2)
-                        // TODO(Clark): Add support for descending sort orders.
-                        && descending.iter().all(|v| !*v)
-        } else {
-            false
-        };
-    // Right-side of join is considered to be sort-partitioned on the join key if it is sort-partitioned on a
-    // sequence of expressions that has the join key as a prefix.
-    let is_right_sort_partitioned =
-        if let ClusteringSpec::Range(RangeClusteringConfig { by, descending, .. }) =
-            right_clustering_spec.as_ref()
-        {
-            by.len() >= right_on.len()
-                        && by.iter().zip(right_on.iter()).all(|(e1, e2)| e1 == e2)
-                        // TODO(Clark): Add support for descending sort orders.
-                        && descending.iter().all(|v| !*v)
-        } else {
-            false
-        };
     let left_stats = left_physical.approximate_stats();
     let right_stats = right_physical.approximate_stats();
 
@@ -1066,6 +1244,24 @@ fn translate_join(
     } else {
         (left_stats.size_bytes, false)
     };
+
+    // A side of the join is considered to be sort-partitioned on the join keys if it is sort-partitioned on a
+    // sequence of expressions that has the join keys as a prefix, in any order. Since both sides of a sort-merge
+    // join are merged in the same key order, if the sides are sort-partitioned on the join keys in different orders,
+    // only the larger side is considered to be sort-partitioned.
+    let (is_left_sort_partitioned, is_right_sort_partitioned, sort_key_order) = match (
+        sort_partitioned_key_order(&left_clustering_spec, &left_on),
+        sort_partitioned_key_order(&right_clustering_spec, &right_on),
+    ) {
+        (Some(left_order), Some(right_order)) if left_order == right_order => {
+            (true, true, Some(left_order))
+        }
+        (Some(left_order), Some(_)) if left_is_larger => (true, false, Some(left_order)),
+        (Some(_), Some(right_order)) => (false, true, Some(right_order)),
+        (Some(left_order), None) => (true, false, Some(left_order)),
+        (None, Some(right_order)) => (false, true, Some(right_order)),
+        (None, None) => (false, false, None),
+    };
     let is_larger_partitioned = if left_is_larger {
         is_left_hash_partitioned || is_left_sort_partitioned
     } else {
@@ -1079,12 +1275,21 @@ fn translate_join(
             return JoinStrategy::Cross;
         }
 
-        fn keys_are_primitive(on: &[ExprRef], schema: &SchemaRef) -> bool {
+        fn keys_support_sort_merge(on: &[ExprRef], schema: &SchemaRef) -> bool {
             on.iter().all(|expr| {
                 let dtype = expr.get_type(schema).unwrap();
                 dtype.is_integer()
                     || dtype.is_floating()
-                    || matches!(dtype, DataType::Utf8 | DataType::Binary | DataType::Boolean)
+                    || matches!(
+                        dtype,
+                        DataType::Utf8
+                            | DataType::Binary
+                            | DataType::Boolean
+                            | DataType::Date
+                            | DataType::Time(..)
+                            | DataType::Timestamp(..)
+                            | DataType::Duration(..)
+                    )
             })
         }
 
@@ -1102,14 +1307,14 @@ fn translate_join(
         {
             JoinStrategy::Broadcast
         // Larger side of join is range-partitioned on the join column, so we use a sort-merge join.
-        // TODO(Clark): Support non-primitive dtypes for sort-merge join (e.g. temporal types).
+        // TODO(Clark): Support nested dtypes for sort-merge join.
         // TODO(Clark): Also do a sort-merge join if a downstream op needs the table to be sorted on the join key.
         // TODO(Clark): Look into defaulting to sort-merge join over hash join under more input partitioning setups.
         // TODO(Kevin): Support sort-merge join for other types of joins.
         // TODO(advancedxy): Rewrite null safe equals to support SMJ
         } else if *join_type == JoinType::Inner
-            && keys_are_primitive(&left_on, &left.schema())
-            && keys_are_primitive(&right_on, &right.schema())
+            && keys_support_sort_merge(&left_on, &left.schema())
+            && keys_support_sort_merge(&right_on, &right.schema())
             && (is_left_sort_partitioned || is_right_sort_partitioned)
             && (!is_larger_partitioned
                 || (left_is_larger && is_left_sort_partitioned
@@ -1249,6 +1454,29 @@ fn translate_join(
                     "Sort-merge join does not support null-safe equals yet".to_string(),
                 ));
             }
+            // Merge on the join keys in the order that the sort-partitioned side(s) are already sorted by.
+            let (left_on, right_on) = match &sort_key_order {
+                Some(order) => (
+                    order
+                        .iter()
+                        .map(|i| left_on[*i].clone())
+                        .collect::<Vec<_>>(),
+                    order
+                        .iter()
+                        .map(|i| right_on[*i].clone())
+                        .collect::<Vec<_>>(),
+                ),
+                None => (left_on, right_on),
+            };
+            // If neither side is range-partitioned yet, both are range-partitioned on boundaries over both of them.
+            let num_partitions = if !is_left_sort_partitioned && !is_right_sort_partitioned {
+                max(
+                    estimated_range_partitions(left, &left_on, num_partitions),
+                    estimated_range_partitions(right, &right_on, num_partitions),
+                )
+            } else {
+                num_partitions
+            };
 
             let needs_presort = if cfg.sort_merge_join_sort_with_aligned_boundaries {
                 // Use the special-purpose presorting that ensures join inputs are sorted with aligned
@@ -1265,6 +1493,7 @@ fn translate_join(
                         std::iter::repeat_n(false, left_on.len()).collect(),
                         std::iter::repeat_n(false, left_on.len()).collect(),
                         num_partitions,
+                        false,
                     ))
                     .arced();
                 }
@@ -1275,6 +1504,7 @@ fn translate_join(
                         std::iter::repeat_n(false, right_on.len()).collect(),
                         std::iter::repeat_n(false, right_on.len()).collect(),
                         num_partitions,
+                        false,
                     ))
                     .arced();
                 }
@@ -1306,7 +1536,11 @@ fn translate_join(
                 num_left_partitions,
                 num_right_partitions,
             ) {
-                (true, true, a, b) | (false, false, a, b) => max(a, b),
+                (true, true, a, b) => max(a, b),
+                // Neither side is partitioned yet, so both are shuffled into as many partitions as their data needs.
+                (false, false, a, b) => {
+                    estimated_shuffle_partitions(&(&left_stats + &right_stats), max(a, b), cfg)
+                }
                 (_, _, 1, x) | (_, _, x, 1) => x,
                 (true, false, a, b)
                     if (a as f64) >= (b as f64) * cfg.hash_join_partition_size_leniency =>
@@ -1397,12 +1631,18 @@ mod tests {
     use common_error::DaftResult;
     use daft_core::prelude::*;
     use daft_dsl::{lit, resolved_col};
-    use daft_logical_plan::LogicalPlanBuilder;
+    use daft_logical_plan::{
+        partitioning::{ClusteringSpec, HashClusteringConfig, RangeClusteringConfig},
+        LogicalPlanBuilder,
+    };
 
-    use super::HashJoin;
+    use super::{BroadcastJoin, HashJoin};
     use crate::{
         physical_planner::logical_to_physical,
-        test::{dummy_scan_node, dummy_scan_operator},
+        test::{
+            dummy_scan_node, dummy_scan_operator, dummy_scan_operator_with_size_bytes,
+            dummy_scan_operator_with_stats,
+        },
         PhysicalPlan, PhysicalPlanRef,
     };
 
@@ -1497,6 +1737,148 @@ mod tests {
         Ok(())
     }
 
+    /// Tests that a grouped aggregation of a multi-partition input is planned in two stages, with the partial
+    /// aggregations hash partitioned by the group keys before they're merged.
+    ///
+    /// Aggregation -> Project-Aggregation-ShuffleExchange-Aggregation
+    #[test]
+    fn grouped_agg_multi_partition_two_stages() -> DaftResult<()> {
+        let cfg = DaftExecutionConfig::default().into();
+        let logical_plan = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]))
+        .into_partitions(10)?
+        .aggregate(vec![resolved_col("a").mean()], vec![resolved_col("b")])?
+        .build();
+        let physical_plan = logical_to_physical(logical_plan, cfg)?;
+
+        let PhysicalPlan::Project(project) = physical_plan.as_ref() else {
+            panic!("expected a final projection, got {physical_plan:?}");
+        };
+        let PhysicalPlan::Aggregate(final_agg) = project.input.as_ref() else {
+            panic!("expected a final aggregation, got {:?}", project.input);
+        };
+        assert_eq!(final_agg.groupby, vec![resolved_col("b")]);
+        let PhysicalPlan::ShuffleExchange(shuffle) = final_agg.input.as_ref() else {
+            panic!("expected a shuffle, got {:?}", final_agg.input);
+        };
+        assert_matches!(
+            shuffle.clustering_spec().as_ref(),
+            ClusteringSpec::Hash(HashClusteringConfig { num_partitions: 10, by })
+                if by == &vec![resolved_col("b")]
+        );
+        let PhysicalPlan::Aggregate(partial_agg) = shuffle.input.as_ref() else {
+            panic!("expected a partial aggregation, got {:?}", shuffle.input);
+        };
+        assert_eq!(partial_agg.groupby, vec![resolved_col("b")]);
+        assert_eq!(partial_agg.input.clustering_spec().num_partitions(), 10);
+        Ok(())
+    }
+
+    /// Tests that with a target partition size, the partial aggregations of a grouped aggregation are shuffled into as
+    /// many partitions as their estimated size needs, but no more than the number of input partitions.
+    #[test]
+    fn grouped_agg_cost_based_num_partitions() -> DaftResult<()> {
+        let shuffle_num_partitions = |size_bytes: usize| -> DaftResult<usize> {
+            let cfg = DaftExecutionConfig {
+                target_partition_size_bytes: Some(1024 * 1024),
+                ..Default::default()
+            };
+            let logical_plan = dummy_scan_node(dummy_scan_operator_with_stats(
+                vec![
+                    Field::new("a", DataType::Int64),
+                    Field::new("b", DataType::Int64),
+                ],
+                Some(1000),
+                Some(size_bytes),
+            ))
+            .into_partitions(10)?
+            .aggregate(vec![resolved_col("a").sum()], vec![resolved_col("b")])?
+            .build();
+            let physical_plan = logical_to_physical(logical_plan, cfg.into())?;
+            let PhysicalPlan::Project(project) = physical_plan.as_ref() else {
+                panic!("expected a final projection, got {physical_plan:?}");
+            };
+            let PhysicalPlan::Aggregate(final_agg) = project.input.as_ref() else {
+                panic!("expected a final aggregation, got {:?}", project.input);
+            };
+            assert_matches!(final_agg.input.as_ref(), PhysicalPlan::ShuffleExchange(_));
+            Ok(final_agg.input.clustering_spec().num_partitions())
+        };
+        assert_eq!(shuffle_num_partitions(10 * 1024)?, 1);
+        assert_eq!(shuffle_num_partitions(100 * 1024 * 1024 * 1024)?, 10);
+        Ok(())
+    }
+
+    /// Tests that with a target partition size, partitions that a filter is expected to leave much smaller than the
+    /// target are coalesced, and that they aren't without one.
+    #[test]
+    fn filter_coalesces_small_partitions() -> DaftResult<()> {
+        let builder = dummy_scan_node(dummy_scan_operator_with_stats(
+            vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Utf8),
+            ],
+            Some(1000),
+            Some(10 * 1024),
+        ))
+        .into_partitions(10)?
+        .filter(resolved_col("a").lt(lit(2)))?;
+
+        let physical_plan = logical_to_physical(builder.build(), Default::default())?;
+        assert_matches!(physical_plan.as_ref(), PhysicalPlan::Filter(_));
+        assert_eq!(physical_plan.clustering_spec().num_partitions(), 10);
+
+        let cfg = DaftExecutionConfig {
+            target_partition_size_bytes: Some(1024 * 1024),
+            ..Default::default()
+        };
+        let physical_plan = logical_to_physical(builder.build(), cfg.into())?;
+        assert_matches!(
+            physical_plan.as_ref(),
+            PhysicalPlan::ShuffleExchange(shuffle)
+                if matches!(shuffle.input.as_ref(), PhysicalPlan::Filter(_))
+        );
+        assert_eq!(physical_plan.clustering_spec().num_partitions(), 1);
+        Ok(())
+    }
+
+    /// Tests that repartitioning by range is planned as a range shuffle, which is dropped if the input already has
+    /// the same range partitioning, e.g. from a sort.
+    #[test]
+    fn repartition_by_range() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let builder = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]))
+        .into_partitions(4)?
+        .range_repartition(Some(3), vec![resolved_col("a")], vec![true])?;
+        let physical_plan = logical_to_physical(builder.build(), cfg.clone())?;
+        assert_matches!(physical_plan.as_ref(), PhysicalPlan::ShuffleExchange(_));
+        assert_eq!(
+            physical_plan.clustering_spec().as_ref(),
+            &ClusteringSpec::Range(RangeClusteringConfig::new(
+                3,
+                vec![resolved_col("a")],
+                vec![true]
+            ))
+        );
+
+        let logical_plan = builder
+            .range_repartition(Some(3), vec![resolved_col("a")], vec![true])?
+            .build();
+        let physical_plan = logical_to_physical(logical_plan, cfg)?;
+        // Check that the last repartition was dropped.
+        assert_matches!(
+            physical_plan.as_ref(),
+            PhysicalPlan::ShuffleExchange(shuffle)
+                if !matches!(shuffle.input.as_ref(), PhysicalPlan::ShuffleExchange(_))
+        );
+        Ok(())
+    }
+
     #[derive(Debug, Clone, Copy)]
     enum RepartitionOptions {
         Good(usize),
@@ -1710,4 +2092,182 @@ mod tests {
         }
         Ok(())
     }
+
+    /// Helper function to plan an inner join on columns "a" and "b", without a join strategy, of two inputs that are
+    /// each range-partitioned by `left_by` and `right_by`.
+    fn get_range_partitioned_join_plan(
+        key_dtype: DataType,
+        left_by: Vec<&str>,
+        right_by: Vec<&str>,
+    ) -> DaftResult<Arc<PhysicalPlan>> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let range_partitioned = |by: Vec<&str>| {
+            let num_keys = by.len();
+            dummy_scan_node(dummy_scan_operator(vec![
+                Field::new("a", key_dtype.clone()),
+                Field::new("b", key_dtype.clone()),
+                Field::new("c", DataType::Int64),
+            ]))
+            .range_repartition(
+                Some(10),
+                by.into_iter().map(resolved_col).collect(),
+                vec![false; num_keys],
+            )
+        };
+        let logical_plan = range_partitioned(left_by)?
+            .join(
+                range_partitioned(right_by)?,
+                vec![resolved_col("a"), resolved_col("b")],
+                vec![resolved_col("a"), resolved_col("b")],
+                JoinType::Inner,
+                None,
+                Default::default(),
+            )?
+            .build();
+        logical_to_physical(logical_plan, cfg)
+    }
+
+    /// Tests that a sort-merge join is planned if both sides are already range-partitioned on the join keys, merging
+    /// on the join keys in the order that the sides are sorted by.
+    #[test]
+    fn sort_merge_join_range_partitioned_inputs() -> DaftResult<()> {
+        for key_dtype in [
+            DataType::Int64,
+            DataType::Utf8,
+            DataType::Date,
+            DataType::Timestamp(TimeUnit::Microseconds, None),
+        ] {
+            let physical_plan = get_range_partitioned_join_plan(
+                key_dtype.clone(),
+                vec!["b", "a"],
+                vec!["b", "a", "c"],
+            )?;
+            let PhysicalPlan::SortMergeJoin(join) = physical_plan.as_ref() else {
+                panic!("expected a sort-merge join on {key_dtype} keys, got {physical_plan:?}");
+            };
+            assert_eq!(join.left_on, vec![resolved_col("b"), resolved_col("a")]);
+            assert_eq!(join.right_on, vec![resolved_col("b"), resolved_col("a")]);
+            // Neither side needs to be sorted again.
+            assert!(!join.needs_presort);
+            assert_matches!(join.left.as_ref(), PhysicalPlan::ShuffleExchange(_));
+            assert_matches!(join.right.as_ref(), PhysicalPlan::ShuffleExchange(_));
+        }
+        Ok(())
+    }
+
+    /// Tests that if the sides are range-partitioned on the join keys in different orders, only the larger side is
+    /// considered to be sort-partitioned, and the other side is sorted again.
+    #[test]
+    fn sort_merge_join_range_partitioned_inputs_mismatch() -> DaftResult<()> {
+        let physical_plan =
+            get_range_partitioned_join_plan(DataType::Int64, vec!["a", "b"], vec!["b", "a"])?;
+        let PhysicalPlan::SortMergeJoin(join) = physical_plan.as_ref() else {
+            panic!("expected a sort-merge join, got {physical_plan:?}");
+        };
+        assert_eq!(join.left_on.len(), 2);
+        let resorted_side = if join.left_is_larger {
+            &join.right
+        } else {
+            &join.left
+        };
+        assert_matches!(resorted_side.as_ref(), PhysicalPlan::Sort(_));
+        Ok(())
+    }
+
+    /// Helper function to plan a join on column "a", without a join strategy, of inputs with the provided sizes.
+    fn get_sized_join_plan(
+        cfg: Arc<DaftExecutionConfig>,
+        join_type: JoinType,
+        left_size_bytes: usize,
+        right_size_bytes: usize,
+    ) -> DaftResult<Arc<PhysicalPlan>> {
+        let right = dummy_scan_node(dummy_scan_operator_with_size_bytes(
+            vec![
+                Field::new("a", DataType::Int64),
+                Field::new("c", DataType::Int64),
+            ],
+            Some(right_size_bytes),
+        ));
+        let logical_plan = dummy_scan_node(dummy_scan_operator_with_size_bytes(
+            vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Int64),
+            ],
+            Some(left_size_bytes),
+        ))
+        .join(
+            right,
+            vec![resolved_col("a")],
+            vec![resolved_col("a")],
+            join_type,
+            None,
+            Default::default(),
+        )?
+        .build();
+        logical_to_physical(logical_plan, cfg)
+    }
+
+    /// Tests that a broadcast join is planned if the smaller side of the join is under the broadcast size threshold
+    /// and isn't the side that is outer joined by, with the smaller side broadcast to the larger side.
+    #[test]
+    fn broadcast_join_small_side() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let large = 100 * 1024 * 1024;
+        let small = 1024;
+        for join_type in [
+            JoinType::Inner,
+            JoinType::Left,
+            JoinType::Semi,
+            JoinType::Anti,
+        ] {
+            let physical_plan = get_sized_join_plan(cfg.clone(), join_type, large, small)?;
+            let PhysicalPlan::BroadcastJoin(BroadcastJoin {
+                broadcaster,
+                receiver,
+                is_swapped,
+                ..
+            }) = physical_plan.as_ref()
+            else {
+                panic!("expected a {join_type} broadcast join, got {physical_plan:?}");
+            };
+            assert_eq!(broadcaster.approximate_stats().size_bytes, small);
+            assert_eq!(receiver.approximate_stats().size_bytes, large);
+            assert!(is_swapped);
+        }
+
+        let physical_plan = get_sized_join_plan(cfg, JoinType::Right, small, large)?;
+        let PhysicalPlan::BroadcastJoin(BroadcastJoin {
+            broadcaster,
+            is_swapped,
+            ..
+        }) = physical_plan.as_ref()
+        else {
+            panic!("expected a right broadcast join, got {physical_plan:?}");
+        };
+        assert_eq!(broadcaster.approximate_stats().size_bytes, small);
+        assert!(!is_swapped);
+        Ok(())
+    }
+
+    /// Tests that a broadcast join isn't planned if the smaller side of the join is over the broadcast size
+    /// threshold, or is the side that is outer joined by.
+    #[test]
+    fn broadcast_join_not_planned() -> DaftResult<()> {
+        let mut cfg = DaftExecutionConfig::default();
+        cfg.broadcast_join_size_bytes_threshold = 512;
+        let physical_plan = get_sized_join_plan(cfg.into(), JoinType::Inner, 1024 * 1024, 1024)?;
+        assert_matches!(physical_plan.as_ref(), PhysicalPlan::HashJoin(_));
+
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        for (join_type, left_size_bytes, right_size_bytes) in [
+            (JoinType::Left, 1024, 1024 * 1024),
+            (JoinType::Right, 1024 * 1024, 1024),
+            (JoinType::Outer, 1024 * 1024, 1024),
+        ] {
+            let physical_plan =
+                get_sized_join_plan(cfg.clone(), join_type, left_size_bytes, right_size_bytes)?;
+            assert_matches!(physical_plan.as_ref(), PhysicalPlan::HashJoin(_));
+        }
+        Ok(())
+    }
 }
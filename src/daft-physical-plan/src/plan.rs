@@ -29,6 +29,7 @@ pub enum PhysicalPlan {
     Sort(Sort),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
+    Window(Window),
     Aggregate(Aggregate),
     Pivot(Pivot),
     Concat(Concat),
@@ -39,6 +40,7 @@ pub enum PhysicalPlan {
     TabularWriteParquet(TabularWriteParquet),
     TabularWriteJson(TabularWriteJson),
     TabularWriteCsv(TabularWriteCsv),
+    TabularWriteIpc(TabularWriteIpc),
     ShuffleExchange(ShuffleExchange),
     #[cfg(feature = "python")]
     IcebergWrite(IcebergWrite),
@@ -86,7 +88,13 @@ impl PhysicalPlan {
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => {
                 input.clustering_spec()
             }
+            Self::Window(Window { input, .. }) => input.clustering_spec(),
 
+            Self::Sort(Sort {
+                input,
+                within_partitions: true,
+                ..
+            }) => input.clustering_spec(),
             Self::Sort(Sort {
                 input,
                 sort_by,
@@ -180,6 +188,7 @@ impl PhysicalPlan {
             }) => clustering_spec.clone(),
             Self::TabularWriteParquet(TabularWriteParquet { input, .. }) => input.clustering_spec(),
             Self::TabularWriteCsv(TabularWriteCsv { input, .. }) => input.clustering_spec(),
+            Self::TabularWriteIpc(TabularWriteIpc { input, .. }) => input.clustering_spec(),
             Self::TabularWriteJson(TabularWriteJson { input, .. }) => input.clustering_spec(),
             #[cfg(feature = "python")]
             Self::IcebergWrite(_) | Self::DeltaLakeWrite(_) | Self::LanceWrite(_) => {
@@ -264,6 +273,7 @@ impl PhysicalPlan {
             }
             Self::Project(Project { input, .. })
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. })
+            | Self::Window(Window { input, .. })
             | Self::ActorPoolProject(ActorPoolProject { input, .. }) => {
                 // TODO(sammy), we need the schema to estimate the new size per row
                 input.approximate_stats()
@@ -361,9 +371,10 @@ impl PhysicalPlan {
             }
             // Post-write DataFrame will contain paths to files that were written.
             // TODO(Clark): Estimate output size via root directory and estimates for # of partitions given partitioning column.
-            Self::TabularWriteParquet(_) | Self::TabularWriteCsv(_) | Self::TabularWriteJson(_) => {
-                ApproxStats::empty()
-            }
+            Self::TabularWriteParquet(_)
+            | Self::TabularWriteCsv(_)
+            | Self::TabularWriteJson(_)
+            | Self::TabularWriteIpc(_) => ApproxStats::empty(),
             #[cfg(feature = "python")]
             Self::IcebergWrite(_) | Self::DeltaLakeWrite(_) | Self::LanceWrite(_) => {
                 ApproxStats::empty()
@@ -387,6 +398,7 @@ impl PhysicalPlan {
             Self::Pivot(Pivot { input, .. }) => vec![input],
             Self::TabularWriteParquet(TabularWriteParquet { input, .. }) => vec![input],
             Self::TabularWriteCsv(TabularWriteCsv { input, .. }) => vec![input],
+            Self::TabularWriteIpc(TabularWriteIpc { input, .. }) => vec![input],
             Self::TabularWriteJson(TabularWriteJson { input, .. }) => vec![input],
             Self::ShuffleExchange(ShuffleExchange { input, .. }) => vec![input],
             #[cfg(feature = "python")]
@@ -409,6 +421,7 @@ impl PhysicalPlan {
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => {
                 vec![input]
             }
+            Self::Window(Window { input, .. }) => vec![input],
         }
     }
 
@@ -431,13 +444,15 @@ impl PhysicalPlan {
                 Self::Unpivot(Unpivot { ids, values, variable_name, value_name, .. }) => Self::Unpivot(Unpivot::new(input.clone(), ids.clone(), values.clone(), variable_name, value_name)),
                 Self::Pivot(Pivot { group_by, pivot_column, value_column, names, .. }) => Self::Pivot(Pivot::new(input.clone(), group_by.clone(), pivot_column.clone(), value_column.clone(), names.clone())),
                 Self::Sample(Sample { fraction, with_replacement, seed, .. }) => Self::Sample(Sample::new(input.clone(), *fraction, *with_replacement, *seed)),
-                Self::Sort(Sort { sort_by, descending, nulls_first,  num_partitions, .. }) => Self::Sort(Sort::new(input.clone(), sort_by.clone(), descending.clone(),nulls_first.clone(), *num_partitions)),
+                Self::Sort(Sort { sort_by, descending, nulls_first,  num_partitions, within_partitions, .. }) => Self::Sort(Sort::new(input.clone(), sort_by.clone(), descending.clone(),nulls_first.clone(), *num_partitions, *within_partitions)),
                 Self::ShuffleExchange(ShuffleExchange { strategy, .. }) => Self::ShuffleExchange(ShuffleExchange { input: input.clone(), strategy: strategy.clone() }),
                 Self::Aggregate(Aggregate { aggregations, groupby, ..}) => Self::Aggregate(Aggregate::new(input.clone(), aggregations.clone(), groupby.clone())),
                 Self::TabularWriteParquet(TabularWriteParquet { schema, file_info, .. }) => Self::TabularWriteParquet(TabularWriteParquet::new(schema.clone(), file_info.clone(), input.clone())),
                 Self::TabularWriteCsv(TabularWriteCsv { schema, file_info, .. }) => Self::TabularWriteCsv(TabularWriteCsv::new(schema.clone(), file_info.clone(), input.clone())),
+                Self::TabularWriteIpc(TabularWriteIpc { schema, file_info, .. }) => Self::TabularWriteIpc(TabularWriteIpc::new(schema.clone(), file_info.clone(), input.clone())),
                 Self::TabularWriteJson(TabularWriteJson { schema, file_info, .. }) => Self::TabularWriteJson(TabularWriteJson::new(schema.clone(), file_info.clone(), input.clone())),
                 Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { column_name, .. }) => Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId::new(input.clone(), column_name)),
+                Self::Window(Window { window_functions, window_spec, .. }) => Self::Window(Window::new(input.clone(), window_functions.clone(), window_spec.clone())),
                 #[cfg(feature = "python")]
                 Self::IcebergWrite(IcebergWrite { schema, iceberg_info, .. }) => Self::IcebergWrite(IcebergWrite::new(schema.clone(), iceberg_info.clone(), input.clone())),
                 #[cfg(feature = "python")]
@@ -493,8 +508,10 @@ impl PhysicalPlan {
             Self::Concat(..) => "Concat",
             Self::TabularWriteParquet(..) => "TabularWriteParquet",
             Self::TabularWriteCsv(..) => "TabularWriteCsv",
+            Self::TabularWriteIpc(..) => "TabularWriteIpc",
             Self::TabularWriteJson(..) => "TabularWriteJson",
             Self::MonotonicallyIncreasingId(..) => "MonotonicallyIncreasingId",
+            Self::Window(..) => "Window",
             #[cfg(feature = "python")]
             Self::IcebergWrite(..) => "IcebergWrite",
             #[cfg(feature = "python")]
@@ -531,10 +548,12 @@ impl PhysicalPlan {
                 tabular_write_parquet.multiline_display()
             }
             Self::TabularWriteCsv(tabular_write_csv) => tabular_write_csv.multiline_display(),
+            Self::TabularWriteIpc(tabular_write_ipc) => tabular_write_ipc.multiline_display(),
             Self::TabularWriteJson(tabular_write_json) => tabular_write_json.multiline_display(),
             Self::MonotonicallyIncreasingId(monotonically_increasing_id) => {
                 monotonically_increasing_id.multiline_display()
             }
+            Self::Window(window) => window.multiline_display(),
             #[cfg(feature = "python")]
             Self::IcebergWrite(iceberg_info) => iceberg_info.multiline_display(),
             #[cfg(feature = "python")]
@@ -550,6 +569,11 @@ impl PhysicalPlan {
         s
     }
 
+    /// The plan as a JSON tree, see [`JsonDisplay`](common_display::json::JsonDisplay).
+    pub fn repr_json(&self, simple: bool) -> String {
+        common_display::json::JsonDisplay::repr_json(self, simple)
+    }
+
     pub fn repr_indent(&self) -> String {
         let mut s = String::new();
         self.fmt_tree_indent_style(0, &mut s).unwrap();
@@ -6,11 +6,32 @@ use daft_schema::{field::Field, schema::Schema};
 
 /// Create a dummy scan node containing the provided fields in its schema and the provided limit.
 pub fn dummy_scan_operator(fields: Vec<Field>) -> ScanOperatorRef {
+    dummy_scan_operator_with_size_bytes(fields, None)
+}
+
+/// Create a dummy scan node containing the provided fields in its schema, and with the provided estimate of its
+/// in-memory size.
+pub fn dummy_scan_operator_with_size_bytes(
+    fields: Vec<Field>,
+    size_bytes_per_task: Option<usize>,
+) -> ScanOperatorRef {
+    dummy_scan_operator_with_stats(fields, None, size_bytes_per_task)
+}
+
+/// Create a dummy scan node containing the provided fields in its schema, and with the provided number of rows and
+/// estimate of its in-memory size.
+pub fn dummy_scan_operator_with_stats(
+    fields: Vec<Field>,
+    num_rows_per_task: Option<usize>,
+    size_bytes_per_task: Option<usize>,
+) -> ScanOperatorRef {
     let schema = Arc::new(Schema::new(fields).unwrap());
     ScanOperatorRef(Arc::new(DummyScanOperator {
         schema,
         num_scan_tasks: 1,
-        num_rows_per_task: None,
+        num_rows_per_task,
+        size_bytes_per_task,
+        can_absorb_aggregations: false,
     }))
 }
 
@@ -1,10 +1,14 @@
 use common_error::DaftResult;
 use daft_core::{
-    prelude::SchemaRef,
+    prelude::{Field, Schema, SchemaRef},
     series::Series,
     utils::arrow::{cast_array_for_daft_if_needed, cast_array_from_daft_if_needed},
 };
-use pyo3::{exceptions::PyValueError, prelude::*, types::PyList};
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyCapsule, PyList},
+};
 
 use crate::RecordBatch;
 
@@ -17,7 +21,7 @@ pub fn record_batches_to_table(
         return Ok(RecordBatch::empty(Some(schema))?);
     }
 
-    let names = schema.names();
+    let fields = schema.fields.values().collect::<Vec<_>>();
     let num_batches = batches.len();
     // First extract all the arrays at once while holding the GIL
     let mut extracted_arrow_arrays: Vec<(Vec<Box<dyn arrow2::array::Array>>, usize)> =
@@ -30,8 +34,8 @@ pub fn record_batches_to_table(
             .into_iter()
             .map(|col| common_arrow_ffi::array_to_rust(py, col))
             .collect::<PyResult<Vec<_>>>()?;
-        if names.len() != columns.len() {
-            return Err(PyValueError::new_err(format!("Error when converting Arrow Record Batches to Daft Table. Expected: {} columns, got: {}", names.len(), columns.len())));
+        if fields.len() != columns.len() {
+            return Err(PyValueError::new_err(format!("Error when converting Arrow Record Batches to Daft Table. Expected: {} columns, got: {}", fields.len(), columns.len())));
         }
         extracted_arrow_arrays.push((columns, rb.len()?));
     }
@@ -43,8 +47,12 @@ pub fn record_batches_to_table(
                 .into_iter()
                 .enumerate()
                 .map(|(i, array)| {
+                    // Keep the metadata of the schema's fields, such as units or extension names, on the columns.
+                    let schema_field = fields[i];
+                    let field = Field::new(schema_field.name.clone(), array.data_type().into())
+                        .with_metadata(schema_field.metadata.clone());
                     let cast_array = cast_array_for_daft_if_needed(array);
-                    Series::try_from((names.get(i).unwrap().as_str(), cast_array))
+                    Series::try_from_field_and_arrow_array(field, cast_array)
                 })
                 .collect::<DaftResult<Vec<_>>>()?;
             tables.push(RecordBatch::new_with_size(
@@ -80,3 +88,86 @@ pub fn table_to_record_batch(
 
     Ok(record.into())
 }
+
+/// Exports tables with the same schema to Python as an Arrow C stream of struct arrays, one per table.
+pub fn tables_to_arrow_c_stream<'py>(
+    py: Python<'py>,
+    tables: &[RecordBatch],
+    schema: &SchemaRef,
+) -> PyResult<Bound<'py, PyCapsule>> {
+    let mut arrow_fields = None;
+    let mut arrays = Vec::with_capacity(tables.len());
+    for table in tables {
+        let columns = table
+            .columns
+            .iter()
+            .map(|s| cast_array_from_daft_if_needed(s.to_arrow().to_boxed()))
+            .collect::<Vec<_>>();
+        let fields = arrow_fields.get_or_insert_with(|| {
+            columns
+                .iter()
+                .zip(schema.fields.values())
+                .map(|(column, field)| {
+                    arrow2::datatypes::Field::new(&field.name, column.data_type().clone(), true)
+                        .with_metadata((*field.metadata).clone())
+                })
+                .collect::<Vec<_>>()
+        });
+        let dtype = arrow2::datatypes::DataType::Struct(fields.clone());
+        arrays.push(arrow2::array::StructArray::new(dtype, columns, None).boxed());
+    }
+    // Without any tables, the stream's type comes from the schema alone.
+    let fields = match arrow_fields {
+        Some(fields) => fields,
+        None => schema
+            .fields
+            .values()
+            .map(|field| field.to_arrow())
+            .collect::<DaftResult<Vec<_>>>()?,
+    };
+    let field =
+        arrow2::datatypes::Field::new("", arrow2::datatypes::DataType::Struct(fields), false);
+    common_arrow_ffi::export_arrow_c_stream(py, field, arrays)
+}
+
+/// Imports an object that implements `__arrow_c_stream__` with a stream of struct arrays, such as a polars or pyarrow
+/// table, as a table whose columns are the fields of the structs.
+pub fn arrow_c_stream_to_table(py: Python, obj: &Bound<PyAny>) -> PyResult<RecordBatch> {
+    let (field, arrays) = common_arrow_ffi::import_arrow_c_stream(py, obj)?;
+    let arrow2::datatypes::DataType::Struct(arrow_fields) = field.data_type().to_logical_type()
+    else {
+        return Err(PyValueError::new_err(format!(
+            "Expected an Arrow C stream of struct arrays, got arrays of type {:?}",
+            field.data_type()
+        )));
+    };
+    let schema: SchemaRef = Schema::new(arrow_fields.iter().map(Field::from).collect())?.into();
+    py.allow_threads(|| {
+        let tables = arrays
+            .into_iter()
+            .map(|array| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<arrow2::array::StructArray>()
+                    .expect("Arrays of a struct stream should be struct arrays");
+                let columns = array
+                    .values()
+                    .iter()
+                    .zip(schema.fields.values())
+                    .map(|(column, field)| {
+                        Series::try_from_field_and_arrow_array(
+                            field.clone(),
+                            cast_array_for_daft_if_needed(column.clone()),
+                        )
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                // Nulls of the structs themselves aren't rows of a table, so they're ignored.
+                RecordBatch::new_with_size(schema.clone(), columns, array.len())
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        if tables.is_empty() {
+            return Ok(RecordBatch::empty(Some(schema))?);
+        }
+        Ok(RecordBatch::concat(tables.as_slice())?)
+    })
+}
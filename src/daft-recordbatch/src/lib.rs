@@ -21,8 +21,9 @@ use daft_core::{
     prelude::*,
 };
 use daft_dsl::{
-    functions::FunctionEvaluator, null_lit, resolved_col, AggExpr, ApproxPercentileParams, Column,
-    Expr, ExprRef, LiteralValue, PlanRef, ResolvedColumn, SketchType, UnresolvedColumn,
+    functions::FunctionEvaluator, null_lit, resolved_col, AggExpr, ApproxPercentileParams,
+    BoundColumn, Column, Expr, ExprRef, LiteralValue, PlanRef, ResolvedColumn, SketchType,
+    UnresolvedColumn,
 };
 use daft_logical_plan::FileInfos;
 use futures::{StreamExt, TryStreamExt};
@@ -62,6 +63,32 @@ impl Hash for RecordBatch {
     }
 }
 
+/// Applies a binary operator to its evaluated operands.
+fn eval_binary_op(op: &daft_dsl::Operator, lhs: Series, rhs: Series) -> DaftResult<Series> {
+    use daft_core::array::ops::{DaftCompare, DaftLogical};
+    use daft_dsl::Operator::*;
+    match op {
+        Plus => lhs + rhs,
+        Minus => lhs - rhs,
+        TrueDivide => lhs / rhs,
+        FloorDivide => lhs.floor_div(&rhs),
+        Multiply => lhs * rhs,
+        Modulus => lhs % rhs,
+        Lt => Ok(lhs.lt(&rhs)?.into_series()),
+        LtEq => Ok(lhs.lte(&rhs)?.into_series()),
+        Eq => Ok(lhs.equal(&rhs)?.into_series()),
+        EqNullSafe => Ok(lhs.eq_null_safe(&rhs)?.into_series()),
+        NotEq => Ok(lhs.not_equal(&rhs)?.into_series()),
+        GtEq => Ok(lhs.gte(&rhs)?.into_series()),
+        Gt => Ok(lhs.gt(&rhs)?.into_series()),
+        And => lhs.and(&rhs),
+        Or => lhs.or(&rhs),
+        Xor => lhs.xor(&rhs),
+        ShiftLeft => lhs.shift_left(&rhs),
+        ShiftRight => lhs.shift_right(&rhs),
+    }
+}
+
 #[inline]
 fn validate_schema(schema: &Schema, columns: &[Series]) -> DaftResult<()> {
     if schema.fields.len() != columns.len() {
@@ -543,6 +570,11 @@ impl RecordBatch {
             Expr::Cast(child, dtype) => self.eval_expression(child)?.cast(dtype),
             // TODO: remove ability to evaluate on unresolved col once we fix all tests
             Expr::Column(Column::Resolved(ResolvedColumn::Basic(name))) | Expr::Column(Column::Unresolved(UnresolvedColumn { name, plan_ref: PlanRef::Unqualified, plan_schema: None })) => self.get_column(name).cloned(),
+            Expr::Column(Column::Bound(BoundColumn { index, field })) => match self.columns.get(*index) {
+                Some(column) if column.name() == field.name => Ok(column.clone()),
+                // The batch doesn't have the schema that the expression was bound to, so fall back to the name.
+                _ => self.get_column(&field.name).cloned(),
+            },
             Expr::Not(child) => !(self.eval_expression(child)?),
             Expr::IsNull(child) => self.eval_expression(child)?.is_null(),
             Expr::NotNull(child) => self.eval_expression(child)?.not_null(),
@@ -581,31 +613,21 @@ impl RecordBatch {
             Expr::Between(child, lower, upper) => self
                 .eval_expression(child)?
                 .between(&self.eval_expression(lower)?, &self.eval_expression(upper)?),
-            Expr::BinaryOp { op, left, right } => {
-                let lhs = self.eval_expression(left)?;
-                let rhs = self.eval_expression(right)?;
-                use daft_core::array::ops::{DaftCompare, DaftLogical};
-                use daft_dsl::Operator::*;
-                match op {
-                    Plus => lhs + rhs,
-                    Minus => lhs - rhs,
-                    TrueDivide => lhs / rhs,
-                    FloorDivide => lhs.floor_div(&rhs),
-                    Multiply => lhs * rhs,
-                    Modulus => lhs % rhs,
-                    Lt => Ok(lhs.lt(&rhs)?.into_series()),
-                    LtEq => Ok(lhs.lte(&rhs)?.into_series()),
-                    Eq => Ok(lhs.equal(&rhs)?.into_series()),
-                    EqNullSafe => Ok(lhs.eq_null_safe(&rhs)?.into_series()),
-                    NotEq => Ok(lhs.not_equal(&rhs)?.into_series()),
-                    GtEq => Ok(lhs.gte(&rhs)?.into_series()),
-                    Gt => Ok(lhs.gt(&rhs)?.into_series()),
-                    And => lhs.and(&rhs),
-                    Or => lhs.or(&rhs),
-                    Xor => lhs.xor(&rhs),
-                    ShiftLeft => lhs.shift_left(&rhs),
-                    ShiftRight => lhs.shift_right(&rhs),
+            Expr::BinaryOp { .. } => {
+                // Walk down the left operands of long chains of binary operators, like generated disjunctions of
+                // thousands of predicates, iteratively rather than recursively to not overflow the stack.
+                let mut spine = vec![];
+                let mut leftmost = expr;
+                while let Expr::BinaryOp { op, left, right } = leftmost {
+                    spine.push((op, right));
+                    leftmost = left.as_ref();
                 }
+                spine
+                    .into_iter()
+                    .rev()
+                    .try_fold(self.eval_expression(leftmost)?, |lhs, (op, right)| {
+                        eval_binary_op(op, lhs, self.eval_expression(right)?)
+                    })
             }
             Expr::Function { func, inputs } => {
                 let evaluated_inputs = inputs
@@ -620,7 +642,8 @@ impl RecordBatch {
                     .iter()
                     .map(|e| self.eval_expression(e))
                     .collect::<DaftResult<Vec<_>>>()?;
-                func.udf.evaluate(evaluated_inputs.as_slice())
+                func.udf
+                    .evaluate_with_num_rows(evaluated_inputs.as_slice(), self.len())
             }
             Expr::Literal(lit_value) => Ok(lit_value.to_series()),
             Expr::IfElse {
@@ -648,6 +671,9 @@ impl RecordBatch {
             Expr::Exists(_subquery) => Err(DaftError::ComputeError(
                 "EXISTS <SUBQUERY> should be optimized away before evaluation. This indicates a bug in the query optimizer.".to_string(),
             )),
+            Expr::Window(..) => Err(DaftError::ComputeError(
+                "Window functions should be planned as window operators before evaluation. This indicates a bug in the query planner.".to_string(),
+            )),
             Expr::Column(Column::Resolved(ResolvedColumn::OuterRef(Field { name, .. }))) => Err(DaftError::ComputeError(
                 format!("Outer reference columns should be eliminated before evaluation. This indicates either that column {name} does not exist in the table, or there is a bug in the query optimizer."),
             )),
@@ -997,7 +1023,7 @@ impl PartialEq for RecordBatch {
 impl Display for RecordBatch {
     // `f` is a buffer, and this method must write the formatted string into it
     fn fmt(&self, f: &mut Formatter) -> Result {
-        let table = self.to_comfy_table(Some(32));
+        let table = self.to_comfy_table(Some(30));
         writeln!(f, "{table}")
     }
 }
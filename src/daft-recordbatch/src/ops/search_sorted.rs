@@ -18,7 +18,8 @@ impl RecordBatch {
             return Err(DaftError::ValueError(format!("Mismatch in number of arguments for `descending` in search sorted: num_columns: {} vs : descending.len() {}", self.num_columns(), descending.len())));
         }
 
-        if self.num_columns() == 1 {
+        // Nested types only have comparators, which the multi-column search uses.
+        if self.num_columns() == 1 && !self.get_column_by_index(0)?.data_type().is_nested() {
             return self
                 .get_column_by_index(0)?
                 .search_sorted(keys.get_column_by_index(0)?, *descending.first().unwrap());
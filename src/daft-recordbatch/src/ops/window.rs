@@ -0,0 +1,210 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{array::ops::arrow2::comparison::build_multi_array_is_equal, prelude::*};
+use daft_dsl::{Expr, ExprRef, NullStrategy, OffsetWindowParams, WindowExpr};
+
+use crate::RecordBatch;
+
+impl RecordBatch {
+    /// Appends the results of window functions to the rows of this table, which must already be sorted by the
+    /// `partition_by` and then the `order_by` expressions of their window.
+    ///
+    /// Every window function must be aliased to the name of the column of its results. With
+    /// [`NullStrategy::Skip`], the window functions are computed over only the rows without null `order_by` values,
+    /// and are null for the other rows.
+    pub fn window(
+        &self,
+        window_functions: &[ExprRef],
+        partition_by: &[ExprRef],
+        order_by: &[ExprRef],
+        null_strategy: NullStrategy,
+    ) -> DaftResult<Self> {
+        let results = match null_strategy {
+            NullStrategy::Skip if !order_by.is_empty() => {
+                self.window_results_skipping_nulls(window_functions, partition_by, order_by)?
+            }
+            _ => self.window_results(window_functions, partition_by, order_by)?,
+        };
+        self.union(&Self::from_nonempty_columns(results)?)
+    }
+
+    /// The results of window functions over the rows without null `order_by` values, scattered back to the rows of
+    /// this table with nulls for the rows with null `order_by` values.
+    fn window_results_skipping_nulls(
+        &self,
+        window_functions: &[ExprRef],
+        partition_by: &[ExprRef],
+        order_by: &[ExprRef],
+    ) -> DaftResult<Vec<Series>> {
+        let keys = self.eval_expression_list(order_by)?;
+        let mut is_kept = vec![true; self.len()];
+        for key in &keys.columns {
+            for (kept, not_null) in is_kept.iter_mut().zip(key.not_null()?.bool()?.as_arrow()) {
+                *kept &= not_null.unwrap_or(false);
+            }
+        }
+        if is_kept.iter().all(|kept| *kept) {
+            return self.window_results(window_functions, partition_by, order_by);
+        }
+
+        // Skipping rows keeps the others sorted, so the window functions can be computed over just the kept rows.
+        let kept_indices = is_kept
+            .iter()
+            .enumerate()
+            .filter(|(_, kept)| **kept)
+            .map(|(i, _)| i as u64)
+            .collect::<Vec<_>>();
+        let kept_rows = self.take(&UInt64Array::from(("kept", kept_indices)).into_series())?;
+        let mut num_kept = 0;
+        let scatter_indices = is_kept.iter().map(|kept| {
+            kept.then(|| {
+                num_kept += 1;
+                num_kept - 1
+            })
+        });
+        let scatter_indices =
+            UInt64Array::from_iter(Field::new("indices", DataType::UInt64), scatter_indices)
+                .into_series();
+        kept_rows
+            .window_results(window_functions, partition_by, order_by)?
+            .iter()
+            .map(|results| results.take(&scatter_indices))
+            .collect()
+    }
+
+    /// The results of window functions over all the rows of this table.
+    fn window_results(
+        &self,
+        window_functions: &[ExprRef],
+        partition_by: &[ExprRef],
+        order_by: &[ExprRef],
+    ) -> DaftResult<Vec<Series>> {
+        // The index of the first row of the partition of every row, and of the first row that it ties with in the
+        // order of its partition.
+        let partition_starts = self.run_starts(partition_by, None)?;
+        let peer_starts = self.run_starts(order_by, Some(&partition_starts))?;
+        let partition_ends = run_ends(&partition_starts);
+
+        window_functions
+            .iter()
+            .map(|function| {
+                let (window_expr, name) = unalias_window_function(function)?;
+                let result = match window_expr {
+                    WindowExpr::RowNumber => {
+                        let row_numbers = partition_starts
+                            .iter()
+                            .enumerate()
+                            .map(|(i, start)| (i - start + 1) as u64)
+                            .collect::<Vec<_>>();
+                        UInt64Array::from((name, row_numbers)).into_series()
+                    }
+                    WindowExpr::Rank => {
+                        let ranks = partition_starts
+                            .iter()
+                            .zip(&peer_starts)
+                            .map(|(start, peer_start)| (peer_start - start + 1) as u64)
+                            .collect::<Vec<_>>();
+                        UInt64Array::from((name, ranks)).into_series()
+                    }
+                    WindowExpr::Lag(params) => {
+                        let indices = partition_starts
+                            .iter()
+                            .enumerate()
+                            .map(|(i, start)| i.checked_sub(params.offset).filter(|j| j >= start));
+                        self.offset_values(params, indices, name)?
+                    }
+                    WindowExpr::Lead(params) => {
+                        let indices = partition_ends
+                            .iter()
+                            .enumerate()
+                            .map(|(i, end)| i.checked_add(params.offset).filter(|j| j < end));
+                        self.offset_values(params, indices, name)?
+                    }
+                };
+                Ok(result)
+            })
+            .collect()
+    }
+
+    /// For every row, the index of the first row of the run of consecutive rows with equal values of `exprs` that it
+    /// belongs to, where runs also end wherever the runs of `outer_starts` do.
+    fn run_starts(
+        &self,
+        exprs: &[ExprRef],
+        outer_starts: Option<&[usize]>,
+    ) -> DaftResult<Vec<usize>> {
+        let num_rows = self.len();
+        if exprs.is_empty() {
+            return Ok(match outer_starts {
+                Some(outer_starts) => outer_starts.to_vec(),
+                None => vec![0; num_rows],
+            });
+        }
+        let keys = self.eval_expression_list(exprs)?;
+        let is_equal = build_multi_array_is_equal(
+            keys.columns.as_slice(),
+            keys.columns.as_slice(),
+            vec![true; keys.num_columns()].as_slice(),
+            vec![true; keys.num_columns()].as_slice(),
+        )?;
+        let mut starts = Vec::with_capacity(num_rows);
+        for i in 0..num_rows {
+            let continues_run = i > 0
+                && outer_starts.is_none_or(|outer_starts| outer_starts[i] == outer_starts[i - 1])
+                && is_equal(i - 1, i);
+            starts.push(if continues_run { starts[i - 1] } else { i });
+        }
+        Ok(starts)
+    }
+
+    /// The values of the child of a lag or lead at `indices`, or its default where there is no row at the offset.
+    fn offset_values(
+        &self,
+        params: &OffsetWindowParams,
+        indices: impl Iterator<Item = Option<usize>>,
+        name: &str,
+    ) -> DaftResult<Series> {
+        let indices = indices.map(|i| i.map(|i| i as u64)).collect::<Vec<_>>();
+        let missing = indices.iter().map(Option::is_none).collect::<Vec<_>>();
+        let indices =
+            UInt64Array::from_iter(Field::new("indices", DataType::UInt64), indices.into_iter());
+        let values = self
+            .eval_expression(&params.child)?
+            .take(&indices.into_series())?;
+        let values = match &params.default {
+            // The default is only used for missing rows, rather than for every null value of the child.
+            Some(default) => {
+                let default = self.eval_expression(default)?;
+                let missing = BooleanArray::from(("missing", missing.as_slice())).into_series();
+                default.if_else(&values, &missing)?
+            }
+            None => values,
+        };
+        Ok(values.rename(name))
+    }
+}
+
+/// For every row, the index after the last row of its run, given the indices of the first rows of the runs.
+fn run_ends(starts: &[usize]) -> Vec<usize> {
+    let mut ends = vec![0; starts.len()];
+    let mut end = starts.len();
+    for i in (0..starts.len()).rev() {
+        ends[i] = end;
+        if starts[i] == i {
+            end = i;
+        }
+    }
+    ends
+}
+
+/// The window function of an aliased window function, and the name of the column of its results.
+fn unalias_window_function(function: &ExprRef) -> DaftResult<(&WindowExpr, &str)> {
+    if let Expr::Alias(inner, name) = function.as_ref()
+        && let Expr::Window(window_expr, _) = inner.as_ref()
+    {
+        Ok((window_expr, name.as_ref()))
+    } else {
+        Err(DaftError::ValueError(format!(
+            "Expected an aliased window function, but received {function}"
+        )))
+    }
+}
@@ -7,7 +7,7 @@ use daft_core::{
 use daft_dsl::python::PyExpr;
 use daft_logical_plan::FileInfos;
 use indexmap::IndexMap;
-use pyo3::{exceptions::PyValueError, prelude::*};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyCapsule};
 
 use crate::{ffi, RecordBatch};
 
@@ -442,6 +442,24 @@ impl PyRecordBatch {
         Ok(Self { table })
     }
 
+    /// Creates a table from an object that implements the Arrow PyCapsule Interface, such as a polars DataFrame.
+    #[staticmethod]
+    pub fn from_arrow_c_stream(py: Python, obj: Bound<PyAny>) -> PyResult<Self> {
+        let table = ffi::arrow_c_stream_to_table(py, &obj)?;
+        Ok(Self { table })
+    }
+
+    #[pyo3(signature = (requested_schema=None))]
+    pub fn __arrow_c_stream__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<PyObject>,
+    ) -> PyResult<Bound<'py, PyCapsule>> {
+        // Requested schemas are only a hint for the producer, so the table is exported as is.
+        let _ = requested_schema;
+        ffi::tables_to_arrow_c_stream(py, &[self.table.clone()], &self.table.schema)
+    }
+
     #[staticmethod]
     pub fn from_pylist_series(dict: IndexMap<String, PySeries>) -> PyResult<Self> {
         let mut fields: Vec<Field> = Vec::new();
@@ -2,7 +2,10 @@ use std::sync::Arc;
 
 use common_error::DaftResult;
 use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
-use common_scan_info::{PartitionField, Pushdowns, ScanOperator, ScanTaskLike, ScanTaskLikeRef};
+use common_scan_info::{
+    DatasetStatistics, PartitionField, Pushdowns, ScanOperator, ScanTaskLike, ScanTaskLikeRef,
+};
+use daft_dsl::ExprRef;
 use daft_schema::schema::SchemaRef;
 
 use crate::{storage_config::StorageConfig, ChunkSpec, DataSource, ScanTask};
@@ -61,6 +64,14 @@ impl ScanOperator for AnonymousScanOperator {
         false
     }
 
+    fn can_absorb_aggregation(
+        &self,
+        _aggregation: &ExprRef,
+        _pushdowns: &Pushdowns,
+    ) -> DaftResult<bool> {
+        Ok(false)
+    }
+
     fn multiline_display(&self) -> Vec<String> {
         let mut lines = vec![
             "AnonymousScanOperator".to_string(),
@@ -72,6 +83,10 @@ impl ScanOperator for AnonymousScanOperator {
         lines
     }
 
+    fn statistics(&self) -> Option<Arc<DatasetStatistics>> {
+        None
+    }
+
     fn to_scan_tasks(&self, pushdowns: Pushdowns) -> DaftResult<Vec<ScanTaskLikeRef>> {
         let files = self.files.clone();
         let file_format_config = self.file_format_config.clone();
@@ -100,6 +115,7 @@ impl ScanOperator for AnonymousScanOperator {
                         chunk_spec,
                         size_bytes: None,
                         iceberg_delete_files: None,
+                        deletion_vector: None,
                         metadata: None,
                         partition_spec: None,
                         statistics: None,
@@ -25,6 +25,7 @@ pub struct ParquetScanBuilder {
     pub multithreaded: bool,
     pub schema: Option<SchemaRef>,
     pub file_path_column: Option<String>,
+    pub row_index_column: Option<String>,
     pub hive_partitioning: bool,
 }
 
@@ -47,6 +48,7 @@ impl ParquetScanBuilder {
             schema: None,
             io_config: None,
             file_path_column: None,
+            row_index_column: None,
             hive_partitioning: false,
         }
     }
@@ -91,6 +93,11 @@ impl ParquetScanBuilder {
         self
     }
 
+    pub fn row_index_column(mut self, row_index_column: String) -> Self {
+        self.row_index_column = Some(row_index_column);
+        self
+    }
+
     pub fn hive_partitioning(mut self, hive_partitioning: bool) -> Self {
         self.hive_partitioning = hive_partitioning;
         self
@@ -102,6 +109,7 @@ impl ParquetScanBuilder {
             field_id_mapping: self.field_id_mapping,
             row_groups: self.row_groups,
             chunk_size: self.chunk_size,
+            row_index_column: self.row_index_column,
         };
 
         let operator = Arc::new(
@@ -371,10 +379,7 @@ pub fn delta_scan<T: AsRef<str>>(
     Python::with_gil(|py| {
         let io_config = io_config.unwrap_or_default();
 
-        let storage_config = StorageConfig {
-            io_config: Some(io_config),
-            multithreaded_io,
-        };
+        let storage_config = StorageConfig::new_internal(multithreaded_io, Some(io_config));
 
         // let py_io_config = PyIOConfig { config: io_config };
         let delta_lake_scan = PyModule::import(py, "daft.delta_lake.delta_lake_scan")?;
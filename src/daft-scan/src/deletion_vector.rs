@@ -0,0 +1,367 @@
+//! Delta Lake deletion vectors, which mark the rows of a data file that were deleted without rewriting the file.
+//!
+//! See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#deletion-vectors> for the format.
+
+use std::{fmt::Write, sync::Arc};
+
+use common_error::{DaftError, DaftResult};
+use daft_io::{IOClient, IOStatsRef};
+use serde::{Deserialize, Serialize};
+
+/// The descriptor of a deletion vector in the `deletionVector` field of an add action of the transaction log.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", derive(pyo3::FromPyObject), pyo3(from_item_all))]
+pub struct DeletionVectorDescriptor {
+    /// The root of the table, which the paths of deletion vectors stored next to the table are relative to.
+    pub table_uri: String,
+    /// Where the deletion vector is stored: `u` in a file in the table, `p` in a file at an absolute path, or `i`
+    /// inline in the descriptor.
+    pub storage_type: String,
+    /// The encoded UUID of the file, the absolute path of the file, or the encoded deletion vector itself.
+    pub path_or_inline_dv: String,
+    /// The position of the deletion vector in its file.
+    pub offset: Option<i32>,
+    /// The size of the serialized deletion vector.
+    pub size_in_bytes: i32,
+    /// The number of deleted rows.
+    pub cardinality: i64,
+}
+
+/// The magic number that serialized deletion vectors start with.
+const DELETION_VECTOR_MAGIC: u32 = 1_681_511_377;
+
+impl DeletionVectorDescriptor {
+    /// Reads the positions of the deleted rows in the data file, in ascending order.
+    pub async fn read_deleted_rows(
+        &self,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<Vec<i64>> {
+        let size = usize::try_from(self.size_in_bytes).map_err(|_| {
+            DaftError::ValueError(format!(
+                "Invalid deletion vector size: {}",
+                self.size_in_bytes
+            ))
+        })?;
+        let data = match self.storage_type.as_str() {
+            "i" => {
+                let mut data = z85_decode(&self.path_or_inline_dv)?;
+                data.truncate(size);
+                data
+            }
+            "u" | "p" => {
+                let path = self.file_path()?;
+                // Every deletion vector in the file is prefixed by its size and followed by its checksum.
+                let offset = self.offset.unwrap_or(1) as usize;
+                let bytes = io_client
+                    .single_url_get(path.clone(), Some(offset..offset + 4 + size), io_stats)
+                    .await?
+                    .bytes()
+                    .await?;
+                let stored_size = bytes
+                    .get(..4)
+                    .map(|prefix| u32::from_be_bytes(prefix.try_into().unwrap()) as usize);
+                if stored_size != Some(size) || bytes.len() < 4 + size {
+                    return Err(DaftError::ValueError(format!(
+                        "Deletion vector at offset {offset} of {path} doesn't have the expected size of {size} bytes"
+                    )));
+                }
+                bytes[4..4 + size].to_vec()
+            }
+            storage_type => {
+                return Err(DaftError::ValueError(format!(
+                    "Unsupported deletion vector storage type: {storage_type}"
+                )))
+            }
+        };
+        let positions = deserialize_deletion_vector(&data)?;
+        if positions.len() as i64 != self.cardinality {
+            return Err(DaftError::ValueError(format!(
+                "Expected {} deleted rows in deletion vector, but found {}",
+                self.cardinality,
+                positions.len()
+            )));
+        }
+        Ok(positions)
+    }
+
+    /// The path of the file that the deletion vector is stored in.
+    fn file_path(&self) -> DaftResult<String> {
+        if self.storage_type == "p" {
+            return Ok(self.path_or_inline_dv.clone());
+        }
+        // The last 20 characters encode the UUID of the file, and any before them are a directory of the table.
+        let split = self
+            .path_or_inline_dv
+            .len()
+            .checked_sub(20)
+            .filter(|split| self.path_or_inline_dv.is_char_boundary(*split))
+            .ok_or_else(|| {
+                DaftError::ValueError(format!(
+                    "Invalid deletion vector path: {}",
+                    self.path_or_inline_dv
+                ))
+            })?;
+        let (prefix, encoded_uuid) = self.path_or_inline_dv.split_at(split);
+        let uuid = z85_decode(encoded_uuid)?;
+        let uuid = format!(
+            "{}-{}-{}-{}-{}",
+            hex(&uuid[..4]),
+            hex(&uuid[4..6]),
+            hex(&uuid[6..8]),
+            hex(&uuid[8..10]),
+            hex(&uuid[10..])
+        );
+        let table_uri = self.table_uri.trim_end_matches('/');
+        Ok(if prefix.is_empty() {
+            format!("{table_uri}/deletion_vector_{uuid}.bin")
+        } else {
+            format!("{table_uri}/{prefix}/deletion_vector_{uuid}.bin")
+        })
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        write!(hex, "{b:02x}").unwrap();
+    }
+    hex
+}
+
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// Decodes Z85, the variant of Base85 that Delta Lake encodes inline deletion vectors and UUIDs with.
+fn z85_decode(encoded: &str) -> DaftResult<Vec<u8>> {
+    let invalid = || DaftError::ValueError(format!("Invalid Z85 encoding: {encoded}"));
+    if encoded.len() % 5 != 0 {
+        return Err(invalid());
+    }
+    let mut decoded = Vec::with_capacity(encoded.len() / 5 * 4);
+    for chunk in encoded.as_bytes().chunks_exact(5) {
+        let mut value = 0u64;
+        for c in chunk {
+            let digit = Z85_ALPHABET
+                .iter()
+                .position(|a| a == c)
+                .ok_or_else(invalid)?;
+            value = value * 85 + digit as u64;
+        }
+        let value = u32::try_from(value).map_err(|_| invalid())?;
+        decoded.extend_from_slice(&value.to_be_bytes());
+    }
+    Ok(decoded)
+}
+
+/// Reads little-endian integers from the front of a buffer.
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, n: usize) -> DaftResult<&'a [u8]> {
+        if self.bytes.len() < n {
+            return Err(DaftError::ValueError(
+                "Deletion vector is truncated".to_string(),
+            ));
+        }
+        let (taken, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u16(&mut self) -> DaftResult<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> DaftResult<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> DaftResult<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+}
+
+/// Deserializes a deletion vector, which is a 64-bit Roaring bitmap in the portable format of its reference
+/// implementations: the number of 32-bit bitmaps, followed by the high 32 bits of the values of each bitmap and the
+/// bitmap itself.
+fn deserialize_deletion_vector(data: &[u8]) -> DaftResult<Vec<i64>> {
+    let mut reader = ByteReader { bytes: data };
+    let magic = reader.u32()?;
+    if magic != DELETION_VECTOR_MAGIC {
+        return Err(DaftError::ValueError(format!(
+            "Unexpected deletion vector magic number: {magic}"
+        )));
+    }
+    let num_bitmaps = reader.u64()?;
+    let mut positions = vec![];
+    for _ in 0..num_bitmaps {
+        let high_bits = reader.u32()?;
+        read_roaring_bitmap(&mut reader, u64::from(high_bits) << 32, &mut positions)?;
+    }
+    Ok(positions)
+}
+
+const SERIAL_COOKIE_NO_RUNCONTAINER: u32 = 12346;
+const SERIAL_COOKIE: u32 = 12347;
+const NO_OFFSET_THRESHOLD: usize = 4;
+const MAX_ARRAY_CONTAINER_CARDINALITY: usize = 4096;
+
+/// Reads a 32-bit Roaring bitmap, adding its values to `base` and appending them to `positions`.
+fn read_roaring_bitmap(
+    reader: &mut ByteReader,
+    base: u64,
+    positions: &mut Vec<i64>,
+) -> DaftResult<()> {
+    let cookie = reader.u32()?;
+    let (num_containers, run_flags) = if cookie == SERIAL_COOKIE_NO_RUNCONTAINER {
+        (reader.u32()? as usize, None)
+    } else if cookie & 0xFFFF == SERIAL_COOKIE {
+        let num_containers = (cookie >> 16) as usize + 1;
+        (
+            num_containers,
+            Some(reader.take(num_containers.div_ceil(8))?),
+        )
+    } else {
+        return Err(DaftError::ValueError(format!(
+            "Unexpected Roaring bitmap cookie: {cookie}"
+        )));
+    };
+    let mut headers = Vec::with_capacity(num_containers);
+    for _ in 0..num_containers {
+        let key = reader.u16()?;
+        let cardinality = reader.u16()? as usize + 1;
+        headers.push((key, cardinality));
+    }
+    // The containers follow each other, so their offsets aren't needed to read all of them.
+    if run_flags.is_none() || num_containers >= NO_OFFSET_THRESHOLD {
+        reader.take(4 * num_containers)?;
+    }
+    for (i, (key, cardinality)) in headers.into_iter().enumerate() {
+        let base = base | (u64::from(key) << 16);
+        let is_run = run_flags.is_some_and(|flags| flags[i / 8] & (1 << (i % 8)) != 0);
+        if is_run {
+            let num_runs = reader.u16()?;
+            for _ in 0..num_runs {
+                let start = u64::from(reader.u16()?);
+                let length = u64::from(reader.u16()?);
+                positions.extend((start..=start + length).map(|value| (base + value) as i64));
+            }
+        } else if cardinality <= MAX_ARRAY_CONTAINER_CARDINALITY {
+            for _ in 0..cardinality {
+                positions.push((base + u64::from(reader.u16()?)) as i64);
+            }
+        } else {
+            let words = reader.take(8192)?;
+            for (i, word) in words.chunks_exact(8).enumerate() {
+                let mut word = u64::from_le_bytes(word.try_into().unwrap());
+                while word != 0 {
+                    let bit = u64::from(word.trailing_zeros());
+                    positions.push((base + i as u64 * 64 + bit) as i64);
+                    word &= word - 1;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn z85_encode(data: &[u8]) -> String {
+        data.chunks_exact(4)
+            .flat_map(|chunk| {
+                let mut value = u32::from_be_bytes(chunk.try_into().unwrap());
+                let mut digits = [0u8; 5];
+                for digit in digits.iter_mut().rev() {
+                    *digit = Z85_ALPHABET[(value % 85) as usize];
+                    value /= 85;
+                }
+                digits
+            })
+            .map(char::from)
+            .collect()
+    }
+
+    #[test]
+    fn check_z85_decode() -> DaftResult<()> {
+        assert_eq!(
+            z85_decode("HelloWorld")?,
+            vec![0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B]
+        );
+        assert!(z85_decode("Hello").is_ok());
+        assert!(z85_decode("Hell").is_err());
+        assert!(z85_decode("Hell~").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_deserialize_deletion_vector() -> DaftResult<()> {
+        let mut data = DELETION_VECTOR_MAGIC.to_le_bytes().to_vec();
+        data.extend(2u64.to_le_bytes());
+
+        // An array container without run containers, which always has offsets.
+        data.extend(0u32.to_le_bytes());
+        data.extend(SERIAL_COOKIE_NO_RUNCONTAINER.to_le_bytes());
+        data.extend(1u32.to_le_bytes());
+        data.extend([0u16, 2].iter().flat_map(|v| v.to_le_bytes()));
+        data.extend(0u32.to_le_bytes());
+        data.extend([1u16, 5, 7].iter().flat_map(|v| v.to_le_bytes()));
+
+        // A run container and a bitmap container in the values with the high bits 1.
+        data.extend(1u32.to_le_bytes());
+        data.extend((SERIAL_COOKIE | (1 << 16)).to_le_bytes());
+        data.push(0b01);
+        data.extend([1u16, 2, 2, 4999].iter().flat_map(|v| v.to_le_bytes()));
+        data.extend([10u16, 2].iter().flat_map(|v| v.to_le_bytes()));
+        let mut words = vec![0u64; 1024];
+        for value in 0..5000 {
+            words[value * 13 % 65536 / 64] |= 1 << (value * 13 % 64);
+        }
+        data.extend(words.iter().flat_map(|w| w.to_le_bytes()));
+
+        let positions = deserialize_deletion_vector(&data)?;
+        let high = 1i64 << 32;
+        let mut expected = vec![1, 5, 7];
+        expected.extend((10..=12).map(|v| high + (1 << 16) + v));
+        let mut bitmap_values = (0..5000).map(|v| v * 13 % 65536).collect::<Vec<_>>();
+        bitmap_values.sort_unstable();
+        expected.extend(bitmap_values.into_iter().map(|v| high + (2 << 16) + v));
+        assert_eq!(positions, expected);
+
+        assert!(deserialize_deletion_vector(&data[..data.len() - 1]).is_err());
+        assert!(deserialize_deletion_vector(&data[4..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_deletion_vector_paths() -> DaftResult<()> {
+        let uuid = (0u8..16).collect::<Vec<_>>();
+        let descriptor = DeletionVectorDescriptor {
+            table_uri: "s3://bucket/table/".to_string(),
+            storage_type: "u".to_string(),
+            path_or_inline_dv: format!("ab{}", z85_encode(&uuid)),
+            offset: Some(1),
+            size_in_bytes: 10,
+            cardinality: 1,
+        };
+        assert_eq!(
+            descriptor.file_path()?,
+            "s3://bucket/table/ab/deletion_vector_00010203-0405-0607-0809-0a0b0c0d0e0f.bin"
+        );
+
+        let descriptor = DeletionVectorDescriptor {
+            path_or_inline_dv: z85_encode(&uuid),
+            ..descriptor
+        };
+        assert_eq!(
+            descriptor.file_path()?,
+            "s3://bucket/table/deletion_vector_00010203-0405-0607-0809-0a0b0c0d0e0f.bin"
+        );
+        Ok(())
+    }
+}
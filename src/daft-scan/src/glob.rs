@@ -1,21 +1,34 @@
-use std::{sync::Arc, vec};
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+    vec,
+};
 
 use common_error::{DaftError, DaftResult};
 use common_file_formats::{CsvSourceConfig, FileFormat, FileFormatConfig, ParquetSourceConfig};
 use common_runtime::RuntimeRef;
-use common_scan_info::{PartitionField, Pushdowns, ScanOperator, ScanTaskLike, ScanTaskLikeRef};
+use common_scan_info::{
+    DatasetStatistics, PartitionField, Pushdowns, ScanOperator, ScanTaskLike, ScanTaskLikeRef,
+    DATASET_STATISTICS_FILE_NAME,
+};
 use daft_core::{prelude::Utf8Array, series::IntoSeries};
 use daft_csv::CsvParseOptions;
+use daft_dsl::ExprRef;
 use daft_io::{parse_url, FileMetadata, IOClient, IOStatsContext, IOStatsRef};
-use daft_parquet::read::ParquetSchemaInferenceOptions;
+use daft_parquet::{
+    metadata::{split_summary_metadata, SUMMARY_METADATA_FILE_NAME},
+    read::ParquetSchemaInferenceOptions,
+    row_group_metadata_to_table_stats,
+};
 use daft_recordbatch::RecordBatch;
 use daft_schema::{
     dtype::DataType,
     field::Field,
     schema::{Schema, SchemaRef},
 };
-use daft_stats::{PartitionSpec, TableMetadata};
+use daft_stats::{PartitionSpec, TableMetadata, TableStatistics};
 use futures::{stream::BoxStream, Stream, StreamExt, TryStreamExt};
+use parquet2::metadata::FileMetaData;
 use snafu::Snafu;
 
 use crate::{
@@ -36,6 +49,11 @@ pub struct GlobScanOperator {
     // When creating the glob scan operator, we might collect file metadata for the first file during schema inference.
     // Cache this metadata (along with the first filepath) so we can use it to populate the stats for the first scan task.
     first_metadata: Option<(String, TableMetadata)>,
+    // Statistics of the dataset in the directory that is scanned, if it was analyzed.
+    statistics: Option<Arc<DatasetStatistics>>,
+    // Footers of the Parquet files of the dataset in the directory that is scanned, along with the paths of the files,
+    // if they were summarized in a `_metadata` file when they were written.
+    parquet_summary: Option<Vec<(String, Arc<FileMetaData>)>>,
 }
 
 /// Wrapper struct that implements a sync Iterator for a BoxStream
@@ -74,6 +92,100 @@ impl From<Error> for DaftError {
     }
 }
 
+/// Reads the [`DatasetStatistics`] persisted in the directory of `glob_path` when the dataset was analyzed, if there are
+/// any. Statistics are only looked up for plain directory paths, which is how analyzed datasets are read.
+async fn read_dataset_statistics(
+    glob_path: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> Option<DatasetStatistics> {
+    if glob_path.contains(['*', '?', '{', '}', '[', ']']) {
+        return None;
+    }
+    let statistics_path = format!(
+        "{}/{DATASET_STATISTICS_FILE_NAME}",
+        glob_path.trim_end_matches('/')
+    );
+    // A missing or unreadable statistics file just means the dataset wasn't analyzed.
+    let bytes = io_client
+        .single_url_get(statistics_path, None, io_stats)
+        .await
+        .ok()?
+        .bytes()
+        .await
+        .ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+/// The column statistics of a Parquet file, from the statistics of its row groups in its footer.
+///
+/// Only the columns of `schema` that are stored in the file, rather than generated from its path, get statistics.
+fn parquet_file_statistics(
+    metadata: &FileMetaData,
+    schema: &Schema,
+    generated_fields: &Schema,
+) -> Option<TableStatistics> {
+    let row_groups = metadata.row_groups.values().collect::<Vec<_>>();
+    let file_columns = row_groups
+        .first()?
+        .columns()
+        .iter()
+        .filter_map(|column| column.descriptor().path_in_schema.first())
+        .collect::<HashSet<_>>();
+    let file_schema = Schema::new(
+        schema
+            .fields
+            .values()
+            .filter(|field| {
+                file_columns.contains(&field.name)
+                    && !generated_fields.fields.contains_key(&field.name)
+            })
+            .cloned()
+            .collect(),
+    )
+    .ok()?;
+    row_groups
+        .into_iter()
+        .map(|row_group| row_group_metadata_to_table_stats(row_group, &file_schema).ok())
+        .reduce(|acc, stats| acc?.union(&stats?).ok())
+        .flatten()
+}
+
+/// Reads the footers of the Parquet files of the dataset in the directory of `glob_path` from the `_metadata` file that
+/// summarizes them, if there is one, along with the paths of the files. Like statistics, summaries are only looked up
+/// for plain directory paths.
+async fn read_parquet_summary(
+    glob_path: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    field_id_mapping: Option<Arc<BTreeMap<i32, Field>>>,
+) -> Option<Vec<(String, Arc<FileMetaData>)>> {
+    if glob_path.contains(['*', '?', '{', '}', '[', ']']) {
+        return None;
+    }
+    let (_, dataset_path) = parse_url(glob_path).ok()?;
+    let dataset_path = dataset_path.trim_end_matches('/');
+    let summary_path = format!("{dataset_path}/{SUMMARY_METADATA_FILE_NAME}");
+    // A missing or unreadable summary just means that the files of the dataset need to be listed.
+    let summary = daft_parquet::read::read_parquet_metadata(
+        &summary_path,
+        io_client,
+        io_stats,
+        field_id_mapping,
+    )
+    .await
+    .ok()?;
+    let files = split_summary_metadata(&summary)?;
+    if files.is_empty() {
+        return None;
+    }
+    let files = files
+        .into_iter()
+        .map(|(file_path, metadata)| (format!("{dataset_path}/{file_path}"), Arc::new(metadata)))
+        .collect();
+    Some(files)
+}
+
 async fn run_glob(
     glob_path: &str,
     limit: Option<usize>,
@@ -172,6 +284,30 @@ impl GlobScanOperator {
             }
             .into()),
         }?;
+        // A path that globs to itself is a single file rather than the directory of a dataset.
+        let is_dataset_directory = glob_paths.len() == 1
+            && !first_filepath.ends_with(first_glob_path.trim_end_matches('/'));
+        let statistics = if is_dataset_directory {
+            read_dataset_statistics(first_glob_path, io_client.clone(), Some(io_stats.clone()))
+                .await
+                .map(Arc::new)
+        } else {
+            None
+        };
+        let parquet_summary = match file_format_config.as_ref() {
+            FileFormatConfig::Parquet(ParquetSourceConfig {
+                field_id_mapping, ..
+            }) if is_dataset_directory => {
+                read_parquet_summary(
+                    first_glob_path,
+                    io_client.clone(),
+                    Some(io_stats.clone()),
+                    field_id_mapping.clone(),
+                )
+                .await
+            }
+            _ => None,
+        };
         // If hive partitioning is set, create partition fields from the hive partitions.
         let mut partition_fields = if hive_partitioning {
             let hive_partitions = parse_hive_partitioning(&first_filepath)?;
@@ -208,29 +344,43 @@ impl GlobScanOperator {
                         ref field_id_mapping,
                         ..
                     }) => {
-                        let io_stats = IOStatsContext::new(format!(
-                            "GlobScanOperator constructor read_parquet_schema: for uri {first_filepath}"
-                        ));
-
-                        let (schema, metadata) =
-                            daft_parquet::read::read_parquet_schema_and_metadata(
-                                first_filepath.as_str(),
-                                io_client,
-                                Some(io_stats),
-                                ParquetSchemaInferenceOptions {
-                                    coerce_int96_timestamp_unit,
-                                    ..Default::default()
+                        let schema_inference_options = ParquetSchemaInferenceOptions {
+                            coerce_int96_timestamp_unit,
+                            ..Default::default()
+                        };
+                        // The files of a summarized dataset all have the schema of the summary.
+                        if let Some([(_, metadata), ..]) = parquet_summary.as_deref() {
+                            let schema = daft_parquet::read::infer_schema_from_metadata(
+                                metadata,
+                                schema_inference_options,
+                            )?;
+                            (schema, None)
+                        } else {
+                            let io_stats = IOStatsContext::new(format!(
+                                "GlobScanOperator constructor read_parquet_schema: for uri {first_filepath}"
+                            ));
+
+                            let (schema, metadata) =
+                                daft_parquet::read::read_parquet_schema_and_metadata(
+                                    first_filepath.as_str(),
+                                    io_client,
+                                    Some(io_stats),
+                                    schema_inference_options,
+                                    field_id_mapping.clone(),
+                                )
+                                .await?;
+                            let metadata = Some((
+                                first_filepath,
+                                TableMetadata {
+                                    length: metadata.num_rows,
+                                    num_distinct:
+                                        daft_parquet::metadata::num_distinct_from_metadata(
+                                            &metadata,
+                                        ),
                                 },
-                                field_id_mapping.clone(),
-                            )
-                            .await?;
-                        let metadata = Some((
-                            first_filepath,
-                            TableMetadata {
-                                length: metadata.num_rows,
-                            },
-                        ));
-                        (schema, metadata)
+                            ));
+                            (schema, metadata)
+                        }
                     }
                     FileFormatConfig::Csv(CsvSourceConfig {
                         delimiter,
@@ -276,6 +426,15 @@ impl GlobScanOperator {
                             "Warc schemas do not need to be inferred".to_string(),
                         ))
                     }
+                    FileFormatConfig::ArrowIpc(_) => {
+                        let schema = daft_ipc::read_ipc_schema(
+                            first_filepath.as_str(),
+                            io_client,
+                            Some(io_stats),
+                        )
+                        .await?;
+                        (schema, None)
+                    }
                     #[cfg(feature = "python")]
                     FileFormatConfig::Database(_) => {
                         return Err(DaftError::ValueError(
@@ -302,6 +461,22 @@ impl GlobScanOperator {
                 None,
             ),
         };
+        // The row index isn't stored in the file, but is read alongside the file's own columns.
+        let schema = match file_format_config.as_ref() {
+            FileFormatConfig::Parquet(ParquetSourceConfig {
+                row_index_column: Some(row_index_column),
+                ..
+            }) => {
+                if schema.has_field(row_index_column) {
+                    return Err(DaftError::ValueError(format!(
+                        "Row index column `{row_index_column}` conflicts with an existing column of the same name"
+                    )));
+                }
+                let row_index_field = Field::new(row_index_column, DataType::UInt64);
+                Arc::new(schema.non_distinct_union(&Schema::new(vec![row_index_field])?))
+            }
+            _ => schema,
+        };
         Ok(Self {
             glob_paths,
             file_format_config,
@@ -312,6 +487,8 @@ impl GlobScanOperator {
             partitioning_keys,
             generated_fields: Arc::new(generated_fields),
             first_metadata,
+            statistics,
+            parquet_summary,
         })
     }
 }
@@ -347,6 +524,16 @@ impl ScanOperator for GlobScanOperator {
         false
     }
 
+    fn can_absorb_aggregation(
+        &self,
+        _aggregation: &ExprRef,
+        _pushdowns: &Pushdowns,
+    ) -> DaftResult<bool> {
+        // Aggregations that file metadata answers, e.g. from Parquet footers, are answered from the statistics of the
+        // scan tasks once they're materialized.
+        Ok(false)
+    }
+
     fn multiline_display(&self) -> Vec<String> {
         let condensed_glob_paths = if self.glob_paths.len() <= 7 {
             self.glob_paths.join(", ")
@@ -373,10 +560,17 @@ impl ScanOperator for GlobScanOperator {
         ];
         lines.extend(self.file_format_config.multiline_display());
         lines.extend(self.storage_config.multiline_display());
+        if let Some(statistics) = &self.statistics {
+            lines.extend(statistics.multiline_display());
+        }
 
         lines
     }
 
+    fn statistics(&self) -> Option<Arc<DatasetStatistics>> {
+        self.statistics.clone()
+    }
+
     fn to_scan_tasks(&self, pushdowns: Pushdowns) -> DaftResult<Vec<ScanTaskLikeRef>> {
         let (io_runtime, io_client) = self.storage_config.get_io_client_and_runtime()?;
         let io_stats = IOStatsContext::new(format!(
@@ -385,13 +579,34 @@ impl ScanOperator for GlobScanOperator {
         ));
         let file_format = self.file_format_config.file_format();
 
-        let files = run_glob_parallel(
-            self.glob_paths.clone(),
-            io_client,
-            io_runtime,
-            Some(io_stats),
-            file_format,
-        )?;
+        // The files of a summarized dataset don't need to be listed, nor do their footers need to be fetched.
+        let files: Box<
+            dyn Iterator<Item = DaftResult<(String, Option<u64>, Option<Arc<FileMetaData>>)>>,
+        > = match &self.parquet_summary {
+            Some(parquet_summary) => {
+                Box::new(parquet_summary.clone().into_iter().map(|(path, metadata)| {
+                    let size_bytes = metadata
+                        .row_groups
+                        .values()
+                        .map(|row_group| row_group.compressed_size() as u64)
+                        .sum();
+                    Ok((path, Some(size_bytes), Some(metadata)))
+                }))
+            }
+            None => Box::new(
+                run_glob_parallel(
+                    self.glob_paths.clone(),
+                    io_client,
+                    io_runtime,
+                    Some(io_stats),
+                    file_format,
+                )?
+                .map(|f| {
+                    let FileMetadata { filepath, size, .. } = f?;
+                    Ok((filepath, size, None))
+                }),
+            ),
+        };
 
         let file_format_config = self.file_format_config.clone();
         let schema = self.schema.clone();
@@ -406,6 +621,7 @@ impl ScanOperator for GlobScanOperator {
         } else {
             None
         };
+        let generated_fields_schema = self.generated_fields.clone();
         let file_path_column = self.file_path_column.clone();
         let hive_partitioning = self.hive_partitioning;
         let partition_fields = self
@@ -425,11 +641,7 @@ impl ScanOperator for GlobScanOperator {
             .enumerate()
             .filter_map(|(idx, f)| {
                 let scan_task_result = (|| {
-                    let FileMetadata {
-                        filepath: path,
-                        size: size_bytes,
-                        ..
-                    } = f?;
+                    let (path, size_bytes, parquet_metadata) = f?;
                     // Create partition values from hive partitions, if any.
                     let mut partition_values = if hive_partitioning {
                         let hive_partitions = parse_hive_partitioning(&path)?;
@@ -470,9 +682,22 @@ impl ScanOperator for GlobScanOperator {
                         .and_then(|rgs| rgs.get(idx).cloned())
                         .flatten();
                     let chunk_spec = row_group.map(ChunkSpec::Parquet);
+                    // The statistics in the footers of summarized files are of whole files, so they can answer
+                    // e.g. min and max aggregations without reading any data.
+                    let statistics = parquet_metadata
+                        .as_ref()
+                        .filter(|_| chunk_spec.is_none())
+                        .and_then(|metadata| {
+                            parquet_file_statistics(metadata, &schema, &generated_fields_schema)
+                        });
                     Ok(Some(ScanTask::new(
                         vec![DataSource::File {
-                            metadata: if let Some(first_filepath) = first_filepath
+                            metadata: if let Some(parquet_metadata) = &parquet_metadata {
+                                Some(TableMetadata {
+                                    length: parquet_metadata.num_rows,
+                                    num_distinct: Default::default(),
+                                })
+                            } else if let Some(first_filepath) = first_filepath
                                 && path == *first_filepath
                             {
                                 first_metadata.cloned()
@@ -483,9 +708,10 @@ impl ScanOperator for GlobScanOperator {
                             chunk_spec,
                             size_bytes,
                             iceberg_delete_files: None,
+                            deletion_vector: None,
                             partition_spec,
-                            statistics: None,
-                            parquet_metadata: None,
+                            statistics,
+                            parquet_metadata,
                         }],
                         file_format_config.clone(),
                         schema.clone(),
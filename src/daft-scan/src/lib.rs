@@ -3,17 +3,19 @@
 use std::{
     any::Any,
     borrow::Cow,
+    collections::BTreeMap,
     fmt::Debug,
     hash::{Hash, Hasher},
     sync::Arc,
 };
 
 use common_display::DisplayAs;
-use common_error::DaftError;
+use common_error::{DaftError, DaftResult};
 use common_file_formats::FileFormatConfig;
 use common_scan_info::{Pushdowns, ScanTaskLike, ScanTaskLikeRef};
+use daft_dsl::LiteralValue;
 use daft_schema::schema::{Schema, SchemaRef};
-use daft_stats::{PartitionSpec, TableMetadata, TableStatistics};
+use daft_stats::{ColumnRangeStatistics, PartitionSpec, TableMetadata, TableStatistics};
 use itertools::Itertools;
 use parquet2::metadata::FileMetaData;
 use serde::{Deserialize, Serialize};
@@ -24,11 +26,13 @@ pub mod glob;
 mod hive;
 use common_daft_config::DaftExecutionConfig;
 pub mod builder;
+pub mod deletion_vector;
 pub mod scan_task_iters;
 
 #[cfg(feature = "python")]
 pub mod python;
 pub mod storage_config;
+use deletion_vector::DeletionVectorDescriptor;
 #[cfg(feature = "python")]
 use pyo3::PyErr;
 #[cfg(feature = "python")]
@@ -132,6 +136,7 @@ pub enum DataSource {
         chunk_spec: Option<ChunkSpec>,
         size_bytes: Option<u64>,
         iceberg_delete_files: Option<Vec<String>>,
+        deletion_vector: Option<DeletionVectorDescriptor>,
         metadata: Option<TableMetadata>,
         partition_spec: Option<PartitionSpec>,
         statistics: Option<TableStatistics>,
@@ -164,6 +169,7 @@ impl Hash for DataSource {
                 chunk_spec,
                 size_bytes,
                 iceberg_delete_files,
+                deletion_vector,
                 metadata,
                 partition_spec,
                 statistics,
@@ -175,6 +181,7 @@ impl Hash for DataSource {
                 }
                 size_bytes.hash(state);
                 iceberg_delete_files.hash(state);
+                deletion_vector.hash(state);
                 metadata.hash(state);
                 partition_spec.hash(state);
                 statistics.hash(state);
@@ -292,6 +299,25 @@ impl DataSource {
         }
     }
 
+    #[must_use]
+    pub fn get_deletion_vector(&self) -> Option<&DeletionVectorDescriptor> {
+        match self {
+            Self::File {
+                deletion_vector, ..
+            } => deletion_vector.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Whether some rows of the file are deleted by Iceberg delete files or a Delta Lake deletion vector, so that
+    /// the file has fewer rows than its metadata says.
+    #[must_use]
+    pub fn has_deleted_rows(&self) -> bool {
+        self.get_iceberg_delete_files()
+            .is_some_and(|files| !files.is_empty())
+            || self.get_deletion_vector().is_some()
+    }
+
     #[must_use]
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
@@ -301,6 +327,7 @@ impl DataSource {
                 chunk_spec,
                 size_bytes,
                 iceberg_delete_files,
+                deletion_vector,
                 metadata,
                 partition_spec,
                 statistics,
@@ -319,6 +346,12 @@ impl DataSource {
                 if let Some(iceberg_delete_files) = iceberg_delete_files {
                     res.push(format!("Iceberg delete files = {iceberg_delete_files:?}"));
                 }
+                if let Some(deletion_vector) = deletion_vector {
+                    res.push(format!(
+                        "Deletion vector = {{ Cardinality = {} }}",
+                        deletion_vector.cardinality
+                    ));
+                }
                 if let Some(metadata) = metadata {
                     res.push(format!(
                         "Metadata = {}",
@@ -463,6 +496,10 @@ impl ScanTaskLike for ScanTask {
         self.num_rows()
     }
 
+    fn has_deleted_rows(&self) -> bool {
+        self.sources.iter().any(DataSource::has_deleted_rows)
+    }
+
     fn approx_num_rows(&self, config: Option<&DaftExecutionConfig>) -> Option<f64> {
         self.approx_num_rows(config)
     }
@@ -471,6 +508,56 @@ impl ScanTaskLike for ScanTask {
         self.upper_bound_rows()
     }
 
+    fn num_distinct(&self) -> Option<&BTreeMap<String, usize>> {
+        self.metadata
+            .as_ref()
+            .map(|metadata| &metadata.num_distinct)
+            .filter(|num_distinct| !num_distinct.is_empty())
+    }
+
+    fn column_bounds(&self, column: &str) -> Option<(LiteralValue, LiteralValue)> {
+        let [source] = self.sources.as_slice() else {
+            return None;
+        };
+        if self.pushdowns.filters.is_some()
+            || self.pushdowns.limit.is_some()
+            || source.get_chunk_spec().is_some()
+            || source.has_deleted_rows()
+        {
+            return None;
+        }
+        // Partition columns have the same value in every row.
+        if let Some(value) = source
+            .get_partition_spec()
+            .and_then(|spec| spec.keys.get_column(column).ok())
+        {
+            let value = LiteralValue::Series(value.clone());
+            return Some((value.clone(), value));
+        }
+        // Catalogs record the exact bounds of the integer and temporal columns of their files, while e.g. the
+        // statistics of SQL scans are the bounds of the ranges of partitions.
+        if !matches!(source, DataSource::File { .. }) {
+            return None;
+        }
+        match source.get_statistics()?.columns.get(column)? {
+            ColumnRangeStatistics::Loaded(lower, upper)
+                if lower.data_type().is_integer() || lower.data_type().is_temporal() =>
+            {
+                Some((
+                    LiteralValue::Series(lower.clone()),
+                    LiteralValue::Series(upper.clone()),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    fn partition_value(&self, column: &str) -> Option<LiteralValue> {
+        // The sources of a task all have the same partition values.
+        let value = self.partition_spec()?.keys.get_column(column).ok()?;
+        Some(LiteralValue::Series(value.clone()))
+    }
+
     fn size_bytes_on_disk(&self) -> Option<usize> {
         self.size_bytes_on_disk()
     }
@@ -490,6 +577,37 @@ impl ScanTaskLike for ScanTask {
     fn schema(&self) -> SchemaRef {
         self.schema.clone()
     }
+
+    fn with_pushdowns(&self, pushdowns: Pushdowns) -> ScanTaskLikeRef {
+        Arc::new(Self::new(
+            self.sources.clone(),
+            self.file_format_config.clone(),
+            self.schema.clone(),
+            self.storage_config.clone(),
+            pushdowns,
+            self.generated_fields.clone(),
+        ))
+    }
+
+    fn file_versions(&self) -> DaftResult<Vec<(String, Option<String>)>> {
+        let (io_runtime, io_client) = self.storage_config.get_io_client_and_runtime()?;
+        io_runtime.block_on_current_thread(futures::future::try_join_all(self.sources.iter().map(
+            |source| {
+                let io_client = io_client.clone();
+                async move {
+                    let path = source.get_path().to_string();
+                    // Only files have versions that can be looked up.
+                    let version = match source {
+                        DataSource::File { .. } => {
+                            io_client.single_url_head(path.clone(), None).await?.etag
+                        }
+                        _ => None,
+                    };
+                    DaftResult::Ok((path, version))
+                }
+            },
+        )))
+    }
 }
 
 impl From<ScanTask> for ScanTaskLikeRef {
@@ -539,7 +657,17 @@ impl ScanTask {
                 },
             )
             .unwrap();
-        let metadata = length.map(|l| TableMetadata { length: l });
+        // NDVs can't be added up, so they're only kept for scan tasks of a single source.
+        let metadata = length.map(|length| TableMetadata {
+            length,
+            num_distinct: match sources.as_slice() {
+                [source] => source
+                    .get_metadata()
+                    .map(|metadata| metadata.num_distinct.clone())
+                    .unwrap_or_default(),
+                _ => Default::default(),
+            },
+        });
         Self {
             sources,
             schema,
@@ -637,6 +765,9 @@ impl ScanTask {
         if self.pushdowns.filters.is_some() {
             // Cannot obtain an accurate num_rows if there are filters
             None
+        } else if self.sources.iter().any(DataSource::has_deleted_rows) {
+            // The metadata of files counts their deleted rows too
+            None
         } else {
             // Only can obtain an accurate num_rows if metadata is provided
             self.metadata.as_ref().map(|m| {
@@ -673,6 +804,8 @@ impl ScanTask {
                         }
                         // TODO(desmond): We can do a lot better here.
                         FileFormatConfig::Warc(_) => 1.0,
+                        // Arrow IPC files hold the same buffers as they take up in memory, unless they're compressed.
+                        FileFormatConfig::ArrowIpc(_) => 1.0,
                         #[cfg(feature = "python")]
                         FileFormatConfig::Database(_) => 1.0,
                         #[cfg(feature = "python")]
@@ -687,9 +820,20 @@ impl ScanTask {
         approx_total_num_rows_before_pushdowns.map(|approx_total_num_rows_before_pushdowns| {
             if self.pushdowns.filters.is_some() {
                 // HACK: This might not be a good idea? We could also just return None here
-                // Assume that filters filter out about 80% of the data
-                let estimated_selectivity =
-                    self.pushdowns.estimated_selectivity(self.schema.as_ref());
+                // Assume that filters filter out about 80% of the data, unless the column stats of
+                // the files tell us more.
+                let mut stats = self
+                    .statistics
+                    .as_ref()
+                    .map(TableStatistics::selectivity_stats)
+                    .unwrap_or_default();
+                for (column, &num_distinct) in self.metadata.iter().flat_map(|m| &m.num_distinct) {
+                    stats.entry(column.clone()).or_default().num_distinct =
+                        Some(num_distinct as f64);
+                }
+                let estimated_selectivity = self
+                    .pushdowns
+                    .estimated_selectivity_with_stats(self.schema.as_ref(), &stats);
                 // Set the lower bound approximated number of rows to 1 to avoid underestimation.
                 (approx_total_num_rows_before_pushdowns * estimated_selectivity).max(1.0)
             } else if let Some(limit) = self.pushdowns.limit {
@@ -843,6 +987,7 @@ mod test {
     use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
     use common_scan_info::{Pushdowns, ScanOperator};
     use daft_schema::{schema::Schema, time_unit::TimeUnit};
+    use daft_stats::TableMetadata;
     use itertools::Itertools;
 
     use crate::{glob::GlobScanOperator, storage_config::StorageConfig, DataSource, ScanTask};
@@ -854,6 +999,7 @@ mod test {
                 chunk_spec: None,
                 size_bytes: None,
                 iceberg_delete_files: None,
+                deletion_vector: None,
                 metadata: None,
                 partition_spec: None,
                 statistics: None,
@@ -866,6 +1012,7 @@ mod test {
             field_id_mapping: None,
             row_groups: None,
             chunk_size: None,
+            row_index_column: None,
         });
 
         ScanTask::new(
@@ -884,6 +1031,7 @@ mod test {
             field_id_mapping: None,
             row_groups: None,
             chunk_size: None,
+            row_index_column: None,
         });
 
         let mut sources: Vec<String> = Vec::new();
@@ -946,6 +1094,44 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_num_rows_with_deleted_rows() {
+        let scan_task = |iceberg_delete_files: Option<Vec<String>>| {
+            let source = DataSource::File {
+                path: "test".to_string(),
+                chunk_spec: None,
+                size_bytes: None,
+                iceberg_delete_files,
+                deletion_vector: None,
+                metadata: Some(TableMetadata {
+                    length: 10,
+                    num_distinct: Default::default(),
+                }),
+                partition_spec: None,
+                statistics: None,
+                parquet_metadata: None,
+            };
+            ScanTask::new(
+                vec![source],
+                Arc::new(FileFormatConfig::Parquet(ParquetSourceConfig {
+                    coerce_int96_timestamp_unit: TimeUnit::Seconds,
+                    field_id_mapping: None,
+                    row_groups: None,
+                    chunk_size: None,
+                    row_index_column: None,
+                })),
+                Arc::new(Schema::empty()),
+                Arc::new(StorageConfig::new_internal(false, None)),
+                Pushdowns::default(),
+                None,
+            )
+        };
+        assert_eq!(scan_task(None).num_rows(), Some(10));
+        assert_eq!(scan_task(Some(vec![])).num_rows(), Some(10));
+        // The metadata of a file with delete files counts the deleted rows too.
+        assert_eq!(scan_task(Some(vec!["delete".to_string()])).num_rows(), None);
+    }
+
     #[test]
     fn test_display_condenses() -> DaftResult<()> {
         let scan_task = make_scan_task(7);
@@ -74,19 +74,21 @@ pub mod pylib {
     use common_py_serde::impl_bincode_py_state_serialization;
     use common_scan_info::{
         python::pylib::{PyPartitionField, PyPushdowns},
-        PartitionField, Pushdowns, ScanOperator, ScanOperatorRef, ScanTaskLike, ScanTaskLikeRef,
+        DatasetStatistics, PartitionField, Pushdowns, ScanOperator, ScanOperatorRef, ScanTaskLike,
+        ScanTaskLikeRef,
     };
+    use daft_dsl::{python::PyExpr, ExprRef};
     use daft_logical_plan::{LogicalPlanBuilder, PyLogicalPlanBuilder};
     use daft_recordbatch::{python::PyRecordBatch, RecordBatch};
     use daft_schema::{python::schema::PySchema, schema::SchemaRef};
-    use daft_stats::{PartitionSpec, TableMetadata, TableStatistics};
+    use daft_stats::{PartitionSpec, TableMetadata, TableStatistics, TruthValue};
     use pyo3::{prelude::*, pyclass, types::PyIterator};
     use serde::{Deserialize, Serialize};
 
     use super::PythonTablesFactoryArgs;
     use crate::{
-        anonymous::AnonymousScanOperator, glob::GlobScanOperator, storage_config::StorageConfig,
-        DataSource, ScanTask,
+        anonymous::AnonymousScanOperator, deletion_vector::DeletionVectorDescriptor,
+        glob::GlobScanOperator, storage_config::StorageConfig, DataSource, ScanTask,
     };
     #[pyclass(module = "daft.daft", frozen)]
     #[derive(Debug, Clone)]
@@ -279,12 +281,36 @@ pub mod pylib {
         fn can_absorb_select(&self) -> bool {
             self.can_absorb_select
         }
+        fn can_absorb_aggregation(
+            &self,
+            aggregation: &ExprRef,
+            pushdowns: &Pushdowns,
+        ) -> DaftResult<bool> {
+            Python::with_gil(|py| {
+                let pyexpr = PyExpr {
+                    expr: aggregation.clone(),
+                };
+                let pypd = PyPushdowns(pushdowns.clone().into());
+                Ok(self
+                    .operator
+                    .call_method1(
+                        py,
+                        pyo3::intern!(py, "can_absorb_aggregation"),
+                        (pyexpr, pypd),
+                    )?
+                    .extract::<bool>(py)?)
+            })
+        }
 
         fn multiline_display(&self) -> Vec<String> {
             let lines = vec![format!("PythonScanOperator: {}", self.display_name)];
             lines
         }
 
+        fn statistics(&self) -> Option<Arc<DatasetStatistics>> {
+            None
+        }
+
         fn to_scan_tasks(&self, pushdowns: Pushdowns) -> DaftResult<Vec<ScanTaskLikeRef>> {
             let scan_tasks = Python::with_gil(|py| {
                 let pypd = PyPushdowns(pushdowns.clone().into()).into_pyobject(py)?;
@@ -357,7 +383,8 @@ pub mod pylib {
             iceberg_delete_files=None,
             pushdowns=None,
             partition_values=None,
-            stats=None
+            stats=None,
+            deletion_vector=None
         ))]
         pub fn catalog_scan_task(
             file: String,
@@ -370,6 +397,7 @@ pub mod pylib {
             pushdowns: Option<PyPushdowns>,
             partition_values: Option<PyRecordBatch>,
             stats: Option<PyRecordBatch>,
+            deletion_vector: Option<DeletionVectorDescriptor>,
         ) -> PyResult<Option<Self>> {
             if let Some(ref pvalues) = partition_values
                 && let Some(Some(ref partition_filters)) =
@@ -388,23 +416,37 @@ pub mod pylib {
                     Some(true) => {}
                 }
             }
-            // TODO(Clark): Filter out scan tasks with pushed down filters + table stats?
+
+            let statistics = stats
+                .map(|s| TableStatistics::from_stats_table(&s.table))
+                .transpose()?;
+            // Skip files whose column bounds rule out every row. Filters that can't be evaluated on the bounds, e.g.
+            // because they reference columns without bounds, keep the file.
+            if let Some(ref statistics) = statistics
+                && let Some(Some(ref filters)) = pushdowns.as_ref().map(|p| &p.0.filters)
+                && statistics
+                    .eval_expression(filters)
+                    .is_ok_and(|evaled| evaled.to_truth_value() == TruthValue::False)
+            {
+                return Ok(None);
+            }
 
             let pspec = PartitionSpec {
                 keys: partition_values
                     .map_or_else(|| RecordBatch::empty(None).unwrap(), |p| p.table),
             };
-            let statistics = stats
-                .map(|s| TableStatistics::from_stats_table(&s.table))
-                .transpose()?;
 
-            let metadata = num_rows.map(|n| TableMetadata { length: n as usize });
+            let metadata = num_rows.map(|n| TableMetadata {
+                length: n as usize,
+                ..Default::default()
+            });
 
             let data_source = DataSource::File {
                 path: file,
                 chunk_spec: None,
                 size_bytes,
                 iceberg_delete_files,
+                deletion_vector,
                 metadata,
                 partition_spec: Some(pspec),
                 statistics,
@@ -450,7 +492,10 @@ pub mod pylib {
             let data_source = DataSource::Database {
                 path: url,
                 size_bytes,
-                metadata: num_rows.map(|n| TableMetadata { length: n as usize }),
+                metadata: num_rows.map(|n| TableMetadata {
+                    length: n as usize,
+                    ..Default::default()
+                }),
                 statistics,
             };
 
@@ -499,6 +544,7 @@ pub mod pylib {
                 size_bytes,
                 metadata: num_rows.map(|num_rows| TableMetadata {
                     length: num_rows as usize,
+                    ..Default::default()
                 }),
                 statistics,
                 partition_spec: None,
@@ -584,9 +630,11 @@ pub mod pylib {
             chunk_spec: None,
             size_bytes: Some(file_size),
             iceberg_delete_files: None,
+            deletion_vector: None,
             metadata: if has_metadata.unwrap_or(false) {
                 Some(TableMetadata {
                     length: metadata.num_rows,
+                    ..Default::default()
                 })
             } else {
                 None
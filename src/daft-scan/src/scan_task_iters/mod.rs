@@ -7,7 +7,9 @@ use common_error::{DaftError, DaftResult};
 use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
 use common_scan_info::{ScanTaskLike, ScanTaskLikeRef, SPLIT_AND_MERGE_PASS};
 use daft_io::IOStatsContext;
-use daft_parquet::read::read_parquet_metadata;
+use daft_parquet::read::{
+    read_parquet_metadata, row_groups_matching_predicate, ParquetSchemaInferenceOptions,
+};
 use parquet2::metadata::RowGroupList;
 
 use crate::{ChunkSpec, DataSource, Pushdowns, ScanTask, ScanTaskRef};
@@ -183,6 +185,41 @@ impl Iterator for MergeByFileSize<'_> {
     }
 }
 
+/// Drops the ScanTasks that are not needed to satisfy a LIMIT pushdown, i.e. every ScanTask after those whose exact
+/// numbers of rows already add up to the limit.
+///
+/// ScanTasks without an exact number of rows (e.g. because they have filters or delete files) don't count towards
+/// the limit, so all of them are kept unless enough rows are known to come from the ScanTasks before them.
+#[must_use]
+fn prune_by_limit<'a>(
+    scan_tasks: BoxScanTaskIter<'a>,
+    pushdowns: &Pushdowns,
+) -> BoxScanTaskIter<'a> {
+    let Some(limit) = pushdowns.limit else {
+        return scan_tasks;
+    };
+    // At least one ScanTask is always kept, so that the scan still produces the schema of its output.
+    let mut num_rows = 0;
+    let mut is_first = true;
+    Box::new(scan_tasks.take_while(move |t| {
+        if num_rows >= limit && !is_first {
+            return false;
+        }
+        is_first = false;
+        if let Ok(t) = t
+            && t.sources.iter().all(|source| {
+                source
+                    .get_iceberg_delete_files()
+                    .is_none_or(std::vec::Vec::is_empty)
+            })
+            && let Some(task_num_rows) = t.num_rows()
+        {
+            num_rows += task_num_rows;
+        }
+        true
+    }))
+}
+
 #[must_use]
 fn split_by_row_groups(
     scan_tasks: BoxScanTaskIter,
@@ -207,10 +244,14 @@ fn split_by_row_groups(
                         - have no specified chunk spec or number of rows
                         - have size past split threshold
                         - no iceberg delete files
+                        - no row index column, which must count rows from the start of the file
                     */
                     if let (
                         FileFormatConfig::Parquet(ParquetSourceConfig {
-                            field_id_mapping, ..
+                            coerce_int96_timestamp_unit,
+                            field_id_mapping,
+                            row_index_column: None,
+                            ..
                         }),
                         [source],
                         Some(None),
@@ -223,9 +264,7 @@ fn split_by_row_groups(
                     ) && source
                         .get_size_bytes()
                         .is_none_or(|s| s > max_size_bytes as u64)
-                      && source
-                        .get_iceberg_delete_files()
-                        .is_none_or(std::vec::Vec::is_empty)
+                      && !source.has_deleted_rows()
                     {
                         let (io_runtime, io_client) =
                             t.storage_config.get_io_client_and_runtime()?;
@@ -235,12 +274,16 @@ fn split_by_row_groups(
                         let io_stats =
                             IOStatsContext::new(format!("split_by_row_groups for {path:#?}"));
 
-                        let mut file = io_runtime.block_on_current_thread(read_parquet_metadata(
-                            path,
-                            io_client,
-                            Some(io_stats),
-                            field_id_mapping.clone(),
-                        ))?;
+                        // Footers that were already read, such as from the summary of a dataset, needn't be fetched again.
+                        let file = match source.get_parquet_metadata() {
+                            Some(metadata) => metadata.clone(),
+                            None => io_runtime.block_on_current_thread(read_parquet_metadata(
+                                path,
+                                io_client,
+                                Some(io_stats),
+                                field_id_mapping.clone(),
+                            ))?,
+                        };
 
                         let mut new_tasks: Vec<DaftResult<ScanTaskRef>> = Vec::new();
                         let mut curr_row_group_indices = Vec::new();
@@ -248,16 +291,35 @@ fn split_by_row_groups(
                         let mut curr_size_bytes = 0;
                         let mut curr_num_rows = 0;
 
-                        let row_groups = std::mem::take(&mut file.row_groups);
+                        // Skip row groups whose statistics rule out that any of their rows pass the pushed down filters.
+                        let matching_row_groups = t
+                            .pushdowns
+                            .filters
+                            .as_ref()
+                            .map(|filters| {
+                                row_groups_matching_predicate(
+                                    &file,
+                                    filters,
+                                    ParquetSchemaInferenceOptions {
+                                        coerce_int96_timestamp_unit: *coerce_int96_timestamp_unit,
+                                        ..Default::default()
+                                    },
+                                )
+                            })
+                            .transpose()?;
+                        let mut row_groups = file.row_groups.clone();
+                        if let Some(matching_row_groups) = matching_row_groups {
+                            row_groups.retain(|i, _| matching_row_groups.contains(i));
+                        }
                         let num_row_groups = row_groups.len();
-                        for (i, rg) in row_groups {
+                        for (n, (i, rg)) in row_groups.into_iter().enumerate() {
                             curr_row_groups.push((i, rg));
                             let rg = &curr_row_groups.last().unwrap().1;
                             curr_row_group_indices.push(i as i64);
                             curr_size_bytes += rg.compressed_size();
                             curr_num_rows += rg.num_rows();
 
-                            if curr_size_bytes >= min_size_bytes || i == num_row_groups - 1 {
+                            if curr_size_bytes >= min_size_bytes || n == num_row_groups - 1 {
                                 let mut new_source = source.clone();
 
                                 if let DataSource::File {
@@ -333,6 +395,7 @@ fn split_and_merge_pass(
                 .downcast::<ScanTask>()
                 .map_err(|e| DaftError::TypeError(format!("Expected Arc<ScanTask>, found {:?}", e)))
         }));
+        let iter = prune_by_limit(iter, pushdowns);
         if cfg.scantask_splitting_level == 1 {
             let split_tasks = split_by_row_groups(
                 iter,
@@ -371,3 +434,86 @@ fn split_and_merge_pass(
 fn set_pass() {
     let _ = SPLIT_AND_MERGE_PASS.set(&split_and_merge_pass);
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
+    use common_scan_info::Pushdowns;
+    use daft_schema::{schema::Schema, time_unit::TimeUnit};
+    use daft_stats::TableMetadata;
+
+    use super::{prune_by_limit, BoxScanTaskIter};
+    use crate::{storage_config::StorageConfig, DataSource, ScanTask, ScanTaskRef};
+
+    fn make_scan_task(path: &str, num_rows: Option<usize>, pushdowns: &Pushdowns) -> ScanTaskRef {
+        let source = DataSource::File {
+            path: path.to_string(),
+            chunk_spec: None,
+            size_bytes: None,
+            iceberg_delete_files: None,
+            deletion_vector: None,
+            metadata: num_rows.map(|length| TableMetadata {
+                length,
+                num_distinct: Default::default(),
+            }),
+            partition_spec: None,
+            statistics: None,
+            parquet_metadata: None,
+        };
+        let file_format_config = FileFormatConfig::Parquet(ParquetSourceConfig {
+            coerce_int96_timestamp_unit: TimeUnit::Seconds,
+            field_id_mapping: None,
+            row_groups: None,
+            chunk_size: None,
+            row_index_column: None,
+        });
+        ScanTask::new(
+            vec![source],
+            Arc::new(file_format_config),
+            Arc::new(Schema::empty()),
+            Arc::new(StorageConfig::new_internal(false, None)),
+            pushdowns.clone(),
+            None,
+        )
+        .into()
+    }
+
+    fn pruned_paths(num_rows: &[Option<usize>], limit: Option<usize>) -> DaftResult<Vec<String>> {
+        let pushdowns = Pushdowns::default().with_limit(limit);
+        let scan_tasks = num_rows
+            .iter()
+            .enumerate()
+            .map(|(i, &num_rows)| Ok(make_scan_task(&format!("file{i}"), num_rows, &pushdowns)))
+            .collect::<Vec<_>>();
+        let iter: BoxScanTaskIter = Box::new(scan_tasks.into_iter());
+        prune_by_limit(iter, &pushdowns)
+            .map(|t| Ok(t?.sources[0].get_path().to_string()))
+            .collect()
+    }
+
+    /// Tests that the ScanTasks after those that are known to have enough rows for the limit are dropped.
+    #[test]
+    fn limit_prunes_scan_tasks() -> DaftResult<()> {
+        let num_rows = [Some(10), Some(10), Some(10), Some(10)];
+        assert_eq!(pruned_paths(&num_rows, Some(15))?, vec!["file0", "file1"]);
+        assert_eq!(pruned_paths(&num_rows, Some(10))?, vec!["file0"]);
+        assert_eq!(pruned_paths(&num_rows, Some(0))?, vec!["file0"]);
+        assert_eq!(pruned_paths(&num_rows, Some(100))?.len(), 4);
+        assert_eq!(pruned_paths(&num_rows, None)?.len(), 4);
+        Ok(())
+    }
+
+    /// Tests that ScanTasks with unknown numbers of rows don't count towards the limit.
+    #[test]
+    fn limit_keeps_scan_tasks_with_unknown_num_rows() -> DaftResult<()> {
+        let num_rows = [None, Some(10), None, Some(10), Some(10)];
+        assert_eq!(
+            pruned_paths(&num_rows, Some(15))?,
+            vec!["file0", "file1", "file2", "file3"]
+        );
+        Ok(())
+    }
+}
@@ -21,6 +21,9 @@ pub struct StorageConfig {
     // TODO: store Arc<IOConfig> instead
     pub io_config: Option<IOConfig>,
     pub multithreaded_io: bool,
+    /// The number of times the native runner attempts to read each scan task, when reading it fails
+    /// with a transient IO error that the IO layer's own retries didn't get past.
+    pub scan_task_max_attempts: usize,
 }
 
 impl StorageConfig {
@@ -29,9 +32,16 @@ impl StorageConfig {
         Self {
             io_config,
             multithreaded_io,
+            scan_task_max_attempts: 1,
         }
     }
 
+    #[must_use]
+    pub fn with_scan_task_max_attempts(mut self, scan_task_max_attempts: usize) -> Self {
+        self.scan_task_max_attempts = scan_task_max_attempts;
+        self
+    }
+
     pub fn get_io_client_and_runtime(&self) -> DaftResult<(RuntimeRef, Arc<IOClient>)> {
         // Grab an IOClient and Runtime
         // TODO: This should be cleaned up and hidden behind a better API from daft-io
@@ -55,6 +65,12 @@ impl StorageConfig {
             ));
         }
         res.push(format!("Use multithreading = {}", self.multithreaded_io));
+        if self.scan_task_max_attempts > 1 {
+            res.push(format!(
+                "Scan task max attempts = {}",
+                self.scan_task_max_attempts
+            ));
+        }
         res
     }
 }
@@ -75,10 +91,21 @@ impl From<IOConfig> for StorageConfig {
 #[pymethods]
 impl StorageConfig {
     #[new]
-    #[must_use]
-    #[pyo3(signature = (multithreaded_io, io_config=None))]
-    pub fn new(multithreaded_io: bool, io_config: Option<python::IOConfig>) -> Self {
-        Self::new_internal(multithreaded_io, io_config.map(|c| c.config))
+    #[pyo3(signature = (multithreaded_io, io_config=None, scan_task_max_attempts=1))]
+    pub fn new(
+        multithreaded_io: bool,
+        io_config: Option<python::IOConfig>,
+        scan_task_max_attempts: usize,
+    ) -> PyResult<Self> {
+        if scan_task_max_attempts == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "scan_task_max_attempts must be at least 1",
+            ));
+        }
+        Ok(
+            Self::new_internal(multithreaded_io, io_config.map(|c| c.config))
+                .with_scan_task_max_attempts(scan_task_max_attempts),
+        )
     }
 
     #[getter]
@@ -92,6 +119,12 @@ impl StorageConfig {
     pub fn multithreaded_io(&self) -> bool {
         self.multithreaded_io
     }
+
+    #[getter]
+    #[must_use]
+    pub fn scan_task_max_attempts(&self) -> usize {
+        self.scan_task_max_attempts
+    }
 }
 
 impl_bincode_py_state_serialization!(StorageConfig);
@@ -15,8 +15,8 @@ use daft_physical_plan::{
     ops::{
         ActorPoolProject, Aggregate, BroadcastJoin, Concat, EmptyScan, Explode, Filter, HashJoin,
         InMemoryScan, Limit, MonotonicallyIncreasingId, Pivot, Project, Sample, Sort,
-        SortMergeJoin, TabularScan, TabularWriteCsv, TabularWriteJson, TabularWriteParquet,
-        Unpivot,
+        SortMergeJoin, TabularScan, TabularWriteCsv, TabularWriteIpc, TabularWriteJson,
+        TabularWriteParquet, Unpivot, Window,
     },
     PhysicalPlan, PhysicalPlanRef, QueryStageOutput,
 };
@@ -28,7 +28,7 @@ use {
     daft_core::prelude::SchemaRef,
     daft_core::python::PySchema,
     daft_dsl::python::PyExpr,
-    daft_logical_plan::{OutputFileInfo, PyLogicalPlanBuilder},
+    daft_logical_plan::{sink_info::ParquetEncryptionInfo, OutputFileInfo, PyLogicalPlanBuilder},
     daft_scan::python::pylib::PyScanTask,
     pyo3::{
         pyclass, pymethods,
@@ -83,6 +83,10 @@ impl PhysicalPlanScheduler {
         Ok(self.plan().repr_mermaid(options))
     }
 
+    pub fn repr_json(&self, simple: bool) -> PyResult<String> {
+        Ok(self.plan().repr_json(simple))
+    }
+
     pub fn to_json_string(&self) -> PyResult<String> {
         serde_json::to_string(&self.plan())
             .map_err(|e| pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
@@ -162,6 +166,10 @@ fn tabular_write(
     compression: Option<&String>,
     partition_cols: Option<&Vec<ExprRef>>,
     io_config: Option<&IOConfig>,
+    job_id: Option<&String>,
+    bloom_filter_columns: Option<&Vec<String>>,
+    row_group_size_bytes: Option<usize>,
+    encryption: Option<&ParquetEncryptionInfo>,
 ) -> PyResult<PyObject> {
     let py_iter = py
         .import(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
@@ -176,6 +184,10 @@ fn tabular_write(
             io_config.map(|cfg| common_io_config::python::IOConfig {
                 config: cfg.clone(),
             }),
+            job_id,
+            bloom_filter_columns,
+            row_group_size_bytes,
+            encryption.map(|e| e.config.as_ref().clone_ref(py)),
         ))?;
     Ok(py_iter.into())
 }
@@ -453,6 +465,25 @@ fn physical_plan_to_partition_tasks(
                 .call1((upstream_iter, *fraction, *with_replacement, *seed))?;
             Ok(py_iter.into())
         }
+        PhysicalPlan::Window(Window {
+            input,
+            window_functions,
+            window_spec,
+        }) => {
+            let upstream_iter =
+                physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?;
+            let py_iter = py
+                .import(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
+                .getattr(pyo3::intern!(py, "window"))?
+                .call1((
+                    upstream_iter,
+                    exprs_to_pyexprs(window_functions),
+                    exprs_to_pyexprs(&window_spec.partition_by),
+                    exprs_to_pyexprs(&window_spec.order_by),
+                    window_spec.null_strategy.to_string(),
+                ))?;
+            Ok(py_iter.into())
+        }
         PhysicalPlan::MonotonicallyIncreasingId(MonotonicallyIncreasingId {
             input,
             column_name,
@@ -465,12 +496,38 @@ fn physical_plan_to_partition_tasks(
                 .call1((upstream_iter, column_name))?;
             Ok(py_iter.into())
         }
+        PhysicalPlan::Sort(Sort {
+            input,
+            sort_by,
+            descending,
+            nulls_first,
+            within_partitions: true,
+            ..
+        }) => {
+            let upstream_iter =
+                physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?;
+            let sort_by_pyexprs: Vec<PyExpr> = sort_by
+                .iter()
+                .map(|expr| PyExpr::from(expr.clone()))
+                .collect();
+            let py_iter = py
+                .import(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
+                .getattr(pyo3::intern!(py, "sort_within_partitions"))?
+                .call1((
+                    upstream_iter,
+                    sort_by_pyexprs,
+                    descending.clone(),
+                    nulls_first.clone(),
+                ))?;
+            Ok(py_iter.into())
+        }
         PhysicalPlan::Sort(Sort {
             input,
             sort_by,
             descending,
             nulls_first,
             num_partitions,
+            within_partitions: false,
         }) => {
             let upstream_iter =
                 physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?;
@@ -494,6 +551,22 @@ fn physical_plan_to_partition_tasks(
             let upstream_iter =
                 physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?;
             let input_num_partitions = input.clustering_spec().num_partitions();
+            // The boundaries of the ranges are sampled from the materialized input before it's fanned out, so range
+            // partitioning can't be a fanout of the upstream tasks like the other schemes.
+            if let daft_logical_plan::ClusteringSpec::Range(range_clustering_config) =
+                physical_plan.clustering_spec().as_ref()
+            {
+                let py_iter = py
+                    .import(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
+                    .getattr(pyo3::intern!(py, "range_repartition"))?
+                    .call1((
+                        upstream_iter,
+                        range_clustering_config.num_partitions,
+                        exprs_to_pyexprs(&range_clustering_config.by),
+                        range_clustering_config.descending.clone(),
+                    ))?;
+                return Ok(py_iter.into());
+            }
             match strategy {
                 ShuffleExchangeStrategy::NaiveFullyMaterializingMapReduce { target_spec } => {
                     let mapped = match target_spec.as_ref() {
@@ -516,7 +589,7 @@ fn physical_plan_to_partition_tasks(
                             .getattr(pyo3::intern!(py, "fanout_random"))?
                             .call1((upstream_iter, random_clustering_config.num_partitions()))?,
                         daft_logical_plan::ClusteringSpec::Range(_) => {
-                            unimplemented!("FanoutByRange not implemented, since only use case (sorting) doesn't need it yet.");
+                            unreachable!("Range partitioning should have been planned above");
                         }
                         daft_logical_plan::ClusteringSpec::Unknown(_) => {
                             unreachable!("Cannot use NaiveFullyMaterializingMapReduce ShuffleExchange to map to an Unknown ClusteringSpec");
@@ -559,7 +632,7 @@ fn physical_plan_to_partition_tasks(
                             .getattr(pyo3::intern!(py, "fanout_random"))?
                             .call1((merged, random_clustering_config.num_partitions()))?,
                         daft_logical_plan::ClusteringSpec::Range(_) => {
-                            unimplemented!("FanoutByRange not implemented, since only use case (sorting) doesn't need it yet.");
+                            unreachable!("Range partitioning should have been planned above");
                         }
                         daft_logical_plan::ClusteringSpec::Unknown(_) => {
                             unreachable!("Cannot use NaiveFullyMaterializingMapReduce ShuffleExchange to map to an Unknown ClusteringSpec");
@@ -810,6 +883,10 @@ fn physical_plan_to_partition_tasks(
                     partition_cols,
                     compression,
                     io_config,
+                    job_id,
+                    bloom_filter_columns,
+                    row_group_size_bytes,
+                    encryption,
                 },
             input,
         }) => tabular_write(
@@ -821,6 +898,10 @@ fn physical_plan_to_partition_tasks(
             compression.as_ref(),
             partition_cols.as_ref(),
             io_config.as_ref(),
+            job_id.as_ref(),
+            bloom_filter_columns.as_ref(),
+            *row_group_size_bytes,
+            encryption.as_ref(),
         ),
         PhysicalPlan::TabularWriteCsv(TabularWriteCsv {
             schema,
@@ -831,6 +912,10 @@ fn physical_plan_to_partition_tasks(
                     partition_cols,
                     compression,
                     io_config,
+                    job_id,
+                    bloom_filter_columns,
+                    row_group_size_bytes,
+                    encryption,
                 },
             input,
         }) => tabular_write(
@@ -842,6 +927,10 @@ fn physical_plan_to_partition_tasks(
             compression.as_ref(),
             partition_cols.as_ref(),
             io_config.as_ref(),
+            job_id.as_ref(),
+            bloom_filter_columns.as_ref(),
+            *row_group_size_bytes,
+            encryption.as_ref(),
         ),
         PhysicalPlan::TabularWriteJson(TabularWriteJson {
             schema,
@@ -852,6 +941,39 @@ fn physical_plan_to_partition_tasks(
                     partition_cols,
                     compression,
                     io_config,
+                    job_id,
+                    bloom_filter_columns,
+                    row_group_size_bytes,
+                    encryption,
+                },
+            input,
+        }) => tabular_write(
+            py,
+            physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?,
+            file_format,
+            schema,
+            root_dir,
+            compression.as_ref(),
+            partition_cols.as_ref(),
+            io_config.as_ref(),
+            job_id.as_ref(),
+            bloom_filter_columns.as_ref(),
+            *row_group_size_bytes,
+            encryption.as_ref(),
+        ),
+        PhysicalPlan::TabularWriteIpc(TabularWriteIpc {
+            schema,
+            file_info:
+                OutputFileInfo {
+                    root_dir,
+                    file_format,
+                    partition_cols,
+                    compression,
+                    io_config,
+                    job_id,
+                    bloom_filter_columns,
+                    row_group_size_bytes,
+                    encryption,
                 },
             input,
         }) => tabular_write(
@@ -863,6 +985,10 @@ fn physical_plan_to_partition_tasks(
             compression.as_ref(),
             partition_cols.as_ref(),
             io_config.as_ref(),
+            job_id.as_ref(),
+            bloom_filter_columns.as_ref(),
+            *row_group_size_bytes,
+            encryption.as_ref(),
         ),
         #[cfg(feature = "python")]
         PhysicalPlan::IcebergWrite(IcebergWrite {
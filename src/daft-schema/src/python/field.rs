@@ -3,7 +3,7 @@ use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use super::datatype::PyDataType;
-use crate::field::Field;
+use crate::field::{Field, Metadata};
 
 #[pyclass(module = "daft.daft")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,8 +14,11 @@ pub struct PyField {
 #[pymethods]
 impl PyField {
     #[staticmethod]
-    pub fn create(name: &str, data_type: PyDataType) -> PyResult<Self> {
-        Ok(Field::new(name, data_type.dtype).into())
+    #[pyo3(signature = (name, data_type, metadata=None))]
+    pub fn create(name: &str, data_type: PyDataType, metadata: Option<Metadata>) -> PyResult<Self> {
+        Ok(Field::new(name, data_type.dtype)
+            .with_metadata(metadata.unwrap_or_default())
+            .into())
     }
 
     pub fn name(&self) -> PyResult<String> {
@@ -26,6 +29,10 @@ impl PyField {
         Ok(self.field.dtype.clone().into())
     }
 
+    pub fn metadata(&self) -> PyResult<Metadata> {
+        Ok(self.field.metadata.as_ref().clone())
+    }
+
     pub fn eq(&self, other: &Self) -> PyResult<bool> {
         Ok(self.field.eq(&other.field))
     }
@@ -30,10 +30,13 @@ impl PySchema {
                 // the registered Arrow extension types
                 let py_dtype: PyDataType = f.dtype.clone().into();
                 let py_arrow_dtype = py_dtype.to_arrow(py)?;
-                pyarrow
-                    .getattr(pyo3::intern!(py, "field"))
-                    .unwrap()
-                    .call1((f.name.clone(), py_arrow_dtype))
+                let metadata = (!f.metadata.is_empty()).then(|| f.metadata.as_ref().clone());
+                pyarrow.getattr(pyo3::intern!(py, "field")).unwrap().call1((
+                    f.name.clone(),
+                    py_arrow_dtype,
+                    true,
+                    metadata,
+                ))
             })
             .collect::<PyResult<Vec<_>>>()?;
         pyarrow
@@ -56,6 +59,10 @@ impl PySchema {
         Ok(self.schema.fields.eq(&other.schema.fields))
     }
 
+    pub fn validate_matches(&self, expected: &Self) -> PyResult<()> {
+        Ok(self.schema.validate_matches(&expected.schema)?)
+    }
+
     pub fn estimate_row_size_bytes(&self) -> PyResult<f64> {
         Ok(self.schema.estimate_row_size_bytes())
     }
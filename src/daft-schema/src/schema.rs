@@ -156,6 +156,49 @@ impl Schema {
         })
     }
 
+    /// Checks that this schema matches `expected` exactly, returning a [`DaftError::SchemaMismatch`]
+    /// that lists every missing, unexpected, mistyped or reordered column otherwise.
+    pub fn validate_matches(&self, expected: &Self) -> DaftResult<()> {
+        let mut diffs = vec![];
+        for (name, expected_field) in &expected.fields {
+            match self.fields.get(name) {
+                None => diffs.push(format!(
+                    "missing column `{name}` of type {}",
+                    expected_field.dtype
+                )),
+                Some(field) if field.dtype != expected_field.dtype => diffs.push(format!(
+                    "column `{name}` has type {}, expected {}",
+                    field.dtype, expected_field.dtype
+                )),
+                Some(_) => {}
+            }
+        }
+        for (name, field) in &self.fields {
+            if !expected.fields.contains_key(name) {
+                diffs.push(format!(
+                    "unexpected column `{name}` of type {}",
+                    field.dtype
+                ));
+            }
+        }
+        if diffs.is_empty() && !self.fields.keys().eq(expected.fields.keys()) {
+            diffs.push(format!(
+                "columns are ordered as [{}], expected [{}]",
+                self.names().join(", "),
+                expected.names().join(", ")
+            ));
+        }
+
+        if diffs.is_empty() {
+            Ok(())
+        } else {
+            Err(DaftError::SchemaMismatch(format!(
+                "Schema does not match the expected schema:\n  - {}",
+                diffs.join("\n  - ")
+            )))
+        }
+    }
+
     pub fn to_arrow(&self) -> DaftResult<arrow2::datatypes::Schema> {
         let arrow_fields: DaftResult<Vec<arrow2::datatypes::Field>> =
             self.fields.iter().map(|(_, f)| f.to_arrow()).collect();
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use daft_dsl::ExprRef;
+
+use crate::error::Result;
+
+/// Function is a temporary function scoped to a session, which SQL can call by name.
+pub trait Function: Sync + Send + std::fmt::Debug {
+    /// Returns the expression for applying this function to the given arguments.
+    fn call(&self, args: Vec<ExprRef>) -> Result<ExprRef>;
+
+    /// Returns the inner python object for a python function.
+    #[cfg(feature = "python")]
+    fn to_py(&self, _: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
+        Err(crate::error::Error::unsupported(
+            "Only python functions can be converted to python objects",
+        )
+        .into())
+    }
+}
+
+/// Reference-counted function.
+pub type FunctionRef = Arc<dyn Function>;
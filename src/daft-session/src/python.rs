@@ -1,10 +1,13 @@
+use std::sync::Arc;
+
 use daft_catalog::{
     python::{PyCatalogWrapper, PyIdentifier, PyTable, PyTableSource, PyTableWrapper},
     Identifier,
 };
-use pyo3::prelude::*;
+use daft_dsl::{python::PyExpr, ExprRef};
+use pyo3::{intern, prelude::*, types::PyTuple};
 
-use crate::Session;
+use crate::{error::Result, Function, FunctionRef, Session};
 
 #[pyclass]
 pub struct PySession(Session);
@@ -22,6 +25,12 @@ impl PySession {
             .attach_catalog(PyCatalogWrapper::wrap(catalog), alias)?)
     }
 
+    pub fn attach_function(&self, function: PyObject, alias: String) -> PyResult<()> {
+        Ok(self
+            .0
+            .attach_function(PyFunctionWrapper::wrap(function), alias)?)
+    }
+
     pub fn attach_table(&self, table: PyObject, alias: String) -> PyResult<()> {
         Ok(self.0.attach_table(PyTableWrapper::wrap(table), alias)?)
     }
@@ -30,6 +39,10 @@ impl PySession {
         Ok(self.0.detach_catalog(alias)?)
     }
 
+    pub fn detach_function(&self, alias: &str) -> PyResult<()> {
+        Ok(self.0.detach_function(alias)?)
+    }
+
     pub fn detach_table(&self, alias: &str) -> PyResult<()> {
         Ok(self.0.detach_table(alias)?)
     }
@@ -62,6 +75,10 @@ impl PySession {
         self.0.get_catalog(name)?.to_py(py)
     }
 
+    pub fn get_function(&self, py: Python<'_>, name: &str) -> PyResult<PyObject> {
+        self.0.get_function(name)?.to_py(py)
+    }
+
     pub fn get_table(&self, py: Python<'_>, ident: &PyIdentifier) -> PyResult<PyObject> {
         self.0.get_table(ident.as_ref())?.to_py(py)
     }
@@ -70,6 +87,10 @@ impl PySession {
         Ok(self.0.has_catalog(name))
     }
 
+    pub fn has_function(&self, name: &str) -> PyResult<bool> {
+        Ok(self.0.has_function(name))
+    }
+
     pub fn has_table(&self, ident: &PyIdentifier) -> PyResult<bool> {
         Ok(self.0.has_table(ident.as_ref()))
     }
@@ -79,6 +100,11 @@ impl PySession {
         Ok(self.0.list_catalogs(pattern)?)
     }
 
+    #[pyo3(signature = (pattern=None))]
+    pub fn list_functions(&self, pattern: Option<&str>) -> PyResult<Vec<String>> {
+        Ok(self.0.list_functions(pattern)?)
+    }
+
     #[pyo3(signature = (pattern=None))]
     pub fn list_tables(&self, pattern: Option<&str>) -> PyResult<Vec<String>> {
         Ok(self.0.list_tables(pattern)?)
@@ -101,6 +127,56 @@ impl From<&PySession> for Session {
     }
 }
 
+impl From<Session> for PySession {
+    fn from(sess: Session) -> Self {
+        Self(sess)
+    }
+}
+
+/// PyFunctionWrapper wraps a python callable which builds an expression from its argument expressions, e.g. a daft UDF.
+#[derive(Debug)]
+pub struct PyFunctionWrapper(PyObject);
+
+impl From<PyObject> for PyFunctionWrapper {
+    fn from(obj: PyObject) -> Self {
+        Self(obj)
+    }
+}
+
+impl PyFunctionWrapper {
+    pub fn wrap(obj: PyObject) -> FunctionRef {
+        Arc::new(Self::from(obj))
+    }
+}
+
+impl Function for PyFunctionWrapper {
+    fn call(&self, args: Vec<ExprRef>) -> Result<ExprRef> {
+        Python::with_gil(|py| {
+            let from_pyexpr = py
+                .import(intern!(py, "daft.expressions.expressions"))?
+                .getattr(intern!(py, "Expression"))?
+                .getattr(intern!(py, "_from_pyexpr"))?;
+            // args = [Expression._from_pyexpr(arg) for arg in args]
+            let args = args
+                .into_iter()
+                .map(|arg| from_pyexpr.call1((PyExpr::from(arg),)))
+                .collect::<PyResult<Vec<_>>>()?;
+            // expr = function(*args)._expr
+            let expr = self
+                .0
+                .bind(py)
+                .call1(PyTuple::new(py, args)?)?
+                .getattr(intern!(py, "_expr"))?
+                .extract::<PyExpr>()?;
+            Ok(expr.expr)
+        })
+    }
+
+    fn to_py(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(self.0.clone_ref(py))
+    }
+}
+
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_class::<PySession>()?;
     Ok(())
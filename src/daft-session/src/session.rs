@@ -5,6 +5,7 @@ use uuid::Uuid;
 
 use crate::{
     error::Result, obj_already_exists_err, obj_not_found_err, options::Options, unsupported_err,
+    FunctionRef,
 };
 
 /// Session holds all state for query planning and execution (e.g. connection).
@@ -25,7 +26,8 @@ struct SessionState {
     catalogs: Bindings<CatalogRef>,
     /// Bindings for the attached tables.
     tables: Bindings<TableRef>,
-    // TODO execution context
+    /// Bindings for the temporary functions.
+    functions: Bindings<FunctionRef>,
     // TODO identifier matcher for case-insensitive matching
 }
 
@@ -37,6 +39,7 @@ impl Session {
             options: Options::default(),
             catalogs: Bindings::empty(),
             tables: Bindings::empty(),
+            functions: Bindings::empty(),
         };
         let state = RwLock::new(state);
         let state = Arc::new(state);
@@ -76,6 +79,16 @@ impl Session {
         Ok(())
     }
 
+    /// Attaches a temporary function to this session, err if already exists.
+    pub fn attach_function(&self, function: FunctionRef, alias: impl Into<String>) -> Result<()> {
+        let alias = alias.into();
+        if self.state().functions.exists(&alias) {
+            obj_already_exists_err!("Function", &alias.into())
+        }
+        self.state_mut().functions.insert(alias, function);
+        Ok(())
+    }
+
     /// Creates a temp table scoped to this session from an existing view.
     ///
     /// TODO feat: consider making a CreateTableSource object for more complicated options.
@@ -125,6 +138,15 @@ impl Session {
         Ok(())
     }
 
+    /// Detaches a temporary function from this session, err if does not exist.
+    pub fn detach_function(&self, alias: &str) -> Result<()> {
+        if !self.state().functions.exists(alias) {
+            obj_not_found_err!("Function", &alias.into())
+        }
+        self.state_mut().functions.remove(alias);
+        Ok(())
+    }
+
     /// Detaches a catalog from this session, err if does not exist.
     pub fn detach_catalog(&self, alias: &str) -> Result<()> {
         if !self.state().catalogs.exists(alias) {
@@ -147,6 +169,15 @@ impl Session {
         }
     }
 
+    /// Returns the temporary function or an object not found error.
+    pub fn get_function(&self, name: &str) -> Result<FunctionRef> {
+        if let Some(function) = self.state().functions.get(name) {
+            Ok(function.clone())
+        } else {
+            obj_not_found_err!("Function", &name.into())
+        }
+    }
+
     /// Returns the table or an object not found error.
     pub fn get_table(&self, name: &Identifier) -> Result<TableRef> {
         //
@@ -195,6 +226,11 @@ impl Session {
         self.state().catalogs.exists(name)
     }
 
+    /// Returns true iff the session has a matching temporary function.
+    pub fn has_function(&self, name: &str) -> bool {
+        self.state().functions.exists(name)
+    }
+
     /// Returns true iff the session has access to a matching table.
     pub fn has_table(&self, name: &Identifier) -> bool {
         self.get_table(name).is_ok()
@@ -205,6 +241,11 @@ impl Session {
         Ok(self.state().catalogs.list(pattern))
     }
 
+    /// Lists all temporary functions matching the pattern.
+    pub fn list_functions(&self, pattern: Option<&str>) -> Result<Vec<String>> {
+        Ok(self.state().functions.list(pattern))
+    }
+
     /// Lists all tables matching the pattern.
     pub fn list_tables(&self, pattern: Option<&str>) -> Result<Vec<String>> {
         Ok(self.state().tables.list(pattern))
@@ -300,4 +341,33 @@ mod tests {
             .get_table(&Identifier::simple("non_existent_table"))
             .is_err());
     }
+
+    #[derive(Debug)]
+    struct Negate;
+
+    impl crate::Function for Negate {
+        fn call(&self, args: Vec<daft_dsl::ExprRef>) -> Result<daft_dsl::ExprRef> {
+            Ok(args[0].clone().not())
+        }
+    }
+
+    #[test]
+    fn test_attach_function() {
+        let sess = Session::empty();
+        sess.attach_function(Arc::new(Negate), "negate")
+            .expect("failed to attach function");
+
+        // Attaching the same alias twice is an error
+        assert!(sess.attach_function(Arc::new(Negate), "negate").is_err());
+        assert!(sess.has_function("negate"));
+        assert_eq!(sess.list_functions(None).unwrap(), vec!["negate"]);
+
+        let function = sess.get_function("negate").unwrap();
+        let expr = function.call(vec![daft_dsl::resolved_col("a")]).unwrap();
+        assert_eq!(expr, daft_dsl::resolved_col("a").not());
+
+        sess.detach_function("negate").unwrap();
+        assert!(!sess.has_function("negate"));
+        assert!(sess.get_function("negate").is_err());
+    }
 }
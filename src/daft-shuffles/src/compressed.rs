@@ -0,0 +1,51 @@
+use arrow2::io::ipc::write::Compression;
+use common_error::DaftResult;
+use daft_core::prelude::SchemaRef;
+use daft_micropartition::MicroPartition;
+
+use crate::ipc;
+
+/// A partition kept in memory as an Arrow IPC stream whose buffers are LZ4 compressed.
+///
+/// Holding cached or shuffled partitions this way trades the CPU time of (de)compressing them for
+/// a smaller memory footprint; [`CompressedPartition::decompress`] loads the partition again.
+#[derive(Debug)]
+pub struct CompressedPartition {
+    schema: SchemaRef,
+    num_rows: usize,
+    data: Vec<u8>,
+}
+
+impl CompressedPartition {
+    pub fn try_new(partition: &MicroPartition) -> DaftResult<Self> {
+        let mut data = vec![];
+        ipc::write_partition(&mut data, partition, Some(Compression::LZ4))?;
+        data.shrink_to_fit();
+        Ok(Self {
+            schema: partition.schema(),
+            num_rows: partition.len(),
+            data,
+        })
+    }
+
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_rows == 0
+    }
+
+    /// The number of bytes taken by the compressed data.
+    pub fn size_bytes(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn decompress(&self) -> DaftResult<MicroPartition> {
+        ipc::read_partition(self.data.as_slice(), "Compressed partition")
+    }
+}
@@ -0,0 +1,115 @@
+use std::{
+    fs::{self, File},
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use common_error::{DaftError, DaftResult};
+use daft_micropartition::{MicroPartition, MicroPartitionRef};
+
+use crate::{ipc, ReduceInputIterator, ShuffleId, ShuffleService};
+
+/// Spills shuffled partitions to Arrow IPC files on local disk.
+///
+/// Each map output for a reducer is written to `<root>/<shuffle_id>/<reduce_id>/<map_id>.arrow`.
+#[derive(Debug)]
+pub struct FileShuffleService {
+    root: PathBuf,
+}
+
+impl FileShuffleService {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Creates a service that writes to a new directory under the system's temp directory.
+    pub fn try_new_in_temp_dir() -> DaftResult<Self> {
+        let root = std::env::temp_dir().join(format!("daft-shuffle-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&root)?;
+        Ok(Self::new(root))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn shuffle_dir(&self, shuffle_id: ShuffleId) -> PathBuf {
+        self.root.join(shuffle_id.to_string())
+    }
+
+    fn reduce_dir(&self, shuffle_id: ShuffleId, reduce_id: usize) -> PathBuf {
+        self.shuffle_dir(shuffle_id).join(reduce_id.to_string())
+    }
+}
+
+fn write_partition(path: &Path, partition: &MicroPartition) -> DaftResult<()> {
+    let file = BufWriter::new(File::create_new(path).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::AlreadyExists {
+            DaftError::ValueError(format!(
+                "Map output was already written to {}",
+                path.display()
+            ))
+        } else {
+            e.into()
+        }
+    })?);
+    ipc::write_partition(file, partition, None)
+}
+
+fn read_partition(path: &Path) -> DaftResult<MicroPartition> {
+    let file = BufReader::new(File::open(path)?);
+    ipc::read_partition(file, &format!("Shuffle file {}", path.display()))
+}
+
+impl ShuffleService for FileShuffleService {
+    fn write_map_output(
+        &self,
+        shuffle_id: ShuffleId,
+        map_id: usize,
+        partitions: Vec<MicroPartitionRef>,
+    ) -> DaftResult<()> {
+        for (reduce_id, partition) in partitions.iter().enumerate() {
+            let dir = self.reduce_dir(shuffle_id, reduce_id);
+            fs::create_dir_all(&dir)?;
+            write_partition(&dir.join(format!("{map_id}.arrow")), partition)?;
+        }
+        Ok(())
+    }
+
+    fn fetch_reduce_input(
+        &self,
+        shuffle_id: ShuffleId,
+        reduce_id: usize,
+    ) -> DaftResult<ReduceInputIterator> {
+        let dir = self.reduce_dir(shuffle_id, reduce_id);
+        let mut paths = match fs::read_dir(&dir) {
+            Ok(entries) => entries
+                .map(|entry| {
+                    let path = entry?.path();
+                    let map_id = path
+                        .file_stem()
+                        .and_then(|stem| stem.to_str()?.parse::<usize>().ok());
+                    Ok(map_id.map(|map_id| (map_id, path)))
+                })
+                .filter_map(Result::transpose)
+                .collect::<DaftResult<Vec<_>>>()?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(e) => return Err(e.into()),
+        };
+        paths.sort_unstable_by_key(|(map_id, _)| *map_id);
+        // Only read a map output once the reducer asks for it.
+        Ok(Box::new(
+            paths
+                .into_iter()
+                .map(|(_, path)| read_partition(&path).map(Arc::new)),
+        ))
+    }
+
+    fn remove_shuffle(&self, shuffle_id: ShuffleId) -> DaftResult<()> {
+        match fs::remove_dir_all(self.shuffle_dir(shuffle_id)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+};
+
+use common_error::{DaftError, DaftResult};
+use daft_micropartition::MicroPartitionRef;
+
+use crate::{CompressedPartition, ReduceInputIterator, ShuffleId, ShuffleService};
+
+#[derive(Debug, Clone)]
+enum StoredPartition {
+    Loaded(MicroPartitionRef),
+    Compressed(Arc<CompressedPartition>),
+}
+
+impl StoredPartition {
+    fn load(self) -> DaftResult<MicroPartitionRef> {
+        match self {
+            Self::Loaded(partition) => Ok(partition),
+            Self::Compressed(partition) => Ok(Arc::new(partition.decompress()?)),
+        }
+    }
+}
+
+/// Partitions written to a shuffle, by reducer and then by map task.
+type ShufflePartitions = HashMap<usize, BTreeMap<usize, StoredPartition>>;
+
+/// Keeps all shuffled partitions in memory.
+#[derive(Debug, Default)]
+pub struct InMemoryShuffleService {
+    shuffles: Mutex<HashMap<ShuffleId, ShufflePartitions>>,
+    compress: bool,
+}
+
+impl InMemoryShuffleService {
+    /// Creates a service that keeps partitions as [`CompressedPartition`]s until they're fetched.
+    pub fn compressed() -> Self {
+        Self {
+            shuffles: Mutex::default(),
+            compress: true,
+        }
+    }
+}
+
+impl ShuffleService for InMemoryShuffleService {
+    fn write_map_output(
+        &self,
+        shuffle_id: ShuffleId,
+        map_id: usize,
+        partitions: Vec<MicroPartitionRef>,
+    ) -> DaftResult<()> {
+        let partitions = partitions
+            .into_iter()
+            .map(|partition| {
+                Ok(if self.compress {
+                    StoredPartition::Compressed(Arc::new(CompressedPartition::try_new(&partition)?))
+                } else {
+                    StoredPartition::Loaded(partition)
+                })
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        let mut shuffles = self.shuffles.lock().unwrap();
+        let shuffle = shuffles.entry(shuffle_id).or_default();
+        for (reduce_id, partition) in partitions.into_iter().enumerate() {
+            if shuffle
+                .entry(reduce_id)
+                .or_default()
+                .insert(map_id, partition)
+                .is_some()
+            {
+                return Err(DaftError::ValueError(format!(
+                    "Map output {map_id} was already written for shuffle {shuffle_id}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn fetch_reduce_input(
+        &self,
+        shuffle_id: ShuffleId,
+        reduce_id: usize,
+    ) -> DaftResult<ReduceInputIterator> {
+        let shuffles = self.shuffles.lock().unwrap();
+        let parts = shuffles
+            .get(&shuffle_id)
+            .and_then(|shuffle| shuffle.get(&reduce_id))
+            .map(|parts| parts.values().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+        // Compressed partitions are only decompressed once the reducer asks for them.
+        Ok(Box::new(parts.into_iter().map(StoredPartition::load)))
+    }
+
+    fn remove_shuffle(&self, shuffle_id: ShuffleId) -> DaftResult<()> {
+        self.shuffles.lock().unwrap().remove(&shuffle_id);
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use std::{
+    io::{Read, Write},
+    sync::Arc,
+};
+
+use arrow2::io::ipc::{
+    read::{read_stream_metadata, StreamReader, StreamState},
+    write::{Compression, StreamWriter, WriteOptions},
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+
+/// Key of the Arrow schema metadata entry that holds the Daft schema, so that logical types
+/// survive the round trip through Arrow IPC.
+const DAFT_SCHEMA_KEY: &str = "daft.schema";
+
+/// Writes the record batches of `partition` as an Arrow IPC stream, compressing its buffers with
+/// `compression` if set.
+pub(crate) fn write_partition<W: Write>(
+    mut writer: W,
+    partition: &MicroPartition,
+    compression: Option<Compression>,
+) -> DaftResult<()> {
+    let schema = partition.schema();
+    let mut arrow_schema = schema.to_arrow()?;
+    arrow_schema.metadata.insert(
+        DAFT_SCHEMA_KEY.to_string(),
+        serde_json::to_string(&*schema)?,
+    );
+
+    let mut writer = StreamWriter::new(&mut writer, WriteOptions { compression });
+    writer.start(&arrow_schema, None)?;
+    for batch in partition.get_tables()?.iter() {
+        let chunk = arrow2::chunk::Chunk::new(batch.get_inner_arrow_arrays().collect());
+        writer.write(&chunk, None)?;
+    }
+    writer.finish()?;
+    Ok(())
+}
+
+/// Reads a partition written by [`write_partition`], `source` describing where it's read from.
+pub(crate) fn read_partition<R: Read>(reader: R, source: &str) -> DaftResult<MicroPartition> {
+    let (schema, batches) = read_batches(reader, source)?;
+    let batches = batches.collect::<DaftResult<Vec<_>>>()?;
+    Ok(MicroPartition::new_loaded(schema, Arc::new(batches), None))
+}
+
+/// Reads the schema of a partition written by [`write_partition`], and returns it with an iterator
+/// that reads its record batches one at a time.
+pub(crate) fn read_batches<R: Read>(
+    mut reader: R,
+    source: &str,
+) -> DaftResult<(SchemaRef, impl Iterator<Item = DaftResult<RecordBatch>>)> {
+    let metadata = read_stream_metadata(&mut reader)?;
+    let schema: SchemaRef = match metadata.schema.metadata.get(DAFT_SCHEMA_KEY) {
+        Some(schema) => Arc::new(serde_json::from_str(schema)?),
+        None => Arc::new(Schema::try_from(&metadata.schema)?),
+    };
+
+    let source = source.to_string();
+    let batch_schema = schema.clone();
+    let batches = StreamReader::new(reader, metadata, None).map(move |state| match state? {
+        StreamState::Some(chunk) => {
+            let num_rows = chunk.len();
+            let columns = chunk
+                .into_arrays()
+                .into_iter()
+                .zip(batch_schema.fields.values())
+                .map(|(array, field)| Series::try_from_field_and_arrow_array(field.clone(), array))
+                .collect::<DaftResult<Vec<_>>>()?;
+            RecordBatch::new_with_size(batch_schema.clone(), columns, num_rows)
+        }
+        StreamState::Waiting => Err(DaftError::InternalError(format!("{source} is incomplete"))),
+    });
+    Ok((schema, batches))
+}
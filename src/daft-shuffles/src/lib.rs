@@ -0,0 +1,202 @@
+//! Exchanging data between the map and reduce sides of a shuffle.
+//!
+//! A map task splits its output into one partition per reducer (e.g. with
+//! `MicroPartition::partition_by_hash`) and writes them to a [`ShuffleService`]; each reducer then
+//! fetches its partition of every map output. Reduce inputs are fetched lazily, one map output at
+//! a time, so implementations that keep the data outside of memory don't need all of a shuffle to
+//! fit in RAM. Partitions can also be held in memory as LZ4-compressed Arrow IPC with
+//! [`CompressedPartition`], e.g. by [`InMemoryShuffleService::compressed`], and operators whose
+//! state doesn't fit in memory can spill partitions to local disk with [`SpillFile`].
+
+mod compressed;
+mod file;
+mod in_memory;
+mod ipc;
+mod spill;
+
+use std::sync::Arc;
+
+use common_error::DaftResult;
+pub use compressed::CompressedPartition;
+use daft_core::prelude::SchemaRef;
+use daft_micropartition::{MicroPartition, MicroPartitionRef};
+pub use file::FileShuffleService;
+pub use in_memory::InMemoryShuffleService;
+pub use spill::{SpillFile, SpilledBatches};
+
+/// Identifies a shuffle, e.g. by the id of the stage whose output is being shuffled.
+pub type ShuffleId = usize;
+
+/// An iterator over the map outputs for a reduce partition, in map order.
+pub type ReduceInputIterator = Box<dyn Iterator<Item = DaftResult<MicroPartitionRef>> + Send>;
+
+pub trait ShuffleService: std::fmt::Debug + Send + Sync {
+    /// Stores the output of map task `map_id`, which has one partition per reducer.
+    fn write_map_output(
+        &self,
+        shuffle_id: ShuffleId,
+        map_id: usize,
+        partitions: Vec<MicroPartitionRef>,
+    ) -> DaftResult<()>;
+
+    /// Fetches the partitions written for reducer `reduce_id` by every map task.
+    fn fetch_reduce_input(
+        &self,
+        shuffle_id: ShuffleId,
+        reduce_id: usize,
+    ) -> DaftResult<ReduceInputIterator>;
+
+    /// Drops all of the data written for a shuffle.
+    fn remove_shuffle(&self, shuffle_id: ShuffleId) -> DaftResult<()>;
+}
+
+pub type ShuffleServiceRef = Arc<dyn ShuffleService>;
+
+/// Merges the reduce inputs for `reduce_id` into a single partition with the given schema.
+///
+/// The batches of the map outputs are kept as they are rather than copied into a single batch,
+/// and a reducer without any map outputs gets an empty partition.
+pub fn reduce_merge(
+    service: &dyn ShuffleService,
+    shuffle_id: ShuffleId,
+    reduce_id: usize,
+    schema: SchemaRef,
+) -> DaftResult<MicroPartition> {
+    let parts = service
+        .fetch_reduce_input(shuffle_id, reduce_id)?
+        .collect::<DaftResult<Vec<_>>>()?;
+    if parts.is_empty() {
+        return Ok(MicroPartition::empty(Some(schema)));
+    }
+    MicroPartition::concat(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::resolved_col;
+    use daft_micropartition::MicroPartition;
+    use daft_recordbatch::RecordBatch;
+
+    use crate::{
+        reduce_merge, CompressedPartition, FileShuffleService, InMemoryShuffleService,
+        ShuffleService, SpillFile,
+    };
+
+    fn map_output(values: Vec<i64>, num_reducers: usize) -> DaftResult<Vec<Arc<MicroPartition>>> {
+        let batch =
+            RecordBatch::from_nonempty_columns(
+                vec![Int64Array::from(("a", values)).into_series()],
+            )?;
+        let mp = MicroPartition::new_loaded(batch.schema.clone(), Arc::new(vec![batch]), None);
+        Ok(mp
+            .partition_by_hash(&[resolved_col("a")], num_reducers)?
+            .into_iter()
+            .map(Arc::new)
+            .collect())
+    }
+
+    fn check_shuffle(service: &dyn ShuffleService) -> DaftResult<()> {
+        service.write_map_output(0, 0, map_output(vec![1, 2, 3, 4], 2)?)?;
+        service.write_map_output(0, 1, map_output(vec![2, 4, 6], 2)?)?;
+
+        let mut values = vec![];
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64)])?);
+        for reduce_id in 0..2 {
+            let merged = reduce_merge(service, 0, reduce_id, schema.clone())?;
+            let batch = RecordBatch::concat(merged.get_tables()?.as_slice())?;
+            let column = batch.get_column("a")?.i64()?;
+            values.push(column.as_slice().to_vec());
+        }
+        // Equal keys always end up with the same reducer.
+        assert_eq!(values.iter().map(Vec::len).sum::<usize>(), 7);
+        for v in [2, 4] {
+            assert!(values
+                .iter()
+                .any(|vals| vals.iter().filter(|x| **x == v).count() == 2));
+        }
+
+        service.remove_shuffle(0)?;
+        assert_eq!(service.fetch_reduce_input(0, 0)?.count(), 0);
+        let merged = reduce_merge(service, 0, 0, schema)?;
+        assert_eq!(merged.len(), 0);
+        assert_eq!(merged.schema().names(), vec!["a"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_in_memory_shuffle() -> DaftResult<()> {
+        check_shuffle(&InMemoryShuffleService::default())
+    }
+
+    #[test]
+    fn test_compressed_in_memory_shuffle() -> DaftResult<()> {
+        check_shuffle(&InMemoryShuffleService::compressed())
+    }
+
+    #[test]
+    fn test_compressed_partition() -> DaftResult<()> {
+        let batch = RecordBatch::from_nonempty_columns(vec![
+            Int64Array::from(("a", vec![7; 4096])).into_series(),
+            Utf8Array::from_values("b", std::iter::repeat_n("daft", 4096)).into_series(),
+        ])?;
+        let mp = MicroPartition::new_loaded(batch.schema.clone(), Arc::new(vec![batch]), None);
+        let compressed = CompressedPartition::try_new(&mp)?;
+        assert_eq!(compressed.len(), 4096);
+        assert!(compressed.size_bytes() < mp.size_bytes()?.unwrap());
+
+        let decompressed = compressed.decompress()?;
+        assert_eq!(decompressed.schema(), mp.schema());
+        assert_eq!(
+            RecordBatch::concat(decompressed.get_tables()?.as_slice())?,
+            RecordBatch::concat(mp.get_tables()?.as_slice())?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_file_shuffle() -> DaftResult<()> {
+        let service = FileShuffleService::try_new_in_temp_dir()?;
+        check_shuffle(&service)?;
+
+        // Partitions with no rows keep their schema.
+        let schema: SchemaRef = Schema::new(vec![Field::new("a", DataType::Int64)])?.into();
+        let empty = Arc::new(MicroPartition::empty(Some(schema.clone())));
+        service.write_map_output(1, 0, vec![empty])?;
+        let merged = reduce_merge(&service, 1, 0, schema)?;
+        assert_eq!(merged.len(), 0);
+        assert_eq!(merged.schema().names(), vec!["a"]);
+
+        std::fs::remove_dir_all(service.root())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_spill_file() -> DaftResult<()> {
+        let batches = vec![
+            RecordBatch::from_nonempty_columns(vec![
+                Int64Array::from(("a", vec![1, 2])).into_series()
+            ])?,
+            RecordBatch::from_nonempty_columns(vec![
+                Int64Array::from(("a", vec![3])).into_series()
+            ])?,
+        ];
+        let mp =
+            MicroPartition::new_loaded(batches[0].schema.clone(), Arc::new(batches.clone()), None);
+        let spill_file = SpillFile::try_new(&mp)?;
+        assert_eq!(spill_file.len(), 3);
+
+        // Batches are read back one at a time, as they were written.
+        let read = spill_file.read_batches()?.collect::<DaftResult<Vec<_>>>()?;
+        assert_eq!(read, batches);
+
+        let path = spill_file.path().to_path_buf();
+        assert!(path.exists());
+        drop(spill_file);
+        assert!(!path.exists());
+        Ok(())
+    }
+}
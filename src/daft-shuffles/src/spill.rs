@@ -0,0 +1,71 @@
+use std::{
+    fs::{self, File},
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
+};
+
+use common_error::DaftResult;
+use daft_core::prelude::SchemaRef;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+
+use crate::ipc;
+
+/// An iterator over the record batches of a [`SpillFile`], which reads them one at a time.
+pub type SpilledBatches = Box<dyn Iterator<Item = DaftResult<RecordBatch>> + Send>;
+
+/// A partition spilled to an Arrow IPC file under the system's temp directory, so that operators
+/// whose state doesn't fit in memory can read it back later, e.g. the sorted runs of an external
+/// sort.
+///
+/// The file is deleted when this is dropped.
+#[derive(Debug)]
+pub struct SpillFile {
+    path: PathBuf,
+    schema: SchemaRef,
+    num_rows: usize,
+}
+
+impl SpillFile {
+    pub fn try_new(partition: &MicroPartition) -> DaftResult<Self> {
+        let path = std::env::temp_dir().join(format!("daft-spill-{}.arrow", uuid::Uuid::new_v4()));
+        let file = BufWriter::new(File::create_new(&path)?);
+        // Take ownership of the file before writing it, so that it's deleted if the write fails.
+        let spill_file = Self {
+            path,
+            schema: partition.schema(),
+            num_rows: partition.len(),
+        };
+        ipc::write_partition(file, partition, None)?;
+        Ok(spill_file)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_rows == 0
+    }
+
+    /// Reads the record batches of the spilled partition back, in the order they were written.
+    pub fn read_batches(&self) -> DaftResult<SpilledBatches> {
+        let file = BufReader::new(File::open(&self.path)?);
+        let (_, batches) = ipc::read_batches(file, &format!("Spill file {}", self.path.display()))?;
+        Ok(Box::new(batches))
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
@@ -0,0 +1,25 @@
+/// The 22 TPC-H queries, shared with the Python benchmarking harness.
+pub const QUERIES: [&str; 22] = [
+    include_str!("../../../../benchmarking/tpch/queries/01.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/02.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/03.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/04.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/05.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/06.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/07.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/08.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/09.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/10.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/11.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/12.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/13.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/14.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/15.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/16.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/17.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/18.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/19.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/20.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/21.sql"),
+    include_str!("../../../../benchmarking/tpch/queries/22.sql"),
+];
@@ -0,0 +1,215 @@
+use std::{hint::black_box, rc::Rc, sync::Arc};
+
+use common_scan_info::{test::DummyScanOperator, ScanOperatorRef};
+use daft_core::prelude::*;
+use daft_logical_plan::{LogicalPlanBuilder, LogicalPlanRef};
+use daft_session::Session;
+use daft_sql::SQLPlanner;
+use tango_bench::{
+    benchmark_fn, tango_benchmarks, tango_main, Benchmark, IntoBenchmarks, MeasurementSettings,
+    DEFAULT_SETTINGS,
+};
+
+mod common;
+
+use common::QUERIES;
+
+/// Creates a scan over a dummy source with the given schema and (scale factor 1) row count,
+/// so that the optimizer's stats-based rules see realistic table sizes.
+fn table(num_rows: usize, fields: &[(&str, DataType)]) -> LogicalPlanRef {
+    let schema = Arc::new(
+        Schema::new(
+            fields
+                .iter()
+                .map(|(name, dtype)| Field::new(*name, dtype.clone()))
+                .collect(),
+        )
+        .unwrap(),
+    );
+    let scan_op = ScanOperatorRef(Arc::new(DummyScanOperator {
+        schema,
+        num_scan_tasks: 1,
+        num_rows_per_task: Some(num_rows),
+        size_bytes_per_task: None,
+        can_absorb_aggregations: false,
+    }));
+    LogicalPlanBuilder::table_scan(scan_op, None)
+        .unwrap()
+        .build()
+}
+
+/// Registers the TPC-H tables in a fresh session.
+fn tpch_session() -> Rc<Session> {
+    use DataType::{Date, Float64, Int64, Utf8};
+
+    let tables = [
+        (
+            "nation",
+            table(
+                25,
+                &[
+                    ("n_nationkey", Int64),
+                    ("n_name", Utf8),
+                    ("n_regionkey", Int64),
+                    ("n_comment", Utf8),
+                ],
+            ),
+        ),
+        (
+            "region",
+            table(
+                5,
+                &[
+                    ("r_regionkey", Int64),
+                    ("r_name", Utf8),
+                    ("r_comment", Utf8),
+                ],
+            ),
+        ),
+        (
+            "part",
+            table(
+                200_000,
+                &[
+                    ("p_partkey", Int64),
+                    ("p_name", Utf8),
+                    ("p_mfgr", Utf8),
+                    ("p_brand", Utf8),
+                    ("p_type", Utf8),
+                    ("p_size", Int64),
+                    ("p_container", Utf8),
+                    ("p_retailprice", Float64),
+                    ("p_comment", Utf8),
+                ],
+            ),
+        ),
+        (
+            "supplier",
+            table(
+                10_000,
+                &[
+                    ("s_suppkey", Int64),
+                    ("s_name", Utf8),
+                    ("s_address", Utf8),
+                    ("s_nationkey", Int64),
+                    ("s_phone", Utf8),
+                    ("s_acctbal", Float64),
+                    ("s_comment", Utf8),
+                ],
+            ),
+        ),
+        (
+            "partsupp",
+            table(
+                800_000,
+                &[
+                    ("ps_partkey", Int64),
+                    ("ps_suppkey", Int64),
+                    ("ps_availqty", Int64),
+                    ("ps_supplycost", Float64),
+                    ("ps_comment", Utf8),
+                ],
+            ),
+        ),
+        (
+            "customer",
+            table(
+                150_000,
+                &[
+                    ("c_custkey", Int64),
+                    ("c_name", Utf8),
+                    ("c_address", Utf8),
+                    ("c_nationkey", Int64),
+                    ("c_phone", Utf8),
+                    ("c_acctbal", Float64),
+                    ("c_mktsegment", Utf8),
+                    ("c_comment", Utf8),
+                ],
+            ),
+        ),
+        (
+            "orders",
+            table(
+                1_500_000,
+                &[
+                    ("o_orderkey", Int64),
+                    ("o_custkey", Int64),
+                    ("o_orderstatus", Utf8),
+                    ("o_totalprice", Float64),
+                    ("o_orderdate", Date),
+                    ("o_orderpriority", Utf8),
+                    ("o_clerk", Utf8),
+                    ("o_shippriority", Int64),
+                    ("o_comment", Utf8),
+                ],
+            ),
+        ),
+        (
+            "lineitem",
+            table(
+                6_001_215,
+                &[
+                    ("l_orderkey", Int64),
+                    ("l_partkey", Int64),
+                    ("l_suppkey", Int64),
+                    ("l_linenumber", Int64),
+                    ("l_quantity", Float64),
+                    ("l_extendedprice", Float64),
+                    ("l_discount", Float64),
+                    ("l_tax", Float64),
+                    ("l_returnflag", Utf8),
+                    ("l_linestatus", Utf8),
+                    ("l_shipdate", Date),
+                    ("l_commitdate", Date),
+                    ("l_receiptdate", Date),
+                    ("l_shipinstruct", Utf8),
+                    ("l_shipmode", Utf8),
+                    ("l_comment", Utf8),
+                ],
+            ),
+        ),
+    ];
+
+    let session = Session::default();
+    for (name, plan) in tables {
+        session
+            .create_temp_table(name, &plan.into(), false)
+            .unwrap();
+    }
+    session.into()
+}
+
+fn bench_plan(q: usize) -> Benchmark {
+    benchmark_fn(format!("tpch/q{q:02}/plan"), move |b| {
+        let session = tpch_session();
+        let query = QUERIES[q - 1];
+        b.iter(move || {
+            let mut planner = SQLPlanner::new(session.clone());
+            black_box(planner.plan_sql(query).unwrap())
+        })
+    })
+}
+
+fn bench_optimize(q: usize) -> Benchmark {
+    benchmark_fn(format!("tpch/q{q:02}/optimize"), move |b| {
+        let mut planner = SQLPlanner::new(tpch_session());
+        let builder = LogicalPlanBuilder::from(planner.plan_sql(QUERIES[q - 1]).unwrap());
+        b.iter(move || black_box(builder.optimize().unwrap()))
+    })
+}
+
+fn all_benchmarks() -> impl IntoBenchmarks {
+    (1..=QUERIES.len())
+        .flat_map(|q| [bench_plan(q), bench_optimize(q)])
+        .collect::<Vec<_>>()
+}
+
+// Planning is far slower per iteration than the kernel benchmarks, so keep the defaults
+// except for yielding between samples to reduce scheduler noise.
+const SETTINGS: MeasurementSettings = MeasurementSettings {
+    yield_before_sample: true,
+    ..DEFAULT_SETTINGS
+};
+
+tango_benchmarks!(all_benchmarks());
+tango_main!(SETTINGS);
@@ -0,0 +1,89 @@
+//! Runs the TPC-H queries on the native executor over Parquet files generated by the Python
+//! benchmarking harness, e.g. at scale factor 1:
+//!
+//! ```sh
+//! python -m benchmarking.tpch.data_generation --scale_factor 1 --num_parts 1 --generate_parquet
+//! DAFT_TPCH_DATA_DIR=data/tpch-dbgen/1_0/1/parquet DAFT_PROGRESS_BAR=0 \
+//!     cargo bench -p daft-sql --features tpch-execution --bench tpch_execution
+//! ```
+use std::{hint::black_box, rc::Rc, sync::Arc};
+
+use common_daft_config::DaftExecutionConfig;
+use common_error::DaftResult;
+use daft_local_execution::NativeExecutor;
+use daft_logical_plan::LogicalPlanBuilder;
+use daft_micropartition::partitioning::InMemoryPartitionSetCache;
+use daft_session::Session;
+use daft_sql::SQLPlanner;
+use tango_bench::{
+    benchmark_fn, tango_benchmarks, tango_main, Benchmark, IntoBenchmarks, MeasurementSettings,
+    SampleLengthKind, DEFAULT_SETTINGS,
+};
+
+mod common;
+
+use common::QUERIES;
+
+const TABLES: [&str; 8] = [
+    "nation", "region", "part", "supplier", "partsupp", "customer", "orders", "lineitem",
+];
+
+/// Registers the tables generated under `data_dir`, one directory of Parquet files per table, in a
+/// fresh session.
+fn tpch_session(data_dir: &str) -> Rc<Session> {
+    let session: Rc<Session> = Session::default().into();
+    for name in TABLES {
+        let mut planner = SQLPlanner::new(session.clone());
+        let plan = planner
+            .plan_sql(&format!(
+                "SELECT * FROM read_parquet('{data_dir}/{name}/*.parquet')"
+            ))
+            .unwrap();
+        session
+            .create_temp_table(name, &plan.into(), false)
+            .unwrap();
+    }
+    session
+}
+
+fn bench_execute(data_dir: &str, q: usize) -> Benchmark {
+    let data_dir = data_dir.to_string();
+    benchmark_fn(format!("tpch/q{q:02}/execute"), move |b| {
+        let mut planner = SQLPlanner::new(tpch_session(&data_dir));
+        let builder = LogicalPlanBuilder::from(planner.plan_sql(QUERIES[q - 1]).unwrap())
+            .optimize()
+            .unwrap();
+        let cfg = Arc::new(DaftExecutionConfig::default());
+        b.iter(move || {
+            let results = NativeExecutor::new()
+                .run(
+                    &builder,
+                    &InMemoryPartitionSetCache::empty(),
+                    cfg.clone(),
+                    None,
+                )
+                .unwrap();
+            black_box(results.into_iter().collect::<DaftResult<Vec<_>>>().unwrap())
+        })
+    })
+}
+
+fn all_benchmarks() -> impl IntoBenchmarks {
+    let data_dir = std::env::var("DAFT_TPCH_DATA_DIR")
+        .expect("DAFT_TPCH_DATA_DIR should be set to a directory of generated TPC-H Parquet files");
+    (1..=QUERIES.len())
+        .map(|q| bench_execute(&data_dir, q))
+        .collect::<Vec<_>>()
+}
+
+// A query takes long enough that a single run is a stable sample, and running it many times per
+// sample would make the benchmark take hours.
+const SETTINGS: MeasurementSettings = MeasurementSettings {
+    max_iterations_per_sample: 1,
+    sampler_type: SampleLengthKind::Flat,
+    yield_before_sample: true,
+    ..DEFAULT_SETTINGS
+};
+
+tango_benchmarks!(all_benchmarks());
+tango_main!(SETTINGS);
@@ -12,10 +12,11 @@ use sqlparser::ast::{
 use crate::{
     error::{PlannerError, SQLPlannerResult},
     modules::{
-        coalesce::SQLCoalesce, hashing::SQLModuleHashing, SQLModule, SQLModuleAggs,
-        SQLModuleConfig, SQLModuleFloat, SQLModuleImage, SQLModuleJson, SQLModuleList,
-        SQLModuleMap, SQLModuleNumeric, SQLModulePartitioning, SQLModulePython, SQLModuleSketch,
-        SQLModuleStructs, SQLModuleTemporal, SQLModuleUri, SQLModuleUtf8,
+        coalesce::SQLCoalesce, hashing::SQLModuleHashing, sequence::SQLMonotonicallyIncreasingId,
+        SQLModule, SQLModuleAggs, SQLModuleConfig, SQLModuleFloat, SQLModuleGeo, SQLModuleImage,
+        SQLModuleJson, SQLModuleList, SQLModuleMap, SQLModuleNumeric, SQLModulePartitioning,
+        SQLModulePython, SQLModuleSketch, SQLModuleStructs, SQLModuleTemporal, SQLModuleUri,
+        SQLModuleUtf8,
     },
     planner::SQLPlanner,
     unsupported_sql_err,
@@ -26,6 +27,7 @@ pub(crate) static SQL_FUNCTIONS: LazyLock<SQLFunctions> = LazyLock::new(|| {
     let mut functions = SQLFunctions::new();
     functions.register::<SQLModuleAggs>();
     functions.register::<SQLModuleFloat>();
+    functions.register::<SQLModuleGeo>();
     functions.register::<SQLModuleHashing>();
     functions.register::<SQLModuleImage>();
     functions.register::<SQLModuleJson>();
@@ -41,6 +43,10 @@ pub(crate) static SQL_FUNCTIONS: LazyLock<SQLFunctions> = LazyLock::new(|| {
     functions.register::<SQLModuleUtf8>();
     functions.register::<SQLModuleConfig>();
     functions.add_fn("coalesce", SQLCoalesce {});
+    functions.add_fn(
+        "monotonically_increasing_id",
+        SQLMonotonicallyIncreasingId {},
+    );
     functions
 });
 
@@ -287,6 +293,26 @@ impl SQLPlanner<'_> {
         // lookup function variant(s) by name
         // SQL function names are case-insensitive
         let fn_name = func.name.to_string().to_lowercase();
+
+        // fall back to the session's temporary functions, which are matched exactly
+        if SQL_FUNCTIONS.get(fn_name.as_str()).is_none() {
+            let name = func.name.to_string();
+            if self.session().has_function(&name) {
+                let function = self.session().get_function(&name)?;
+                let args = match &func.args {
+                    FunctionArguments::None => vec![],
+                    FunctionArguments::Subquery(_) => {
+                        unsupported_sql_err!("subquery function argument")
+                    }
+                    FunctionArguments::List(args) => args
+                        .args
+                        .iter()
+                        .map(|arg| self.plan_function_arg(arg))
+                        .collect::<SQLPlannerResult<Vec<_>>>()?,
+                };
+                return Ok(function.call(args)?);
+            }
+        }
         let mut fn_match = get_func_from_sqlfunctions_registry(fn_name.as_str())?;
 
         // TODO: Filter the variants for correct arity.
@@ -31,7 +31,7 @@ mod tests {
     use std::sync::Arc;
 
     use daft_core::prelude::*;
-    use daft_dsl::{lit, unresolved_col, Expr, Subquery};
+    use daft_dsl::{binary_op, lit, unresolved_col, Expr, ExprRef, Operator, Subquery};
     use daft_logical_plan::{
         logical_plan::Source, source_info::PlaceHolderInfo, ClusteringSpec, JoinOptions,
         LogicalPlan, LogicalPlanBuilder, LogicalPlanRef, SourceInfo,
@@ -534,4 +534,24 @@ mod tests {
 
         Ok(())
     }
+
+    #[rstest]
+    #[case::boolean_logic(
+        unresolved_col("a")
+            .gt(lit(1))
+            .or(unresolved_col("b").lt_eq(lit(2.5)))
+            .and(unresolved_col("c").eq(lit("it's")))
+    )]
+    #[case::membership(unresolved_col("a").is_in(vec![lit(1), lit(2)]).not())]
+    #[case::between(unresolved_col("a").between(lit(1), lit(3)))]
+    #[case::nulls(unresolved_col("a").is_null().or(unresolved_col("b").not_null()))]
+    #[case::null_safe_equals(unresolved_col("a").eq_null_safe(lit(1)))]
+    #[case::arithmetic(binary_op(Operator::Plus, unresolved_col("a"), lit(1)).gt(lit(2)))]
+    #[case::quoted_identifier(unresolved_col("my col").not_eq(lit(1)))]
+    fn test_predicate_to_sql_round_trip(#[case] expr: ExprRef) -> SQLPlannerResult<()> {
+        let sql = expr.to_sql().expect("predicate should translate to SQL");
+        let parsed = sql_expr(&sql)?;
+        assert_eq!(parsed.to_sql(), Some(sql));
+        Ok(())
+    }
 }
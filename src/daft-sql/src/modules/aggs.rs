@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use daft_core::prelude::CountMode;
-use daft_dsl::{unresolved_col, AggExpr, Expr, ExprRef, LiteralValue};
+use daft_dsl::{unresolved_col, AggExpr, ApproxPercentileParams, Expr, ExprRef, LiteralValue};
 use sqlparser::ast::{FunctionArg, FunctionArgExpr};
 
 use super::SQLModule;
@@ -30,7 +30,18 @@ impl SQLModule for SQLModuleAggs {
         parent.add_fn("bool_and", AggExpr::BoolAnd(nil.clone()));
         parent.add_fn("bool_or", AggExpr::BoolOr(nil.clone()));
         parent.add_fn("stddev", AggExpr::Stddev(nil.clone()));
-        parent.add_fn("stddev_samp", AggExpr::Stddev(nil));
+        parent.add_fn("stddev_samp", AggExpr::Stddev(nil.clone()));
+        parent.add_fn(
+            "approx_count_distinct",
+            AggExpr::ApproxCountDistinct(nil.clone()),
+        );
+        let approx_percentile = AggExpr::ApproxPercentile(ApproxPercentileParams {
+            child: nil,
+            percentiles: vec![],
+            force_list_output: false,
+        });
+        parent.add_fn("approx_percentile", approx_percentile.clone());
+        parent.add_fn("approx_quantile", approx_percentile);
     }
 }
 
@@ -56,6 +67,12 @@ impl SQLFunction for AggExpr {
             Self::Stddev(_) => static_docs::STDDEV_DOCSTRING.to_string(),
             Self::BoolAnd(_) => static_docs::BOOL_AND_DOCSTRING.to_string(),
             Self::BoolOr(_) => static_docs::BOOL_OR_DOCSTRING.to_string(),
+            Self::ApproxCountDistinct(_) => {
+                static_docs::APPROX_COUNT_DISTINCT_DOCSTRING.to_string()
+            }
+            Self::ApproxPercentile(_) => {
+                static_docs::APPROX_PERCENTILE_DOCSTRING.replace("{}", alias)
+            }
             e => unimplemented!("Need to implement docstrings for {e}"),
         }
     }
@@ -70,7 +87,9 @@ impl SQLFunction for AggExpr {
             | Self::Max(_)
             | Self::Stddev(_)
             | Self::BoolAnd(_)
-            | Self::BoolOr(_) => &["input"],
+            | Self::BoolOr(_)
+            | Self::ApproxCountDistinct(_) => &["input"],
+            Self::ApproxPercentile(_) => &["input", "percentiles"],
             e => unimplemented!("Need to implement arg names for {e}"),
         }
     }
@@ -125,8 +144,27 @@ fn to_expr(expr: &AggExpr, args: &[ExprRef]) -> SQLPlannerResult<ExprRef> {
             ensure!(args.len() == 1, "sum takes exactly one argument");
             Ok(args[0].clone().sum())
         }
-        AggExpr::ApproxCountDistinct(_) => unsupported_sql_err!("approx_percentile"),
-        AggExpr::ApproxPercentile(_) => unsupported_sql_err!("approx_percentile"),
+        AggExpr::ApproxCountDistinct(_) => {
+            ensure!(
+                args.len() == 1,
+                "approx_count_distinct takes exactly one argument"
+            );
+            Ok(args[0].clone().approx_count_distinct())
+        }
+        AggExpr::ApproxPercentile(_) => {
+            ensure!(
+                args.len() == 2,
+                "approx_percentile takes exactly two arguments"
+            );
+            let (percentiles, force_list_output) = approx_percentiles_arg(&args[1])?;
+            ensure!(
+                percentiles.iter().all(|p| (0.0..=1.0).contains(p)),
+                "approx_percentile percentiles must be between 0 and 1"
+            );
+            Ok(args[0]
+                .clone()
+                .approx_percentiles(&percentiles, force_list_output))
+        }
         AggExpr::ApproxSketch(_, _) => unsupported_sql_err!("approx_sketch"),
         AggExpr::MergeSketch(_, _) => unsupported_sql_err!("merge_sketch"),
         AggExpr::Mean(_) => {
@@ -161,6 +199,29 @@ fn to_expr(expr: &AggExpr, args: &[ExprRef]) -> SQLPlannerResult<ExprRef> {
     }
 }
 
+/// The percentiles of a call to `approx_percentile`, and whether they were given as a list rather than a single
+/// number, in which case the result is a list of the percentiles too.
+fn approx_percentiles_arg(arg: &ExprRef) -> SQLPlannerResult<(Vec<f64>, bool)> {
+    let as_percentile = |expr: &ExprRef| {
+        let lit = expr.as_literal()?;
+        lit.as_f64().or_else(|| lit.as_i64().map(|i| i as f64))
+    };
+    let percentiles = match arg.as_ref() {
+        Expr::List(items) => items
+            .iter()
+            .map(as_percentile)
+            .collect::<Option<Vec<_>>>()
+            .map(|percentiles| (percentiles, true)),
+        _ => as_percentile(arg).map(|percentile| (vec![percentile], false)),
+    };
+    match percentiles {
+        Some(percentiles) => Ok(percentiles),
+        None => unsupported_sql_err!(
+            "approx_percentile percentiles must be a number or a list of numbers, got {arg}"
+        ),
+    }
+}
+
 mod static_docs {
     pub(crate) const COUNT_DOCSTRING: &str =
         "Counts the number of non-null elements in the input expression.
@@ -511,4 +572,25 @@ Example:
     │ true    │
     ╰─────────╯
     (Showing first 1 of 1 rows)";
+
+    pub(crate) const APPROX_COUNT_DISTINCT_DOCSTRING: &str =
+        "Estimates the number of distinct non-null elements in the input expression with a HyperLogLog sketch.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT approx_count_distinct(x) FROM tbl";
+
+    pub(crate) const APPROX_PERCENTILE_DOCSTRING: &str =
+        "Estimates the given percentiles, between 0 and 1, of the input expression with a DDSketch. Returns a single
+value for a single percentile, or a list of values for a list of percentiles.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT {}(x, 0.5), {}(x, [0.25, 0.75]) FROM tbl";
 }
@@ -0,0 +1,158 @@
+use daft_dsl::{ExprRef, LiteralValue};
+use daft_functions::geo::{
+    bbox::within_bbox,
+    geohash::{geohash_decode, geohash_encode, MAX_GEOHASH_PRECISION},
+    haversine::haversine_distance,
+    point::point,
+};
+use sqlparser::ast::FunctionArg;
+
+use super::SQLModule;
+use crate::{
+    error::SQLPlannerResult,
+    functions::{SQLFunction, SQLFunctions},
+    invalid_operation_err,
+};
+
+pub struct SQLModuleGeo;
+
+impl SQLModule for SQLModuleGeo {
+    fn register(parent: &mut SQLFunctions) {
+        parent.add_fn("point", SQLPoint);
+        parent.add_fn("haversine_distance", SQLHaversineDistance);
+        parent.add_fn("geohash_encode", SQLGeohashEncode);
+        parent.add_fn("geohash_decode", SQLGeohashDecode);
+        parent.add_fn("within_bbox", SQLWithinBbox);
+    }
+}
+
+fn plan_args<const N: usize>(
+    name: &str,
+    inputs: &[FunctionArg],
+    planner: &crate::planner::SQLPlanner,
+) -> SQLPlannerResult<[ExprRef; N]> {
+    let args = inputs
+        .iter()
+        .map(|arg| planner.plan_function_arg(arg))
+        .collect::<SQLPlannerResult<Vec<_>>>()?;
+    match args.try_into() {
+        Ok(args) => Ok(args),
+        Err(args) => invalid_operation_err!("{name} takes {N} arguments, got {}", args.len()),
+    }
+}
+
+pub struct SQLPoint;
+
+impl SQLFunction for SQLPoint {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        let [lat, lon] = plan_args("point", inputs, planner)?;
+        Ok(point(lat, lon))
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Builds a point struct of a latitude and a longitude in degrees.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["lat", "lon"]
+    }
+}
+
+pub struct SQLHaversineDistance;
+
+impl SQLFunction for SQLHaversineDistance {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        let [lat1, lon1, lat2, lon2] = plan_args("haversine_distance", inputs, planner)?;
+        Ok(haversine_distance(lat1, lon1, lat2, lon2))
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Computes the great-circle distance in kilometers between two points given in degrees."
+            .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["lat1", "lon1", "lat2", "lon2"]
+    }
+}
+
+pub struct SQLGeohashEncode;
+
+impl SQLFunction for SQLGeohashEncode {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        let [lat, lon, precision] = plan_args("geohash_encode", inputs, planner)?;
+        let precision = match precision.as_literal().and_then(LiteralValue::as_i64) {
+            Some(precision) if (1..=MAX_GEOHASH_PRECISION as i64).contains(&precision) => {
+                precision as usize
+            }
+            _ => invalid_operation_err!(
+                "geohash_encode precision must be an integer between 1 and {MAX_GEOHASH_PRECISION}"
+            ),
+        };
+        Ok(geohash_encode(lat, lon, precision))
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Encodes a point given in degrees as a geohash with the given number of characters."
+            .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["lat", "lon", "precision"]
+    }
+}
+
+pub struct SQLGeohashDecode;
+
+impl SQLFunction for SQLGeohashDecode {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        let [input] = plan_args("geohash_decode", inputs, planner)?;
+        Ok(geohash_decode(input))
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Decodes a geohash into the point struct at the center of its cell.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input"]
+    }
+}
+
+pub struct SQLWithinBbox;
+
+impl SQLFunction for SQLWithinBbox {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        let [lat, lon, min_lat, min_lon, max_lat, max_lon] =
+            plan_args("within_bbox", inputs, planner)?;
+        Ok(within_bbox(lat, lon, min_lat, min_lon, max_lat, max_lon))
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Checks whether a point given in degrees is in a bounding box, which crosses the antimeridian when min_lon is greater than max_lon.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["lat", "lon", "min_lat", "min_lon", "max_lat", "max_lon"]
+    }
+}
@@ -1,7 +1,9 @@
 use daft_dsl::ExprRef;
 use daft_functions::{
     hash::hash,
-    minhash::{minhash, MinHashFunction},
+    minhash::{
+        minhash, minhash_similarity, simhash, simhash_distance, MinHashFunction, SimHashFunction,
+    },
 };
 use sqlparser::ast::FunctionArg;
 
@@ -18,6 +20,9 @@ impl SQLModule for SQLModuleHashing {
     fn register(parent: &mut SQLFunctions) {
         parent.add_fn("hash", SQLHash);
         parent.add_fn("minhash", SQLMinhash);
+        parent.add_fn("minhash_similarity", SQLMinhashSimilarity);
+        parent.add_fn("simhash", SQLSimhash);
+        parent.add_fn("simhash_distance", SQLSimhashDistance);
     }
 }
 
@@ -150,3 +155,138 @@ impl SQLFunction for SQLMinhash {
         &["input", "num_hashes", "ngram_size", "seed", "hash_function"]
     }
 }
+
+pub struct SQLMinhashSimilarity;
+
+impl SQLFunction for SQLMinhashSimilarity {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [left, right] => {
+                let left = planner.plan_function_arg(left)?;
+                let right = planner.plan_function_arg(right)?;
+                Ok(minhash_similarity(left, right))
+            }
+            _ => unsupported_sql_err!("Invalid arguments for minhash_similarity: '{inputs:?}'"),
+        }
+    }
+
+    fn docstrings(&self, _: &str) -> String {
+        "Estimates the Jaccard similarity of two minhash signatures as the fraction of hashes they share."
+            .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["left", "right"]
+    }
+}
+
+pub struct SQLSimhash;
+
+impl TryFrom<SQLFunctionArguments> for SimHashFunction {
+    type Error = PlannerError;
+
+    fn try_from(args: SQLFunctionArguments) -> Result<Self, Self::Error> {
+        let ngram_size = args
+            .get_named("ngram_size")
+            .ok_or_else(|| PlannerError::invalid_operation("ngram_size is required"))?
+            .as_literal()
+            .and_then(daft_dsl::LiteralValue::as_i64)
+            .ok_or_else(|| PlannerError::invalid_operation("ngram_size must be an integer"))?
+            as usize;
+
+        let seed = args
+            .get_named("seed")
+            .map(|arg| {
+                arg.as_literal()
+                    .and_then(daft_dsl::LiteralValue::as_i64)
+                    .ok_or_else(|| PlannerError::invalid_operation("seed must be an integer"))
+            })
+            .transpose()?
+            .unwrap_or(1) as u32;
+
+        let hash_function = args
+            .get_named("hash_function")
+            .map(|arg| {
+                arg.as_literal()
+                    .and_then(daft_dsl::LiteralValue::as_str)
+                    .ok_or_else(|| {
+                        PlannerError::invalid_operation("hash_function must be a string")
+                    })
+            })
+            .transpose()?
+            .unwrap_or("murmurhash3");
+
+        Ok(Self {
+            ngram_size,
+            seed,
+            hash_function: hash_function.parse()?,
+        })
+    }
+}
+
+impl SQLFunction for SQLSimhash {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [input, args @ ..] => {
+                let input = planner.plan_function_arg(input)?;
+                let args: SimHashFunction = planner.plan_function_args(
+                    args,
+                    &["ngram_size", "seed", "hash_function"],
+                    0,
+                )?;
+
+                Ok(simhash(
+                    input,
+                    args.ngram_size,
+                    args.seed,
+                    args.hash_function,
+                ))
+            }
+            _ => unsupported_sql_err!("Invalid arguments for simhash: '{inputs:?}'"),
+        }
+    }
+
+    fn docstrings(&self, _: &str) -> String {
+        "Calculates a 64-bit SimHash of the inputs ngrams, which is similar for similar inputs."
+            .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input", "ngram_size", "seed", "hash_function"]
+    }
+}
+
+pub struct SQLSimhashDistance;
+
+impl SQLFunction for SQLSimhashDistance {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [left, right] => {
+                let left = planner.plan_function_arg(left)?;
+                let right = planner.plan_function_arg(right)?;
+                Ok(simhash_distance(left, right))
+            }
+            _ => unsupported_sql_err!("Invalid arguments for simhash_distance: '{inputs:?}'"),
+        }
+    }
+
+    fn docstrings(&self, _: &str) -> String {
+        "Counts the bits that differ between two simhashes.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["left", "right"]
+    }
+}
@@ -4,6 +4,7 @@ pub mod aggs;
 pub mod coalesce;
 pub mod config;
 pub mod float;
+pub mod geo;
 pub mod hashing;
 pub mod image;
 pub mod json;
@@ -12,6 +13,7 @@ pub mod map;
 pub mod numeric;
 pub mod partitioning;
 pub mod python;
+pub mod sequence;
 pub mod sketch;
 pub mod structs;
 pub mod temporal;
@@ -21,6 +23,7 @@ pub mod utf8;
 pub use aggs::SQLModuleAggs;
 pub use config::SQLModuleConfig;
 pub use float::SQLModuleFloat;
+pub use geo::SQLModuleGeo;
 pub use image::SQLModuleImage;
 pub use json::SQLModuleJson;
 pub use list::SQLModuleList;
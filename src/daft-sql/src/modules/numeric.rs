@@ -1,18 +1,23 @@
 use daft_dsl::{ExprRef, LiteralValue};
-use daft_functions::numeric::{
-    abs::abs,
-    ceil::ceil,
-    clip::clip,
-    exp::{exp, expm1},
-    floor::floor,
-    log::{ln, log, log10, log1p, log2},
-    round::round,
-    sign::sign,
-    sqrt::sqrt,
-    trigonometry::{
-        arccos, arccosh, arcsin, arcsinh, arctan, arctanh, atan2, cos, cosh, cot, csc, degrees,
-        radians, sec, sin, sinh, tan, tanh,
+use daft_functions::{
+    numeric::{
+        abs::abs,
+        bucket::width_bucket,
+        ceil::ceil,
+        clip::clip,
+        exp::{exp, expm1},
+        floor::floor,
+        int_to_ip::int_to_ip,
+        log::{ln, log, log10, log1p, log2},
+        round::round,
+        sign::sign,
+        sqrt::sqrt,
+        trigonometry::{
+            arccos, arccosh, arcsin, arcsinh, arctan, arctanh, atan2, cos, cosh, cot, csc, degrees,
+            radians, sec, sin, sinh, tan, tanh,
+        },
     },
+    random::{random, RandomDistribution},
 };
 
 use super::SQLModule;
@@ -59,6 +64,11 @@ impl SQLModule for SQLModuleNumeric {
         parent.add_fn("atanh", SQLNumericExpr::ArcTanh);
         parent.add_fn("acosh", SQLNumericExpr::ArcCosh);
         parent.add_fn("asinh", SQLNumericExpr::ArcSinh);
+        parent.add_fn("int_to_ip", SQLNumericExpr::IntToIp);
+        parent.add_fn("rand", SQLNumericExpr::Rand);
+        parent.add_fn("randn", SQLNumericExpr::Randn);
+        parent.add_fn("randint", SQLNumericExpr::RandInt);
+        parent.add_fn("width_bucket", SQLNumericExpr::WidthBucket);
     }
 }
 enum SQLNumericExpr {
@@ -94,6 +104,11 @@ enum SQLNumericExpr {
     ArcTanh,
     ArcCosh,
     ArcSinh,
+    IntToIp,
+    Rand,
+    Randn,
+    RandInt,
+    WidthBucket,
 }
 
 impl SQLFunction for SQLNumericExpr {
@@ -142,6 +157,11 @@ impl SQLFunction for SQLNumericExpr {
             Self::ArcTanh => "Calculates the inverse hyperbolic tangent of a number.",
             Self::ArcCosh => "Calculates the inverse hyperbolic cosine of a number.",
             Self::ArcSinh => "Calculates the inverse hyperbolic sine of a number.",
+            Self::IntToIp => "Formats an integer as an IPv4 address, or returns null if it's out of range.",
+            Self::Rand => "Generates a random float between 0 (inclusive) and 1 (exclusive) for each row, with an optional seed.",
+            Self::Randn => "Generates a random float from the standard normal distribution for each row, with an optional seed.",
+            Self::RandInt => "Generates a random integer between low (inclusive) and high (exclusive) for each row, with an optional seed.",
+            Self::WidthBucket => "Assigns each value to one of n equal-width buckets between low and high, numbered from 1, with 0 for values below low and n + 1 for values at or above high.",
         };
         docstring.to_string()
     }
@@ -175,11 +195,15 @@ impl SQLFunction for SQLNumericExpr {
             | Self::Expm1
             | Self::ArcTanh
             | Self::ArcCosh
-            | Self::ArcSinh => &["input"],
+            | Self::ArcSinh
+            | Self::IntToIp => &["input"],
             Self::Log => &["input", "base"],
             Self::Round => &["input", "precision"],
             Self::ArcTan2 => &["y", "x"],
             Self::Clip => &["input", "min", "max"],
+            Self::Rand | Self::Randn => &["seed"],
+            Self::RandInt => &["low", "high", "seed"],
+            Self::WidthBucket => &["input", "low", "high", "num_buckets"],
         }
     }
 }
@@ -347,5 +371,62 @@ fn to_expr(expr: &SQLNumericExpr, args: &[ExprRef]) -> SQLPlannerResult<ExprRef>
             ensure!(args.len() == 1, "asinh takes exactly one argument");
             Ok(arcsinh(args[0].clone()))
         }
+        SQLNumericExpr::IntToIp => {
+            ensure!(args.len() == 1, "int_to_ip takes exactly one argument");
+            Ok(int_to_ip(args[0].clone()))
+        }
+        SQLNumericExpr::Rand => {
+            ensure!(args.len() <= 1, "rand takes at most one argument");
+            let seed = args.first().map(random_seed).transpose()?;
+            Ok(random(RandomDistribution::Uniform, seed, None))
+        }
+        SQLNumericExpr::Randn => {
+            ensure!(args.len() <= 1, "randn takes at most one argument");
+            let seed = args.first().map(random_seed).transpose()?;
+            Ok(random(RandomDistribution::Normal, seed, None))
+        }
+        SQLNumericExpr::RandInt => {
+            ensure!(
+                args.len() == 2 || args.len() == 3,
+                "randint takes two or three arguments"
+            );
+            let bounds = args[..2]
+                .iter()
+                .map(|arg| arg.as_literal().and_then(LiteralValue::as_i64))
+                .collect::<Option<Vec<_>>>();
+            let Some(&[low, high]) = bounds.as_deref() else {
+                invalid_operation_err!("randint bounds must be integers")
+            };
+            ensure!(low < high, "randint low must be less than high");
+            let seed = args.get(2).map(random_seed).transpose()?;
+            Ok(random(
+                RandomDistribution::Integer { low, high },
+                seed,
+                None,
+            ))
+        }
+        SQLNumericExpr::WidthBucket => {
+            ensure!(args.len() == 4, "width_bucket takes exactly four arguments");
+            let Some(num_buckets) = args[3].as_literal().and_then(LiteralValue::as_i64) else {
+                invalid_operation_err!("width_bucket number of buckets must be an integer")
+            };
+            ensure!(
+                num_buckets > 0,
+                "width_bucket number of buckets must be positive"
+            );
+            Ok(width_bucket(
+                args[0].clone(),
+                args[1].clone(),
+                args[2].clone(),
+                num_buckets,
+            ))
+        }
+    }
+}
+
+fn random_seed(arg: &ExprRef) -> SQLPlannerResult<u64> {
+    match arg.as_literal().and_then(LiteralValue::as_i64) {
+        Some(seed) if seed >= 0 => Ok(seed as u64),
+        _ => invalid_operation_err!("random seed must be a non-negative integer"),
     }
 }
@@ -0,0 +1,25 @@
+use crate::{ensure, functions::SQLFunction};
+
+pub struct SQLMonotonicallyIncreasingId {}
+
+impl SQLFunction for SQLMonotonicallyIncreasingId {
+    fn to_expr(
+        &self,
+        inputs: &[sqlparser::ast::FunctionArg],
+        _planner: &crate::planner::SQLPlanner,
+    ) -> crate::error::SQLPlannerResult<daft_dsl::ExprRef> {
+        ensure!(
+            inputs.is_empty(),
+            "monotonically_increasing_id takes no arguments"
+        );
+        Ok(daft_functions::sequence::monotonically_increasing_id())
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        static_docs::DOCSTRING.to_string()
+    }
+}
+
+mod static_docs {
+    pub(super) const DOCSTRING: &str = "Generates a unique, monotonically increasing 64-bit id for each row, with the partition number in the upper 28 bits and the row number in the partition in the lower 36 bits.";
+}
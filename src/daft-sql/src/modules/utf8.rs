@@ -1,4 +1,4 @@
-use daft_core::array::ops::Utf8NormalizeOptions;
+use daft_core::array::ops::{DecimalFormat, UnicodeForm, Utf8NormalizeOptions};
 use daft_dsl::{binary_op, ExprRef, LiteralValue, Operator};
 use daft_functions::{
     count_matches::{utf8_count_matches, CountMatchesFunction},
@@ -179,6 +179,16 @@ impl SQLModule for SQLModuleUtf8 {
         parent.add_fn("to_datetime", SQLUtf8ToDatetime);
         parent.add_fn("count_matches", SQLCountMatches);
         parent.add_fn("normalize", SQLNormalize);
+        parent.add_fn("remove_accents", SQLUtf8RemoveAccents);
+        parent.add_fn("ascii_fold", SQLUtf8AsciiFold);
+        parent.add_fn("is_email", SQLUtf8IsEmail);
+        parent.add_fn("is_ipv4", SQLUtf8IsIpv4);
+        parent.add_fn("is_ipv6", SQLUtf8IsIpv6);
+        parent.add_fn("is_phone_number", SQLUtf8IsPhoneNumber);
+        parent.add_fn("ip_to_int", SQLUtf8IpToInt);
+        parent.add_fn("extract_domain", SQLUtf8ExtractDomain);
+        parent.add_fn("parse_decimal", SQLUtf8ParseDecimal);
+        parent.add_fn("format_decimal", SQLFormatDecimal);
         parent.add_fn("tokenize_encode", SQLTokenizeEncode);
         parent.add_fn("tokenize_decode", SQLTokenizeDecode);
         parent.add_fn("concat", SQLConcat);
@@ -572,6 +582,70 @@ impl SQLFunction for SQLCountMatches {
     }
 }
 
+utf8_function!(
+    SQLUtf8RemoveAccents,
+    "remove_accents",
+    daft_functions::utf8::remove_accents,
+    "Removes the accents and other combining marks of the characters of the string",
+    "string_input"
+);
+
+utf8_function!(
+    SQLUtf8AsciiFold,
+    "ascii_fold",
+    daft_functions::utf8::ascii_fold,
+    "Replaces the characters of the string with their closest ASCII equivalents where there are any",
+    "string_input"
+);
+
+utf8_function!(
+    SQLUtf8IsEmail,
+    "is_email",
+    daft_functions::utf8::is_email,
+    "Returns true if the string is an email address",
+    "string_input"
+);
+
+utf8_function!(
+    SQLUtf8IsIpv4,
+    "is_ipv4",
+    daft_functions::utf8::is_ipv4,
+    "Returns true if the string is an IPv4 address",
+    "string_input"
+);
+
+utf8_function!(
+    SQLUtf8IsIpv6,
+    "is_ipv6",
+    daft_functions::utf8::is_ipv6,
+    "Returns true if the string is an IPv6 address",
+    "string_input"
+);
+
+utf8_function!(
+    SQLUtf8IsPhoneNumber,
+    "is_phone_number",
+    daft_functions::utf8::is_phone_number,
+    "Returns true if the string is a phone number of 7 to 15 digits",
+    "string_input"
+);
+
+utf8_function!(
+    SQLUtf8IpToInt,
+    "ip_to_int",
+    daft_functions::utf8::ip_to_int,
+    "Converts an IPv4 address to an integer, or returns null if the string is not one",
+    "string_input"
+);
+
+utf8_function!(
+    SQLUtf8ExtractDomain,
+    "extract_domain",
+    daft_functions::utf8::extract_domain,
+    "Extracts the lowercased host of a URL or domain of an email address",
+    "string_input"
+);
+
 pub struct SQLNormalize;
 
 impl TryFrom<SQLFunctionArguments> for Utf8NormalizeOptions {
@@ -582,12 +656,17 @@ impl TryFrom<SQLFunctionArguments> for Utf8NormalizeOptions {
         let lowercase = args.try_get_named("lowercase")?.unwrap_or(false);
         let nfd_unicode = args.try_get_named("nfd_unicode")?.unwrap_or(false);
         let white_space = args.try_get_named("white_space")?.unwrap_or(false);
+        let form = args
+            .try_get_named::<String>("form")?
+            .map(|form| form.parse::<UnicodeForm>())
+            .transpose()?;
 
         Ok(Self {
             remove_punct,
             lowercase,
             nfd_unicode,
             white_space,
+            form,
         })
     }
 }
@@ -610,7 +689,13 @@ impl SQLFunction for SQLNormalize {
                 let input = planner.plan_function_arg(input)?;
                 let args: Utf8NormalizeOptions = planner.plan_function_args(
                     args,
-                    &["remove_punct", "lowercase", "nfd_unicode", "white_space"],
+                    &[
+                        "remove_punct",
+                        "lowercase",
+                        "nfd_unicode",
+                        "white_space",
+                        "form",
+                    ],
                     0,
                 )?;
                 Ok(daft_functions::utf8::normalize(input, args))
@@ -629,6 +714,123 @@ impl SQLFunction for SQLNormalize {
             "lowercase",
             "nfd_unicode",
             "white_space",
+            "form",
+        ]
+    }
+}
+
+/// Reads the `decimal_separator` and `thousands_separator` arguments, where an empty
+/// thousands separator means that digits aren't grouped.
+fn decimal_format(args: &SQLFunctionArguments) -> SQLPlannerResult<DecimalFormat> {
+    let default = DecimalFormat::default();
+    let decimal_separator = args
+        .try_get_named("decimal_separator")?
+        .unwrap_or(default.decimal_separator);
+    let thousands_separator = match args.try_get_named::<String>("thousands_separator")? {
+        Some(separator) => separator.chars().next(),
+        None => default.thousands_separator,
+    };
+    Ok(DecimalFormat::try_new(
+        decimal_separator,
+        thousands_separator,
+    )?)
+}
+
+pub struct SQLUtf8ParseDecimal;
+
+impl SQLFunction for SQLUtf8ParseDecimal {
+    fn to_expr(
+        &self,
+        inputs: &[sqlparser::ast::FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [input, args @ ..] if args.len() >= 2 => {
+                let input = planner.plan_function_arg(input)?;
+                let args = planner.parse_function_args(
+                    args,
+                    &["decimal_separator", "thousands_separator"],
+                    2,
+                )?;
+                let (Some(precision), Some(scale)) =
+                    (args.try_get_positional(0)?, args.try_get_positional(1)?)
+                else {
+                    invalid_operation_err!("parse_decimal precision and scale must be integers")
+                };
+                let format = decimal_format(&args)?;
+                Ok(daft_functions::utf8::parse_decimal(
+                    input, precision, scale, format,
+                ))
+            }
+            _ => invalid_operation_err!(
+                "Invalid arguments for parse_decimal. Expected parse_decimal(string_input, precision, scale)"
+            ),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Parses a formatted number, such as a currency amount, into a decimal with the given precision and scale."
+            .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &[
+            "string_input",
+            "precision",
+            "scale",
+            "decimal_separator",
+            "thousands_separator",
+        ]
+    }
+}
+
+pub struct SQLFormatDecimal;
+
+impl SQLFunction for SQLFormatDecimal {
+    fn to_expr(
+        &self,
+        inputs: &[sqlparser::ast::FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [input, args @ ..] => {
+                let input = planner.plan_function_arg(input)?;
+                let args = planner.parse_function_args(
+                    args,
+                    &[
+                        "decimal_separator",
+                        "thousands_separator",
+                        "currency_symbol",
+                        "accounting",
+                    ],
+                    0,
+                )?;
+                let format = decimal_format(&args)?;
+                let currency_symbol = args.try_get_named("currency_symbol")?;
+                let accounting = args.try_get_named("accounting")?.unwrap_or(false);
+                Ok(daft_functions::numeric::format_decimal::format_decimal(
+                    input,
+                    format,
+                    currency_symbol,
+                    accounting,
+                ))
+            }
+            _ => invalid_operation_err!("Invalid arguments for format_decimal"),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Formats a decimal or integer as a string with grouped digits and an optional currency symbol."
+            .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &[
+            "input",
+            "decimal_separator",
+            "thousands_separator",
+            "currency_symbol",
+            "accounting",
         ]
     }
 }
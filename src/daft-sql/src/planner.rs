@@ -9,14 +9,14 @@ use std::{
 use common_error::DaftResult;
 use daft_algebra::boolean::combine_conjunction;
 use daft_catalog::Identifier;
-use daft_core::prelude::*;
+use daft_core::{array::ops::Collation, prelude::*};
 use daft_dsl::{
     has_agg, lit, literals_to_series, null_lit, resolved_col, unresolved_col, Column, Expr,
     ExprRef, LiteralValue, Operator, PlanRef, Subquery, UnresolvedColumn,
 };
 use daft_functions::{
     numeric::{ceil::ceil, floor::floor},
-    utf8::{ilike, like, to_date, to_datetime},
+    utf8::{collation_key, ilike, like, to_date, to_datetime},
 };
 use daft_logical_plan::{
     ops::{SetQuantifier, UnionStrategy},
@@ -27,9 +27,9 @@ use itertools::Itertools;
 use sqlparser::{
     ast::{
         self, BinaryOperator, CastKind, ColumnDef, DateTimeField, Distinct, ExcludeSelectItem,
-        FunctionArg, FunctionArgExpr, GroupByExpr, Ident, ObjectName, Query, SelectItem, SetExpr,
-        Subscript, TableAlias, TableFunctionArgs, TableWithJoins, TimezoneInfo, UnaryOperator,
-        Value, WildcardAdditionalOptions, With,
+        ExprWithAlias, FunctionArg, FunctionArgExpr, GroupByExpr, Ident, ObjectName,
+        PivotValueSource, Query, SelectItem, SetExpr, Subscript, TableAlias, TableFunctionArgs,
+        TableWithJoins, TimezoneInfo, UnaryOperator, Value, WildcardAdditionalOptions, With,
     },
     dialect::GenericDialect,
     parser::{Parser, ParserOptions},
@@ -187,7 +187,7 @@ impl<'a> SQLPlanner<'a> {
     }
 
     /// Borrow the planning session
-    fn session(&self) -> Ref<'_, Rc<Session>> {
+    pub(crate) fn session(&self) -> Ref<'_, Rc<Session>> {
         Ref::map(self.context.borrow(), |i| &i.session)
     }
 
@@ -269,7 +269,7 @@ impl<'a> SQLPlanner<'a> {
                 right,
             } => {
                 use sqlparser::ast::{
-                    SetOperator::{Intersect, Union},
+                    SetOperator::{Except, Intersect, Union},
                     SetQuantifier as SQLSetQuantifier,
                 };
                 fn make_query(expr: &SetExpr) -> Query {
@@ -317,6 +317,12 @@ impl<'a> SQLPlanner<'a> {
                     (Intersect, SQLSetQuantifier::None | SQLSetQuantifier::Distinct) => {
                         left.intersect(&right, false).map_err(|e| e.into())
                     }
+                    (Except, SQLSetQuantifier::All) => {
+                        left.except(&right, true).map_err(|e| e.into())
+                    }
+                    (Except, SQLSetQuantifier::None | SQLSetQuantifier::Distinct) => {
+                        left.except(&right, false).map_err(|e| e.into())
+                    }
                     (op, set_quantifier) => {
                         unsupported_sql_err!("{op} {set_quantifier} is not supported.")
                     }
@@ -883,11 +889,31 @@ impl<'a> SQLPlanner<'a> {
             sqlparser::ast::TableFactor::NestedJoin { .. } => {
                 unsupported_sql_err!("Unsupported table factor: NestedJoin")
             }
-            sqlparser::ast::TableFactor::Pivot { .. } => {
-                unsupported_sql_err!("Unsupported table factor: Pivot")
+            sqlparser::ast::TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => {
+                if default_on_null.is_some() {
+                    unsupported_sql_err!("PIVOT with DEFAULT ON NULL");
+                }
+                let table = self.plan_relation(table)?;
+                let plan =
+                    self.plan_pivot(table, aggregate_functions, value_column, value_source)?;
+                (plan, alias)
             }
-            sqlparser::ast::TableFactor::Unpivot { .. } => {
-                unsupported_sql_err!("Unsupported table factor: Unpivot")
+            sqlparser::ast::TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                alias,
+            } => {
+                let table = self.plan_relation(table)?;
+                (self.plan_unpivot(table, value, name, columns)?, alias)
             }
             sqlparser::ast::TableFactor::MatchRecognize { .. } => {
                 unsupported_sql_err!("Unsupported table factor: MatchRecognize")
@@ -919,6 +945,129 @@ impl<'a> SQLPlanner<'a> {
         self.plan_table_function(func, &args)
     }
 
+    /// Plans `<table> PIVOT (<agg>(<value>) FOR <pivot> IN (<values>))`, which groups the rows of the table by its other
+    /// columns and has a column for each of the listed values of the pivot column, with the aggregation of the value
+    /// column over the rows of the group with that value.
+    fn plan_pivot(
+        &self,
+        table: LogicalPlanBuilder,
+        aggregate_functions: &[ExprWithAlias],
+        value_column: &[Ident],
+        value_source: &PivotValueSource,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let [ExprWithAlias {
+            expr: agg,
+            alias: None,
+        }] = aggregate_functions
+        else {
+            unsupported_sql_err!("PIVOT with more than one or an aliased aggregation");
+        };
+        let [pivot_column] = value_column else {
+            unsupported_sql_err!("PIVOT on more than one column");
+        };
+        let PivotValueSource::List(values) = value_source else {
+            unsupported_sql_err!("PIVOT without a list of values");
+        };
+
+        let mut planner = self.new_with_context();
+        planner.set_plan(table.clone());
+        let agg_expr = planner.plan_expr(agg)?;
+        // Aggregations are named after the column they aggregate, so that's the column of values to pivot.
+        let value_column = match agg_expr.as_ref() {
+            Expr::Agg(agg)
+                if agg
+                    .children()
+                    .iter()
+                    .all(|child| matches!(child.as_ref(), Expr::Column(_))) =>
+            {
+                unresolved_col(agg.name())
+            }
+            _ => invalid_operation_err!("Expected PIVOT to aggregate a column, got {agg_expr}"),
+        };
+        let pivot_column = planner.plan_identifier(std::slice::from_ref(pivot_column))?;
+        let schema = table.schema();
+        let pivot_dtype = &schema.get_field(pivot_column.name())?.dtype;
+
+        // The pivoted columns are named by the values as strings, like the pivot column's values are matched to them.
+        let mut names = Vec::with_capacity(values.len());
+        let mut aliases = Vec::with_capacity(values.len());
+        for ExprWithAlias { expr, alias } in values {
+            let value = planner.plan_expr(expr)?;
+            let Expr::Literal(value) = value.as_ref() else {
+                invalid_operation_err!("Expected PIVOT values to be literals, got {value}");
+            };
+            let name = value.to_series().cast(pivot_dtype)?.to_str_values()?;
+            let Some(name) = name.utf8()?.get(0) else {
+                invalid_operation_err!("Expected PIVOT values to not be null");
+            };
+            aliases.push(
+                alias
+                    .as_ref()
+                    .map_or(name, |alias| alias.value.as_str())
+                    .to_string(),
+            );
+            names.push(name.to_string());
+        }
+
+        let group_by = schema
+            .names()
+            .into_iter()
+            .filter(|name| {
+                name.as_str() != pivot_column.name() && name.as_str() != value_column.name()
+            })
+            .map(unresolved_col)
+            .collect::<Vec<_>>();
+        let plan = table.pivot(
+            group_by.clone(),
+            pivot_column,
+            value_column,
+            agg_expr,
+            names.clone(),
+        )?;
+        if names == aliases {
+            return Ok(plan);
+        }
+        let projection = group_by
+            .into_iter()
+            .chain(
+                names
+                    .into_iter()
+                    .zip(aliases)
+                    .map(|(name, alias)| unresolved_col(name).alias(alias)),
+            )
+            .collect();
+        Ok(plan.select(projection)?)
+    }
+
+    /// Plans `<table> UNPIVOT (<value> FOR <name> IN (<columns>))`, which has a row for each of the columns for each row
+    /// of the table, with the name of the column and its value, along with the other columns of the table.
+    fn plan_unpivot(
+        &self,
+        table: LogicalPlanBuilder,
+        value: &Ident,
+        name: &Ident,
+        columns: &[Ident],
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let mut planner = self.new_with_context();
+        planner.set_plan(table.clone());
+        let values = columns
+            .iter()
+            .map(|column| planner.plan_identifier(std::slice::from_ref(column)))
+            .collect::<SQLPlannerResult<Vec<_>>>()?;
+        let value_names = values
+            .iter()
+            .map(|value| value.name())
+            .collect::<HashSet<_>>();
+        let ids = table
+            .schema()
+            .names()
+            .into_iter()
+            .filter(|column| !value_names.contains(column.as_str()))
+            .map(unresolved_col)
+            .collect();
+        Ok(table.unpivot(ids, values, name.value.clone(), value.value.clone())?)
+    }
+
     /// Plan a `FROM <table>` table factor.
     ///
     /// All plans returned by plan_relation_table should have a SubqueryAlias with the table's name.
@@ -1186,11 +1335,11 @@ impl<'a> SQLPlanner<'a> {
                 Ok(expr.cast(&dtype))
             }
             SQLExpr::IsFalse(expr) => Ok(self.plan_expr(expr)?.eq(lit(false))),
-            SQLExpr::IsNotFalse(_) => Ok(self.plan_expr(expr)?.eq(lit(false)).not()),
+            SQLExpr::IsNotFalse(expr) => Ok(self.plan_expr(expr)?.eq(lit(false)).not()),
             SQLExpr::IsTrue(expr) => Ok(self.plan_expr(expr)?.eq(lit(true))),
             SQLExpr::IsNotTrue(expr) => Ok(self.plan_expr(expr)?.eq(lit(true)).not()),
             SQLExpr::IsNull(expr) => Ok(self.plan_expr(expr)?.is_null()),
-            SQLExpr::IsNotNull(expr) => Ok(self.plan_expr(expr)?.is_null().not()),
+            SQLExpr::IsNotNull(expr) => Ok(self.plan_expr(expr)?.not_null()),
             SQLExpr::UnaryOp { op, expr } => self.plan_unary_op(op, expr),
             SQLExpr::CompoundIdentifier(idents) => self.plan_identifier(idents),
             SQLExpr::CompositeAccess { .. } => {
@@ -1200,11 +1349,12 @@ impl<'a> SQLPlanner<'a> {
             SQLExpr::IsNotUnknown(_) => {
                 unsupported_sql_err!("IS NOT UNKNOWN")
             }
-            SQLExpr::IsDistinctFrom(_, _) => {
-                unsupported_sql_err!("IS DISTINCT FROM")
-            }
-            SQLExpr::IsNotDistinctFrom(_, _) => {
-                unsupported_sql_err!("IS NOT DISTINCT FROM")
+            SQLExpr::IsDistinctFrom(left, right) => Ok(self
+                .plan_expr(left)?
+                .eq_null_safe(self.plan_expr(right)?)
+                .not()),
+            SQLExpr::IsNotDistinctFrom(left, right) => {
+                Ok(self.plan_expr(left)?.eq_null_safe(self.plan_expr(right)?))
             }
             SQLExpr::InList {
                 expr,
@@ -1348,7 +1498,15 @@ impl<'a> SQLPlanner<'a> {
             }
             SQLExpr::Trim { .. } => unsupported_sql_err!("TRIM"),
             SQLExpr::Overlay { .. } => unsupported_sql_err!("OVERLAY"),
-            SQLExpr::Collate { .. } => unsupported_sql_err!("COLLATE"),
+            SQLExpr::Collate { expr, collation } => {
+                let collation = match collation.0.as_slice() {
+                    [name] => name.value.parse::<Collation>()?,
+                    _ => invalid_operation_err!(
+                        "Expected a collation name, instead got: {collation}"
+                    ),
+                };
+                Ok(collation_key(self.plan_expr(expr)?, collation))
+            }
             SQLExpr::Nested(e) => self.plan_expr(e),
             SQLExpr::IntroducedString { .. } => unsupported_sql_err!("INTRODUCED STRING"),
             SQLExpr::TypedString { data_type, value } => match data_type {
@@ -41,6 +41,7 @@ impl TryFrom<SQLFunctionArguments> for ParquetScanBuilder {
             })?;
         let chunk_size = args.try_get_named("chunk_size")?;
         let file_path_column = args.try_get_named("file_path_column")?;
+        let row_index_column = args.try_get_named("row_index_column")?;
         let multithreaded = args.try_get_named("multithreaded")?.unwrap_or(true);
         let hive_partitioning = args.try_get_named("hive_partitioning")?.unwrap_or(false);
 
@@ -64,6 +65,7 @@ impl TryFrom<SQLFunctionArguments> for ParquetScanBuilder {
             multithreaded,
             schema,
             file_path_column,
+            row_index_column,
             hive_partitioning,
         })
     }
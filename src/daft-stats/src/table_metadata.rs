@@ -1,8 +1,14 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Hash)]
 pub struct TableMetadata {
     pub length: usize,
+    /// The approximate number of distinct non-null values of the columns for which it is known, e.g. because it was
+    /// recorded when the table was written.
+    #[serde(default)]
+    pub num_distinct: BTreeMap<String, usize>,
 }
 
 impl TableMetadata {
@@ -10,6 +16,16 @@ impl TableMetadata {
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
         res.push(format!("Length = {}", self.length));
+        if !self.num_distinct.is_empty() {
+            res.push(format!(
+                "Approx num distinct = {{ {} }}",
+                self.num_distinct
+                    .iter()
+                    .map(|(column, num_distinct)| format!("{column}: {num_distinct}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
         res
     }
 }
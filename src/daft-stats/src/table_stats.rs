@@ -6,8 +6,11 @@ use std::{
 };
 
 use common_error::{DaftError, DaftResult};
-use daft_core::prelude::*;
-use daft_dsl::{Column, Expr, ExprRef, ResolvedColumn};
+use daft_core::{array::ops::prefix_upper_bound, prelude::*};
+use daft_dsl::{
+    functions::ScalarFunction, BoundColumn, Column, ColumnSelectivityStats, Expr, ExprRef,
+    LiteralValue, ResolvedColumn, SelectivityStats,
+};
 use daft_recordbatch::RecordBatch;
 use indexmap::{IndexMap, IndexSet};
 
@@ -129,18 +132,52 @@ impl TableStatistics {
         Ok(sum_so_far)
     }
 
+    /// The min and max of every column whose bounds are numeric, for estimating the selectivity of
+    /// filters over them.
+    #[must_use]
+    pub fn selectivity_stats(&self) -> SelectivityStats {
+        fn bound(series: &Series) -> Option<f64> {
+            let series = series.as_physical().ok()?.cast(&DataType::Float64).ok()?;
+            series.f64().ok()?.get(0)
+        }
+
+        self.columns
+            .iter()
+            .filter_map(|(name, stats)| match stats {
+                ColumnRangeStatistics::Loaded(lower, upper)
+                    if lower.data_type().to_physical().is_numeric() =>
+                {
+                    let stats = ColumnSelectivityStats {
+                        min: bound(lower),
+                        max: bound(upper),
+                        ..Default::default()
+                    };
+                    Some((name.clone(), stats))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn column_stats(&self, col_name: &str) -> crate::Result<ColumnRangeStatistics> {
+        let col = self.columns.get(col_name);
+        let Some(col) = col else {
+            return Err(crate::Error::DaftCoreCompute {
+                source: DaftError::FieldNotFound(col_name.to_string()),
+            });
+        };
+
+        Ok(col.clone())
+    }
+
     pub fn eval_expression(&self, expr: &Expr) -> crate::Result<ColumnRangeStatistics> {
         match expr {
             Expr::Alias(col, _) => self.eval_expression(col.as_ref()),
             Expr::Column(Column::Resolved(ResolvedColumn::Basic(col_name))) => {
-                let col = self.columns.get(col_name.as_ref());
-                let Some(col) = col else {
-                    return Err(crate::Error::DaftCoreCompute {
-                        source: DaftError::FieldNotFound(col_name.to_string()),
-                    });
-                };
-
-                Ok(col.clone())
+                self.column_stats(col_name)
+            }
+            Expr::Column(Column::Bound(BoundColumn { field, .. })) => {
+                self.column_stats(&field.name)
             }
             Expr::Literal(lit_value) => lit_value.try_into(),
             Expr::Not(col) => self.eval_expression(col)?.not(),
@@ -162,6 +199,21 @@ impl TableStatistics {
                     _ => Ok(ColumnRangeStatistics::Missing),
                 }
             }
+            // startswith(e, 'abc') is only true for values of e in ['abc', 'abd'), which the bounds of e can rule out.
+            Expr::ScalarFunction(ScalarFunction { udf, inputs })
+                if udf.name() == "startswith"
+                    && let [input, prefix] = inputs.as_slice()
+                    && let Some(LiteralValue::Utf8(prefix)) = prefix.as_literal() =>
+            {
+                let input = self.eval_expression(input)?;
+                let lower = input.gte(&(&LiteralValue::Utf8(prefix.clone())).try_into()?)?;
+                match prefix_upper_bound(prefix) {
+                    Some(upper) => {
+                        lower.bitand(&input.lt(&(&LiteralValue::Utf8(upper)).try_into()?)?)
+                    }
+                    None => Ok(lower),
+                }
+            }
             _ => Ok(ColumnRangeStatistics::Missing),
         }
     }
@@ -211,6 +263,7 @@ impl Display for TableStatistics {
 mod test {
     use daft_core::prelude::*;
     use daft_dsl::{lit, resolved_col};
+    use daft_functions::utf8::{endswith, startswith};
     use daft_recordbatch::RecordBatch;
 
     use super::TableStatistics;
@@ -249,4 +302,49 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_startswith() -> crate::Result<()> {
+        let table = RecordBatch::from_nonempty_columns(vec![Utf8Array::from_values(
+            "a",
+            ["abc", "abd", "abz"].into_iter(),
+        )
+        .into_series()])
+        .unwrap();
+        let table_stats = TableStatistics::from_table(&table);
+
+        let cases = [
+            ("x", TruthValue::False),
+            ("aa", TruthValue::False),
+            ("abc", TruthValue::Maybe),
+            ("abz", TruthValue::Maybe),
+            ("ab", TruthValue::True),
+            ("", TruthValue::True),
+        ];
+        for (prefix, expected) in cases {
+            let expr = startswith(resolved_col("a"), lit(prefix));
+            let result = table_stats.eval_expression(&expr)?;
+            assert_eq!(result.to_truth_value(), expected, "startswith {prefix}");
+        }
+
+        // Other string predicates can't use the bounds.
+        let expr = endswith(resolved_col("a"), lit("x"));
+        let result = table_stats.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::Maybe);
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_stats() {
+        let table = RecordBatch::from_nonempty_columns(vec![
+            Int64Array::from(("a", vec![4, 1, 3])).into_series(),
+            Utf8Array::from_values("b", ["x", "y", "z"].into_iter()).into_series(),
+        ])
+        .unwrap();
+        let stats = TableStatistics::from_table(&table).selectivity_stats();
+
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats["a"].min, Some(1.0));
+        assert_eq!(stats["a"].max, Some(4.0));
+    }
 }
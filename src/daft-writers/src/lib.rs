@@ -79,7 +79,9 @@ pub fn make_physical_writer_factory(
             );
             let row_group_size_calculator = TargetInMemorySizeBytesCalculator::new(
                 min(
-                    cfg.parquet_target_row_group_size,
+                    file_info
+                        .row_group_size_bytes
+                        .unwrap_or(cfg.parquet_target_row_group_size),
                     cfg.parquet_target_filesize,
                 ),
                 cfg.parquet_inflation_factor,
@@ -103,7 +105,8 @@ pub fn make_physical_writer_factory(
                 Arc::new(file_writer_factory)
             }
         }
-        FileFormat::Csv => {
+        // JSON Lines is text like CSV, so its files are sized like CSV files.
+        FileFormat::Csv | FileFormat::Json => {
             let file_size_calculator = TargetInMemorySizeBytesCalculator::new(
                 cfg.csv_target_filesize,
                 cfg.csv_inflation_factor,
@@ -124,7 +127,27 @@ pub fn make_physical_writer_factory(
                 Arc::new(file_writer_factory)
             }
         }
-        _ => unreachable!("Physical write should only support Parquet and CSV"),
+        FileFormat::ArrowIpc => {
+            // Arrow IPC files hold record batches as they are laid out in memory, so they aren't inflated when read.
+            let file_size_calculator =
+                TargetInMemorySizeBytesCalculator::new(cfg.parquet_target_filesize, 1.0);
+
+            let file_writer_factory = TargetFileSizeWriterFactory::new(
+                Arc::new(base_writer_factory),
+                Arc::new(file_size_calculator),
+            );
+
+            if let Some(partition_cols) = &file_info.partition_cols {
+                let partitioned_writer_factory = PartitionedWriterFactory::new(
+                    Arc::new(file_writer_factory),
+                    partition_cols.clone(),
+                );
+                Arc::new(partitioned_writer_factory)
+            } else {
+                Arc::new(file_writer_factory)
+            }
+        }
+        _ => unreachable!("Physical write should only support Parquet, CSV, JSON and Arrow IPC"),
     }
 }
 
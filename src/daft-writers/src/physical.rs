@@ -35,14 +35,8 @@ impl WriterFactory for PhysicalWriterFactory {
         match self.native {
             true => unimplemented!(),
             false => {
-                let writer = create_pyarrow_file_writer(
-                    &self.output_file_info.root_dir,
-                    file_idx,
-                    self.output_file_info.compression.as_ref(),
-                    self.output_file_info.io_config.as_ref(),
-                    self.output_file_info.file_format,
-                    partition_values,
-                )?;
+                let writer =
+                    create_pyarrow_file_writer(&self.output_file_info, file_idx, partition_values)?;
                 Ok(writer)
             }
         }
@@ -50,25 +44,46 @@ impl WriterFactory for PhysicalWriterFactory {
 }
 
 pub fn create_pyarrow_file_writer(
-    root_dir: &str,
+    file_info: &OutputFileInfo,
     file_idx: usize,
-    compression: Option<&String>,
-    io_config: Option<&daft_io::IOConfig>,
-    format: FileFormat,
     partition: Option<&RecordBatch>,
 ) -> DaftResult<Box<dyn FileWriter<Input = Arc<MicroPartition>, Result = Option<RecordBatch>>>> {
-    match format {
+    match file_info.file_format {
         #[cfg(feature = "python")]
         FileFormat::Parquet => Ok(Box::new(crate::pyarrow::PyArrowWriter::new_parquet_writer(
-            root_dir,
+            &file_info.root_dir,
             file_idx,
-            compression,
-            io_config,
+            file_info.compression.as_ref(),
+            file_info.io_config.as_ref(),
             partition,
+            file_info.job_id.as_deref(),
+            file_info.bloom_filter_columns.as_deref(),
+            file_info.encryption.as_ref(),
         )?)),
         #[cfg(feature = "python")]
         FileFormat::Csv => Ok(Box::new(crate::pyarrow::PyArrowWriter::new_csv_writer(
-            root_dir, file_idx, io_config, partition,
+            &file_info.root_dir,
+            file_idx,
+            file_info.io_config.as_ref(),
+            partition,
+            file_info.job_id.as_deref(),
+        )?)),
+        #[cfg(feature = "python")]
+        FileFormat::Json => Ok(Box::new(crate::pyarrow::PyArrowWriter::new_json_writer(
+            &file_info.root_dir,
+            file_idx,
+            file_info.io_config.as_ref(),
+            partition,
+            file_info.job_id.as_deref(),
+        )?)),
+        #[cfg(feature = "python")]
+        FileFormat::ArrowIpc => Ok(Box::new(crate::pyarrow::PyArrowWriter::new_ipc_writer(
+            &file_info.root_dir,
+            file_idx,
+            file_info.compression.as_ref(),
+            file_info.io_config.as_ref(),
+            partition,
+            file_info.job_id.as_deref(),
         )?)),
         _ => Err(DaftError::ComputeError(
             "Unsupported file format for physical write".to_string(),
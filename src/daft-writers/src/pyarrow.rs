@@ -1,9 +1,13 @@
 use std::sync::Arc;
 
 use common_error::DaftResult;
+use daft_logical_plan::sink_info::ParquetEncryptionInfo;
 use daft_micropartition::{python::PyMicroPartition, MicroPartition};
 use daft_recordbatch::{python::PyRecordBatch, RecordBatch};
-use pyo3::{types::PyAnyMethods, PyObject, Python};
+use pyo3::{
+    types::{PyAnyMethods, PyDict, PyDictMethods},
+    PyObject, Python,
+};
 
 use crate::FileWriter;
 
@@ -20,6 +24,9 @@ impl PyArrowWriter {
         compression: Option<&String>,
         io_config: Option<&daft_io::IOConfig>,
         partition_values: Option<&RecordBatch>,
+        job_id: Option<&str>,
+        bloom_filter_columns: Option<&[String]>,
+        encryption: Option<&ParquetEncryptionInfo>,
     ) -> DaftResult<Self> {
         Python::with_gil(|py| {
             let file_writer_module = py.import(pyo3::intern!(py, "daft.io.writer"))?;
@@ -36,15 +43,28 @@ impl PyArrowWriter {
                 None => None,
             };
 
-            let py_writer = file_writer_class.call1((
-                root_dir,
-                file_idx,
-                partition_values,
-                compression.map(|c| c.as_str()),
-                io_config.map(|cfg| daft_io::python::IOConfig {
-                    config: cfg.clone(),
-                }),
-            ))?;
+            let kwargs = PyDict::new(py);
+            kwargs.set_item(pyo3::intern!(py, "job_id"), job_id)?;
+            kwargs.set_item(
+                pyo3::intern!(py, "bloom_filter_columns"),
+                bloom_filter_columns,
+            )?;
+            kwargs.set_item(
+                pyo3::intern!(py, "encryption"),
+                encryption.map(|e| e.config.as_ref()),
+            )?;
+            let py_writer = file_writer_class.call(
+                (
+                    root_dir,
+                    file_idx,
+                    partition_values,
+                    compression.map(|c| c.as_str()),
+                    io_config.map(|cfg| daft_io::python::IOConfig {
+                        config: cfg.clone(),
+                    }),
+                ),
+                Some(&kwargs),
+            )?;
             Ok(Self {
                 py_writer: py_writer.into(),
                 is_closed: false,
@@ -58,6 +78,7 @@ impl PyArrowWriter {
         file_idx: usize,
         io_config: Option<&daft_io::IOConfig>,
         partition_values: Option<&RecordBatch>,
+        job_id: Option<&str>,
     ) -> DaftResult<Self> {
         Python::with_gil(|py| {
             let file_writer_module = py.import(pyo3::intern!(py, "daft.io.writer"))?;
@@ -73,14 +94,105 @@ impl PyArrowWriter {
                 }
                 None => None,
             };
-            let py_writer = file_writer_class.call1((
-                root_dir,
-                file_idx,
-                partition_values,
-                io_config.map(|cfg| daft_io::python::IOConfig {
-                    config: cfg.clone(),
-                }),
-            ))?;
+            let kwargs = PyDict::new(py);
+            kwargs.set_item(pyo3::intern!(py, "job_id"), job_id)?;
+            let py_writer = file_writer_class.call(
+                (
+                    root_dir,
+                    file_idx,
+                    partition_values,
+                    io_config.map(|cfg| daft_io::python::IOConfig {
+                        config: cfg.clone(),
+                    }),
+                ),
+                Some(&kwargs),
+            )?;
+            Ok(Self {
+                py_writer: py_writer.into(),
+                is_closed: false,
+                bytes_written: 0,
+            })
+        })
+    }
+
+    pub fn new_json_writer(
+        root_dir: &str,
+        file_idx: usize,
+        io_config: Option<&daft_io::IOConfig>,
+        partition_values: Option<&RecordBatch>,
+        job_id: Option<&str>,
+    ) -> DaftResult<Self> {
+        Python::with_gil(|py| {
+            let file_writer_module = py.import(pyo3::intern!(py, "daft.io.writer"))?;
+            let file_writer_class = file_writer_module.getattr("JSONFileWriter")?;
+            let _from_pytable = py
+                .import(pyo3::intern!(py, "daft.recordbatch"))?
+                .getattr(pyo3::intern!(py, "RecordBatch"))?
+                .getattr(pyo3::intern!(py, "_from_pytable"))?;
+            let partition_values = match partition_values {
+                Some(pv) => {
+                    let py_table = _from_pytable.call1((PyRecordBatch::from(pv.clone()),))?;
+                    Some(py_table)
+                }
+                None => None,
+            };
+            let kwargs = PyDict::new(py);
+            kwargs.set_item(pyo3::intern!(py, "job_id"), job_id)?;
+            let py_writer = file_writer_class.call(
+                (
+                    root_dir,
+                    file_idx,
+                    partition_values,
+                    io_config.map(|cfg| daft_io::python::IOConfig {
+                        config: cfg.clone(),
+                    }),
+                ),
+                Some(&kwargs),
+            )?;
+            Ok(Self {
+                py_writer: py_writer.into(),
+                is_closed: false,
+                bytes_written: 0,
+            })
+        })
+    }
+
+    pub fn new_ipc_writer(
+        root_dir: &str,
+        file_idx: usize,
+        compression: Option<&String>,
+        io_config: Option<&daft_io::IOConfig>,
+        partition_values: Option<&RecordBatch>,
+        job_id: Option<&str>,
+    ) -> DaftResult<Self> {
+        Python::with_gil(|py| {
+            let file_writer_module = py.import(pyo3::intern!(py, "daft.io.writer"))?;
+            let file_writer_class = file_writer_module.getattr("IpcFileWriter")?;
+            let _from_pytable = py
+                .import(pyo3::intern!(py, "daft.recordbatch"))?
+                .getattr(pyo3::intern!(py, "RecordBatch"))?
+                .getattr(pyo3::intern!(py, "_from_pytable"))?;
+            let partition_values = match partition_values {
+                Some(pv) => {
+                    let py_table = _from_pytable.call1((PyRecordBatch::from(pv.clone()),))?;
+                    Some(py_table)
+                }
+                None => None,
+            };
+            let kwargs = PyDict::new(py);
+            kwargs.set_item(pyo3::intern!(py, "job_id"), job_id)?;
+            let py_writer = file_writer_class.call(
+                (
+                    root_dir,
+                    file_idx,
+                    partition_values,
+                    compression.map(|c| c.as_str()),
+                    io_config.map(|cfg| daft_io::python::IOConfig {
+                        config: cfg.clone(),
+                    }),
+                ),
+                Some(&kwargs),
+            )?;
             Ok(Self {
                 py_writer: py_writer.into(),
                 is_closed: false,
@@ -1,4 +1,4 @@
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{Cursor, Read, Seek, SeekFrom};
 
 use parquet_format_safe::{
     thrift::protocol::TCompactInputProtocol, BloomFilterAlgorithm, BloomFilterCompression,
@@ -47,3 +47,24 @@ pub fn read<R: Read + Seek>(
 
     Ok(())
 }
+
+/// Deserializes the header of a bloom filter from the start of `data`, returning the length of the header and the
+/// length of the bitset that follows it.
+/// Results in `None` if the algorithm or the compression of the filter is not supported.
+/// # Error
+/// Errors if `data` doesn't start with a complete header.
+pub fn read_header(data: &[u8]) -> Result<Option<(usize, usize)>, Error> {
+    let mut reader = Cursor::new(data);
+    let mut prot = TCompactInputProtocol::new(&mut reader, usize::MAX);
+    let header = BloomFilterHeader::read_from_in_protocol(&mut prot)?;
+
+    if header.algorithm != BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}) {
+        return Ok(None);
+    }
+    if header.compression != BloomFilterCompression::UNCOMPRESSED(Uncompressed {}) {
+        return Ok(None);
+    }
+
+    let header_length = reader.position() as usize;
+    Ok(Some((header_length, header.num_bytes.try_into()?)))
+}
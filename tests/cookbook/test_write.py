@@ -1,10 +1,12 @@
 from __future__ import annotations
 
+import io
 import os
 import uuid
 from datetime import datetime
 
 import pyarrow as pa
+import pyarrow.parquet as papq
 import pytest
 from pyarrow import dataset as pads
 
@@ -42,6 +44,119 @@ def test_parquet_write_with_partitioning(tmp_path, with_morsel_size):
     assert len(pd_df._preview.preview_partition) == 5
 
 
+@pytest.mark.parametrize("partition_cols", [None, ["Borough"]])
+def test_parquet_write_and_read(tmp_path, partition_cols, with_morsel_size):
+    df = daft.read_csv(COOKBOOK_DATA_CSV)
+
+    read_back_df = df.write_parquet_and_read(tmp_path, partition_cols=partition_cols)
+    assert read_back_df.schema() == df.schema()
+    assert_df_equals(df.to_pandas(), read_back_df.to_pandas())
+
+
+@pytest.mark.parametrize("partition_cols", [None, ["Borough"]])
+def test_empty_parquet_write_and_read(tmp_path, partition_cols, with_morsel_size):
+    df = daft.read_csv(COOKBOOK_DATA_CSV).where(daft.col("Borough") == "NOT_A_REAL_BOROUGH")
+
+    read_back_df = df.write_parquet_and_read(tmp_path, partition_cols=partition_cols)
+    assert read_back_df.schema() == df.schema()
+    assert read_back_df.count_rows() == 0
+
+
+def test_parquet_write_commits_job(tmp_path, with_morsel_size):
+    df = daft.read_csv(COOKBOOK_DATA_CSV)
+
+    paths = df.write_parquet(tmp_path, partition_cols=["Borough"]).to_pydict()["path"]
+
+    markers = [name for name in os.listdir(tmp_path) if name.startswith("_")]
+    assert len(markers) == 1 and markers[0].startswith("_committed_")
+    job_id = markers[0][len("_committed_") :]
+    assert all(os.path.basename(path).startswith(f"{job_id}-") for path in paths)
+
+    # Marker files are not picked up when reading the directory back.
+    assert_df_equals(df.to_pandas(), daft.read_parquet(tmp_path.as_posix() + "/**").to_pandas())
+
+
+def test_parquet_write_with_expected_schema(tmp_path, with_morsel_size):
+    df = daft.read_csv(COOKBOOK_DATA_CSV)
+
+    df.write_parquet(tmp_path, expected_schema=df.schema())
+
+    with pytest.raises(ValueError, match="unexpected column `extra`"):
+        df.with_column("extra", daft.lit(1)).write_parquet(tmp_path, expected_schema=df.schema())
+    assert len(daft.read_parquet(tmp_path.as_posix()).collect()) == len(df.collect())
+
+
+@pytest.mark.parametrize("partition_cols", [None, ["Borough"]])
+def test_parquet_write_with_existing_schema(tmp_path, partition_cols, with_morsel_size):
+    df = daft.read_csv(COOKBOOK_DATA_CSV)
+
+    # Validating against a directory with no data yet is a no-op.
+    df.write_parquet(tmp_path, partition_cols=partition_cols, expected_schema="existing")
+    df.write_parquet(tmp_path, partition_cols=partition_cols, expected_schema="existing")
+
+    drifted = df.with_column("Unique Key", df["Unique Key"].cast(daft.DataType.string()))
+    with pytest.raises(ValueError, match="column `Unique Key` has type Utf8, expected Int64"):
+        drifted.write_parquet(tmp_path, partition_cols=partition_cols, expected_schema="existing")
+
+
+def test_cleanup_uncommitted_writes(tmp_path, with_morsel_size):
+    from daft.filesystem import cleanup_uncommitted_writes
+
+    df = daft.read_csv(COOKBOOK_DATA_CSV)
+    committed_paths = df.write_parquet(tmp_path).to_pydict()["path"]
+
+    # Simulate a job that was interrupted after writing some of its output.
+    (tmp_path / "_started_deadbeef").touch()
+    (tmp_path / "deadbeef-0-0.parquet").touch()
+
+    deleted = cleanup_uncommitted_writes(tmp_path)
+    assert sorted(os.path.basename(path) for path in deleted) == ["_started_deadbeef", "deadbeef-0-0.parquet"]
+    assert all(os.path.exists(path) for path in committed_paths)
+    assert cleanup_uncommitted_writes(tmp_path) == []
+
+
+@pytest.mark.parametrize("partition_cols", [None, ["Borough"]])
+def test_parquet_write_metadata(tmp_path, partition_cols, with_morsel_size):
+    df = daft.read_csv(COOKBOOK_DATA_CSV)
+    num_rows = len(df.collect())
+
+    paths = df.write_parquet(tmp_path, partition_cols=partition_cols, write_metadata=True).to_pydict()["path"]
+    summary = papq.read_metadata(tmp_path / "_metadata")
+    assert summary.num_rows == num_rows
+    summarized_paths = {summary.row_group(i).column(0).file_path for i in range(summary.num_row_groups)}
+    assert summarized_paths == {os.path.relpath(path.removeprefix("file://"), tmp_path) for path in paths}
+
+    # Reading the directory back uses the summary rather than listing the files and fetching their footers.
+    read_back_df = daft.read_parquet(tmp_path.as_posix(), hive_partitioning=partition_cols is not None)
+    assert_df_equals(df.to_pandas(), read_back_df.select(*df.column_names).to_pandas())
+
+    # Appended files are added to the summary, whereas writes that don't summarize the dataset delete it.
+    df.write_parquet(tmp_path, partition_cols=partition_cols, write_metadata=True)
+    assert papq.read_metadata(tmp_path / "_metadata").num_rows == 2 * num_rows
+    assert len(daft.read_parquet(tmp_path.as_posix()).collect()) == 2 * num_rows
+    df.write_parquet(tmp_path, partition_cols=partition_cols)
+    assert not os.path.exists(tmp_path / "_metadata")
+    assert len(daft.read_parquet(tmp_path.as_posix()).collect()) == 3 * num_rows
+
+
+def test_parquet_write_metadata_aggregations(tmp_path, with_morsel_size):
+    # The footer statistics of summarized files may answer global aggregations without reading the files.
+    df = daft.from_pydict({"x": [5, 2, None, 9, 3], "y": ["a", "b", "c", "d", "e"]}).into_partitions(2)
+    df.write_parquet(tmp_path, write_metadata=True)
+    result = daft.read_parquet(tmp_path.as_posix()).agg(
+        daft.col("x").min().alias("min"),
+        daft.col("x").max().alias("max"),
+        daft.col("y").count("all").alias("count"),
+    )
+    assert result.to_pydict() == {"min": [2], "max": [9], "count": [5]}
+
+    # No files are scanned, since the aggregations are answered from the footer statistics.
+    out = io.StringIO()
+    result.explain(show_all=True, file=out)
+    optimized_plan = out.getvalue().split("== Optimized Logical Plan ==")[-1].split("== Physical Plan ==")[0]
+    assert "Num Scan Tasks = 0" in optimized_plan
+
+
 @pytest.mark.parametrize("write_mode", ["append", "overwrite"])
 def test_empty_parquet_write_without_partitioning(tmp_path, write_mode, with_morsel_size):
     df = daft.read_csv(COOKBOOK_DATA_CSV)
@@ -248,6 +363,24 @@ def test_csv_write_with_partitioning(tmp_path, with_morsel_size):
     assert len(pd_df) == 5
 
 
+def test_json_write(tmp_path, with_morsel_size):
+    data = {"x": [1, 2, None], "y": ["a", "b", "c"]}
+    output_files = daft.from_pydict(data).into_partitions(2).write_json(tmp_path)
+    assert all(path.endswith(".json") for path in output_files.to_pydict()["path"])
+
+    read_back = daft.read_json(tmp_path.as_posix() + "/*.json").sort("y").to_pydict()
+    assert read_back == data
+
+
+def test_json_write_with_partitioning(tmp_path, with_morsel_size):
+    data = {"x": [1, 2, 3], "y": ["a", "b", "a"]}
+    output_files = daft.from_pydict(data).write_json(tmp_path, partition_cols=["y"])
+    assert len(output_files) == 2
+
+    read_back = daft.read_json(tmp_path.as_posix() + "/**/*.json").sort("x").to_pydict()
+    assert read_back == data
+
+
 def test_empty_csv_write(tmp_path, with_morsel_size):
     df = daft.read_csv(COOKBOOK_DATA_CSV)
     df = df.where(daft.lit(False))
@@ -256,6 +256,24 @@ def test_agg_groupby(make_df, repartition_nparts, with_morsel_size):
         assert set(x for x in res if x is not None) == set(x for x in exp if x is not None), "Sets should match"
 
 
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 7])
+def test_agg_groupby_nested_keys(make_df, repartition_nparts, with_morsel_size):
+    daft_df = make_df(
+        {
+            "lists": [[1, 2], [1], [1, 2], [1, None], [1], [1, None]],
+            "structs": [{"a": 1}, {"a": 2}, {"a": 1}, {"a": None}, {"a": 3}, {"a": None}],
+            "values": [1, 2, 3, 4, 5, 6],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("lists", "structs").agg(col("values").sum()).sort("values")
+    assert daft_df.to_pydict() == {
+        "lists": [[1], [1, 2], [1], [1, None]],
+        "structs": [{"a": 2}, {"a": 1}, {"a": 3}, {"a": None}],
+        "values": [2, 4, 5, 10],
+    }
+
+
 @pytest.mark.parametrize("repartition_nparts", [1, 2, 5])
 def test_agg_groupby_all_null(make_df, repartition_nparts, with_morsel_size):
     daft_df = make_df(
@@ -754,6 +772,24 @@ def test_agg_with_groupby_key_in_agg(make_df, repartition_nparts, with_morsel_si
     }
 
 
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+@pytest.mark.parametrize("groupby", [["key"], ["key", "other"]])
+def test_agg_groupby_canonicalizes_nans_and_zeros(make_df, repartition_nparts, with_morsel_size, groupby):
+    daft_df = make_df(
+        {
+            "key": [0.0, -0.0, float("nan"), -float("nan"), 0.0, float("nan")],
+            "other": [1, 1, 1, 1, 1, 1],
+            "values": [1, 2, 10, 20, 4, 40],
+        },
+        repartition=repartition_nparts,
+    )
+    # All NaNs are grouped together, as are -0.0 and 0.0.
+    res = daft_df.groupby(*groupby).agg(col("values").sum()).sort("values").to_pydict()
+    assert res["values"] == [7, 70]
+    assert res["key"][0] == 0.0
+    assert np.isnan(res["key"][1])
+
+
 @pytest.mark.parametrize("repartition_nparts", [2, 3])
 def test_agg_set_duplicates_across_partitions(make_df, repartition_nparts, with_morsel_size):
     """Test that set aggregation correctly maintains uniqueness across partitions.
@@ -0,0 +1,51 @@
+from __future__ import annotations
+
+import io
+
+import pytest
+
+
+def test_anonymize_hash(make_df) -> None:
+    df = make_df({"id": [1, 2, 3], "email": ["a@x.com", "b@x.com", None]})
+    anonymized = df.anonymize(["email"], key="pepper").sort("id").to_pydict()
+
+    assert anonymized["id"] == [1, 2, 3]
+    assert anonymized["email"][0] != anonymized["email"][1]
+    assert anonymized["email"][2] is None
+
+    # Hashes are deterministic for a given key, and change with the key.
+    assert df.anonymize(["email"], key=b"pepper").sort("id").to_pydict() == anonymized
+    assert df.anonymize(["email"], key="salt").sort("id").to_pydict()["email"][0] != anonymized["email"][0]
+
+
+def test_anonymize_hash_hmac_sha256(make_df) -> None:
+    # Test case 2 of RFC 4231.
+    df = make_df({"text": ["what do ya want for nothing?"]})
+    assert df.anonymize(["text"], key="Jefe").to_pydict() == {
+        "text": ["5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"]
+    }
+
+
+def test_anonymize_hash_key_not_in_plan(make_df) -> None:
+    df = make_df({"email": ["a@x.com"]})
+    with pytest.raises(ValueError, match="key is required"):
+        df.anonymize(["email"])
+
+    out = io.StringIO()
+    df.anonymize(["email"], key="pepper").explain(show_all=True, file=out)
+    assert "pepper" not in out.getvalue()
+
+
+def test_anonymize_mask(make_df) -> None:
+    df = make_df({"id": [1, 2, 3], "name": ["Alice", "Bob", None], "phone": ["555-0100", None, "(555) 01"]})
+    anonymized = df.anonymize(["name", "phone"], method="mask").sort("id").to_pydict()
+
+    assert anonymized == {"id": [1, 2, 3], "name": ["Xxxxx", "Xxx", None], "phone": ["999-9999", None, "(999) 99"]}
+
+
+def test_anonymize_invalid(make_df) -> None:
+    df = make_df({"name": ["Alice"]})
+    with pytest.raises(ValueError, match="Unsupported anonymization method"):
+        df.anonymize(["name"], method="redact")
+    with pytest.raises(ValueError, match="don't exist"):
+        df.anonymize(["email"], method="mask")
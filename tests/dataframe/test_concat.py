@@ -20,3 +20,28 @@ def test_concat_schema_mismatch(make_df, with_morsel_size):
 def test_self_concat(make_df, with_morsel_size):
     df = make_df({"foo": [1, 2, 3]})
     assert df.concat(df).to_pydict() == {"foo": [1, 2, 3, 1, 2, 3]}
+
+
+def test_concat_by_name(make_df, with_morsel_size):
+    df1 = make_df({"foo": [1, 2], "bar": ["a", "b"]})
+    df2 = make_df({"baz": [True], "foo": [3.5]})
+    result = df1.concat(df2, strategy="by_name")
+    assert result.column_names == ["foo", "bar", "baz"]
+    assert result.to_pydict() == {"foo": [1.0, 2.0, 3.5], "bar": ["a", "b", None], "baz": [None, None, True]}
+
+
+def test_concat_by_name_without_upcast(make_df, with_morsel_size):
+    df1 = make_df({"foo": [1, 2], "bar": ["a", "b"]})
+    df2 = make_df({"foo": [3.5]})
+    with pytest.raises(ValueError, match="without upcasting"):
+        df1.concat(df2, strategy="by_name", upcast=False)
+    assert df1.concat(make_df({"foo": [3]}), strategy="by_name", upcast=False).to_pydict() == {
+        "foo": [1, 2, 3],
+        "bar": ["a", "b", None],
+    }
+
+
+def test_concat_invalid_strategy(make_df):
+    df = make_df({"foo": [1]})
+    with pytest.raises(ValueError, match="strategy"):
+        df.concat(df, strategy="horizontal")
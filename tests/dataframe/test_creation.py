@@ -260,6 +260,24 @@ def test_create_dataframe_arrow_unsupported_dtype(valid_data: list[dict[str, flo
         df.to_arrow()
 
 
+###
+# Polars tests
+###
+
+
+@pytest.mark.parametrize("multiple", [False, True])
+def test_create_dataframe_polars(valid_data: list[dict[str, float]], multiple) -> None:
+    pl = pytest.importorskip("polars")
+
+    pl_df = pl.DataFrame(valid_data)
+    df = daft.from_polars([pl_df, pl_df] if multiple else pl_df)
+    expected = pl.concat([pl_df, pl_df]) if multiple else pl_df
+    assert len(df) == len(expected)
+    assert set(df.column_names) == set(expected.columns)
+    # Check roundtrip.
+    assert df.to_polars().equals(expected)
+
+
 ###
 # Pandas tests
 ###
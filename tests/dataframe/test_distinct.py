@@ -62,3 +62,31 @@ def test_distinct_with_empty(make_df, repartition_nparts, with_morsel_size):
     resultset = daft_df.to_pydict()
     assert len(resultset["id"]) == 0
     assert len(resultset["values"]) == 0
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 5])
+def test_distinct_nested(make_df, repartition_nparts, with_morsel_size):
+    daft_df = make_df(
+        {
+            "lists": [[1, 2], [1, None], [1, 2], None, [1, None], [], None],
+            "structs": [{"a": 1}, {"a": None}, {"a": 1}, None, {"a": None}, {"a": 2}, None],
+        },
+        repartition=repartition_nparts,
+    )
+    result = daft_df.distinct().to_pydict()
+    rows = sorted(zip(result["lists"], result["structs"]), key=repr)
+    assert rows == sorted([([1, 2], {"a": 1}), ([1, None], {"a": None}), (None, None), ([], {"a": 2})], key=repr)
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 5])
+def test_distinct_canonicalizes_nans_and_zeros(make_df, repartition_nparts, with_morsel_size):
+    daft_df = make_df(
+        {
+            "floats": [0.0, -0.0, float("nan"), -float("nan"), 0.0, float("nan")],
+            "values": ["a", "a", "b", "b", "a", "b"],
+        },
+        repartition=repartition_nparts,
+    )
+    # All NaNs are equal to each other and -0.0 is equal to 0.0, so there is one row for each.
+    assert len(daft_df.distinct().to_pydict()["floats"]) == 2
+    assert len(daft_df.select("floats").distinct().to_pydict()["floats"]) == 2
@@ -11,6 +11,7 @@ from daft.expressions import col
     [
         pa.array([[1, 2], [3, 4], None, []], type=pa.list_(pa.int64())),
         pa.array([[1, 2], [3, 4], None, []], type=pa.large_list(pa.int64())),
+        pa.array([[1, 2], [3, 4], None, None], type=pa.list_(pa.int64(), list_size=2)),
     ],
 )
 def test_explode(make_df, data):
@@ -24,6 +25,7 @@ def test_explode(make_df, data):
     [
         pa.array([[1, 2], [3, 4], None, []], type=pa.list_(pa.int64())),
         pa.array([[1, 2], [3, 4], None, []], type=pa.large_list(pa.int64())),
+        pa.array([[1, 2], [3, 4], None, None], type=pa.list_(pa.int64(), list_size=2)),
     ],
 )
 def test_explode_multiple_cols(make_df, data):
@@ -1,11 +1,16 @@
 from __future__ import annotations
 
+import functools
+import io
+import operator
 from typing import Any
 
 import pytest
 
 import daft
 from daft import DataFrame
+from daft.exceptions import DaftCoreException
+from tests.conftest import get_tests_daft_runner_name
 
 
 def test_filter_missing_column(make_df, valid_data: list[dict[str, Any]]) -> None:
@@ -50,3 +55,165 @@ def test_filter_alias_for_where() -> None:
     actual = df.filter("z = 9 AND y > 5").collect().to_pydict()
 
     assert actual == expected
+
+
+def test_assert_that() -> None:
+    df = daft.from_pydict({"x": [1, 2, 3]})
+    assert df.assert_that(daft.col("x") > 0, "x must be positive").to_pydict() == {"x": [1, 2, 3]}
+    assert df.assert_that("x > 0", "x must be positive").to_pydict() == {"x": [1, 2, 3]}
+
+
+def test_assert_that_fails() -> None:
+    df = daft.from_pydict({"x": [1, -2, None]})
+    with pytest.raises(DaftCoreException, match="Assertion failed for 2 of 3 rows: x must be positive"):
+        df.assert_that(daft.col("x") > 0, "x must be positive").collect()
+
+
+def test_assert_that_non_boolean() -> None:
+    df = daft.from_pydict({"x": [1, 2, 3]})
+    with pytest.raises(DaftCoreException, match="Expected assertion .* to resolve to type Boolean"):
+        df.assert_that(daft.col("x"), "x must be positive")
+
+
+def test_assert_that_not_pushed_down() -> None:
+    # Filtering before the assertion would skip the violating row, so the query must still fail.
+    df = daft.from_pydict({"x": [1, -2, 3]})
+    with pytest.raises(DaftCoreException, match="Assertion failed for 1 of 3 rows: x must be positive"):
+        df.assert_that(daft.col("x") > 0, "x must be positive").where(daft.col("x") > 0).collect()
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() == "ray", reason="Violations are recorded in the processes that execute the query"
+)
+def test_assert_that_violations_table() -> None:
+    df = daft.from_pydict({"x": [1, -2, None, 4], "y": ["a", "b", "c", "d"]})
+    valid = df.assert_that(daft.col("x") > 0, "x must be positive", violations_table="x_violations")
+    # Nothing is recorded until the query is executed.
+    assert daft.read_table("x_violations").to_pydict() == {"x": [], "y": []}
+
+    # The violations are recorded by the same execution that produces the valid rows, with all their columns even if
+    # they aren't selected afterwards, and filters on the result aren't pushed below the check.
+    assert valid.where(daft.col("x") > 2).select("x").to_pydict() == {"x": [4]}
+    assert daft.read_table("x_violations").sort("x").to_pydict() == {"x": [-2, None], "y": ["b", "c"]}
+
+
+def test_assert_that_violations_table_plan() -> None:
+    df = daft.from_pydict({"x": [1, -2, None, 4]})
+    valid = df.assert_that(daft.col("x") > 0, "x must be positive", violations_table="x_violations")
+    out = io.StringIO()
+    valid.where(daft.col("x") > 2).explain(show_all=True, file=out)
+    optimized = out.getvalue().split("== Optimized Logical Plan ==")[1].split("== Physical Plan ==")[0]
+    assert "Violations = x_violations-" in optimized
+    # The filter stays above the check.
+    assert optimized.index("Filter") < optimized.index("Assert")
+
+
+def test_filter_reduces_estimated_size() -> None:
+    df = daft.from_pydict({"x": list(range(1000))})
+    size = df._builder.estimated_size_bytes()
+    assert size > 0
+
+    filtered = df.where(daft.col("x") == 7)
+    assert 0 < filtered._builder.estimated_size_bytes() < size
+
+
+def test_filter_long_or_chain(make_df) -> None:
+    df = make_df({"x": list(range(2000))})
+    predicate = functools.reduce(operator.or_, [daft.col("x") == value for value in range(0, 2000, 3)])
+    assert sorted(df.where(predicate).to_pydict()["x"]) == list(range(0, 2000, 3))
+
+
+def test_filter_on_renamed_struct_field(make_df) -> None:
+    df = make_df({"id": [1, 2, 3], "s": [{"x": 1}, {"x": 5}, {"x": 3}]})
+    df = df.select(daft.col("id").alias("key"), daft.col("s").struct.get("x").alias("x"))
+    df = df.where((daft.col("x") > 2) & (daft.col("key") < 3))
+    assert df.to_pydict() == {"key": [2], "x": [5]}
+
+
+def test_filter_with_pushdown_disabled(make_df) -> None:
+    with daft.planning_config_ctx(disabled_optimizer_rules=["PushDownFilter"]):
+        assert daft.context.get_context().daft_planning_config.disabled_optimizer_rules == ["PushDownFilter"]
+        df = make_df({"id": [1, 2, 3], "x": [4, 5, 6]})
+        df = df.select(daft.col("id").alias("key"), daft.col("x")).where(daft.col("key") >= 2)
+        assert df.sort("key").to_pydict() == {"key": [2, 3], "x": [5, 6]}
+    assert daft.context.get_context().daft_planning_config.disabled_optimizer_rules == []
+
+
+@pytest.mark.parametrize("other_col", ["id", "other_id"])
+def test_filter_is_in_dataframe(make_df, other_col) -> None:
+    df = make_df({"id": [1, 2, 3, 4, None], "x": ["a", "b", "c", "d", "e"]})
+    other = make_df({other_col: [2, 4, 4, 6, None]})
+
+    semi = df.where(daft.col("id").is_in(other))
+    assert semi.sort("id").to_pydict() == {"id": [2, 4], "x": ["b", "d"]}
+    assert "Type = Semi" in semi._builder.optimize().pretty_print()
+
+    # As with a list, negating `is_in` is null rather than true for null ids, so their rows are filtered out.
+    anti = df.where(~daft.col("id").is_in(other) & (daft.col("x") != "a"))
+    assert anti.sort("id").to_pydict() == {"id": [3], "x": ["c"]}
+    anti_list = df.where(~daft.col("id").is_in([2, 4, 4, 6, None]) & (daft.col("x") != "a"))
+    assert anti_list.sort("id").to_pydict() == anti.sort("id").to_pydict()
+
+
+def test_filter_is_in_dataframe_with_multiple_columns(make_df) -> None:
+    df = make_df({"id": [1, 2]})
+    with pytest.raises(ValueError, match="one output column"):
+        df.where(daft.col("id").is_in(make_df({"a": [1], "b": [2]})))
+
+
+def test_filter_and_project_fused_into_scan(tmp_path) -> None:
+    daft.from_pydict({"x": list(range(100)), "y": ["a", "b"] * 50}).write_parquet(str(tmp_path))
+
+    # Keep the filters out of the scan's pushdowns, so that they're fused into the scan instead.
+    with daft.planning_config_ctx(disabled_optimizer_rules=["PushDownFilter"]):
+        df = daft.read_parquet(str(tmp_path)).where(daft.col("x") % 3 == 0).with_column("z", daft.col("x") * 2)
+        df = df.where(daft.col("z") > 100)
+        expected = [x for x in range(100) if x % 3 == 0 and x > 50]
+        assert df.sort("x").to_pydict() == {
+            "x": expected,
+            "y": ["a" if x % 2 == 0 else "b" for x in expected],
+            "z": [x * 2 for x in expected],
+        }
+
+        if get_tests_daft_runner_name() == "native":
+            out = io.StringIO()
+            df.explain(show_all=True, file=out)
+            physical_plan = out.getvalue().split("== Physical Plan ==")[-1]
+            assert "Fused Filter" in physical_plan
+            assert "Fused Project" in physical_plan
+
+        # The limit is fused on top of the filters and projection, and stops the scan once it's reached.
+        limited = df.limit(5).to_pydict()
+        assert len(limited["x"]) == 5
+        assert set(limited["x"]) <= set(expected)
+        assert limited["z"] == [x * 2 for x in limited["x"]]
+
+        if get_tests_daft_runner_name() == "native":
+            out = io.StringIO()
+            df.limit(5).explain(show_all=True, file=out)
+            assert "Fused Limit: 5" in out.getvalue().split("== Physical Plan ==")[-1]
+
+
+@pytest.mark.parametrize("disabled_optimizer_rules", [[], ["PushDownFilter"]])
+def test_always_false_filter_skips_scan(tmp_path, disabled_optimizer_rules) -> None:
+    daft.from_pydict({"x": list(range(100)), "y": ["a", "b"] * 50}).write_parquet(str(tmp_path))
+
+    # The filter is simplified to false, whether or not it's pushed into the scan, so no files need to be read.
+    with daft.planning_config_ctx(disabled_optimizer_rules=disabled_optimizer_rules):
+        df = daft.read_parquet(str(tmp_path)).where((daft.col("x") > 1) & daft.lit(False))
+        assert df.to_pydict() == {"x": [], "y": []}
+        assert df.count_rows() == 0
+
+        if get_tests_daft_runner_name() == "native":
+            out = io.StringIO()
+            df.explain(show_all=True, file=out)
+            physical_plan = out.getvalue().split("== Physical Plan ==")[-1]
+            assert "EmptyScan" in physical_plan
+
+
+def test_filter_long_or_chain_with_mixed_types(make_df) -> None:
+    df = make_df({"x": list(range(100))})
+    predicate = functools.reduce(
+        operator.or_, [daft.col("x") == (value if value % 2 == 0 else float(value)) for value in range(20)]
+    )
+    assert sorted(df.where(predicate).to_pydict()["x"]) == list(range(20))
@@ -0,0 +1,96 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import col
+from daft.functions import geohash_decode, geohash_encode, haversine_distance, point, within_bbox
+
+
+@pytest.fixture
+def cities():
+    return daft.from_pydict(
+        {
+            "city": ["London", "Paris", "Tokyo", "Suva", None],
+            "lat": [51.5074, 48.8566, 35.6762, -18.1416, None],
+            "lon": [-0.1278, 2.3522, 139.6503, 178.4419, 0.0],
+        }
+    )
+
+
+def test_haversine_distance(cities):
+    df = cities.select(haversine_distance(col("lat"), col("lon"), 40.7128, -74.0060).round(1).alias("km"))
+    assert df.to_pydict() == {"km": [5570.2, 5837.2, 10851.7, 12771.3, None]}
+
+
+def test_haversine_distance_between_columns():
+    df = daft.from_pydict({"lat1": [0.0, 10], "lon1": [0.0, 20], "lat2": [0.0, 10], "lon2": [180.0, 20]})
+    df = df.select(haversine_distance(col("lat1"), col("lon1"), col("lat2"), col("lon2")).alias("km"))
+    assert df.schema()["km"].dtype == daft.DataType.float64()
+    assert [round(km, 3) for km in df.to_pydict()["km"]] == [20015.114, 0.0]
+
+
+def test_haversine_distance_requires_numbers(cities):
+    with pytest.raises(Exception, match="numeric"):
+        cities.select(haversine_distance(col("city"), col("lon"), 0.0, 0.0)).collect()
+
+
+def test_geohash_roundtrip(cities):
+    df = cities.with_column("geohash", geohash_encode(col("lat"), col("lon"), precision=6))
+    assert df.to_pydict()["geohash"] == ["gcpvj0", "u09tvw", "xn76cy", "ruybu9", None]
+
+    df = df.with_column("point", geohash_decode(col("geohash")))
+    assert df.schema()["point"].dtype == daft.DataType.struct(
+        {"lat": daft.DataType.float64(), "lon": daft.DataType.float64()}
+    )
+    for row in df.where(col("point").not_null()).to_pylist():
+        assert abs(row["point"]["lat"] - row["lat"]) < 0.01
+        assert abs(row["point"]["lon"] - row["lon"]) < 0.01
+
+
+def test_geohash_encode_out_of_range():
+    df = daft.from_pydict({"lat": [91.0, 0.0, float("nan")], "lon": [0.0, -181.0, 0.0]})
+    assert df.select(geohash_encode(col("lat"), col("lon"))).to_pydict() == {"lat": [None, None, None]}
+
+
+@pytest.mark.parametrize("precision", [0, 13])
+def test_geohash_encode_invalid_precision(precision):
+    with pytest.raises(ValueError, match="between 1 and 12"):
+        geohash_encode(col("lat"), col("lon"), precision=precision)
+
+
+def test_geohash_decode_invalid():
+    df = daft.from_pydict({"geohash": ["S", "", "abc", None]})
+    df = df.select(geohash_decode(col("geohash")))
+    assert df.to_pydict() == {"geohash": [{"lat": 22.5, "lon": 22.5}, None, None, None]}
+
+
+def test_point(cities):
+    df = cities.select(point(col("lat"), col("lon")).alias("point"))
+    assert df.to_pydict()["point"][:2] == [{"lat": 51.5074, "lon": -0.1278}, {"lat": 48.8566, "lon": 2.3522}]
+
+
+@pytest.mark.parametrize(
+    ["bbox", "expected"],
+    [
+        # Europe
+        ((35.0, -10.0, 60.0, 30.0), ["London", "Paris"]),
+        # The Pacific, across the antimeridian
+        ((-30.0, 120.0, 40.0, -150.0), ["Tokyo", "Suva"]),
+        # The bounds are inclusive
+        ((51.5074, -0.1278, 51.5074, -0.1278), ["London"]),
+        ((0.0, 0.0, 10.0, 10.0), []),
+    ],
+)
+def test_within_bbox(cities, bbox, expected):
+    df = cities.where(within_bbox(col("lat"), col("lon"), *bbox))
+    assert df.to_pydict()["city"] == expected
+
+
+def test_within_bbox_with_column_bounds(cities):
+    bounds = daft.from_pydict(
+        {"min_lat": [35.0, -30.0], "min_lon": [-10.0, 120.0], "max_lat": [60.0, 40.0], "max_lon": [30.0, -150.0]}
+    )
+    df = cities.join(bounds, how="cross")
+    df = df.where(within_bbox(col("lat"), col("lon"), col("min_lat"), col("min_lon"), col("max_lat"), col("max_lon")))
+    assert sorted(df.to_pydict()["city"]) == ["London", "Paris", "Suva", "Tokyo"]
@@ -0,0 +1,76 @@
+from __future__ import annotations
+
+import io
+
+import pytest
+
+import daft
+from daft import col
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Iterations are only supported on the native runner"
+)
+
+
+def _extend_paths(edges):
+    next_hops = edges.select(col("src").alias("dst"), col("dst").alias("next"))
+
+    def extend(paths):
+        longer = paths.join(next_hops, on="dst").select("src", col("next").alias("dst"))
+        return paths.concat(longer).distinct()
+
+    return extend
+
+
+def test_iterate_until_fixed_point(make_df):
+    edges = make_df({"src": [1, 2, 3, 5], "dst": [2, 3, 4, 5]})
+    closure = edges.iterate(_extend_paths(edges), max_iters=10).sort(["src", "dst"])
+    assert closure.to_pydict() == {"src": [1, 1, 1, 2, 2, 3, 5], "dst": [2, 3, 4, 3, 4, 4, 5]}
+
+
+def test_iterate_stops_at_max_iters(make_df):
+    edges = make_df({"src": [1, 2, 3], "dst": [2, 3, 4]})
+    paths = edges.iterate(_extend_paths(edges), max_iters=1).sort(["src", "dst"])
+    assert paths.to_pydict() == {"src": [1, 1, 2, 2, 3], "dst": [2, 3, 3, 4, 4]}
+
+
+def test_iterate_with_convergence_expr(make_df):
+    calls = []
+
+    def halve(df):
+        calls.append(None)
+        return df.select((col("x") / 2).alias("x"))
+
+    df = make_df({"x": [16.0, 64.0]})
+    result = df.iterate(halve, max_iters=100, convergence_expr=col("x").max() <= 4)
+    assert result.to_pydict() == {"x": [1.0, 4.0]}
+    # The function only builds the plan of an iteration, which is run until convergence.
+    assert len(calls) == 1
+
+
+def test_iterate_validates_arguments(make_df):
+    df = make_df({"x": [1]})
+    with pytest.raises(ValueError, match="max_iters"):
+        df.iterate(lambda df: df, max_iters=0)
+    with pytest.raises(TypeError, match="plan_fn"):
+        df.iterate(lambda df: None, max_iters=1)
+
+
+def test_iterate_is_a_plan_node():
+    df = daft.from_pydict({"x": [1, 2, 3]})
+    result = df.iterate(lambda df: df.with_column("x", col("x") + 1), max_iters=3)
+    out = io.StringIO()
+    result.explain(show_all=True, file=out)
+    assert "Iterate: max iterations = 3" in out.getvalue()
+    assert result.to_pydict() == {"x": [4, 5, 6]}
+
+
+def test_iterate_nested(make_df):
+    df = make_df({"x": [0]})
+
+    def add_up_to_three(df):
+        return df.iterate(lambda df: df.with_column("x", col("x") + 1), max_iters=3)
+
+    result = df.iterate(add_up_to_three, max_iters=2)
+    assert result.to_pydict() == {"x": [6]}
@@ -1,7 +1,11 @@
 from __future__ import annotations
 
+import decimal
+from datetime import datetime
+
 import pyarrow as pa
 import pytest
+import pytz
 
 import daft
 from daft import col
@@ -97,6 +101,76 @@ def test_joins(join_strategy, join_type, make_df, n_partitions: int, with_morsel
     }
 
 
+@pytest.mark.parametrize("n_partitions", [1, 2, 4])
+@pytest.mark.parametrize(
+    "join_strategy",
+    [None, "hash", "sort_merge", "broadcast"],
+    indirect=True,
+)
+def test_joins_on_nested_keys(join_strategy, make_df, n_partitions: int):
+    skip_invalid_join_strategies(join_strategy, "inner")
+
+    df = make_df(
+        {
+            "lists": [[1, 2], [1], [], [1, None]],
+            "structs": [{"a": 1}, {"a": 2}, {"a": None}, {"a": 3}],
+            "B": ["a", "b", "c", "d"],
+        },
+        repartition=n_partitions,
+    )
+    other = make_df(
+        {
+            "lists": [[1, None], [2, 1], [1, 2], []],
+            "structs": [{"a": 3}, {"a": 2}, {"a": 1}, {"a": None}],
+            "C": ["w", "x", "y", "z"],
+        },
+    )
+
+    joined = df.join(other, on=["lists", "structs"], strategy=join_strategy).sort("B").to_pydict()
+    assert joined == {
+        "lists": [[1, 2], [], [1, None]],
+        "structs": [{"a": 1}, {"a": None}, {"a": 3}],
+        "B": ["a", "c", "d"],
+        "C": ["y", "z", "w"],
+    }
+
+
+@pytest.mark.parametrize("n_partitions", [1, 2, 4])
+@pytest.mark.parametrize("join_strategy", [None, "hash"], indirect=True)
+def test_joins_on_temporal_and_decimal_keys(join_strategy, make_df, n_partitions: int):
+    utc = pytz.utc
+    pacific = pytz.timezone("US/Pacific")
+    instant = datetime(2024, 1, 1, 12, 0, 0)
+    df = make_df(
+        {
+            "ts": pa.array(
+                [utc.localize(instant), utc.localize(instant.replace(hour=13))], type=pa.timestamp("us", tz="UTC")
+            ),
+            "dec": [decimal.Decimal("1.50"), decimal.Decimal("-2.25")],
+            "B": ["a", "b"],
+        },
+        repartition=n_partitions,
+    )
+    other = make_df(
+        {
+            # The same instants in another time zone are cast to UTC by the join.
+            "ts": pa.array(
+                [
+                    utc.localize(instant.replace(hour=13)).astimezone(pacific),
+                    utc.localize(instant).astimezone(pacific),
+                ],
+                type=pa.timestamp("us", tz="UTC"),
+            ),
+            "dec": [decimal.Decimal("-2.25"), decimal.Decimal("1.50")],
+            "C": ["x", "y"],
+        },
+    )
+
+    joined = df.join(other, on=["ts", "dec"], strategy=join_strategy).sort("B").to_pydict()
+    assert joined["B"] == ["a", "b"]
+    assert joined["C"] == ["y", "x"]
+
+
 @pytest.mark.parametrize("n_partitions", [1, 2, 4])
 @pytest.mark.parametrize(
     "join_strategy",
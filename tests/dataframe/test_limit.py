@@ -0,0 +1,29 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import col
+
+
+@pytest.mark.parametrize("n_partitions", [1, 3])
+@pytest.mark.parametrize("limit", [0, 1, 7, 500])
+def test_filter_then_limit(make_df, n_partitions: int, limit: int, with_morsel_size):
+    df = make_df({"x": list(range(3_000))}, repartition=n_partitions)
+    result = df.where(col("x") % 3 == 0).limit(limit).to_pydict()["x"]
+    assert len(result) == limit
+    assert all(x % 3 == 0 for x in result)
+
+
+def test_nested_limits_with_projection(make_df, with_morsel_size):
+    df = make_df({"x": list(range(5_000))})
+    df = df.limit(3_000).with_column("y", col("x") * 2).where(col("x") > 100).limit(5)
+    result = df.to_pydict()
+    assert len(result["x"]) == 5
+    assert result["y"] == [x * 2 for x in result["x"]]
+
+
+def test_limit_after_blocking_sink(make_df, with_morsel_size):
+    # The sort has to see all of its input, even though only the first rows after it are needed.
+    df = make_df({"x": list(range(5_000))}, repartition=2)
+    result = df.where(col("x") % 2 == 1).sort("x", desc=True).limit(3).to_pydict()
+    assert result == {"x": [4_999, 4_997, 4_995]}
@@ -175,3 +175,54 @@ def test_map_groups_with_alias(make_df, repartition_nparts, with_morsel_size):
     daft_cols = daft_df.to_pydict()
 
     assert daft_cols == expected
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_groupby_apply(make_df, repartition_nparts, with_morsel_size):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 2],
+            "a": [1, 3, 3],
+            "b": ["x", "y", "z"],
+        },
+        repartition=repartition_nparts,
+    )
+
+    def fn(table):
+        a = table["a"].to_pylist()
+        return {"a": [x / sum(a) for x in a], "b": [s.upper() for s in table["b"].to_pylist()]}
+
+    daft_df = (
+        daft_df.groupby("group")
+        .apply(fn, {"a": daft.DataType.float64(), "b": daft.DataType.string()})
+        .sort(["group", "a"], desc=False)
+    )
+    expected = {"group": [1, 1, 2], "a": [0.25, 0.75, 1.0], "b": ["X", "Y", "Z"]}
+
+    assert daft_df.to_pydict() == expected
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 3])
+def test_groupby_apply_returns_arrow_and_pandas(make_df, repartition_nparts, with_morsel_size):
+    import pandas as pd
+    import pyarrow as pa
+
+    daft_df = make_df({"group": [1, 2, 2], "a": [1, 2, 3]}, repartition=repartition_nparts)
+    return_dtypes = {"count": daft.DataType.int64()}
+
+    def to_arrow(table):
+        return pa.table({"count": [table.num_rows]})
+
+    def to_pandas(table):
+        return pd.DataFrame({"count": [len(table.to_pandas())]})
+
+    for fn in [to_arrow, to_pandas]:
+        result = daft_df.groupby("group").apply(fn, return_dtypes).sort("group")
+        assert result.to_pydict() == {"group": [1, 2], "count": [1, 2]}
+
+
+def test_groupby_apply_rejects_key_columns_in_output(make_df):
+    daft_df = make_df({"group": [1, 2], "a": [1, 2]})
+
+    with pytest.raises(ValueError, match="group keys"):
+        daft_df.groupby("group").apply(lambda table: table, {"group": daft.DataType.int64()})
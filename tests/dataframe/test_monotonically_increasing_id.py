@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import pytest
 
+import daft
 from daft.datatype import DataType
 from daft.expressions import col
 from daft.functions import monotonically_increasing_id
@@ -520,3 +521,20 @@ def test_monotonic_id_in_join_condition(make_df) -> None:
     )
 
     assert df1.to_pydict() == df2.to_pydict()
+
+
+def test_monotonic_id_with_existing_id_column(make_df) -> None:
+    df = make_df({"id": ["a", "b", "c"]}).with_column("row", monotonically_increasing_id())
+    assert df.to_pydict() == {"id": ["a", "b", "c"], "row": [0, 1, 2]}
+
+
+def test_monotonic_id_in_filter(make_df) -> None:
+    df = make_df({"a": [5, 6, 7, 8], "id": [1, 2, 3, 4]}).where(monotonically_increasing_id() % 2 == 1)
+    assert df.column_names == ["a", "id"]
+    assert df.to_pydict() == {"a": [6, 8], "id": [2, 4]}
+
+
+def test_monotonic_id_in_sql(make_df) -> None:
+    df = make_df({"a": [5, 6, 7]})
+    actual = daft.sql("SELECT a, monotonically_increasing_id() AS row FROM df").to_pydict()
+    assert actual == {"a": [5, 6, 7], "row": [0, 1, 2]}
@@ -1,5 +1,9 @@
 from __future__ import annotations
 
+import pytest
+
+from tests.conftest import get_tests_daft_runner_name
+
 
 def test_into_partitions_some_empty(make_df) -> None:
     data = {"foo": [1, 2, 3]}
@@ -23,3 +27,51 @@ def test_into_partitions_some_no_split(make_df) -> None:
     df = df.into_partitions(4).collect()
 
     assert df.to_pydict() == data
+
+
+@pytest.mark.parametrize("desc", [False, True])
+def test_repartition_by_range(make_df, desc: bool) -> None:
+    data = {"foo": [7, 3, 9, 1, 5, 8, 2, 6, 4, 0] * 10}
+    df = make_df(data).into_partitions(4).repartition_by_range(3, "foo", desc=desc).collect()
+    assert sorted(df.to_pydict()["foo"]) == sorted(data["foo"])
+
+    if get_tests_daft_runner_name() != "native":
+        # The partitions are contiguous ranges of the values, in order.
+        parts = list(df.iter_partitions())
+        if get_tests_daft_runner_name() == "ray":
+            import ray
+
+            parts = ray.get(parts)
+        values = [sorted(part.to_pydict()["foo"], reverse=desc) for part in parts]
+        flattened = [v for part in values for v in part]
+        assert flattened == sorted(data["foo"], reverse=desc)
+
+
+def test_repartition_by_range_mismatched_desc(make_df) -> None:
+    with pytest.raises(Exception, match="descending flags"):
+        make_df({"foo": [1, 2], "bar": [3, 4]}).repartition_by_range(2, ["foo", "bar"], desc=[True])
+
+
+@pytest.mark.parametrize(
+    "key",
+    [
+        [[1, 2], [3], [], None, [1, 2], [3]],
+        [{"a": 1, "b": "x"}, {"a": 2, "b": None}, None, {"a": 1, "b": "x"}, {"a": 2, "b": None}, None],
+    ],
+    ids=["list", "struct"],
+)
+def test_repartition_by_nested_key(make_df, key) -> None:
+    df = make_df({"key": key, "row": list(range(len(key)))}).repartition(3, "key").collect()
+    assert sorted(df.to_pydict()["row"]) == list(range(len(key)))
+
+    if get_tests_daft_runner_name() != "native":
+        # Equal keys, including nulls, are hashed to the same partition.
+        parts = list(df.iter_partitions())
+        if get_tests_daft_runner_name() == "ray":
+            import ray
+
+            parts = ray.get(parts)
+        partition_of_key = {}
+        for i, part in enumerate(parts):
+            for k in part.to_pydict()["key"]:
+                assert partition_of_key.setdefault(repr(k), i) == i
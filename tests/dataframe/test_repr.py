@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import io
+import json
 import re
 
 import numpy as np
@@ -191,7 +193,7 @@ def test_repr_with_unicode(make_df, data_source):
         ),
         "🦁": (
             "Utf8",
-            ["🔥a", "b🔥", "🦁🔥🦁🔥🦁🔥🦁🔥🦁🔥🦁🔥🦁🔥🦁🔥🦁🔥🦁🔥🦁🔥🦁🔥🦁🔥🦁🔥🦁…"],
+            ["🔥a", "b🔥", "🦁🔥🦁🔥🦁🔥🦁🔥🦁🔥🦁🔥🦁🔥…"],
         ),
     }
 
@@ -334,3 +336,42 @@ def test_repr_empty_struct():
 (Showing first 2 of 2 rows)"""
 
     assert str(df) == expected_repr
+
+
+def test_explain_verbose_shows_output_schema():
+    df = daft.from_pydict({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    df = df.select((daft.col("a") * 2.5).alias("c"), daft.col("b"))
+
+    with io.StringIO() as out:
+        df.explain(file=out, verbose=True)
+        verbose = out.getvalue()
+    assert "Project: col(a) * lit(2.5) as c, col(b)" in verbose
+    assert "Output schema = c#Float64, b#Utf8" in verbose
+    assert "Semantic ID = Project-" in verbose
+
+    # Semantic IDs are deterministic, so explaining the same plan again shows the same IDs.
+    with io.StringIO() as out:
+        df.explain(file=out, verbose=True)
+        assert out.getvalue() == verbose
+
+    with io.StringIO() as out:
+        df.explain(file=out)
+        assert "Output schema" not in out.getvalue()
+
+
+def test_explain_json():
+    df = daft.from_pydict({"a": [1, 2, 3]}).where(daft.col("a") > 1)
+
+    with io.StringIO() as out:
+        df.explain(show_all=True, format="json", file=out)
+        explained = out.getvalue()
+    assert "== Physical Plan ==" in explained
+
+    unoptimized = json.loads(df._builder.pretty_print(format="json"))
+    assert unoptimized["name"] == "Filter"
+    assert unoptimized["details"] == ["Filter: col(a) > lit(1)"]
+    [source] = unoptimized["children"]
+    assert source["children"] == []
+
+    simple = json.loads(df._builder.pretty_print(simple=True, format="json"))
+    assert simple["details"] == ["Filter"]
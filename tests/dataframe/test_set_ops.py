@@ -224,3 +224,19 @@ def test_union_all_by_name():
 
     assert actual_sql == expected
     assert actual_df == expected
+
+
+def test_union_all_by_name_coerces_types():
+    df1 = daft.from_pydict({"x": [1, 2], "y": ["a", "b"]}).with_column("x", daft.col("x").cast(daft.DataType.int32()))
+    df2 = daft.from_pydict({"y": ["c"], "x": [3.5]})
+
+    actual = df1.union_all_by_name(df2)
+    assert actual.schema()["x"].dtype == daft.DataType.float64()
+    assert actual.sort("y").to_pydict() == {"x": [1.0, 2.0, 3.5], "y": ["a", "b", "c"]}
+
+
+def test_union_by_name_fails_without_supertype():
+    df1 = daft.from_pydict({"x": [1, 2]})
+    df2 = daft.from_pydict({"x": [[1], [2]]})
+    with pytest.raises(daft.exceptions.DaftCoreException, match="supertype"):
+        df1.union_by_name(df2).collect()
@@ -72,3 +72,14 @@ def test_show_not_from_cached_repr(make_df, valid_data, data_source):
     assert len(df_display.preview.preview_partition) == len(valid_data)
     assert df_display.preview.dataframe_num_rows == 3
     assert df_display.num_rows == 3
+
+
+def test_preview(make_df, valid_data):
+    df = make_df(valid_data)
+    df_display = df.preview(2)
+
+    assert df_display.schema == df.schema()
+    assert len(df_display.preview.preview_partition) == 2
+    assert df_display.num_rows == 2
+    assert "(Showing first 2" in repr(df_display)
+    assert "(Showing first 2" in df_display._repr_html_()
@@ -8,6 +8,7 @@ import pytest
 import daft
 from daft.datatype import DataType
 from daft.errors import ExpressionTypeError
+from tests.conftest import get_tests_daft_runner_name
 
 ###
 # Validation tests
@@ -401,3 +402,115 @@ def test_multi_column_sort_nulls_first(make_df, cast_to, nulls_first, desc, expe
     select * from df order by id1 {id1_ordering} {id1_nulls}, id2 {id2_ordering} {id2_nulls}
     """).to_pydict()
     assert result == expected
+
+
+@pytest.mark.parametrize("desc", [False, True])
+@pytest.mark.parametrize("oversampling_factor", [1, 32])
+def test_large_sort_with_sampled_boundaries(make_df, desc, oversampling_factor):
+    # Enough rows for sorts to be split into ranges of sampled boundaries, with duplicated keys and nulls.
+    keys = [None if i % 97 == 0 else (i * 7919) % 50_000 for i in range(100_000)]
+    df = make_df({"key": keys, "row": list(range(len(keys)))}, repartition=4)
+
+    with daft.execution_config_ctx(sort_boundary_oversampling_factor=oversampling_factor):
+        result = df.sort("key", desc=desc).to_pydict()
+
+    non_null = sorted((k for k in keys if k is not None), reverse=desc)
+    nulls = [None] * (len(keys) - len(non_null))
+    assert result["key"] == (nulls + non_null if desc else non_null + nulls)
+    assert sorted(result["row"]) == list(range(len(keys)))
+
+
+@pytest.mark.parametrize("desc", [False, True])
+def test_sort_spilling_sorted_runs(make_df, desc):
+    # A memory limit this low makes sorts on the native runner spill every morsel as a sorted run, and merge the runs
+    # from disk a morsel of each at a time.
+    keys = [None if i % 89 == 0 else (i * 7919) % 5_000 for i in range(20_000)]
+    df = make_df({"key": keys, "row": list(range(len(keys)))}, repartition=4)
+
+    with daft.execution_config_ctx(memory_limit_bytes=64 * 1024, default_morsel_size=1_000):
+        result = df.sort("key", desc=desc).to_pydict()
+
+    non_null = sorted((k for k in keys if k is not None), reverse=desc)
+    nulls = [None] * (len(keys) - len(non_null))
+    assert result["key"] == (nulls + non_null if desc else non_null + nulls)
+    assert sorted(result["row"]) == list(range(len(keys)))
+
+
+@pytest.mark.parametrize("desc", [False, True])
+def test_sort_within_partitions(make_df, desc):
+    data = {"x": [7, 3, None, 9, 1, 5, 8, 2, None, 6, 4, 0]}
+    df = make_df(data).into_partitions(3).sort_within_partitions("x", desc=desc).collect()
+    assert sorted(df.to_pydict()["x"], key=lambda x: (x is None, x)) == sorted(
+        data["x"], key=lambda x: (x is None, x)
+    )
+
+    if get_tests_daft_runner_name() != "native":
+        # Every partition is sorted, but rows aren't moved between partitions.
+        parts = list(df.iter_partitions())
+        if get_tests_daft_runner_name() == "ray":
+            import ray
+
+            parts = ray.get(parts)
+        assert len(parts) == 3
+        for part in parts:
+            values = part.to_pydict()["x"]
+            non_null = [v for v in values if v is not None]
+            nulls = [None] * (len(values) - len(non_null))
+            expected = sorted(non_null, reverse=desc)
+            assert values == (nulls + expected if desc else expected + nulls)
+
+
+@pytest.mark.parametrize("desc", [False, True])
+def test_sort_nested(make_df, desc):
+    # Lists are ordered lexicographically by their elements and structs field by field, with nulls in them ordered
+    # after values.
+    lists = [[2, 1], [1, None], None, [], [1, 2], [1]]
+    structs = [
+        {"a": 2, "b": "x"},
+        {"a": 1, "b": "y"},
+        None,
+        {"a": 1, "b": "x"},
+        {"a": None, "b": "x"},
+        {"a": 0, "b": "z"},
+    ]
+    df = make_df({"lists": lists, "structs": structs}, repartition=2)
+
+    sorted_lists = [[], [1], [1, 2], [1, None], [2, 1]]
+    result = df.sort("lists", desc=desc).to_pydict()["lists"]
+    assert result == ([None] + sorted_lists[::-1] if desc else sorted_lists + [None])
+
+    sorted_structs = [
+        {"a": 0, "b": "z"},
+        {"a": 1, "b": "x"},
+        {"a": 1, "b": "y"},
+        {"a": 2, "b": "x"},
+        {"a": None, "b": "x"},
+    ]
+    result = df.sort("structs", desc=desc).to_pydict()["structs"]
+    assert result == ([None] + sorted_structs[::-1] if desc else sorted_structs + [None])
+
+
+def test_sort_case_insensitive_collation(make_df):
+    df = make_df({"name": ["b", "C", "a", "B"]}, repartition=2)
+    result = df.sort("name", collation="case_insensitive").to_pydict()["name"]
+    assert [n.lower() for n in result] == ["a", "b", "b", "c"]
+
+
+@pytest.mark.parametrize("desc", [False, True])
+def test_sort_natural_collation(make_df, desc):
+    df = make_df({"f": ["file10", "file2", None, "file1"]}, repartition=2)
+    result = df.sort("f", desc=desc, collation="natural").to_pydict()["f"]
+    expected = ["file1", "file2", "file10"]
+    assert result == ([None] + expected[::-1] if desc else expected + [None])
+
+
+def test_sort_collation_per_column(make_df):
+    df = make_df({"id": [2, 1, 1], "f": ["x10", "x9", "x10"]})
+    result = df.sort(["id", "f"], collation=["binary", "natural"]).to_pydict()
+    assert result == {"id": [1, 1, 2], "f": ["x9", "x10", "x10"]}
+
+
+def test_sort_invalid_collation(make_df):
+    df = make_df({"f": ["a", "b"]})
+    with pytest.raises(Exception, match="Collation"):
+        df.sort("f", collation="klingon").collect()
@@ -56,3 +56,29 @@ def test_to_pandas(n_partitions: int) -> None:
     pd_df = df.to_pandas().sort_values("integers").reset_index(drop=True)
     expected_df = pd.DataFrame(TEST_DATA).sort_values("integers").reset_index(drop=True)
     pd.testing.assert_frame_equal(pd_df, expected_df)
+
+
+def test_to_pandas_nullable_dtypes() -> None:
+    df = daft.from_pydict({"integers": [1, None, 3], "bools": [True, None, False], "strings": ["a", None, "c"]})
+    pd_df = df.to_pandas(use_nullable_dtypes=True)
+    assert pd_df["integers"].dtype == pd.Int64Dtype()
+    assert pd_df["bools"].dtype == pd.BooleanDtype()
+    assert pd_df["strings"].dtype == pd.StringDtype()
+    assert pd_df["integers"].isna().tolist() == [False, True, False]
+
+
+def test_to_pandas_date_as_object() -> None:
+    df = daft.from_pydict({"dates": [datetime.date(2020, 1, 1), None]})
+    assert df.to_pandas()["dates"].dtype == object
+    assert pd.api.types.is_datetime64_any_dtype(df.to_pandas(date_as_object=False)["dates"])
+
+
+@pytest.mark.parametrize("n_partitions", [1, 2])
+def test_to_polars(n_partitions: int) -> None:
+    pl = pytest.importorskip("polars")
+
+    df = daft.from_pydict(TEST_DATA).repartition(n_partitions)
+    pl_df = df.to_polars()
+    assert isinstance(pl_df, pl.DataFrame)
+    expected_table = sort_arrow_table(df.to_arrow(), "integers")
+    assert sort_arrow_table(pl_df.to_arrow(), "integers").to_pydict() == expected_table.to_pydict()
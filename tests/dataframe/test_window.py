@@ -0,0 +1,152 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import Window, col
+from daft.functions import rank, row_number
+
+
+def sorted_pydict(df, by):
+    return df.sort(by).to_pydict()
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_row_number_over_partitions(make_df, repartition_nparts):
+    df = make_df(
+        {"group": ["a", "b", "a", "b", "a"], "value": [3, 2, 1, 4, 2]},
+        repartition=repartition_nparts,
+    )
+    window = Window().partition_by("group").order_by("value")
+    df = df.with_column("row", row_number().over(window))
+    assert sorted_pydict(df, ["group", "value"]) == {
+        "group": ["a", "a", "a", "b", "b"],
+        "value": [1, 2, 3, 2, 4],
+        "row": [1, 2, 3, 1, 2],
+    }
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 3])
+def test_rank_with_ties(make_df, repartition_nparts):
+    df = make_df({"score": [10, 20, 20, 30, 10]}, repartition=repartition_nparts)
+    df = df.select(col("score"), rank().over(Window().order_by("score", desc=True)).alias("rank"))
+    assert sorted_pydict(df, "rank") == {
+        "score": [30, 20, 20, 10, 10],
+        "rank": [1, 2, 2, 4, 4],
+    }
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2])
+def test_lag_and_lead_stay_within_partitions(make_df, repartition_nparts):
+    df = make_df(
+        {"group": [1, 1, 1, 2, 2], "day": [1, 2, 3, 1, 2], "price": [10, 12, None, 5, 7]},
+        repartition=repartition_nparts,
+    )
+    window = Window().partition_by("group").order_by("day")
+    df = df.with_columns(
+        {
+            "prev": col("price").lag().over(window),
+            "next": col("price").lead(1, default=0).over(window),
+        }
+    )
+    assert sorted_pydict(df, ["group", "day"]) == {
+        "group": [1, 1, 1, 2, 2],
+        "day": [1, 2, 3, 1, 2],
+        "price": [10, 12, None, 5, 7],
+        "prev": [None, 10, 12, None, 5],
+        # The default only fills in for rows without a next row, rather than for null values.
+        "next": [12, None, 0, 7, 0],
+    }
+
+
+@pytest.mark.parametrize(
+    "nulls, desc, expected",
+    [
+        ("largest", False, [1, 3, 2, 3]),
+        ("largest", True, [4, 1, 3, 1]),
+        ("smallest", False, [3, 1, 4, 1]),
+        ("smallest", True, [2, 3, 1, 3]),
+    ],
+)
+@pytest.mark.parametrize("repartition_nparts", [1, 2])
+def test_rank_with_null_ordering(make_df, repartition_nparts, nulls, desc, expected):
+    df = make_df({"id": [1, 2, 3, 4], "value": [1, None, 2, None]}, repartition=repartition_nparts)
+    window = Window().order_by("value", desc=desc, nulls=nulls)
+    df = df.with_column("rank", rank().over(window))
+    assert sorted_pydict(df, "id") == {"id": [1, 2, 3, 4], "value": [1, None, 2, None], "rank": expected}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2])
+def test_window_functions_skipping_nulls(make_df, repartition_nparts):
+    df = make_df(
+        {"group": [1, 1, 1, 1, 2, 2], "day": [1, None, 3, 4, None, 2], "price": [10, 11, 12, 13, 5, 7]},
+        repartition=repartition_nparts,
+    )
+    window = Window().partition_by("group").order_by("day", nulls="skip")
+    df = df.with_columns(
+        {
+            "rank": rank().over(window),
+            "prev": col("price").lag(1, default=0).over(window),
+            "next": col("price").lead().over(window),
+        }
+    )
+    assert sorted_pydict(df, "price") == {
+        "group": [2, 2, 1, 1, 1, 1],
+        "day": [None, 2, 1, None, 3, 4],
+        "price": [5, 7, 10, 11, 12, 13],
+        # Rows with null days are left out of their windows, so lag and lead skip over them.
+        "rank": [None, 1, 1, None, 2, 3],
+        "prev": [None, 0, 0, None, 10, 12],
+        "next": [None, None, 12, None, 13, None],
+    }
+
+
+def test_window_invalid_null_strategy(make_df):
+    df = make_df({"value": [1, 2, 3]})
+    with pytest.raises(Exception, match="null strategy"):
+        df.select(row_number().over(Window().order_by("value", nulls="first")))
+
+
+def test_window_functions_over_different_windows(make_df):
+    df = make_df({"group": ["a", "a", "b"], "value": [1, 2, 3]})
+    df = df.select(
+        "group",
+        "value",
+        row_number().over(Window().partition_by("group").order_by("value", desc=True)).alias("in_group"),
+        row_number().over(Window().order_by("value")).alias("overall"),
+    )
+    assert sorted_pydict(df, "value") == {
+        "group": ["a", "a", "b"],
+        "value": [1, 2, 3],
+        "in_group": [2, 1, 1],
+        "overall": [1, 2, 3],
+    }
+
+
+def test_window_function_in_expression(make_df):
+    df = make_df({"value": [5, 6, 7]})
+    df = df.with_column("diff", col("value") - col("value").lag(1, default=0).over(Window().order_by("value")))
+    assert sorted_pydict(df, "value") == {"value": [5, 6, 7], "diff": [5, 1, 1]}
+
+
+def test_window_function_on_empty_dataframe(make_df):
+    df = make_df({"value": [1, 2]}).where(col("value") > 2)
+    df = df.with_column("row", row_number().over(Window().order_by("value")))
+    assert df.to_pydict() == {"value": [], "row": []}
+
+
+def test_window_function_not_allowed_in_filter(make_df):
+    df = make_df({"value": [1, 2, 3]})
+    with pytest.raises(Exception, match="only allowed in projections"):
+        df.where(row_number().over(Window().order_by("value")) == 1)
+
+
+def test_window_function_requires_window(make_df):
+    df = make_df({"value": [1, 2, 3]})
+    with pytest.raises(Exception, match="window function"):
+        df.select(col("value").over(Window().order_by("value")))
+
+
+def test_window_frame_not_supported(make_df):
+    df = make_df({"value": [1, 2, 3]})
+    with pytest.raises(Exception, match="frame"):
+        df.select(row_number().over(Window().order_by("value").rows_between(-1, 0))).collect()
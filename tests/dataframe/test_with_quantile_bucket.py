@@ -0,0 +1,49 @@
+from __future__ import annotations
+
+from collections import Counter
+
+import pytest
+
+import daft
+from daft import col
+
+
+@pytest.mark.parametrize("num_buckets", [1, 4, 10])
+def test_with_quantile_bucket(make_df, num_buckets: int) -> None:
+    df = make_df({"x": list(range(1000))})
+    data = df.with_quantile_bucket("bucket", col("x"), num_buckets).sort("x").to_pydict()
+    assert data["bucket"] == sorted(data["bucket"])
+    counts = Counter(data["bucket"])
+    assert sorted(counts) == list(range(1, num_buckets + 1))
+    # The quantiles are approximate, so the buckets are only roughly the same size.
+    assert all(abs(count - 1000 / num_buckets) <= 25 for count in counts.values())
+
+
+def test_with_quantile_bucket_nulls(make_df) -> None:
+    df = make_df({"x": [3.0, None, 1.0, 2.0, None]})
+    data = df.with_quantile_bucket("bucket", col("x"), 3).to_pydict()
+    assert [bucket is None for bucket in data["bucket"]] == [x is None for x in data["x"]]
+
+    df = make_df({"x": [None, None]}).select(col("x").cast(daft.DataType.float64()))
+    assert df.with_quantile_bucket("bucket", col("x"), 3).to_pydict()["bucket"] == [None, None]
+
+
+def test_with_quantile_bucket_invalid_num_buckets(make_df) -> None:
+    df = make_df({"x": [1, 2, 3]})
+    with pytest.raises(ValueError):
+        df.with_quantile_bucket("bucket", col("x"), 0)
+
+
+def test_width_bucket() -> None:
+    df = daft.from_pydict({"x": [-1.0, 0.0, 2.5, 7.5, 10.0, float("nan"), None]})
+    data = df.select(
+        col("x").width_bucket(0, 10, 4).alias("up"),
+        col("x").width_bucket(10, 0, 4).alias("down"),
+    ).to_pydict()
+    assert data["up"] == [0, 1, 2, 4, 5, None, None]
+    assert data["down"] == [5, 5, 4, 2, 1, None, None]
+
+    with pytest.raises(ValueError):
+        col("x").width_bucket(0, 10, 0)
+    with pytest.raises(Exception):
+        df.select(col("x").width_bucket(1, 1, 4)).collect()
@@ -0,0 +1,44 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import col
+
+
+def test_one_hot():
+    df = daft.from_pydict({"x": [3, 1, 2, None, 1]})
+    result = df.select(col("x").one_hot([1, 2, 3]))
+    assert result.schema()["x"].dtype == daft.DataType.fixed_size_list(daft.DataType.uint8(), 3)
+    assert result.to_pydict() == {"x": [[0, 0, 1], [1, 0, 0], [0, 1, 0], None, [1, 0, 0]]}
+
+
+def test_one_hot_casts_categories():
+    df = daft.from_pydict({"x": [1.0, 2.5, 3.0]})
+    assert df.select(col("x").one_hot([1, 3])).to_pydict() == {"x": [[1, 0], [0, 0], [0, 1]]}
+
+
+def test_one_hot_no_categories():
+    with pytest.raises(ValueError):
+        col("x").one_hot([])
+
+
+def test_label_encode():
+    df = daft.from_pydict({"x": ["b", "a", None, "c", "b"]})
+    assert df.select(col("x").label_encode(["a", "b", "b"])).to_pydict() == {"x": [1, 0, None, None, 1]}
+    assert df.select(col("x").label_encode({"a": 10, "b": 20, "c": None})).to_pydict() == {
+        "x": [20, 10, None, None, 20]
+    }
+
+
+def test_label_encode_to_strings():
+    df = daft.from_pydict({"x": [True, False]})
+    result = df.select(col("x").label_encode({True: "yes", False: "no"}))
+    assert result.schema()["x"].dtype == daft.DataType.string()
+    assert result.to_pydict() == {"x": ["yes", "no"]}
+
+
+def test_with_label_encoding(make_df):
+    df = make_df({"x": ["b", "a", None, "c", "b", "a"]}, repartition=2)
+    result = df.with_label_encoding("label", col("x")).sort("x").to_pydict()
+    assert result == {"x": ["a", "a", "b", "b", "c", None], "label": [0, 0, 1, 1, 2, None]}
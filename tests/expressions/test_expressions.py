@@ -82,6 +82,20 @@ def test_repr_binary_operators(op, symbol) -> None:
     assert output == repr(copied)
 
 
+def test_repr_binary_operators_minimal_parentheses() -> None:
+    a, b, c = col("a"), col("b"), col("c")
+    assert repr((a + b) * c) == "(col(a) + col(b)) * col(c)"
+    assert repr(a + b * c) == "col(a) + col(b) * col(c)"
+    assert repr(a - (b + c)) == "col(a) - (col(b) + col(c))"
+    assert repr((a > 1) & (b < c)) == "(col(a) > lit(1)) & (col(b) < col(c))"
+
+
+def test_repr_is_in_truncates_long_lists() -> None:
+    output = repr(col("a").is_in(list(range(10_000))))
+    assert output.startswith("col(a) IN (lit(0), lit(1), ")
+    assert output.endswith("lit(9), ... 9990 more)")
+
+
 def test_repr_functions_abs() -> None:
     a = col("a")
     y = abs(a)
@@ -0,0 +1,62 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import col
+
+
+def test_list_minhash_matches_joined_string():
+    df = daft.from_pydict(
+        {
+            "text": ["the quick brown fox", "jumps over the lazy dog", None],
+            "tokens": [["the", "quick", "brown", "fox"], ["jumps", "over", "the", "lazy", "dog"], None],
+        }
+    )
+    res = df.select(
+        col("text").minhash(num_hashes=16, ngram_size=2).alias("text"),
+        col("tokens").minhash(num_hashes=16, ngram_size=2).alias("tokens"),
+    ).to_pydict()
+    assert res["text"] == res["tokens"]
+    assert res["tokens"][2] is None
+
+
+def test_minhash_similarity():
+    df = daft.from_pydict(
+        {
+            "a": ["the quick brown fox jumps", "the quick brown fox jumps", "the quick brown fox", None],
+            "b": ["the quick brown fox jumps", "a completely different sentence here", None, "the quick brown fox"],
+        }
+    )
+    res = df.select(
+        col("a").minhash(num_hashes=64, ngram_size=1).minhash_similarity(col("b").minhash(64, 1)).alias("sim")
+    ).to_pydict()["sim"]
+    assert res[0] == 1.0
+    assert 0.0 <= res[1] < 0.5
+    assert res[2] is None and res[3] is None
+
+
+def test_minhash_similarity_mismatched_sizes():
+    df = daft.from_pydict({"a": ["the quick brown fox"]})
+    with pytest.raises(Exception, match="minhash signatures of the same size"):
+        df.select(col("a").minhash(16, 1).minhash_similarity(col("a").minhash(32, 1))).collect()
+
+
+@pytest.mark.parametrize("hash_function", ["murmurhash3", "xxhash", "sha1"])
+def test_simhash(hash_function):
+    df = daft.from_pydict(
+        {
+            "a": ["the quick brown fox jumps over the lazy dog", "the quick brown fox", None],
+            "b": ["the quick brown fox jumps over the lazy dog", "the quick brown fox", "fox"],
+        }
+    )
+    res = df.select(
+        col("a").simhash(ngram_size=2, hash_function=hash_function).alias("a"),
+        col("a")
+        .simhash(ngram_size=2, hash_function=hash_function)
+        .simhash_distance(col("b").simhash(ngram_size=2, hash_function=hash_function))
+        .alias("distance"),
+    ).to_pydict()
+    assert res["a"][0] != res["a"][1]
+    assert res["a"][2] is None
+    assert res["distance"] == [0, 0, None]
@@ -12,6 +12,7 @@ from daft.expressions.testing import expr_structurally_equal
 from daft.recordbatch import MicroPartition
 from daft.series import Series
 from daft.udf import udf
+from tests.conftest import get_tests_daft_runner_name
 
 
 def test_udf():
@@ -439,3 +440,21 @@ def test_udf_empty(batch_size, use_actor_pool):
 
     result = df.select(identity(col("a")))
     assert result.to_pydict() == {"a": []}
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() != "native", reason="Morsels are only used by the native runner")
+def test_udf_batches_limited_by_default_morsel_size_bytes():
+    batch_lengths = []
+
+    @udf(return_dtype=DataType.int64())
+    def lengths(data):
+        batch_lengths.append(len(data))
+        return [len(s) for s in data.to_pylist()]
+
+    df = daft.from_pydict({"a": ["x" * 1000] * 100})
+    with daft.execution_config_ctx(default_morsel_size_bytes=10_000):
+        result = df.select(lengths(col("a"))).to_pydict()
+
+    assert result == {"a": [1000] * 100}
+    assert sum(batch_lengths) == 100
+    assert 1 <= max(batch_lengths) <= 10
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import datetime
+import io
 import math
 
 import pandas as pd
@@ -51,6 +52,33 @@ def test_sql_partitioned_read(test_db, num_partitions, partition_bound_strategy,
         assert_df_equals(df.to_pandas(coerce_temporal_nanoseconds=True), pdf, sort_key="id")
 
 
+@pytest.mark.integration()
+@pytest.mark.parametrize("num_partitions", [1, 2])
+def test_sql_read_with_aggregation_pushdown(test_db, num_partitions, pdf) -> None:
+    df = daft.read_sql(
+        f"SELECT * FROM {TEST_TABLE_NAME}", test_db, partition_col="id", num_partitions=num_partitions
+    )
+    df = df.where(df["id"] > 100).agg(
+        df["id"].count("all").alias("count"),
+        df["null_col"].count("null").alias("nulls"),
+        df["id"].min().alias("min"),
+        df["float_col"].max().alias("max"),
+    )
+
+    # The aggregations are computed by the database rather than by reading the rows.
+    out = io.StringIO()
+    df.explain(show_all=True, file=out)
+    assert "Aggregation pushdown" in out.getvalue()
+
+    pdf = pdf[pdf["id"] > 100]
+    assert df.to_pydict() == {
+        "count": [len(pdf)],
+        "nulls": [int(pdf["null_col"].isna().sum())],
+        "min": [int(pdf["id"].min())],
+        "max": [float(pdf["float_col"].max())],
+    }
+
+
 @pytest.mark.integration()
 @pytest.mark.parametrize("num_partitions", [1, 2, 3, 4])
 @pytest.mark.parametrize("partition_col", ["id", "float_col", "date_col", "date_time_col"])
@@ -232,6 +260,27 @@ def test_sql_read_with_non_pushdowned_predicate(test_db, num_partitions, pdf) ->
     assert_df_equals(df.to_pandas(coerce_temporal_nanoseconds=True), pdf, sort_key="id")
 
 
+@pytest.mark.integration()
+@pytest.mark.parametrize("num_partitions", [1, 2])
+def test_sql_read_with_partially_pushdowned_predicate(test_db, num_partitions, pdf) -> None:
+    df = daft.read_sql(
+        f"SELECT * FROM {TEST_TABLE_NAME}",
+        test_db,
+        partition_col="id",
+        num_partitions=num_partitions,
+    )
+
+    # The comparison on `id` is pushed into the query, while the rest of the filter is applied by Daft, which needs
+    # `float_col` even though it isn't selected.
+    df = df.where((df["id"] > 100) & (df["float_col"] > 150).if_else(df["id"] % 2 == 0, df["id"] % 3 == 0))
+    df = df.select(df["id"])
+
+    even = (pdf["float_col"] > 150) & (pdf["id"] % 2 == 0)
+    multiple_of_three = (pdf["float_col"] <= 150) & (pdf["id"] % 3 == 0)
+    pdf = pdf[(pdf["id"] > 100) & (even | multiple_of_three)]
+    assert_df_equals(df.to_pandas(), pdf[["id"]], sort_key="id")
+
+
 @pytest.mark.integration()
 @pytest.mark.parametrize("num_partitions", [1, 2])
 def test_sql_read_with_is_in_filter_pushdown(test_db, num_partitions, pdf) -> None:
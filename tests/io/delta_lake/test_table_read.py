@@ -1,5 +1,10 @@
 from __future__ import annotations
 
+import json
+import os
+import struct
+import uuid
+
 import pyarrow as pa
 import pytest
 
@@ -114,3 +119,71 @@ def test_deltalake_read_versioned(tmp_path, base_table):
     expected_schema = Schema.from_pyarrow_schema(deltalake.DeltaTable(path, version=0).schema().to_pyarrow())
     assert df.schema() == expected_schema
     assert_pyarrow_tables_equal(df.to_arrow(), base_table)
+
+
+Z85_ALPHABET = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#"
+
+
+def _z85_encode(data: bytes) -> str:
+    encoded = []
+    for (value,) in struct.iter_unpack(">I", data):
+        digits = []
+        for _ in range(5):
+            value, digit = divmod(value, 85)
+            digits.append(Z85_ALPHABET[digit])
+        encoded.extend(reversed(digits))
+    return "".join(encoded)
+
+
+def _serialize_deletion_vector(rows: list[int]) -> bytes:
+    """Serializes rows below 2^16 as a 64-bit Roaring bitmap with a single array container."""
+    data = struct.pack("<IQI", 1681511377, 1, 0)
+    data += struct.pack("<IIHHI", 12346, 1, 0, len(rows) - 1, 0)
+    data += struct.pack(f"<{len(rows)}H", *sorted(rows))
+    return data
+
+
+def _delete_rows_with_deletion_vector(path, deleted_rows: list[int], storage_type: str) -> None:
+    """Commits a deletion vector for the only data file of a Delta Lake table, as Spark does for deletes."""
+    log_dir = path / "_delta_log"
+    with open(log_dir / "00000000000000000000.json") as f:
+        add = next(json.loads(line)["add"] for line in f if "add" in json.loads(line))
+    data = _serialize_deletion_vector(deleted_rows)
+    deletion_vector = {"storageType": storage_type, "sizeInBytes": len(data), "cardinality": len(deleted_rows)}
+    if storage_type == "i":
+        deletion_vector["pathOrInlineDv"] = _z85_encode(data + b"\x00" * (-len(data) % 4))
+    else:
+        file_uuid = uuid.uuid4()
+        with open(path / f"deletion_vector_{file_uuid}.bin", "wb") as f:
+            f.write(b"\x01" + struct.pack(">I", len(data)) + data + struct.pack(">I", 0))
+        deletion_vector["pathOrInlineDv"] = _z85_encode(file_uuid.bytes)
+        deletion_vector["offset"] = 1
+    remove = {"path": add["path"], "deletionTimestamp": add["modificationTime"], "dataChange": True}
+    with open(log_dir / "00000000000000000001.json", "w") as f:
+        f.write(json.dumps({"remove": remove}) + os.linesep)
+        f.write(json.dumps({"add": {**add, "deletionVector": deletion_vector}}) + os.linesep)
+
+
+@pytest.mark.parametrize("storage_type", ["i", "u"])
+def test_deltalake_read_deletion_vectors(tmp_path, storage_type):
+    deltalake = pytest.importorskip("deltalake")
+    path = tmp_path / "some_table"
+    deltalake.write_deltalake(path, pa.table({"a": list(range(10)), "b": [str(i) for i in range(10)]}))
+    _delete_rows_with_deletion_vector(path, [0, 3, 4, 9], storage_type)
+
+    df = daft.read_deltalake(str(path))
+    assert df.count_rows() == 6
+    assert df.to_pydict() == {"a": [1, 2, 5, 6, 7, 8], "b": ["1", "2", "5", "6", "7", "8"]}
+    assert df.where(df["a"] > 4).select("b").to_pydict() == {"b": ["5", "6", "7", "8"]}
+    assert len(daft.read_deltalake(str(path), version=0).collect()) == 10
+
+
+def test_deltalake_count_with_deletion_vectors_not_answered_from_metadata(tmp_path):
+    deltalake = pytest.importorskip("deltalake")
+    path = tmp_path / "some_table"
+    deltalake.write_deltalake(path, pa.table({"a": list(range(10))}))
+    _delete_rows_with_deletion_vector(path, [0, 3, 4, 9], "i")
+
+    # The number of records in the metadata of the file includes its deleted rows.
+    df = daft.read_deltalake(str(path))
+    assert df.agg(daft.col("a").count("all").alias("count")).to_pydict() == {"count": [6]}
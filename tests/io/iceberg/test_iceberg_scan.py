@@ -0,0 +1,69 @@
+from __future__ import annotations
+
+import datetime
+
+import pyarrow as pa
+import pytest
+
+import daft
+from daft import col
+
+pyiceberg = pytest.importorskip("pyiceberg")
+
+from pyiceberg.catalog.sql import SqlCatalog
+
+
+@pytest.fixture(scope="function")
+def local_catalog(tmpdir):
+    catalog = SqlCatalog(
+        "default",
+        **{
+            "uri": f"sqlite:///{tmpdir}/pyiceberg_catalog.db",
+            "warehouse": f"file://{tmpdir}",
+        },
+    )
+    catalog.create_namespace("default")
+    return catalog
+
+
+@pytest.fixture(scope="function")
+def table_with_three_files(local_catalog):
+    schema = pa.schema([("id", pa.int64()), ("name", pa.large_string()), ("day", pa.date32())])
+    table = local_catalog.create_table("default.test_stats", schema)
+    # Every append writes a file with ids that don't overlap with those of the others.
+    for i in range(3):
+        table.append(
+            pa.table(
+                {
+                    "id": [i * 10 + j for j in range(10)],
+                    "name": [f"{i}-{j}" for j in range(10)],
+                    "day": [datetime.date(2024, 1 + i, 1 + j) for j in range(10)],
+                },
+                schema=schema,
+            )
+        )
+    return table
+
+
+@pytest.mark.parametrize(
+    ["predicate", "num_files"],
+    [
+        (col("id") >= 25, 1),
+        (col("id") < 12, 2),
+        (col("name").str.startswith("1-"), 1),
+        (col("day") > datetime.date(2024, 1, 31), 2),
+        # The filter can't be evaluated on the bounds, so no files are skipped.
+        (col("name").str.endswith("-3"), 3),
+    ],
+)
+def test_iceberg_read_prunes_files_by_column_bounds(table_with_three_files, predicate, num_files):
+    with daft.execution_config_ctx(scan_tasks_min_size_bytes=1):
+        df = daft.read_iceberg(table_with_three_files).where(predicate)
+        assert df.num_partitions() == num_files
+        expected = daft.from_arrow(table_with_three_files.scan().to_arrow()).where(predicate)
+        assert df.sort("id").to_pydict() == expected.sort("id").to_pydict()
+
+
+def test_iceberg_read_prunes_all_files(table_with_three_files):
+    df = daft.read_iceberg(table_with_three_files).where(col("id") == 100)
+    assert df.to_pydict() == {"id": [], "name": [], "day": []}
@@ -45,3 +45,34 @@ def test_lancedb_read_limit(lance_dataset_path):
     df = df.limit(1)
     df = df.select("vector")
     assert df.to_pydict() == {"vector": data["vector"][:1]}
+
+
+@pytest.fixture(scope="function")
+def lance_dataset_with_fragments(tmp_path_factory):
+    tmp_dir = tmp_path_factory.mktemp("lance")
+    table = pa.Table.from_pydict({"id": list(range(10)), "value": [float(i) for i in range(10)]})
+    # Each fragment has 2 rows.
+    lance.write_dataset(table, tmp_dir, max_rows_per_file=2)
+    yield str(tmp_dir)
+
+
+def test_lancedb_read_fragments_as_partitions(lance_dataset_with_fragments):
+    with daft.execution_config_ctx(scan_tasks_min_size_bytes=0, scan_tasks_max_size_bytes=0):
+        df = daft.read_lance(lance_dataset_with_fragments)
+        assert df.num_partitions() == 5
+        assert sorted(df.select("id").to_pydict()["id"]) == list(range(10))
+
+
+def test_lancedb_read_limit_skips_fragments(lance_dataset_with_fragments):
+    with daft.execution_config_ctx(scan_tasks_min_size_bytes=0, scan_tasks_max_size_bytes=0):
+        df = daft.read_lance(lance_dataset_with_fragments).limit(3)
+        assert df.num_partitions() == 2
+        assert len(df.to_pydict()["id"]) == 3
+
+
+def test_lancedb_read_with_deletions(lance_dataset_with_fragments):
+    lance.dataset(lance_dataset_with_fragments).delete("id % 2 = 0")
+    df = daft.read_lance(lance_dataset_with_fragments)
+    assert sorted(df.to_pydict()["id"]) == [1, 3, 5, 7, 9]
+    assert df.count_rows() == 5
+    assert len(daft.read_lance(lance_dataset_with_fragments).limit(3).to_pydict()["id"]) == 3
@@ -194,3 +194,44 @@ def test_hive_daft_roundtrip(tmpdir, partition_by, file_format, filter):
         source = source.where(daft.col(first_col) == sample_value)
         target = target.where(daft.col(first_col) == sample_value)
     assert_tables_equal(target.to_arrow(), source.to_arrow())
+
+
+@pytest.mark.parametrize("file_format", ["csv", "parquet"])
+def test_hive_partition_column_aggregations(tmpdir, file_format):
+    # Global aggregations of partition columns may be answered from the partition values of the scan tasks.
+    ds.write_dataset(
+        SAMPLE_DATA,
+        tmpdir,
+        format=file_format,
+        partitioning=ds.partitioning(pa.schema([SAMPLE_DATA.schema.field("int_col")]), flavor="hive"),
+    )
+    read = daft.read_csv if file_format == "csv" else daft.read_parquet
+    df = read(os.path.join(tmpdir, "**"), hive_partitioning=True)
+    result = df.agg(
+        daft.col("int_col").min().alias("min"),
+        daft.col("int_col").max().alias("max"),
+        daft.col("id").count("all").alias("count"),
+    ).to_pydict()
+    assert result == {"min": [0], "max": [2], "count": [NUM_ROWS]}
+
+
+@pytest.mark.parametrize("file_format", ["csv", "parquet"])
+@pytest.mark.parametrize("filter", [True, False])
+def test_hive_partition_column_distinct(tmpdir, file_format, filter):
+    # Distinct values of partition columns may be found from the first row of a scan task per partition.
+    partition_by = ["str_col", "nullable_int"]
+    ds.write_dataset(
+        SAMPLE_DATA,
+        tmpdir,
+        format=file_format,
+        partitioning=ds.partitioning(pa.schema([SAMPLE_DATA.schema.field(col) for col in partition_by]), flavor="hive"),
+    )
+    read = daft.read_csv if file_format == "csv" else daft.read_parquet
+    df = read(os.path.join(tmpdir, "**"), hive_partitioning=True)
+    rows = SAMPLE_DATA.to_pylist()
+    if filter:
+        df = df.where(daft.col("value") > 90)
+        rows = [row for row in rows if row["value"] > 90]
+    result = df.select(*partition_by).distinct().to_pylist()
+    expected = {tuple(row[col] for col in partition_by) for row in rows}
+    assert sorted((tuple(row.values()) for row in result), key=str) == sorted(expected, key=str)
@@ -0,0 +1,88 @@
+from __future__ import annotations
+
+import datetime
+
+import pyarrow as pa
+import pyarrow.feather as feather
+import pytest
+
+import daft
+from daft import DataType, col
+
+
+@pytest.fixture
+def feather_path(tmp_path):
+    table = pa.table(
+        {
+            "id": pa.array([1, 2, 3, 4], type=pa.int32()),
+            "name": pa.array(["a", "b", None, "d"]),
+            "tags": pa.array([[1], [], None, [2, 3]], type=pa.list_(pa.int64())),
+        }
+    )
+    path = tmp_path / "file.feather"
+    # Write several record batches, which are read back as separate tables.
+    feather.write_feather(table, str(path), chunksize=2, compression="lz4")
+    return str(path)
+
+
+def test_read_ipc(feather_path):
+    df = daft.read_ipc(feather_path)
+    assert df.schema()["id"].dtype == DataType.int32()
+    assert df.schema()["name"].dtype == DataType.string()
+    assert df.schema()["tags"].dtype == DataType.list(DataType.int64())
+    assert df.to_pydict() == {
+        "id": [1, 2, 3, 4],
+        "name": ["a", "b", None, "d"],
+        "tags": [[1], [], None, [2, 3]],
+    }
+
+
+def test_read_ipc_with_pushdowns(feather_path):
+    df = daft.read_ipc(feather_path)
+    assert df.where(col("id") > 1).select("name").limit(2).to_pydict() == {"name": ["b", None]}
+    assert df.count_rows() == 4
+
+
+def test_read_ipc_with_schema(feather_path):
+    df = daft.read_ipc(feather_path, infer_schema=False, schema={"id": DataType.int64(), "name": DataType.string()})
+    assert df.to_pydict() == {"id": [1, 2, 3, 4], "name": ["a", "b", None, "d"]}
+
+
+def test_read_ipc_not_ipc_file(tmp_path):
+    path = tmp_path / "file.arrow"
+    path.write_text("not an arrow file")
+    with pytest.raises(Exception, match="not an Arrow IPC file"):
+        daft.read_ipc(str(path))
+
+
+@pytest.mark.parametrize("compression", [None, "lz4", "zstd"])
+def test_ipc_roundtrip(tmp_path, compression):
+    data = {
+        "id": [1, 2, 3],
+        "name": ["a", None, "c"],
+        "ts": [datetime.datetime(2024, 1, 1), None, datetime.datetime(2024, 1, 3)],
+    }
+    before = daft.from_pydict(data)
+    files = before.write_ipc(str(tmp_path), compression=compression).to_pydict()["path"]
+    assert all(f.endswith(".arrow") for f in files)
+    after = daft.read_ipc(str(tmp_path))
+    assert after.schema() == before.schema()
+    assert after.sort("id").to_pydict() == data
+
+
+def test_ipc_roundtrip_partitioned(tmp_path):
+    before = daft.from_pydict({"group": ["a", "b", "a"], "value": [1, 2, 3]})
+    before.write_ipc(str(tmp_path), partition_cols=["group"])
+    after = daft.read_ipc(f"{tmp_path}/**", hive_partitioning=True)
+    assert after.sort("value").select("group", "value").to_pydict() == {"group": ["a", "b", "a"], "value": [1, 2, 3]}
+
+
+def test_write_ipc_empty(tmp_path):
+    before = daft.from_pydict({"value": [1, 2]}).where(col("value") > 2)
+    before.write_ipc(str(tmp_path))
+    assert daft.read_ipc(str(tmp_path)).to_pydict() == {"value": []}
+
+
+def test_write_ipc_unsupported_compression(tmp_path):
+    with pytest.raises(Exception, match="lz4 and zstd"):
+        daft.from_pydict({"value": [1]}).write_ipc(str(tmp_path), compression="snappy")
@@ -21,6 +21,7 @@ from ..integration.io.conftest import minio_create_bucket
 
 PYARROW_GE_11_0_0 = tuple(int(s) for s in pa.__version__.split(".") if s.isnumeric()) >= (11, 0, 0)
 PYARROW_GE_13_0_0 = tuple(int(s) for s in pa.__version__.split(".") if s.isnumeric()) >= (13, 0, 0)
+PYARROW_GE_22_0_0 = tuple(int(s) for s in pa.__version__.split(".") if s.isnumeric()) >= (22, 0, 0)
 
 
 ###
@@ -432,3 +433,99 @@ def test_parquet_nested_optional_or_required_fields(tmpdir, optional_outer_struc
     expected = MicroPartition.from_arrow(expected)
     df = daft.read_parquet(output_file)
     assert df.to_arrow() == expected.to_arrow(), f"Expected:\n{expected.to_arrow()}\n\nReceived:\n{df.to_arrow()}"
+
+
+@pytest.mark.parametrize("chunk_size", [None, 3])
+def test_parquet_read_row_index_column(tmpdir, chunk_size):
+    for name, values in [("a", [1, 2, 3, 4, 5]), ("b", [6, 7, 8])]:
+        papq.write_table(pa.table({"x": values}), f"{tmpdir}/{name}.parquet", row_group_size=2)
+
+    df = daft.read_parquet(
+        f"{tmpdir}/*.parquet", file_path_column="path", row_index_column="row", _chunk_size=chunk_size
+    )
+    assert df.schema()["row"].dtype == DataType.uint64()
+
+    result = df.where(col("x") % 2 == 0).sort("x").select("x", "row", col("path").str.split("/").list.get(-1))
+    assert result.to_pydict() == {
+        "x": [2, 4, 6, 8],
+        "row": [1, 3, 0, 2],
+        "path": ["a.parquet", "a.parquet", "b.parquet", "b.parquet"],
+    }
+
+
+def test_parquet_read_row_index_column_filter_on_unselected_column(tmpdir):
+    papq.write_table(pa.table({"x": [1, 2, 3, 4], "y": ["a", "b", "c", "d"]}), f"{tmpdir}/file.parquet")
+
+    df = daft.read_parquet(f"{tmpdir}/file.parquet", row_index_column="row")
+    result = df.where(col("x") > 2).select("y", "row")
+    assert result.column_names == ["y", "row"]
+    assert result.to_pydict() == {"y": ["c", "d"], "row": [2, 3]}
+
+
+def test_parquet_read_row_index_column_conflicts_with_existing_column(tmpdir):
+    papq.write_table(pa.table({"row": [1, 2, 3]}), f"{tmpdir}/file.parquet")
+    with pytest.raises(Exception, match="conflicts with an existing column"):
+        daft.read_parquet(f"{tmpdir}/file.parquet", row_index_column="row")
+
+
+def test_parquet_metadata_cache_picks_up_overwritten_files(tmpdir):
+    from daft.context import invalidate_parquet_metadata_cache, set_parquet_metadata_cache_capacity
+
+    path = f"{tmpdir}/file.parquet"
+    papq.write_table(pa.table({"x": [1, 2, 3]}), path)
+    assert daft.read_parquet(path).to_pydict() == {"x": [1, 2, 3]}
+    assert daft.read_parquet(path).to_pydict() == {"x": [1, 2, 3]}
+
+    papq.write_table(pa.table({"x": [1, 2], "y": ["a", "b"]}), path)
+    assert daft.read_parquet(path).to_pydict() == {"x": [1, 2], "y": ["a", "b"]}
+
+    invalidate_parquet_metadata_cache(path)
+    set_parquet_metadata_cache_capacity(0)
+    try:
+        assert daft.read_parquet(path).to_pydict() == {"x": [1, 2], "y": ["a", "b"]}
+    finally:
+        set_parquet_metadata_cache_capacity(256 * 1024 * 1024)
+
+
+@pytest.mark.skipif(not PYARROW_GE_22_0_0, reason="Writing bloom filters requires pyarrow>=22.0.0")
+def test_parquet_bloom_filter_round_trip(tmpdir):
+    df = daft.from_pydict({"id": list(range(1000)), "name": [f"name_{i}" for i in range(1000)]})
+    df.write_parquet(str(tmpdir), bloom_filter_columns=["id", "name"])
+
+    read_df = daft.read_parquet(str(tmpdir))
+    assert read_df.where(col("id") == 42).to_pydict() == {"id": [42], "name": ["name_42"]}
+    assert read_df.where(col("name") == "name_7").to_pydict() == {"id": [7], "name": ["name_7"]}
+    assert read_df.where(col("id").is_in([5000, 6000])).to_pydict() == {"id": [], "name": []}
+
+
+def test_parquet_bloom_filter_columns_must_exist(tmpdir):
+    df = daft.from_pydict({"id": [1, 2, 3]})
+    with pytest.raises(Exception, match="missing"):
+        df.write_parquet(str(tmpdir), bloom_filter_columns=["missing"])
+
+
+def test_parquet_write_row_group_size(tmpdir):
+    df = daft.from_pydict({"id": list(range(100_000))})
+    default_paths = df.write_parquet(str(tmpdir.join("default"))).to_pydict()["path"]
+    small_paths = df.write_parquet(str(tmpdir.join("small")), row_group_size_bytes=8 * 1024).to_pydict()["path"]
+
+    def num_row_groups(paths):
+        return sum(papq.ParquetFile(path).num_row_groups for path in paths)
+
+    assert num_row_groups(small_paths) > num_row_groups(default_paths)
+    assert daft.read_parquet(str(tmpdir.join("small"))).sort("id").to_pydict() == df.to_pydict()
+
+
+def test_parquet_write_row_group_size_must_be_positive(tmpdir):
+    df = daft.from_pydict({"id": [1, 2, 3]})
+    with pytest.raises(Exception, match="must be positive"):
+        df.write_parquet(str(tmpdir), row_group_size_bytes=0)
+
+
+def test_parquet_read_scan_task_max_attempts(tmpdir):
+    df = daft.from_pydict({"id": [1, 2, 3]})
+    df.write_parquet(str(tmpdir))
+    retrying = daft.read_parquet(str(tmpdir), scan_task_max_attempts=3)
+    assert retrying.sort("id").to_pydict() == df.to_pydict()
+    with pytest.raises(ValueError, match="must be at least 1"):
+        daft.read_parquet(str(tmpdir), scan_task_max_attempts=0)
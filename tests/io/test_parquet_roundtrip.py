@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import datetime
 import decimal
+import json
 import random
 
 import numpy as np
@@ -51,8 +52,7 @@ PYARROW_GE_8_0_0 = tuple(int(s) for s in pa.__version__.split(".") if s.isnumeri
             DataType.duration(TimeUnit.ms()),
         ),
         ([[1, 2, 3], [], None], pa.large_list(pa.int64()), DataType.list(DataType.int64())),
-        # TODO: Crashes when parsing fixed size lists
-        # ([[1, 2, 3], [4, 5, 6], None], pa.list_(pa.int64(), list_size=3), DataType.fixed_size_list(DataType.int64(), 3)),
+        ([[1, 2, 3], [4, 5, 6], None], pa.list_(pa.int64(), list_size=3), DataType.fixed_size_list(DataType.int64(), 3)),
         ([{"bar": 1}, {"bar": None}, None], pa.struct({"bar": pa.int64()}), DataType.struct({"bar": DataType.int64()})),
         (
             [[("a", 1), ("b", 2)], [], None],
@@ -134,6 +134,32 @@ def test_roundtrip_tensor_types(tmp_path):
     assert df_original.to_arrow() == df_roundtrip.to_arrow()
 
 
+@pytest.mark.parametrize(
+    ["data", "dtype"],
+    [
+        ([np.array([1.0, 2.0, 3.0]), None, np.array([4.0, 5.0, 6.0])], DataType.embedding(DataType.float32(), 3)),
+        ([np.array([[1, 2], [3, 4]]), None, np.array([[5, 6], [7, 8]])], DataType.tensor(DataType.int64(), (2, 2))),
+        (
+            [np.zeros((2, 2, 3), dtype=np.uint8), None, np.ones((2, 2, 3), dtype=np.uint8)],
+            DataType.image("RGB", 2, 2),
+        ),
+        (
+            [np.zeros((2, 3, 3), dtype=np.uint8), None, np.ones((4, 1, 3), dtype=np.uint8)],
+            DataType.image("RGB"),
+        ),
+    ],
+)
+def test_roundtrip_extension_types(tmp_path, data, dtype):
+    before = daft.from_pydict({"foo": Series.from_pylist(data, pyobj="force")})
+    before = before.with_column("foo", before["foo"].cast(dtype))
+    before = before.concat(before)
+    before.write_parquet(str(tmp_path))
+    after = daft.read_parquet(str(tmp_path))
+    assert before.schema()["foo"].dtype == dtype
+    assert after.schema()["foo"].dtype == dtype
+    assert before.to_arrow() == after.to_arrow()
+
+
 @pytest.mark.parametrize("fixed_shape", [True, False])
 def test_roundtrip_sparse_tensor_types(tmp_path, fixed_shape):
     if fixed_shape:
@@ -168,6 +194,81 @@ def test_roundtrip_boolean_rle(tmp_path, has_none):
     assert pa_original == df_roundtrip.to_arrow()
 
 
+@pytest.mark.skipif(
+    not hasattr(papq.ParquetWriter, "add_key_value_metadata"),
+    reason="PyArrow can't add footer metadata after writing a Parquet file",
+)
+def test_roundtrip_num_distinct(tmp_path):
+    before = daft.from_pydict({"key": [i % 10 for i in range(1000)], "id": list(range(1000))})
+    [path] = before.write_parquet(str(tmp_path)).to_pydict()["path"]
+
+    num_distinct = json.loads(papq.read_metadata(path).metadata[b"daft.num_distinct"])
+    assert num_distinct["key"] == 10
+    assert abs(num_distinct["id"] - 1000) < 50
+
+    # The planner estimates the number of groups from the recorded distinct counts.
+    after = daft.read_parquet(str(tmp_path))
+    by_key = after.groupby("key").agg(daft.col("id").max())
+    by_id = after.groupby("id").agg(daft.col("key").max())
+    assert by_key._builder.estimated_size_bytes() * 10 < by_id._builder.estimated_size_bytes()
+
+
+def test_analyze_parquet(tmp_path):
+    daft.from_pydict(
+        {"key": [i % 10 for i in range(1000)], "value": [float(i) if i % 4 else None for i in range(1000)]}
+    ).write_parquet(str(tmp_path))
+
+    stats = daft.analyze_parquet(str(tmp_path)).to_pydict()
+    assert stats["column"] == ["key", "value"]
+    assert stats["min"] == [0.0, 1.0]
+    assert stats["max"] == [9.0, 999.0]
+    assert stats["null_count"] == [0, 250]
+    assert stats["num_distinct"][0] == 10
+
+    persisted = json.loads((tmp_path / "_daft_statistics.json").read_text())
+    assert persisted["num_rows"] == 1000
+    assert persisted["columns"]["value"]["null_count"] == 250
+
+    # The statistics file isn't read as data, but the planner uses it.
+    df = daft.read_parquet(str(tmp_path))
+    assert df.count_rows() == 1000
+    value_not_null = df.where(daft.col("value").not_null())._builder.estimated_size_bytes()
+    key_not_null = df.where(daft.col("key").not_null())._builder.estimated_size_bytes()
+    assert value_not_null < key_not_null
+
+    # Writing to the dataset again invalidates its statistics.
+    daft.from_pydict({"key": [0], "value": [0.0]}).write_parquet(str(tmp_path))
+    assert not (tmp_path / "_daft_statistics.json").exists()
+
+
+def test_analyze_parquet_histogram(tmp_path):
+    # Most of the values are 0, so a range predicate over the rest is much more selective than the bounds suggest.
+    daft.from_pydict({"x": [0] * 900 + list(range(1, 101))}).write_parquet(str(tmp_path))
+    daft.analyze_parquet(str(tmp_path))
+
+    histogram = json.loads((tmp_path / "_daft_statistics.json").read_text())["columns"]["x"]["histogram"]
+    assert histogram[0] == 0.0 and histogram[-1] == 100.0
+    assert histogram == sorted(histogram)
+
+    df = daft.read_parquet(str(tmp_path))
+    unfiltered = df._builder.estimated_size_bytes()
+    assert df.where(daft.col("x") > 0)._builder.estimated_size_bytes() < unfiltered / 2
+
+
+def test_roundtrip_field_metadata(tmp_path):
+    schema = pa.schema([pa.field("distance", pa.float64(), metadata={"unit": "km"}), pa.field("id", pa.int64())])
+    table = pa.table({"distance": [1.5, 2.5, 3.5], "id": [1, 2, 3]}, schema=schema)
+    df = daft.from_arrow(table).where(daft.col("id") > 1).select("distance", "id")
+    df.write_parquet(str(tmp_path))
+
+    read_back = daft.read_parquet(str(tmp_path))
+    assert read_back.schema()["distance"].metadata == {"unit": "km"}
+    assert read_back.schema()["id"].metadata == {}
+    arrow_schema = read_back.to_arrow().schema
+    assert arrow_schema.field("distance").metadata == {b"unit": b"km"}
+    assert not arrow_schema.field("id").metadata
+
+
 # TODO: reading/writing:
 # 1. Embedding type
 # 2. Image type
@@ -27,6 +27,34 @@ def test_split_parquet_read(parquet_files):
         assert df.to_pydict() == {"data": ["aaa"] * 100}
 
 
+def test_split_parquet_read_prunes_row_groups(tmpdir):
+    # 10 row groups of 10 consecutive values each, of which only the last 3 can pass the filter.
+    tbl = pa.table({"x": list(range(100))})
+    papq.write_table(tbl, str(tmpdir / "file.pq"), row_group_size=10)
+
+    with daft.execution_config_ctx(
+        scan_tasks_min_size_bytes=1,
+        scan_tasks_max_size_bytes=10,
+    ):
+        df = daft.read_parquet(str(tmpdir)).where(daft.col("x") >= 75)
+        assert df.num_partitions() == 3, "Should only have scan tasks for the row groups that can pass the filter"
+        assert df.to_pydict() == {"x": list(range(75, 100))}
+
+
+def test_split_parquet_read_prunes_row_groups_by_prefix(tmpdir):
+    # 10 row groups of 10 consecutive strings each, of which only one can start with "07".
+    tbl = pa.table({"s": [f"{i:03d}" for i in range(100)]})
+    papq.write_table(tbl, str(tmpdir / "file.pq"), row_group_size=10)
+
+    with daft.execution_config_ctx(
+        scan_tasks_min_size_bytes=1,
+        scan_tasks_max_size_bytes=10,
+    ):
+        df = daft.read_parquet(str(tmpdir)).where(daft.col("s").str.startswith("07"))
+        assert df.num_partitions() == 1, "Should only have a scan task for the row group that can pass the filter"
+        assert df.to_pydict() == {"s": [f"{i:03d}" for i in range(70, 80)]}
+
+
 @pytest.mark.skip(reason="Not implemented yet")
 def test_split_parquet_read_some_splits(tmpdir):
     with daft.execution_config_ctx(scantask_splitting_level=2):
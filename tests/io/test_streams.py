@@ -0,0 +1,94 @@
+from __future__ import annotations
+
+import json
+import os
+import subprocess
+import sys
+import threading
+
+import pytest
+
+import daft
+
+pytestmark = pytest.mark.skipif(sys.platform == "win32", reason="Named pipes and /dev/stdin are Unix-only")
+
+
+@pytest.fixture
+def fifo_path(tmp_path):
+    path = tmp_path / "pipe"
+    os.mkfifo(path)
+    return path
+
+
+def _write_in_background(path, data: bytes) -> threading.Thread:
+    def write():
+        with open(path, "wb") as f:
+            f.write(data)
+
+    thread = threading.Thread(target=write)
+    thread.start()
+    return thread
+
+
+def _read_in_background(path, result: list) -> threading.Thread:
+    def read():
+        with open(path, "rb") as f:
+            result.append(f.read())
+
+    thread = threading.Thread(target=read)
+    thread.start()
+    return thread
+
+
+def test_read_csv_from_named_pipe(fifo_path):
+    thread = _write_in_background(fifo_path, b"a,b\n1,x\n2,y\n3,z\n")
+    # The start of the pipe that's read to infer the schema is replayed when the whole pipe is read.
+    df = daft.read_csv(str(fifo_path))
+    assert df.to_pydict() == {"a": [1, 2, 3], "b": ["x", "y", "z"]}
+    thread.join()
+
+
+def test_read_named_pipe_again_after_scan(fifo_path):
+    thread = _write_in_background(fifo_path, b"a\n1\n")
+    assert daft.read_csv(str(fifo_path)).to_pydict() == {"a": [1]}
+    thread.join()
+    # The scan releases the pipe, so the next read waits for the next writer instead of replaying the first one.
+    thread = _write_in_background(fifo_path, b"a\n2\n")
+    assert daft.read_csv(str(fifo_path)).to_pydict() == {"a": [2]}
+    thread.join()
+
+
+def test_read_json_from_named_pipe(fifo_path):
+    rows = [{"a": i, "b": str(i)} for i in range(1000)]
+    thread = _write_in_background(fifo_path, "".join(json.dumps(row) + "\n" for row in rows).encode())
+    df = daft.read_json(str(fifo_path))
+    assert df.to_pylist() == rows
+    thread.join()
+
+
+def test_write_csv_to_named_pipe(fifo_path):
+    result = []
+    thread = _read_in_background(fifo_path, result)
+    df = daft.from_pydict({"a": [1, 2, 3], "b": ["x", "y", "z"]}).into_partitions(2)
+    assert df.write_csv(str(fifo_path)).to_pydict() == {"path": [str(fifo_path)]}
+    thread.join()
+    assert result == [b'"a","b"\n1,"x"\n2,"y"\n3,"z"\n']
+
+
+def test_write_json_to_named_pipe(fifo_path):
+    result = []
+    thread = _read_in_background(fifo_path, result)
+    daft.from_pydict({"a": [1, None], "b": ["x", "y"]}).write_json(str(fifo_path))
+    thread.join()
+    assert result == [b'{"a":1,"b":"x"}\n{"a":null,"b":"y"}\n']
+
+
+def test_pipeline_through_stdin_and_stdout():
+    script = "import daft; daft.read_csv('-').where(daft.col('a') > 1).write_json('-')"
+    result = subprocess.run(
+        [sys.executable, "-c", script],
+        input=b"a,b\n1,x\n2,y\n3,z\n",
+        capture_output=True,
+        check=True,
+    )
+    assert result.stdout == b'{"a":2,"b":"y"}\n{"a":3,"b":"z"}\n'
@@ -0,0 +1,71 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.datatype import DataType
+from daft.expressions import col
+from daft.recordbatch import MicroPartition
+
+
+def test_list_rle_encode():
+    table = MicroPartition.from_pydict(
+        {
+            "a": [
+                ["a", "a", "b", "a"],
+                [None, None, "c"],
+                None,
+                [],
+                ["d"],
+            ],
+        }
+    )
+
+    res = table.eval_expression_list([col("a").list.rle_encode()])
+    assert res.schema()["a"].dtype == DataType.list(
+        DataType.struct({"value": DataType.string(), "run_length": DataType.uint64()})
+    )
+    assert res.to_pydict()["a"] == [
+        [{"value": "a", "run_length": 2}, {"value": "b", "run_length": 1}, {"value": "a", "run_length": 1}],
+        [{"value": None, "run_length": 2}, {"value": "c", "run_length": 1}],
+        None,
+        [],
+        [{"value": "d", "run_length": 1}],
+    ]
+
+
+def test_fixed_size_list_rle_encode():
+    table = MicroPartition.from_pydict({"a": [[1, 1], [2, 3]]}).eval_expression_list(
+        [col("a").cast(DataType.fixed_size_list(DataType.int64(), 2))]
+    )
+
+    res = table.eval_expression_list([col("a").list.rle_encode()])
+    assert res.to_pydict()["a"] == [
+        [{"value": 1, "run_length": 2}],
+        [{"value": 2, "run_length": 1}, {"value": 3, "run_length": 1}],
+    ]
+
+
+def test_list_delta_encode():
+    table = MicroPartition.from_pydict(
+        {
+            "a": [[10, 12, 11, 11], [5], [], None, [1, None, 3]],
+        }
+    ).eval_expression_list([col("a").cast(DataType.list(DataType.uint8()))])
+
+    res = table.eval_expression_list([col("a").list.delta_encode()])
+    assert res.schema()["a"].dtype == DataType.list(DataType.int64())
+    assert res.to_pydict()["a"] == [[10, 2, -1, 0], [5], [], None, [1, None, None]]
+
+
+def test_list_delta_encode_floats():
+    table = MicroPartition.from_pydict({"a": [[1.5, 1.0, 3.0]]})
+
+    res = table.eval_expression_list([col("a").list.delta_encode()])
+    assert res.to_pydict()["a"] == [[1.5, -0.5, 2.0]]
+
+
+def test_list_delta_encode_non_numeric():
+    table = MicroPartition.from_pydict({"a": [["a", "b"]]})
+
+    with pytest.raises(Exception, match="Expected list of numbers"):
+        table.eval_expression_list([col("a").list.delta_encode()])
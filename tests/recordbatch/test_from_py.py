@@ -11,7 +11,7 @@ import pytest
 
 import daft
 from daft import DataType, TimeUnit
-from daft.recordbatch import MicroPartition
+from daft.recordbatch import MicroPartition, RecordBatch
 from daft.series import Series
 from daft.utils import pyarrow_supports_fixed_shape_tensor
 from tests.conftest import get_tests_daft_runner_name
@@ -239,6 +239,21 @@ def test_from_arrow_roundtrip(uuid_ext_type) -> None:
     assert table.to_arrow() == expected_table
 
 
+@pytest.mark.skipif(
+    tuple(int(s) for s in pa.__version__.split(".")[:2]) < (14, 0),
+    reason="pyarrow supports the Arrow PyCapsule Interface from 14.0",
+)
+def test_arrow_c_stream_roundtrip() -> None:
+    pa_table = pa.table({"a": [1, None, 3], "b": ["x", "y", None], "c": [[1], [], None]})
+    table = RecordBatch.from_arrow_c_stream(pa_table)
+    assert table.to_pydict() == pa_table.to_pydict()
+    # pyarrow imports the table's stream without going through Daft's pyarrow conversion.
+    assert pa.table(table) == table.to_arrow()
+    empty = RecordBatch.from_arrow_c_stream(pa_table.slice(0, 0))
+    assert empty.schema() == table.schema()
+    assert pa.table(empty).to_pydict() == {"a": [], "b": [], "c": []}
+
+
 def test_from_pandas_roundtrip() -> None:
     df = pd.DataFrame(PYTHON_TYPE_ARRAYS)
     table = MicroPartition.from_pandas(df)
@@ -670,3 +685,18 @@ def test_to_arrow_iterator() -> None:
     df = daft.from_pydict({"a": [1, 2, 3], "b": [4, 5, 6]})
     it = df.to_arrow_iter()
     assert isinstance(next(it), pa.RecordBatch)
+
+
+def test_from_arrow_keeps_field_metadata() -> None:
+    schema = pa.schema([pa.field("temp", pa.float64(), metadata={"unit": "celsius"}), pa.field("idx", pa.int64())])
+    table = pa.table({"temp": [20.5, 21.0], "idx": [0, 1]}, schema=schema)
+
+    df = daft.from_arrow(table)
+    assert df.schema()["temp"].metadata == {"unit": "celsius"}
+
+    # Kernels that don't change the type of a column keep its metadata.
+    df = df.where(daft.col("idx") > 0).select(daft.col("temp").alias("t"), "idx").sort("t")
+    assert df.schema()["t"].metadata == {"unit": "celsius"}
+    result = df.to_arrow()
+    assert result.schema.field("t").metadata == {b"unit": b"celsius"}
+    assert result.to_pydict() == {"t": [21.0], "idx": [1]}
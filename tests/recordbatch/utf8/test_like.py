@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import pytest
+
 from daft.expressions import col
 from daft.recordbatch import MicroPartition
 
@@ -8,3 +10,23 @@ def test_utf8_like():
     table = MicroPartition.from_pydict({"col": ["foo", None, "barBaz", "quux", "1"]})
     result = table.eval_expression_list([col("col").str.like("foo%")])
     assert result.to_pydict() == {"col": [True, None, False, False, False]}
+
+
+@pytest.mark.parametrize(
+    ["pattern", "expected"],
+    [
+        ("foo", [True, False, False, False, None]),
+        ("foo%", [True, True, False, False, None]),
+        ("%bar", [False, False, True, False, None]),
+        ("%o.b%", [False, True, False, False, None]),
+        ("f_o%", [True, True, False, False, None]),
+        ("%", [True, True, True, True, None]),
+        # Regex metacharacters are matched literally.
+        ("foo.%", [False, True, False, False, None]),
+        ("(x|y)%", [False, False, False, True, None]),
+    ],
+)
+def test_utf8_like_patterns(pattern, expected):
+    table = MicroPartition.from_pydict({"col": ["foo", "foo.bar", "xbar", "(x|y)", None]})
+    result = table.eval_expression_list([col("col").str.like(pattern)])
+    assert result.to_pydict() == {"col": expected}
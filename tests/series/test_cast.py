@@ -871,6 +871,26 @@ def test_series_cast_timestamp(input_t, input, output_t, output) -> None:
     assert res == output
 
 
+def test_series_cast_timestamp_to_finer_unit_overflow() -> None:
+    # Year 3000 is out of the range of nanosecond timestamps.
+    series = Series.from_pylist([datetime(3000, 1, 1)]).cast(DataType.timestamp(TimeUnit.s()))
+    with pytest.raises(DaftCoreException, match="Overflow"):
+        series.cast(DataType.timestamp(TimeUnit.ns()))
+
+
+def test_series_cast_timestamp_to_coarser_unit_before_epoch() -> None:
+    # Values before the epoch are rounded down to the second that contains them.
+    series = Series.from_pylist([-1, -1_000_000, 1]).cast(DataType.timestamp(TimeUnit.us()))
+    casted = series.cast(DataType.timestamp(TimeUnit.s()))
+    assert casted.cast(DataType.int64()).to_pylist() == [-1, -1, 0]
+
+
+def test_series_cast_duration_units() -> None:
+    series = Series.from_pylist([timedelta(seconds=1, milliseconds=500), None])
+    assert series.cast(DataType.duration(TimeUnit.ms())).cast(DataType.int64()).to_pylist() == [1500, None]
+    assert series.cast(DataType.duration(TimeUnit.s())).to_pylist() == [timedelta(seconds=1), None]
+
+
 @pytest.mark.parametrize(
     ["input_t", "input", "output_t", "output"],
     [
@@ -821,6 +821,15 @@ def test_compare_timestamps_no_tz(tu1, tu2):
     assert (tz1.cast(DataType.timestamp(tu1)) == tz1.cast(DataType.timestamp(tu2))).to_pylist() == [True]
 
 
+def test_compare_timestamps_no_tz_keeps_finer_precision():
+    # Comparing at the coarser unit would truncate the microseconds and treat these timestamps as equal.
+    us = Series.from_pylist([datetime(2022, 1, 1, 0, 0, 0, 1)]).cast(DataType.timestamp("us"))
+    ms = Series.from_pylist([datetime(2022, 1, 1)]).cast(DataType.timestamp("ms"))
+    assert (us == ms).to_pylist() == [False]
+    assert (ms == us).to_pylist() == [False]
+    assert (ms < us).to_pylist() == [True]
+
+
 def test_compare_timestamps_no_tz_date():
     tz1 = Series.from_pylist([datetime(2022, 1, 1)])
     Series.from_pylist([date(2022, 1, 1)])
@@ -1,9 +1,11 @@
 from __future__ import annotations
 
 import decimal
+import struct
 from datetime import date, datetime, time
 
 import numpy as np
+import pyarrow as pa
 import pytest
 import pytz
 import xxhash
@@ -58,6 +60,21 @@ def test_hash_bool_array_with_reference():
     assert hashed_again.to_pylist() == expected
 
 
+@pytest.mark.parametrize("dtype, fmt", [(np.float32, "<f"), (np.float64, "<d")])
+def test_hash_float_array_canonicalizes_nans_and_zeros(dtype, fmt):
+    arr = Series.from_numpy(np.array([0.0, -0.0, np.nan, -np.nan, 1.5], dtype=dtype))
+    zero_hash = xxhash.xxh3_64_intdigest(struct.pack(fmt, 0.0))
+    nan_hash = xxhash.xxh3_64_intdigest(struct.pack(fmt, float("nan")))
+    # Every NaN hashes like the canonical NaN and -0.0 like 0.0, since they're equal in group-bys and distincts.
+    assert arr.hash().to_pylist() == [
+        zero_hash,
+        zero_hash,
+        nan_hash,
+        nan_hash,
+        xxhash.xxh3_64_intdigest(struct.pack(fmt, 1.5)),
+    ]
+
+
 def test_hash_str_array_with_reference():
     arr = Series.from_pylist(["hi", "bye", None])
     expected = [xxhash.xxh3_64_intdigest(b"hi"), xxhash.xxh3_64_intdigest(b"bye"), xxhash.xxh3_64_intdigest(b"")]
@@ -465,6 +482,88 @@ def test_hash_struct_with_nones(dtype, seed):
             assert hashed[different_inds[i]] != hashed[different_inds[j]]
 
 
+def xxh3(data: bytes, seed: int = 0) -> int:
+    return xxhash.xxh3_64_intdigest(data, seed=seed)
+
+
+def hashes_to_bytes(*hashes: int) -> bytes:
+    return b"".join(h.to_bytes(8, "little") for h in hashes)
+
+
+# The following tests pin the hashes of nested and temporal types to reference implementations, so that hashes stay the
+# same across versions and platforms, e.g. for data that was partitioned by them.
+
+
+def test_hash_list_array_stable():
+    arr = Series.from_pylist([[1, 2], [], None]).cast(DataType.list(DataType.int64()))
+    elements = [xxh3((1).to_bytes(8, "little")), xxh3((2).to_bytes(8, "little"))]
+    assert arr.hash().to_pylist() == [xxh3(hashes_to_bytes(*elements)), xxh3(b""), None]
+
+    # The elements of every list are hashed with the seed of its row.
+    seeds = Series.from_pylist([7, 8, 9]).cast(DataType.uint64())
+    elements = [xxh3((1).to_bytes(8, "little"), 7), xxh3((2).to_bytes(8, "little"), 7)]
+    assert arr.hash(seeds).to_pylist() == [xxh3(hashes_to_bytes(*elements), 7), xxh3(b"", 8), None]
+
+
+def test_hash_fixed_size_list_array_stable():
+    arr = Series.from_pylist([[1, 2], None]).cast(DataType.fixed_size_list(DataType.int32(), 2))
+    elements = [xxh3((1).to_bytes(4, "little")), xxh3((2).to_bytes(4, "little"))]
+    assert arr.hash().to_pylist() == [xxh3(hashes_to_bytes(*elements)), None]
+
+
+def test_hash_struct_array_stable():
+    arr = Series.from_pylist([{"a": 1, "b": "x"}, None]).cast(
+        DataType.struct({"a": DataType.int64(), "b": DataType.string()})
+    )
+    # Every field is hashed with the hash of the fields before it as the seed.
+    assert arr.hash().to_pylist() == [xxh3(b"x", xxh3((1).to_bytes(8, "little"))), None]
+
+
+def test_hash_map_array_stable():
+    arr = Series.from_arrow(pa.array([[("a", 1), ("b", 2)], []], type=pa.map_(pa.string(), pa.int64())))
+    # Maps are hashed as lists of key-value structs.
+    entries = [
+        xxh3((1).to_bytes(8, "little"), xxh3(b"a")),
+        xxh3((2).to_bytes(8, "little"), xxh3(b"b")),
+    ]
+    assert arr.hash().to_pylist() == [xxh3(hashes_to_bytes(*entries)), xxh3(b"")]
+
+
+def test_hash_decimal_array_stable():
+    arr = Series.from_pylist([decimal.Decimal("14.20"), decimal.Decimal("-0.05"), None])
+    # Decimals are hashed as the little-endian bytes of their unscaled 128-bit values.
+    expected = [xxh3((1420).to_bytes(16, "little", signed=True)), xxh3((-5).to_bytes(16, "little", signed=True))]
+    assert arr.hash().to_pylist()[:2] == expected
+
+
+def test_hash_temporal_arrays_stable():
+    arr = Series.from_pylist([date(1970, 1, 11)])
+    assert arr.hash().to_pylist() == [xxh3((10).to_bytes(4, "little"))]
+
+    arr = Series.from_pylist([time(0, 0, 1)]).cast(DataType.time("us"))
+    assert arr.hash().to_pylist() == [xxh3((1_000_000).to_bytes(8, "little"))]
+
+    # Timestamps are hashed as their offsets from the epoch in UTC, so that the same instants in different time zones
+    # have the same hashes.
+    instant = datetime(2017, 11, 16, 22, 31, 8, tzinfo=pytz.utc)
+    micros = int(instant.timestamp()) * 1_000_000
+    utc = Series.from_pylist([instant]).cast(DataType.timestamp("us", "UTC"))
+    pacific = Series.from_pylist([instant.astimezone(pytz.timezone("US/Pacific"))]).cast(
+        DataType.timestamp("us", "US/Pacific")
+    )
+    naive = Series.from_pylist([instant.replace(tzinfo=None)]).cast(DataType.timestamp("us"))
+    expected = [xxh3(micros.to_bytes(8, "little", signed=True))]
+    assert utc.hash().to_pylist() == expected
+    assert pacific.hash().to_pylist() == expected
+    assert naive.hash().to_pylist() == expected
+
+
+def test_hash_unhashable_type():
+    arr = Series.from_pylist([object()], pyobj="force")
+    with pytest.raises(ValueError, match="Cannot hash"):
+        arr.hash()
+
+
 @pytest.mark.parametrize(
     "dtype",
     [
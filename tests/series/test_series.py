@@ -31,6 +31,24 @@ def test_series_arrow_chunked_array_round_trip() -> None:
     assert arrow.combine_chunks() == back_to_arrow
 
 
+@pytest.mark.parametrize(
+    "chunks, arrow_type, large_arrow_type",
+    [
+        ([["a", None], ["bc"]], pa.string(), pa.large_string()),
+        ([[b"a", None], [b"bc"]], pa.binary(), pa.large_binary()),
+        ([[["a"], None], [[None, "bc"], []]], pa.list_(pa.string()), pa.large_list(pa.large_string())),
+    ],
+)
+def test_series_arrow_chunked_array_promoted_to_large_offsets(chunks, arrow_type, large_arrow_type) -> None:
+    # Chunks are promoted to 64-bit offsets before they're combined, so that they can hold more than 2GB of data.
+    arrow = pa.chunked_array(chunks, type=arrow_type)
+    s = Series.from_arrow(arrow)
+    assert s.datatype() == DataType.from_arrow_type(arrow_type)
+    back_to_arrow = s.to_arrow()
+    assert back_to_arrow.type == large_arrow_type
+    assert back_to_arrow.to_pylist() == arrow.to_pylist()
+
+
 @pytest.mark.parametrize("pyobj", ["allow", "disallow", "force"])
 def test_series_pylist_round_trip_objects(pyobj) -> None:
     data = [1, 2, 3, 4, None]
@@ -155,16 +155,20 @@ def test_series_list_size_bytes(dtype, size, with_nulls) -> None:
     s = Series.from_arrow(data)
 
     # Offset array is increased in bit width from 32 to 64 when converting to large_list
-    conversion_to_large_list_bytes = len(data) * 4
+    conversion_to_large_list_bytes = (len(data) + 1) * 4
 
-    size_bytes = s.size_bytes()
+    assert s.datatype() == DataType.from_arrow_type(list_dtype)
+    assert s.size_bytes() == get_total_buffer_size(data) + conversion_to_large_list_bytes
 
-    # TODO(jay): There is an off-by-1 error in the arrow2 estimated_bytes_size API for List and LargeList:
-    # https://github.com/jorgecarleitao/arrow2/blob/64d8ec203f991468032025a13a4f971f1f2cfc14/src/compute/aggregate/memory.rs#LL76C1-L76C1
-    size_bytes = size_bytes + 4
 
-    assert s.datatype() == DataType.from_arrow_type(list_dtype)
-    assert size_bytes == get_total_buffer_size(data) + conversion_to_large_list_bytes
+def test_series_sliced_list_size_bytes() -> None:
+    pydata = [list(range(i, i + 10)) for i in range(100)]
+    s = Series.from_arrow(pa.array(pydata, pa.large_list(pa.int64())))
+
+    # A slice only counts the list elements it contains, not the whole child array it shares.
+    sliced = s.slice(0, 10)
+    assert sliced.size_bytes() == Series.from_arrow(pa.array(pydata[:10], pa.large_list(pa.int64()))).size_bytes()
+    assert sliced.size_bytes() < s.size_bytes()
 
 
 @pytest.mark.parametrize("dtype, size", itertools.product(ARROW_INT_TYPES + ARROW_FLOAT_TYPES, [0, 1, 2, 8, 9, 16]))
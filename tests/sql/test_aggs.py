@@ -112,3 +112,45 @@ def test_having_non_grouped():
     ).to_pydict()
 
     assert actual == {"count": [10]}
+
+
+def test_approx_aggs_sql():
+    df = daft.from_pydict(
+        {
+            "id": [1, 2, 3, 3, 3, 3, 2, 1, 3, 1],
+            "values": [1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5, 9.5, 10.5],
+        }
+    ).into_partitions(3)
+    expected = (
+        df.groupby("id")
+        .agg(
+            col("values").approx_count_distinct().alias("approx_count_distinct"),
+            col("values").approx_percentiles(0.5).alias("median"),
+            col("values").approx_percentiles([0.25, 0.75]).alias("quartiles"),
+        )
+        .sort("id")
+        .to_pydict()
+    )
+
+    actual = daft.sql("""
+    SELECT
+        id,
+        approx_count_distinct(values) as approx_count_distinct,
+        approx_percentile(values, 0.5) as median,
+        approx_quantile(values, [0.25, 0.75]) as quartiles
+    FROM df
+    GROUP BY id
+    ORDER BY id
+    """).to_pydict()
+
+    assert actual == expected
+
+
+def test_approx_percentile_sql_invalid_args():
+    df = daft.from_pydict({"values": [1.5, 2.5]})
+
+    with pytest.raises(Exception, match="between 0 and 1"):
+        daft.sql("SELECT approx_percentile(values, 1.5) FROM df").collect()
+
+    with pytest.raises(Exception, match="must be a number or a list of numbers"):
+        daft.sql("SELECT approx_percentile(values, values) FROM df").collect()
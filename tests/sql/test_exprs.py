@@ -268,3 +268,37 @@ def test_round(precision, value, expected):
     expected = {"literal": [expected]}
 
     assert actual == expected
+
+
+def test_random_exprs():
+    df = daft.from_pydict({"a": list(range(100))})
+    catalog = SQLCatalog({"df": df})
+    actual = daft.sql(
+        """
+    SELECT
+        rand() as rand,
+        randn(7) as randn,
+        randint(-3, 3, 7) as randint
+    FROM df
+    """,
+        catalog=catalog,
+    ).to_pydict()
+
+    assert all(0 <= value < 1 for value in actual["rand"])
+    assert all(-3 <= value < 3 for value in actual["randint"])
+    assert len(set(actual["randn"])) == 100
+
+
+@pytest.mark.parametrize("query", ["select randint(3, 3)", "select randint(1.5, 3)", "select rand(-1)"])
+def test_random_exprs_invalid_args(query):
+    with pytest.raises(Exception):
+        daft.sql(query).collect()
+
+
+def test_width_bucket():
+    df = daft.from_pydict({"x": [-1.0, 0.0, 2.5, 7.5, 10.0, None]})
+    catalog = SQLCatalog({"df": df})
+    actual = daft.sql("SELECT width_bucket(x, 0, 10, 4) as bucket FROM df", catalog=catalog).to_pydict()
+    expected = df.select(col("x").width_bucket(0, 10, 4).alias("bucket")).to_pydict()
+
+    assert actual == expected == {"bucket": [0, 1, 2, 4, 5, None]}
@@ -0,0 +1,50 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import col
+from daft.functions import geohash_decode, geohash_encode, haversine_distance, point, within_bbox
+
+
+@pytest.fixture
+def df():
+    return daft.from_pydict(
+        {
+            "lat": [51.5074, 48.8566, 35.6762, -18.1416],
+            "lon": [-0.1278, 2.3522, 139.6503, 178.4419],
+        }
+    )
+
+
+def test_geo_functions(df):
+    actual = daft.sql(
+        """
+        SELECT
+            point(lat, lon) AS point,
+            haversine_distance(lat, lon, 40.7128, -74.0060) AS distance,
+            geohash_encode(lat, lon, 7) AS geohash,
+            geohash_decode(geohash_encode(lat, lon, 7)) AS center,
+            within_bbox(lat, lon, -30.0, 120.0, 40.0, -150.0) AS in_pacific
+        FROM df
+        """
+    ).to_pydict()
+    expected = df.select(
+        point(col("lat"), col("lon")).alias("point"),
+        haversine_distance(col("lat"), col("lon"), 40.7128, -74.0060).alias("distance"),
+        geohash_encode(col("lat"), col("lon"), 7).alias("geohash"),
+        geohash_decode(geohash_encode(col("lat"), col("lon"), 7)).alias("center"),
+        within_bbox(col("lat"), col("lon"), -30.0, 120.0, 40.0, -150.0).alias("in_pacific"),
+    ).to_pydict()
+    assert actual == expected
+    assert actual["in_pacific"] == [False, False, True, True]
+
+
+def test_geohash_encode_invalid_precision(df):
+    with pytest.raises(Exception, match="between 1 and 12"):
+        daft.sql("SELECT geohash_encode(lat, lon, 13) FROM df").collect()
+
+
+def test_haversine_distance_wrong_number_of_args(df):
+    with pytest.raises(Exception, match="takes 4 arguments"):
+        daft.sql("SELECT haversine_distance(lat, lon, 0.0) FROM df").collect()
@@ -101,3 +101,9 @@ def test_groupby_orderby_count_star(df):
     """)
 
     assert df.collect().to_pydict() == {"text": ["g2", "g3", "g1"], "n": [3, 7, 103]}
+
+
+def test_orderby_collate():
+    df = daft.from_pydict({"f": ["b", "A", "c"]})
+    df = daft.sql("SELECT * FROM df ORDER BY f COLLATE nocase")
+    assert df.collect().to_pydict() == {"f": ["A", "b", "c"]}
@@ -56,6 +56,28 @@ def test_intersect_with_nulls(make_df):
     assert actual.to_pydict() == expected.to_pydict()
 
 
+def test_except(make_df):
+    df1 = make_df({"foo": [1, 2, 2, 3, 3, None]})
+    df2 = make_df({"bar": [2, 3, 4]})
+    catalog = SQLCatalog({"df1": df1, "df2": df2})
+
+    expected = df1.except_distinct(df2).sort(by="foo").to_pydict()
+    actual = daft.sql("select * from df1 except select * from df2", catalog).sort(by="foo").to_pydict()
+    assert actual == expected == {"foo": [1, None]}
+
+    expected = df1.except_all(df2).sort(by="foo").to_pydict()
+    actual = daft.sql("select * from df1 except all select * from df2", catalog).sort(by="foo").to_pydict()
+    assert actual == expected == {"foo": [1, 2, 3, None]}
+
+
+def test_intersect_all(make_df):
+    df1 = make_df({"foo": [1, 2, 2, 2, 3]})
+    df2 = make_df({"bar": [2, 2, 3, 4]})
+    catalog = SQLCatalog({"df1": df1, "df2": df2})
+    actual = daft.sql("select * from df1 intersect all select * from df2", catalog).sort(by="foo").to_pydict()
+    assert actual == df1.intersect_all(df2).sort(by="foo").to_pydict() == {"foo": [2, 2, 3]}
+
+
 def test_union_simple(make_df):
     df1 = make_df({"foo": [1, 2, 3]})
     df2 = make_df({"foo": [2, 3, 4]})
@@ -325,3 +325,38 @@ def test_cast_image():
     df = daft.from_pydict({"img": s})
     actual = daft.sql("select cast(img as image(RGB)) from df", catalog=SQLCatalog({"df": df})).collect()
     assert actual.schema()["img"].dtype == DataType.image("RGB")
+
+
+def test_sql_pivot():
+    df = daft.from_pydict(
+        {
+            "version": ["3.8", "3.8", "3.9", "3.9"],
+            "platform": ["macos", "macos", "macos", "windows"],
+            "downloads": [100, 200, 150, 250],
+        }
+    )
+    actual = daft.sql("""
+        SELECT * FROM df
+        PIVOT (sum(downloads) FOR platform IN ('macos', 'windows' AS win, 'linux'))
+        ORDER BY version
+        """).to_pydict()
+    assert actual == {
+        "version": ["3.8", "3.9"],
+        "macos": [300, 150],
+        "win": [None, 250],
+        "linux": [None, None],
+    }
+
+    with pytest.raises(Exception, match="list of values"):
+        daft.sql("SELECT * FROM df PIVOT (sum(downloads) FOR platform IN (ANY))").collect()
+
+
+def test_sql_unpivot():
+    df = daft.from_pydict({"year": [2020, 2021], "jan": [10, 30], "feb": [20, 40]})
+    actual = daft.sql("""
+        SELECT * FROM df
+        UNPIVOT (inventory FOR month IN (jan, feb))
+        ORDER BY year, month DESC
+        """).to_pydict()
+    expected = df.unpivot("year", ["jan", "feb"], "month", "inventory").sort(["year", "month"], desc=[False, True])
+    assert actual == expected.to_pydict()
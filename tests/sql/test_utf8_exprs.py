@@ -1,3 +1,5 @@
+import decimal
+
 import daft
 from daft import col
 
@@ -120,3 +122,66 @@ def test_utf8_exprs():
     )
     actual = actual.to_pydict()
     assert actual == expected
+
+
+def test_like_prefix_filter(tmp_path):
+    daft.from_pydict({"s": ["ab", "abc", "abd", "abz\U0010ffff", "ac", "b", None]}).write_parquet(str(tmp_path))
+    df = daft.read_parquet(str(tmp_path))
+    actual = daft.sql("SELECT s FROM df WHERE s LIKE 'ab%' ORDER BY s").to_pydict()
+    assert actual == {"s": ["ab", "abc", "abd", "abz\U0010ffff"]}
+    actual = daft.sql("SELECT s FROM df WHERE s NOT LIKE 'abc' ORDER BY s").to_pydict()
+    assert actual == {"s": ["ab", "abd", "abz\U0010ffff", "ac", "b"]}
+
+
+def test_unicode_normalization_functions():
+    df = daft.from_pydict({"s": ["Café", "ﬁancé", "Straße"]})
+    actual = daft.sql("""
+        SELECT
+            normalize(s, form:='NFC') AS nfc,
+            remove_accents(s) AS no_accents,
+            ascii_fold(s) AS folded
+        FROM df
+    """).to_pydict()
+    assert actual == {
+        "nfc": ["Café", "ﬁancé", "Straße"],
+        "no_accents": ["Cafe", "ﬁance", "Straße"],
+        "folded": ["Cafe", "fiance", "Strasse"],
+    }
+
+
+def test_validation_functions():
+    df = daft.from_pydict({"s": ["user@example.com", "https://10.0.0.1/x", "+1 555 010 0199", "2001:db8::1"]})
+    actual = daft.sql("""
+        SELECT
+            is_email(s) AS email,
+            is_ipv6(s) AS ipv6,
+            is_phone_number(s) AS phone,
+            extract_domain(s) AS domain,
+            is_ipv4(extract_domain(s)) AS ipv4,
+            int_to_ip(ip_to_int(extract_domain(s))) AS ip
+        FROM df
+    """).to_pydict()
+    assert actual == {
+        "email": [True, False, False, False],
+        "ipv6": [False, False, False, True],
+        "phone": [False, False, True, False],
+        "domain": ["example.com", "10.0.0.1", None, None],
+        "ipv4": [False, True, None, None],
+        "ip": [None, "10.0.0.1", None, None],
+    }
+
+
+def test_decimal_parsing_functions():
+    df = daft.from_pydict({"s": ["$1,234.5", "(99.99)", "1.234,5 €", "n/a"]})
+    actual = daft.sql("""
+        SELECT
+            parse_decimal(s, 10, 2) AS us,
+            parse_decimal(s, 10, 2, decimal_separator:=',', thousands_separator:='.') AS eu,
+            format_decimal(parse_decimal(s, 10, 2), currency_symbol:='$', accounting:=true) AS formatted
+        FROM df
+    """).to_pydict()
+    assert actual == {
+        "us": [decimal.Decimal("1234.50"), decimal.Decimal("-99.99"), None, None],
+        "eu": [None, decimal.Decimal("-9999.00"), decimal.Decimal("1234.50"), None],
+        "formatted": ["$1,234.50", "($99.99)", None, None],
+    }
@@ -0,0 +1,110 @@
+from __future__ import annotations
+
+from datetime import datetime, timedelta
+
+import pytest
+
+import daft
+from daft import col
+from daft.incremental import MicroBatchStream, aggregate_incrementally
+
+
+def _write(path, data):
+    daft.from_pydict(data).write_parquet(str(path))
+
+
+def test_aggregate_incrementally(tmp_path):
+    glob = str(tmp_path / "**" / "*.parquet")
+    aggs = [
+        col("x").sum(),
+        col("x").count().alias("count"),
+        col("x").max().alias("max"),
+        col("x").agg_list().alias("xs"),
+    ]
+
+    def read(paths):
+        return daft.read_parquet(paths)
+
+    _write(tmp_path / "0", {"k": ["a", "b", "a"], "x": [1, 2, 3]})
+    state = aggregate_incrementally(glob, read, aggs, group_by=["k"])
+    assert len(state.manifest) == 1
+
+    # Nothing to do when no files were added.
+    assert aggregate_incrementally(glob, read, aggs, group_by=["k"], previous=state) is state
+
+    _write(tmp_path / "1", {"k": ["b", "c"], "x": [4, 5]})
+    state = aggregate_incrementally(glob, read, aggs, group_by=["k"], previous=state)
+    assert len(state.manifest) == 2
+
+    result = state.result.sort("k").to_pydict()
+    assert result["k"] == ["a", "b", "c"]
+    assert result["x"] == [4, 6, 5]
+    assert result["count"] == [2, 2, 1]
+    assert result["max"] == [3, 4, 5]
+    assert [sorted(xs) for xs in result["xs"]] == [[1, 3], [2, 4], [5]]
+
+
+def test_aggregate_incrementally_without_group_by(tmp_path):
+    glob = str(tmp_path / "**" / "*.parquet")
+    aggs = [col("x").sum()]
+
+    _write(tmp_path / "0", {"x": [1, 2]})
+    state = aggregate_incrementally(glob, daft.read_parquet, aggs)
+    _write(tmp_path / "1", {"x": [3]})
+    state = aggregate_incrementally(glob, daft.read_parquet, aggs, previous=state)
+    assert state.result.to_pydict() == {"x": [6]}
+
+
+def test_aggregate_incrementally_rejects_unmergeable_aggregations(tmp_path):
+    _write(tmp_path / "0", {"x": [1, 2]})
+    with pytest.raises(ValueError, match="can't be merged"):
+        aggregate_incrementally(str(tmp_path / "**" / "*.parquet"), daft.read_parquet, [col("x").mean()])
+
+
+def test_micro_batch_stream(tmp_path):
+    input_dir = tmp_path / "input"
+    checkpoint_path = str(tmp_path / "checkpoint.json")
+    batches = []
+
+    def make_stream():
+        return MicroBatchStream(
+            str(input_dir / "**" / "*.parquet"),
+            read=daft.read_parquet,
+            transform=lambda df: df.with_column("y", col("x") * 2),
+            sink=lambda df: batches.append(df.to_pydict()),
+            checkpoint_path=checkpoint_path,
+        )
+
+    _write(input_dir / "0", {"x": [1, 2]})
+    stream = make_stream()
+    assert stream.run(stop_when_idle=True) == 1
+    assert batches == [{"x": [1, 2], "y": [2, 4]}]
+
+    # A restarted stream only processes files that were added since the checkpoint.
+    _write(input_dir / "1", {"x": [3]})
+    stream = make_stream()
+    assert len(stream.processed_files) == 1
+    assert stream.run(stop_when_idle=True) == 1
+    assert batches[-1] == {"x": [3], "y": [6]}
+    assert stream.run_once() == 0
+
+
+def test_micro_batch_stream_drops_late_rows(tmp_path):
+    input_dir = tmp_path / "input"
+    batches = []
+    stream = MicroBatchStream(
+        str(input_dir / "**" / "*.parquet"),
+        read=daft.read_parquet,
+        sink=lambda df: batches.append(df.sort("x").to_pydict()["x"]),
+        event_time_column="ts",
+        allowed_lateness=timedelta(minutes=10),
+    )
+
+    start = datetime(2024, 1, 1, 12, 0)
+    _write(input_dir / "0", {"x": [1, 2], "ts": [start, start + timedelta(minutes=30)]})
+    stream.run_once()
+    assert stream.watermark == start + timedelta(minutes=20)
+
+    _write(input_dir / "1", {"x": [3, 4], "ts": [start + timedelta(minutes=5), start + timedelta(minutes=25)]})
+    stream.run_once()
+    assert batches == [[1, 2], [4]]
@@ -0,0 +1,151 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pyarrow.parquet as papq
+import pytest
+
+import daft
+from daft.context import get_context, invalidate_result_cache
+from daft.functions import rand
+from daft.runners.result_cache import QueryResultCache
+from tests.conftest import get_tests_daft_runner_name
+
+
+class _FakeBuilder:
+    def __init__(self, fingerprint: int, plan: str = "", cacheable: bool = True, versions=None) -> None:
+        self._fingerprint = fingerprint
+        self._plan = plan
+        self._cacheable = cacheable
+        self.versions = [("a.parquet", "v1")] if versions is None else versions
+
+    def fingerprint(self) -> int:
+        return self._fingerprint
+
+    def plan_eq(self, other: _FakeBuilder) -> bool:
+        return self._plan == other._plan
+
+    def is_cacheable(self) -> bool:
+        return self._cacheable
+
+    def source_file_versions(self) -> list[tuple[str, str | None]]:
+        return self.versions
+
+
+def test_query_result_cache_evicts_least_recently_used():
+    cache = QueryResultCache(max_entries=2)
+    versions = [("a.parquet", "v1")]
+    cache.put(_FakeBuilder(1), versions, ["a"])
+    cache.put(_FakeBuilder(2), versions, ["b"])
+    assert cache.get(_FakeBuilder(1), versions) == ["a"]
+
+    cache.put(_FakeBuilder(3), versions, ["c"])
+    assert cache.get(_FakeBuilder(2), versions) is None
+    assert cache.get(_FakeBuilder(1), versions) == ["a"]
+    assert len(cache) == 2
+
+    assert cache.invalidate(_FakeBuilder(1))
+    assert not cache.invalidate(_FakeBuilder(1))
+    cache.clear()
+    assert len(cache) == 0
+
+
+def test_query_result_cache_compares_plans():
+    cache = QueryResultCache()
+    versions = [("a.parquet", "v1")]
+    cache.put(_FakeBuilder(1, plan="x"), versions, ["a"])
+    # Plans whose fingerprints collide aren't mistaken for each other.
+    assert cache.get(_FakeBuilder(1, plan="y"), versions) is None
+    assert cache.get(_FakeBuilder(1, plan="x"), versions) == ["a"]
+    # Results are dropped once the files they were computed from change.
+    assert cache.get(_FakeBuilder(1, plan="x"), [("a.parquet", "v2")]) is None
+    assert len(cache) == 0
+
+
+def test_query_result_cache_only_runs_on_miss():
+    cache = QueryResultCache()
+    runs = []
+
+    def run():
+        runs.append(1)
+        yield from ["a", "b"]
+
+    assert list(cache.get_or_run(_FakeBuilder(1), run())) == ["a", "b"]
+    assert list(cache.get_or_run(_FakeBuilder(1), run())) == ["a", "b"]
+    assert len(runs) == 1
+
+    # Results of queries that aren't fully consumed are not cached.
+    next(cache.get_or_run(_FakeBuilder(2), run()))
+    assert len(cache) == 1
+
+
+@pytest.mark.parametrize(
+    "builder",
+    [_FakeBuilder(1, cacheable=False), _FakeBuilder(1, versions=[("a.parquet", None)])],
+    ids=["not_cacheable", "unversioned_file"],
+)
+def test_query_result_cache_skips_uncacheable_queries(builder):
+    cache = QueryResultCache()
+    runs = []
+
+    def run():
+        runs.append(1)
+        yield from ["a"]
+
+    assert list(cache.get_or_run(builder, run())) == ["a"]
+    assert list(cache.get_or_run(builder, run())) == ["a"]
+    assert len(runs) == 2
+    assert len(cache) == 0
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() == "ray", reason="The result cache is not used by the Ray Runner")
+def test_result_cache_reuses_results(tmp_path):
+    path = str(tmp_path / "data.parquet")
+    daft.from_pydict({"x": [1, 2, 3]}).write_parquet(path)
+    calls = []
+
+    @daft.udf(return_dtype=daft.DataType.int64())
+    def double(x):
+        calls.append(1)
+        return [v * 2 for v in x.to_pylist()]
+
+    def query():
+        return daft.read_parquet(path).select(double(daft.col("x")).alias("y")).sort("y")
+
+    with daft.execution_config_ctx(enable_result_cache=True):
+        invalidate_result_cache()
+        assert query().to_pydict() == {"y": [2, 4, 6]}
+        num_calls = len(calls)
+        assert query().to_pydict() == {"y": [2, 4, 6]}
+        assert len(calls) == num_calls
+
+        invalidate_result_cache(query())
+        assert query().to_pydict() == {"y": [2, 4, 6]}
+        assert len(calls) > num_calls
+        invalidate_result_cache()
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() == "ray", reason="The result cache is not used by the Ray Runner")
+def test_result_cache_invalidated_by_overwritten_files(tmp_path):
+    path = str(tmp_path / "data.parquet")
+    papq.write_table(pa.table({"x": [1, 2, 3]}), path)
+
+    def query():
+        return daft.read_parquet(path).sort("x")
+
+    with daft.execution_config_ctx(enable_result_cache=True):
+        invalidate_result_cache()
+        assert query().to_pydict() == {"x": [1, 2, 3]}
+        # Overwrite the file in place with one of the same size.
+        papq.write_table(pa.table({"x": [4, 5, 6]}), path)
+        assert query().to_pydict() == {"x": [4, 5, 6]}
+        invalidate_result_cache()
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() == "ray", reason="The result cache is not used by the Ray Runner")
+def test_result_cache_skips_nondeterministic_queries():
+    df = daft.from_pydict({"x": [1, 2, 3]})
+    with daft.execution_config_ctx(enable_result_cache=True):
+        invalidate_result_cache()
+        df.with_column("r", rand()).collect()
+        df.sample(0.5).collect()
+        assert len(get_context().get_or_create_runner()._query_result_cache) == 0
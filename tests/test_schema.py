@@ -59,6 +59,28 @@ def test_schema_eq():
     assert s1 != t_empty.schema()
 
 
+def test_schema_validate_matches():
+    schema = TABLE.schema()
+    schema.validate_matches(TABLE.schema())
+
+    expected = Schema._from_field_name_and_types(
+        [("int", DataType.int32()), ("float", DataType.float64()), ("string", DataType.string()), ("date", DataType.date())]
+    )
+    with pytest.raises(ValueError) as exc_info:
+        schema.validate_matches(expected)
+    message = str(exc_info.value)
+    assert "column `int` has type Int64, expected Int32" in message
+    assert "missing column `date` of type Date" in message
+    assert "unexpected column `bool` of type Boolean" in message
+
+
+def test_schema_validate_matches_order():
+    schema = TABLE.schema()
+    reordered = Schema._from_fields(list(reversed(list(schema))))
+    with pytest.raises(ValueError, match="columns are ordered as"):
+        schema.validate_matches(reordered)
+
+
 def test_schema_to_name_set():
     schema = TABLE.schema()
     assert schema.to_name_set() == set(DATA.keys())
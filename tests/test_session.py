@@ -2,6 +2,7 @@ import pytest
 
 import daft
 from daft.catalog import Catalog, Identifier, Table
+from daft.context import get_context
 from daft.session import Session
 
 ###
@@ -13,6 +14,21 @@ def test_current_session_exists():
     assert daft.current_session() is not None
 
 
+def test_current_session_is_held_by_context():
+    original = daft.current_session()
+    try:
+        sess = Session()
+        daft.set_session(sess)
+        daft.attach_table(Table.from_df("tbl", daft.from_pydict({"x": [1]})), alias="tbl")
+        #
+        # the context hands out the same session it was given
+        assert get_context().session.has_table("tbl")
+        assert sess.has_table("tbl")
+    finally:
+        daft.set_session(original)
+    assert not daft.current_session().has_table("tbl")
+
+
 ###
 # ATTACH & DETACH CATALOG
 ###
@@ -112,6 +128,47 @@ def test_detach_table():
         sess.detach_table("tbl1")
 
 
+###
+# ATTACH & DETACH FUNCTION
+###
+
+
+@daft.udf(return_dtype=daft.DataType.int64())
+def add_one(x):
+    return [v + 1 for v in x.to_pylist()]
+
+
+def test_attach_function():
+    sess = Session()
+    sess.create_temp_table("T", daft.from_pydict({"x": [1, 2, 3]}))
+    #
+    # attach a udf, which defaults to its function name, and a plain function
+    sess.attach_function(add_one)
+    sess.attach_function(lambda x: x * 2, alias="double")
+    #
+    # functions are callable from sql
+    assert sess.sql("SELECT add_one(x) AS y, double(x) AS z FROM T").to_pydict() == {"y": [2, 3, 4], "z": [2, 4, 6]}
+    #
+    # error!
+    with pytest.raises(Exception, match="already exists"):
+        sess.attach_function(add_one)
+
+
+def test_detach_function():
+    sess = Session()
+    sess.create_temp_table("T", daft.from_pydict({"x": [1, 2, 3]}))
+    sess.attach_function(add_one)
+    #
+    # detach existing
+    sess.detach_function("add_one")
+    with pytest.raises(Exception, match="not found"):
+        sess.sql("SELECT add_one(x) FROM T").collect()
+    #
+    # error!
+    with pytest.raises(Exception, match="not found"):
+        sess.detach_function("add_one")
+
+
 ###
 # CREATE TABLE
 ###
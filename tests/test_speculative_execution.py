@@ -0,0 +1,109 @@
+from __future__ import annotations
+
+import threading
+import time
+
+import pytest
+
+import daft
+from daft.runners import pyrunner
+from daft.runners.pyrunner import PyRunner, SpeculativeExecutionTracker, TaskCancelledError
+from tests.conftest import get_tests_daft_runner_name
+
+
+def test_speculative_execution_tracker(monkeypatch):
+    now = 0.0
+    monkeypatch.setattr(pyrunner.time, "monotonic", lambda: now)
+
+    tracker = SpeculativeExecutionTracker(multiplier=2.0)
+    for task_id in ["a", "b", "c", "slow"]:
+        tracker.task_started(task_id)
+
+    # Nothing is a straggler until enough tasks have finished to compare against.
+    now = 10.0
+    tracker.task_finished("a")
+    tracker.task_finished("b")
+    assert tracker.stragglers() == []
+
+    tracker.task_finished("c")
+    assert tracker.stragglers() == []
+
+    now = 20.5
+    assert tracker.stragglers() == ["slow"]
+
+    # A straggler is only re-launched once.
+    tracker.mark_speculated("slow")
+    assert tracker.stragglers() == []
+
+
+def test_speculative_execution_tracker_cancels_other_copy():
+    tracker = SpeculativeExecutionTracker(multiplier=2.0)
+    cancel_event = tracker.task_started("a")
+    assert tracker.cancel_event("a") is cancel_event
+    assert not cancel_event.is_set()
+
+    # Whichever copy finishes first stops the other one.
+    tracker.task_finished("a")
+    assert cancel_event.is_set()
+    assert tracker.cancel_event("a") is None
+
+
+def test_cancelled_task_stops_before_next_instruction():
+    class CountingInstruction:
+        def __init__(self):
+            self.num_runs = 0
+
+        def run(self, partitions):
+            self.num_runs += 1
+            return partitions
+
+    instruction = CountingInstruction()
+    cancel_event = threading.Event()
+    cancel_event.set()
+    with pytest.raises(TaskCancelledError):
+        PyRunner(use_thread_pool=False).build_partitions([instruction], [], [], cancel_event)
+    assert instruction.num_runs == 0
+
+
+def test_speculative_execution_multiplier_must_be_greater_than_one():
+    with pytest.raises(ValueError, match="greater than 1.0"):
+        with daft.execution_config_ctx(speculative_execution_multiplier=0.5):
+            pass
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "py", reason="Speculative execution is only supported on the PyRunner"
+)
+def test_speculative_execution_results_are_unchanged():
+    df = daft.from_pydict({"x": list(range(100))}).into_partitions(10)
+    df = df.with_column("y", df["x"] * 2)
+    with daft.execution_config_ctx(enable_speculative_execution=True, speculative_execution_multiplier=1.01):
+        result = df.sort("x").to_pydict()
+    assert result == {"x": list(range(100)), "y": [x * 2 for x in range(100)]}
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "py", reason="Speculative execution is only supported on the PyRunner"
+)
+def test_speculative_execution_relaunches_stragglers():
+    lock = threading.Lock()
+    batches = []
+
+    @daft.udf(return_dtype=daft.DataType.int64())
+    def slow_the_first_time(x):
+        values = x.to_pylist()
+        with lock:
+            is_first_time = values not in batches
+            batches.append(values)
+        if 0 in values and is_first_time:
+            time.sleep(3)
+        return [v * 2 for v in values]
+
+    df = daft.from_pydict({"x": list(range(100))}).into_partitions(10)
+    df = df.with_column("y", slow_the_first_time(df["x"]))
+    with daft.execution_config_ctx(enable_speculative_execution=True, speculative_execution_multiplier=1.5):
+        result = df.sort("x").to_pydict()
+    assert result == {"x": list(range(100)), "y": [x * 2 for x in range(100)]}
+
+    # The straggler was re-launched while it was still running.
+    assert sum(0 in values for values in batches) == 2